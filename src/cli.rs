@@ -26,7 +26,7 @@ pub(crate) struct Cli {
 
 #[derive(clap::Args, Debug)]
 pub(crate) struct ValidateCli {
-    /// Store to validate: 'user' (blank-slate semantics) or 'repo' (dogfood backlog).
+    /// Store to validate: 'user' (blank-slate semantics), 'repo' (dogfood backlog), or 'both'.
     #[clap(long, default_value = "repo")]
     pub store: String,
     /// Output format: 'text' or 'json'.
@@ -35,6 +35,16 @@ pub(crate) struct ValidateCli {
     /// Print per-gate timing information.
     #[clap(long, short = 'v')]
     pub verbose: bool,
+    /// Keep running and re-validate whenever the repo or `.decapod/data` changes.
+    #[clap(long)]
+    pub watch: bool,
+    /// Describe a gate (purpose, bound constitution fragment, remediation) without running it.
+    #[clap(long, value_name = "GATE", conflicts_with = "watch")]
+    pub explain: Option<String>,
+    /// Report gates that newly fail or pass versus the previous `--baseline` run,
+    /// then store this run as the baseline (`.decapod/generated/validate_baseline.json`).
+    #[clap(long)]
+    pub baseline: bool,
 }
 
 #[derive(clap::Args, Debug)]
@@ -42,6 +52,16 @@ pub(crate) struct CapabilitiesCli {
     /// Output format: 'json' or 'text'.
     #[clap(long, default_value = "text")]
     pub format: String,
+    /// Probe one capability, subsystem, or `<subsystem>.<op>`; exits non-zero if absent.
+    #[clap(long)]
+    pub check: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct BootstrapCli {
+    /// Output format (only 'json' is supported).
+    #[clap(long, default_value = "json")]
+    pub format: String,
 }
 
 #[derive(clap::Args, Debug)]
@@ -63,6 +83,14 @@ pub(crate) enum WorkspaceCommand {
     },
     /// Show current workspace status
     Status,
+    /// List Decapod-managed worktrees under .decapod/workspaces
+    List,
+    /// Remove managed worktrees that are missing on disk or already merged
+    Prune {
+        /// Report what would be removed without removing anything
+        #[clap(long)]
+        dry_run: bool,
+    },
     /// Publish workspace changes as a patch/PR bundle
     Publish {
         /// Title for the change
@@ -71,6 +99,12 @@ pub(crate) enum WorkspaceCommand {
         /// Description for the change
         #[clap(long)]
         description: Option<String>,
+        /// Open a PR/MR on the configured forge (`[publish]` forge/base/reviewers in .decapod/config.toml)
+        #[clap(long)]
+        pr: bool,
+        /// Stage and commit all uncommitted changes, including untracked files
+        #[clap(long, visible_alias = "include-untracked")]
+        all: bool,
     },
 }
 
@@ -141,6 +175,9 @@ pub(crate) struct InitGroupCli {
     /// Seed detected surfaces (repeatable and/or comma-separated).
     #[clap(long = "surface", value_delimiter = ',')]
     pub detected_surfaces: Vec<String>,
+    /// Preset that also seeds categories, risk zones, and starter standards.
+    #[clap(long, value_enum)]
+    pub profile: Option<InitProfileName>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -206,6 +243,9 @@ pub(crate) struct InitWithCli {
     /// Seed detected surfaces (repeatable and/or comma-separated).
     #[clap(long = "surface", value_delimiter = ',')]
     pub detected_surfaces: Vec<String>,
+    /// Preset that also seeds categories, risk zones, and starter standards.
+    #[clap(long, value_enum)]
+    pub profile: Option<InitProfileName>,
 }
 
 #[derive(clap::ValueEnum, Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -220,6 +260,27 @@ pub(crate) struct DecapodProjectConfig {
     pub schema_version: String,
     pub init: InitConfigSection,
     pub repo: RepoContext,
+    /// Settings and other sections owned outside `init` (e.g. `[validate]`, `[publish]`).
+    #[serde(flatten, default)]
+    pub extra: toml::Table,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum InitProfileName {
+    RustLib,
+    Service,
+    Cli,
+}
+
+impl InitProfileName {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InitProfileName::RustLib => "rust-lib",
+            InitProfileName::Service => "service",
+            InitProfileName::Cli => "cli",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -227,6 +288,8 @@ pub(crate) struct InitConfigSection {
     pub specs: bool,
     pub diagram_style: InitDiagramStyle,
     pub entrypoints: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<InitProfileName>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -261,8 +324,10 @@ impl Default for DecapodProjectConfig {
                     "GEMINI.md".to_string(),
                     "CODEX.md".to_string(),
                 ],
+                profile: None,
             },
             repo: RepoContext::default(),
+            extra: toml::Table::new(),
         }
     }
 }
@@ -278,9 +343,22 @@ pub(crate) enum SessionCommand {
     /// Acquire a new session token (required before using other commands)
     Acquire,
     /// Show current session status
-    Status,
+    Status {
+        /// Output format: 'text' or 'json'
+        #[clap(long, default_value = "text")]
+        format: String,
+        /// Print the session token instead of masking it
+        #[clap(long)]
+        show_secrets: bool,
+    },
     /// Release the current session token
     Release,
+    /// Print the resolved agent id and where it came from (env, file, git, default)
+    Whoami {
+        /// Output format: 'text' or 'json'
+        #[clap(long, default_value = "text")]
+        format: String,
+    },
     /// Bootstrap a governed work session with stubs and handshake artifact
     Init {
         /// Intended scope for this work session
@@ -311,9 +389,15 @@ pub(crate) enum SetupCommand {
         /// Install Rust pre-commit hook (fmt + clippy)
         #[clap(long)]
         pre_commit: bool,
+        /// Install pre-push hook that blocks the push when `decapod validate` fails
+        #[clap(long)]
+        pre_push: bool,
         /// Remove installed hooks
         #[clap(long)]
         uninstall: bool,
+        /// Overwrite existing non-Decapod hooks (originals are backed up to `<hook>.pre-decapod`; an existing backup is never replaced)
+        #[clap(long)]
+        force: bool,
     },
 }
 
@@ -639,15 +723,28 @@ pub(crate) enum QaCommand {
 }
 
 #[derive(clap::Args, Debug)]
+#[clap(args_conflicts_with_subcommands = true)]
 pub(crate) struct HandshakeCli {
+    #[clap(subcommand)]
+    pub command: Option<HandshakeCommand>,
     /// Intended scope of work for this agent/session
     #[clap(long)]
-    pub scope: String,
+    pub scope: Option<String>,
     /// Proof commands this agent commits to run
     #[clap(long = "proof")]
     pub proofs: Vec<String>,
 }
 
+#[derive(Subcommand, Debug)]
+pub(crate) enum HandshakeCommand {
+    /// Re-hash the declared docs of a stored handshake and report drift
+    Verify {
+        /// Handshake record (`.decapod/records/handshakes/*.json`)
+        #[clap(long)]
+        path: PathBuf,
+    },
+}
+
 #[derive(clap::Args, Debug)]
 pub(crate) struct ReleaseCli {
     #[clap(subcommand)]
@@ -666,6 +763,64 @@ pub(crate) enum ReleaseCommand {
 
 // ===== Main Command Enum =====
 
+#[derive(clap::Args, Debug)]
+pub(crate) struct ConfigCli {
+    #[clap(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum ConfigCommand {
+    /// Show the effective value of a setting and where it came from
+    Get {
+        key: String,
+        /// Output format: 'json' or 'text'.
+        #[clap(long, default_value = "text")]
+        format: String,
+    },
+    /// Persist a setting in .decapod/config.toml
+    Set { key: String, value: String },
+    /// List every setting with its effective value, source, and env override
+    List {
+        /// Output format: 'json' or 'text'.
+        #[clap(long, default_value = "text")]
+        format: String,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct MigrationCli {
+    #[clap(subcommand)]
+    pub command: MigrationCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum MigrationCommand {
+    /// Backups of .decapod/data taken before schema migrations
+    Backup(MigrationBackupCli),
+}
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct MigrationBackupCli {
+    #[clap(subcommand)]
+    pub command: MigrationBackupCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum MigrationBackupCommand {
+    /// List backups, newest first, with timestamp, version, and size
+    List {
+        /// Output format: 'json' or 'text'.
+        #[clap(long, default_value = "text")]
+        format: String,
+    },
+    /// Verify a backup and swap it in for the current .decapod/data (current state is archived first)
+    Restore {
+        #[clap(long)]
+        id: String,
+    },
+}
+
 #[derive(clap::Args, Debug)]
 pub(crate) struct TraceCli {
     #[clap(subcommand)]
@@ -680,6 +835,12 @@ pub(crate) enum TraceCommand {
         #[clap(long, default_value = "10")]
         last: usize,
     },
+    /// Per-op counts, error rates, and latency percentiles as JSON
+    Stats {
+        /// Include only traces at or after this RFC3339 timestamp
+        #[clap(long)]
+        since: Option<String>,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -696,6 +857,14 @@ pub(crate) enum Command {
     #[clap(name = "setup")]
     Setup(SetupCli),
 
+    /// Repo-scoped settings in .decapod/config.toml (env vars take precedence)
+    #[clap(name = "config")]
+    Config(ConfigCli),
+
+    /// Schema migration backups: list and restore
+    #[clap(name = "migration")]
+    Migration(MigrationCli),
+
     /// Session token management (required for agent operation)
     #[clap(name = "session", visible_alias = "s")]
     Session(SessionCli),
@@ -760,6 +929,10 @@ pub(crate) enum Command {
     #[clap(name = "capabilities")]
     Capabilities(CapabilitiesCli),
 
+    /// One-call onboarding bundle: core constitution, capabilities, repo map, workspace status
+    #[clap(name = "bootstrap")]
+    Bootstrap(BootstrapCli),
+
     /// Internalized context artifacts: create, attach, and inspect context adapters
     #[clap(name = "internalize")]
     Internalize(internalize::InternalizeCli),
@@ -830,6 +1003,9 @@ pub(crate) enum StateCommitCommand {
         /// Output file for scope_record.cbor
         #[clap(long, default_value = "scope_record.cbor")]
         output: PathBuf,
+        /// Prior scope_record.cbor over the same base; unchanged entries are reused
+        #[clap(long)]
+        since: Option<PathBuf>,
     },
     /// Verify a STATE_COMMIT matches current workspace
     Verify {
@@ -839,6 +1015,9 @@ pub(crate) enum StateCommitCommand {
         /// Expected state_commit_root
         #[clap(long)]
         expected_root: Option<String>,
+        /// Also compare each recorded entry against the current checkout
+        #[clap(long)]
+        against_worktree: bool,
     },
     /// Explain the contents of a scope_record.cbor file
     Explain {
@@ -848,12 +1027,31 @@ pub(crate) enum StateCommitCommand {
     },
 }
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub(crate) enum BrokerAuditFormat {
+    Text,
+    Jsonl,
+}
+
 #[derive(Subcommand, Debug)]
 pub(crate) enum BrokerCommand {
     /// Show the audit log of brokered mutations.
-    Audit,
+    Audit {
+        /// Output format: 'text' (raw log) or 'jsonl' (validated, one event per line).
+        #[clap(long, value_enum, default_value = "text")]
+        format: BrokerAuditFormat,
+    },
     /// Verify audit log integrity and detect crash-induced divergence.
     Verify,
+    /// Rebuild subsystem state as of an event (inclusive) into a throwaway directory.
+    Replay {
+        /// Event ID from todo.events.jsonl or federation.events.jsonl
+        #[clap(long)]
+        to: String,
+        /// Empty or absent directory to reconstruct into; the live store is untouched
+        #[clap(long)]
+        into: PathBuf,
+    },
 }
 
 #[derive(clap::Args, Debug)]
@@ -868,19 +1066,52 @@ pub(crate) enum KnowledgeCommand {
     Add {
         #[clap(long)]
         id: String,
-        #[clap(long)]
-        title: String,
-        #[clap(long)]
-        text: String,
-        #[clap(long)]
-        provenance: String,
+        /// Defaults to the first markdown heading of --text-file.
+        #[clap(long, required_unless_present = "text_file")]
+        title: Option<String>,
+        #[clap(
+            long,
+            required_unless_present = "text_file",
+            conflicts_with = "text_file"
+        )]
+        text: Option<String>,
+        /// Read the entry body verbatim from a markdown file.
+        #[clap(long, value_name = "PATH")]
+        text_file: Option<PathBuf>,
+        /// Defaults to `file:<path>@sha256:<hash>` of --text-file.
+        #[clap(long, required_unless_present = "text_file")]
+        provenance: Option<String>,
         #[clap(long)]
         claim_id: Option<String>,
+        /// Slug grouping related entries (e.g. `security`, `style`).
+        #[clap(long, default_value = "general")]
+        namespace: String,
     },
     /// Search project knowledge
     Search {
         #[clap(long)]
         query: String,
+        /// Stores to query: repo, user, or all (results tagged with their origin)
+        #[clap(long, default_value = "repo")]
+        scope: String,
+        /// Only return entries in this namespace (default: all namespaces).
+        #[clap(long)]
+        namespace: Option<String>,
+    },
+    /// Summarize entries by status, namespace, and provenance source
+    Stats {
+        /// Expiry reference timestamp (epoch seconds + Z); defaults to now.
+        #[clap(long)]
+        as_of: Option<String>,
+    },
+    /// Fold a duplicate entry into another and deprecate the duplicate
+    Merge {
+        /// Entry that keeps the combined content
+        #[clap(long)]
+        into: String,
+        /// Duplicate entry to deprecate
+        #[clap(long)]
+        from: String,
     },
     /// Record explicit promotion of advisory/episodic knowledge into procedural class
     Promote {
@@ -932,7 +1163,12 @@ pub(crate) enum ArchiveCommand {
     /// List all session archives
     List,
     /// Verify archive integrity (hashes and presence)
-    Verify,
+    Verify {
+        /// Re-record hashes that drifted for a known format reason; real
+        /// corruption (missing or changed content) is refused
+        #[clap(long)]
+        repair: bool,
+    },
 }
 
 #[derive(clap::Args, Debug)]
@@ -952,8 +1188,37 @@ pub(crate) enum FeedbackCommand {
         #[clap(long)]
         links: Option<String>,
     },
-    /// Propose preference updates based on feedback
-    Propose,
+    /// List feedback entries (open by default)
+    List {
+        /// Filter by status: open, addressed, rejected, or all
+        #[clap(long)]
+        status: Option<String>,
+    },
+    /// Resolve a feedback entry as addressed or rejected
+    Resolve {
+        #[clap(long)]
+        id: String,
+        #[clap(long, value_parser = ["addressed", "rejected"])]
+        outcome: String,
+        /// Note explaining the resolution
+        #[clap(long)]
+        note: Option<String>,
+    },
+    /// Create a todo from a feedback entry and mark the feedback promoted
+    Promote {
+        #[clap(long)]
+        id: String,
+        #[clap(long, default_value = "medium", value_parser = ["high", "medium", "low"])]
+        priority: String,
+        #[clap(long)]
+        category: Option<String>,
+    },
+    /// Propose preference updates based on open feedback
+    Propose {
+        /// Record the proposed preferences and mark the source feedback incorporated
+        #[clap(long)]
+        apply: bool,
+    },
 }
 
 #[derive(clap::Args, Debug)]
@@ -997,7 +1262,16 @@ pub enum ProofSubCommand {
         name: String,
     },
     /// Show proof configuration and results
-    List,
+    List {
+        #[clap(long, value_enum, default_value = "text")]
+        format: ProofListFormat,
+    },
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub enum ProofListFormat {
+    Text,
+    Json,
 }
 
 #[derive(clap::Args, Debug)]
@@ -1021,6 +1295,12 @@ pub(crate) enum ContextCommand {
         path: PathBuf,
         #[clap(long)]
         summary: String,
+        /// Replace the file with the summary and a `context restore` pointer.
+        #[clap(long)]
+        in_place: bool,
+        /// With --in-place, rewrite this index file instead of --path.
+        #[clap(long, requires = "in_place")]
+        index: Option<PathBuf>,
     },
     /// Restore content from an archive (budget-gated)
     Restore {
@@ -1030,6 +1310,9 @@ pub(crate) enum ContextCommand {
         profile: String,
         #[clap(long)]
         current_files: Vec<PathBuf>,
+        /// Restore even if the profile budget would be exceeded.
+        #[clap(long)]
+        force: bool,
     },
 }
 
@@ -1044,6 +1327,9 @@ pub(crate) struct SchemaCli {
     /// Force deterministic output (removes volatile timestamps)
     #[clap(long)]
     pub deterministic: bool,
+    /// Diff the current catalog against a prior `data schema` JSON file
+    #[clap(long, value_name = "OLD_CATALOG_JSON")]
+    pub diff: Option<PathBuf>,
 }
 
 #[derive(clap::Args, Debug)]
@@ -266,6 +266,9 @@ decapod rpc --op context.resolve
 ## Control-Plane First Loop
 
 ```bash
+# One call: core constitution excerpts, capabilities, repo map, workspace status
+decapod bootstrap --format json
+
 # Discover what this binary actually supports in this repo
 decapod capabilities --format json
 decapod data schema --deterministic
@@ -4,7 +4,7 @@ use crate::core::rpc::{Advisory, Attestation, Interlock, LoopSignal, Reconciliat
 use crate::core::workspace;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -14,6 +14,142 @@ pub const INTERLOCK_VERIFICATION_REQUIRED: &str = "verification_required";
 pub const INTERLOCK_STORE_BOUNDARY_VIOLATION: &str = "store_boundary_violation";
 pub const INTERLOCK_DECISION_REQUIRED: &str = "decision_required";
 
+pub const INTERLOCK_POLICY_REL_PATH: &str = ".decapod/policy/interlock_policy.json";
+pub const INTERLOCK_POLICY_SCHEMA_VERSION: &str = "1.0.0";
+
+const KNOWN_INTERLOCK_CODES: [&str; 4] = [
+    INTERLOCK_WORKSPACE_REQUIRED,
+    INTERLOCK_VERIFICATION_REQUIRED,
+    INTERLOCK_STORE_BOUNDARY_VIOLATION,
+    INTERLOCK_DECISION_REQUIRED,
+];
+
+/// Conditions the engine detects, in evaluation order (first match interlocks).
+pub const CONDITION_STORE_BOUNDARY: &str = "store_boundary";
+pub const CONDITION_MISSING_COMPLETION_PROOF: &str = "missing_completion_proof";
+pub const CONDITION_UNRESOLVED_DECISION: &str = "unresolved_decision";
+pub const CONDITION_UNISOLATED_WORKSPACE: &str = "unisolated_workspace";
+
+const INTERLOCK_CONDITIONS: [&str; 4] = [
+    CONDITION_STORE_BOUNDARY,
+    CONDITION_MISSING_COMPLETION_PROOF,
+    CONDITION_UNRESOLVED_DECISION,
+    CONDITION_UNISOLATED_WORKSPACE,
+];
+
+/// When a detected condition actually raises its interlock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterlockEnforcement {
+    /// Interlock whenever the condition is detected
+    Always,
+    /// Interlock only for high-risk ops; otherwise the condition is advisory
+    HighRisk,
+    /// Never interlock on this condition
+    Off,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InterlockRule {
+    pub code: String,
+    pub enforcement: InterlockEnforcement,
+    pub unblock_ops: Vec<String>,
+}
+
+/// Repo-level mapping of assurance conditions to interlock codes
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InterlockPolicy {
+    pub schema_version: String,
+    pub conditions: BTreeMap<String, InterlockRule>,
+}
+
+fn ops(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+pub fn default_interlock_policy() -> InterlockPolicy {
+    let mut conditions = BTreeMap::new();
+    conditions.insert(
+        CONDITION_STORE_BOUNDARY.to_string(),
+        InterlockRule {
+            code: INTERLOCK_STORE_BOUNDARY_VIOLATION.to_string(),
+            enforcement: InterlockEnforcement::Always,
+            unblock_ops: ops(&["todo.add", "todo.claim", "todo.done", "assurance.evaluate"]),
+        },
+    );
+    conditions.insert(
+        CONDITION_MISSING_COMPLETION_PROOF.to_string(),
+        InterlockRule {
+            code: INTERLOCK_VERIFICATION_REQUIRED.to_string(),
+            enforcement: InterlockEnforcement::Always,
+            unblock_ops: ops(&["qa.check", "validate", "assurance.evaluate"]),
+        },
+    );
+    conditions.insert(
+        CONDITION_UNRESOLVED_DECISION.to_string(),
+        InterlockRule {
+            code: INTERLOCK_DECISION_REQUIRED.to_string(),
+            enforcement: InterlockEnforcement::Always,
+            unblock_ops: ops(&[
+                "scaffold.next_question",
+                "mentor.obligations",
+                "assurance.evaluate",
+            ]),
+        },
+    );
+    conditions.insert(
+        CONDITION_UNISOLATED_WORKSPACE.to_string(),
+        InterlockRule {
+            code: INTERLOCK_WORKSPACE_REQUIRED.to_string(),
+            enforcement: InterlockEnforcement::Always,
+            unblock_ops: ops(&["workspace.ensure", "workspace.status"]),
+        },
+    );
+    InterlockPolicy {
+        schema_version: INTERLOCK_POLICY_SCHEMA_VERSION.to_string(),
+        conditions,
+    }
+}
+
+/// Load `.decapod/policy/interlock_policy.json`, layering it over the shipped default.
+///
+/// Conditions omitted from the file keep their default rule.
+pub fn load_interlock_policy(repo_root: &Path) -> Result<InterlockPolicy, DecapodError> {
+    let mut policy = default_interlock_policy();
+    let path = repo_root.join(INTERLOCK_POLICY_REL_PATH);
+    if !path.exists() {
+        return Ok(policy);
+    }
+    let raw = fs::read_to_string(&path).map_err(DecapodError::IoError)?;
+    let parsed: InterlockPolicy = serde_json::from_str(&raw)
+        .map_err(|e| DecapodError::ValidationError(format!("INTERLOCK_POLICY_INVALID: {}", e)))?;
+    if parsed.schema_version != INTERLOCK_POLICY_SCHEMA_VERSION {
+        return Err(DecapodError::ValidationError(format!(
+            "INTERLOCK_POLICY_SCHEMA_MISMATCH: actual={} expected={}",
+            parsed.schema_version, INTERLOCK_POLICY_SCHEMA_VERSION
+        )));
+    }
+    for (condition, rule) in parsed.conditions {
+        if !INTERLOCK_CONDITIONS.contains(&condition.as_str()) {
+            return Err(DecapodError::ValidationError(format!(
+                "INTERLOCK_POLICY_UNKNOWN_CONDITION: {} (expected one of {})",
+                condition,
+                INTERLOCK_CONDITIONS.join("|")
+            )));
+        }
+        if !KNOWN_INTERLOCK_CODES.contains(&rule.code.as_str()) {
+            return Err(DecapodError::ValidationError(format!(
+                "INTERLOCK_POLICY_UNKNOWN_CODE: {} for condition {} (expected one of {})",
+                rule.code,
+                condition,
+                KNOWN_INTERLOCK_CODES.join("|")
+            )));
+        }
+        policy.conditions.insert(condition, rule);
+    }
+    Ok(policy)
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AssurancePhase {
@@ -48,6 +184,62 @@ pub struct AssuranceEvaluateResult {
     pub attestation: Attestation,
 }
 
+/// Durable, write-once audit record of a single assurance decision.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AttestationRecord {
+    pub schema_version: String,
+    pub attestation_id: String,
+    /// Operation that was evaluated (not `assurance.evaluate` itself)
+    pub evaluated_op: String,
+    pub timestamp: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interlock_code: Option<String>,
+    pub outcome: String,
+    pub touched_paths: Vec<String>,
+    pub input_hash: String,
+    /// SHA-256 over the record with `content_hash` empty
+    pub content_hash: String,
+}
+
+/// Directory holding append-only attestation records.
+pub fn attestation_records_dir(repo_root: &Path) -> PathBuf {
+    repo_root
+        .join(".decapod")
+        .join("records")
+        .join("attestations")
+}
+
+/// List persisted attestation records, oldest first.
+pub fn list_attestation_records(repo_root: &Path) -> Result<Vec<AttestationRecord>, DecapodError> {
+    let dir = attestation_records_dir(repo_root);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut records = Vec::new();
+    for entry in fs::read_dir(&dir).map_err(DecapodError::IoError)? {
+        let path = entry.map_err(DecapodError::IoError)?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let raw = fs::read_to_string(&path).map_err(DecapodError::IoError)?;
+        let record: AttestationRecord = serde_json::from_str(&raw).map_err(|e| {
+            DecapodError::ValidationError(format!(
+                "Invalid attestation record {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        records.push(record);
+    }
+    // ULIDs sort chronologically; the timestamp keeps ordering stable across id schemes.
+    records.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.attestation_id.cmp(&b.attestation_id))
+    });
+    Ok(records)
+}
+
 pub struct AssuranceEngine {
     repo_root: PathBuf,
 }
@@ -110,6 +302,11 @@ impl AssuranceEngine {
             },
         );
 
+        let policy = load_interlock_policy(&self.repo_root)?;
+        let (interlock, advisories) =
+            self.resolve_interlock(input, &obligations, &workspace_status, &policy, high_risk);
+        recommended.splice(0..0, advisories);
+
         Self::dedupe_and_cap(&mut must);
         Self::dedupe_and_cap(&mut recommended);
 
@@ -127,7 +324,6 @@ impl AssuranceEngine {
             ],
         };
 
-        let interlock = self.resolve_interlock(input, &obligations, &workspace_status);
         let loop_signal = self.detect_loop_signal()?;
         let env_notes = vec![
             format!("repo_root={}", self.repo_root.display()),
@@ -163,79 +359,100 @@ impl AssuranceEngine {
         })
     }
 
+    /// The first enforced condition detected becomes the interlock. A
+    /// `high_risk` condition detected on a routine op is returned as a
+    /// recommended reconciliation instead of blocking.
     fn resolve_interlock(
         &self,
         input: &AssuranceEvaluateInput,
         obligations: &crate::core::mentor::Obligations,
         status: &workspace::WorkspaceStatus,
-    ) -> Option<Interlock> {
-        if self.has_store_boundary_violation(input) {
-            return Some(Interlock {
-                code: INTERLOCK_STORE_BOUNDARY_VIOLATION.to_string(),
-                message:
+        policy: &InterlockPolicy,
+        high_risk: bool,
+    ) -> (Option<Interlock>, Vec<ReconciliationPointer>) {
+        let mut advisories = Vec::new();
+        for condition in INTERLOCK_CONDITIONS {
+            let Some(rule) = policy.conditions.get(condition) else {
+                continue;
+            };
+            let advisory_only = match rule.enforcement {
+                InterlockEnforcement::Always => false,
+                InterlockEnforcement::HighRisk => !high_risk,
+                InterlockEnforcement::Off => continue,
+            };
+            let detected = match condition {
+                CONDITION_STORE_BOUNDARY => self.has_store_boundary_violation(input),
+                CONDITION_MISSING_COMPLETION_PROOF => {
+                    self.requires_completion_proof(input) && !self.has_completion_proofs(input)
+                }
+                CONDITION_UNRESOLVED_DECISION => {
+                    self.requires_mandatory_decision(input, obligations)
+                }
+                CONDITION_UNISOLATED_WORKSPACE => {
+                    self.requires_workspace_interlock(input)
+                        && (!status.can_work || status.git.is_protected)
+                }
+                _ => false,
+            };
+            if !detected {
+                continue;
+            }
+            let (message, evidence) = match condition {
+                CONDITION_STORE_BOUNDARY => (
                     "Direct .decapod/data mutation requested outside allowed control-plane ops"
                         .to_string(),
-                unblock_ops: vec![
-                    "todo.add".to_string(),
-                    "todo.claim".to_string(),
-                    "todo.done".to_string(),
-                    "assurance.evaluate".to_string(),
-                ],
-                evidence: Some(serde_json::json!({ "touched_paths": input.touched_paths })),
-            });
-        }
-
-        if self.requires_completion_proof(input) && !self.has_completion_proofs(input) {
-            return Some(Interlock {
-                code: INTERLOCK_VERIFICATION_REQUIRED.to_string(),
-                message: "Completion is blocked until required proofs have run".to_string(),
-                unblock_ops: vec![
-                    "qa.check".to_string(),
-                    "validate".to_string(),
-                    "assurance.evaluate".to_string(),
-                ],
-                evidence: Some(serde_json::json!({ "phase": input.phase })),
-            });
-        }
-
-        if self.requires_mandatory_decision(input, obligations) {
-            return Some(Interlock {
-                code: INTERLOCK_DECISION_REQUIRED.to_string(),
-                message: "Mandatory decision must be reconciled before proceeding".to_string(),
-                unblock_ops: vec![
-                    "scaffold.next_question".to_string(),
-                    "mentor.obligations".to_string(),
-                    "assurance.evaluate".to_string(),
-                ],
-                evidence: Some(serde_json::json!({
-                    "contradictions": obligations.contradictions,
-                    "touched_paths": input.touched_paths
-                })),
-            });
-        }
-
-        if self.requires_workspace_interlock(input) && (!status.can_work || status.git.is_protected)
-        {
-            return Some(Interlock {
-                code: INTERLOCK_WORKSPACE_REQUIRED.to_string(),
-                message: format!(
-                    "Meaningful op '{}' is blocked outside a valid isolated workspace",
-                    input.op
+                    serde_json::json!({ "touched_paths": input.touched_paths }),
                 ),
-                unblock_ops: vec![
-                    "workspace.ensure".to_string(),
-                    "workspace.status".to_string(),
-                ],
-                evidence: Some(serde_json::json!({
-                    "branch": status.git.current_branch,
-                    "is_protected": status.git.is_protected,
-                    "in_container": status.container.in_container,
-                    "docker_available": status.container.docker_available,
-                })),
-            });
+                CONDITION_MISSING_COMPLETION_PROOF => (
+                    "Completion is blocked until required proofs have run".to_string(),
+                    serde_json::json!({ "phase": input.phase }),
+                ),
+                CONDITION_UNRESOLVED_DECISION => (
+                    "Mandatory decision must be reconciled before proceeding".to_string(),
+                    serde_json::json!({
+                        "contradictions": obligations.contradictions,
+                        "touched_paths": input.touched_paths
+                    }),
+                ),
+                _ => (
+                    format!(
+                        "Meaningful op '{}' is blocked outside a valid isolated workspace",
+                        input.op
+                    ),
+                    serde_json::json!({
+                        "branch": status.git.current_branch,
+                        "is_protected": status.git.is_protected,
+                        "in_container": status.container.in_container,
+                        "docker_available": status.container.docker_available,
+                    }),
+                ),
+            };
+            if advisory_only {
+                advisories.push(ReconciliationPointer {
+                    kind: "interlock".to_string(),
+                    r#ref: condition.to_string(),
+                    title: message,
+                    why_short: format!("Advisory: '{}' interlocks only high-risk ops", rule.code),
+                    evidence: crate::core::rpc::EvidenceRef {
+                        source: "interlock_policy".to_string(),
+                        id: rule.code.clone(),
+                        hash: None,
+                    },
+                });
+                continue;
+            }
+            return (
+                Some(Interlock {
+                    code: rule.code.clone(),
+                    message,
+                    unblock_ops: rule.unblock_ops.clone(),
+                    evidence: Some(evidence),
+                }),
+                advisories,
+            );
         }
 
-        None
+        (None, advisories)
     }
 
     fn has_store_boundary_violation(&self, input: &AssuranceEvaluateInput) -> bool {
@@ -255,7 +472,11 @@ impl AssuranceEngine {
     fn requires_workspace_interlock(&self, input: &AssuranceEvaluateInput) -> bool {
         !matches!(
             input.op.as_str(),
-            "agent.init" | "workspace.status" | "assurance.evaluate" | "mentor.obligations"
+            "agent.init"
+                | "workspace.status"
+                | "workspace.list"
+                | "assurance.evaluate"
+                | "mentor.obligations"
         )
     }
 
@@ -397,9 +618,47 @@ impl AssuranceEngine {
             .map_err(DecapodError::IoError)?;
         file.write_all(b"\n").map_err(DecapodError::IoError)?;
 
+        self.persist_attestation_record(input, &attestation)?;
+
         Ok(attestation)
     }
 
+    fn persist_attestation_record(
+        &self,
+        input: &AssuranceEvaluateInput,
+        attestation: &Attestation,
+    ) -> Result<(), DecapodError> {
+        let mut record = AttestationRecord {
+            schema_version: "1.0.0".to_string(),
+            attestation_id: attestation.id.clone(),
+            evaluated_op: input.op.clone(),
+            timestamp: attestation.timestamp.clone(),
+            interlock_code: attestation.interlock_code.clone(),
+            outcome: attestation.outcome.clone(),
+            touched_paths: attestation.touched_paths.clone(),
+            input_hash: attestation.input_hash.clone(),
+            content_hash: String::new(),
+        };
+        let canonical = serde_json::to_vec(&record).map_err(|e| {
+            DecapodError::ValidationError(format!("Failed to encode attestation record: {e}"))
+        })?;
+        record.content_hash = format!("{:x}", Sha256::digest(&canonical));
+
+        let dir = attestation_records_dir(&self.repo_root);
+        fs::create_dir_all(&dir).map_err(DecapodError::IoError)?;
+        let pretty = serde_json::to_vec_pretty(&record).map_err(|e| {
+            DecapodError::ValidationError(format!("Failed to serialize attestation record: {e}"))
+        })?;
+        // create_new keeps records append-only: an id is never rewritten.
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(dir.join(format!("{}.json", record.attestation_id)))
+            .map_err(DecapodError::IoError)?;
+        file.write_all(&pretty).map_err(DecapodError::IoError)?;
+        Ok(())
+    }
+
     fn obligation_to_pointer(obligation: &Obligation) -> ReconciliationPointer {
         let kind = match obligation.kind {
             ObligationKind::DocAnchor => "doc_anchor",
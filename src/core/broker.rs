@@ -361,6 +361,111 @@ pub struct ReplayReport {
     pub total_events: usize,
 }
 
+/// Outcome of [`replay_to`]: where the target event was found and how much
+/// of its log was applied.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PointInTimeReplay {
+    pub event_id: String,
+    pub event_ts: String,
+    pub subsystem: String,
+    pub events_applied: usize,
+    pub into: String,
+}
+
+/// Event-sourced subsystems that can be rebuilt from their JSONL log.
+const REPLAYABLE_LOGS: &[(&str, &str)] = &[
+    ("todo", crate::core::schemas::TODO_EVENTS_NAME),
+    ("federation", crate::core::schemas::FEDERATION_EVENTS_NAME),
+];
+
+/// Reconstruct the state of the subsystem whose event log contains
+/// `event_id`, as of that event (inclusive), inside `into`.
+///
+/// The log prefix is copied into `into` and rebuilt there with the
+/// subsystem's own rebuild path; the live store under `store_root` is only
+/// read. `into` must be absent or empty so nothing is clobbered.
+pub fn replay_to(
+    store_root: &Path,
+    event_id: &str,
+    into: &Path,
+) -> Result<PointInTimeReplay, error::DecapodError> {
+    use std::io::BufRead;
+
+    if into.exists() {
+        let occupied = std::fs::read_dir(into)
+            .map_err(error::DecapodError::IoError)?
+            .next()
+            .is_some();
+        if occupied {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Replay target {} must be empty or absent",
+                into.display()
+            )));
+        }
+    }
+
+    for (subsystem, log_name) in REPLAYABLE_LOGS {
+        let log_path = store_root.join(log_name);
+        if !log_path.is_file() {
+            continue;
+        }
+        let f = std::fs::File::open(&log_path).map_err(error::DecapodError::IoError)?;
+        let mut prefix = Vec::new();
+        let mut event_ts = None;
+        for line in std::io::BufReader::new(f).lines() {
+            let line = line.map_err(error::DecapodError::IoError)?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let ev: JsonValue = serde_json::from_str(&line).map_err(|e| {
+                error::DecapodError::ValidationError(format!(
+                    "Invalid event in {}: {}",
+                    log_name, e
+                ))
+            })?;
+            prefix.push(line);
+            if ev.get("event_id").and_then(|v| v.as_str()) == Some(event_id) {
+                event_ts = Some(
+                    ev.get("ts")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                );
+                break;
+            }
+        }
+        let Some(event_ts) = event_ts else {
+            continue;
+        };
+
+        std::fs::create_dir_all(into).map_err(error::DecapodError::IoError)?;
+        let mut body = prefix.join("\n");
+        body.push('\n');
+        std::fs::write(into.join(log_name), body).map_err(error::DecapodError::IoError)?;
+        match *subsystem {
+            "todo" => {
+                crate::core::todo::rebuild_from_events(into)?;
+            }
+            _ => {
+                crate::plugins::federation::rebuild_from_events(into)?;
+            }
+        }
+
+        return Ok(PointInTimeReplay {
+            event_id: event_id.to_string(),
+            event_ts,
+            subsystem: subsystem.to_string(),
+            events_applied: prefix.len(),
+            into: into.to_string_lossy().to_string(),
+        });
+    }
+
+    Err(error::DecapodError::NotFound(format!(
+        "Event '{}' not found in any replayable event log",
+        event_id
+    )))
+}
+
 fn log_write_event(audit_path: &Path, op: &str, db_path: &Path) -> Result<(), error::DecapodError> {
     use std::fs::OpenOptions;
     use std::io::Write;
@@ -408,7 +513,8 @@ pub fn schema() -> serde_json::Value {
         "version": "0.1.0",
         "description": "State mutation broker (The Thin Waist)",
         "commands": [
-            { "name": "audit", "description": "Show the mutation audit log" }
+            { "name": "audit", "description": "Show the mutation audit log" },
+            { "name": "replay", "description": "Rebuild todo or federation state up to and including an event into a throwaway directory" }
         ],
         "envelope": {
             "schema_version": "1.0.0",
@@ -66,7 +66,7 @@ pub fn resolve_snapshot(
     };
 
     let mut common_ops: Vec<_> = ops_count.into_iter().collect();
-    common_ops.sort_by(|a, b| b.1.cmp(&a.1));
+    common_ops.sort_by_key(|b| std::cmp::Reverse(b.1));
     let common_ops = common_ops.into_iter().take(5).map(|(op, _)| op).collect();
 
     let risk_profile = if total < 5 {
@@ -114,6 +114,8 @@ mod tests {
                 op: "todo.add".to_string(),
                 request: json!({}),
                 response: json!({"success": true}),
+                duration_ms: None,
+                status: None,
             },
             TraceEvent {
                 trace_id: "t2".to_string(),
@@ -122,6 +124,8 @@ mod tests {
                 op: "todo.claim".to_string(),
                 request: json!({}),
                 response: json!({"success": true}),
+                duration_ms: None,
+                status: None,
             },
             TraceEvent {
                 trace_id: "t3".to_string(),
@@ -130,6 +134,8 @@ mod tests {
                 op: "todo.done".to_string(),
                 request: json!({}),
                 response: json!({"success": false}),
+                duration_ms: None,
+                status: None,
             },
             TraceEvent {
                 trace_id: "t4".to_string(),
@@ -138,6 +144,8 @@ mod tests {
                 op: "todo.add".to_string(),
                 request: json!({}),
                 response: json!({"success": true}),
+                duration_ms: None,
+                status: None,
             },
             TraceEvent {
                 trace_id: "t5".to_string(),
@@ -146,6 +154,8 @@ mod tests {
                 op: "todo.add".to_string(),
                 request: json!({}),
                 response: json!({"success": true}),
+                duration_ms: None,
+                status: None,
             },
             TraceEvent {
                 trace_id: "t6".to_string(),
@@ -154,6 +164,8 @@ mod tests {
                 op: "todo.add".to_string(),
                 request: json!({}),
                 response: json!({"success": true}),
+                duration_ms: None,
+                status: None,
             },
         ];
 
@@ -453,6 +453,7 @@ pub fn initialize_knowledge_db(root: &Path) -> Result<(), error::DecapodError> {
         conn.execute(schemas::KNOWLEDGE_DB_INDEX_CREATED, [])?;
         conn.execute(schemas::KNOWLEDGE_DB_INDEX_MERGE_KEY, [])?;
         conn.execute(schemas::KNOWLEDGE_DB_INDEX_ACTIVE_MERGE_SCOPE, [])?;
+        conn.execute(schemas::KNOWLEDGE_DB_INDEX_NAMESPACE, [])?;
         Ok(())
     })?;
 
@@ -486,6 +487,7 @@ fn ensure_knowledge_columns(conn: &rusqlite::Connection) -> Result<(), rusqlite:
     add_col("supersedes_id", "TEXT", "NULL")?;
     add_col("ttl_policy", "TEXT NOT NULL", "'persistent'")?;
     add_col("expires_ts", "TEXT", "NULL")?;
+    add_col("namespace", "TEXT NOT NULL", "'general'")?;
     Ok(())
 }
 
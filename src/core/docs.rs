@@ -29,8 +29,187 @@ pub struct Bindings {
     pub paths: std::collections::HashMap<String, String>,
     pub tags: std::collections::HashMap<String, String>,
     pub mandates: std::collections::HashMap<String, Vec<String>>, // op -> [mandate_ids]
+    #[serde(default)]
+    pub gates: std::collections::HashMap<String, String>, // validate gate -> doc ref
 }
 
+/// Constitution fragment each `decapod validate` gate enforces.
+const GATE_BINDINGS: &[(&str, &str)] = &[
+    (
+        "validate_user_store_blank_slate",
+        "interfaces/STORE_MODEL.md#1. Stores",
+    ),
+    (
+        "validate_repo_store_dogfood",
+        "interfaces/STORE_MODEL.md#4. Guarantees (Contract)",
+    ),
+    ("validate_repo_map", "core/DECAPOD.md#Subsystems"),
+    ("validate_no_legacy_namespaces", "core/DEPRECATION.md"),
+    (
+        "validate_embedded_self_contained",
+        "interfaces/DOC_RULES.md#6. No Duplicate Authority",
+    ),
+    (
+        "validate_docs_templates_bucket",
+        "interfaces/DOC_RULES.md#2. Layers (Meaning)",
+    ),
+    (
+        "validate_entrypoint_invariants",
+        "core/DECAPOD.md#For Agents: Quick Start",
+    ),
+    (
+        "validate_interface_contract_bootstrap",
+        "core/INTERFACES.md",
+    ),
+    ("validate_health_purity", "plugins/HEALTH.md#Health States"),
+    (
+        "validate_project_scoped_state",
+        "interfaces/STORE_MODEL.md#5. Red Lines (Unacceptable Behavior)",
+    ),
+    (
+        "validate_generated_artifact_whitelist",
+        "interfaces/STORE_MODEL.md#2. Assets (What We Protect)",
+    ),
+    (
+        "validate_project_config_toml",
+        "specs/SYSTEM.md#7. Project & Capability Definitions",
+    ),
+    (
+        "validate_project_specs_docs",
+        "specs/SYSTEM.md#7. Project & Capability Definitions",
+    ),
+    (
+        "validate_spec_drift",
+        "interfaces/DOC_RULES.md#4. Subsystem Truth (Single Source)",
+    ),
+    (
+        "validate_machine_contract",
+        "interfaces/CONTROL_PLANE.md#3.5 Subsystem Truth (No Phantom Features)",
+    ),
+    (
+        "validate_workunit_manifests_if_present",
+        "interfaces/PLAN_GOVERNED_EXECUTION.md#6. Proof Semantics for TODO Completion",
+    ),
+    (
+        "validate_context_capsule_policy_contract",
+        "interfaces/AGENT_CONTEXT_PACK.md#2.2 Policy-Bound Capsule Issuance",
+    ),
+    (
+        "validate_context_capsules_if_present",
+        "interfaces/AGENT_CONTEXT_PACK.md#2.1 Deterministic Context Capsule Query",
+    ),
+    (
+        "validate_knowledge_promotions_if_present",
+        "interfaces/KNOWLEDGE_STORE.md#4. Validation Gates (Promotion-Binding)",
+    ),
+    (
+        "validate_skill_cards_if_present",
+        "interfaces/KNOWLEDGE_STORE.md#7. Guardrails (One-Line Constraints)",
+    ),
+    (
+        "validate_skill_resolutions_if_present",
+        "interfaces/KNOWLEDGE_STORE.md#7. Guardrails (One-Line Constraints)",
+    ),
+    (
+        "validate_internalization_artifacts_if_present",
+        "interfaces/AGENT_CONTEXT_PACK.md#1. Canonical Layout",
+    ),
+    (
+        "validate_eval_gate_if_required",
+        "interfaces/TESTING.md#1. Verification Claim Rule",
+    ),
+    (
+        "validate_schema_determinism",
+        "interfaces/CONTROL_PLANE.md#3. Interoperability: The Thin Waist",
+    ),
+    (
+        "validate_database_schema_versions",
+        "interfaces/STORE_MODEL.md#4. Guarantees (Contract)",
+    ),
+    (
+        "validate_health_cache_integrity",
+        "plugins/HEALTH.md#Storage",
+    ),
+    ("validate_risk_map", "plugins/POLICY.md"),
+    ("validate_risk_map_violations", "plugins/POLICY.md"),
+    (
+        "validate_policy_integrity",
+        "plugins/POLICY.md#Human-In-The-Loop (HITL) Overrides",
+    ),
+    (
+        "validate_knowledge_integrity",
+        "interfaces/KNOWLEDGE_STORE.md#2. Spec",
+    ),
+    (
+        "validate_lineage_hard_gate",
+        "plugins/FEDERATION.md#9. Validation Gates",
+    ),
+    (
+        "validate_repomap_determinism",
+        "interfaces/CONTROL_PLANE.md#3. Interoperability: The Thin Waist",
+    ),
+    ("validate_watcher_audit", "plugins/WATCHER.md"),
+    ("validate_watcher_purity", "plugins/WATCHER.md"),
+    ("validate_archive_integrity", "plugins/ARCHIVE.md"),
+    (
+        "validate_control_plane_contract",
+        "interfaces/CONTROL_PLANE.md#5. Concurrency Pattern: Request, Don’t Poke",
+    ),
+    (
+        "validate_canon_mutation",
+        "interfaces/CONTROL_PLANE.md#1. The Contract: Agents Talk to Decapod, Not the Internals",
+    ),
+    (
+        "validate_heartbeat_invocation_gate",
+        "interfaces/CONTROL_PLANE.md#3.6 Invocation Heartbeat",
+    ),
+    (
+        "validate_markdown_primitives_roundtrip_gate",
+        "interfaces/DOC_RULES.md#1. Canonical Doc Header (Required)",
+    ),
+    (
+        "validate_federation_gates",
+        "plugins/FEDERATION.md#9. Validation Gates",
+    ),
+    (
+        "validate_git_workspace_context",
+        "core/DECAPOD.md#Worktree + On-Demand Sandbox",
+    ),
+    (
+        "validate_git_protected_branch",
+        "core/DECAPOD.md#Workspace Rules (Non-Negotiable)",
+    ),
+    (
+        "validate_tooling_gate",
+        "methodology/CI_CD.md#2. CI Baseline (Per PR)",
+    ),
+    (
+        "validate_state_commit_gate",
+        "interfaces/TESTING.md#5. Proof Surfaces in Decapod",
+    ),
+    (
+        "validate_obligations",
+        "interfaces/PLAN_GOVERNED_EXECUTION.md#1. Contract",
+    ),
+    ("validate_gatekeeper_gate", "specs/SECURITY.md"),
+    (
+        "validate_coplayer_policy_tightening",
+        "interfaces/AGENT_CONTEXT_PACK.md#6. Security Scoping",
+    ),
+    (
+        "validate_lcm_immutability",
+        "interfaces/AGENT_CONTEXT_PACK.md#5. Append-Only Log Contract",
+    ),
+    (
+        "validate_lcm_rebuild_gate",
+        "interfaces/AGENT_CONTEXT_PACK.md#5. Append-Only Log Contract",
+    ),
+    (
+        "validate_plan_governed_execution_gate",
+        "interfaces/PLAN_GOVERNED_EXECUTION.md#2. Governed Artifacts",
+    ),
+];
+
 fn truncate_chars(input: &str, max_chars: usize) -> String {
     let mut chars = input.chars();
     let truncated: String = chars.by_ref().take(max_chars).collect();
@@ -73,13 +252,11 @@ pub fn resolve_scoped_fragments(
     }
 
     for touched in touched_paths {
-        for (prefix, doc_ref) in &bindings.paths {
-            if touched.contains(prefix) {
-                let (path, anchor) = split_doc_ref(doc_ref);
-                *path_boosts.entry(path.to_string()).or_insert(0) += 25;
-                if let Some(a) = anchor {
-                    preferred_anchors.insert(path.to_string(), a.to_string());
-                }
+        if let Some(doc_ref) = match_path_binding(&bindings.paths, touched) {
+            let (path, anchor) = split_doc_ref(doc_ref);
+            *path_boosts.entry(path.to_string()).or_insert(0) += 25;
+            if let Some(a) = anchor {
+                preferred_anchors.insert(path.to_string(), a.to_string());
             }
         }
     }
@@ -160,6 +337,14 @@ pub fn get_bindings(_repo_root: &Path) -> Bindings {
         "workspace.status".to_string(),
         "core/DECAPOD.md#workspaces".to_string(),
     );
+    ops.insert(
+        "workspace.list".to_string(),
+        "core/DECAPOD.md#workspaces".to_string(),
+    );
+    ops.insert(
+        "workspace.prune".to_string(),
+        "core/DECAPOD.md#workspaces".to_string(),
+    );
     ops.insert(
         "validate".to_string(),
         "core/DECAPOD.md#validation".to_string(),
@@ -185,11 +370,17 @@ pub fn get_bindings(_repo_root: &Path) -> Bindings {
         vec!["no-master".to_string(), "validate-before-done".to_string()],
     );
 
+    let gates = GATE_BINDINGS
+        .iter()
+        .map(|(gate, doc_ref)| (gate.to_string(), doc_ref.to_string()))
+        .collect();
+
     Bindings {
         ops,
         paths,
         tags,
         mandates,
+        gates,
     }
 }
 
@@ -341,7 +532,72 @@ fn extract_section(content: &str, anchor: &str) -> Option<(String, String)> {
     }
 }
 
-fn split_doc_ref(doc_ref: &str) -> (&str, Option<&str>) {
+/// Resolve a touched path against `Bindings::paths`. Separators are
+/// normalized and a binding matches when its components form a contiguous run
+/// of the path's components (`*` wildcards within a component; a final
+/// binding component also matches a file stem, so `rpc` binds `src/core/rpc.rs`).
+/// The binding with the most components wins, so `src/plugins/federation`
+/// beats `src/plugins`, and `rpc` no longer matches `grpcx/`.
+pub fn match_path_binding<'a>(
+    paths: &'a HashMap<String, String>,
+    touched: &str,
+) -> Option<&'a str> {
+    let touched = path_components(touched);
+    paths
+        .iter()
+        .filter_map(|(binding, doc_ref)| {
+            let pattern = path_components(binding);
+            binding_matches(&pattern, &touched).then_some((pattern.len(), binding, doc_ref))
+        })
+        .max_by(|(la, ba, _), (lb, bb, _)| la.cmp(lb).then_with(|| bb.cmp(ba)))
+        .map(|(_, _, doc_ref)| doc_ref.as_str())
+}
+
+fn path_components(path: &str) -> Vec<&str> {
+    path.split(['/', '\\'])
+        .filter(|c| !c.is_empty() && *c != ".")
+        .collect()
+}
+
+fn binding_matches(pattern: &[&str], touched: &[&str]) -> bool {
+    if pattern.is_empty() || pattern.len() > touched.len() {
+        return false;
+    }
+    (0..=touched.len() - pattern.len()).any(|start| {
+        pattern.iter().enumerate().all(|(i, pat)| {
+            let comp = touched[start + i];
+            let is_last = i + 1 == pattern.len() && start + i + 1 == touched.len();
+            component_matches(pat, comp)
+                || (is_last
+                    && comp
+                        .rsplit_once('.')
+                        .is_some_and(|(stem, _)| component_matches(pat, stem)))
+        })
+    })
+}
+
+/// `*` matches any run of characters within one component.
+fn component_matches(pattern: &str, comp: &str) -> bool {
+    let Some((head, rest)) = pattern.split_once('*') else {
+        return pattern == comp;
+    };
+    let Some(mut remaining) = comp.strip_prefix(head) else {
+        return false;
+    };
+    let mut parts = rest.split('*').peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return remaining.len() >= part.len() && remaining.ends_with(part);
+        }
+        match remaining.find(part) {
+            Some(idx) => remaining = &remaining[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+pub fn split_doc_ref(doc_ref: &str) -> (&str, Option<&str>) {
     let parts: Vec<&str> = doc_ref.split('#').collect();
     (parts[0], parts.get(1).copied())
 }
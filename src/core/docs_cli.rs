@@ -6,6 +6,7 @@
 use crate::core::{assets, docs, error};
 use clap::Subcommand;
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 /// CLI structure for `decapod docs` command
@@ -40,7 +41,11 @@ pub enum DocsCommand {
         source: DocumentSource,
     },
     /// Dump all embedded constitution for agentic ingestion.
-    Ingest,
+    Ingest {
+        /// Ingest only this doc (repeatable), e.g. `core/DECAPOD.md`
+        #[clap(long = "path")]
+        path: Vec<String>,
+    },
     /// Return scoped constitution fragments relevant to a concrete query.
     Search {
         /// Problem/query text to scope against constitution docs.
@@ -73,6 +78,31 @@ pub enum DocsCommand {
 #[derive(Debug, Default)]
 pub struct DocsRunResult {
     pub ingested_core_constitution: bool,
+    /// Doc path -> sha256 of the merged content that was emitted.
+    pub ingested_docs: BTreeMap<String, String>,
+}
+
+/// Core docs an agent must have ingested before mutating operations.
+pub fn required_core_docs() -> Vec<String> {
+    assets::list_docs()
+        .into_iter()
+        .filter(|p| p.starts_with("core/") && p.ends_with(".md"))
+        .collect()
+}
+
+/// Accept `core/X.md`, `constitution/core/X.md`, or `embedded/core/X.md`.
+pub fn normalize_doc_path(raw: &str) -> String {
+    let trimmed = raw.trim().trim_start_matches("./");
+    trimmed
+        .strip_prefix("constitution/")
+        .or_else(|| trimmed.strip_prefix("embedded/"))
+        .unwrap_or(trimmed)
+        .to_string()
+}
+
+/// Hash of a doc as ingestion emits it (embedded base plus project override).
+pub fn merged_doc_hash(repo_root: &Path, relative_path: &str) -> Option<String> {
+    assets::get_merged_doc(repo_root, relative_path).map(|c| format!("{:x}", Sha256::digest(c)))
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -187,17 +217,40 @@ pub fn run_docs_cli(cli: DocsCli) -> Result<DocsRunResult, error::DecapodError>
                 }
             }
         }
-        DocsCommand::Ingest => {
-            let docs = assets::list_docs();
+        DocsCommand::Ingest { path } => {
             // Determine repo root for override merging
             let current_dir = std::env::current_dir().map_err(error::DecapodError::IoError)?;
             let repo_root = find_repo_root(&current_dir)?;
+            let all_docs = assets::list_docs();
+            let selective = !path.is_empty();
+            let docs = if selective {
+                let mut chosen = Vec::new();
+                for raw in &path {
+                    let rel = normalize_doc_path(raw);
+                    if !all_docs.contains(&rel) {
+                        return Err(error::DecapodError::NotFound(format!(
+                            "Document not found: {}",
+                            raw
+                        )));
+                    }
+                    if !chosen.contains(&rel) {
+                        chosen.push(rel);
+                    }
+                }
+                chosen
+            } else {
+                all_docs
+            };
             let mut ingested_core_constitution = false;
+            let mut ingested_docs = BTreeMap::new();
 
             for doc_path in docs {
                 // Convert embedded path to relative path for override merging
                 let relative_path = doc_path.strip_prefix("embedded/").unwrap_or(&doc_path);
-                if relative_path.starts_with("core/") && relative_path.ends_with(".md") {
+                if !selective
+                    && relative_path.starts_with("core/")
+                    && relative_path.ends_with(".md")
+                {
                     ingested_core_constitution = true;
                 }
 
@@ -205,10 +258,15 @@ pub fn run_docs_cli(cli: DocsCli) -> Result<DocsRunResult, error::DecapodError>
                     println!("--- BEGIN {} ---", doc_path);
                     println!("{}", content);
                     println!("--- END {} ---", doc_path);
+                    ingested_docs.insert(
+                        relative_path.to_string(),
+                        format!("{:x}", Sha256::digest(&content)),
+                    );
                 }
             }
             Ok(DocsRunResult {
                 ingested_core_constitution,
+                ingested_docs,
             })
         }
         DocsCommand::Search {
@@ -13,6 +13,7 @@ pub enum ExternalCapability {
     VcsWrite,
     ProofExec,
     VerificationExec,
+    WorkflowExec,
     SystemInspect,
 }
 
@@ -23,9 +24,38 @@ impl ExternalCapability {
             ExternalCapability::VcsWrite => "vcs_write",
             ExternalCapability::ProofExec => "proof_exec",
             ExternalCapability::VerificationExec => "verification_exec",
+            ExternalCapability::WorkflowExec => "workflow_exec",
             ExternalCapability::SystemInspect => "system_inspect",
         }
     }
+
+    /// Capabilities whose commands change repository state.
+    fn is_mutating(self) -> bool {
+        matches!(self, ExternalCapability::VcsWrite)
+    }
+}
+
+/// Set to log mutating commands instead of running them.
+pub const DRY_RUN_ENV: &str = "DECAPOD_EXTERNAL_ACTION_DRY_RUN";
+
+/// True when [`DRY_RUN_ENV`] is set to `1`, `true`, `yes`, or `on`.
+pub fn dry_run_enabled() -> bool {
+    match std::env::var(DRY_RUN_ENV) {
+        Ok(v) => matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"),
+        Err(_) => false,
+    }
+}
+
+fn synthetic_success() -> Output {
+    #[cfg(unix)]
+    use std::os::unix::process::ExitStatusExt;
+    #[cfg(windows)]
+    use std::os::windows::process::ExitStatusExt;
+    Output {
+        status: std::process::ExitStatus::from_raw(0),
+        stdout: Vec::new(),
+        stderr: Vec::new(),
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -34,9 +64,39 @@ struct ExternalActionRule {
     allowed_bins: Vec<String>,
 }
 
+/// Project-level allow/deny rule evaluated before any external command runs.
+///
+/// `capability` and `command` accept `*`; `args_prefix` matches the leading
+/// arguments (e.g. `["push", "origin"]`). The first matching rule wins.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct ExternalActionPolicyRule {
+    id: String,
+    effect: PolicyEffect,
+    #[serde(default = "wildcard")]
+    capability: String,
+    #[serde(default = "wildcard")]
+    command: String,
+    #[serde(default)]
+    args_prefix: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum PolicyEffect {
+    Allow,
+    Deny,
+}
+
+fn wildcard() -> String {
+    "*".to_string()
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct ExternalActionConfig {
+    #[serde(default)]
     rules: Vec<ExternalActionRule>,
+    #[serde(default)]
+    policy: Vec<ExternalActionPolicyRule>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -50,6 +110,10 @@ struct ExternalActionEvent {
     cwd: String,
     status: String,
     exit_code: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rule: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
 }
 
 fn now_iso() -> String {
@@ -81,11 +145,20 @@ fn default_config() -> ExternalActionConfig {
                 capability: "verification_exec".to_string(),
                 allowed_bins: vec!["decapod".to_string()],
             },
+            ExternalActionRule {
+                capability: "workflow_exec".to_string(),
+                allowed_bins: vec![
+                    "cargo".to_string(),
+                    "decapod".to_string(),
+                    "git".to_string(),
+                ],
+            },
             ExternalActionRule {
                 capability: "system_inspect".to_string(),
                 allowed_bins: vec!["lsof".to_string()],
             },
         ],
+        policy: Vec::new(),
     }
 }
 
@@ -101,7 +174,32 @@ fn maybe_load_config(store_root: &Path) -> ExternalActionConfig {
     let Ok(content) = std::fs::read_to_string(path) else {
         return default_config();
     };
-    serde_json::from_str(&content).unwrap_or_else(|_| default_config())
+    let Ok(mut config) = serde_json::from_str::<ExternalActionConfig>(&content) else {
+        return default_config();
+    };
+    // A policy-only file keeps the built-in capability allowlists.
+    if config.rules.is_empty() {
+        config.rules = default_config().rules;
+    }
+    config
+}
+
+fn matching_policy_rule<'a>(
+    config: &'a ExternalActionConfig,
+    capability: ExternalCapability,
+    bin: &str,
+    args: &[&str],
+) -> Option<&'a ExternalActionPolicyRule> {
+    config.policy.iter().find(|rule| {
+        (rule.capability == "*" || rule.capability == capability.as_str())
+            && (rule.command == "*" || rule.command == bin)
+            && rule.args_prefix.len() <= args.len()
+            && rule
+                .args_prefix
+                .iter()
+                .zip(args)
+                .all(|(want, got)| want == got)
+    })
 }
 
 fn allowed_for_capability(
@@ -179,44 +277,134 @@ pub fn execute(
     command: &str,
     args: &[&str],
     cwd: &Path,
+) -> Result<Output, error::DecapodError> {
+    execute_with(
+        store_root,
+        capability,
+        scope,
+        command,
+        args,
+        cwd,
+        dry_run_enabled(),
+    )
+}
+
+/// [`execute`] with dry-run chosen by the caller instead of [`DRY_RUN_ENV`].
+pub fn execute_with(
+    store_root: &Path,
+    capability: ExternalCapability,
+    scope: &str,
+    command: &str,
+    args: &[&str],
+    cwd: &Path,
+    dry_run: bool,
 ) -> Result<Output, error::DecapodError> {
     let config = maybe_load_config(store_root);
-    let allowed_bins = allowed_for_capability(&config, capability);
     let bin = command_bin(command);
+    let event = |status: &str, exit_code: Option<i32>, rule: Option<&str>, reason: Option<&str>| {
+        ExternalActionEvent {
+            ts: now_iso(),
+            event_id: crate::core::ulid::new_ulid(),
+            capability: capability.as_str().to_string(),
+            scope: scope.to_string(),
+            command: command.to_string(),
+            args: args.iter().map(|s| s.to_string()).collect(),
+            cwd: cwd.to_string_lossy().to_string(),
+            status: status.to_string(),
+            exit_code,
+            rule: rule.map(str::to_string),
+            reason: reason.map(str::to_string),
+        }
+    };
+    let deny = |rule: Option<&str>, message: String| {
+        let _ = log_event(store_root, &event("denied", None, rule, Some(&message)));
+        Err(error::DecapodError::ValidationError(message))
+    };
+
+    let policy_rule = matching_policy_rule(&config, capability, &bin, args);
+    if let Some(rule) = policy_rule
+        && rule.effect == PolicyEffect::Deny
+    {
+        return deny(
+            Some(&rule.id),
+            format!(
+                "External action denied by policy rule '{}': capability '{}' command '{} {}'",
+                rule.id,
+                capability.as_str(),
+                bin,
+                args.join(" ")
+            ),
+        );
+    }
+
+    let allowed_bins = allowed_for_capability(&config, capability);
     let is_allowed = allowed_bins.iter().any(|b| b == &bin)
         || (capability == ExternalCapability::VerificationExec && bin.starts_with("decapod"));
     if !is_allowed {
-        return Err(error::DecapodError::ValidationError(format!(
-            "External action denied: capability '{}' does not allow binary '{}'",
-            capability.as_str(),
-            bin
-        )));
+        return deny(
+            None,
+            format!(
+                "External action denied: capability '{}' does not allow binary '{}'",
+                capability.as_str(),
+                bin
+            ),
+        );
     }
 
-    require_external_approval(store_root, capability, scope)?;
+    if let Err(e) = require_external_approval(store_root, capability, scope) {
+        let _ = log_event(
+            store_root,
+            &event("denied", None, None, Some(&e.to_string())),
+        );
+        return Err(e);
+    }
 
-    let output = Command::new(command)
-        .args(args)
-        .current_dir(cwd)
-        .output()
-        .map_err(error::DecapodError::IoError)?;
+    // Dry-run still runs every check above so the audit log shows exactly
+    // what would have executed; read-only capabilities run for real because
+    // callers depend on their output.
+    if capability.is_mutating() && dry_run {
+        let _ = log_event(
+            store_root,
+            &event(
+                "dry_run",
+                Some(0),
+                policy_rule.map(|r| r.id.as_str()),
+                Some("not executed: dry run"),
+            ),
+        );
+        return Ok(synthetic_success());
+    }
 
-    let event = ExternalActionEvent {
-        ts: now_iso(),
-        event_id: crate::core::ulid::new_ulid(),
-        capability: capability.as_str().to_string(),
-        scope: scope.to_string(),
-        command: command.to_string(),
-        args: args.iter().map(|s| s.to_string()).collect(),
-        cwd: cwd.to_string_lossy().to_string(),
-        status: if output.status.success() {
-            "success".to_string()
-        } else {
-            "error".to_string()
-        },
-        exit_code: output.status.code(),
+    let output = match Command::new(command).args(args).current_dir(cwd).output() {
+        Ok(output) => output,
+        Err(e) => {
+            let _ = log_event(
+                store_root,
+                &event(
+                    "error",
+                    None,
+                    policy_rule.map(|r| r.id.as_str()),
+                    Some(&e.to_string()),
+                ),
+            );
+            return Err(error::DecapodError::IoError(e));
+        }
     };
-    let _ = log_event(store_root, &event);
+
+    let status = if output.status.success() {
+        "success"
+    } else {
+        "error"
+    };
+    let _ = log_event(
+        store_root,
+        &event(
+            status,
+            output.status.code(),
+            policy_rule.map(|r| r.id.as_str()),
+            None,
+        ),
+    );
 
     Ok(output)
 }
@@ -231,9 +419,20 @@ pub fn schema() -> serde_json::Value {
             "vcs_write",
             "proof_exec",
             "verification_exec",
+            "workflow_exec",
             "system_inspect"
         ],
         "config": ".decapod/EXTERNAL_ACTIONS.json",
+        "policy": {
+            "fields": ["id", "effect", "capability", "command", "args_prefix"],
+            "effects": ["allow", "deny"],
+            "matching": "first matching rule wins; '*' matches any capability/command"
+        },
+        "dry_run": {
+            "env": "DECAPOD_EXTERNAL_ACTION_DRY_RUN",
+            "applies_to": ["vcs_write"],
+            "behavior": "log intended command with status 'dry_run' and return empty successful output"
+        },
         "storage": ["external_actions.events.jsonl"]
     })
 }
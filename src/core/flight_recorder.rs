@@ -6,7 +6,7 @@
 
 use crate::core::error::DecapodError;
 use crate::core::store::Store;
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
@@ -32,6 +32,8 @@ pub enum FlightRecorderCommand {
         /// Limit to N most recent events per source
         #[clap(long, default_value = "100")]
         limit: usize,
+        #[clap(flatten)]
+        filter: EventFilterArgs,
     },
     /// Export transcript as markdown
     Transcript {
@@ -41,15 +43,101 @@ pub enum FlightRecorderCommand {
         /// Include only events from this actor
         #[clap(long)]
         actor: Option<String>,
+        #[clap(flatten)]
+        filter: EventFilterArgs,
     },
 }
 
+/// Scoping options shared by timeline and transcript rendering.
+#[derive(Args, Debug, Clone, Default)]
+pub struct EventFilterArgs {
+    /// Include only events at or after this RFC3339 timestamp
+    #[clap(long)]
+    pub since: Option<String>,
+    /// Include only events at or before this RFC3339 timestamp
+    #[clap(long)]
+    pub until: Option<String>,
+    /// Include only events whose op equals or starts with this value (e.g. `todo.claim`, `todo.`)
+    #[clap(long)]
+    pub op: Option<String>,
+    /// Include only events from this source subsystem (broker, todo, federation, ...)
+    #[clap(long)]
+    pub subsystem: Option<String>,
+    /// Group the causal chain for this intent_ref or task_id (add -> claim -> done -> proof)
+    #[clap(long, alias = "correlate")]
+    pub trace: Option<String>,
+}
+
+/// Parsed form of [`EventFilterArgs`] applied while reading event logs.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+    pub op: Option<String>,
+    pub subsystem: Option<String>,
+    pub trace: Option<String>,
+}
+
+impl EventFilterArgs {
+    pub fn parse(&self) -> Result<EventFilter, DecapodError> {
+        Ok(EventFilter {
+            since: parse_bound("--since", self.since.as_deref())?,
+            until: parse_bound("--until", self.until.as_deref())?,
+            op: self.op.clone(),
+            subsystem: self.subsystem.clone(),
+            trace: self.trace.clone(),
+        })
+    }
+}
+
+fn parse_bound(flag: &str, value: Option<&str>) -> Result<Option<u64>, DecapodError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    crate::core::time::parse_rfc3339_epoch(value)
+        .map(Some)
+        .ok_or_else(|| {
+            DecapodError::ValidationError(format!(
+                "{} expects an RFC3339 timestamp (e.g. 2026-02-19T10:00:00Z), got '{}'",
+                flag, value
+            ))
+        })
+}
+
+impl EventFilter {
+    fn includes_source(&self, source: &str) -> bool {
+        self.subsystem.as_deref().is_none_or(|s| s == source)
+    }
+
+    fn matches(&self, ev: &TimelineEvent) -> bool {
+        if let Some(op) = &self.op
+            && !ev.op.starts_with(op.as_str())
+        {
+            return false;
+        }
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+        // Events without a parseable timestamp cannot be placed in a window.
+        let Some(ts) = crate::core::time::parse_event_ts_epoch(&ev.ts) else {
+            return false;
+        };
+        self.since.is_none_or(|since| ts >= since) && self.until.is_none_or(|until| ts <= until)
+    }
+}
+
 pub fn run_flight_recorder_cli(store: &Store, cli: FlightRecorderCli) -> Result<(), DecapodError> {
     match cli.command {
-        FlightRecorderCommand::Timeline { format, limit } => render_timeline(store, &format, limit),
-        FlightRecorderCommand::Transcript { output, actor } => {
-            render_transcript(store, output.as_deref(), actor.as_deref())
-        }
+        FlightRecorderCommand::Timeline {
+            format,
+            limit,
+            filter,
+        } => render_timeline(store, &format, limit, &filter.parse()?),
+        FlightRecorderCommand::Transcript {
+            output,
+            actor,
+            filter,
+        } => render_transcript(store, output.as_deref(), actor.as_deref(), &filter.parse()?),
     }
 }
 
@@ -63,6 +151,10 @@ pub struct TimelineEvent {
     pub session_id: Option<String>,
     pub correlation_id: Option<String>,
     pub status: Option<String>,
+    #[serde(default)]
+    pub intent_ref: Option<String>,
+    #[serde(default)]
+    pub task_id: Option<String>,
     pub details: serde_json::Value,
 }
 
@@ -73,9 +165,82 @@ pub struct Timeline {
     pub sources: Vec<String>,
     pub events: Vec<TimelineEvent>,
     pub gaps: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace: Option<TraceChain>,
+}
+
+/// Correlation keys that make up one unit of work's causal chain.
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceChain {
+    pub root: String,
+    pub intent_refs: Vec<String>,
+    pub task_ids: Vec<String>,
 }
 
-fn render_timeline(store: &Store, format: &str, limit: usize) -> Result<(), DecapodError> {
+/// Keeps only events transitively linked to `root` through a shared intent_ref or task_id.
+///
+/// Todo mutations mint a fresh intent_ref per transition, so the task_id is what stitches
+/// add/claim/done together, while broker events only carry the intent_ref.
+pub fn correlate_events(
+    events: Vec<TimelineEvent>,
+    root: &str,
+) -> (Vec<TimelineEvent>, TraceChain) {
+    let mut keys: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+    keys.insert(root.to_string());
+    loop {
+        let before = keys.len();
+        for ev in &events {
+            let ev_keys = [ev.intent_ref.as_deref(), ev.task_id.as_deref()];
+            if ev_keys.iter().flatten().any(|k| keys.contains(*k)) {
+                keys.extend(ev_keys.iter().flatten().map(|k| k.to_string()));
+            }
+        }
+        if keys.len() == before {
+            break;
+        }
+    }
+
+    let chain: Vec<TimelineEvent> = events
+        .into_iter()
+        .filter(|ev| {
+            [ev.intent_ref.as_deref(), ev.task_id.as_deref()]
+                .iter()
+                .flatten()
+                .any(|k| keys.contains(*k))
+        })
+        .collect();
+
+    let mut intent_refs = std::collections::BTreeSet::new();
+    let mut task_ids = std::collections::BTreeSet::new();
+    for ev in &chain {
+        if let Some(i) = &ev.intent_ref {
+            intent_refs.insert(i.clone());
+        }
+        if let Some(t) = &ev.task_id {
+            task_ids.insert(t.clone());
+        }
+    }
+
+    (
+        chain,
+        TraceChain {
+            root: root.to_string(),
+            intent_refs: intent_refs.into_iter().collect(),
+            task_ids: task_ids.into_iter().collect(),
+        },
+    )
+}
+
+/// Collects timeline events from the store's event logs, applying `filter` while reading.
+///
+/// A trace reads every log in full so the chain is correlated before `limit`
+/// truncates it; otherwise `limit` bounds each log as it is read.
+pub fn collect_timeline(store: &Store, limit: usize, filter: &EventFilter) -> Timeline {
+    let read_limit = if filter.trace.is_some() {
+        usize::MAX
+    } else {
+        limit
+    };
     let mut all_events = Vec::new();
     let mut sources = Vec::new();
     let mut gaps = Vec::new();
@@ -91,9 +256,12 @@ fn render_timeline(store: &Store, format: &str, limit: usize) -> Result<(), Deca
     ];
 
     for (name, path) in &event_files {
+        if !filter.includes_source(name) {
+            continue;
+        }
         if path.exists() {
             sources.push(name.to_string());
-            match read_events(path, limit) {
+            match read_events(path, read_limit, filter) {
                 Ok(events) => {
                     for mut ev in events {
                         ev.source = name.to_string();
@@ -109,18 +277,44 @@ fn render_timeline(store: &Store, format: &str, limit: usize) -> Result<(), Deca
         }
     }
 
-    all_events.sort_by(|a, b| a.ts.cmp(&b.ts));
+    sort_events(&mut all_events);
+
+    let mut trace = None;
+    if let Some(root) = &filter.trace {
+        let (mut chain, keys) = correlate_events(all_events, root);
+        chain.truncate(limit);
+        all_events = chain;
+        trace = Some(keys);
+    }
+
+    Timeline {
+        rendered_at: crate::core::time::now_epoch_z(),
+        event_count: all_events.len(),
+        sources,
+        events: all_events,
+        gaps,
+        trace,
+    }
+}
+
+fn render_timeline(
+    store: &Store,
+    format: &str,
+    limit: usize,
+    filter: &EventFilter,
+) -> Result<(), DecapodError> {
+    let timeline = collect_timeline(store, limit, filter);
 
     if format == "json" {
-        let timeline = Timeline {
-            rendered_at: crate::core::time::now_epoch_z(),
-            event_count: all_events.len(),
-            sources,
-            events: all_events,
-            gaps,
-        };
         println!("{}", serde_json::to_string_pretty(&timeline).unwrap());
     } else {
+        let Timeline {
+            events: all_events,
+            sources,
+            gaps,
+            trace,
+            ..
+        } = timeline;
         println!("===================================================================");
         println!("          GOVERNANCE FLIGHT RECORDER - TIMELINE");
         println!("===================================================================");
@@ -128,6 +322,11 @@ fn render_timeline(store: &Store, format: &str, limit: usize) -> Result<(), Deca
         println!("Rendered: {}", crate::core::time::now_epoch_z());
         println!("Total Events: {}", all_events.len());
         println!("Sources: {}", sources.join(", "));
+        if let Some(trace) = &trace {
+            println!("Trace: {}", trace.root);
+            println!("  Tasks: {}", trace.task_ids.join(", "));
+            println!("  Intents: {}", trace.intent_refs.len());
+        }
         println!();
 
         if !gaps.is_empty() {
@@ -169,6 +368,15 @@ fn render_timeline(store: &Store, format: &str, limit: usize) -> Result<(), Deca
     Ok(())
 }
 
+/// Orders events chronologically; logs mix epoch-`Z` and RFC3339 stamps, so compare parsed seconds.
+fn sort_events(events: &mut [TimelineEvent]) {
+    events.sort_by(|a, b| {
+        let a_secs = crate::core::time::parse_event_ts_epoch(&a.ts);
+        let b_secs = crate::core::time::parse_event_ts_epoch(&b.ts);
+        a_secs.cmp(&b_secs).then_with(|| a.ts.cmp(&b.ts))
+    });
+}
+
 fn truncate(s: &str, max: usize) -> String {
     if s.len() > max {
         format!("{}...", &s[..max - 3])
@@ -177,7 +385,11 @@ fn truncate(s: &str, max: usize) -> String {
     }
 }
 
-fn read_events(path: &PathBuf, limit: usize) -> Result<Vec<TimelineEvent>, DecapodError> {
+fn read_events(
+    path: &PathBuf,
+    limit: usize,
+    filter: &EventFilter,
+) -> Result<Vec<TimelineEvent>, DecapodError> {
     let file = File::open(path).map_err(DecapodError::IoError)?;
     let reader = BufReader::new(file);
     let mut events = Vec::new();
@@ -221,9 +433,13 @@ fn read_events(path: &PathBuf, limit: usize) -> Result<Vec<TimelineEvent>, Decap
                         .get("status")
                         .and_then(|v| v.as_str())
                         .map(String::from),
+                    intent_ref: event_field(&json, "intent_ref"),
+                    task_id: event_field(&json, "task_id"),
                     details: json,
                 };
-                events.push(ev);
+                if filter.matches(&ev) {
+                    events.push(ev);
+                }
             }
             Err(_) => continue,
         }
@@ -236,10 +452,19 @@ fn read_events(path: &PathBuf, limit: usize) -> Result<Vec<TimelineEvent>, Decap
     Ok(events)
 }
 
+/// Reads a correlation key from the event top level, falling back to its payload.
+fn event_field(json: &serde_json::Value, key: &str) -> Option<String> {
+    json.get(key)
+        .or_else(|| json.get("payload").and_then(|p| p.get(key)))
+        .and_then(|v| v.as_str())
+        .map(String::from)
+}
+
 fn render_transcript(
     store: &Store,
     output_path: Option<&str>,
     actor_filter: Option<&str>,
+    filter: &EventFilter,
 ) -> Result<(), DecapodError> {
     let mut all_events = Vec::new();
 
@@ -253,8 +478,16 @@ fn render_transcript(
     ];
 
     for (name, path) in &event_files {
+        if !filter.includes_source(name) {
+            continue;
+        }
+        let read_limit = if filter.trace.is_some() {
+            usize::MAX
+        } else {
+            10000
+        };
         if path.exists()
-            && let Ok(events) = read_events(path, 10000)
+            && let Ok(events) = read_events(path, read_limit, filter)
         {
             for mut ev in events {
                 if let Some(filter) = actor_filter
@@ -268,7 +501,15 @@ fn render_transcript(
         }
     }
 
-    all_events.sort_by(|a, b| a.ts.cmp(&b.ts));
+    sort_events(&mut all_events);
+
+    let mut trace = None;
+    if let Some(root) = &filter.trace {
+        let (mut chain, keys) = correlate_events(all_events, root);
+        chain.truncate(10000);
+        all_events = chain;
+        trace = Some(keys);
+    }
 
     let mut md = String::new();
     md.push_str("# Governance Transcript\n\n");
@@ -280,6 +521,10 @@ fn render_transcript(
     if let Some(f) = actor_filter {
         md.push_str(&format!("Actor Filter: {}\n", f));
     }
+    if let Some(trace) = &trace {
+        md.push_str(&format!("Trace: {}\n", trace.root));
+        md.push_str(&format!("Tasks: {}\n", trace.task_ids.join(", ")));
+    }
     md.push_str("\n---\n\n");
     md.push_str("## Timeline\n\n");
 
@@ -296,6 +541,12 @@ fn render_transcript(
         if let Some(corr) = &ev.correlation_id {
             md.push_str(&format!("- **Correlation:** {}\n", corr));
         }
+        if let Some(intent) = &ev.intent_ref {
+            md.push_str(&format!("- **Intent:** {}\n", intent));
+        }
+        if let Some(task) = &ev.task_id {
+            md.push_str(&format!("- **Task:** {}\n", task));
+        }
         if let Some(status) = &ev.status {
             md.push_str(&format!("- **Status:** {}\n", status));
         }
@@ -322,10 +573,155 @@ pub fn schema() -> serde_json::Value {
         "version": "0.1.0",
         "description": "Governance timeline renderer - makes the narrow corridor legible",
         "commands": [
-            { "name": "timeline", "description": "Render governance timeline from event logs", "parameters": ["format", "limit"] },
-            { "name": "transcript", "description": "Export transcript as markdown", "parameters": ["output", "actor"] }
+            { "name": "timeline", "description": "Render governance timeline from event logs", "parameters": ["format", "limit", "since", "until", "op", "subsystem", "trace"] },
+            { "name": "transcript", "description": "Export transcript as markdown", "parameters": ["output", "actor", "since", "until", "op", "subsystem", "trace"] }
         ],
         "storage": ["read-only over existing event logs"],
         "notes": "Read-only rendering; never fabricates missing structure"
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::store::StoreKind;
+    use tempfile::tempdir;
+
+    fn seeded_store() -> (tempfile::TempDir, Store) {
+        let tmp = tempdir().unwrap();
+        let lines = [
+            r#"{"ts":"2026-02-19T09:55:00Z","event_id":"e1","op":"todo.add"}"#,
+            r#"{"ts":"2026-02-19T10:01:00Z","event_id":"e2","op":"todo.claim"}"#,
+            r#"{"ts":"1771495500Z","event_id":"e3","op":"todo.done"}"#,
+            r#"{"ts":"2026-02-19T10:20:00Z","event_id":"e4","op":"todo.archive"}"#,
+        ];
+        std::fs::write(
+            tmp.path().join("todo.events.jsonl"),
+            format!("{}\n", lines.join("\n")),
+        )
+        .unwrap();
+        std::fs::write(
+            tmp.path().join("broker.events.jsonl"),
+            r#"{"ts":"2026-02-19T10:02:00Z","event_id":"b1","op":"broker.send"}"#,
+        )
+        .unwrap();
+        let store = Store {
+            kind: StoreKind::Repo,
+            root: tmp.path().to_path_buf(),
+        };
+        (tmp, store)
+    }
+
+    fn ids(timeline: &Timeline) -> Vec<&str> {
+        timeline
+            .events
+            .iter()
+            .map(|e| e.event_id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_window_excludes_out_of_range_events() {
+        let (_tmp, store) = seeded_store();
+        let filter = EventFilterArgs {
+            since: Some("2026-02-19T10:00:00Z".to_string()),
+            until: Some("2026-02-19T10:10:00Z".to_string()),
+            ..Default::default()
+        }
+        .parse()
+        .unwrap();
+        let timeline = collect_timeline(&store, 100, &filter);
+        assert_eq!(ids(&timeline), vec!["e2", "b1", "e3"]);
+    }
+
+    #[test]
+    fn test_op_and_subsystem_filters() {
+        let (_tmp, store) = seeded_store();
+        let filter = EventFilterArgs {
+            op: Some("todo.".to_string()),
+            subsystem: Some("todo".to_string()),
+            ..Default::default()
+        }
+        .parse()
+        .unwrap();
+        let timeline = collect_timeline(&store, 100, &filter);
+        assert_eq!(ids(&timeline), vec!["e1", "e2", "e3", "e4"]);
+        assert_eq!(timeline.sources, vec!["todo".to_string()]);
+    }
+
+    #[test]
+    fn test_default_filter_renders_everything() {
+        let (_tmp, store) = seeded_store();
+        let timeline = collect_timeline(&store, 100, &EventFilter::default());
+        assert_eq!(timeline.event_count, 5);
+    }
+
+    #[test]
+    fn test_trace_isolates_one_intent_chain() {
+        let tmp = tempdir().unwrap();
+        let todo = [
+            r#"{"ts":"1771495200Z","event_id":"t1","event_type":"task.add","task_id":"task_a","payload":{"intent_ref":"intent:todo.add:A"}}"#,
+            r#"{"ts":"1771495210Z","event_id":"t2","event_type":"task.add","task_id":"task_b","payload":{"intent_ref":"intent:todo.add:B"}}"#,
+            r#"{"ts":"1771495220Z","event_id":"t3","event_type":"task.claim","task_id":"task_a","payload":{"intent_ref":"intent:task.claim:A2"}}"#,
+            r#"{"ts":"1771495230Z","event_id":"t4","event_type":"task.done","task_id":"task_b","payload":{}}"#,
+            r#"{"ts":"1771495240Z","event_id":"t5","event_type":"task.done","task_id":"task_a","payload":{}}"#,
+        ];
+        std::fs::write(tmp.path().join("todo.events.jsonl"), todo.join("\n")).unwrap();
+        let broker = [
+            r#"{"ts":"1771495201Z","event_id":"b1","op":"todo.add","intent_ref":"intent:todo.add:A"}"#,
+            r#"{"ts":"1771495211Z","event_id":"b2","op":"todo.add","intent_ref":"intent:todo.add:B"}"#,
+            r#"{"ts":"1771495221Z","event_id":"b3","op":"task.claim","intent_ref":"intent:task.claim:A2"}"#,
+        ];
+        std::fs::write(tmp.path().join("broker.events.jsonl"), broker.join("\n")).unwrap();
+        let store = Store {
+            kind: StoreKind::Repo,
+            root: tmp.path().to_path_buf(),
+        };
+
+        let filter = EventFilter {
+            trace: Some("intent:todo.add:A".to_string()),
+            ..Default::default()
+        };
+        let timeline = collect_timeline(&store, 100, &filter);
+        assert_eq!(ids(&timeline), vec!["t1", "b1", "t3", "b3", "t5"]);
+        let trace = timeline.trace.expect("trace chain");
+        assert_eq!(trace.task_ids, vec!["task_a".to_string()]);
+        assert_eq!(trace.intent_refs.len(), 2);
+    }
+
+    #[test]
+    fn test_trace_correlates_before_applying_limit() {
+        let tmp = tempdir().unwrap();
+        let todo = [
+            r#"{"ts":"1771495200Z","event_id":"u1","event_type":"task.add","task_id":"task_x"}"#,
+            r#"{"ts":"1771495201Z","event_id":"u2","event_type":"task.add","task_id":"task_y"}"#,
+            r#"{"ts":"1771495202Z","event_id":"u3","event_type":"task.add","task_id":"task_z"}"#,
+            r#"{"ts":"1771495210Z","event_id":"t1","event_type":"task.add","task_id":"task_a","payload":{"intent_ref":"intent:todo.add:A"}}"#,
+            r#"{"ts":"1771495220Z","event_id":"t2","event_type":"task.claim","task_id":"task_a","payload":{"intent_ref":"intent:task.claim:A2"}}"#,
+            r#"{"ts":"1771495230Z","event_id":"t3","event_type":"task.done","task_id":"task_a","payload":{}}"#,
+        ];
+        std::fs::write(tmp.path().join("todo.events.jsonl"), todo.join("\n")).unwrap();
+        let store = Store {
+            kind: StoreKind::Repo,
+            root: tmp.path().to_path_buf(),
+        };
+
+        let filter = EventFilter {
+            trace: Some("intent:todo.add:A".to_string()),
+            ..Default::default()
+        };
+        let timeline = collect_timeline(&store, 2, &filter);
+        assert_eq!(ids(&timeline), vec!["t1", "t2"]);
+        let trace = timeline.trace.expect("trace chain");
+        assert_eq!(trace.intent_refs.len(), 2, "keys come from the whole chain");
+    }
+
+    #[test]
+    fn test_invalid_bound_is_rejected() {
+        let args = EventFilterArgs {
+            since: Some("yesterday".to_string()),
+            ..Default::default()
+        };
+        assert!(args.parse().is_err());
+    }
+}
@@ -6,7 +6,7 @@
 //! - Secret scanning
 //! - Dangerous pattern detection
 
-use crate::core::error;
+use crate::core::{error, state_commit};
 use fancy_regex::Regex;
 use std::path::{Path, PathBuf};
 
@@ -77,6 +77,37 @@ impl std::fmt::Display for ViolationKind {
     }
 }
 
+/// Paths staged in the index, byte-for-byte.
+///
+/// `-z` output is neither quoted nor escaped, so non-UTF-8 and other unusual
+/// file names reach the checks intact. `Ok(None)` when git exits non-zero
+/// (e.g. outside a repository).
+pub fn staged_paths(repo_root: &Path) -> std::io::Result<Option<Vec<PathBuf>>> {
+    let output = std::process::Command::new("git")
+        .args(["diff", "--cached", "-z", "--name-only"])
+        .current_dir(repo_root)
+        .output()?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    Ok(Some(
+        output
+            .stdout
+            .split(|b| *b == 0)
+            .filter(|p| !p.is_empty())
+            .map(|p| PathBuf::from(state_commit::os_from_bytes(p.to_vec())))
+            .collect(),
+    ))
+}
+
+/// Read a file for scanning. Invalid UTF-8 is replaced rather than skipped so
+/// a stray byte cannot hide a secret from the scan.
+fn read_for_scan(full_path: &Path) -> Option<String> {
+    std::fs::read(full_path)
+        .ok()
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
 /// Run all gatekeeper checks
 pub fn run_gatekeeper(
     repo_root: &Path,
@@ -144,9 +175,8 @@ fn scan_for_secrets(
             continue;
         }
 
-        let content = match std::fs::read_to_string(&full_path) {
-            Ok(c) => c,
-            Err(_) => continue,
+        let Some(content) = read_for_scan(&full_path) else {
+            continue;
         };
 
         for (line_num, line) in content.lines().enumerate() {
@@ -188,9 +218,8 @@ fn scan_for_dangerous_patterns(
             continue;
         }
 
-        let content = match std::fs::read_to_string(&full_path) {
-            Ok(c) => c,
-            Err(_) => continue,
+        let Some(content) = read_for_scan(&full_path) else {
+            continue;
         };
 
         for (line_num, line) in content.lines().enumerate() {
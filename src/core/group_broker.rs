@@ -1,5 +1,6 @@
 use crate::core::db;
 use crate::core::error;
+use crate::core::settings;
 use crate::core::time;
 use rusqlite::OptionalExtension;
 use serde::{Deserialize, Serialize};
@@ -11,8 +12,6 @@ use std::process::Command;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 const BROKER_INTERNAL_ENV: &str = "DECAPOD_GROUP_BROKER_INTERNAL";
-const BROKER_DISABLE_ENV: &str = "DECAPOD_GROUP_BROKER_DISABLE";
-const BROKER_IDLE_SECS_ENV: &str = "DECAPOD_GROUP_BROKER_IDLE_SECS";
 const BROKER_REQUEST_ID_ENV: &str = "DECAPOD_GROUP_BROKER_REQUEST_ID";
 const BROKER_PROTOCOL_CLIENT_OVERRIDE_ENV: &str = "DECAPOD_GROUP_BROKER_PROTOCOL_CLIENT_OVERRIDE";
 const BROKER_PROTOCOL_SERVER_OVERRIDE_ENV: &str = "DECAPOD_GROUP_BROKER_PROTOCOL_SERVER_OVERRIDE";
@@ -56,10 +55,7 @@ pub fn maybe_route_mutation(
     broker_root: &Path,
     argv: &[String],
 ) -> Result<bool, error::DecapodError> {
-    if std::env::var(BROKER_DISABLE_ENV)
-        .map(|v| v == "1")
-        .unwrap_or(false)
-    {
+    if settings::current().broker_disable {
         return Ok(false);
     }
     if is_internal_invocation() {
@@ -175,13 +171,7 @@ fn run_as_leader(
     emit_phase_hook("queued", &local_request.request_id);
     let local_response = execute_request(broker_root, &local_request)?;
 
-    let idle_timeout = Duration::from_secs(
-        std::env::var(BROKER_IDLE_SECS_ENV)
-            .ok()
-            .and_then(|v| v.parse::<u64>().ok())
-            .filter(|v| *v > 0)
-            .unwrap_or(3),
-    );
+    let idle_timeout = Duration::from_secs(settings::current().broker_idle_secs);
     let mut last_activity = Instant::now();
 
     loop {
@@ -429,6 +419,13 @@ fn hash_payload(argv: &[String]) -> String {
     format!("{:x}", hasher.finalize())
 }
 
+/// PID of another live process holding the broker lock under `broker_root`.
+pub fn broker_lock_holder(broker_root: &Path) -> Option<u32> {
+    let raw = fs::read_to_string(broker_lock_path(broker_root)).ok()?;
+    let pid = raw.trim().parse::<u32>().ok().filter(|pid| *pid > 0)?;
+    (pid != std::process::id() && is_pid_alive(pid)).then_some(pid)
+}
+
 fn broker_lock_path(broker_root: &Path) -> PathBuf {
     broker_root.join("broker.lock")
 }
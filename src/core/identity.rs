@@ -0,0 +1,152 @@
+//! Agent identity resolution.
+//!
+//! The acting agent id is resolved, first match wins, from:
+//!
+//! 1. `DECAPOD_AGENT_ID` (trimmed, non-empty)
+//! 2. `.decapod/agent_id` in the project root (first line, trimmed)
+//! 3. git config: the local part of `user.email`, else `user.name`, as a slug
+//! 4. `unknown`
+//!
+//! Setting `DECAPOD_AGENT_ID=unknown` explicitly still opts out of the fallbacks.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+/// Name of the per-project identity file under `.decapod/`.
+pub const AGENT_ID_FILE: &str = "agent_id";
+
+/// Id used when no source yields one.
+pub const UNKNOWN_AGENT_ID: &str = "unknown";
+
+static PROJECT_ROOT: OnceLock<Option<PathBuf>> = OnceLock::new();
+static GIT_IDENTITY: OnceLock<Option<String>> = OnceLock::new();
+
+/// Where a resolved agent id came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentIdSource {
+    Env,
+    File,
+    Git,
+    Default,
+}
+
+impl AgentIdSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AgentIdSource::Env => "env",
+            AgentIdSource::File => "file",
+            AgentIdSource::Git => "git",
+            AgentIdSource::Default => "default",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentIdentity {
+    pub id: String,
+    pub source: AgentIdSource,
+}
+
+/// Record the project root used for the file and git fallbacks; later calls
+/// are ignored.
+pub fn install(repo_root: Option<&Path>) {
+    let _ = PROJECT_ROOT.set(repo_root.map(Path::to_path_buf));
+}
+
+/// Resolve the acting agent for this process.
+pub fn resolve() -> AgentIdentity {
+    let root = PROJECT_ROOT.get().and_then(|r| r.as_deref());
+    resolve_with(
+        root,
+        |key| std::env::var(key).ok(),
+        |root| GIT_IDENTITY.get_or_init(|| git_identity(root)).clone(),
+    )
+}
+
+/// The resolved agent id (see [`resolve`]).
+pub fn current_agent_id() -> String {
+    resolve().id
+}
+
+/// [`resolve`] with injectable env and git lookups.
+pub fn resolve_with<E, G>(repo_root: Option<&Path>, env: E, git: G) -> AgentIdentity
+where
+    E: Fn(&str) -> Option<String>,
+    G: Fn(&Path) -> Option<String>,
+{
+    if let Some(id) = env("DECAPOD_AGENT_ID")
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+    {
+        return AgentIdentity {
+            id,
+            source: AgentIdSource::Env,
+        };
+    }
+    if let Some(root) = repo_root {
+        if let Some(id) = read_agent_id_file(root) {
+            return AgentIdentity {
+                id,
+                source: AgentIdSource::File,
+            };
+        }
+        if let Some(id) = git(root) {
+            return AgentIdentity {
+                id,
+                source: AgentIdSource::Git,
+            };
+        }
+    }
+    AgentIdentity {
+        id: UNKNOWN_AGENT_ID.to_string(),
+        source: AgentIdSource::Default,
+    }
+}
+
+pub fn agent_id_file_path(repo_root: &Path) -> PathBuf {
+    repo_root.join(".decapod").join(AGENT_ID_FILE)
+}
+
+fn read_agent_id_file(repo_root: &Path) -> Option<String> {
+    let raw = std::fs::read_to_string(agent_id_file_path(repo_root)).ok()?;
+    let id = raw.lines().next()?.trim();
+    (!id.is_empty()).then(|| id.to_string())
+}
+
+fn git_config(repo_root: &Path, key: &str) -> Option<String> {
+    let out = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["config", "--get", key])
+        .output()
+        .ok()?;
+    if !out.status.success() {
+        return None;
+    }
+    let value = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
+
+fn git_identity(repo_root: &Path) -> Option<String> {
+    let raw = git_config(repo_root, "user.email")
+        .and_then(|email| email.split('@').next().map(str::to_string))
+        .filter(|local| !local.is_empty())
+        .or_else(|| git_config(repo_root, "user.name"))?;
+    let slug = slugify(&raw);
+    (!slug.is_empty()).then_some(slug)
+}
+
+/// Lowercase, with runs of anything but ASCII alphanumerics, `-` and `_`
+/// collapsed to a single `-`.
+fn slugify(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for ch in raw.trim().chars() {
+        if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+            out.push(ch.to_ascii_lowercase());
+        } else if !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_matches('-').to_string()
+}
@@ -12,6 +12,8 @@
 //! - Immutable sources: Never modifies existing docs/KG
 //! - Compact views: Max 5 items per obligations list
 //! - Optional LLM: Only for ranking/phrasing, never adding obligations
+//! - Cached derivation: Base obligations are cached per context signature for a
+//!   short TTL; contradictions, container hygiene and co-players are always live
 
 use crate::core::error::DecapodError;
 use crate::core::rpc::{Blocker, BlockerKind};
@@ -77,6 +79,9 @@ pub struct Obligations {
     pub contradictions: Vec<Contradiction>,
     /// Co-player snapshots for in-context inference
     pub coplayer_snapshots: Vec<crate::core::coplayer::CoPlayerSnapshot>,
+    /// Whether base obligations were served from the context-signature cache
+    #[serde(default)]
+    pub cache_hit: bool,
 }
 
 /// A detected contradiction
@@ -157,17 +162,141 @@ enum CandidateSource {
     },
 }
 
+/// Default lifetime of cached base obligations (override with `DECAPOD_MENTOR_CACHE_TTL_SECS`).
+const DEFAULT_CACHE_TTL_SECS: u64 = 30;
+
+/// Default cap on cached context signatures; the least recently written are evicted first.
+const DEFAULT_CACHE_MAX_ENTRIES: usize = 256;
+
+/// On-disk cache entry for base obligations derived from one context signature
+#[derive(Debug, Deserialize, Serialize)]
+struct CachedBaseObligations {
+    cached_at: u64,
+    must: Vec<Obligation>,
+    recommended: Vec<Obligation>,
+}
+
 /// Mentor engine for computing obligations
 pub struct MentorEngine {
     repo_root: PathBuf,
+    cache_ttl_secs: u64,
+    cache_max_entries: usize,
 }
 
 impl MentorEngine {
     /// Create a new mentor engine
     pub fn new(repo_root: &Path) -> Self {
+        let cache_ttl_secs = std::env::var("DECAPOD_MENTOR_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_CACHE_TTL_SECS);
         Self {
             repo_root: repo_root.to_path_buf(),
+            cache_ttl_secs,
+            cache_max_entries: DEFAULT_CACHE_MAX_ENTRIES,
+        }
+    }
+
+    /// Override the base-obligation cache TTL (0 disables caching)
+    pub fn with_cache_ttl(mut self, secs: u64) -> Self {
+        self.cache_ttl_secs = secs;
+        self
+    }
+
+    /// Override how many context signatures the base-obligation cache keeps
+    pub fn with_cache_max_entries(mut self, entries: usize) -> Self {
+        self.cache_max_entries = entries;
+        self
+    }
+
+    /// Content-addressed signature of an obligations context
+    pub fn context_signature(context: &ObligationsContext) -> String {
+        use sha2::{Digest, Sha256};
+        let canonical = serde_json::to_string(context).unwrap_or_default();
+        format!("{:x}", Sha256::digest(canonical.as_bytes()))
+    }
+
+    fn cache_dir(&self) -> PathBuf {
+        self.repo_root
+            .join(".decapod")
+            .join("data")
+            .join("mentor_cache")
+    }
+
+    fn cache_path(&self, signature: &str) -> PathBuf {
+        self.cache_dir().join(format!("{}.json", signature))
+    }
+
+    /// Drop entries older than the TTL, then the oldest entries beyond the cap.
+    fn prune_cache(&self) {
+        let Ok(dir) = std::fs::read_dir(self.cache_dir()) else {
+            return;
+        };
+        let now = std::time::SystemTime::now();
+        let ttl = std::time::Duration::from_secs(self.cache_ttl_secs);
+        let mut live = Vec::new();
+        for entry in dir.flatten() {
+            let path = entry.path();
+            let Some(modified) = entry.metadata().and_then(|m| m.modified()).ok() else {
+                continue;
+            };
+            if now.duration_since(modified).unwrap_or_default() > ttl {
+                let _ = std::fs::remove_file(&path);
+            } else {
+                live.push((modified, path));
+            }
+        }
+        if live.len() > self.cache_max_entries {
+            live.sort();
+            let excess = live.len() - self.cache_max_entries;
+            for (_, path) in live.into_iter().take(excess) {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    fn load_cached_base(&self, signature: &str) -> Option<(Vec<Obligation>, Vec<Obligation>)> {
+        if self.cache_ttl_secs == 0 {
+            return None;
+        }
+        let raw = std::fs::read_to_string(self.cache_path(signature)).ok()?;
+        let entry: CachedBaseObligations = serde_json::from_str(&raw).ok()?;
+        let now = crate::core::time::parse_event_ts_epoch(&crate::core::time::now_epoch_z())?;
+        if now.saturating_sub(entry.cached_at) > self.cache_ttl_secs {
+            return None;
         }
+        Some((entry.must, entry.recommended))
+    }
+
+    /// Best-effort cache write; a failed write only costs a recompute next time.
+    fn store_cached_base(&self, signature: &str, must: &[Obligation], recommended: &[Obligation]) {
+        if self.cache_ttl_secs == 0 {
+            return;
+        }
+        let Some(cached_at) =
+            crate::core::time::parse_event_ts_epoch(&crate::core::time::now_epoch_z())
+        else {
+            return;
+        };
+        let path = self.cache_path(signature);
+        let Some(parent) = path.parent() else {
+            return;
+        };
+        if std::fs::create_dir_all(parent).is_err() {
+            return;
+        }
+        let entry = CachedBaseObligations {
+            cached_at,
+            must: must.to_vec(),
+            recommended: recommended.to_vec(),
+        };
+        if let Ok(body) = serde_json::to_string(&entry) {
+            let tmp = path.with_extension("json.tmp");
+            if std::fs::write(&tmp, body).is_ok() {
+                let _ = std::fs::rename(&tmp, &path);
+            }
+        }
+        self.prune_cache();
     }
 
     /// Compute obligations for a given operation context
@@ -178,17 +307,22 @@ impl MentorEngine {
         // Step 1: Get container obligations FIRST (Silicon Valley hygiene priority)
         let container_obligations = self.get_container_candidates()?;
 
-        // Step 2: Retrieve candidates from all other sources
-        let candidates = self.retrieve_candidates(context)?;
-
-        // Step 3: Score candidates based on relevance
-        let scored = self.score_candidates(&candidates, context);
-
-        // Step 4: Check for contradictions
-        let contradictions = self.detect_contradictions(&scored, context);
+        // Steps 2-3 + 5: Derive base obligations, served from cache for a stable context
+        let signature = Self::context_signature(context);
+        let (mut must, mut recommended, cache_hit) = match self.load_cached_base(&signature) {
+            Some((must, recommended)) => (must, recommended, true),
+            None => {
+                let candidates = self.retrieve_candidates(context)?;
+                let scored = self.score_candidates(&candidates, context);
+                let (must, recommended) = self.build_obligations(scored, context);
+                self.store_cached_base(&signature, &must, &recommended);
+                (must, recommended, false)
+            }
+        };
 
-        // Step 5: Build obligations lists (capped at 5 each)
-        let (mut must, mut recommended) = self.build_obligations(scored, context);
+        // Step 4: Check for contradictions against live ADR state (never cached)
+        let adr_scored = self.score_candidates(&self.get_adr_candidates()?, context);
+        let contradictions = self.detect_contradictions(&adr_scored, context);
 
         // Step 6: Prepend container obligations (they take precedence)
         // Container obligations are MUST if Dockerfile missing or work not containerized
@@ -208,7 +342,7 @@ impl MentorEngine {
 
         // Step 7: Resolve co-player snapshots for in-context inference
         let mut coplayer_snapshots = Vec::new();
-        let agent_id = std::env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+        let agent_id = crate::core::identity::current_agent_id();
 
         let mut has_high_risk_coplayer = false;
 
@@ -259,6 +393,7 @@ impl MentorEngine {
             recommended,
             contradictions,
             coplayer_snapshots,
+            cache_hit,
         })
     }
 
@@ -19,6 +19,11 @@ pub const DECAPOD_VERSION: &str = env!("CARGO_PKG_VERSION");
 const GENERATED_VERSION_COUNTER: &str = "generated/version_counter.json";
 const GENERATED_APPLIED_MIGRATIONS: &str = "generated/migrations/applied.json";
 const GENERATED_MIGRATION_CATALOG: &str = "generated/migrations/catalog.json";
+/// Backup directories live in `.decapod/data` as `<prefix><version>_<id>`.
+const BACKUP_DIR_PREFIX: &str = ".migration_backup_";
+const BACKUP_MANIFEST: &str = "manifest.json";
+/// Backups kept after a successful migration; older ones are pruned.
+const BACKUP_RETAIN: usize = 5;
 
 /// Migration definition
 pub struct Migration {
@@ -196,14 +201,13 @@ where
         )));
     }
 
-    fs::remove_dir_all(&backup_dir).map_err(error::DecapodError::IoError)?;
-    Ok(())
+    prune_backups(&data_root, BACKUP_RETAIN)
 }
 
-fn schema_upgrade_pending(data_root: &Path) -> Result<bool, error::DecapodError> {
+fn todo_schema_version(data_root: &Path) -> Result<Option<u32>, error::DecapodError> {
     let todo_db = data_root.join(schemas::TODO_DB_NAME);
     if !todo_db.exists() {
-        return Ok(false);
+        return Ok(None);
     }
     let conn = db::db_connect(&todo_db.to_string_lossy())?;
     let version_res: Result<String, _> = conn.query_row(
@@ -211,24 +215,106 @@ fn schema_upgrade_pending(data_root: &Path) -> Result<bool, error::DecapodError>
         [],
         |row| row.get(0),
     );
-    let current_version = version_res
-        .ok()
-        .and_then(|raw| raw.parse::<u32>().ok())
-        .unwrap_or(0);
-    Ok(current_version < schemas::TODO_SCHEMA_VERSION)
+    Ok(Some(
+        version_res
+            .ok()
+            .and_then(|raw| raw.parse::<u32>().ok())
+            .unwrap_or(0),
+    ))
+}
+
+fn schema_upgrade_pending(data_root: &Path) -> Result<bool, error::DecapodError> {
+    Ok(todo_schema_version(data_root)?.is_some_and(|v| v < schemas::TODO_SCHEMA_VERSION))
+}
+
+/// One file captured in a backup.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BackupFile {
+    pub name: String,
+    pub size_bytes: u64,
+    pub sha256: String,
+}
+
+/// `manifest.json` written into every backup directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub id: String,
+    pub created_at: String,
+    pub decapod_version: String,
+    /// `todo.db` schema version at backup time, if the database existed.
+    pub todo_schema_version: Option<u32>,
+    /// `migration` or `pre_restore`
+    pub reason: String,
+    pub files: Vec<BackupFile>,
+}
+
+/// A backup as reported by `migration backup list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupEntry {
+    pub id: String,
+    pub created_at: String,
+    pub decapod_version: String,
+    pub todo_schema_version: Option<u32>,
+    pub reason: String,
+    pub size_bytes: u64,
+    pub file_count: usize,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupRestore {
+    pub restored: String,
+    /// Backup of the state that the restore replaced.
+    pub archived: String,
+    pub files: Vec<String>,
+}
+
+fn is_backed_up_file(name: &str) -> bool {
+    name.ends_with(".db") || name.ends_with(".jsonl")
+}
+
+fn sha256_file(path: &Path) -> Result<String, error::DecapodError> {
+    let bytes = fs::read(path).map_err(error::DecapodError::IoError)?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+/// Copy one data file into a backup. SQLite databases go through the online
+/// backup API so pages still in the `-wal` file are included; a plain copy
+/// of the main file would drop every write not yet checkpointed.
+fn copy_data_file(src: &Path, target: &Path) -> Result<(), error::DecapodError> {
+    if src.extension().is_some_and(|e| e == "db") {
+        let conn = Connection::open_with_flags(src, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        conn.backup(rusqlite::MAIN_DB, target, None)?;
+        return Ok(());
+    }
+    fs::copy(src, target).map_err(error::DecapodError::IoError)?;
+    Ok(())
 }
 
 fn create_data_backup(data_root: &Path) -> Result<Option<std::path::PathBuf>, error::DecapodError> {
+    create_data_backup_with_reason(data_root, "migration")
+        .map(|created| created.map(|(dir, _)| dir))
+}
+
+/// Copy every top-level `.db`/`.jsonl` file of `data_root` into a new backup
+/// directory and record their sizes and hashes in its manifest.
+pub fn create_data_backup_with_reason(
+    data_root: &Path,
+    reason: &str,
+) -> Result<Option<(std::path::PathBuf, BackupManifest)>, error::DecapodError> {
     if !data_root.exists() {
         return Ok(None);
     }
+    let id = crate::core::ulid::new_ulid();
     let backup_dir = data_root.join(format!(
-        ".migration_backup_{}_{}",
+        "{}{}_{}",
+        BACKUP_DIR_PREFIX,
         DECAPOD_VERSION.replace('.', "_"),
-        crate::core::ulid::new_ulid()
+        id
     ));
     fs::create_dir_all(&backup_dir).map_err(error::DecapodError::IoError)?;
 
+    let mut files = Vec::new();
     for entry in fs::read_dir(data_root).map_err(error::DecapodError::IoError)? {
         let entry = entry.map_err(error::DecapodError::IoError)?;
         let path = entry.path();
@@ -236,18 +322,39 @@ fn create_data_backup(data_root: &Path) -> Result<Option<std::path::PathBuf>, er
             continue;
         }
         let name = entry.file_name().to_string_lossy().to_string();
-        if name.ends_with(".db") || name.ends_with(".jsonl") {
-            fs::copy(&path, backup_dir.join(&name)).map_err(error::DecapodError::IoError)?;
+        if is_backed_up_file(&name) {
+            let target = backup_dir.join(&name);
+            copy_data_file(&path, &target)?;
+            files.push(BackupFile {
+                size_bytes: fs::metadata(&target)
+                    .map_err(error::DecapodError::IoError)?
+                    .len(),
+                sha256: sha256_file(&target)?,
+                name,
+            });
         }
     }
-    Ok(Some(backup_dir))
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    let manifest = BackupManifest {
+        id,
+        created_at: crate::core::time::now_epoch_z(),
+        decapod_version: DECAPOD_VERSION.to_string(),
+        todo_schema_version: todo_schema_version(data_root)?,
+        reason: reason.to_string(),
+        files,
+    };
+    let body = serde_json::to_string_pretty(&manifest).map_err(|e| {
+        error::DecapodError::ValidationError(format!("Failed to serialize backup manifest: {}", e))
+    })?;
+    fs::write(backup_dir.join(BACKUP_MANIFEST), body).map_err(error::DecapodError::IoError)?;
+    Ok(Some((backup_dir, manifest)))
 }
 
 fn restore_data_backup(data_root: &Path, backup_dir: &Path) -> Result<(), error::DecapodError> {
     for entry in fs::read_dir(backup_dir).map_err(error::DecapodError::IoError)? {
         let entry = entry.map_err(error::DecapodError::IoError)?;
         let backup_file = entry.path();
-        if !backup_file.is_file() {
+        if !backup_file.is_file() || entry.file_name() == BACKUP_MANIFEST {
             continue;
         }
         let name = entry.file_name();
@@ -256,6 +363,161 @@ fn restore_data_backup(data_root: &Path, backup_dir: &Path) -> Result<(), error:
     Ok(())
 }
 
+/// Backup directories that carry a readable manifest, newest first.
+fn backup_dirs(
+    data_root: &Path,
+) -> Result<Vec<(std::path::PathBuf, BackupManifest)>, error::DecapodError> {
+    if !data_root.exists() {
+        return Ok(Vec::new());
+    }
+    let mut out = Vec::new();
+    for entry in fs::read_dir(data_root).map_err(error::DecapodError::IoError)? {
+        let entry = entry.map_err(error::DecapodError::IoError)?;
+        let path = entry.path();
+        if !path.is_dir()
+            || !entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(BACKUP_DIR_PREFIX)
+        {
+            continue;
+        }
+        let Some(manifest) = fs::read_to_string(path.join(BACKUP_MANIFEST))
+            .ok()
+            .and_then(|raw| serde_json::from_str::<BackupManifest>(&raw).ok())
+        else {
+            continue;
+        };
+        out.push((path, manifest));
+    }
+    // Ids are ULIDs, so lexical order is creation order.
+    out.sort_by(|a, b| b.1.id.cmp(&a.1.id));
+    Ok(out)
+}
+
+fn prune_backups(data_root: &Path, keep: usize) -> Result<(), error::DecapodError> {
+    for (dir, _) in backup_dirs(data_root)?.into_iter().skip(keep) {
+        fs::remove_dir_all(&dir).map_err(error::DecapodError::IoError)?;
+    }
+    Ok(())
+}
+
+/// Backups of `data_root`, newest first.
+pub fn list_backups(data_root: &Path) -> Result<Vec<BackupEntry>, error::DecapodError> {
+    Ok(backup_dirs(data_root)?
+        .into_iter()
+        .map(|(dir, manifest)| BackupEntry {
+            size_bytes: manifest.files.iter().map(|f| f.size_bytes).sum(),
+            file_count: manifest.files.len(),
+            path: dir.to_string_lossy().to_string(),
+            id: manifest.id,
+            created_at: manifest.created_at,
+            decapod_version: manifest.decapod_version,
+            todo_schema_version: manifest.todo_schema_version,
+            reason: manifest.reason,
+        })
+        .collect())
+}
+
+fn verify_backup(dir: &Path, manifest: &BackupManifest) -> Result<(), error::DecapodError> {
+    for file in &manifest.files {
+        let path = dir.join(&file.name);
+        let size = fs::metadata(&path).map(|m| m.len()).map_err(|_| {
+            error::DecapodError::ValidationError(format!(
+                "Backup '{}' is missing {}",
+                manifest.id, file.name
+            ))
+        })?;
+        if size != file.size_bytes || sha256_file(&path)? != file.sha256 {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Backup '{}' failed integrity check: {} does not match its manifest hash",
+                manifest.id, file.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Replace the `.db`/`.jsonl` files of `data_root` with backup `id`.
+///
+/// The backup's hashes are verified and the current state is archived as a
+/// `pre_restore` backup before anything is replaced; files are then staged
+/// under the data root and renamed into place. Refuses while another process
+/// holds the broker lock.
+pub fn restore_backup(data_root: &Path, id: &str) -> Result<BackupRestore, error::DecapodError> {
+    if !crate::core::ulid::is_valid(id) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Invalid backup id '{}'",
+            id
+        )));
+    }
+    if let Some(pid) = crate::core::group_broker::broker_lock_holder(data_root) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Refusing restore: decapod process {} holds the broker lock; retry once it exits",
+            pid
+        )));
+    }
+    let (dir, manifest) = backup_dirs(data_root)?
+        .into_iter()
+        .find(|(_, m)| m.id == id)
+        .ok_or_else(|| {
+            error::DecapodError::NotFound(format!(
+                "No backup '{}' (see `decapod migration backup list`)",
+                id
+            ))
+        })?;
+    verify_backup(&dir, &manifest)?;
+
+    let (_, archived) =
+        create_data_backup_with_reason(data_root, "pre_restore")?.ok_or_else(|| {
+            error::DecapodError::NotFound(format!("{} does not exist", data_root.display()))
+        })?;
+
+    // Stage the copies beside the live files so a failed copy leaves the data
+    // root untouched and each file is swapped in with a same-filesystem rename.
+    let staging = data_root.join(format!(".restore_{}", manifest.id));
+    if staging.exists() {
+        fs::remove_dir_all(&staging).map_err(error::DecapodError::IoError)?;
+    }
+    fs::create_dir_all(&staging).map_err(error::DecapodError::IoError)?;
+    let staged = manifest
+        .files
+        .iter()
+        .try_for_each(|file| {
+            fs::copy(dir.join(&file.name), staging.join(&file.name))
+                .map(|_| ())
+                .map_err(error::DecapodError::IoError)
+        })
+        .and_then(|_| verify_backup(&staging, &manifest));
+    if let Err(e) = staged {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+
+    let restored: HashSet<&str> = manifest.files.iter().map(|f| f.name.as_str()).collect();
+    for entry in fs::read_dir(data_root).map_err(error::DecapodError::IoError)? {
+        let entry = entry.map_err(error::DecapodError::IoError)?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let stale = (is_backed_up_file(&name) && !restored.contains(name.as_str()))
+            || name.ends_with(".db-wal")
+            || name.ends_with(".db-shm")
+            || name.ends_with(".db-journal");
+        if stale && entry.path().is_file() {
+            fs::remove_file(entry.path()).map_err(error::DecapodError::IoError)?;
+        }
+    }
+    for file in &manifest.files {
+        fs::rename(staging.join(&file.name), data_root.join(&file.name))
+            .map_err(error::DecapodError::IoError)?;
+    }
+    fs::remove_dir_all(&staging).map_err(error::DecapodError::IoError)?;
+    Ok(BackupRestore {
+        restored: manifest.id,
+        archived: archived.id,
+        files: manifest.files.into_iter().map(|f| f.name).collect(),
+    })
+}
+
 /// Run all idempotent migrations
 fn run_migrations(decapod_root: &Path) -> Result<(), error::DecapodError> {
     let mut migrations = all_migrations();
@@ -18,6 +18,7 @@ pub mod external_action;
 pub mod flight_recorder;
 pub mod gatekeeper;
 pub mod group_broker;
+pub mod identity;
 pub mod interview;
 pub mod mentor;
 pub mod migration;
@@ -25,12 +26,14 @@ pub mod obligation;
 pub mod output;
 pub mod plan_governance;
 pub mod pool;
+pub mod profiles;
 pub mod project_specs;
 pub mod proof;
 pub mod repomap;
 pub mod rpc;
 pub mod scaffold;
 pub mod schemas;
+pub mod settings;
 pub mod standards;
 pub mod state_commit;
 pub mod store;
@@ -39,5 +42,6 @@ pub mod todo;
 pub mod trace;
 pub mod ulid;
 pub mod validate;
+pub mod watch;
 pub mod workspace;
 pub mod workunit;
@@ -7,6 +7,8 @@
 //! KEY PRINCIPLE: Completion is DERIVED, never asserted.
 //! - Status is computed from: dependencies satisfied, proofs verified, state_commit present
 //! - No user-settable status field - status is always derived
+//! - The only explicit transitions are `satisfy` (evidence-backed, prerequisites
+//!   met) and `waive` (trust-gated); both record provenance in `metadata`
 
 use crate::core::broker::DbBroker;
 use crate::core::error;
@@ -23,6 +25,7 @@ pub enum ObligationStatus {
     Open,
     Met,
     Failed,
+    Waived,
 }
 
 impl ObligationStatus {
@@ -31,6 +34,7 @@ impl ObligationStatus {
             ObligationStatus::Open => "open",
             ObligationStatus::Met => "met",
             ObligationStatus::Failed => "failed",
+            ObligationStatus::Waived => "waived",
         }
     }
 
@@ -38,11 +42,20 @@ impl ObligationStatus {
         match s {
             "met" => ObligationStatus::Met,
             "failed" => ObligationStatus::Failed,
+            "waived" => ObligationStatus::Waived,
             _ => ObligationStatus::Open,
         }
     }
+
+    /// Whether dependents may treat this obligation as done.
+    pub fn is_resolved(&self) -> bool {
+        matches!(self, ObligationStatus::Met | ObligationStatus::Waived)
+    }
 }
 
+/// Trust level required to waive an obligation.
+pub const WAIVE_REQUIRED_TRUST: &str = "verified";
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ObligationNode {
     pub id: String,
@@ -79,6 +92,24 @@ pub struct GraphValidationResult {
     pub total_edges: usize,
 }
 
+/// An obligation that can never be met because a prerequisite is gone or failed.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UnsatisfiableObligation {
+    pub obligation_id: String,
+    pub depends_on: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ObligationCheckResult {
+    pub ok: bool,
+    /// Each cycle as the obligation IDs along it, starting from the smallest ID.
+    pub cycles: Vec<Vec<String>>,
+    pub unsatisfiable: Vec<UnsatisfiableObligation>,
+    pub total_nodes: usize,
+    pub total_edges: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ObligationEdge {
     pub edge_id: String,
@@ -144,6 +175,27 @@ pub enum ObligationCommand {
     },
     /// Validate the entire obligation graph (cycles, dependencies, proofs, commits).
     ValidateGraph,
+    /// Detect deadlocks: dependency cycles and prerequisites that can never resolve.
+    Check,
+    /// Mark an obligation met on evidence; refused while prerequisites are unmet.
+    Satisfy {
+        #[clap(long)]
+        id: String,
+        /// Evidence reference (commit:, file:, url:, ...)
+        #[clap(long)]
+        evidence: String,
+    },
+    /// Waive an obligation so dependents unblock; the acting agent must hold
+    /// verified trust.
+    Waive {
+        #[clap(long)]
+        id: String,
+        #[clap(long)]
+        reason: String,
+        /// Agent accepting the waiver; must match the resolved agent identity
+        #[clap(long)]
+        by: Option<String>,
+    },
 }
 
 pub fn run_obligation_cli(store: &Store, cli: ObligationCli) -> Result<(), error::DecapodError> {
@@ -174,12 +226,33 @@ pub fn run_obligation_cli(store: &Store, cli: ObligationCli) -> Result<(), error
             let result = validate_obligation_graph(store)?;
             println!("{}", serde_json::to_string_pretty(&result).unwrap());
         }
+        ObligationCommand::Check => {
+            let result = check_obligation_graph(store)?;
+            println!("{}", serde_json::to_string_pretty(&result).unwrap());
+            if !result.ok {
+                return Err(error::DecapodError::ValidationError(format!(
+                    "Obligation graph deadlocked: {} cycle(s), {} unsatisfiable obligation(s)",
+                    result.cycles.len(),
+                    result.unsatisfiable.len()
+                )));
+            }
+        }
         ObligationCommand::Complete { id, commit } => {
             complete_obligation(store, &id, &commit)?;
             let (status, reason) = verify_obligation(store, &id)?;
             println!("Obligation {} updated with commit {}.", id, commit);
             println!("Status: {:?}\nReason: {}", status, reason);
         }
+        ObligationCommand::Satisfy { id, evidence } => {
+            let actor = crate::core::identity::current_agent_id();
+            let obligation = satisfy_obligation(store, &id, &evidence, &actor)?;
+            println!("{}", serde_json::to_string_pretty(&obligation).unwrap());
+        }
+        ObligationCommand::Waive { id, reason, by } => {
+            let actor = crate::core::identity::current_agent_id();
+            let obligation = waive_obligation(store, &id, &reason, &actor, by.as_deref())?;
+            println!("{}", serde_json::to_string_pretty(&obligation).unwrap());
+        }
     }
     Ok(())
 }
@@ -357,14 +430,12 @@ pub fn derive_obligation_status(
     let mut validation_errors = Vec::new();
 
     let dependencies = get_dependencies(store, id)?;
-    let dependencies_satisfied = dependencies
-        .iter()
-        .all(|dep| dep.status == ObligationStatus::Met);
+    let dependencies_satisfied = dependencies.iter().all(|dep| dep.status.is_resolved());
 
     if !dependencies_satisfied {
         let unsatisfied: Vec<String> = dependencies
             .iter()
-            .filter(|d| d.status != ObligationStatus::Met)
+            .filter(|d| !d.status.is_resolved())
             .map(|d| d.id.clone())
             .collect();
         validation_errors.push(format!("Dependencies not met: {:?}", unsatisfied));
@@ -383,7 +454,11 @@ pub fn derive_obligation_status(
         validation_errors.push("STATE_COMMIT root missing".to_string());
     }
 
-    let derived_status = if dependencies_satisfied && proofs_satisfied && commit_present {
+    // Explicit satisfy/waive transitions stand on their recorded provenance.
+    let derived_status = if obligation.status.is_resolved() && has_recorded_resolution(&obligation)
+    {
+        obligation.status
+    } else if dependencies_satisfied && proofs_satisfied && commit_present {
         ObligationStatus::Met
     } else {
         ObligationStatus::Open
@@ -416,7 +491,7 @@ pub fn validate_obligation_graph(
 
         let validation = derive_obligation_status(store, &obligation.id)?;
 
-        if validation.derived_status != ObligationStatus::Met {
+        if !validation.derived_status.is_resolved() {
             unsatisfied_obligations.push(obligation.id.clone());
         }
 
@@ -447,6 +522,101 @@ pub fn validate_obligation_graph(
     })
 }
 
+/// Scan the raw `obligation_edges` table for deadlocks. Unlike
+/// [`get_dependencies`], edges pointing at removed obligations are kept so
+/// they can be reported. A waived prerequisite was never met, so it leaves its
+/// dependents unsatisfiable; dependents that are no longer open are skipped.
+pub fn check_obligation_graph(store: &Store) -> Result<ObligationCheckResult, error::DecapodError> {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    let broker = DbBroker::new(&store.root);
+    let db_path = obligation_db_path(&store.root);
+    let (statuses, edges) =
+        broker.with_conn(&db_path, "decapod", None, "obligation.check", |conn| {
+            let mut stmt = conn.prepare("SELECT id, status FROM obligations")?;
+            let statuses = stmt
+                .query_map([], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        ObligationStatus::from_status_str(&row.get::<_, String>(1)?),
+                    ))
+                })?
+                .collect::<Result<BTreeMap<_, _>, _>>()?;
+            let mut stmt = conn.prepare("SELECT from_id, to_id FROM obligation_edges")?;
+            let edges = stmt
+                .query_map([], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((statuses, edges))
+        })?;
+
+    let mut adjacency: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut unsatisfiable = Vec::new();
+    for (from, to) in &edges {
+        adjacency.entry(from).or_default().insert(to);
+        if statuses.get(from) != Some(&ObligationStatus::Open) {
+            continue;
+        }
+        let reason = match statuses.get(to) {
+            None => Some("prerequisite obligation no longer exists"),
+            Some(ObligationStatus::Failed) => Some("prerequisite obligation failed"),
+            Some(ObligationStatus::Waived) => Some("prerequisite obligation was waived"),
+            Some(_) => None,
+        };
+        if let Some(reason) = reason {
+            unsatisfiable.push(UnsatisfiableObligation {
+                obligation_id: from.clone(),
+                depends_on: to.clone(),
+                reason: reason.to_string(),
+            });
+        }
+    }
+    unsatisfiable
+        .sort_by(|a, b| (&a.obligation_id, &a.depends_on).cmp(&(&b.obligation_id, &b.depends_on)));
+
+    // Every back edge closes a cycle over the current DFS path.
+    let mut cycles: BTreeSet<Vec<String>> = BTreeSet::new();
+    let mut done: BTreeSet<&str> = BTreeSet::new();
+    for &start in adjacency.keys() {
+        if done.contains(start) {
+            continue;
+        }
+        let mut path: Vec<&str> = vec![start];
+        let mut frames = vec![adjacency[start].iter()];
+        while let Some(frame) = frames.last_mut() {
+            match frame.next() {
+                Some(&next) => {
+                    if let Some(pos) = path.iter().position(|&n| n == next) {
+                        let mut cycle: Vec<String> =
+                            path[pos..].iter().map(|n| n.to_string()).collect();
+                        let min = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+                        cycle.rotate_left(min);
+                        cycles.insert(cycle);
+                    } else if !done.contains(next) {
+                        path.push(next);
+                        frames.push(adjacency.get(next).map(|s| s.iter()).unwrap_or_default());
+                    }
+                }
+                None => {
+                    frames.pop();
+                    if let Some(node) = path.pop() {
+                        done.insert(node);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(ObligationCheckResult {
+        ok: cycles.is_empty() && unsatisfiable.is_empty(),
+        cycles: cycles.into_iter().collect(),
+        unsatisfiable,
+        total_nodes: statuses.len(),
+        total_edges: edges.len(),
+    })
+}
+
 fn detect_cycle_in_path(
     _obligations: &[ObligationNode],
     store: &Store,
@@ -555,3 +725,158 @@ pub fn complete_obligation(
         Ok(())
     })
 }
+
+/// Record an explicit resolution: new status plus a `resolution` entry in
+/// metadata describing who transitioned it, when, and why.
+fn record_resolution(
+    store: &Store,
+    obligation: &ObligationNode,
+    status: ObligationStatus,
+    resolution: serde_json::Value,
+) -> Result<ObligationNode, error::DecapodError> {
+    let broker = DbBroker::new(&store.root);
+    let db_path = obligation_db_path(&store.root);
+    let mut metadata = match obligation.metadata.clone() {
+        Some(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    metadata.insert("resolution".to_string(), resolution);
+    let metadata_json = serde_json::Value::Object(metadata).to_string();
+    let now = crate::core::time::now_epoch_z();
+
+    broker.with_conn(&db_path, "decapod", None, "obligation.resolve", |conn| {
+        conn.execute(
+            "UPDATE obligations SET status = ?1, metadata = ?2, updated_at = ?3 WHERE id = ?4",
+            params![status.as_str(), metadata_json, now, obligation.id],
+        )?;
+        Ok(())
+    })?;
+    get_obligation(store, &obligation.id)
+}
+
+fn has_recorded_resolution(obligation: &ObligationNode) -> bool {
+    obligation
+        .metadata
+        .as_ref()
+        .and_then(|m| m.get("resolution"))
+        .is_some_and(|r| r.is_object())
+}
+
+fn ensure_unresolved(obligation: &ObligationNode) -> Result<(), error::DecapodError> {
+    if obligation.status.is_resolved() {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Obligation {} is already {}",
+            obligation.id,
+            obligation.status.as_str()
+        )));
+    }
+    Ok(())
+}
+
+/// Mark an obligation met on the strength of `evidence`. Refused while any
+/// prerequisite is neither met nor waived.
+pub fn satisfy_obligation(
+    store: &Store,
+    id: &str,
+    evidence: &str,
+    actor: &str,
+) -> Result<ObligationNode, error::DecapodError> {
+    let obligation = get_obligation(store, id)?;
+    ensure_unresolved(&obligation)?;
+    if evidence.trim().is_empty() {
+        return Err(error::DecapodError::ValidationError(
+            "obligation satisfy requires a non-empty --evidence reference".to_string(),
+        ));
+    }
+    let unmet: Vec<String> = get_dependencies(store, id)?
+        .into_iter()
+        .filter(|d| !d.status.is_resolved())
+        .map(|d| d.id)
+        .collect();
+    if !unmet.is_empty() {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Cannot satisfy obligation {}: prerequisites not met: {}",
+            id,
+            unmet.join(", ")
+        )));
+    }
+    record_resolution(
+        store,
+        &obligation,
+        ObligationStatus::Met,
+        serde_json::json!({
+            "action": "satisfy",
+            "evidence": evidence,
+            "by": actor,
+            "at": crate::core::time::now_epoch_z(),
+        }),
+    )
+}
+
+/// Waive an obligation on behalf of `actor`, the resolved agent identity.
+/// Trust is checked against `actor`; a claimed `by` that names anyone else is
+/// refused. Each waiver is also appended to `obligation.events.jsonl`.
+pub fn waive_obligation(
+    store: &Store,
+    id: &str,
+    reason: &str,
+    actor: &str,
+    by: Option<&str>,
+) -> Result<ObligationNode, error::DecapodError> {
+    let obligation = get_obligation(store, id)?;
+    ensure_unresolved(&obligation)?;
+    if reason.trim().is_empty() {
+        return Err(error::DecapodError::ValidationError(
+            "obligation waive requires a non-empty --reason".to_string(),
+        ));
+    }
+    if let Some(by) = by.filter(|b| *b != actor) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Waiver --by '{}' does not match the acting agent '{}'",
+            by, actor
+        )));
+    }
+    if !crate::core::todo::check_trust_level(&store.root, actor, WAIVE_REQUIRED_TRUST)? {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Waiving obligation {} requires trust '{}'; agent '{}' does not hold it",
+            id, WAIVE_REQUIRED_TRUST, actor
+        )));
+    }
+    let now = crate::core::time::now_epoch_z();
+    append_obligation_event(
+        store,
+        serde_json::json!({
+            "event_id": crate::core::ulid::new_ulid(),
+            "ts": now,
+            "event_type": "obligation.waive",
+            "obligation_id": id,
+            "reason": reason,
+            "by": actor,
+        }),
+    )?;
+    record_resolution(
+        store,
+        &obligation,
+        ObligationStatus::Waived,
+        serde_json::json!({
+            "action": "waive",
+            "reason": reason,
+            "by": actor,
+            "at": now,
+        }),
+    )
+}
+
+fn append_obligation_event(
+    store: &Store,
+    event: serde_json::Value,
+) -> Result<(), error::DecapodError> {
+    use std::io::Write;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(store.root.join(schemas::OBLIGATION_EVENTS_NAME))
+        .map_err(error::DecapodError::IoError)?;
+    writeln!(file, "{}", event).map_err(error::DecapodError::IoError)?;
+    Ok(())
+}
@@ -1,6 +1,85 @@
 //! Compact output rendering helpers for CLI surfaces.
 //!
-//! Keeps command result output bounded and readable while preserving signal.
+//! Keeps command result output bounded and readable while preserving signal,
+//! and masks secrets before JSON reaches a terminal or a pasted issue.
+
+use crate::core::error::DecapodError;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Keys whose values are masked by [`redact_secrets`] (matched case-insensitively).
+pub const SENSITIVE_KEYS: &[&str] = &[
+    "token",
+    "password",
+    "password_hash",
+    "session",
+    "secret",
+    "api_key",
+    "authorization",
+];
+
+/// Suffixes that mark a key as sensitive (`session_token`, `db_password`).
+const SENSITIVE_SUFFIXES: &[&str] = &["_token", "_password", "_secret"];
+
+pub const REDACTED: &str = "[REDACTED]";
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    SENSITIVE_KEYS.contains(&key.as_str()) || SENSITIVE_SUFFIXES.iter().any(|s| key.ends_with(s))
+}
+
+/// Replace the value of every sensitive key, at any depth, with [`REDACTED`].
+pub fn redact_secrets(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| {
+                    if is_sensitive_key(&k) && !v.is_null() {
+                        (k, Value::String(REDACTED.to_string()))
+                    } else {
+                        (k, redact_secrets(v))
+                    }
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(redact_secrets).collect()),
+        other => other,
+    }
+}
+
+/// Pretty JSON for CLI output, redacted unless `show_secrets` is set.
+pub fn render_json<T: Serialize>(value: &T, show_secrets: bool) -> Result<String, DecapodError> {
+    let value =
+        serde_json::to_value(value).map_err(|e| DecapodError::ValidationError(e.to_string()))?;
+    let value = if show_secrets {
+        value
+    } else {
+        redact_secrets(value)
+    };
+    serde_json::to_string_pretty(&value).map_err(|e| DecapodError::ValidationError(e.to_string()))
+}
+
+/// Streams records as JSON Lines: one compact, redacted object per line,
+/// flushed as it is written so consumers can read incrementally.
+pub struct JsonlWriter<W: std::io::Write> {
+    out: W,
+}
+
+impl<W: std::io::Write> JsonlWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+
+    pub fn write_record<T: Serialize>(&mut self, record: &T) -> Result<(), DecapodError> {
+        let value = serde_json::to_value(record)
+            .map_err(|e| DecapodError::ValidationError(e.to_string()))?;
+        let line = serde_json::to_string(&redact_secrets(value))
+            .map_err(|e| DecapodError::ValidationError(e.to_string()))?;
+        writeln!(self.out, "{}", line)?;
+        self.out.flush()?;
+        Ok(())
+    }
+}
 
 /// Collapse newlines/extra whitespace and bound length for terminal display.
 pub fn compact_line(input: &str, max_chars: usize) -> String {
@@ -101,4 +180,25 @@ mod tests {
         assert!(result.contains("two"));
         assert!(!result.contains("more"));
     }
+
+    #[test]
+    fn test_redact_secrets_masks_sensitive_keys_at_any_depth() {
+        let value = serde_json::json!({
+            "agent_id": "a1",
+            "token": "tok-123",
+            "password_hash": "abc",
+            "session_ttl_secs": 3600,
+            "nested": [{"session_token": "tok-456", "session": "s", "safe": "ok"}],
+            "api_key": null
+        });
+        let redacted = redact_secrets(value);
+        assert_eq!(redacted["agent_id"], "a1");
+        assert_eq!(redacted["token"], REDACTED);
+        assert_eq!(redacted["password_hash"], REDACTED);
+        assert_eq!(redacted["session_ttl_secs"], 3600);
+        assert_eq!(redacted["nested"][0]["session_token"], REDACTED);
+        assert_eq!(redacted["nested"][0]["session"], REDACTED);
+        assert_eq!(redacted["nested"][0]["safe"], "ok");
+        assert!(redacted["api_key"].is_null());
+    }
 }
@@ -0,0 +1,108 @@
+//! Init profiles: opinionated presets applied by `decapod init --profile`.
+//!
+//! Each profile ships as two embedded assets under `templates/profiles/`:
+//! `<name>.json` (extra todo categories and RISKMAP zones) and `<name>.md`
+//! (starter standards written to `.decapod/STANDARDS.md`). Profiles only add
+//! to what is already there: existing categories, mapped zone paths, and an
+//! existing standards file are left untouched, so re-running init is safe.
+
+use crate::core::{error, standards, todo};
+use crate::plugins::policy::{self, RiskZone};
+use serde::Deserialize;
+use std::path::Path;
+
+macro_rules! embedded_profiles {
+    ($($name:literal),* $(,)?) => {
+        /// Names accepted by `decapod init --profile`.
+        pub const PROFILE_NAMES: &[&str] = &[$($name),*];
+
+        fn embedded_profile(name: &str) -> Option<(&'static str, &'static str)> {
+            match name {
+                $(
+                    $name => Some((
+                        include_str!(concat!("../../templates/profiles/", $name, ".json")),
+                        include_str!(concat!("../../templates/profiles/", $name, ".md")),
+                    )),
+                )*
+                _ => None,
+            }
+        }
+    };
+}
+
+embedded_profiles!("rust-lib", "service", "cli");
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProfileCategory {
+    pub name: String,
+    pub description: String,
+    pub keywords: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct InitProfile {
+    #[serde(skip)]
+    pub name: String,
+    pub description: String,
+    pub categories: Vec<ProfileCategory>,
+    pub risk_zones: Vec<RiskZone>,
+    #[serde(skip)]
+    pub standards: String,
+}
+
+/// What applying a profile changed.
+#[derive(Debug, Default)]
+pub struct ProfileSummary {
+    pub categories_added: usize,
+    pub zones_added: usize,
+    pub standards_written: bool,
+}
+
+pub fn load(name: &str) -> Result<InitProfile, error::DecapodError> {
+    let (manifest, standards) = embedded_profile(name).ok_or_else(|| {
+        error::DecapodError::ValidationError(format!(
+            "Unknown init profile '{}'; expected one of: {}",
+            name,
+            PROFILE_NAMES.join(", ")
+        ))
+    })?;
+    let mut profile: InitProfile = serde_json::from_str(manifest).map_err(|e| {
+        error::DecapodError::ValidationError(format!(
+            "Embedded profile '{}' is invalid: {}",
+            name, e
+        ))
+    })?;
+    profile.name = name.to_string();
+    profile.standards = standards.to_string();
+    Ok(profile)
+}
+
+/// Seed `profile` into the project at `target_dir`, whose `.decapod/data`
+/// store must already be initialized.
+pub fn apply(
+    target_dir: &Path,
+    profile: &InitProfile,
+) -> Result<ProfileSummary, error::DecapodError> {
+    let decapod_dir = target_dir.join(".decapod");
+    let store_root = decapod_dir.join("data");
+
+    let categories: Vec<(&str, &str, &str)> = profile
+        .categories
+        .iter()
+        .map(|c| (c.name.as_str(), c.description.as_str(), c.keywords.as_str()))
+        .collect();
+    let categories_added = todo::seed_categories(&store_root, &categories)?;
+    let zones_added = policy::seed_risk_zones(&store_root, &profile.risk_zones)?;
+
+    let standards_path = decapod_dir.join(standards::PROFILE_STANDARDS_FILE);
+    let standards_written = !standards_path.exists();
+    if standards_written {
+        std::fs::write(&standards_path, &profile.standards)?;
+    }
+
+    Ok(ProfileSummary {
+        categories_added,
+        zones_added,
+        standards_written,
+    })
+}
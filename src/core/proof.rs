@@ -19,6 +19,39 @@ pub struct ProofDef {
     pub description: String,
     #[serde(default)]
     pub required: bool,
+    /// Exit code the command must return to pass.
+    #[serde(default)]
+    pub expect_exit: i32,
+    /// Substring stdout must contain to pass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expect_stdout_contains: Option<String>,
+    /// Substring stderr must contain to pass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expect_stderr_contains: Option<String>,
+}
+
+impl ProofDef {
+    /// Every way the observed run differs from what this proof expects.
+    pub fn mismatches(&self, exit_code: i32, stdout: &str, stderr: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        if exit_code != self.expect_exit {
+            out.push(format!(
+                "expected exit {}, got {}",
+                self.expect_exit, exit_code
+            ));
+        }
+        for (stream, expected, actual) in [
+            ("stdout", &self.expect_stdout_contains, stdout),
+            ("stderr", &self.expect_stderr_contains, stderr),
+        ] {
+            if let Some(needle) = expected
+                && !actual.contains(needle.as_str())
+            {
+                out.push(format!("expected {} to contain '{}'", stream, needle));
+            }
+        }
+        out
+    }
 }
 
 /// Result of running a single proof
@@ -31,6 +64,9 @@ pub struct ProofResult {
     pub passed: bool,
     pub output: String,
     pub required: bool,
+    /// Why the proof failed its expectations; empty when it passed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mismatches: Vec<String>,
 }
 
 /// Event logged for each proof run
@@ -44,6 +80,8 @@ pub struct ProofEvent {
     pub exit_code: i32,
     pub duration_ms: u64,
     pub passed: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mismatches: Vec<String>,
     pub store: String,
     pub root: String,
     pub actor: String,
@@ -85,7 +123,8 @@ fn run_single_proof(
     let exit_code = output.status.code().unwrap_or(-1);
 
     let duration_ms = start_time.elapsed().as_millis();
-    let passed = exit_code == 0;
+    let mismatches = proof_def.mismatches(exit_code, &stdout, &stderr);
+    let passed = mismatches.is_empty();
 
     // Truncate very long output
     let output_truncated: String = stdout.chars().take(1000).collect();
@@ -98,6 +137,7 @@ fn run_single_proof(
         passed,
         output: format!("{}\n{}", output_truncated, stderr),
         required: proof_def.required,
+        mismatches,
     })
 }
 
@@ -166,6 +206,7 @@ pub fn run_proofs(
             exit_code: result.exit_code,
             duration_ms: result.duration_ms,
             passed: result.passed,
+            mismatches: result.mismatches.clone(),
             store: format!("{:?}", store.kind),
             root: store.root.to_string_lossy().to_string(),
             actor: actor.to_string(),
@@ -245,6 +286,77 @@ fn append_proof_event(store: &Store, event: &ProofEvent) -> Result<(), DecapodEr
     Ok(())
 }
 
+/// Version of the `govern proof list --format json` contract.
+pub const PROOF_LIST_SCHEMA_VERSION: &str = "1.0.0";
+
+/// A configured proof joined with its most recent run.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProofListEntry {
+    pub name: String,
+    pub command: String,
+    pub required: bool,
+    /// `pass` or `fail`; `None` if the proof has never run.
+    pub last_status: Option<String>,
+    pub last_run_ts: Option<String>,
+    /// `<events log>#<event_id>` of the run that produced `last_status`.
+    pub artifact: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProofListReport {
+    pub schema_version: String,
+    pub proofs: Vec<ProofListEntry>,
+}
+
+/// Join each proof in proofs.toml with its latest event in `proof.events.jsonl`.
+pub fn list_proofs(store_root: &Path) -> Result<ProofListReport, DecapodError> {
+    let config = load_proof_config(store_root)?;
+    let events_path = store_root.join("proof.events.jsonl");
+    let mut latest: std::collections::HashMap<String, serde_json::Value> =
+        std::collections::HashMap::new();
+    if let Ok(raw) = fs::read_to_string(&events_path) {
+        for event in raw
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        {
+            if let Some(name) = event.get("proof_name").and_then(|v| v.as_str()) {
+                latest.insert(name.to_string(), event);
+            }
+        }
+    }
+
+    let proofs = config
+        .proof
+        .iter()
+        .map(|def| {
+            let last = latest.get(&def.name);
+            let field = |key: &str| {
+                last.and_then(|e| e.get(key))
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+            };
+            ProofListEntry {
+                name: def.name.clone(),
+                command: format!("{} {}", def.command, def.args.join(" "))
+                    .trim_end()
+                    .to_string(),
+                required: def.required,
+                last_status: last
+                    .and_then(|e| e.get("passed"))
+                    .and_then(|v| v.as_bool())
+                    .map(|passed| if passed { "pass" } else { "fail" }.to_string()),
+                last_run_ts: field("ts"),
+                artifact: field("event_id").map(|id| format!("{}#{}", events_path.display(), id)),
+            }
+        })
+        .collect();
+
+    Ok(ProofListReport {
+        schema_version: PROOF_LIST_SCHEMA_VERSION.to_string(),
+        proofs,
+    })
+}
+
 /// The proofs.toml config structure
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct ProofConfig {
@@ -270,8 +382,10 @@ pub fn execute_proof_cli(cli: &ProofCommandCli, store_root: &Path) -> Result<(),
                 for proof_result in &result.results {
                     if !proof_result.passed {
                         eprintln!(
-                            "❌ Proof '{}' failed with exit code {}: {}",
-                            proof_result.name, proof_result.exit_code, proof_result.output
+                            "❌ Proof '{}' failed ({}): {}",
+                            proof_result.name,
+                            proof_result.mismatches.join("; "),
+                            proof_result.output
                         );
                     }
                 }
@@ -289,7 +403,14 @@ pub fn execute_proof_cli(cli: &ProofCommandCli, store_root: &Path) -> Result<(),
                 "Individual proof testing not yet implemented".to_string(),
             ))
         }
-        crate::ProofSubCommand::List => {
+        crate::ProofSubCommand::List {
+            format: crate::ProofListFormat::Json,
+        } => {
+            let report = list_proofs(store_root)?;
+            println!("{}", serde_json::to_string_pretty(&report).unwrap());
+            Ok(())
+        }
+        crate::ProofSubCommand::List { .. } => {
             let config = load_proof_config(store_root)?;
             println!("Available proofs:");
             for (i, proof_def) in config.proof.iter().enumerate() {
@@ -320,7 +441,10 @@ pub fn schema() -> serde_json::Value {
                 "command": "string (required)",
                 "args": ["string array (optional)"],
                 "description": "string (optional)",
-                "required": "bool (default: true)"
+                "required": "bool (default: true)",
+                "expect_exit": "int (default: 0)",
+                "expect_stdout_contains": "string (optional)",
+                "expect_stderr_contains": "string (optional)"
             }]
         },
         "events": ["proof.run"],
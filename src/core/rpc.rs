@@ -12,6 +12,7 @@
 //! - `blocked_by`: Missing answers/proofs
 
 use crate::core::docs::{DocFragment, Mandate};
+use crate::core::schemas;
 use serde::{Deserialize, Serialize};
 use sha2::Digest;
 use std::collections::HashMap;
@@ -178,6 +179,40 @@ pub struct CapabilitiesReport {
     pub interlock_codes: Vec<String>,
 }
 
+impl CapabilitiesReport {
+    /// Looks up a capability by name, a subsystem by name, or a `<subsystem>.<op>`.
+    pub fn probe(&self, name: &str) -> Option<serde_json::Value> {
+        if let Some(cap) = self.capabilities.iter().find(|c| c.name == name) {
+            return Some(serde_json::json!({
+                "kind": "capability",
+                "name": cap.name,
+                "stability": cap.stability,
+                "description": cap.description,
+            }));
+        }
+        if let Some(sub) = self.subsystems.iter().find(|s| s.name == name) {
+            return Some(serde_json::json!({
+                "kind": "subsystem",
+                "name": sub.name,
+                "status": sub.status,
+                "schema_version": sub.schema_version,
+            }));
+        }
+        let (sub_name, op) = name.split_once('.')?;
+        self.subsystems
+            .iter()
+            .find(|s| s.name == sub_name && s.ops.iter().any(|o| o == op))
+            .map(|sub| {
+                serde_json::json!({
+                    "kind": "op",
+                    "name": name,
+                    "subsystem": sub.name,
+                    "status": sub.status,
+                })
+            })
+    }
+}
+
 /// Individual capability
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Capability {
@@ -200,6 +235,11 @@ pub struct SubsystemInfo {
     pub status: String,
     /// Operations supported
     pub ops: Vec<String>,
+    /// Schema version the binary's `ensure_schema` migrates to; `None` for
+    /// subsystems without a versioned DB. A DB reporting a lower version is
+    /// behind this binary.
+    #[serde(default)]
+    pub schema_version: Option<u32>,
 }
 
 /// Workspace capabilities
@@ -358,6 +398,25 @@ pub fn generate_capabilities() -> CapabilitiesReport {
                 stability: "stable".to_string(),
                 cost: "medium".to_string(),
             },
+            Capability {
+                name: "federation.add_node".to_string(),
+                description: "Create a typed federation node with provenance".to_string(),
+                stability: "stable".to_string(),
+                cost: "medium".to_string(),
+            },
+            Capability {
+                name: "federation.add_edge".to_string(),
+                description: "Link two federation nodes with a typed, weighted edge".to_string(),
+                stability: "stable".to_string(),
+                cost: "medium".to_string(),
+            },
+            Capability {
+                name: "knowledge.search".to_string(),
+                description: "Search knowledge with as_of/window_days filters and rank control"
+                    .to_string(),
+                stability: "stable".to_string(),
+                cost: "medium".to_string(),
+            },
             Capability {
                 name: "validate.run".to_string(),
                 description: "Run deterministic validation gates".to_string(),
@@ -376,6 +435,19 @@ pub fn generate_capabilities() -> CapabilitiesReport {
                 stability: "stable".to_string(),
                 cost: "low".to_string(),
             },
+            Capability {
+                name: "workspace.list".to_string(),
+                description: "List Decapod-managed worktrees".to_string(),
+                stability: "beta".to_string(),
+                cost: "low".to_string(),
+            },
+            Capability {
+                name: "workspace.prune".to_string(),
+                description: "Remove managed worktrees that are missing or already merged"
+                    .to_string(),
+                stability: "beta".to_string(),
+                cost: "low".to_string(),
+            },
             Capability {
                 name: "eval.gate".to_string(),
                 description: "Run variance-aware statistical promotion gate over eval aggregates"
@@ -428,16 +500,19 @@ pub fn generate_capabilities() -> CapabilitiesReport {
                     "done".to_string(),
                     "list".to_string(),
                 ],
+                schema_version: Some(schemas::TODO_SCHEMA_VERSION),
             },
             SubsystemInfo {
                 name: "knowledge".to_string(),
                 status: "active".to_string(),
                 ops: vec!["add".to_string(), "search".to_string()],
+                schema_version: None,
             },
             SubsystemInfo {
                 name: "federation".to_string(),
                 status: "active".to_string(),
                 ops: vec!["add".to_string(), "get".to_string(), "graph".to_string()],
+                schema_version: Some(schemas::FEDERATION_SCHEMA_VERSION),
             },
             SubsystemInfo {
                 name: "lcm".to_string(),
@@ -451,6 +526,7 @@ pub fn generate_capabilities() -> CapabilitiesReport {
                     "schema".to_string(),
                     "rebuild".to_string(),
                 ],
+                schema_version: None,
             },
             SubsystemInfo {
                 name: "map".to_string(),
@@ -460,6 +536,7 @@ pub fn generate_capabilities() -> CapabilitiesReport {
                     "agentic".to_string(),
                     "schema".to_string(),
                 ],
+                schema_version: None,
             },
             SubsystemInfo {
                 name: "eval".to_string(),
@@ -472,6 +549,7 @@ pub fn generate_capabilities() -> CapabilitiesReport {
                     "gate".to_string(),
                     "bucket-failures".to_string(),
                 ],
+                schema_version: None,
             },
         ],
         workspace: WorkspaceCapabilities {
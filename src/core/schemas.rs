@@ -21,6 +21,18 @@ pub const POLICY_DB_SCHEMA_APPROVALS: &str = "
 ";
 pub const POLICY_DB_SCHEMA_INDEX: &str =
     "CREATE INDEX IF NOT EXISTS idx_approvals_fingerprint ON approvals(action_fingerprint)";
+pub const POLICY_DB_SCHEMA_APPROVAL_EVENTS: &str = "
+    CREATE TABLE IF NOT EXISTS approval_events (
+        event_id TEXT PRIMARY KEY,
+        kind TEXT NOT NULL,
+        action TEXT NOT NULL,
+        action_fingerprint TEXT NOT NULL,
+        approval_id TEXT,
+        actor TEXT NOT NULL,
+        scope TEXT NOT NULL,
+        ts TEXT NOT NULL
+    )
+";
 
 pub const HEALTH_DB_SCHEMA_CLAIMS: &str = "
     CREATE TABLE IF NOT EXISTS claims (
@@ -58,9 +70,13 @@ pub const FEEDBACK_DB_SCHEMA: &str = "
         source TEXT NOT NULL,
         text TEXT NOT NULL,
         links TEXT,
-        created_at TEXT NOT NULL
+        created_at TEXT NOT NULL,
+        status TEXT NOT NULL DEFAULT 'open',
+        resolved_at TEXT,
+        resolution_note TEXT
     )
 ";
+pub const FEEDBACK_EVENTS_NAME: &str = "feedback.events.jsonl";
 
 pub const ARCHIVE_DB_SCHEMA: &str = "
     CREATE TABLE IF NOT EXISTS archives (
@@ -72,6 +88,8 @@ pub const ARCHIVE_DB_SCHEMA: &str = "
     )
 ";
 
+pub const OBLIGATION_EVENTS_NAME: &str = "obligation.events.jsonl";
+
 pub const GOVERNANCE_DB_SCHEMA_OBLIGATIONS: &str = "
     CREATE TABLE IF NOT EXISTS obligations (
         id TEXT PRIMARY KEY,
@@ -149,6 +167,8 @@ pub const MEMORY_DB_SCHEMA_EDGES: &str = "
         edge_type TEXT NOT NULL,
         created_at TEXT NOT NULL,
         actor TEXT NOT NULL DEFAULT 'decapod',
+        confidence REAL NOT NULL DEFAULT 1.0,
+        rationale TEXT,
         FOREIGN KEY(source_id) REFERENCES nodes(id),
         FOREIGN KEY(target_id) REFERENCES nodes(id)
     )
@@ -202,7 +222,8 @@ pub const KNOWLEDGE_DB_SCHEMA: &str = "
         merge_key TEXT DEFAULT '',
         supersedes_id TEXT,
         ttl_policy TEXT NOT NULL DEFAULT 'persistent',
-        expires_ts TEXT
+        expires_ts TEXT,
+        namespace TEXT NOT NULL DEFAULT 'general'
     )
 ";
 
@@ -212,6 +233,8 @@ pub const KNOWLEDGE_DB_INDEX_CREATED: &str =
     "CREATE INDEX IF NOT EXISTS idx_knowledge_created ON knowledge(created_at)";
 pub const KNOWLEDGE_DB_INDEX_MERGE_KEY: &str =
     "CREATE INDEX IF NOT EXISTS idx_knowledge_merge_key ON knowledge(merge_key)";
+pub const KNOWLEDGE_DB_INDEX_NAMESPACE: &str =
+    "CREATE INDEX IF NOT EXISTS idx_knowledge_namespace ON knowledge(namespace)";
 pub const KNOWLEDGE_DB_INDEX_ACTIVE_MERGE_SCOPE: &str = "CREATE INDEX IF NOT EXISTS idx_knowledge_active_merge_scope ON knowledge(status, merge_key, scope)";
 
 // Legacy Decide Schemas (preserved for migration)
@@ -308,6 +331,22 @@ pub const CRON_DB_SCHEMA: &str = "
     )
 ";
 
+pub const CRON_DB_SCHEMA_EXECUTIONS: &str = "
+    CREATE TABLE IF NOT EXISTS cron_executions (
+        id TEXT PRIMARY KEY,
+        job_id TEXT NOT NULL,
+        trigger TEXT NOT NULL,
+        started_at TEXT NOT NULL,
+        finished_at TEXT,
+        status TEXT NOT NULL,
+        exit_code INTEGER,
+        error TEXT
+    )
+";
+
+pub const CRON_DB_INDEX_EXECUTIONS_JOB: &str =
+    "CREATE INDEX IF NOT EXISTS idx_cron_executions_job ON cron_executions(job_id, started_at)";
+
 pub const REFLEX_DB_SCHEMA: &str = "
     CREATE TABLE IF NOT EXISTS reflexes (
         id TEXT PRIMARY KEY,
@@ -329,7 +368,7 @@ pub const REFLEX_DB_SCHEMA: &str = "
 // --- 4. Transactional Bin (TODO) ---
 pub const TODO_DB_NAME: &str = "todo.db";
 pub const TODO_EVENTS_NAME: &str = "todo.events.jsonl";
-pub const TODO_SCHEMA_VERSION: u32 = 15;
+pub const TODO_SCHEMA_VERSION: u32 = 16;
 
 pub const TODO_DB_SCHEMA_META: &str = "
     CREATE TABLE IF NOT EXISTS meta (
@@ -363,7 +402,9 @@ pub const TODO_DB_SCHEMA_TASKS: &str = "
         component TEXT DEFAULT '',
         assigned_to TEXT DEFAULT '',
         assigned_at TEXT,
-        one_shot INTEGER DEFAULT 0
+        one_shot INTEGER DEFAULT 0,
+        claim_lease_secs INTEGER,
+        claim_expires_at TEXT
     )
 ";
 
@@ -582,6 +623,17 @@ pub const APTITUDE_DB_SCHEMA_OBSERVATIONS: &str = "
     )
 ";
 
+pub const APTITUDE_DB_SCHEMA_BEHAVIOR_OBSERVATIONS: &str = "
+    CREATE TABLE IF NOT EXISTS behavior_observations (
+        id TEXT PRIMARY KEY,
+        subject TEXT NOT NULL,
+        pattern TEXT NOT NULL,
+        evidence TEXT,
+        source TEXT NOT NULL,
+        created_at TEXT NOT NULL
+    )
+";
+
 pub const APTITUDE_DB_SCHEMA_CONSOLIDATIONS: &str = "
     CREATE TABLE IF NOT EXISTS consolidations (
         id TEXT PRIMARY KEY,
@@ -620,6 +672,7 @@ pub const APTITUDE_DB_SCHEMA_INDEX_PATTERN_CATEGORY: &str =
     "CREATE INDEX IF NOT EXISTS idx_patterns_category ON patterns(category)";
 pub const APTITUDE_DB_SCHEMA_INDEX_OBS_PROCESSED: &str =
     "CREATE INDEX IF NOT EXISTS idx_observations_processed ON observations(processed)";
+pub const APTITUDE_DB_SCHEMA_INDEX_BEHAVIOR_SUBJECT: &str = "CREATE INDEX IF NOT EXISTS idx_behavior_observations_subject ON behavior_observations(subject, pattern)";
 pub const APTITUDE_DB_SCHEMA_INDEX_PROMPT_CONTEXT: &str =
     "CREATE INDEX IF NOT EXISTS idx_agent_prompts_context ON agent_prompts(context)";
 
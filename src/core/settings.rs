@@ -0,0 +1,307 @@
+//! Repo-scoped runtime settings backed by `.decapod/config.toml`.
+//!
+//! Each knob has a dotted key (`validate.timeout_secs` lives under
+//! `[validate] timeout_secs`), one or more environment variables, and a
+//! built-in default. Precedence is env var > config file > default, so a
+//! committed value can always be overridden for a single invocation.
+
+use crate::core::error::DecapodError;
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Schema version written when `config set` creates the file.
+const CONFIG_SCHEMA_VERSION: &str = "1.0.0";
+
+static LOADED: OnceLock<Settings> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingKind {
+    /// Positive integer
+    Secs,
+    /// `true`/`false` (env vars use `1`)
+    Flag,
+}
+
+/// One configurable knob.
+#[derive(Debug, Clone, Copy)]
+pub struct SettingSpec {
+    pub key: &'static str,
+    pub env: &'static [&'static str],
+    pub kind: SettingKind,
+    pub default: &'static str,
+    pub description: &'static str,
+}
+
+pub const SETTINGS: &[SettingSpec] = &[
+    SettingSpec {
+        key: "session.ttl_secs",
+        env: &["DECAPOD_SESSION_TTL_SECS"],
+        kind: SettingKind::Secs,
+        default: "3600",
+        description: "Lifetime of an agent session token",
+    },
+    SettingSpec {
+        key: "session.require_agent_id",
+        env: &["DECAPOD_REQUIRE_AGENT_ID"],
+        kind: SettingKind::Flag,
+        default: "false",
+        description: "Refuse mutating commands when the agent id resolves to `unknown`",
+    },
+    SettingSpec {
+        key: "awareness.ttl_secs",
+        env: &["DECAPOD_AWARENESS_TTL_SECS"],
+        kind: SettingKind::Secs,
+        default: "86400",
+        description: "How long constitution ingestion counts before mutating ops demand re-ingestion",
+    },
+    SettingSpec {
+        key: "validate.timeout_secs",
+        env: &[
+            "DECAPOD_VALIDATE_TIMEOUT_SECS",
+            "DECAPOD_VALIDATE_TIMEOUT_SECONDS",
+        ],
+        kind: SettingKind::Secs,
+        default: "120",
+        description: "Wall-clock bound for a single `decapod validate` run",
+    },
+    SettingSpec {
+        key: "broker.idle_secs",
+        env: &["DECAPOD_GROUP_BROKER_IDLE_SECS"],
+        kind: SettingKind::Secs,
+        default: "3",
+        description: "Idle time before the group broker shuts down",
+    },
+    SettingSpec {
+        key: "broker.disable",
+        env: &["DECAPOD_GROUP_BROKER_DISABLE"],
+        kind: SettingKind::Flag,
+        default: "false",
+        description: "Run mutations directly instead of routing through the group broker",
+    },
+    SettingSpec {
+        key: "broker.enforce_route",
+        env: &["DECAPOD_GROUP_BROKER_ENFORCE_ROUTE"],
+        kind: SettingKind::Flag,
+        default: "false",
+        description: "Fail mutations that could not be routed through the group broker",
+    },
+];
+
+/// Where an effective value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingSource {
+    Env,
+    File,
+    Default,
+}
+
+impl SettingSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SettingSource::Env => "env",
+            SettingSource::File => "file",
+            SettingSource::Default => "default",
+        }
+    }
+}
+
+/// Typed view of every knob in [`SETTINGS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub session_ttl_secs: u64,
+    pub session_require_agent_id: bool,
+    pub awareness_ttl_secs: u64,
+    pub validate_timeout_secs: u64,
+    pub broker_idle_secs: u64,
+    pub broker_disable: bool,
+    pub broker_enforce_route: bool,
+}
+
+impl Settings {
+    /// Resolve every knob for `repo_root`, reading env vars through `env`.
+    pub fn resolve<F>(repo_root: Option<&Path>, env: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let file = repo_root.map(read_config_table).unwrap_or_default();
+        let secs = |key: &str| {
+            resolve_value(spec(key), &file, &env)
+                .0
+                .parse::<u64>()
+                .unwrap_or_default()
+        };
+        let flag = |key: &str| resolve_value(spec(key), &file, &env).0 == "true";
+        Self {
+            session_ttl_secs: secs("session.ttl_secs"),
+            session_require_agent_id: flag("session.require_agent_id"),
+            awareness_ttl_secs: secs("awareness.ttl_secs"),
+            validate_timeout_secs: secs("validate.timeout_secs"),
+            broker_idle_secs: secs("broker.idle_secs"),
+            broker_disable: flag("broker.disable"),
+            broker_enforce_route: flag("broker.enforce_route"),
+        }
+    }
+}
+
+fn process_env(key: &str) -> Option<String> {
+    std::env::var(key).ok()
+}
+
+/// Load settings for this process once; later calls return the first result.
+pub fn install(repo_root: Option<&Path>) -> &'static Settings {
+    LOADED.get_or_init(|| Settings::resolve(repo_root, process_env))
+}
+
+/// Settings for this process (env and defaults only if [`install`] never ran).
+pub fn current() -> &'static Settings {
+    LOADED.get_or_init(|| Settings::resolve(None, process_env))
+}
+
+fn spec(key: &str) -> &'static SettingSpec {
+    SETTINGS
+        .iter()
+        .find(|s| s.key == key)
+        .expect("setting registered in SETTINGS")
+}
+
+pub fn find_spec(key: &str) -> Result<&'static SettingSpec, DecapodError> {
+    SETTINGS.iter().find(|s| s.key == key).ok_or_else(|| {
+        DecapodError::ValidationError(format!(
+            "Unknown config key '{}'. Known keys: {}",
+            key,
+            SETTINGS
+                .iter()
+                .map(|s| s.key)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    })
+}
+
+/// Normalize a raw value for `kind`, or `None` if it does not parse.
+fn normalize(kind: SettingKind, raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    match kind {
+        SettingKind::Secs => raw
+            .parse::<u64>()
+            .ok()
+            .filter(|v| *v > 0)
+            .map(|v| v.to_string()),
+        SettingKind::Flag => match raw {
+            "1" | "true" => Some("true".to_string()),
+            "0" | "false" => Some("false".to_string()),
+            _ => None,
+        },
+    }
+}
+
+fn file_value(table: &toml::Table, key: &str) -> Option<String> {
+    let (section, field) = key.split_once('.')?;
+    match table.get(section)?.get(field)? {
+        toml::Value::Integer(i) => Some(i.to_string()),
+        toml::Value::Boolean(b) => Some(b.to_string()),
+        toml::Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+fn resolve_value<F>(spec: &SettingSpec, file: &toml::Table, env: &F) -> (String, SettingSource)
+where
+    F: Fn(&str) -> Option<String>,
+{
+    if let Some(v) = spec
+        .env
+        .iter()
+        .find_map(|k| env(k).and_then(|raw| normalize(spec.kind, &raw)))
+    {
+        return (v, SettingSource::Env);
+    }
+    if let Some(v) = file_value(file, spec.key).and_then(|raw| normalize(spec.kind, &raw)) {
+        return (v, SettingSource::File);
+    }
+    (spec.default.to_string(), SettingSource::Default)
+}
+
+pub fn config_path(repo_root: &Path) -> PathBuf {
+    repo_root.join(".decapod").join("config.toml")
+}
+
+fn read_config_table(repo_root: &Path) -> toml::Table {
+    std::fs::read_to_string(config_path(repo_root))
+        .ok()
+        .and_then(|raw| toml::from_str::<toml::Table>(&raw).ok())
+        .unwrap_or_default()
+}
+
+/// Deserialize one `[section]` of the config; a missing file or section yields defaults.
+pub fn load_section<T: DeserializeOwned + Default>(repo_root: &Path, section: &str) -> T {
+    read_config_table(repo_root)
+        .remove(section)
+        .and_then(|value| value.try_into().ok())
+        .unwrap_or_default()
+}
+
+/// Effective value of `key` and where it came from, using the process env.
+pub fn effective(repo_root: &Path, key: &str) -> Result<(String, SettingSource), DecapodError> {
+    let spec = find_spec(key)?;
+    Ok(resolve_value(
+        spec,
+        &read_config_table(repo_root),
+        &process_env,
+    ))
+}
+
+/// Persist `key = value` in `.decapod/config.toml`, keeping every other entry.
+pub fn set(repo_root: &Path, key: &str, value: &str) -> Result<(), DecapodError> {
+    let spec = find_spec(key)?;
+    let normalized = normalize(spec.kind, value).ok_or_else(|| {
+        DecapodError::ValidationError(format!(
+            "Invalid value '{}' for '{}': expected {}",
+            value,
+            key,
+            match spec.kind {
+                SettingKind::Secs => "a positive integer",
+                SettingKind::Flag => "true or false",
+            }
+        ))
+    })?;
+    let toml_value = match spec.kind {
+        SettingKind::Secs => toml::Value::Integer(normalized.parse().unwrap_or_default()),
+        SettingKind::Flag => toml::Value::Boolean(normalized == "true"),
+    };
+
+    let path = config_path(repo_root);
+    let mut table = if path.exists() {
+        let raw = std::fs::read_to_string(&path).map_err(DecapodError::IoError)?;
+        toml::from_str::<toml::Table>(&raw).map_err(|e| {
+            DecapodError::ValidationError(format!("Invalid .decapod/config.toml syntax: {}", e))
+        })?
+    } else {
+        let mut fresh = toml::Table::new();
+        fresh.insert(
+            "schema_version".to_string(),
+            toml::Value::String(CONFIG_SCHEMA_VERSION.to_string()),
+        );
+        fresh
+    };
+    let (section, field) = key.split_once('.').unwrap_or((key, key));
+    let entry = table
+        .entry(section.to_string())
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    let Some(section_table) = entry.as_table_mut() else {
+        return Err(DecapodError::ValidationError(format!(
+            "Cannot set '{}': '{}' in .decapod/config.toml is not a table",
+            key, section
+        )));
+    };
+    section_table.insert(field.to_string(), toml_value);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(DecapodError::IoError)?;
+    }
+    let serialized = toml::to_string_pretty(&table).map_err(|e| {
+        DecapodError::ValidationError(format!("Failed to serialize config.toml: {}", e))
+    })?;
+    std::fs::write(&path, serialized).map_err(DecapodError::IoError)
+}
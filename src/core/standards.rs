@@ -2,10 +2,17 @@
 //!
 //! Resolves industry defaults + project override.md into resolved standards
 //! that agents can query for consistent behavior.
+//!
+//! Layers are applied in ascending precedence, later layers winning per key:
+//! 1. `embedded`: built-in industry defaults
+//! 2. `profile`: `.decapod/STANDARDS.md`, seeded by `decapod init --profile`
+//! 3. `repo`: `.decapod/OVERRIDE.md`
+//! 4. `branch`: `.decapod/overrides/<branch>.md` for the checked-out branch/worktree,
+//!    with the branch name percent-encoded (see [`branch_override_file_name`])
 
 use crate::core::error::DecapodError;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 /// Resolved standards for a project
@@ -19,6 +26,39 @@ pub struct ResolvedStandards {
     pub override_path: Option<PathBuf>,
     /// When these standards were resolved
     pub resolved_at: String,
+    /// Winning layer per resolved key (`category.key`, or `category` for non-object values)
+    #[serde(default)]
+    pub sources: HashMap<String, StandardsLayer>,
+    /// Layers considered during resolution, in ascending precedence
+    #[serde(default)]
+    pub layers: Vec<LayerInfo>,
+}
+
+/// Source layer of a resolved standard, ordered by ascending precedence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StandardsLayer {
+    /// Built-in industry defaults
+    Embedded,
+    /// Starter standards from an init profile at `.decapod/STANDARDS.md`
+    Profile,
+    /// Repository-wide `.decapod/OVERRIDE.md`
+    Repo,
+    /// Branch/worktree override at `.decapod/overrides/<branch>.md`
+    Branch,
+}
+
+/// Starter standards file written by `decapod init --profile`, under `.decapod/`.
+pub const PROFILE_STANDARDS_FILE: &str = "STANDARDS.md";
+
+/// Description of one layer considered during resolution
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LayerInfo {
+    pub layer: StandardsLayer,
+    /// Backing file, if the layer is file-based
+    pub path: Option<PathBuf>,
+    /// Whether the layer contributed values
+    pub applied: bool,
 }
 
 /// A standard value (can be any JSON-compatible type)
@@ -107,15 +147,13 @@ fn default_standards() -> HashMap<String, StandardValue> {
     standards
 }
 
-/// Read override.md from project root
-fn read_override_file(project_root: &Path) -> Option<HashMap<String, StandardValue>> {
-    let override_path = project_root.join(".decapod").join("OVERRIDE.md");
-
+/// Read a markdown override file (OVERRIDE.md or a branch override)
+fn read_override_file(override_path: &Path) -> Option<HashMap<String, StandardValue>> {
     if !override_path.exists() {
         return None;
     }
 
-    let content = std::fs::read_to_string(&override_path).ok()?;
+    let content = std::fs::read_to_string(override_path).ok()?;
 
     // Parse simple key: value format from markdown
     // Format: ## Section, then key: value pairs
@@ -162,34 +200,125 @@ fn read_override_file(project_root: &Path) -> Option<HashMap<String, StandardVal
     Some(overrides)
 }
 
-/// Resolve standards by merging defaults with overrides
+/// Path of the branch/worktree override for the currently checked-out branch, if any.
+fn branch_override_path(project_root: &Path) -> Option<PathBuf> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(project_root)
+        .args(["branch", "--show-current"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if branch.is_empty() {
+        return None;
+    }
+    Some(
+        project_root
+            .join(".decapod")
+            .join("overrides")
+            .join(branch_override_file_name(&branch)),
+    )
+}
+
+/// File name of the override for `branch` under `.decapod/overrides/`.
+///
+/// Bytes outside `[A-Za-z0-9._-]` are percent-encoded (`/` becomes `%2F`,
+/// `%` becomes `%25`), so distinct branches never share an override file.
+pub fn branch_override_file_name(branch: &str) -> String {
+    let mut name = String::with_capacity(branch.len() + 3);
+    for byte in branch.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'_' | b'-') {
+            name.push(byte as char);
+        } else {
+            name.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    name.push_str(".md");
+    name
+}
+
+/// Merge `value` into `standards[key]`, recording `layer` as the source of every key it sets.
+fn apply_layer_value(
+    standards: &mut HashMap<String, StandardValue>,
+    sources: &mut HashMap<String, StandardsLayer>,
+    key: String,
+    value: StandardValue,
+    layer: StandardsLayer,
+) {
+    // Merge objects, replace primitives
+    match (standards.get(&key), &value) {
+        (Some(serde_json::Value::Object(existing_obj)), serde_json::Value::Object(layer_obj)) => {
+            let mut merged = existing_obj.clone();
+            for (k, v) in layer_obj {
+                merged.insert(k.clone(), v.clone());
+                sources.insert(format!("{}.{}", key, k), layer);
+            }
+            standards.insert(key, serde_json::Value::Object(merged));
+        }
+        _ => {
+            sources.retain(|k, _| k != &key && !k.starts_with(&format!("{}.", key)));
+            if let serde_json::Value::Object(obj) = &value {
+                for k in obj.keys() {
+                    sources.insert(format!("{}.{}", key, k), layer);
+                }
+            } else {
+                sources.insert(key.clone(), layer);
+            }
+            standards.insert(key, value);
+        }
+    }
+}
+
+/// Resolve standards by merging defaults with overrides, layer by layer
 pub fn resolve_standards(project_root: &Path) -> Result<ResolvedStandards, DecapodError> {
-    let mut standards = default_standards();
+    let mut standards = HashMap::new();
+    let mut sources = HashMap::new();
+    for (key, value) in default_standards() {
+        apply_layer_value(
+            &mut standards,
+            &mut sources,
+            key,
+            value,
+            StandardsLayer::Embedded,
+        );
+    }
+    let mut layers = vec![LayerInfo {
+        layer: StandardsLayer::Embedded,
+        path: None,
+        applied: true,
+    }];
+
     let override_path = project_root.join(".decapod").join("OVERRIDE.md");
     let has_override = override_path.exists();
 
-    // Apply overrides
-    if let Some(overrides) = read_override_file(project_root) {
+    let file_layers = [
+        (
+            StandardsLayer::Profile,
+            Some(project_root.join(".decapod").join(PROFILE_STANDARDS_FILE)),
+        ),
+        (StandardsLayer::Repo, Some(override_path.clone())),
+        (StandardsLayer::Branch, branch_override_path(project_root)),
+    ];
+    for (layer, path) in file_layers {
+        let Some(path) = path else {
+            continue;
+        };
+        let overrides = read_override_file(&path);
+        let applied = overrides.as_ref().is_some_and(|o| !o.is_empty());
+        // Sort for deterministic source attribution across runs.
+        let mut overrides: Vec<_> = overrides.unwrap_or_default().into_iter().collect();
+        overrides.sort_by(|a, b| a.0.cmp(&b.0));
         for (key, value) in overrides {
-            // Merge objects, replace primitives
-            if let Some(existing) = standards.get(&key) {
-                if let (
-                    serde_json::Value::Object(existing_obj),
-                    serde_json::Value::Object(override_obj),
-                ) = (existing, &value)
-                {
-                    let mut merged = existing_obj.clone();
-                    for (k, v) in override_obj {
-                        merged.insert(k.clone(), v.clone());
-                    }
-                    standards.insert(key, serde_json::Value::Object(merged));
-                } else {
-                    standards.insert(key, value);
-                }
-            } else {
-                standards.insert(key, value);
-            }
+            apply_layer_value(&mut standards, &mut sources, key, value, layer);
         }
+        layers.push(LayerInfo {
+            layer,
+            path: Some(path),
+            applied,
+        });
     }
 
     // Extract project name from override or use directory name
@@ -219,9 +348,40 @@ pub fn resolve_standards(project_root: &Path) -> Result<ResolvedStandards, Decap
             None
         },
         resolved_at: crate::core::time::now_epoch_z(),
+        sources,
+        layers,
     })
 }
 
+/// SHA-256 over the effective standards and their source layers, in key
+/// order. `resolved_at` and layer file paths are left out, so the fingerprint
+/// only moves when a resolved value (or the layer supplying it) changes.
+pub fn standards_fingerprint(standards: &ResolvedStandards) -> String {
+    use sha2::{Digest, Sha256};
+    let canonical = serde_json::json!({
+        "project_name": standards.project_name,
+        "standards": standards.standards.iter().collect::<BTreeMap<_, _>>(),
+        "sources": standards.sources.iter().collect::<BTreeMap<_, _>>(),
+    });
+    format!(
+        "{:x}",
+        Sha256::digest(serde_json::to_vec(&canonical).unwrap_or_default())
+    )
+}
+
+/// Source layer that supplied `category.key`, if resolved
+pub fn standard_source(
+    standards: &ResolvedStandards,
+    category: &str,
+    key: &str,
+) -> Option<StandardsLayer> {
+    standards
+        .sources
+        .get(&format!("{}.{}", category, key))
+        .or_else(|| standards.sources.get(category))
+        .copied()
+}
+
 /// Get a specific standard value
 pub fn get_standard(
     standards: &ResolvedStandards,
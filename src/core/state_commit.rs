@@ -1,5 +1,8 @@
 use sha2::{Digest, Sha256};
-use std::path::Path;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
 
 pub struct StateCommitInput {
     pub base_sha: String,
@@ -9,21 +12,73 @@ pub struct StateCommitInput {
 
 #[derive(Clone)]
 pub struct StateCommitEntry {
-    pub path: String,
+    /// Repo-relative path exactly as git reports it; not necessarily UTF-8.
+    pub path: Vec<u8>,
     pub kind: u8, // 0 = file, 1 = symlink
     pub mode_exec: bool,
     pub content_hash: String,
     pub size: u64,
 }
 
+impl StateCommitEntry {
+    /// The path for display, with invalid UTF-8 replaced.
+    pub fn display_path(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.path)
+    }
+}
+
 pub struct StateCommitOutput {
     pub scope_record_bytes: Vec<u8>,
     pub scope_record_hash: String,
     pub state_commit_root: String,
     pub entries: Vec<StateCommitEntry>,
+    /// Entries carried over from a prior record instead of re-hashed.
+    pub reused_entries: usize,
+}
+
+/// Newest record format `prove` writes.
+///
+/// v2 hashes non-UTF-8 content as raw bytes and keeps non-UTF-8 paths as CBOR
+/// byte strings; v1 records hashed a lossy UTF-8 decode of the content. The two
+/// agree whenever every path and blob is UTF-8, and such records are still
+/// written as v1 so their bytes (and the v1 golden vectors) do not change.
+pub const RECORD_VERSION: u64 = 2;
+
+/// Oldest format that records `entries` exactly: v2 only when a path or a
+/// blob (`all_content_utf8 == false`) is not UTF-8.
+fn record_version_for(entries: &[StateCommitEntry], all_content_utf8: bool) -> u64 {
+    if all_content_utf8 && entries.iter().all(|e| std::str::from_utf8(&e.path).is_ok()) {
+        1
+    } else {
+        RECORD_VERSION
+    }
+}
+
+fn algo_version(record_version: u64) -> String {
+    format!("state_commit.v{}", record_version)
+}
+
+/// Decoded form of a `scope_record.cbor`.
+pub struct ScopeRecord {
+    pub algo_version: String,
+    pub base_sha: String,
+    pub head_sha: String,
+    pub record_version: u64,
+    pub ignore_policy_hash: String,
+    pub entries: Vec<StateCommitEntry>,
 }
 
 pub fn run_git(repo_root: &Path, args: &[&str]) -> Result<String, String> {
+    let stdout = run_git_raw(repo_root, args.iter().map(OsStr::new))?;
+    Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+}
+
+/// Run git and return stdout untouched, for output that carries paths or blobs.
+fn run_git_raw<I, S>(repo_root: &Path, args: I) -> Result<Vec<u8>, String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
     let output = std::process::Command::new("git")
         .args(args)
         .current_dir(repo_root)
@@ -37,40 +92,54 @@ pub fn run_git(repo_root: &Path, args: &[&str]) -> Result<String, String> {
         ));
     }
 
-    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    Ok(output.stdout)
+}
+
+/// Raw path bytes from git as an `OsString`; lossless on unix.
+#[cfg(unix)]
+pub(crate) fn os_from_bytes(bytes: Vec<u8>) -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    OsString::from_vec(bytes)
+}
+
+#[cfg(not(unix))]
+pub(crate) fn os_from_bytes(bytes: Vec<u8>) -> OsString {
+    OsString::from(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Map a git path onto the checkout under `repo_root`.
+fn worktree_path(repo_root: &Path, path: &[u8]) -> PathBuf {
+    repo_root.join(os_from_bytes(path.to_vec()))
 }
 
-pub fn git_show(repo_root: &Path, sha: &str, path: &str) -> Result<String, String> {
-    run_git(repo_root, &["show", &format!("{}:{}", sha, path)])
+/// Raw blob bytes of `path` at `sha`.
+pub fn git_show(repo_root: &Path, sha: &str, path: &[u8]) -> Result<Vec<u8>, String> {
+    let mut spec = format!("{}:", sha).into_bytes();
+    spec.extend_from_slice(path);
+    run_git_raw(repo_root, [OsString::from("show"), os_from_bytes(spec)])
 }
 
-pub fn git_ls_tree(repo_root: &Path, sha: &str, path: &str) -> Result<String, String> {
-    run_git(repo_root, &["ls-tree", "-r", sha, "--", path])
+pub fn git_ls_tree(repo_root: &Path, sha: &str, path: &[u8]) -> Result<String, String> {
+    git_ls_repo(repo_root, sha, path)
 }
 
+/// Paths changed between `base_sha` and `head_sha`, as raw bytes.
+///
+/// `-z` keeps git from quoting or escaping unusual bytes, so each path is
+/// exactly what the tree records.
 pub fn get_path_set(
     repo_root: &Path,
     base_sha: &str,
     head_sha: &str,
-) -> Result<Vec<String>, String> {
+) -> Result<Vec<Vec<u8>>, String> {
     // Use three-dot syntax for merge-base diff (common ancestor...HEAD)
     // or space-separated for explicit range
-    let output = run_git(
-        repo_root,
-        &[
-            "-c",
-            "core.quotepath=false",
-            "diff",
-            "--name-only",
-            base_sha,
-            head_sha,
-        ],
-    )?;
+    let output = run_git_raw(repo_root, ["diff", "-z", "--name-only", base_sha, head_sha])?;
 
     Ok(output
-        .lines()
-        .map(|s| s.to_string())
+        .split(|b| *b == 0)
         .filter(|s| !s.is_empty())
+        .map(<[u8]>::to_vec)
         .collect())
 }
 
@@ -88,7 +157,20 @@ fn parse_ls_tree_line(line: &str) -> Option<(String, String)> {
     Some((mode, oid))
 }
 
-pub fn get_entry(repo_root: &Path, head_sha: &str, path: &str) -> Result<StateCommitEntry, String> {
+pub fn get_entry(
+    repo_root: &Path,
+    head_sha: &str,
+    path: &[u8],
+) -> Result<StateCommitEntry, String> {
+    hashed_entry(repo_root, head_sha, path).map(|(entry, _)| entry)
+}
+
+/// [`get_entry`] plus whether the blob was valid UTF-8.
+fn hashed_entry(
+    repo_root: &Path,
+    head_sha: &str,
+    path: &[u8],
+) -> Result<(StateCommitEntry, bool), String> {
     let line = git_ls_repo(repo_root, head_sha, path)?;
     let (mode, _oid) = parse_ls_tree_line(&line).ok_or("failed to parse ls-tree")?;
 
@@ -96,24 +178,137 @@ pub fn get_entry(repo_root: &Path, head_sha: &str, path: &str) -> Result<StateCo
     let mode_exec = mode == "100755";
 
     let content = git_show(repo_root, head_sha, path)?;
-    let content_bytes = content.as_bytes();
-    let size = content_bytes.len() as u64;
+    let (content_hash, size) = digest_content(&content, RECORD_VERSION);
+
+    Ok((
+        StateCommitEntry {
+            path: path.to_vec(),
+            kind,
+            mode_exec,
+            content_hash,
+            size,
+        },
+        std::str::from_utf8(&content).is_ok(),
+    ))
+}
 
+/// Hash content with surrounding whitespace trimmed, as `record_version` does.
+///
+/// UTF-8 content is trimmed as text in every version. v1 hashed anything else
+/// through a lossy UTF-8 decode; v2 hashes it as raw bytes with ASCII
+/// whitespace trimmed, so distinct binary blobs never collapse onto the same
+/// replacement-character digest.
+fn digest_content(content: &[u8], record_version: u64) -> (String, u64) {
+    let lossy;
+    let content_bytes = match std::str::from_utf8(content) {
+        Ok(text) => text.trim().as_bytes(),
+        Err(_) if record_version < 2 => {
+            lossy = String::from_utf8_lossy(content);
+            lossy.trim().as_bytes()
+        }
+        Err(_) => content.trim_ascii(),
+    };
     let mut hasher = Sha256::new();
     hasher.update(content_bytes);
-    let content_hash = format!("{:x}", hasher.finalize());
+    (
+        format!("{:x}", hasher.finalize()),
+        content_bytes.len() as u64,
+    )
+}
+
+/// A scoped path whose checkout no longer matches its recorded entry.
+pub struct WorktreeDivergence {
+    pub path: Vec<u8>,
+    /// `missing`, `kind`, `mode`, or `content`.
+    pub reason: &'static str,
+    pub recorded_hash: String,
+    pub current_hash: Option<String>,
+}
+
+/// Derive an entry for `path` from the checkout rather than a commit, hashed
+/// the way `record_version` records hash content.
+pub fn worktree_entry(
+    repo_root: &Path,
+    path: &[u8],
+    record_version: u64,
+) -> Result<Option<StateCommitEntry>, String> {
+    let full = worktree_path(repo_root, path);
+    let shown = String::from_utf8_lossy(path);
+    let meta = match std::fs::symlink_metadata(&full) {
+        Ok(meta) => meta,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("failed to stat {}: {}", shown, e)),
+    };
+
+    let (kind, content) = if meta.file_type().is_symlink() {
+        let target = std::fs::read_link(&full)
+            .map_err(|e| format!("failed to read link {}: {}", shown, e))?;
+        (1, target.into_os_string().into_encoded_bytes())
+    } else {
+        let bytes = std::fs::read(&full).map_err(|e| format!("failed to read {}: {}", shown, e))?;
+        (0, bytes)
+    };
+
+    #[cfg(unix)]
+    let mode_exec = {
+        use std::os::unix::fs::PermissionsExt;
+        kind == 0 && meta.permissions().mode() & 0o111 != 0
+    };
+    #[cfg(not(unix))]
+    let mode_exec = false;
 
-    Ok(StateCommitEntry {
-        path: path.to_string(),
+    let (content_hash, size) = digest_content(&content, record_version);
+    Ok(Some(StateCommitEntry {
+        path: path.to_vec(),
         kind,
         mode_exec,
         content_hash,
         size,
-    })
+    }))
+}
+
+/// Compare every entry in `record` against the current checkout.
+pub fn diff_against_worktree(
+    record: &ScopeRecord,
+    repo_root: &Path,
+) -> Result<Vec<WorktreeDivergence>, String> {
+    let mut diverged = Vec::new();
+    for recorded in &record.entries {
+        let current = worktree_entry(repo_root, &recorded.path, record.record_version)?;
+        let reason = match &current {
+            None => Some("missing"),
+            Some(c) if c.kind != recorded.kind => Some("kind"),
+            Some(c) if c.mode_exec != recorded.mode_exec => Some("mode"),
+            Some(c) if c.content_hash != recorded.content_hash => Some("content"),
+            Some(_) => None,
+        };
+        if let Some(reason) = reason {
+            diverged.push(WorktreeDivergence {
+                path: recorded.path.clone(),
+                reason,
+                recorded_hash: recorded.content_hash.clone(),
+                current_hash: current.map(|c| c.content_hash),
+            });
+        }
+    }
+    Ok(diverged)
 }
 
-pub fn git_ls_repo(repo_root: &Path, sha: &str, path: &str) -> Result<String, String> {
-    run_git(repo_root, &["ls-tree", "-r", sha, "--", path])
+/// `ls-tree` line for exactly `path`; `--literal-pathspecs` keeps glob
+/// characters in file names from matching other paths.
+pub fn git_ls_repo(repo_root: &Path, sha: &str, path: &[u8]) -> Result<String, String> {
+    let stdout = run_git_raw(
+        repo_root,
+        [
+            OsString::from("--literal-pathspecs"),
+            OsString::from("ls-tree"),
+            OsString::from("-r"),
+            OsString::from(sha),
+            OsString::from("--"),
+            os_from_bytes(path.to_vec()),
+        ],
+    )?;
+    Ok(String::from_utf8_lossy(&stdout).trim().to_string())
 }
 
 fn encode_uint(v: u64) -> Vec<u8> {
@@ -129,14 +324,26 @@ fn encode_uint(v: u64) -> Vec<u8> {
 }
 
 fn encode_string(s: &str) -> Vec<u8> {
-    let data = s.as_bytes();
+    encode_with_major(0x60, s.as_bytes())
+}
+
+/// Paths are CBOR text when they are UTF-8 (every v1 record so far) and a
+/// byte string otherwise, so the exact bytes survive the round trip.
+fn encode_path(path: &[u8]) -> Vec<u8> {
+    match std::str::from_utf8(path) {
+        Ok(text) => encode_string(text),
+        Err(_) => encode_with_major(0x40, path),
+    }
+}
+
+fn encode_with_major(major: u8, data: &[u8]) -> Vec<u8> {
     let length = data.len();
     if length < 24 {
-        let mut r = vec![0x60 + length as u8];
+        let mut r = vec![major + length as u8];
         r.extend_from_slice(data);
         r
     } else if length < 256 {
-        let mut r = vec![0x78, length as u8];
+        let mut r = vec![major + 24, length as u8];
         r.extend_from_slice(data);
         r
     } else {
@@ -186,14 +393,15 @@ pub fn compute_scope_record(
     base_sha: &str,
     head_sha: &str,
     ignore_policy_hash: &str,
+    record_version: u64,
 ) -> Vec<u8> {
     let mut sorted_entries = entries.to_vec();
-    sorted_entries.sort_by(|a, b| a.path.as_bytes().cmp(b.path.as_bytes()));
+    sorted_entries.sort_by(|a, b| a.path.cmp(&b.path));
 
     let mut entry_arrays = Vec::new();
     for e in &sorted_entries {
         entry_arrays.push(encode_array(&[
-            encode_string(&e.path),
+            encode_path(&e.path),
             encode_uint(e.kind as u64),
             encode_bool(e.mode_exec),
             encode_string(&e.content_hash),
@@ -204,10 +412,10 @@ pub fn compute_scope_record(
     let entries_bytes = encode_array(&entry_arrays);
 
     encode_map(&[
-        (1, encode_string("state_commit.v1")),
+        (1, encode_string(&algo_version(record_version))),
         (2, encode_string(base_sha)),
         (3, encode_string(head_sha)),
-        (4, encode_uint(1)),
+        (4, encode_uint(record_version)),
         (5, encode_string(ignore_policy_hash)),
         (6, entries_bytes),
     ])
@@ -215,12 +423,12 @@ pub fn compute_scope_record(
 
 pub fn compute_merkle_root(entries: &[StateCommitEntry]) -> String {
     let mut sorted_entries = entries.to_vec();
-    sorted_entries.sort_by(|a, b| a.path.as_bytes().cmp(b.path.as_bytes()));
+    sorted_entries.sort_by(|a, b| a.path.cmp(&b.path));
 
     let mut leaf_hashes = Vec::new();
     for e in &sorted_entries {
         let leaf = encode_array(&[
-            encode_string(&e.path),
+            encode_path(&e.path),
             encode_uint(e.kind as u64),
             encode_bool(e.mode_exec),
             encode_string(&e.content_hash),
@@ -253,16 +461,86 @@ pub fn prove(input: &StateCommitInput, repo_root: &Path) -> Result<StateCommitOu
     let paths = get_path_set(repo_root, &input.base_sha, &input.head_sha)?;
 
     let mut entries = Vec::new();
+    let mut all_utf8 = true;
+    for path in &paths {
+        let (entry, utf8) = hashed_entry(repo_root, &input.head_sha, path)?;
+        entries.push(entry);
+        all_utf8 &= utf8;
+    }
+
+    Ok(finish_output(input, entries, 0, all_utf8))
+}
+
+/// Prove `input` reusing content hashes from `prior`, a record over the same base.
+///
+/// Only paths whose blob changed between the prior head and `input.head_sha` are
+/// re-hashed, so the result is byte-identical to a full `prove`. Unchanged blobs
+/// are still read to learn whether they are UTF-8, which picks the record
+/// version; a v1 digest of a non-UTF-8 blob went through a lossy decode, so it
+/// is re-hashed rather than carried over.
+pub fn prove_since(
+    input: &StateCommitInput,
+    repo_root: &Path,
+    prior: &ScopeRecord,
+) -> Result<StateCommitOutput, String> {
+    let prior_base = resolve_commit(repo_root, &prior.base_sha)?;
+    let base = resolve_commit(repo_root, &input.base_sha)?;
+    if prior_base != base {
+        return Err(format!(
+            "prior record base {} does not match base {}",
+            prior.base_sha, input.base_sha
+        ));
+    }
+
+    let changed: HashSet<Vec<u8>> = get_path_set(repo_root, &prior.head_sha, &input.head_sha)?
+        .into_iter()
+        .collect();
+    let prior_entries: HashMap<&[u8], &StateCommitEntry> = prior
+        .entries
+        .iter()
+        .map(|e| (e.path.as_slice(), e))
+        .collect();
+
+    let paths = get_path_set(repo_root, &input.base_sha, &input.head_sha)?;
+    let mut entries = Vec::new();
+    let mut all_utf8 = true;
+    let mut reused = 0;
     for path in &paths {
-        let entry = get_entry(repo_root, &input.head_sha, path)?;
+        if let Some(entry) = prior_entries.get(path.as_slice())
+            && !changed.contains(path)
+        {
+            let utf8 = std::str::from_utf8(&git_show(repo_root, &input.head_sha, path)?).is_ok();
+            if utf8 || prior.record_version >= 2 {
+                entries.push((*entry).clone());
+                all_utf8 &= utf8;
+                reused += 1;
+                continue;
+            }
+        }
+        let (entry, utf8) = hashed_entry(repo_root, &input.head_sha, path)?;
         entries.push(entry);
+        all_utf8 &= utf8;
     }
 
+    Ok(finish_output(input, entries, reused, all_utf8))
+}
+
+fn resolve_commit(repo_root: &Path, rev: &str) -> Result<String, String> {
+    run_git(repo_root, &["rev-parse", &format!("{}^{{commit}}", rev)])
+}
+
+fn finish_output(
+    input: &StateCommitInput,
+    entries: Vec<StateCommitEntry>,
+    reused_entries: usize,
+    all_content_utf8: bool,
+) -> StateCommitOutput {
     let scope_record_bytes = compute_scope_record(
         &entries,
         &input.base_sha,
         &input.head_sha,
         &input.ignore_policy_hash,
+        record_version_for(&entries, all_content_utf8),
     );
 
     let mut hasher = Sha256::new();
@@ -271,11 +549,167 @@ pub fn prove(input: &StateCommitInput, repo_root: &Path) -> Result<StateCommitOu
 
     let state_commit_root = compute_merkle_root(&entries);
 
-    Ok(StateCommitOutput {
+    StateCommitOutput {
         scope_record_bytes,
         scope_record_hash,
         state_commit_root,
         entries,
+        reused_entries,
+    }
+}
+
+/// Minimal CBOR value model covering what `compute_scope_record` emits.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CborValue {
+    Uint(u64),
+    Bytes(Vec<u8>),
+    Text(String),
+    Bool(bool),
+    Array(Vec<CborValue>),
+    Map(Vec<(CborValue, CborValue)>),
+}
+
+pub fn decode_cbor(bytes: &[u8]) -> Result<CborValue, String> {
+    let mut pos = 0;
+    let value = decode_item(bytes, &mut pos)?;
+    if pos != bytes.len() {
+        return Err(format!("trailing bytes after CBOR item at offset {}", pos));
+    }
+    Ok(value)
+}
+
+fn take<'a>(bytes: &'a [u8], pos: &mut usize, n: usize) -> Result<&'a [u8], String> {
+    let end = pos
+        .checked_add(n)
+        .filter(|end| *end <= bytes.len())
+        .ok_or_else(|| format!("truncated CBOR at offset {}", pos))?;
+    let slice = &bytes[*pos..end];
+    *pos = end;
+    Ok(slice)
+}
+
+fn decode_argument(bytes: &[u8], pos: &mut usize, info: u8) -> Result<u64, String> {
+    let width = match info {
+        0..=23 => return Ok(info as u64),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return Err(format!("unsupported CBOR length encoding {}", info)),
+    };
+    Ok(take(bytes, pos, width)?
+        .iter()
+        .fold(0u64, |acc, b| (acc << 8) | *b as u64))
+}
+
+fn decode_item(bytes: &[u8], pos: &mut usize) -> Result<CborValue, String> {
+    let initial = take(bytes, pos, 1)?[0];
+    let major = initial >> 5;
+    let info = initial & 0x1f;
+    match major {
+        0 => Ok(CborValue::Uint(decode_argument(bytes, pos, info)?)),
+        2 => {
+            let len = decode_argument(bytes, pos, info)? as usize;
+            Ok(CborValue::Bytes(take(bytes, pos, len)?.to_vec()))
+        }
+        3 => {
+            let len = decode_argument(bytes, pos, info)? as usize;
+            let raw = take(bytes, pos, len)?;
+            String::from_utf8(raw.to_vec())
+                .map(CborValue::Text)
+                .map_err(|e| format!("invalid UTF-8 in CBOR text: {}", e))
+        }
+        4 => {
+            let len = decode_argument(bytes, pos, info)?;
+            let mut items = Vec::new();
+            for _ in 0..len {
+                items.push(decode_item(bytes, pos)?);
+            }
+            Ok(CborValue::Array(items))
+        }
+        5 => {
+            let len = decode_argument(bytes, pos, info)?;
+            let mut pairs = Vec::new();
+            for _ in 0..len {
+                let k = decode_item(bytes, pos)?;
+                let v = decode_item(bytes, pos)?;
+                pairs.push((k, v));
+            }
+            Ok(CborValue::Map(pairs))
+        }
+        7 if info == 20 => Ok(CborValue::Bool(false)),
+        7 if info == 21 => Ok(CborValue::Bool(true)),
+        _ => Err(format!(
+            "unsupported CBOR item 0x{:02x} at offset {}",
+            initial,
+            *pos - 1
+        )),
+    }
+}
+
+pub fn decode_scope_record(bytes: &[u8]) -> Result<ScopeRecord, String> {
+    let CborValue::Map(pairs) = decode_cbor(bytes)? else {
+        return Err("scope record is not a CBOR map".to_string());
+    };
+    let field = |key: u64| {
+        pairs
+            .iter()
+            .find(|(k, _)| *k == CborValue::Uint(key))
+            .map(|(_, v)| v)
+            .ok_or_else(|| format!("scope record missing field {}", key))
+    };
+    let text = |key: u64| match field(key)? {
+        CborValue::Text(s) => Ok(s.clone()),
+        _ => Err(format!("scope record field {} is not text", key)),
+    };
+
+    let record_version = match field(4)? {
+        CborValue::Uint(v) => *v,
+        _ => return Err("scope record field 4 is not a uint".to_string()),
+    };
+    if !(1..=RECORD_VERSION).contains(&record_version) {
+        return Err(format!(
+            "unsupported scope record version {}",
+            record_version
+        ));
+    }
+    let CborValue::Array(raw_entries) = field(6)? else {
+        return Err("scope record field 6 is not an array".to_string());
+    };
+    let mut entries = Vec::new();
+    for (i, raw) in raw_entries.iter().enumerate() {
+        match raw {
+            CborValue::Array(items) => match items.as_slice() {
+                [
+                    path,
+                    CborValue::Uint(kind),
+                    CborValue::Bool(mode_exec),
+                    CborValue::Text(content_hash),
+                    CborValue::Uint(size),
+                ] => entries.push(StateCommitEntry {
+                    path: match path {
+                        CborValue::Text(text) => text.as_bytes().to_vec(),
+                        CborValue::Bytes(raw) => raw.clone(),
+                        _ => return Err(format!("scope record entry {} path is malformed", i)),
+                    },
+                    kind: *kind as u8,
+                    mode_exec: *mode_exec,
+                    content_hash: content_hash.clone(),
+                    size: *size,
+                }),
+                _ => return Err(format!("scope record entry {} is malformed", i)),
+            },
+            _ => return Err(format!("scope record entry {} is not an array", i)),
+        }
+    }
+
+    Ok(ScopeRecord {
+        algo_version: text(1)?,
+        base_sha: text(2)?,
+        head_sha: text(3)?,
+        record_version,
+        ignore_policy_hash: text(5)?,
+        entries,
     })
 }
 
@@ -30,3 +30,17 @@ pub struct Store {
     /// Absolute path to the store root directory
     pub root: PathBuf,
 }
+
+impl Store {
+    /// The agent-local user store at `$HOME/.decapod/data`, if `HOME` is set.
+    ///
+    /// Nothing is created here: the user store stays a blank slate until
+    /// something is explicitly written to it.
+    pub fn user() -> Option<Self> {
+        let home = std::env::var_os("HOME").filter(|h| !h.is_empty())?;
+        Some(Self {
+            kind: StoreKind::User,
+            root: PathBuf::from(home).join(".decapod").join("data"),
+        })
+    }
+}
@@ -12,6 +12,73 @@ pub fn now_epoch_z() -> String {
     format!("{}Z", secs)
 }
 
+/// Parses an RFC3339 timestamp (`2026-02-19T10:00:00Z`, optional fraction and
+/// `±HH:MM` offset) or a bare `YYYY-MM-DD` date into unix-epoch seconds.
+pub fn parse_rfc3339_epoch(input: &str) -> Option<u64> {
+    let input = input.trim();
+    let (date, time) = match input.split_once(['T', 't', ' ']) {
+        Some((d, t)) => (d, Some(t)),
+        None => (input, None),
+    };
+
+    let mut date_parts = date.splitn(3, '-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: u32 = date_parts.next()?.parse().ok()?;
+    let day: u32 = date_parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let mut secs_of_day: i64 = 0;
+    let mut offset_secs: i64 = 0;
+    if let Some(time) = time {
+        let (clock, offset) = if let Some(stripped) = time.strip_suffix(['Z', 'z']) {
+            (stripped, None)
+        } else if let Some(idx) = time.rfind(['+', '-']) {
+            (&time[..idx], Some(&time[idx..]))
+        } else {
+            (time, None)
+        };
+        let clock = clock.split('.').next()?;
+        let mut clock_parts = clock.splitn(3, ':');
+        let hour: i64 = clock_parts.next()?.parse().ok()?;
+        let minute: i64 = clock_parts.next()?.parse().ok()?;
+        let second: i64 = clock_parts.next().unwrap_or("0").parse().ok()?;
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+        secs_of_day = hour * 3600 + minute * 60 + second;
+        if let Some(offset) = offset {
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let (oh, om) = offset[1..].split_once(':').unwrap_or((&offset[1..], "0"));
+            let oh: i64 = oh.parse().ok()?;
+            let om: i64 = om.parse().ok()?;
+            offset_secs = sign * (oh * 3600 + om * 60);
+        }
+    }
+
+    // Days-from-civil (proleptic Gregorian), see Howard Hinnant's date algorithms.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    let total = days * 86400 + secs_of_day - offset_secs;
+    u64::try_from(total).ok()
+}
+
+/// Parses an event timestamp in either epoch-`Z` (`1771220592Z`) or RFC3339 form.
+pub fn parse_event_ts_epoch(ts: &str) -> Option<u64> {
+    let trimmed = ts.trim();
+    if let Ok(secs) = trimmed.trim_end_matches('Z').parse::<u64>() {
+        return Some(secs);
+    }
+    parse_rfc3339_epoch(trimmed)
+}
+
 pub fn new_event_id() -> String {
     crate::core::ulid::new_ulid()
 }
@@ -45,6 +112,32 @@ mod tests {
         assert!(numeric_part.parse::<u64>().is_ok());
     }
 
+    #[test]
+    fn test_parse_rfc3339_epoch() {
+        assert_eq!(parse_rfc3339_epoch("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(
+            parse_rfc3339_epoch("2026-02-19T10:00:00Z"),
+            Some(1_771_495_200)
+        );
+        assert_eq!(
+            parse_rfc3339_epoch("2026-02-19T12:00:00.250+02:00"),
+            Some(1_771_495_200)
+        );
+        assert_eq!(parse_rfc3339_epoch("2026-02-19"), Some(1_771_459_200));
+        assert_eq!(parse_rfc3339_epoch("not-a-date"), None);
+        assert_eq!(parse_rfc3339_epoch("2026-13-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_parse_event_ts_epoch_accepts_both_forms() {
+        assert_eq!(parse_event_ts_epoch("1771495200Z"), Some(1_771_495_200));
+        assert_eq!(
+            parse_event_ts_epoch("2026-02-19T10:00:00Z"),
+            Some(1_771_495_200)
+        );
+        assert_eq!(parse_event_ts_epoch("unknown"), None);
+    }
+
     #[test]
     fn test_new_event_id_is_unique() {
         let id1 = new_event_id();
@@ -1,6 +1,7 @@
 use crate::core::broker::DbBroker;
 use crate::core::error;
 use crate::core::external_action::{self, ExternalCapability};
+use crate::core::output::JsonlWriter;
 use crate::core::schemas; // Import the new schemas module
 use crate::core::store::Store;
 use crate::plugins::aptitude;
@@ -13,7 +14,7 @@ use clap::{Parser, Subcommand, ValueEnum};
 use rusqlite::{Connection, OptionalExtension, Result as SqlResult, params, types::ToSql};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::env;
 use std::fs::{self, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
@@ -22,11 +23,26 @@ use std::path::{Path, PathBuf};
 const AGENT_EVICT_TIMEOUT_SECS: u64 = 30 * 60;
 const CLAIM_STATUS_CACHE_SCOPE: &str = "todo.claim.status";
 const CLAIM_STATUS_CACHE_TTL_SECS: u64 = 15;
+/// Weight of an `agent_expertise.expertise_level`; unrecognised levels weigh 0.
+const EXPERTISE_RANK_SQL: &str = "CASE lower(expertise_level)
+         WHEN 'expert' THEN 3
+         WHEN 'advanced' THEN 2
+         WHEN 'intermediate' THEN 1
+         ELSE 0
+     END";
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
 enum OutputFormat {
     Text,
     Json,
+    /// One JSON object per line; streamed by `todo list`.
+    Jsonl,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+    Markdown,
+    Json,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
@@ -45,6 +61,47 @@ pub struct TodoCli {
     command: TodoCommand,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum CategoriesCommand {
+    /// Add a category that task auto-inference can match by keyword.
+    Add {
+        #[clap(long)]
+        name: String,
+        #[clap(long, default_value = "")]
+        description: String,
+        /// Comma-separated keywords matched against task titles and tags.
+        #[clap(long, default_value = "")]
+        keywords: String,
+    },
+    /// Remove a category; refuses while tasks are still filed under it.
+    Remove {
+        #[clap(long)]
+        name: String,
+        /// Move tasks from the removed category into this one instead of refusing.
+        #[clap(long)]
+        reassign_to: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DependsCommand {
+    /// Make a task wait on another; refuses a dependency that would form a cycle.
+    Add {
+        #[clap(long)]
+        id: String,
+        /// Task that must finish first.
+        #[clap(long)]
+        on: String,
+    },
+    /// Drop an existing dependency.
+    Remove {
+        #[clap(long)]
+        id: String,
+        #[clap(long)]
+        on: String,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum TodoCommand {
     /// Add a new task.
@@ -93,6 +150,9 @@ pub enum TodoCommand {
     Get {
         #[clap(long)]
         id: String,
+        /// Include the task's ordered event timeline.
+        #[clap(long)]
+        events: bool,
     },
     /// Show a task by ID (compat alias for get).
     Show {
@@ -102,6 +162,9 @@ pub enum TodoCommand {
         /// Task ID positional fallback.
         #[clap(value_name = "ID")]
         id_positional: Option<String>,
+        /// Include the task's ordered event timeline.
+        #[clap(long)]
+        events: bool,
     },
     /// Mark a task done.
     Done {
@@ -117,6 +180,9 @@ pub enum TodoCommand {
         /// File path(s) to hash for drift detection. Defaults to AGENTS.md when --validated is set.
         #[clap(long = "artifact")]
         artifact: Vec<String>,
+        /// Close the task even if it still has open subtasks.
+        #[clap(long)]
+        force: bool,
     },
     /// Archive a task (keeps audit trail).
     Archive {
@@ -149,14 +215,23 @@ pub enum TodoCommand {
     },
     /// Claim a task for active work (prevents other agents from interfering).
     Claim {
+        #[clap(long, required_unless_present = "next", conflicts_with = "next")]
+        id: Option<String>,
+        /// Claim the highest-priority ready, unassigned task instead of a given id.
         #[clap(long)]
-        id: String,
+        next: bool,
+        /// With --next, only consider tasks in this category.
+        #[clap(long, requires = "next")]
+        category: Option<String>,
         /// Agent identifier (defaults to environment or 'unknown').
         #[clap(long)]
         agent: Option<String>,
         /// Claim mode: exclusive takes assignment; shared joins as secondary owner.
         #[clap(long, value_enum, default_value = "exclusive")]
         mode: ClaimMode,
+        /// Lease the claim for this many seconds; renewed by re-claiming or heartbeating.
+        #[clap(long)]
+        lease: Option<u64>,
     },
     /// Read claim status for a task (cache-first).
     ClaimStatus {
@@ -169,9 +244,50 @@ pub enum TodoCommand {
         id: String,
     },
     /// Rebuild the SQLite DB deterministically from the JSONL event log.
-    Rebuild,
-    /// List available task categories.
-    Categories,
+    Rebuild {
+        /// Rebuild into a scratch DB and fail on any divergence instead of replacing the live DB.
+        #[clap(long)]
+        verify: bool,
+    },
+    /// Export the non-archived backlog as a human-readable document.
+    Export {
+        /// Document format (`--as` avoids clashing with the global `--format`).
+        #[clap(
+            id = "export_format",
+            long = "as",
+            value_enum,
+            default_value = "markdown"
+        )]
+        format: ExportFormat,
+        /// Write to this file instead of stdout; unchanged content is not rewritten.
+        #[clap(long)]
+        out: Option<PathBuf>,
+    },
+    /// Re-run category/component inference over existing tasks.
+    Reclassify {
+        /// Report the changes without applying them.
+        #[clap(long)]
+        dry_run: bool,
+        /// Only fill tasks whose category/component is empty, keeping manual assignments.
+        #[clap(long)]
+        only_empty: bool,
+    },
+    /// List open tasks that are overdue or due within a window, soonest first.
+    Due {
+        /// Window in days from now; overdue tasks are always included.
+        #[clap(long, default_value = "7")]
+        within: u64,
+    },
+    /// List available task categories, or add/remove project-specific ones.
+    Categories {
+        #[clap(subcommand)]
+        action: Option<CategoriesCommand>,
+    },
+    /// Add or remove a dependency on an existing task.
+    Depends {
+        #[clap(subcommand)]
+        action: DependsCommand,
+    },
     /// Register an agent and claim ownership of one or more categories.
     RegisterAgent {
         /// Agent identifier (defaults to environment or 'unknown').
@@ -235,6 +351,9 @@ pub enum TodoCommand {
         from: Option<String>,
         #[clap(long)]
         summary: String,
+        /// Log the reconcile git writes instead of running them.
+        #[clap(long)]
+        dry_run: bool,
     },
     /// Add an additional owner to a task (supports multiple ownership).
     AddOwner {
@@ -304,6 +423,12 @@ pub struct Task {
     #[serde(default)]
     pub owners: Vec<TaskOwner>,
     pub one_shot: i32,
+    /// Number of non-archived child tasks (computed, not stored).
+    #[serde(default)]
+    pub subtask_total: u32,
+    /// Number of child tasks marked done (computed, not stored).
+    #[serde(default)]
+    pub subtask_done: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -374,7 +499,7 @@ fn sanitize_branch_segment(input: &str) -> String {
     out.trim_matches('/').to_string()
 }
 
-fn run_git(repo_root: &Path, args: &[&str]) -> Result<String, error::DecapodError> {
+fn run_git(repo_root: &Path, args: &[&str], dry_run: bool) -> Result<String, error::DecapodError> {
     let mut current = Some(repo_root);
     let mut store_root = None;
     while let Some(path) = current {
@@ -392,13 +517,14 @@ fn run_git(repo_root: &Path, args: &[&str]) -> Result<String, error::DecapodErro
         _ => ExternalCapability::VcsWrite,
     };
 
-    let output = external_action::execute(
+    let output = external_action::execute_with(
         &store_root,
         capability,
         "todo.handoff.reconcile",
         "git",
         args,
         repo_root,
+        dry_run,
     )?;
     if !output.status.success() {
         return Err(error::DecapodError::ValidationError(format!(
@@ -415,8 +541,9 @@ fn reconcile_commit_to_agent_branch(
     task_id: &str,
     target_agent: &str,
     summary: &str,
+    dry_run: bool,
 ) -> Result<serde_json::Value, error::DecapodError> {
-    let is_repo = run_git(repo_root, &["rev-parse", "--is-inside-work-tree"]);
+    let is_repo = run_git(repo_root, &["rev-parse", "--is-inside-work-tree"], dry_run);
     if is_repo.is_err() {
         return Ok(serde_json::json!({
             "status": "skipped",
@@ -424,7 +551,7 @@ fn reconcile_commit_to_agent_branch(
         }));
     }
 
-    let source_branch = run_git(repo_root, &["branch", "--show-current"])?;
+    let source_branch = run_git(repo_root, &["branch", "--show-current"], dry_run)?;
     if source_branch.is_empty() {
         return Ok(serde_json::json!({
             "status": "skipped",
@@ -433,7 +560,7 @@ fn reconcile_commit_to_agent_branch(
     }
 
     let target_branch = format!("{}/work", sanitize_branch_segment(target_agent));
-    let status = run_git(repo_root, &["status", "--porcelain"])?;
+    let status = run_git(repo_root, &["status", "--porcelain"], dry_run)?;
     if status.trim().is_empty() {
         return Ok(serde_json::json!({
             "status": "skipped",
@@ -443,10 +570,10 @@ fn reconcile_commit_to_agent_branch(
         }));
     }
 
-    run_git(repo_root, &["add", "-A"])?;
+    run_git(repo_root, &["add", "-A"], dry_run)?;
     let msg = format!("chore(reconcile): handoff {} to {}", task_id, target_agent);
-    run_git(repo_root, &["commit", "-m", &msg])?;
-    let commit = run_git(repo_root, &["rev-parse", "HEAD"])?;
+    run_git(repo_root, &["commit", "-m", &msg], dry_run)?;
+    let commit = run_git(repo_root, &["rev-parse", "HEAD"], dry_run)?;
 
     if source_branch == target_branch {
         return Ok(serde_json::json!({
@@ -465,13 +592,14 @@ fn reconcile_commit_to_agent_branch(
             "--verify",
             &format!("refs/heads/{}", target_branch),
         ],
+        dry_run,
     )
     .is_ok();
 
     if target_exists {
-        run_git(repo_root, &["checkout", &target_branch])?;
-        let cherry = run_git(repo_root, &["cherry-pick", &commit]);
-        let _ = run_git(repo_root, &["checkout", &source_branch]);
+        run_git(repo_root, &["checkout", &target_branch], dry_run)?;
+        let cherry = run_git(repo_root, &["cherry-pick", &commit], dry_run);
+        let _ = run_git(repo_root, &["checkout", &source_branch], dry_run);
         cherry?;
         Ok(serde_json::json!({
             "status": "ok",
@@ -482,8 +610,8 @@ fn reconcile_commit_to_agent_branch(
             "summary": summary
         }))
     } else {
-        run_git(repo_root, &["checkout", "-b", &target_branch])?;
-        let _ = run_git(repo_root, &["checkout", &source_branch]);
+        run_git(repo_root, &["checkout", "-b", &target_branch], dry_run)?;
+        let _ = run_git(repo_root, &["checkout", &source_branch], dry_run);
         Ok(serde_json::json!({
             "status": "ok",
             "mode": "created_branch",
@@ -639,6 +767,11 @@ fn ensure_schema(conn: &Connection) -> Result<(), error::DecapodError> {
         )?;
         conn.execute(schemas::TODO_DB_SCHEMA_INDEX_HASH, [])?;
     }
+
+    if current_version < 16 {
+        let _ = conn.execute("ALTER TABLE tasks ADD COLUMN claim_lease_secs INTEGER", []);
+        let _ = conn.execute("ALTER TABLE tasks ADD COLUMN claim_expires_at TEXT", []);
+    }
     conn.execute(
         "INSERT INTO meta(key, value) VALUES('schema_version', ?1)
          ON CONFLICT(key) DO UPDATE SET value=excluded.value",
@@ -791,15 +924,40 @@ fn seed_default_categories(conn: &Connection) -> Result<(), error::DecapodError>
         ("ux", "User experience and design", "ux,design,ui,usability"),
     ];
 
+    insert_categories(conn, &categories)?;
+    Ok(())
+}
+
+/// Insert `(name, description, keywords)` rows, leaving existing names alone.
+/// Returns how many were new.
+fn insert_categories(
+    conn: &Connection,
+    categories: &[(&str, &str, &str)],
+) -> Result<usize, error::DecapodError> {
     let ts = now_iso();
+    let mut inserted = 0;
     for (name, desc, keywords) in categories {
-        conn.execute(
+        inserted += conn.execute(
             "INSERT OR IGNORE INTO categories(id, name, description, keywords, created_at)
              VALUES(?1, ?2, ?3, ?4, ?5)",
             rusqlite::params![crate::core::ulid::new_ulid(), name, desc, keywords, ts],
         )?;
     }
-    Ok(())
+    Ok(inserted)
+}
+
+/// Add categories on top of the built-in set (e.g. from an init profile);
+/// names that already exist are kept as they are. Returns how many were new.
+pub fn seed_categories(
+    root: &Path,
+    categories: &[(&str, &str, &str)],
+) -> Result<usize, error::DecapodError> {
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+    broker.with_conn(&db_path, "decapod", None, "todo.categories.seed", |conn| {
+        ensure_schema(conn)?;
+        insert_categories(conn, categories)
+    })
 }
 
 fn migrate_task_categories(conn: &Connection) -> Result<(), error::DecapodError> {
@@ -880,6 +1038,93 @@ fn migrate_task_components(conn: &Connection) -> Result<(), error::DecapodError>
     Ok(())
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Reclassification {
+    pub id: String,
+    pub title: String,
+    pub category: Option<(String, String)>,
+    pub component: Option<(String, String)>,
+}
+
+/// Re-run inference over every non-archived task. Inference never clears a
+/// value: a task that matches nothing keeps what it has.
+pub fn reclassify_tasks(
+    root: &Path,
+    dry_run: bool,
+    only_empty: bool,
+) -> Result<Vec<Reclassification>, error::DecapodError> {
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+    let ts = now_iso();
+
+    broker.with_conn(&db_path, "decapod", None, "todo.reclassify", |conn| {
+        ensure_schema(conn)?;
+        let mut stmt = conn.prepare(
+            "SELECT id, title, tags, COALESCE(category, ''), COALESCE(component, '')
+             FROM tasks WHERE status != 'archived' ORDER BY created_at, id",
+        )?;
+        let tasks = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })?
+            .collect::<SqlResult<Vec<_>>>()?;
+
+        let mut changes = Vec::new();
+        for (id, title, tags, category, component) in tasks {
+            let pick = |current: &str, inferred: Option<String>| {
+                inferred
+                    .filter(|next| next != current && (!only_empty || current.is_empty()))
+                    .map(|next| (current.to_string(), next))
+            };
+            let change = Reclassification {
+                category: pick(&category, infer_category_from_task(conn, &title, &tags)?),
+                component: pick(&component, infer_component(&title, &tags)),
+                id,
+                title,
+            };
+            if change.category.is_none() && change.component.is_none() {
+                continue;
+            }
+            if !dry_run {
+                let mut payload = serde_json::Map::new();
+                if let Some((_, next)) = &change.category {
+                    conn.execute(
+                        "UPDATE tasks SET category = ?1, updated_at = ?2 WHERE id = ?3",
+                        rusqlite::params![next, ts, change.id],
+                    )?;
+                    payload.insert("category".to_string(), serde_json::json!(next));
+                }
+                if let Some((_, next)) = &change.component {
+                    conn.execute(
+                        "UPDATE tasks SET component = ?1, updated_at = ?2 WHERE id = ?3",
+                        rusqlite::params![next, ts, change.id],
+                    )?;
+                    payload.insert("component".to_string(), serde_json::json!(next));
+                }
+                let ev = TodoEvent {
+                    ts: ts.clone(),
+                    event_id: crate::core::ulid::new_ulid(),
+                    event_type: "task.reclassify".to_string(),
+                    status: "success".to_string(),
+                    task_id: Some(change.id.clone()),
+                    payload: serde_json::Value::Object(payload),
+                    actor: "decapod".to_string(),
+                };
+                append_event(root, &ev)?;
+                insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+            }
+            changes.push(change);
+        }
+        Ok(changes)
+    })
+}
+
 pub fn infer_component(title: &str, tags: &str) -> Option<String> {
     let text = format!("{} {}", title, tags).to_lowercase();
 
@@ -1023,6 +1268,195 @@ pub fn list_categories(root: &Path) -> Result<Vec<Category>, error::DecapodError
     })
 }
 
+fn normalize_category_name(name: &str) -> Result<String, error::DecapodError> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Invalid category name '{}': use lowercase letters, digits, '-' or '_'",
+            name
+        )));
+    }
+    Ok(name)
+}
+
+fn category_exists(conn: &Connection, name: &str) -> Result<bool, error::DecapodError> {
+    Ok(conn
+        .query_row(
+            "SELECT 1 FROM categories WHERE name = ?",
+            [name],
+            |_| Ok(()),
+        )
+        .optional()
+        .map_err(error::DecapodError::RusqliteError)?
+        .is_some())
+}
+
+pub fn add_category(
+    root: &Path,
+    name: &str,
+    description: &str,
+    keywords: &str,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let name = normalize_category_name(name)?;
+    let keywords = keywords
+        .split(',')
+        .map(|k| k.trim().to_lowercase())
+        .filter(|k| !k.is_empty())
+        .collect::<Vec<_>>()
+        .join(",");
+    let description = description.trim().to_string();
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+    let ts = now_iso();
+
+    broker.with_conn(&db_path, "decapod", None, "todo.categories.add", |conn| {
+        ensure_schema(conn)?;
+        if category_exists(conn, &name)? {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Category '{}' already exists",
+                name
+            )));
+        }
+        conn.execute(
+            "INSERT INTO categories(id, name, description, keywords, created_at)
+             VALUES(?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![
+                crate::core::ulid::new_ulid(),
+                name,
+                description,
+                keywords,
+                ts
+            ],
+        )?;
+        let ev = TodoEvent {
+            ts: ts.clone(),
+            event_id: crate::core::ulid::new_ulid(),
+            event_type: "category.add".to_string(),
+            status: "success".to_string(),
+            task_id: None,
+            payload: serde_json::json!({
+                "name": name,
+                "description": description,
+                "keywords": keywords,
+            }),
+            actor: "decapod".to_string(),
+        };
+        append_event(root, &ev)?;
+        insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+        Ok(())
+    })?;
+
+    Ok(serde_json::json!({
+        "ts": ts,
+        "cmd": "todo.categories.add",
+        "status": "ok",
+        "root": root.to_string_lossy(),
+        "name": name,
+        "description": description,
+        "keywords": keywords,
+    }))
+}
+
+pub fn remove_category(
+    root: &Path,
+    name: &str,
+    reassign_to: Option<&str>,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let name = normalize_category_name(name)?;
+    let reassign_to = reassign_to.map(normalize_category_name).transpose()?;
+    if reassign_to.as_deref() == Some(name.as_str()) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Cannot reassign category '{}' to itself",
+            name
+        )));
+    }
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+    let ts = now_iso();
+
+    let reassigned = broker.with_conn(&db_path, "decapod", None, "todo.categories.remove", |conn| {
+        ensure_schema(conn)?;
+        if !category_exists(conn, &name)? {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Unknown category '{}' (run `decapod todo categories`)",
+                name
+            )));
+        }
+        if let Some(target) = reassign_to.as_deref()
+            && !category_exists(conn, target)?
+        {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Unknown reassignment category '{}' (run `decapod todo categories`)",
+                target
+            )));
+        }
+        let filed: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM tasks WHERE category = ?1",
+            [&name],
+            |row| row.get(0),
+        )?;
+        if filed > 0 && reassign_to.is_none() {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Category '{}' still has {} task(s); pass --reassign-to <category> to move them",
+                name, filed
+            )));
+        }
+        let moved = apply_category_removal(conn, &name, reassign_to.as_deref())?;
+
+        let ev = TodoEvent {
+            ts: ts.clone(),
+            event_id: crate::core::ulid::new_ulid(),
+            event_type: "category.remove".to_string(),
+            status: "success".to_string(),
+            task_id: None,
+            payload: serde_json::json!({
+                "name": name,
+                "reassign_to": reassign_to,
+                "reassigned_tasks": moved,
+            }),
+            actor: "decapod".to_string(),
+        };
+        append_event(root, &ev)?;
+        insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+        Ok(moved)
+    })?;
+
+    Ok(serde_json::json!({
+        "ts": ts,
+        "cmd": "todo.categories.remove",
+        "status": "ok",
+        "root": root.to_string_lossy(),
+        "name": name,
+        "reassign_to": reassign_to,
+        "reassigned_tasks": reassigned,
+    }))
+}
+
+/// Drop a category and its ownership claims, moving filed tasks to `reassign_to`.
+fn apply_category_removal(
+    conn: &Connection,
+    name: &str,
+    reassign_to: Option<&str>,
+) -> Result<usize, error::DecapodError> {
+    let moved = match reassign_to {
+        Some(target) => conn.execute(
+            "UPDATE tasks SET category = ?1 WHERE category = ?2",
+            rusqlite::params![target, name],
+        )?,
+        None => 0,
+    };
+    conn.execute(
+        "DELETE FROM agent_category_claims WHERE category = ?1",
+        [name],
+    )?;
+    conn.execute("DELETE FROM categories WHERE name = ?1", [name])?;
+    Ok(moved)
+}
+
 fn register_agent_categories(
     root: &Path,
     agent_id: &str,
@@ -1192,6 +1626,11 @@ fn record_heartbeat(root: &Path, agent_id: &str) -> Result<serde_json::Value, er
     broker.with_conn(&db_path, "decapod", None, "todo.heartbeat", |conn| {
         ensure_schema(conn)?;
         touch_agent_presence(conn, agent_id, &ts)?;
+        renew_claim_leases(
+            conn,
+            agent_id,
+            parse_epoch_z(&ts).unwrap_or_else(now_unix_secs),
+        )?;
 
         let ev = TodoEvent {
             ts: ts.clone(),
@@ -1217,7 +1656,7 @@ fn record_heartbeat(root: &Path, agent_id: &str) -> Result<serde_json::Value, er
 }
 
 pub fn clock_in_agent_presence(store: &Store) -> Result<(), error::DecapodError> {
-    let default_agent = env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+    let default_agent = crate::core::identity::current_agent_id();
     let _ = record_heartbeat(&store.root, &default_agent)?;
     Ok(())
 }
@@ -1275,7 +1714,7 @@ pub fn cleanup_stale_agent_assignments(
                     )
                     .map_err(error::DecapodError::RusqliteError)?;
                     sync_legacy_owner_column(conn, &task_id)?;
-                    released_count += changed as usize;
+                    released_count += changed;
 
                     let ev = TodoEvent {
                         ts: ts.clone(),
@@ -1420,6 +1859,7 @@ fn summarize_task_context(
                 as_of: None,
                 window_days: None,
                 rank: "relevance",
+                namespace: None,
             },
         )
         .unwrap_or_default();
@@ -1468,6 +1908,7 @@ fn record_task_lesson(
             status: "active",
             ttl_policy: "persistent",
             expires_ts: None,
+            namespace: None,
         },
     );
 
@@ -1508,7 +1949,7 @@ fn run_worker_loop(
     let mut skipped = Vec::new();
 
     for task_id in claimable {
-        let claim_out = claim_task(root, &task_id, agent_id, ClaimMode::Exclusive)?;
+        let claim_out = claim_task(root, &task_id, agent_id, ClaimMode::Exclusive, None)?;
         let claim_status = claim_out
             .get("status")
             .and_then(|v| v.as_str())
@@ -1615,7 +2056,7 @@ fn run_worker_loop(
     }))
 }
 
-fn list_agent_presence(
+pub fn list_agent_presence(
     root: &Path,
     agent: Option<&str>,
 ) -> Result<Vec<AgentPresence>, error::DecapodError> {
@@ -2004,6 +2445,22 @@ fn find_agent_for_category(
         return Ok(Some(agent));
     }
 
+    // Prefer the most expert live agent that recorded expertise in this category.
+    let mut stmt = conn.prepare(&format!(
+        "SELECT agent_id FROM agent_expertise
+         WHERE category = ?1
+         ORDER BY {} DESC, updated_at ASC, agent_id ASC",
+        EXPERTISE_RANK_SQL
+    ))?;
+    let experts = stmt
+        .query_map([category], |row| row.get::<_, String>(0))?
+        .collect::<SqlResult<Vec<_>>>()?;
+    for expert in experts {
+        if !is_agent_stale(conn, &expert, now_ts, AGENT_EVICT_TIMEOUT_SECS)? {
+            return Ok(Some(expert));
+        }
+    }
+
     let agent: Option<String> = conn
         .query_row(
             "SELECT assigned_to FROM tasks
@@ -2093,6 +2550,58 @@ fn env_bool(name: &str, default_value: bool) -> bool {
     }
 }
 
+/// Reject a parent that is missing, already closed, or whose ancestor chain
+/// would make `task_id` its own ancestor.
+fn validate_parent_task(
+    conn: &Connection,
+    parent_id: &str,
+    task_id: &str,
+) -> Result<(), error::DecapodError> {
+    let status: Option<String> = conn
+        .query_row(
+            "SELECT status FROM tasks WHERE id = ?1",
+            rusqlite::params![parent_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    match status.as_deref() {
+        None => {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Parent task {} not found",
+                parent_id
+            )));
+        }
+        Some(s) if s == "done" || s == "archived" => {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Parent task {} is already {}",
+                parent_id, s
+            )));
+        }
+        Some(_) => {}
+    }
+
+    let mut seen = HashSet::new();
+    let mut current = Some(parent_id.to_string());
+    while let Some(id) = current {
+        if id == task_id || !seen.insert(id.clone()) {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Parent task {} would create a cycle in the task hierarchy",
+                parent_id
+            )));
+        }
+        current = conn
+            .query_row(
+                "SELECT parent_task_id FROM tasks WHERE id = ?1",
+                rusqlite::params![id],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .optional()?
+            .flatten()
+            .filter(|p| !p.is_empty());
+    }
+    Ok(())
+}
+
 fn sync_task_dependencies(
     conn: &Connection,
     task_id: &str,
@@ -2360,6 +2869,10 @@ pub fn add_task(root: &Path, args: &TodoCommand) -> Result<serde_json::Value, er
         let task_id = make_task_id(&task_type);
         let task_hash = task_hash_from_id(&task_id);
 
+        if let Some(parent_id) = parent.as_deref().filter(|p| !p.is_empty()) {
+            validate_parent_task(conn, parent_id, &task_id)?;
+        }
+
         // Check if there's an agent already working on tasks in this category
         let auto_assigned_agent = if let Some(cat) = &inferred_category {
             find_agent_for_category(conn, cat, &ts)?
@@ -2511,20 +3024,46 @@ pub fn update_status(
     event_type: &str,
     payload: JsonValue,
 ) -> Result<serde_json::Value, error::DecapodError> {
-    let ts = now_iso();
-    let intent_ref = format!("intent:{}:{}", event_type, crate::core::ulid::new_ulid());
-    let root = &store.root;
-    let broker = DbBroker::new(root);
-    let db_path = todo_db_path(root);
+    require_transition_approval(store, id, event_type)?;
+    transition_status(store, id, new_status, event_type, payload, false)
+}
 
-    // Risk Check
-    let risk_map_path = root.join("RISKMAP.json");
-    let risk_map = if risk_map_path.exists() {
-        let content = std::fs::read_to_string(risk_map_path)?;
-        serde_json::from_str(&content).unwrap_or(policy::RiskMap { zones: vec![] })
-    } else {
-        policy::RiskMap { zones: vec![] }
-    };
+/// Mark `id` done. Unless `force` is set, refuse while it still has open
+/// subtasks; the check runs in the same transaction as the status update so a
+/// subtask added concurrently cannot slip past it.
+pub fn complete_task(
+    store: &Store,
+    id: &str,
+    force: bool,
+) -> Result<serde_json::Value, error::DecapodError> {
+    require_transition_approval(store, id, "task.done")?;
+    transition_status(
+        store,
+        id,
+        "done",
+        "task.done",
+        serde_json::json!({}),
+        !force,
+    )
+}
+
+/// Archive a task that was created moments ago by a compound operation that
+/// then failed. This undoes the caller's own insert, so it skips the approval
+/// gate an ordinary archive is subject to.
+pub(crate) fn discard_created_task(
+    store: &Store,
+    id: &str,
+    payload: JsonValue,
+) -> Result<serde_json::Value, error::DecapodError> {
+    transition_status(store, id, "archived", "task.archive", payload, false)
+}
+
+fn require_transition_approval(
+    store: &Store,
+    id: &str,
+    event_type: &str,
+) -> Result<(), error::DecapodError> {
+    let risk_map = policy::load_risk_map(&store.root)?;
     let (level, _) = policy::eval_risk(event_type, None, &risk_map);
     let requires_human =
         policy::human_in_loop_required(store, "global", level, policy::is_high_risk(level));
@@ -2534,8 +3073,24 @@ pub fn update_status(
             event_type, id
         )));
     }
+    Ok(())
+}
 
-    let mut payload = payload;
+fn transition_status(
+    store: &Store,
+    id: &str,
+    new_status: &str,
+    event_type: &str,
+    payload: JsonValue,
+    refuse_open_subtasks: bool,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let ts = now_iso();
+    let intent_ref = format!("intent:{}:{}", event_type, crate::core::ulid::new_ulid());
+    let root = &store.root;
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+
+    let mut payload = payload;
     if let Some(obj) = payload.as_object_mut() {
         obj.insert(
             "intent_ref".to_string(),
@@ -2545,7 +3100,21 @@ pub fn update_status(
 
     let changed = broker.with_conn(&db_path, "decapod", Some(&intent_ref), event_type, |conn| {
         ensure_schema(conn)?;
-        let changed = conn.execute(
+        let tx =
+            rusqlite::Transaction::new_unchecked(conn, rusqlite::TransactionBehavior::Immediate)?;
+        if refuse_open_subtasks {
+            let open = subtask_rollup(&tx, Some(id))?
+                .get(id)
+                .map(|(total, done)| total.saturating_sub(*done))
+                .unwrap_or(0);
+            if open > 0 {
+                return Err(error::DecapodError::ValidationError(format!(
+                    "Task {} has {} open subtask(s); close them first or pass --force",
+                    id, open
+                )));
+            }
+        }
+        let changed = tx.execute(
             "UPDATE tasks SET status = ?1, updated_at = ?2, completed_at = CASE WHEN ?1 = 'done' THEN ?2 ELSE completed_at END WHERE id = ?3",
             rusqlite::params![new_status, ts, id],
         )?;
@@ -2560,7 +3129,8 @@ pub fn update_status(
             actor: "decapod".to_string(),
         };
         append_event(root, &ev)?;
-        insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+        insert_event(&tx, &ev).map_err(error::DecapodError::RusqliteError)?;
+        tx.commit()?;
         Ok(changed)
     })?;
 
@@ -2638,7 +3208,7 @@ pub fn update_status(
     }))
 }
 
-fn comment_task(
+pub(crate) fn comment_task(
     root: &Path,
     id: &str,
     comment: &str,
@@ -2673,7 +3243,7 @@ fn comment_task(
     }))
 }
 
-fn edit_task(
+pub(crate) fn edit_task(
     root: &Path,
     id: &str,
     title: Option<&str>,
@@ -2793,6 +3363,176 @@ fn edit_task(
     }))
 }
 
+fn task_depends_on(conn: &Connection, id: &str) -> Result<Option<String>, error::DecapodError> {
+    conn.query_row(
+        "SELECT depends_on FROM tasks WHERE id = ?1",
+        rusqlite::params![id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(error::DecapodError::RusqliteError)
+}
+
+/// Reject `task_id -> on` when `on` already reaches `task_id` through
+/// `task_dependencies`.
+fn validate_dependency_acyclic(
+    conn: &Connection,
+    task_id: &str,
+    on: &str,
+) -> Result<(), error::DecapodError> {
+    let mut stmt = conn.prepare(
+        "SELECT depends_on_task_id FROM task_dependencies WHERE task_id = ?1 ORDER BY depends_on_task_id",
+    )?;
+    let mut seen = HashSet::new();
+    let mut queue = vec![on.to_string()];
+    while let Some(current) = queue.pop() {
+        if current == task_id {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Dependency {} -> {} would create a cycle in the dependency graph",
+                task_id, on
+            )));
+        }
+        if !seen.insert(current.clone()) {
+            continue;
+        }
+        let next = stmt
+            .query_map(rusqlite::params![current], |row| row.get::<_, String>(0))?
+            .collect::<SqlResult<Vec<_>>>()?;
+        queue.extend(next);
+    }
+    Ok(())
+}
+
+fn apply_dependency_change(
+    root: &Path,
+    conn: &Connection,
+    id: &str,
+    on: &str,
+    depends_on: &str,
+    event_type: &str,
+    ts: &str,
+) -> Result<(), error::DecapodError> {
+    conn.execute(
+        "UPDATE tasks SET depends_on = ?1, updated_at = ?2 WHERE id = ?3",
+        rusqlite::params![depends_on, ts, id],
+    )?;
+    sync_task_dependencies(conn, id, depends_on, ts)?;
+    let ev = TodoEvent {
+        ts: ts.to_string(),
+        event_id: crate::core::ulid::new_ulid(),
+        event_type: event_type.to_string(),
+        status: "success".to_string(),
+        task_id: Some(id.to_string()),
+        payload: serde_json::json!({
+            "depends_on_task_id": on,
+            "depends_on": depends_on,
+        }),
+        actor: crate::core::identity::current_agent_id(),
+    };
+    append_event(root, &ev)?;
+    insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+    Ok(())
+}
+
+pub fn add_dependency(
+    root: &Path,
+    id: &str,
+    on: &str,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let ts = now_iso();
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+
+    let depends_on = broker.with_conn(&db_path, "decapod", None, "todo.depends.add", |conn| {
+        ensure_schema(conn)?;
+        let Some(current) = task_depends_on(conn, id)? else {
+            return Err(error::DecapodError::NotFound(format!(
+                "Task {} not found",
+                id
+            )));
+        };
+        if task_depends_on(conn, on)?.is_none() {
+            return Err(error::DecapodError::NotFound(format!(
+                "Dependency task {} not found",
+                on
+            )));
+        }
+        if id == on {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Task {} cannot depend on itself",
+                id
+            )));
+        }
+        let mut deps = parse_dependency_ids(&current);
+        if deps.iter().any(|d| d == on) {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Task {} already depends on {}",
+                id, on
+            )));
+        }
+        validate_dependency_acyclic(conn, id, on)?;
+        deps.push(on.to_string());
+        let depends_on = deps.join(",");
+        apply_dependency_change(root, conn, id, on, &depends_on, "task.depends.added", &ts)?;
+        Ok(depends_on)
+    })?;
+
+    Ok(serde_json::json!({
+        "ts": ts,
+        "cmd": "todo.depends.add",
+        "status": "ok",
+        "root": root.to_string_lossy(),
+        "id": id,
+        "on": on,
+        "depends_on": depends_on,
+    }))
+}
+
+pub fn remove_dependency(
+    root: &Path,
+    id: &str,
+    on: &str,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let ts = now_iso();
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+
+    let depends_on =
+        broker.with_conn(&db_path, "decapod", None, "todo.depends.remove", |conn| {
+            ensure_schema(conn)?;
+            let Some(current) = task_depends_on(conn, id)? else {
+                return Err(error::DecapodError::NotFound(format!(
+                    "Task {} not found",
+                    id
+                )));
+            };
+            let deps = parse_dependency_ids(&current);
+            if !deps.iter().any(|d| d == on) {
+                return Err(error::DecapodError::ValidationError(format!(
+                    "Task {} does not depend on {}",
+                    id, on
+                )));
+            }
+            let depends_on = deps
+                .into_iter()
+                .filter(|d| d != on)
+                .collect::<Vec<_>>()
+                .join(",");
+            apply_dependency_change(root, conn, id, on, &depends_on, "task.depends.removed", &ts)?;
+            Ok(depends_on)
+        })?;
+
+    Ok(serde_json::json!({
+        "ts": ts,
+        "cmd": "todo.depends.remove",
+        "status": "ok",
+        "root": root.to_string_lossy(),
+        "id": id,
+        "on": on,
+        "depends_on": depends_on,
+    }))
+}
+
 fn cache_put_claim_status(
     db_path: &Path,
     id: &str,
@@ -2830,6 +3570,118 @@ fn cache_get_claim_status(db_path: &Path, id: &str) -> Option<(String, String, S
     ))
 }
 
+/// Release every claim whose lease has lapsed, emitting `task.lease_expired`.
+/// Returns the released task ids.
+fn release_expired_leases(
+    root: &Path,
+    conn: &Connection,
+    ts: &str,
+) -> Result<Vec<String>, error::DecapodError> {
+    let now = now_unix_secs();
+    let mut stmt = conn.prepare(
+        "SELECT id, assigned_to, claim_expires_at, COALESCE(category, '') FROM tasks
+         WHERE claim_expires_at IS NOT NULL AND claim_expires_at != ''
+           AND assigned_to != '' AND status NOT IN ('done', 'archived')
+         ORDER BY id",
+    )?;
+    let candidates = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?
+        .collect::<SqlResult<Vec<_>>>()?;
+
+    let mut released = Vec::new();
+    for (id, assignee, expires_at, category) in candidates {
+        if parse_epoch_z(&expires_at).is_none_or(|expires| expires > now) {
+            continue;
+        }
+        conn.execute(
+            "UPDATE tasks
+             SET assigned_to = '', assigned_at = NULL, claim_lease_secs = NULL,
+                 claim_expires_at = NULL, updated_at = ?1
+             WHERE id = ?2",
+            rusqlite::params![ts, id],
+        )?;
+        conn.execute(
+            "DELETE FROM task_owners WHERE task_id = ?1 AND agent_id = ?2",
+            rusqlite::params![id, assignee],
+        )?;
+        sync_legacy_owner_column(conn, &id)?;
+        // The category lock was taken by this claim; drop it unless the
+        // agent still holds other live work in the same category.
+        if !category.is_empty() {
+            conn.execute(
+                "DELETE FROM agent_category_claims
+                 WHERE category = ?1 AND agent_id = ?2
+                   AND NOT EXISTS (
+                       SELECT 1 FROM tasks
+                       WHERE category = ?1 AND assigned_to = ?2
+                         AND status NOT IN ('done', 'archived')
+                   )",
+                rusqlite::params![category, assignee],
+            )?;
+        }
+
+        let ev = TodoEvent {
+            ts: ts.to_string(),
+            event_id: crate::core::ulid::new_ulid(),
+            event_type: "task.lease_expired".to_string(),
+            status: "success".to_string(),
+            task_id: Some(id.clone()),
+            payload: serde_json::json!({
+                "assigned_to": "",
+                "previous_assignee": assignee,
+                "claim_expires_at": expires_at,
+            }),
+            actor: "decapod".to_string(),
+        };
+        append_event(root, &ev)?;
+        insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+        released.push(id);
+    }
+    Ok(released)
+}
+
+/// Treat lapsed claim leases as released before a mutating or claim-status
+/// todo command runs.
+pub fn expire_claim_leases(root: &Path) -> Result<Vec<String>, error::DecapodError> {
+    let db_path = todo_db_path(root);
+    if !db_path.exists() {
+        return Ok(Vec::new());
+    }
+    let ts = now_iso();
+    let broker = DbBroker::new(root);
+    let released = broker.with_conn(&db_path, "decapod", None, "todo.lease_expiry", |conn| {
+        ensure_schema(conn)?;
+        release_expired_leases(root, conn, &ts)
+    })?;
+    for id in &released {
+        cache_put_claim_status(&db_path, id, "open", "", &ts);
+    }
+    Ok(released)
+}
+
+/// Push out the lease expiry of every leased task held by `agent_id`.
+fn renew_claim_leases(
+    conn: &Connection,
+    agent_id: &str,
+    now_secs: u64,
+) -> Result<usize, error::DecapodError> {
+    conn.execute(
+        "UPDATE tasks
+         SET claim_expires_at = CAST(?1 + claim_lease_secs AS TEXT) || 'Z'
+         WHERE assigned_to = ?2 AND claim_lease_secs > 0
+           AND status NOT IN ('done', 'archived')",
+        rusqlite::params![now_secs as i64, agent_id],
+    )
+    .map_err(error::DecapodError::RusqliteError)
+}
+
 fn claim_status(root: &Path, id: &str) -> Result<serde_json::Value, error::DecapodError> {
     let ts = now_iso();
     let db_path = todo_db_path(root);
@@ -2895,10 +3747,14 @@ fn claim_task(
     id: &str,
     agent_id: &str,
     mode: ClaimMode,
+    lease_secs: Option<u64>,
 ) -> Result<serde_json::Value, error::DecapodError> {
     let ts = now_iso();
     let broker = DbBroker::new(root);
     let db_path = todo_db_path(root);
+    let lease_secs = lease_secs.filter(|secs| *secs > 0);
+    let claim_expires_at =
+        lease_secs.map(|secs| format!("{}Z", now_unix_secs().saturating_add(secs)));
 
     if mode == ClaimMode::Exclusive
         && let Some((status, assigned_to, updated_at)) = cache_get_claim_status(&db_path, id)
@@ -2927,209 +3783,366 @@ fn claim_task(
 
     let result = broker.with_conn(&db_path, "decapod", None, "todo.claim", |conn| {
         ensure_schema(conn)?;
-        touch_agent_presence(conn, agent_id, &ts)?;
-        let claim_zone = if mode == ClaimMode::Shared {
-            "todo.claim.shared"
-        } else {
-            "todo.claim.exclusive"
-        };
-        enforce_operation_policy(root, conn, claim_zone, agent_id)?;
+        release_expired_leases(root, conn, &ts)?;
+        claim_task_in_conn(
+            root,
+            conn,
+            id,
+            agent_id,
+            mode,
+            lease_secs,
+            claim_expires_at.as_deref(),
+            &ts,
+        )
+    })?;
 
-        // Check if task exists and is not already claimed
-        let current: Option<(String, String, String)> = conn
-            .query_row(
-                "SELECT status, assigned_to, category FROM tasks WHERE id = ?",
-                [id],
-                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
-            )
-            .optional()
-            .map_err(error::DecapodError::RusqliteError)?;
+    Ok(claim_envelope(root, &db_path, id, agent_id, &ts, result))
+}
 
-        match current {
-            None => {
+/// Selects the best ready task (open, unassigned, every dependency done or
+/// archived, category unowned or owned by `agent_id`) and claims it
+/// exclusively inside one immediate transaction, so concurrent `--next`
+/// claims cannot pick the same task. Tasks are weighted by priority
+/// (critical 4 .. low 1) plus the agent's expertise in the task's category
+/// (expert 3 .. intermediate 1), then by priority and age.
+fn claim_next_task(
+    root: &Path,
+    agent_id: &str,
+    category: Option<&str>,
+    lease_secs: Option<u64>,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let ts = now_iso();
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+    let lease_secs = lease_secs.filter(|secs| *secs > 0);
+    let claim_expires_at =
+        lease_secs.map(|secs| format!("{}Z", now_unix_secs().saturating_add(secs)));
+
+    let claimed = broker.with_conn(&db_path, "decapod", None, "todo.claim", |conn| {
+        ensure_schema(conn)?;
+        let tx =
+            rusqlite::Transaction::new_unchecked(conn, rusqlite::TransactionBehavior::Immediate)?;
+        release_expired_leases(root, &tx, &ts)?;
+        let priority_rank = "CASE priority
+                 WHEN 'critical' THEN 4
+                 WHEN 'high' THEN 3
+                 WHEN 'medium' THEN 2
+                 WHEN 'low' THEN 1
+                 ELSE 0
+             END";
+        let mut stmt = tx.prepare(&format!(
+            "SELECT id FROM tasks
+             WHERE status = 'open'
+               AND assigned_to = ''
+               AND (?2 IS NULL OR category = ?2)
+               AND (
+                   category = ''
+                   OR NOT EXISTS (
+                       SELECT 1 FROM agent_category_claims acc
+                       WHERE acc.category = tasks.category AND acc.agent_id != ?1
+                   )
+               )
+               AND NOT EXISTS (
+                   SELECT 1 FROM task_dependencies td
+                   JOIN tasks dep ON dep.id = td.depends_on_task_id
+                   WHERE td.task_id = tasks.id
+                     AND dep.status NOT IN ('done', 'archived')
+               )
+             ORDER BY
+                 {priority} + COALESCE((
+                     SELECT {expertise} FROM agent_expertise
+                     WHERE agent_id = ?1 AND category = tasks.category AND tasks.category != ''
+                 ), 0) DESC,
+                 {priority} DESC,
+                 created_at ASC,
+                 id ASC",
+            priority = priority_rank,
+            expertise = EXPERTISE_RANK_SQL,
+        ))?;
+        let candidates = stmt
+            .query_map(params![agent_id, category], |row| row.get::<_, String>(0))?
+            .collect::<SqlResult<Vec<_>>>()?;
+        drop(stmt);
+
+        for candidate in candidates {
+            let result = claim_task_in_conn(
+                root,
+                &tx,
+                &candidate,
+                agent_id,
+                ClaimMode::Exclusive,
+                lease_secs,
+                claim_expires_at.as_deref(),
+                &ts,
+            )?;
+            if result.get("status").and_then(|v| v.as_str()) == Some("ok") {
+                tx.commit()?;
+                return Ok(Some((candidate, result)));
+            }
+        }
+        tx.commit()?;
+        Ok(None)
+    })?;
+
+    let Some((id, result)) = claimed else {
+        return Ok(serde_json::json!({
+            "ts": ts,
+            "cmd": "todo.claim",
+            "status": "not_found",
+            "root": root.to_string_lossy(),
+            "id": null,
+            "result": {
+                "status": "not_found",
+                "message": match category {
+                    Some(c) => format!("No ready unassigned task in category '{}'", c),
+                    None => "No ready unassigned task".to_string(),
+                }
+            }
+        }));
+    };
+    let mut out = claim_envelope(root, &db_path, &id, agent_id, &ts, result);
+    if let Some(task) = get_task(root, &id)? {
+        out["task"] = serde_json::to_value(task).unwrap_or(JsonValue::Null);
+    }
+    Ok(out)
+}
+
+/// Claims `id` for `agent_id` on an open connection; the caller releases
+/// expired leases first and wraps the result with [`claim_envelope`].
+#[allow(clippy::too_many_arguments)]
+fn claim_task_in_conn(
+    root: &Path,
+    conn: &Connection,
+    id: &str,
+    agent_id: &str,
+    mode: ClaimMode,
+    lease_secs: Option<u64>,
+    claim_expires_at: Option<&str>,
+    ts: &str,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let ts = ts.to_string();
+    touch_agent_presence(conn, agent_id, &ts)?;
+    let claim_zone = if mode == ClaimMode::Shared {
+        "todo.claim.shared"
+    } else {
+        "todo.claim.exclusive"
+    };
+    enforce_operation_policy(root, conn, claim_zone, agent_id)?;
+
+    // Check if task exists and is not already claimed
+    let current: Option<(String, String, String)> = conn
+        .query_row(
+            "SELECT status, assigned_to, category FROM tasks WHERE id = ?",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()
+        .map_err(error::DecapodError::RusqliteError)?;
+
+    match current {
+        None => {
+            return Ok(serde_json::json!({
+                "status": "not_found",
+                "message": format!("Task {} not found", id)
+            }));
+        }
+        Some((status, assigned_to, category)) => {
+            if status == "done" || status == "archived" {
                 return Ok(serde_json::json!({
-                    "status": "not_found",
-                    "message": format!("Task {} not found", id)
+                    "status": "error",
+                    "message": format!("Task {} is already {}", id, status)
                 }));
             }
-            Some((status, assigned_to, category)) => {
-                if status == "done" || status == "archived" {
-                    return Ok(serde_json::json!({
-                        "status": "error",
-                        "message": format!("Task {} is already {}", id, status)
-                    }));
-                }
-                if !assigned_to.is_empty() && assigned_to != agent_id {
-                    if mode == ClaimMode::Shared {
-                        let claim_id = upsert_task_owner(conn, id, agent_id, "secondary", &ts)?;
-                        write_ownership_claim_event(
-                            root,
-                            conn,
-                            &OwnershipClaimRecord {
-                                task_id: id,
-                                agent_id,
-                                claim_type: "secondary",
-                                claim_id: &claim_id,
-                                actor: agent_id,
-                                ts: &ts,
-                            },
-                        )?;
-                        sync_legacy_owner_column(conn, id)?;
-                        return Ok(serde_json::json!({
-                            "status": "ok",
-                            "mode": "shared",
-                            "message": format!("Task {} is assigned to {}; added {} as secondary owner", id, assigned_to, agent_id),
-                            "assigned_to": assigned_to,
-                            "claim_id": claim_id
-                        }));
-                    }
+            if !assigned_to.is_empty() && assigned_to != agent_id {
+                if mode == ClaimMode::Shared {
+                    let claim_id = upsert_task_owner(conn, id, agent_id, "secondary", &ts)?;
+                    write_ownership_claim_event(
+                        root,
+                        conn,
+                        &OwnershipClaimRecord {
+                            task_id: id,
+                            agent_id,
+                            claim_type: "secondary",
+                            claim_id: &claim_id,
+                            actor: agent_id,
+                            ts: &ts,
+                        },
+                    )?;
+                    sync_legacy_owner_column(conn, id)?;
                     return Ok(serde_json::json!({
-                        "status": "conflict",
-                        "mode": "exclusive",
-                        "message": format!("Task {} is already claimed by {}", id, assigned_to),
-                        "resolution": "none",
-                        "assigned_to": assigned_to
+                        "status": "ok",
+                        "mode": "shared",
+                        "message": format!("Task {} is assigned to {}; added {} as secondary owner", id, assigned_to, agent_id),
+                        "assigned_to": assigned_to,
+                        "claim_id": claim_id
                     }));
                 }
+                return Ok(serde_json::json!({
+                    "status": "conflict",
+                    "mode": "exclusive",
+                    "message": format!("Task {} is already claimed by {}", id, assigned_to),
+                    "resolution": "none",
+                    "assigned_to": assigned_to
+                }));
+            }
 
-                if !category.is_empty() && mode == ClaimMode::Exclusive {
-                    if let Some(owner) = get_category_owner(conn, &category)? {
-                        if owner != agent_id {
-                            if is_agent_stale(conn, &owner, &ts, AGENT_EVICT_TIMEOUT_SECS)? {
-                                conn.execute(
-                                    "UPDATE agent_category_claims
-                                     SET agent_id = ?, claimed_at = ?, updated_at = ?
-                                     WHERE category = ?",
-                                    rusqlite::params![agent_id, ts, ts, category],
+            if !category.is_empty() && mode == ClaimMode::Exclusive {
+                if let Some(owner) = get_category_owner(conn, &category)? {
+                    if owner != agent_id {
+                        if is_agent_stale(conn, &owner, &ts, AGENT_EVICT_TIMEOUT_SECS)? {
+                            conn.execute(
+                                "UPDATE agent_category_claims
+                                 SET agent_id = ?, claimed_at = ?, updated_at = ?
+                                 WHERE category = ?",
+                                rusqlite::params![agent_id, ts, ts, category],
+                            )
+                            .map_err(error::DecapodError::RusqliteError)?;
+                        } else {
+                            return Ok(serde_json::json!({
+                                "status": "error",
+                                "message": format!(
+                                    "Category '{}' is owned by {}; cannot claim task {}",
+                                    category, owner, id
                                 )
-                                .map_err(error::DecapodError::RusqliteError)?;
-                            } else {
-                                return Ok(serde_json::json!({
-                                    "status": "error",
-                                    "message": format!(
-                                        "Category '{}' is owned by {}; cannot claim task {}",
-                                        category, owner, id
-                                    )
-                                }));
-                            }
+                            }));
                         }
-                    } else {
-                        claim_category_if_unowned(conn, &category, agent_id, &ts)?;
                     }
+                } else {
+                    claim_category_if_unowned(conn, &category, agent_id, &ts)?;
                 }
             }
         }
+    }
 
-        // Claim the task atomically to avoid read-then-write races across agents.
-        if mode == ClaimMode::Exclusive {
-            let changed = conn
-                .execute(
-                    "UPDATE tasks
-                     SET assigned_to = ?1, assigned_at = ?2, updated_at = ?2
-                     WHERE id = ?3
-                       AND status NOT IN ('done', 'archived')
-                       AND (assigned_to = '' OR assigned_to = ?1)",
-                    rusqlite::params![agent_id, ts, id],
-                )
-                .map_err(error::DecapodError::RusqliteError)?;
-            if changed == 0 {
-                let current: Option<(String, String)> = conn
-                    .query_row(
-                        "SELECT status, assigned_to FROM tasks WHERE id = ?1",
-                        rusqlite::params![id],
-                        |row| Ok((row.get(0)?, row.get(1)?)),
-                    )
-                    .optional()
-                    .map_err(error::DecapodError::RusqliteError)?;
-                return Ok(match current {
-                    None => serde_json::json!({
-                        "status": "not_found",
-                        "message": format!("Task {} not found", id)
-                    }),
-                    Some((status, _assignee)) if status == "done" || status == "archived" => {
-                        serde_json::json!({
-                            "status": "error",
-                            "message": format!("Task {} is already {}", id, status)
-                        })
-                    }
-                    Some((_status, assignee)) => serde_json::json!({
-                        "status": "conflict",
-                        "mode": "exclusive",
-                        "message": format!("Task {} is already claimed by {}", id, assignee),
-                        "resolution": "none",
-                        "assigned_to": assignee
-                    }),
-                });
-            }
-        } else {
-            conn.execute(
-                "UPDATE tasks SET assigned_to = ?, assigned_at = ?, updated_at = ? WHERE id = ?",
-                [agent_id, &ts, &ts, id],
+    // Claim the task atomically to avoid read-then-write races across agents.
+    if mode == ClaimMode::Exclusive {
+        let changed = conn
+            .execute(
+                "UPDATE tasks
+                 SET assigned_to = ?1, assigned_at = ?2, updated_at = ?2
+                 WHERE id = ?3
+                   AND status NOT IN ('done', 'archived')
+                   AND (assigned_to = '' OR assigned_to = ?1)",
+                rusqlite::params![agent_id, ts, id],
             )
             .map_err(error::DecapodError::RusqliteError)?;
+        if changed == 0 {
+            let current: Option<(String, String)> = conn
+                .query_row(
+                    "SELECT status, assigned_to FROM tasks WHERE id = ?1",
+                    rusqlite::params![id],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .optional()
+                .map_err(error::DecapodError::RusqliteError)?;
+            return Ok(match current {
+                None => serde_json::json!({
+                    "status": "not_found",
+                    "message": format!("Task {} not found", id)
+                }),
+                Some((status, _assignee)) if status == "done" || status == "archived" => {
+                    serde_json::json!({
+                        "status": "error",
+                        "message": format!("Task {} is already {}", id, status)
+                    })
+                }
+                Some((_status, assignee)) => serde_json::json!({
+                    "status": "conflict",
+                    "mode": "exclusive",
+                    "message": format!("Task {} is already claimed by {}", id, assignee),
+                    "resolution": "none",
+                    "assigned_to": assignee
+                }),
+            });
         }
+    } else {
+        conn.execute(
+            "UPDATE tasks SET assigned_to = ?, assigned_at = ?, updated_at = ? WHERE id = ?",
+            [agent_id, &ts, &ts, id],
+        )
+        .map_err(error::DecapodError::RusqliteError)?;
+    }
 
-        let claim_id = upsert_task_owner(conn, id, agent_id, "primary", &ts)?;
-        write_ownership_claim_event(
-            root,
-            conn,
-            &OwnershipClaimRecord {
-                task_id: id,
-                agent_id,
-                claim_type: "primary",
-                claim_id: &claim_id,
-                actor: agent_id,
-                ts: &ts,
-            },
-        )?;
-        sync_legacy_owner_column(conn, id)?;
+    conn.execute(
+        "UPDATE tasks SET claim_lease_secs = ?1, claim_expires_at = ?2 WHERE id = ?3",
+        rusqlite::params![lease_secs.map(|secs| secs as i64), claim_expires_at, id],
+    )
+    .map_err(error::DecapodError::RusqliteError)?;
 
-        // Create claim event
-        let ev = TodoEvent {
-            ts: ts.clone(),
-            event_id: crate::core::ulid::new_ulid(),
-            event_type: "task.claim".to_string(),
-            status: "success".to_string(),
-            task_id: Some(id.to_string()),
-            payload: serde_json::json!({
-                "assigned_to": agent_id,
-                "mode": format!("{:?}", mode).to_lowercase(),
-            }),
-            actor: agent_id.to_string(),
-        };
-        append_event(root, &ev)?;
-        insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+    let claim_id = upsert_task_owner(conn, id, agent_id, "primary", &ts)?;
+    write_ownership_claim_event(
+        root,
+        conn,
+        &OwnershipClaimRecord {
+            task_id: id,
+            agent_id,
+            claim_type: "primary",
+            claim_id: &claim_id,
+            actor: agent_id,
+            ts: &ts,
+        },
+    )?;
+    sync_legacy_owner_column(conn, id)?;
 
-        Ok(serde_json::json!({
-            "status": "ok",
+    // Create claim event
+    let ev = TodoEvent {
+        ts: ts.clone(),
+        event_id: crate::core::ulid::new_ulid(),
+        event_type: "task.claim".to_string(),
+        status: "success".to_string(),
+        task_id: Some(id.to_string()),
+        payload: serde_json::json!({
+            "assigned_to": agent_id,
             "mode": format!("{:?}", mode).to_lowercase(),
-            "message": format!("Task {} claimed by {}", id, agent_id),
-            "claim_id": claim_id
-        }))
-    })?;
+            "lease_secs": lease_secs,
+            "claim_expires_at": claim_expires_at,
+        }),
+        actor: agent_id.to_string(),
+    };
+    append_event(root, &ev)?;
+    insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+
+    Ok(serde_json::json!({
+        "status": "ok",
+        "mode": format!("{:?}", mode).to_lowercase(),
+        "message": format!("Task {} claimed by {}", id, agent_id),
+        "claim_id": claim_id,
+        "claim_expires_at": claim_expires_at
+    }))
+}
 
+/// Refreshes the claim-status cache from a claim result and wraps it in the
+/// `todo.claim` envelope.
+fn claim_envelope(
+    root: &Path,
+    db_path: &Path,
+    id: &str,
+    agent_id: &str,
+    ts: &str,
+    result: serde_json::Value,
+) -> serde_json::Value {
     if result.get("status").and_then(|v| v.as_str()) == Some("ok") {
         let assigned_to = result
             .get("assigned_to")
             .and_then(|v| v.as_str())
             .unwrap_or(agent_id);
-        cache_put_claim_status(&db_path, id, "open", assigned_to, &ts);
+        cache_put_claim_status(db_path, id, "open", assigned_to, ts);
     } else if result.get("status").and_then(|v| v.as_str()) == Some("conflict") {
         let assigned_to = result
             .get("assigned_to")
             .and_then(|v| v.as_str())
             .unwrap_or_default();
-        cache_put_claim_status(&db_path, id, "open", assigned_to, &ts);
+        cache_put_claim_status(db_path, id, "open", assigned_to, ts);
     }
 
-    Ok(serde_json::json!({
+    serde_json::json!({
         "ts": ts,
         "cmd": "todo.claim",
         "status": result.get("status").and_then(|v| v.as_str()).unwrap_or("error"),
         "root": root.to_string_lossy(),
         "id": id,
         "result": result,
-    }))
+    })
 }
 
 fn handoff_task(
@@ -3138,6 +4151,7 @@ fn handoff_task(
     to: &str,
     from: Option<&str>,
     summary: &str,
+    dry_run: bool,
 ) -> Result<serde_json::Value, error::DecapodError> {
     let root = &store.root;
     let broker = DbBroker::new(root);
@@ -3192,7 +4206,8 @@ fn handoff_task(
         }
 
         conn.execute(
-            "UPDATE tasks SET assigned_to = ?, assigned_at = ?, updated_at = ? WHERE id = ?",
+            "UPDATE tasks SET assigned_to = ?, assigned_at = ?, updated_at = ?,
+             claim_lease_secs = NULL, claim_expires_at = NULL WHERE id = ?",
             rusqlite::params![to, ts, ts, id],
         )
         .map_err(error::DecapodError::RusqliteError)?;
@@ -3261,6 +4276,7 @@ fn handoff_task(
                 status: "active",
                 ttl_policy: "persistent",
                 expires_ts: None,
+                namespace: None,
             },
         );
         let obs = format!("Task {} handoff to {}: {}", id, to, summary);
@@ -3272,7 +4288,10 @@ fn handoff_task(
             .and_then(|p| p.parent())
             .map(|p| p.to_path_buf())
             .unwrap_or_else(|| root.clone());
-        reconcile_result = reconcile_commit_to_agent_branch(&repo_root, id, to, summary)?;
+        reconcile_result = reconcile_commit_to_agent_branch(&repo_root, id, to, summary, dry_run)?;
+        if dry_run && let Some(obj) = reconcile_result.as_object_mut() {
+            obj.insert("dry_run".to_string(), serde_json::json!(true));
+        }
     }
 
     Ok(serde_json::json!({
@@ -3603,7 +4622,8 @@ fn release_task(root: &Path, id: &str) -> Result<serde_json::Value, error::Decap
 
         // Release the task
         conn.execute(
-            "UPDATE tasks SET assigned_to = '', assigned_at = NULL, updated_at = ? WHERE id = ?",
+            "UPDATE tasks SET assigned_to = '', assigned_at = NULL, claim_lease_secs = NULL,
+             claim_expires_at = NULL, updated_at = ? WHERE id = ?",
             [&ts, id],
         )
         .map_err(error::DecapodError::RusqliteError)?;
@@ -3641,11 +4661,65 @@ fn release_task(root: &Path, id: &str) -> Result<serde_json::Value, error::Decap
     }))
 }
 
+/// One entry in a task's event timeline.
+#[derive(Serialize, Debug, Clone)]
+pub struct TaskTimelineEvent {
+    pub event_id: String,
+    pub ts: String,
+    pub event_type: String,
+    pub actor: String,
+    pub payload: JsonValue,
+}
+
+/// Events recorded for `id`, in the order they were written.
+pub fn task_events(root: &Path, id: &str) -> Result<Vec<TaskTimelineEvent>, error::DecapodError> {
+    let broker = DbBroker::new(root);
+    let db_path = todo_db_path(root);
+
+    broker.with_conn(&db_path, "decapod", None, "todo.events", |conn| {
+        ensure_schema(conn)?;
+        let mut stmt = conn.prepare(
+            "SELECT event_id, ts, event_type, actor, payload FROM task_events
+             WHERE task_id = ?1 ORDER BY rowid",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![id], |row| {
+            let payload: String = row.get(4)?;
+            Ok(TaskTimelineEvent {
+                event_id: row.get(0)?,
+                ts: row.get(1)?,
+                event_type: row.get(2)?,
+                actor: row.get(3)?,
+                payload: serde_json::from_str(&payload).unwrap_or(JsonValue::Null),
+            })
+        })?;
+        Ok(rows.collect::<SqlResult<Vec<_>>>()?)
+    })
+}
+
+fn get_task_response(
+    root: &Path,
+    id: &str,
+    with_events: bool,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let t = get_task(root, id)?;
+    let mut out = serde_json::json!({
+        "ts": now_iso(),
+        "cmd": "todo.get",
+        "status": if t.is_some() { "ok" } else { "not_found" },
+        "root": root.to_string_lossy(),
+        "item": t,
+    });
+    if with_events && t.is_some() {
+        out["events"] = serde_json::to_value(task_events(root, id)?).unwrap_or_default();
+    }
+    Ok(out)
+}
+
 pub fn get_task(root: &Path, id: &str) -> Result<Option<Task>, error::DecapodError> {
     let broker = DbBroker::new(root);
     let db_path = todo_db_path(root);
 
-    broker.with_conn(&db_path, "decapod", None, "todo.get", |conn| {
+    let task = broker.with_conn(&db_path, "decapod", None, "todo.get", |conn| {
         ensure_schema(conn)?;
         let mut stmt = conn.prepare("SELECT id,hash,title,description,tags,owner,due,ref,status,created_at,updated_at,completed_at,closed_at,dir_path,scope,parent_task_id,priority,depends_on,blocks,category,component,assigned_to,assigned_at FROM tasks WHERE id = ?1")?;
         let mut rows = stmt.query(rusqlite::params![id])?;
@@ -3678,11 +4752,64 @@ pub fn get_task(root: &Path, id: &str) -> Result<Option<Task>, error::DecapodErr
                 assigned_at: row.get(22)?,
                 owners,
                 one_shot: row.get(23).unwrap_or(0),
+                subtask_total: 0,
+                subtask_done: 0,
             }))
         } else {
             Ok(None)
         }
-    })
+    })?;
+    let Some(mut task) = task else {
+        return Ok(None);
+    };
+    let rollup = broker.with_conn(&db_path, "decapod", None, "todo.get", |conn| {
+        subtask_rollup(conn, Some(&task.id))
+    })?;
+    apply_subtask_rollup(std::slice::from_mut(&mut task), &rollup);
+    Ok(Some(task))
+}
+
+/// Count children per parent in one grouped query. Archived children are
+/// excluded so a parent's progress reflects live work only.
+fn subtask_rollup(
+    conn: &Connection,
+    parent_id: Option<&str>,
+) -> Result<std::collections::HashMap<String, (u32, u32)>, error::DecapodError> {
+    let mut query =
+        "SELECT parent_task_id, COUNT(*), SUM(CASE WHEN status = 'done' THEN 1 ELSE 0 END)
+         FROM tasks
+         WHERE parent_task_id IS NOT NULL AND parent_task_id != '' AND status != 'archived'"
+            .to_string();
+    if parent_id.is_some() {
+        query.push_str(" AND parent_task_id = ?1");
+    }
+    query.push_str(" GROUP BY parent_task_id");
+    let mut stmt = conn.prepare(&query)?;
+    let map_row = |row: &rusqlite::Row<'_>| -> SqlResult<(String, (u32, u32))> {
+        Ok((row.get(0)?, (row.get(1)?, row.get(2)?)))
+    };
+    let rows = match parent_id {
+        Some(id) => stmt.query_map(rusqlite::params![id], map_row)?,
+        None => stmt.query_map([], map_row)?,
+    };
+    let mut out = std::collections::HashMap::new();
+    for row in rows {
+        let (parent, counts) = row?;
+        out.insert(parent, counts);
+    }
+    Ok(out)
+}
+
+fn apply_subtask_rollup(
+    tasks: &mut [Task],
+    rollup: &std::collections::HashMap<String, (u32, u32)>,
+) {
+    for task in tasks {
+        if let Some((total, done)) = rollup.get(&task.id) {
+            task.subtask_total = *total;
+            task.subtask_done = *done;
+        }
+    }
 }
 
 pub fn list_tasks(
@@ -3693,85 +4820,135 @@ pub fn list_tasks(
     title_search: Option<String>,
     dir: Option<String>,
 ) -> Result<Vec<Task>, error::DecapodError> {
+    let mut out = Vec::new();
+    for_each_task(root, status, scope, tags, title_search, dir, |task| {
+        out.push(task);
+        Ok(())
+    })?;
+    Ok(out)
+}
+
+/// Rows read per broker connection while streaming tasks; the connection is
+/// released between pages so a slow `visit` never holds the todo database.
+const STREAM_PAGE_SIZE: i64 = 256;
+
+/// Hands each task matching the `list_tasks` filters to `visit` in the same
+/// order, reading [`STREAM_PAGE_SIZE`] rows at a time instead of collecting
+/// the whole result set.
+pub fn for_each_task<F>(
+    root: &Path,
+    status: Option<String>,
+    scope: Option<String>,
+    tags: Option<String>,
+    title_search: Option<String>,
+    dir: Option<String>,
+    mut visit: F,
+) -> Result<(), error::DecapodError>
+where
+    F: FnMut(Task) -> Result<(), error::DecapodError>,
+{
     let broker = DbBroker::new(root);
     let db_path = todo_db_path(root);
+    let dir = dir
+        .map(|d| {
+            Path::new(&d)
+                .canonicalize()
+                .map(|abs| abs.to_string_lossy().to_string())
+                .map_err(error::DecapodError::IoError)
+        })
+        .transpose()?;
 
-    broker.with_conn(&db_path, "decapod", None, "todo.list", |conn| {
+    let rollup = broker.with_conn(&db_path, "decapod", None, "todo.list", |conn| {
         ensure_schema(conn)?;
+        subtask_rollup(conn, None)
+    })?;
 
-        let mut query = "SELECT id,hash,title,description,tags,owner,due,ref,status,created_at,updated_at,completed_at,closed_at,dir_path,scope,parent_task_id,priority,depends_on,blocks,category,component,assigned_to,assigned_at FROM tasks WHERE 1=1".to_string();
-        let mut params: Vec<Box<dyn ToSql>> = Vec::new();
-
-        if let Some(s) = status {
-            query.push_str(" AND status = ?");
-            params.push(Box::new(s));
-        }
-        if let Some(s) = scope {
-            query.push_str(" AND scope = ?");
-            params.push(Box::new(s));
-        }
-        if let Some(t) = tags {
-            query.push_str(" AND tags LIKE ?");
-            params.push(Box::new(format!("%{}%", t)));
-        }
-        if let Some(ts) = title_search {
-            query.push_str(" AND title LIKE ?");
-            params.push(Box::new(format!("%{}%", ts)));
-        }
-        if let Some(d) = dir {
-            let abs = Path::new(&d)
-                .canonicalize()
-                .map_err(error::DecapodError::IoError)?
-                .to_string_lossy()
-                .to_string();
-            query.push_str(" AND dir_path = ?");
-            params.push(Box::new(abs));
-        }
-
-        query.push_str(" ORDER BY updated_at DESC");
+    // Keyset cursor over (updated_at DESC, id ASC): stable across pages even
+    // when tasks are added between them.
+    let mut cursor: Option<(String, String)> = None;
+    loop {
+        let page = broker.with_conn(&db_path, "decapod", None, "todo.list", |conn| {
+            let mut query = "SELECT id,hash,title,description,tags,owner,due,ref,status,created_at,updated_at,completed_at,closed_at,dir_path,scope,parent_task_id,priority,depends_on,blocks,category,component,assigned_to,assigned_at FROM tasks WHERE 1=1".to_string();
+            let mut params: Vec<&dyn ToSql> = Vec::new();
+            let tags = tags.as_ref().map(|t| format!("%{}%", t));
+            let title_search = title_search.as_ref().map(|t| format!("%{}%", t));
+
+            for (clause, value) in [
+                (" AND status = ?", &status),
+                (" AND scope = ?", &scope),
+                (" AND tags LIKE ?", &tags),
+                (" AND title LIKE ?", &title_search),
+                (" AND dir_path = ?", &dir),
+            ] {
+                if let Some(v) = value {
+                    query.push_str(clause);
+                    params.push(v);
+                }
+            }
+            if let Some((updated_at, id)) = &cursor {
+                query.push_str(" AND (updated_at < ? OR (updated_at = ? AND id > ?))");
+                params.extend([updated_at as &dyn ToSql, updated_at, id]);
+            }
+            query.push_str(" ORDER BY updated_at DESC, id ASC LIMIT ?");
+            params.push(&STREAM_PAGE_SIZE);
 
-        let mut stmt = conn.prepare(&query)?;
-        let params_as_dyn: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
-        let mut rows = stmt
-            .query(rusqlite::params_from_iter(params_as_dyn.iter().copied()))
-            .map_err(error::DecapodError::RusqliteError)?;
-        let mut out = Vec::new();
-        while let Some(row) = rows.next().map_err(error::DecapodError::RusqliteError)? {
-            let task_id: String = row.get(0).map_err(error::DecapodError::RusqliteError)?;
-            let owners = fetch_task_owners(conn, &task_id)?;
-            out.push(Task {
-                id: task_id,
-                hash: row.get(1).map_err(error::DecapodError::RusqliteError)?,
-                title: row.get(2).map_err(error::DecapodError::RusqliteError)?,
-                description: row.get(3).map_err(error::DecapodError::RusqliteError)?,
-                tags: row.get(4).map_err(error::DecapodError::RusqliteError)?,
-                owner: primary_owner_from_owners(&owners).unwrap_or_else(|| row.get(5).unwrap_or_default()),
-                due: row.get(6).map_err(error::DecapodError::RusqliteError)?,
-                r#ref: row.get(7).map_err(error::DecapodError::RusqliteError)?,
-                status: row.get(8).map_err(error::DecapodError::RusqliteError)?,
-                created_at: row.get(9).map_err(error::DecapodError::RusqliteError)?,
-                updated_at: row.get(10).map_err(error::DecapodError::RusqliteError)?,
-                completed_at: row.get(11).map_err(error::DecapodError::RusqliteError)?,
-                closed_at: row.get(12).map_err(error::DecapodError::RusqliteError)?,
-                dir_path: row.get(13).map_err(error::DecapodError::RusqliteError)?,
-                scope: row.get(14).map_err(error::DecapodError::RusqliteError)?,
-                parent_task_id: row.get(15).map_err(error::DecapodError::RusqliteError)?,
-                priority: row.get(16).map_err(error::DecapodError::RusqliteError)?,
-                depends_on: row.get(17).map_err(error::DecapodError::RusqliteError)?,
-                blocks: row.get(18).map_err(error::DecapodError::RusqliteError)?,
-                category: row.get(19).map_err(error::DecapodError::RusqliteError)?,
-                component: row.get(20).map_err(error::DecapodError::RusqliteError)?,
-                assigned_to: row
-                    .get(21)
-                    .map_err(error::DecapodError::RusqliteError)
-                    .unwrap_or_default(),
-                assigned_at: row.get(22).map_err(error::DecapodError::RusqliteError)?,
-                owners,
-                one_shot: row.get(23).map_err(error::DecapodError::RusqliteError).unwrap_or(0),
-            });
+            let mut stmt = conn.prepare(&query)?;
+            let mut rows = stmt
+                .query(rusqlite::params_from_iter(params.iter().copied()))
+                .map_err(error::DecapodError::RusqliteError)?;
+            let mut page = Vec::new();
+            while let Some(row) = rows.next().map_err(error::DecapodError::RusqliteError)? {
+                let task_id: String = row.get(0).map_err(error::DecapodError::RusqliteError)?;
+                let owners = fetch_task_owners(conn, &task_id)?;
+                let task = Task {
+                    id: task_id,
+                    hash: row.get(1).map_err(error::DecapodError::RusqliteError)?,
+                    title: row.get(2).map_err(error::DecapodError::RusqliteError)?,
+                    description: row.get(3).map_err(error::DecapodError::RusqliteError)?,
+                    tags: row.get(4).map_err(error::DecapodError::RusqliteError)?,
+                    owner: primary_owner_from_owners(&owners).unwrap_or_else(|| row.get(5).unwrap_or_default()),
+                    due: row.get(6).map_err(error::DecapodError::RusqliteError)?,
+                    r#ref: row.get(7).map_err(error::DecapodError::RusqliteError)?,
+                    status: row.get(8).map_err(error::DecapodError::RusqliteError)?,
+                    created_at: row.get(9).map_err(error::DecapodError::RusqliteError)?,
+                    updated_at: row.get(10).map_err(error::DecapodError::RusqliteError)?,
+                    completed_at: row.get(11).map_err(error::DecapodError::RusqliteError)?,
+                    closed_at: row.get(12).map_err(error::DecapodError::RusqliteError)?,
+                    dir_path: row.get(13).map_err(error::DecapodError::RusqliteError)?,
+                    scope: row.get(14).map_err(error::DecapodError::RusqliteError)?,
+                    parent_task_id: row.get(15).map_err(error::DecapodError::RusqliteError)?,
+                    priority: row.get(16).map_err(error::DecapodError::RusqliteError)?,
+                    depends_on: row.get(17).map_err(error::DecapodError::RusqliteError)?,
+                    blocks: row.get(18).map_err(error::DecapodError::RusqliteError)?,
+                    category: row.get(19).map_err(error::DecapodError::RusqliteError)?,
+                    component: row.get(20).map_err(error::DecapodError::RusqliteError)?,
+                    assigned_to: row
+                        .get(21)
+                        .map_err(error::DecapodError::RusqliteError)
+                        .unwrap_or_default(),
+                    assigned_at: row.get(22).map_err(error::DecapodError::RusqliteError)?,
+                    owners,
+                    one_shot: row.get(23).map_err(error::DecapodError::RusqliteError).unwrap_or(0),
+                    subtask_total: 0,
+                    subtask_done: 0,
+                };
+                page.push(task);
+            }
+            Ok(page)
+        })?;
+
+        let full = page.len() as i64 == STREAM_PAGE_SIZE;
+        if let Some(last) = page.last() {
+            cursor = Some((last.updated_at.clone(), last.id.clone()));
         }
-        Ok(out)
-    })
+        for mut task in page {
+            apply_subtask_rollup(std::slice::from_mut(&mut task), &rollup);
+            visit(task)?;
+        }
+        if !full {
+            return Ok(());
+        }
+    }
 }
 
 pub fn rebuild_from_events(root: &Path) -> Result<serde_json::Value, error::DecapodError> {
@@ -3814,6 +4991,136 @@ pub fn rebuild_from_events(root: &Path) -> Result<serde_json::Value, error::Deca
     }))
 }
 
+/// Tables compared by `rebuild --verify`: (table, key columns, ignored columns).
+/// Ignored columns are row ids and timestamps minted at insert time rather
+/// than carried in the event payload.
+const REBUILD_VERIFY_TABLES: &[(&str, &[&str], &[&str])] = &[
+    ("tasks", &["id"], &[]),
+    (
+        "task_owners",
+        &["task_id", "agent_id"],
+        &["id", "claimed_at"],
+    ),
+    (
+        "task_dependencies",
+        &["task_id", "depends_on_task_id"],
+        &["id", "created_at"],
+    ),
+    ("categories", &["name"], &["id", "created_at"]),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RebuildDivergence {
+    pub table: String,
+    pub key: String,
+    /// `missing_in_rebuild`, `missing_in_live`, or `differs`.
+    pub kind: String,
+    pub columns: Vec<String>,
+}
+
+type TableSnapshot = BTreeMap<String, BTreeMap<String, String>>;
+
+fn snapshot_table(
+    conn: &Connection,
+    table: &str,
+    keys: &[&str],
+    ignored: &[&str],
+) -> Result<TableSnapshot, error::DecapodError> {
+    let mut stmt = conn.prepare(&format!("SELECT * FROM {}", table))?;
+    let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+    let mut rows = stmt.query([])?;
+    let mut out = BTreeMap::new();
+    while let Some(row) = rows.next()? {
+        let mut values = BTreeMap::new();
+        for (idx, name) in columns.iter().enumerate() {
+            let value = match row.get_ref(idx)? {
+                rusqlite::types::ValueRef::Null => "NULL".to_string(),
+                rusqlite::types::ValueRef::Integer(i) => i.to_string(),
+                rusqlite::types::ValueRef::Real(f) => f.to_string(),
+                rusqlite::types::ValueRef::Text(t) => String::from_utf8_lossy(t).to_string(),
+                rusqlite::types::ValueRef::Blob(b) => format!("<{} bytes>", b.len()),
+            };
+            values.insert(name.clone(), value);
+        }
+        let key = keys
+            .iter()
+            .map(|k| values.get(*k).cloned().unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("/");
+        values.retain(|name, _| !ignored.contains(&name.as_str()));
+        out.insert(key, values);
+    }
+    Ok(out)
+}
+
+fn diff_snapshots(
+    table: &str,
+    live: &TableSnapshot,
+    rebuilt: &TableSnapshot,
+) -> Vec<RebuildDivergence> {
+    let divergence = |key: &str, kind: &str, columns: Vec<String>| RebuildDivergence {
+        table: table.to_string(),
+        key: key.to_string(),
+        kind: kind.to_string(),
+        columns,
+    };
+    let mut out = Vec::new();
+    for (key, live_row) in live {
+        match rebuilt.get(key) {
+            None => out.push(divergence(key, "missing_in_rebuild", Vec::new())),
+            Some(rebuilt_row) => {
+                let columns: Vec<String> = live_row
+                    .keys()
+                    .chain(rebuilt_row.keys())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .filter(|c| live_row.get(*c) != rebuilt_row.get(*c))
+                    .cloned()
+                    .collect();
+                if !columns.is_empty() {
+                    out.push(divergence(key, "differs", columns));
+                }
+            }
+        }
+    }
+    for key in rebuilt.keys().filter(|k| !live.contains_key(*k)) {
+        out.push(divergence(key, "missing_in_live", Vec::new()));
+    }
+    out
+}
+
+/// Rebuild into a scratch DB and diff it against the live one without
+/// touching the live DB. An empty result means the event log fully explains
+/// the current state.
+pub fn verify_rebuild(root: &Path) -> Result<Vec<RebuildDivergence>, error::DecapodError> {
+    let ev_path = events_path(root);
+    let tmp_db = root.join(format!(".{}.verify.tmp", schemas::TODO_DB_NAME));
+    if tmp_db.exists() {
+        fs::remove_file(&tmp_db).map_err(error::DecapodError::IoError)?;
+    }
+    let rebuilt = if ev_path.is_file() {
+        rebuild_db_from_events(&ev_path, &tmp_db).map(|_| ())
+    } else {
+        crate::db::db_connect(&tmp_db.to_string_lossy()).and_then(|conn| ensure_schema(&conn))
+    };
+    let result = rebuilt.and_then(|_| {
+        let live = connect_todo(root)?;
+        ensure_schema(&live)?;
+        let scratch = crate::db::db_connect(&tmp_db.to_string_lossy())?;
+        let mut divergences = Vec::new();
+        for (table, keys, ignored) in REBUILD_VERIFY_TABLES {
+            let live_rows = snapshot_table(&live, table, keys, ignored)?;
+            let rebuilt_rows = snapshot_table(&scratch, table, keys, ignored)?;
+            divergences.extend(diff_snapshots(table, &live_rows, &rebuilt_rows));
+        }
+        Ok(divergences)
+    });
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = fs::remove_file(format!("{}{}", tmp_db.to_string_lossy(), suffix));
+    }
+    result
+}
+
 pub fn rebuild_db_from_events(events: &Path, out_db: &Path) -> Result<u64, error::DecapodError> {
     let broker = DbBroker::new(out_db.parent().unwrap());
 
@@ -3990,7 +5297,8 @@ pub fn rebuild_db_from_events(events: &Path, out_db: &Path) -> Result<u64, error
                 }
                 "task.comment" => {}
                 "task.worker.run" => {}
-                "task.edit" => {
+                "todo.overdue" => {}
+                "task.edit" | "task.reclassify" => {
                     let id = ev.task_id.clone().unwrap_or_default();
                     if let Some(title) = ev.payload.get("title").and_then(|v| v.as_str()) {
                         conn.execute(
@@ -4060,6 +5368,16 @@ pub fn rebuild_db_from_events(events: &Path, out_db: &Path) -> Result<u64, error
                         )?;
                     }
                 }
+                "task.depends.added" | "task.depends.removed" => {
+                    let id = ev.task_id.clone().unwrap_or_default();
+                    if let Some(depends_on) = ev.payload.get("depends_on").and_then(|v| v.as_str()) {
+                        conn.execute(
+                            "UPDATE tasks SET depends_on = ?1, updated_at = ?2 WHERE id = ?3",
+                            rusqlite::params![depends_on, ev.ts, id],
+                        )?;
+                        sync_task_dependencies(conn, &id, depends_on, &ev.ts)?;
+                    }
+                }
                 "task.claim" => {
                     let id = ev.task_id.clone().unwrap_or_default();
                     let assigned_to = ev
@@ -4067,15 +5385,44 @@ pub fn rebuild_db_from_events(events: &Path, out_db: &Path) -> Result<u64, error
                         .get("assigned_to")
                         .and_then(|v| v.as_str())
                         .unwrap_or("");
+                    let lease_secs = ev.payload.get("lease_secs").and_then(|v| v.as_u64());
+                    let expires_at = ev.payload.get("claim_expires_at").and_then(|v| v.as_str());
+                    conn.execute(
+                        "UPDATE tasks SET assigned_to = ?1, assigned_at = ?2, updated_at = ?2,
+                         claim_lease_secs = ?3, claim_expires_at = ?4 WHERE id = ?5",
+                        rusqlite::params![assigned_to, ev.ts, lease_secs.map(|v| v as i64), expires_at, id],
+                    )?;
+                }
+                "category.add" => {
+                    let field = |key: &str| {
+                        ev.payload
+                            .get(key)
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("")
+                            .to_string()
+                    };
                     conn.execute(
-                        "UPDATE tasks SET assigned_to = ?1, assigned_at = ?2, updated_at = ?2 WHERE id = ?3",
-                        rusqlite::params![assigned_to, ev.ts, id],
+                        "INSERT OR IGNORE INTO categories(id, name, description, keywords, created_at)
+                         VALUES(?1, ?2, ?3, ?4, ?5)",
+                        rusqlite::params![
+                            ev.event_id,
+                            field("name"),
+                            field("description"),
+                            field("keywords"),
+                            ev.ts
+                        ],
                     )?;
                 }
-                "task.release" => {
+                "category.remove" => {
+                    let name = ev.payload.get("name").and_then(|v| v.as_str()).unwrap_or("");
+                    let reassign_to = ev.payload.get("reassign_to").and_then(|v| v.as_str());
+                    apply_category_removal(conn, name, reassign_to)?;
+                }
+                "task.release" | "task.lease_expired" => {
                     let id = ev.task_id.clone().unwrap_or_default();
                     conn.execute(
-                        "UPDATE tasks SET assigned_to = '', assigned_at = NULL, updated_at = ?1 WHERE id = ?2",
+                        "UPDATE tasks SET assigned_to = '', assigned_at = NULL, claim_lease_secs = NULL,
+                         claim_expires_at = NULL, updated_at = ?1 WHERE id = ?2",
                         rusqlite::params![ev.ts, id],
                     )?;
                 }
@@ -4083,7 +5430,8 @@ pub fn rebuild_db_from_events(events: &Path, out_db: &Path) -> Result<u64, error
                     let id = ev.task_id.clone().unwrap_or_default();
                     let to = ev.payload.get("to").and_then(|v| v.as_str()).unwrap_or("");
                     conn.execute(
-                        "UPDATE tasks SET assigned_to = ?1, assigned_at = ?2, updated_at = ?2 WHERE id = ?3",
+                        "UPDATE tasks SET assigned_to = ?1, assigned_at = ?2, updated_at = ?2,
+                         claim_lease_secs = NULL, claim_expires_at = NULL WHERE id = ?3",
                         rusqlite::params![to, ev.ts, id],
                     )?;
                 }
@@ -4105,6 +5453,9 @@ pub fn rebuild_db_from_events(events: &Path, out_db: &Path) -> Result<u64, error
 
                         rusqlite::params![agent_id, ev.ts],
                     )?;
+                    if let Some(seen) = parse_epoch_z(&ev.ts) {
+                        renew_claim_leases(conn, agent_id, seen)?;
+                    }
                 }
                 "agent.session.cleanup" => {
                     // No-op for rebuild - session cleanup is audit-only
@@ -4222,6 +5573,22 @@ pub fn rebuild_db_from_events(events: &Path, out_db: &Path) -> Result<u64, error
                         ],
                     )?;
                 }
+                "task.verify.baseline_updated" => {
+                    let id = ev.task_id.clone().ok_or_else(|| {
+                        error::DecapodError::ValidationError(
+                            "task.verify.baseline_updated missing task_id".to_string(),
+                        )
+                    })?;
+                    let artifacts = ev
+                        .payload
+                        .get("verification_artifacts")
+                        .cloned()
+                        .unwrap_or(serde_json::Value::Null);
+                    conn.execute(
+                        "UPDATE task_verification SET verification_artifacts = ?2, updated_at = ?3 WHERE todo_id = ?1",
+                        rusqlite::params![id, serde_json::to_string(&artifacts).unwrap(), ev.ts],
+                    )?;
+                }
                 "task.proof.claimed" => {
                     let id = ev.task_id.clone().ok_or_else(|| {
                         error::DecapodError::ValidationError(
@@ -4277,16 +5644,20 @@ pub fn schema() -> serde_json::Value {
         "commands": [
             { "name": "add", "parameters": ["title", "tags", "owner", "due", "ref", "dir", "priority", "depends_on", "blocks", "parent"] },
             { "name": "list", "parameters": ["status", "scope", "tags", "title_search", "dir"] },
-            { "name": "get", "parameters": ["id"] },
-            { "name": "show", "parameters": ["id"] },
-            { "name": "done", "parameters": ["id", "validated", "artifact"] },
+            { "name": "get", "parameters": ["id", "events"] },
+            { "name": "show", "parameters": ["id", "events"] },
+            { "name": "done", "parameters": ["id", "validated", "artifact", "force"] },
             { "name": "archive", "parameters": ["id"] },
             { "name": "comment", "parameters": ["id", "comment"] },
             { "name": "edit", "parameters": ["id", "title", "description", "owner", "category"] },
-            { "name": "claim", "parameters": ["id", "agent", "mode"] },
+            { "name": "claim", "parameters": ["id", "next", "category", "agent", "mode", "lease"] },
             { "name": "claim-status", "parameters": ["id"] },
             { "name": "release", "parameters": ["id"] },
             { "name": "categories", "parameters": [] },
+            { "name": "categories add", "parameters": ["name", "description", "keywords"] },
+            { "name": "categories remove", "parameters": ["name", "reassign_to"] },
+            { "name": "depends add", "parameters": ["id", "on"] },
+            { "name": "depends remove", "parameters": ["id", "on"] },
             { "name": "register-agent", "parameters": ["agent", "category"] },
             { "name": "ownerships", "parameters": ["category", "agent"] },
             { "name": "heartbeat", "parameters": ["agent", "autoclaim", "max_claims"] },
@@ -4299,8 +5670,24 @@ pub fn schema() -> serde_json::Value {
             { "name": "register-expertise", "parameters": ["agent", "category", "level"] },
             { "name": "expertise", "parameters": ["agent", "category"] },
 
-            { "name": "rebuild", "parameters": [] }
+            { "name": "rebuild", "parameters": ["verify"] },
+            { "name": "export", "parameters": ["as", "out"] },
+            { "name": "due", "parameters": ["within"] },
+            { "name": "reclassify", "parameters": ["dry_run", "only_empty"] }
         ],
+        "fields": {
+            "title": { "type": "string", "required": true, "description": "Short imperative summary of the work" },
+            "description": { "type": "string", "description": "Longer free-form context for the task" },
+            "priority": { "type": "string", "enum": ["high", "medium", "low"], "description": "Scheduling priority (default: medium)" },
+            "status": { "type": "string", "enum": ["open", "done", "archived"], "description": "Lifecycle state; set via done/archive, not directly" },
+            "tags": { "type": "string", "description": "Comma-separated labels used for filtering" },
+            "owner": { "type": "string", "description": "Primary owning agent or human" },
+            "due": { "type": "string", "description": "Deadline as RFC3339 timestamp or YYYY-MM-DD date" },
+            "ref": { "type": "string", "description": "External reference (issue, URL, or feedback:<id>)" },
+            "category": { "type": "string", "description": "Ownership category used for claim routing" },
+            "parent_task_id": { "type": "string", "description": "Parent task ID when this task is a subtask" },
+            "depends_on": { "type": "string", "description": "Comma-separated task IDs that must finish first" }
+        },
         "task_columns": [
             "id", "hash", "title", "description", "tags", "owner", "status", "created_at", "updated_at",
             "priority", "depends_on", "blocks", "category", "assigned_to", "parent_task_id", "one_shot"
@@ -4334,8 +5721,284 @@ fn resolve_task_id_arg(
     }
 }
 
+/// Emit a `todo.overdue` event for every open task that has crossed its due
+/// date since the last mutating todo command, then hand each event to matching
+/// reflexes. The event log doubles as the idempotency ledger: a task fires
+/// once per `due` value, so rescheduling and crossing again fires anew.
+pub fn emit_overdue_events(store: &Store) -> Result<Vec<JsonValue>, error::DecapodError> {
+    let root = &store.root;
+    let db_path = todo_db_path(root);
+    if !db_path.exists() {
+        return Ok(Vec::new());
+    }
+    let now = now_unix_secs();
+    let ts = now_iso();
+    let broker = DbBroker::new(root);
+    let events = broker.with_conn(&db_path, "decapod", None, "todo.overdue", |conn| {
+        ensure_schema(conn)?;
+        let mut stmt = conn.prepare(
+            "SELECT id, title, due FROM tasks
+             WHERE due IS NOT NULL AND due != '' AND status NOT IN ('done', 'archived')
+             ORDER BY id",
+        )?;
+        let candidates = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .collect::<SqlResult<Vec<_>>>()?;
+
+        let mut events = Vec::new();
+        for (id, title, due) in candidates {
+            let Some(due_epoch) = crate::core::time::parse_event_ts_epoch(&due) else {
+                continue;
+            };
+            if due_epoch >= now {
+                continue;
+            }
+            let already: bool = conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM task_events
+                  WHERE event_type = 'todo.overdue' AND task_id = ?1
+                    AND json_extract(payload, '$.due') = ?2)",
+                rusqlite::params![id, due],
+                |row| row.get(0),
+            )?;
+            if already {
+                continue;
+            }
+            let ev = TodoEvent {
+                ts: ts.clone(),
+                event_id: crate::core::ulid::new_ulid(),
+                event_type: "todo.overdue".to_string(),
+                status: "success".to_string(),
+                task_id: Some(id.clone()),
+                payload: serde_json::json!({
+                    "title": title,
+                    "due": due,
+                    "due_epoch": due_epoch,
+                }),
+                actor: "decapod".to_string(),
+            };
+            append_event(root, &ev)?;
+            insert_event(conn, &ev).map_err(error::DecapodError::RusqliteError)?;
+            events.push(serde_json::json!({
+                "event_id": ev.event_id,
+                "event_type": ev.event_type,
+                "task_id": id,
+                "payload": ev.payload,
+            }));
+        }
+        Ok(events)
+    })?;
+
+    for event in &events {
+        crate::plugins::reflex::fire_trigger(root, "todo.overdue", event)?;
+    }
+    Ok(events)
+}
+
+/// A task with a parsed `due` date, as reported by `todo due`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DueTask {
+    #[serde(flatten)]
+    pub task: Task,
+    pub due_epoch: u64,
+    pub overdue: bool,
+}
+
+/// Open tasks whose `due` is already past or falls within `within_days` of
+/// now, ordered soonest-first. `due` accepts RFC3339 (including date-only)
+/// or the epoch-Z form; tasks without a parseable due date are skipped.
+pub fn due_tasks(root: &Path, within_days: u64) -> Result<Vec<DueTask>, error::DecapodError> {
+    let now = now_unix_secs();
+    let horizon = now.saturating_add(within_days.saturating_mul(86_400));
+    let mut out: Vec<DueTask> = list_tasks(root, None, None, None, None, None)?
+        .into_iter()
+        .filter(|t| t.status != "done" && t.status != "archived")
+        .filter_map(|task| {
+            let due_epoch = task
+                .due
+                .as_deref()
+                .and_then(crate::core::time::parse_event_ts_epoch)?;
+            (due_epoch <= horizon).then_some(DueTask {
+                overdue: due_epoch < now,
+                due_epoch,
+                task,
+            })
+        })
+        .collect();
+    out.sort_by(|a, b| {
+        a.due_epoch
+            .cmp(&b.due_epoch)
+            .then_with(|| a.task.id.cmp(&b.task.id))
+    });
+    Ok(out)
+}
+
+const EXPORT_SECTIONS: [(&str, &str); 3] = [
+    ("open", "Open"),
+    ("in_progress", "In Progress"),
+    ("done", "Done"),
+];
+
+fn export_section(task: &Task) -> Option<&'static str> {
+    match task.status.as_str() {
+        "done" => Some("done"),
+        "archived" => None,
+        _ if !task.assigned_to.is_empty() => Some("in_progress"),
+        _ => Some("open"),
+    }
+}
+
+fn priority_rank(priority: &str) -> u8 {
+    match priority {
+        "high" => 0,
+        "medium" => 1,
+        "low" => 2,
+        _ => 3,
+    }
+}
+
+fn export_markdown_line(task: &Task) -> String {
+    let check = if task.status == "done" { "x" } else { " " };
+    let mut line = format!(
+        "- [{}] **{}** {} (`{}`)",
+        check, task.priority, task.title, task.id
+    );
+    if !task.assigned_to.is_empty() {
+        line.push_str(&format!(" — assignee: {}", task.assigned_to));
+    }
+    if let Some(parent) = task.parent_task_id.as_deref().filter(|p| !p.is_empty()) {
+        line.push_str(&format!(" — parent: `{}`", parent));
+    }
+    if !task.r#ref.is_empty() {
+        line.push_str(&format!(" — ref: {}", task.r#ref));
+    }
+    if task.subtask_total > 0 {
+        line.push_str(&format!(
+            " — subtasks: {}/{}",
+            task.subtask_done, task.subtask_total
+        ));
+    }
+    line
+}
+
+/// Render the backlog grouped by status. Ordering is priority, then title,
+/// then id, and no wall-clock data is emitted, so repeated exports of an
+/// unchanged store are byte-identical.
+pub fn export_tasks(root: &Path, format: ExportFormat) -> Result<String, error::DecapodError> {
+    let mut tasks = list_tasks(root, None, None, None, None, None)?;
+    tasks.sort_by(|a, b| {
+        priority_rank(&a.priority)
+            .cmp(&priority_rank(&b.priority))
+            .then_with(|| a.title.cmp(&b.title))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut sections: Vec<(&str, &str, Vec<&Task>)> = EXPORT_SECTIONS
+        .iter()
+        .map(|(key, heading)| (*key, *heading, Vec::new()))
+        .collect();
+    for task in &tasks {
+        if let Some(key) = export_section(task)
+            && let Some(section) = sections.iter_mut().find(|(k, _, _)| *k == key)
+        {
+            section.2.push(task);
+        }
+    }
+
+    match format {
+        ExportFormat::Json => {
+            let mut obj = serde_json::Map::new();
+            for (key, _, items) in &sections {
+                obj.insert(key.to_string(), serde_json::json!(items));
+            }
+            let doc = serde_json::json!({ "schema_version": "1.0.0", "sections": obj });
+            Ok(format!("{}\n", serde_json::to_string_pretty(&doc).unwrap()))
+        }
+        ExportFormat::Markdown => {
+            let mut md = String::from(
+                "# Backlog\n\n_Generated by `decapod todo export`; edit tasks through the CLI._\n",
+            );
+            for (_, heading, items) in &sections {
+                md.push_str(&format!("\n## {} ({})\n\n", heading, items.len()));
+                if items.is_empty() {
+                    md.push_str("_None._\n");
+                }
+                for task in items {
+                    md.push_str(&export_markdown_line(task));
+                    md.push('\n');
+                }
+            }
+            Ok(md)
+        }
+    }
+}
+
+impl TodoCommand {
+    /// Commands that only read the backlog; mirrors the CLI's mutation gate.
+    fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            TodoCommand::List { .. }
+                | TodoCommand::Get { .. }
+                | TodoCommand::Show { .. }
+                | TodoCommand::Ownerships { .. }
+                | TodoCommand::ClaimStatus { .. }
+                | TodoCommand::Presence { .. }
+                | TodoCommand::ListOwners { .. }
+                | TodoCommand::Expertise { .. }
+                | TodoCommand::Categories { action: None }
+                | TodoCommand::Rebuild { verify: true }
+        )
+    }
+}
+
 pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodError> {
     let root = &store.root;
+    // Reads stay side-effect free; a cron job running `todo due` drives the
+    // sweep when nothing else is mutating the backlog.
+    if !cli.command.is_read_only()
+        && let Err(e) = emit_overdue_events(store)
+    {
+        eprintln!("warning: overdue detection failed: {}", e);
+    }
+    if (!cli.command.is_read_only() || matches!(cli.command, TodoCommand::ClaimStatus { .. }))
+        && let Err(e) = expire_claim_leases(root)
+    {
+        eprintln!("warning: claim lease expiry failed: {}", e);
+    }
+    if let TodoCommand::Export { format, out: None } = &cli.command {
+        print!("{}", export_tasks(root, *format)?);
+        return Ok(());
+    }
+    if cli.format == OutputFormat::Jsonl {
+        let TodoCommand::List {
+            status,
+            scope,
+            tags,
+            title_search,
+            dir,
+        } = &cli.command
+        else {
+            return Err(error::DecapodError::ValidationError(
+                "--format jsonl is only supported by `todo list`".to_string(),
+            ));
+        };
+        let mut writer = JsonlWriter::new(std::io::stdout().lock());
+        return for_each_task(
+            root,
+            Some(status.clone()),
+            scope.clone(),
+            tags.clone(),
+            title_search.clone(),
+            dir.clone(),
+            |task| writer.write_record(&task),
+        );
+    }
     let out = match &cli.command {
         TodoCommand::Add { .. } => add_task(root, &cli.command)?,
         TodoCommand::List {
@@ -4361,32 +6024,21 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
                 "items": items,
             })
         }
-        TodoCommand::Get { id } => {
-            let t = get_task(root, id)?;
-            serde_json::json!({
-                "ts": now_iso(),
-                "cmd": "todo.get",
-                "status": if t.is_some() { "ok" } else { "not_found" },
-                "root": root.to_string_lossy(),
-                "item": t,
-            })
-        }
-        TodoCommand::Show { id, id_positional } => {
+        TodoCommand::Get { id, events } => get_task_response(root, id, *events)?,
+        TodoCommand::Show {
+            id,
+            id_positional,
+            events,
+        } => {
             let task_id = resolve_task_id_arg(id, id_positional, "todo show")?;
-            let t = get_task(root, &task_id)?;
-            serde_json::json!({
-                "ts": now_iso(),
-                "cmd": "todo.get",
-                "status": if t.is_some() { "ok" } else { "not_found" },
-                "root": root.to_string_lossy(),
-                "item": t,
-            })
+            get_task_response(root, &task_id, *events)?
         }
         TodoCommand::Done {
             id,
             id_positional,
             validated,
             artifact,
+            force,
         } => {
             let task_id = resolve_task_id_arg(id, id_positional, "todo done")?;
             let project_root = store
@@ -4404,14 +6056,15 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
                     },
                 )?;
             }
-            let out = update_status(store, &task_id, "done", "task.done", serde_json::json!({}))?;
+            let mut out = complete_task(store, &task_id, *force)?;
             if *validated && out.get("status").and_then(|v| v.as_str()) == Some("ok") {
-                verify::capture_baseline_for_todo(
+                let receipt = verify::capture_baseline_for_todo(
                     store,
                     &project_root,
                     &task_id,
                     artifact.clone(),
                 )?;
+                out["receipt"] = serde_json::json!(receipt.to_string_lossy());
             } else if out.get("status").and_then(|v| v.as_str()) == Some("ok") {
                 mark_todo_claimed_pending_proof(store, &task_id)?;
             }
@@ -4442,11 +6095,38 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
             owner.as_deref(),
             category.as_deref(),
         )?,
-        TodoCommand::Claim { id, agent, mode } => {
-            let default_agent =
-                env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+        TodoCommand::Claim {
+            id,
+            next,
+            category,
+            agent,
+            mode,
+            lease,
+        } => {
+            let default_agent = crate::core::identity::current_agent_id();
             let agent_id = agent.as_deref().unwrap_or(&default_agent);
-            let mut out = claim_task(root, id, agent_id, *mode)?;
+            if *next && *mode != ClaimMode::Exclusive {
+                return Err(error::DecapodError::ValidationError(
+                    "todo claim --next only supports --mode exclusive".to_string(),
+                ));
+            }
+            let mut out = if *next {
+                claim_next_task(root, agent_id, category.as_deref(), *lease)?
+            } else {
+                claim_task(
+                    root,
+                    id.as_deref().unwrap_or_default(),
+                    agent_id,
+                    *mode,
+                    *lease,
+                )?
+            };
+            // With --next the claimed id is only known from the result.
+            let claimed_id = out
+                .get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
             let status = out
                 .get("status")
                 .and_then(|v| v.as_str())
@@ -4455,20 +6135,24 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
             let autorun_enabled = env_bool("DECAPOD_CLAIM_AUTORUN", true);
 
             if *mode == ClaimMode::Exclusive && status == "ok" && !in_container && autorun_enabled {
-                let task_title = get_task(root, id)?
+                let task_title = get_task(root, &claimed_id)?
                     .map(|t| t.title)
-                    .unwrap_or_else(|| id.to_string());
-                let launch =
-                    match container::run_container_for_claim(store, agent_id, id, &task_title) {
-                        Ok(result) => serde_json::json!({
-                            "status": "ok",
-                            "result": result
-                        }),
-                        Err(err) => serde_json::json!({
-                            "status": "error",
-                            "error": err.to_string()
-                        }),
-                    };
+                    .unwrap_or_else(|| claimed_id.clone());
+                let launch = match container::run_container_for_claim(
+                    store,
+                    agent_id,
+                    &claimed_id,
+                    &task_title,
+                ) {
+                    Ok(result) => serde_json::json!({
+                        "status": "ok",
+                        "result": result
+                    }),
+                    Err(err) => serde_json::json!({
+                        "status": "error",
+                        "error": err.to_string()
+                    }),
+                };
                 if let Some(obj) = out.as_object_mut() {
                     obj.insert("container".to_string(), launch);
                 }
@@ -4478,14 +6162,112 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
         }
         TodoCommand::ClaimStatus { id } => claim_status(root, id)?,
         TodoCommand::Release { id } => release_task(root, id)?,
-        TodoCommand::Rebuild => rebuild_from_events(root)?,
-        TodoCommand::Categories => {
-            let categories = list_categories(root)?;
-            serde_json::json!({ "categories": categories })
+        TodoCommand::Rebuild { verify: false } => rebuild_from_events(root)?,
+        TodoCommand::Rebuild { verify: true } => {
+            let divergences = verify_rebuild(root)?;
+            if !divergences.is_empty() {
+                let mut lines: Vec<String> = divergences
+                    .iter()
+                    .take(20)
+                    .map(|d| {
+                        format!(
+                            "- {} {} {}{}",
+                            d.table,
+                            d.key,
+                            d.kind,
+                            if d.columns.is_empty() {
+                                String::new()
+                            } else {
+                                format!(" ({})", d.columns.join(", "))
+                            }
+                        )
+                    })
+                    .collect();
+                if divergences.len() > lines.len() {
+                    lines.push(format!("- ... {} more", divergences.len() - lines.len()));
+                }
+                return Err(error::DecapodError::ValidationError(format!(
+                    "todo.rebuild --verify failed: todo.db diverges from todo.events.jsonl in {} row(s):\n{}",
+                    divergences.len(),
+                    lines.join("\n")
+                )));
+            }
+            serde_json::json!({
+                "ts": now_iso(),
+                "cmd": "todo.rebuild",
+                "status": "ok",
+                "root": root.to_string_lossy(),
+                "verify": true,
+                "divergences": 0,
+            })
+        }
+        TodoCommand::Reclassify {
+            dry_run,
+            only_empty,
+        } => {
+            let changes = reclassify_tasks(root, *dry_run, *only_empty)?;
+            serde_json::json!({
+                "ts": now_iso(),
+                "cmd": "todo.reclassify",
+                "status": "ok",
+                "root": root.to_string_lossy(),
+                "dry_run": dry_run,
+                "only_empty": only_empty,
+                "changed": changes.len(),
+                "changes": changes,
+            })
+        }
+        TodoCommand::Due { within } => {
+            let items = due_tasks(root, *within)?;
+            serde_json::json!({
+                "ts": now_iso(),
+                "cmd": "todo.due",
+                "status": "ok",
+                "root": root.to_string_lossy(),
+                "within_days": within,
+                "overdue": items.iter().filter(|t| t.overdue).count(),
+                "items": items,
+            })
         }
+        TodoCommand::Export { format, out } => {
+            let content = export_tasks(root, *format)?;
+            let path = out.clone().unwrap_or_default();
+            let unchanged = fs::read_to_string(&path).is_ok_and(|existing| existing == content);
+            if !unchanged {
+                if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                    fs::create_dir_all(parent).map_err(error::DecapodError::IoError)?;
+                }
+                fs::write(&path, &content).map_err(error::DecapodError::IoError)?;
+            }
+            serde_json::json!({
+                "ts": now_iso(),
+                "cmd": "todo.export",
+                "status": "ok",
+                "root": root.to_string_lossy(),
+                "path": path.to_string_lossy(),
+                "written": !unchanged,
+            })
+        }
+        TodoCommand::Categories { action } => match action {
+            None => {
+                let categories = list_categories(root)?;
+                serde_json::json!({ "categories": categories })
+            }
+            Some(CategoriesCommand::Add {
+                name,
+                description,
+                keywords,
+            }) => add_category(root, name, description, keywords)?,
+            Some(CategoriesCommand::Remove { name, reassign_to }) => {
+                remove_category(root, name, reassign_to.as_deref())?
+            }
+        },
+        TodoCommand::Depends { action } => match action {
+            DependsCommand::Add { id, on } => add_dependency(root, id, on)?,
+            DependsCommand::Remove { id, on } => remove_dependency(root, id, on)?,
+        },
         TodoCommand::RegisterAgent { agent, categories } => {
-            let default_agent =
-                env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+            let default_agent = crate::core::identity::current_agent_id();
             let agent_id = agent.as_deref().unwrap_or(&default_agent);
             register_agent_categories(root, agent_id, categories)?
         }
@@ -4504,8 +6286,7 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
             autoclaim,
             max_claims,
         } => {
-            let default_agent =
-                env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+            let default_agent = crate::core::identity::current_agent_id();
             let agent_id = agent.as_deref().unwrap_or(&default_agent);
             let heartbeat = record_heartbeat(root, agent_id)?;
             if !*autoclaim {
@@ -4516,7 +6297,8 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
                 let mut skipped: Vec<serde_json::Value> = Vec::new();
 
                 for task_id in task_ids {
-                    let claim_out = claim_task(root, &task_id, agent_id, ClaimMode::Exclusive)?;
+                    let claim_out =
+                        claim_task(root, &task_id, agent_id, ClaimMode::Exclusive, None)?;
                     let status = claim_out
                         .get("status")
                         .and_then(|v| v.as_str())
@@ -4565,8 +6347,7 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
             lesson,
             autoclose,
         } => {
-            let default_agent =
-                env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+            let default_agent = crate::core::identity::current_agent_id();
             let agent_id = agent.as_deref().unwrap_or(&default_agent);
             run_worker_loop(
                 store,
@@ -4582,7 +6363,15 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
             to,
             from,
             summary,
-        } => handoff_task(store, id, to, from.as_deref(), summary)?,
+            dry_run,
+        } => handoff_task(
+            store,
+            id,
+            to,
+            from.as_deref(),
+            summary,
+            *dry_run || external_action::dry_run_enabled(),
+        )?,
         TodoCommand::AddOwner {
             id,
             agent,
@@ -4605,8 +6394,7 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
             category,
             level,
         } => {
-            let default_agent =
-                env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+            let default_agent = crate::core::identity::current_agent_id();
             let agent_id = agent.as_deref().unwrap_or(&default_agent);
             register_agent_expertise(root, agent_id, category, level)?
         }
@@ -4623,7 +6411,7 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
     };
 
     match cli.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Jsonl => {
             println!("{}", serde_json::to_string_pretty(&out).unwrap());
         }
         OutputFormat::Text => match &cli.command {
@@ -4647,7 +6435,7 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
                     println!("No tasks found.");
                 }
             }
-            TodoCommand::Categories => {
+            TodoCommand::Categories { action: None } => {
                 if let Some(cats) = out.get("categories").and_then(|x| x.as_array()) {
                     if cats.is_empty() {
                         println!("No categories defined.");
@@ -4743,6 +6531,53 @@ pub fn run_todo_cli(store: &Store, cli: TodoCli) -> Result<(), error::DecapodErr
                     }
                 }
             }
+            TodoCommand::Reclassify { dry_run, .. } => {
+                let changes = out.get("changes").and_then(|x| x.as_array());
+                match changes {
+                    Some(arr) if !arr.is_empty() => {
+                        let verb = if *dry_run {
+                            "Would reclassify"
+                        } else {
+                            "Reclassified"
+                        };
+                        println!("{} {} task(s):", verb, arr.len());
+                        for v in arr {
+                            let id = v.get("id").and_then(|x| x.as_str()).unwrap_or("?");
+                            let mut parts = Vec::new();
+                            for field in ["category", "component"] {
+                                if let Some(pair) = v.get(field).and_then(|x| x.as_array()) {
+                                    let from = pair[0].as_str().unwrap_or("");
+                                    let to = pair[1].as_str().unwrap_or("");
+                                    let from = if from.is_empty() { "-" } else { from };
+                                    parts.push(format!("{}: {} -> {}", field, from, to));
+                                }
+                            }
+                            println!("- {} {}", id, parts.join(", "));
+                        }
+                    }
+                    _ => println!("No tasks need reclassification."),
+                }
+            }
+            TodoCommand::Due { within } => {
+                let items = out.get("items").and_then(|x| x.as_array());
+                match items {
+                    Some(arr) if !arr.is_empty() => {
+                        println!("Tasks overdue or due within {} day(s):", within);
+                        for v in arr {
+                            let id = v.get("id").and_then(|x| x.as_str()).unwrap_or("?");
+                            let due = v.get("due").and_then(|x| x.as_str()).unwrap_or("?");
+                            let title = v.get("title").and_then(|x| x.as_str()).unwrap_or("");
+                            let flag = if v.get("overdue").and_then(|x| x.as_bool()) == Some(true) {
+                                "OVERDUE"
+                            } else {
+                                "due"
+                            };
+                            println!("- {} [{} {}] {}", id, flag, due, title);
+                        }
+                    }
+                    _ => println!("No tasks due within {} day(s).", within),
+                }
+            }
             TodoCommand::Expertise { .. } => {
                 if let Some(expertise) = out.get("expertise").and_then(|x| x.as_array()) {
                     if expertise.is_empty() {
@@ -15,6 +15,12 @@ pub struct TraceEvent {
     pub op: String,
     pub request: Value,
     pub response: Value,
+    /// Wall-clock time spent dispatching the op; absent in older traces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// `ok` or `error`, from the response envelope; absent in older traces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
 }
 
 /// Patterns that detect secrets in string content.
@@ -135,6 +141,8 @@ pub fn append_trace(project_root: &Path, event: TraceEvent) -> Result<(), Decapo
         op: event.op,
         request: redact(event.request),
         response: redact(event.response),
+        duration_ms: event.duration_ms,
+        status: event.status,
     };
 
     let json = serde_json::to_string(&redacted_event)
@@ -156,6 +164,105 @@ pub fn get_last_traces(project_root: &Path, n: usize) -> Result<Vec<String>, Dec
     Ok(lines[start..].to_vec())
 }
 
+/// Aggregate view of one op across the trace log.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OpStats {
+    pub op: String,
+    pub count: usize,
+    pub errors: usize,
+    pub error_rate: f64,
+    /// Latency percentiles over traces that recorded `duration_ms`.
+    pub p50_ms: Option<u64>,
+    pub p95_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TraceStats {
+    pub total: usize,
+    pub ops: Vec<OpStats>,
+}
+
+/// A response counts as an error when its envelope says `success: false`
+/// or carries an `error` object.
+fn is_error_response(response: &Value) -> bool {
+    response.get("success").and_then(Value::as_bool) == Some(false)
+        || response.get("error").is_some_and(|e| !e.is_null())
+}
+
+/// Trace status for a response envelope: `error` or `ok`.
+pub fn response_status(response: &Value) -> &'static str {
+    if is_error_response(response) {
+        "error"
+    } else {
+        "ok"
+    }
+}
+
+/// Nearest-rank percentile over an ascending slice.
+fn percentile(sorted: &[u64], pct: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted.get(rank - 1).copied()
+}
+
+/// Per-op counts, error rates, and latency percentiles, optionally limited
+/// to traces at or after `since` (epoch seconds). Unparseable lines are skipped.
+pub fn trace_stats(project_root: &Path, since: Option<u64>) -> Result<TraceStats, DecapodError> {
+    let trace_path = project_root.join(".decapod/data/traces.jsonl");
+    if !trace_path.exists() {
+        return Ok(TraceStats {
+            total: 0,
+            ops: vec![],
+        });
+    }
+
+    let content = std::fs::read_to_string(trace_path).map_err(DecapodError::IoError)?;
+    let mut by_op: std::collections::BTreeMap<String, (usize, usize, Vec<u64>)> =
+        std::collections::BTreeMap::new();
+    let mut total = 0;
+    for line in content.lines() {
+        let Ok(event) = serde_json::from_str::<TraceEvent>(line) else {
+            continue;
+        };
+        if let Some(since) = since
+            && crate::core::time::parse_event_ts_epoch(&event.ts).is_none_or(|ts| ts < since)
+        {
+            continue;
+        }
+        total += 1;
+        let entry = by_op.entry(event.op).or_default();
+        entry.0 += 1;
+        let failed = match event.status.as_deref() {
+            Some(status) => status == "error",
+            None => is_error_response(&event.response),
+        };
+        if failed {
+            entry.1 += 1;
+        }
+        if let Some(ms) = event.duration_ms {
+            entry.2.push(ms);
+        }
+    }
+
+    let ops = by_op
+        .into_iter()
+        .map(|(op, (count, errors, mut durations))| {
+            durations.sort_unstable();
+            OpStats {
+                op,
+                count,
+                errors,
+                error_rate: errors as f64 / count as f64,
+                p50_ms: percentile(&durations, 50),
+                p95_ms: percentile(&durations, 95),
+            }
+        })
+        .collect();
+    Ok(TraceStats { total, ops })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,4 +337,61 @@ mod tests {
         let input = "this is a normal log message with no secrets";
         assert_eq!(redact_string(input), input);
     }
+
+    #[test]
+    fn test_trace_stats_aggregates_counts_error_rates_and_latency() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let seeded = [
+            ("t1", "1771495200Z", "todo.add", true, Some(10)),
+            ("t2", "1771495260Z", "todo.add", true, Some(30)),
+            ("t3", "1771495320Z", "todo.add", false, Some(20)),
+            ("t4", "1771495380Z", "todo.add", true, None),
+            ("t5", "1771495440Z", "schema.get", true, Some(5)),
+        ];
+        for (id, ts, op, ok, duration_ms) in seeded {
+            let response = if ok {
+                serde_json::json!({"success": true})
+            } else {
+                serde_json::json!({"success": false, "error": {"code": "boom", "message": "x"}})
+            };
+            append_trace(
+                root,
+                TraceEvent {
+                    trace_id: id.to_string(),
+                    ts: ts.to_string(),
+                    actor: "agent-1".to_string(),
+                    op: op.to_string(),
+                    request: serde_json::json!({}),
+                    status: Some(response_status(&response).to_string()),
+                    response,
+                    duration_ms,
+                },
+            )
+            .unwrap();
+        }
+        // Pre-duration traces still parse.
+        let path = root.join(".decapod/data/traces.jsonl");
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(
+            file,
+            r#"{{"trace_id":"old","ts":"1771495500Z","actor":"a","op":"schema.get","request":{{}},"response":{{"success":true}}}}"#
+        )
+        .unwrap();
+
+        let stats = trace_stats(root, None).unwrap();
+        assert_eq!(stats.total, 6);
+        let add = stats.ops.iter().find(|o| o.op == "todo.add").unwrap();
+        assert_eq!((add.count, add.errors), (4, 1));
+        assert_eq!(add.error_rate, 0.25);
+        assert_eq!((add.p50_ms, add.p95_ms), (Some(20), Some(30)));
+        let schema = stats.ops.iter().find(|o| o.op == "schema.get").unwrap();
+        assert_eq!((schema.count, schema.errors), (2, 0));
+        assert_eq!(schema.p50_ms, Some(5));
+
+        let windowed = trace_stats(root, Some(1_771_495_400)).unwrap();
+        assert_eq!(windowed.total, 2);
+        assert_eq!(windowed.ops.len(), 1);
+        assert_eq!(windowed.ops[0].op, "schema.get");
+    }
 }
@@ -24,13 +24,13 @@ use crate::plugins::aptitude::{SkillCard, SkillResolution};
 use crate::plugins::internalize::{self, DeterminismClass, InternalizationManifest, ReplayClass};
 use crate::{db, primitives, todo};
 use fancy_regex::Regex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Mutex, mpsc};
 use std::time::{Duration, Instant};
 
 /// Spawn a validation gate in a rayon scope with timing and error capture.
@@ -39,13 +39,140 @@ use std::time::{Duration, Instant};
 macro_rules! gate {
     ($_scope:expr, $timings:expr, $ctx:expr, $name:literal, $body:expr) => {{
         let start = Instant::now();
+        let fails_before = $ctx.fail_count.load(Ordering::Relaxed);
+        mark_gate_started($name, start);
         if let Err(e) = $body {
             fail(&format!("gate error: {e}"), $ctx);
         }
-        $timings.lock().unwrap().push(($name, start.elapsed()));
+        let gate_fails = $ctx.fail_count.load(Ordering::Relaxed) - fails_before;
+        $timings
+            .lock()
+            .unwrap()
+            .push(($name, start.elapsed(), gate_fails));
     }};
 }
 
+/// Gate currently running in this process and when it started.
+pub type GateProgress = Mutex<Option<(&'static str, Instant)>>;
+
+static GATE_PROGRESS: GateProgress = Mutex::new(None);
+
+/// Progress of the in-flight `run_validation`, polled by the bounded runner.
+pub fn gate_progress() -> &'static GateProgress {
+    &GATE_PROGRESS
+}
+
+fn mark_gate_started(name: &'static str, start: Instant) {
+    *GATE_PROGRESS.lock().unwrap() = Some((name, start));
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ValidateConfigSection {
+    #[serde(default)]
+    gate_timeouts: BTreeMap<String, u64>,
+}
+
+/// Wall-clock bounds for a validate run.
+///
+/// Every gate is bounded by `default` (the global `validate.timeout_secs`)
+/// unless `[validate.gate_timeouts]` names it. Overrides below the default
+/// tighten a gate; overrides above it give a known-slow gate headroom, and
+/// that excess is added to the overall bound so other gates keep theirs.
+#[derive(Debug, Clone)]
+pub struct GateTimeouts {
+    pub default: Duration,
+    pub overrides: BTreeMap<String, Duration>,
+}
+
+impl GateTimeouts {
+    pub fn new(default: Duration) -> Self {
+        Self {
+            default,
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    /// Global bound plus `[validate.gate_timeouts]` from `.decapod/config.toml`.
+    /// Keys may omit the `validate_` prefix.
+    pub fn load(repo_root: &Path, default_secs: u64) -> Self {
+        let section: ValidateConfigSection =
+            crate::core::settings::load_section(repo_root, "validate");
+        let mut timeouts = Self::new(Duration::from_secs(default_secs));
+        for (gate, secs) in section.gate_timeouts {
+            if secs == 0 {
+                continue;
+            }
+            let gate = if gate.starts_with("validate_") {
+                gate
+            } else {
+                format!("validate_{gate}")
+            };
+            timeouts.overrides.insert(gate, Duration::from_secs(secs));
+        }
+        timeouts
+    }
+
+    pub fn bound_for(&self, gate: &str) -> Duration {
+        self.overrides.get(gate).copied().unwrap_or(self.default)
+    }
+
+    pub fn overall(&self) -> Duration {
+        self.default
+            + self
+                .overrides
+                .values()
+                .map(|d| d.saturating_sub(self.default))
+                .sum::<Duration>()
+    }
+}
+
+/// Why [`wait_for_validation`] gave up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationWaitError {
+    /// A single gate ran past its bound.
+    GateTimeout {
+        gate: String,
+        bound: Duration,
+    },
+    /// The run as a whole ran past [`GateTimeouts::overall`].
+    RunTimeout(Duration),
+    Disconnected,
+}
+
+/// Wait for a validation worker's result on `rx`, enforcing per-gate bounds
+/// by polling `progress` and the overall bound by wall clock.
+pub fn wait_for_validation<T>(
+    rx: &mpsc::Receiver<T>,
+    timeouts: &GateTimeouts,
+    progress: &GateProgress,
+) -> Result<T, ValidationWaitError> {
+    const POLL: Duration = Duration::from_millis(50);
+    let started = Instant::now();
+    let overall = timeouts.overall();
+    loop {
+        let remaining = overall.saturating_sub(started.elapsed());
+        if remaining.is_zero() {
+            return Err(ValidationWaitError::RunTimeout(overall));
+        }
+        match rx.recv_timeout(remaining.min(POLL)) {
+            Ok(result) => return Ok(result),
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                return Err(ValidationWaitError::Disconnected);
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+        }
+        if let Some((gate, gate_start)) = *progress.lock().unwrap() {
+            let bound = timeouts.bound_for(gate);
+            if gate_start.elapsed() > bound {
+                return Err(ValidationWaitError::GateTimeout {
+                    gate: gate.to_string(),
+                    bound,
+                });
+            }
+        }
+    }
+}
+
 struct ValidationContext {
     pass_count: AtomicU32,
     fail_count: AtomicU32,
@@ -59,6 +186,24 @@ struct ValidationContext {
 pub struct ValidationGateTiming {
     pub name: String,
     pub elapsed_ms: u64,
+    /// Store the gate ran against; set only in combined (`--store both`) reports.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store: Option<String>,
+    /// Failures recorded while this gate ran; zero means the gate passed.
+    pub fail_count: u32,
+    /// Command an agent can run to fix this gate; set only when it failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation_command: Option<String>,
+}
+
+impl ValidationGateTiming {
+    /// `store:name` in combined reports, otherwise the bare gate name.
+    pub fn label(&self) -> String {
+        match &self.store {
+            Some(store) => format!("{store}:{}", self.name),
+            None => self.name.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -2599,7 +2744,18 @@ fn validate_risk_map(store: &Store, ctx: &ValidationContext) -> Result<(), error
     info("Risk Map Gate");
     let map_path = store.root.join("RISKMAP.json");
     if map_path.exists() {
-        pass("Risk map (blast-radius) is present", ctx);
+        let issues = crate::plugins::policy::riskmap_issues(&fs::read_to_string(&map_path)?);
+        if issues.is_empty() {
+            pass("Risk map (blast-radius) is present", ctx);
+        } else {
+            fail(
+                &format!(
+                    "Risk map is malformed and would disable risk gating: {}",
+                    issues.join("; ")
+                ),
+                ctx,
+            );
+        }
     } else {
         warn("Risk map missing (run `decapod riskmap init`)", ctx);
     }
@@ -4108,6 +4264,27 @@ fn validate_obligations(store: &Store, ctx: &ValidationContext) -> Result<(), er
             }
         }
     }
+
+    let check = crate::core::obligation::check_obligation_graph(store)?;
+    for cycle in &check.cycles {
+        fail(
+            &format!(
+                "Obligation dependency cycle: {} -> {}",
+                cycle.join(" -> "),
+                cycle[0]
+            ),
+            ctx,
+        );
+    }
+    for u in &check.unsatisfiable {
+        fail(
+            &format!(
+                "Obligation {} can never be met: {} ({})",
+                u.obligation_id, u.reason, u.depends_on
+            ),
+            ctx,
+        );
+    }
     pass(
         &format!(
             "Obligation Graph Validation Gate ({} met nodes verified)",
@@ -4177,17 +4354,8 @@ fn validate_gatekeeper_gate(
     info("Gatekeeper Safety Gate");
 
     // Get staged files from git (if in a git repo)
-    let output = std::process::Command::new("git")
-        .args(["diff", "--cached", "--name-only"])
-        .current_dir(decapod_dir)
-        .output();
-
-    let staged_paths: Vec<PathBuf> = match output {
-        Ok(o) if o.status.success() => String::from_utf8_lossy(&o.stdout)
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(PathBuf::from)
-            .collect(),
+    let staged_paths: Vec<PathBuf> = match crate::core::gatekeeper::staged_paths(decapod_dir) {
+        Ok(Some(paths)) => paths,
         _ => {
             skip(
                 "Git not available or not in a repo; skipping gatekeeper gate",
@@ -4246,16 +4414,27 @@ fn validate_gatekeeper_gate(
     Ok(())
 }
 
-/// Evaluates a set of mandates and returns any active blockers.
+/// A mandate whose check failed, with the blocker it raised.
+#[derive(Debug, Clone, Serialize)]
+pub struct MandateViolation {
+    pub mandate_id: String,
+    pub title: String,
+    pub severity: String,
+    pub blocker: crate::core::rpc::Blocker,
+}
+
+/// Check every mandate and return all violations, in mandate order, so a
+/// caller can report them together instead of one per retry.
 pub fn evaluate_mandates(
     project_root: &Path,
     store: &Store,
     mandates: &[crate::core::docs::Mandate],
-) -> Vec<crate::core::rpc::Blocker> {
+) -> Vec<MandateViolation> {
     use crate::core::rpc::{Blocker, BlockerKind};
-    let mut blockers = Vec::new();
+    let mut violations = Vec::new();
 
     for mandate in mandates {
+        let mut blockers = Vec::new();
         match mandate.check_tag.as_str() {
             "gate.worktree.no_master" => {
                 let status = crate::core::workspace::get_workspace_status(project_root);
@@ -4289,8 +4468,7 @@ pub fn evaluate_mandates(
                 // but we can add a blocker if we want more detail.
             }
             "gate.todo.active_task" => {
-                let agent_id =
-                    std::env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+                let agent_id = crate::core::identity::current_agent_id();
                 if agent_id != "unknown" {
                     let mut active_tasks = crate::core::todo::list_tasks(
                         &store.root,
@@ -4331,9 +4509,15 @@ pub fn evaluate_mandates(
             }
             _ => {}
         }
+        violations.extend(blockers.into_iter().map(|blocker| MandateViolation {
+            mandate_id: mandate.id.clone(),
+            title: mandate.fragment.title.clone(),
+            severity: mandate.severity.clone(),
+            blocker,
+        }));
     }
 
-    blockers
+    violations
 }
 
 /// Co-Player Policy Tightening Gate
@@ -4427,21 +4611,25 @@ pub fn run_validation(
     };
 
     // Store validations — run sequentially since they set up state
-    match store.kind {
+    let start = Instant::now();
+    let store_gate_fails_before = ctx.fail_count.load(Ordering::Relaxed);
+    let store_gate = match store.kind {
         StoreKind::User => {
-            let start = Instant::now();
+            mark_gate_started("validate_user_store_blank_slate", start);
             validate_user_store_blank_slate(&ctx)?;
-            let _ = start;
+            "validate_user_store_blank_slate"
         }
         StoreKind::Repo => {
-            let start = Instant::now();
+            mark_gate_started("validate_repo_store_dogfood", start);
             validate_repo_store_dogfood(store, &ctx, decapod_dir)?;
-            let _ = start;
+            "validate_repo_store_dogfood"
         }
-    }
+    };
 
     // Run remaining gates in parallel for bounded wall-clock validation time.
-    let timings: Mutex<Vec<(&str, Duration)>> = Mutex::new(Vec::new());
+    let store_gate_fails = ctx.fail_count.load(Ordering::Relaxed) - store_gate_fails_before;
+    let timings: Mutex<Vec<(&str, Duration, u32)>> =
+        Mutex::new(vec![(store_gate, start.elapsed(), store_gate_fails)]);
     {
         let _s = ();
         let ctx = &ctx;
@@ -4787,6 +4975,7 @@ pub fn run_validation(
         );
     }
 
+    *GATE_PROGRESS.lock().unwrap() = None;
     let elapsed = total_start.elapsed();
     let pass_count = ctx.pass_count.load(Ordering::Relaxed);
     let fail_count = ctx.fail_count.load(Ordering::Relaxed);
@@ -4796,7 +4985,7 @@ pub fn run_validation(
     let fail_total = (fails.len() as u32).max(fail_count);
     let warn_total = (warns.len() as u32).max(warn_count);
     let mut gate_timings = timings.into_inner().unwrap();
-    gate_timings.sort_by(|a, b| b.1.cmp(&a.1));
+    gate_timings.sort_by_key(|b| std::cmp::Reverse(b.1));
 
     Ok(ValidationReport {
         status: if fail_total > 0 { "fail" } else { "ok" }.to_string(),
@@ -4808,14 +4997,66 @@ pub fn run_validation(
         warnings: warns,
         gate_timings: gate_timings
             .into_iter()
-            .map(|(name, elapsed)| ValidationGateTiming {
+            .map(|(name, elapsed, fail_count)| ValidationGateTiming {
                 name: name.to_string(),
                 elapsed_ms: elapsed.as_millis() as u64,
+                store: None,
+                fail_count,
+                remediation_command: (fail_count > 0)
+                    .then(|| gate_remediation_command(name))
+                    .flatten()
+                    .map(str::to_string),
             })
             .collect(),
     })
 }
 
+/// Merge per-store reports into one, labeling every gate, failure, and
+/// warning with the store it came from. Fails if any store failed.
+pub fn merge_store_reports(reports: Vec<(&str, ValidationReport)>) -> ValidationReport {
+    let mut merged = ValidationReport {
+        status: "ok".to_string(),
+        elapsed_ms: 0,
+        pass_count: 0,
+        fail_count: 0,
+        warn_count: 0,
+        failures: Vec::new(),
+        warnings: Vec::new(),
+        gate_timings: Vec::new(),
+    };
+    for (store, report) in reports {
+        merged.elapsed_ms += report.elapsed_ms;
+        merged.pass_count += report.pass_count;
+        merged.fail_count += report.fail_count;
+        merged.warn_count += report.warn_count;
+        merged.failures.extend(
+            report
+                .failures
+                .into_iter()
+                .map(|f| format!("[{store}] {f}")),
+        );
+        merged.warnings.extend(
+            report
+                .warnings
+                .into_iter()
+                .map(|w| format!("[{store}] {w}")),
+        );
+        merged.gate_timings.extend(
+            report
+                .gate_timings
+                .into_iter()
+                .map(|g| ValidationGateTiming {
+                    store: Some(store.to_string()),
+                    ..g
+                }),
+        );
+    }
+    if merged.fail_count > 0 {
+        merged.status = "fail".to_string();
+    }
+    merged
+}
+
 pub fn render_validation_report(report: &ValidationReport, verbose: bool) {
     use crate::core::ansi::AnsiExt;
 
@@ -4846,10 +5087,11 @@ pub fn render_validation_report(report: &ValidationReport, verbose: bool) {
             "timings".bright_white()
         );
         for gate in &report.gate_timings {
+            let label = gate.label();
             println!(
                 "  {} [{}] {}ms",
                 "✓".bright_green(),
-                gate.name.bright_cyan(),
+                label.bright_cyan(),
                 gate.elapsed_ms
             );
         }
@@ -4870,6 +5112,16 @@ pub fn render_validation_report(report: &ValidationReport, verbose: bool) {
             "failures".bright_red().bold(),
             output::preview_messages(&report.failures, 3, 120)
         );
+        for gate in &report.gate_timings {
+            if let Some(cmd) = &gate.remediation_command {
+                println!(
+                    "  {} [{}] {}",
+                    "fix".bright_cyan().bold(),
+                    gate.label(),
+                    cmd
+                );
+            }
+        }
     }
 
     if !report.warnings.is_empty() {
@@ -4888,3 +5140,654 @@ pub fn render_validation_report(report: &ValidationReport, verbose: bool) {
         );
     }
 }
+
+/// Static description of a validation gate, used by `validate --explain`.
+struct GateDoc {
+    name: &'static str,
+    purpose: &'static str,
+    remediation: &'static [&'static str],
+    /// Exact `decapod ...` invocation that fixes the failure, when one exists.
+    remediation_command: Option<&'static str>,
+    /// Whether `decapod validate` self-heal can repair this gate's failures.
+    auto_remediable: bool,
+}
+
+const GATE_DOCS: &[GateDoc] = &[
+    GateDoc {
+        name: "validate_user_store_blank_slate",
+        purpose: "User store starts empty; Decapod never seeds it automatically.",
+        remediation: &["Remove seeded records from the user store and re-run with `--store user`."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_repo_store_dogfood",
+        purpose: "Repo todo.db exists and matches a deterministic rebuild from todo.events.jsonl.",
+        remediation: &[
+            "Run `decapod todo rebuild` to regenerate todo.db from the event log.",
+            "Never edit .decapod/data/todo.db directly; mutate through `decapod todo`.",
+        ],
+        remediation_command: Some("decapod todo rebuild"),
+        auto_remediable: true,
+    },
+    GateDoc {
+        name: "validate_repo_map",
+        purpose: "Core constitution docs are present in the embedded asset set.",
+        remediation: &["Rebuild the binary or run `decapod init --force` to restore entrypoints."],
+        remediation_command: Some("decapod init --force"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_no_legacy_namespaces",
+        purpose: "No deprecated namespaces or removed command paths are referenced.",
+        remediation: &["Replace legacy references with the routes listed in core/DEPRECATION.md."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_embedded_self_contained",
+        purpose: "Embedded constitution docs do not depend on repo-local copies.",
+        remediation: &[
+            "Move project-specific policy into .decapod/OVERRIDE.md instead of editing embedded docs.",
+        ],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_docs_templates_bucket",
+        purpose: "Root agent entrypoints (AGENTS.md, CLAUDE.md, ...) are present.",
+        remediation: &["Run `decapod init --force` to regenerate missing entrypoints."],
+        remediation_command: Some("decapod init --force"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_entrypoint_invariants",
+        purpose: "AGENTS.md carries every required agent invariant.",
+        remediation: &[
+            "Restore AGENTS.md from the Decapod template or run `decapod init --force`.",
+        ],
+        remediation_command: Some("decapod init --force"),
+        auto_remediable: true,
+    },
+    GateDoc {
+        name: "validate_interface_contract_bootstrap",
+        purpose: "Interface contract bootstrap files exist at their canonical paths.",
+        remediation: &["Run `decapod init --force` to restore missing interface contracts."],
+        remediation_command: Some("decapod init --force"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_health_purity",
+        purpose: "Health state is derived from proof events, never written directly.",
+        remediation: &[
+            "Record outcomes with `decapod govern proof run` instead of editing health data.",
+        ],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_project_scoped_state",
+        purpose: "All Decapod state lives inside the project's .decapod/ directory.",
+        remediation: &["Move stray state files under .decapod/ and remove the originals."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_generated_artifact_whitelist",
+        purpose: ".gitignore keeps generated artifacts out of version control.",
+        remediation: &["Add the missing rules to .gitignore or run `decapod init --force`."],
+        remediation_command: Some("decapod init --force"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_project_config_toml",
+        purpose: ".decapod/config.toml parses and carries a supported schema_version.",
+        remediation: &["Fix the TOML syntax and set `schema_version = \"1.0.0\"`."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_project_specs_docs",
+        purpose: "The canonical local project specs set exists and its manifest is current.",
+        remediation: &[
+            "Run `decapod init --force` to scaffold missing specs files.",
+            "Refresh specs content after repo-signal changes so the manifest fingerprint matches.",
+        ],
+        remediation_command: Some("decapod init --force"),
+        auto_remediable: true,
+    },
+    GateDoc {
+        name: "validate_spec_drift",
+        purpose: "INTERFACES.md stays consistent with the implemented subsystems.",
+        remediation: &["Update INTERFACES.md to describe only implemented interfaces."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_machine_contract",
+        purpose: "The machine contract's interlock codes match the running binary.",
+        remediation: &["Regenerate the machine contract with the current binary."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_workunit_manifests_if_present",
+        purpose: "Workunit manifests parse and satisfy their status/proof invariants.",
+        remediation: &["Fix or regenerate invalid manifests via `decapod govern workunit`."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_context_capsule_policy_contract",
+        purpose: "The context capsule policy file matches the supported schema.",
+        remediation: &["Run `decapod init --force` to restore the capsule policy scaffold."],
+        remediation_command: Some("decapod init --force"),
+        auto_remediable: true,
+    },
+    GateDoc {
+        name: "validate_context_capsules_if_present",
+        purpose: "Stored context capsules are deterministic and hash-consistent.",
+        remediation: &[
+            "Delete the corrupt capsule and re-issue it through `decapod govern capsule query`.",
+        ],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_knowledge_promotions_if_present",
+        purpose: "Knowledge promotions reference existing evidence and approvals.",
+        remediation: &["Re-run the promotion with complete evidence, or remove the invalid entry."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_skill_cards_if_present",
+        purpose: "Skill cards parse and carry valid hashes.",
+        remediation: &["Regenerate the skill card with `decapod data aptitude skill`."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_skill_resolutions_if_present",
+        purpose: "Skill resolutions reference known skill cards deterministically.",
+        remediation: &["Re-resolve skills so every resolution points at a current card."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_internalization_artifacts_if_present",
+        purpose: "Internalization manifests satisfy their determinism and replay contract.",
+        remediation: &["Re-create the artifact via `decapod internalize` or remove it."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_eval_gate_if_required",
+        purpose: "A required eval verdict exists before promotion.",
+        remediation: &["Run the configured eval and record its verdict."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_schema_determinism",
+        purpose: "`decapod data schema` output is byte-identical across runs.",
+        remediation: &[
+            "Remove nondeterministic ordering (e.g. HashMap iteration) from schema output.",
+        ],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_database_schema_versions",
+        purpose: "Every subsystem database is at the schema version the binary expects.",
+        remediation: &["Run any `decapod` command to apply pending migrations, then re-validate."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_health_cache_integrity",
+        purpose: "The health cache holds no entries for unknown claims.",
+        remediation: &["Drop orphaned cache entries by re-running proofs for live claims."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_risk_map",
+        purpose: "A blast-radius risk map is present.",
+        remediation: &["Run `decapod govern policy riskmap init`."],
+        remediation_command: Some("decapod govern policy riskmap init"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_risk_map_violations",
+        purpose: "The audit log records no writes into protected risk zones.",
+        remediation: &["Revert the offending change or obtain approval for the zone."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_policy_integrity",
+        purpose: "Policy approvals are consistent and unexpired.",
+        remediation: &["Re-request approval through `decapod govern policy`."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_knowledge_integrity",
+        purpose: "Knowledge entries carry provenance and valid lifecycle state.",
+        remediation: &["Add provenance to the flagged entries or retire them."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_lineage_hard_gate",
+        purpose: "Intent-tagged task events link back to federation lineage nodes.",
+        remediation: &["Record the missing lineage edge via `decapod data federation`."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_repomap_determinism",
+        purpose: "Repo map output is byte-identical across runs.",
+        remediation: &["Remove nondeterministic ordering from repo map generation."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_watcher_audit",
+        purpose: "The watcher has left an audit trail.",
+        remediation: &["Run `decapod govern watcher run` to record a watcher pass."],
+        remediation_command: Some("decapod govern watcher run"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_watcher_purity",
+        purpose: "Watcher runs perform read-only checks.",
+        remediation: &["Remove mutating commands from the watcher check list."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_archive_integrity",
+        purpose: "Archived session files still match their recorded hashes.",
+        remediation: &["Restore the archive from history; archived files are immutable."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_control_plane_contract",
+        purpose: "Broker events show mutations routed through the control plane.",
+        remediation: &[
+            "Route writes through `decapod` commands instead of touching stores directly.",
+        ],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_canon_mutation",
+        purpose: "Canonical documents were not mutated outside of Decapod.",
+        remediation: &[
+            "Revert direct edits to canon and apply changes through the documented surface.",
+        ],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_heartbeat_invocation_gate",
+        purpose: "Agents invoke the Decapod heartbeat as the control-plane contract requires.",
+        remediation: &["Invoke `decapod todo heartbeat` during long-running work."],
+        remediation_command: Some("decapod todo heartbeat"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_markdown_primitives_roundtrip_gate",
+        purpose: "Markdown primitives survive a parse/render round-trip unchanged.",
+        remediation: &["Fix the malformed markdown primitive reported in the failure."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_federation_gates",
+        purpose: "Federation nodes and edges satisfy write-safety and lifecycle rules.",
+        remediation: &["Repair the flagged nodes via `decapod data federation`."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_git_workspace_context",
+        purpose: "Work happens in an isolated worktree (and container when required), committed often.",
+        remediation: &[
+            "Run `decapod workspace ensure` and work from the created worktree.",
+            "Commit pending changes before validating.",
+        ],
+        remediation_command: Some("decapod workspace ensure"),
+        auto_remediable: true,
+    },
+    GateDoc {
+        name: "validate_git_protected_branch",
+        purpose: "No commits land directly on protected branches.",
+        remediation: &["Move the work to a feature branch via `decapod workspace ensure`."],
+        remediation_command: Some("decapod workspace ensure"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_tooling_gate",
+        purpose: "Formatting, linting, and type checks pass before promotion.",
+        remediation: &[
+            "Run `cargo fmt --all`.",
+            "Run `cargo clippy --all-targets -- -D warnings` and fix findings.",
+        ],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_state_commit_gate",
+        purpose: "STATE_COMMIT golden vectors are present and reproduce.",
+        remediation: &["Restore the golden vectors under tests/golden/state_commit."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_obligations",
+        purpose: "Met obligations still hold and the dependency graph has no deadlocks.",
+        remediation: &[
+            "Inspect cycles and unsatisfiable prerequisites with `decapod obligation check`.",
+            "Resolve or close the failing obligations via `decapod obligation`.",
+        ],
+        remediation_command: Some("decapod obligation check"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_gatekeeper_gate",
+        purpose: "Staged files contain no secrets or blocked paths.",
+        remediation: &["Unstage the flagged files and remove embedded secrets."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_coplayer_policy_tightening",
+        purpose: "Co-player policies only tighten as agent reliability decreases.",
+        remediation: &["Fix the policy derivation so lower reliability never loosens constraints."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_lcm_immutability",
+        purpose: "The LCM ledger is append-only and hash-consistent.",
+        remediation: &["Restore the ledger from history; never rewrite LCM entries."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_lcm_rebuild_gate",
+        purpose: "The LCM index rebuilds deterministically from the ledger.",
+        remediation: &["Run `decapod lcm rebuild` and investigate any mismatch."],
+        remediation_command: Some("decapod lcm rebuild"),
+        auto_remediable: false,
+    },
+    GateDoc {
+        name: "validate_plan_governed_execution_gate",
+        purpose: "Plan artifacts allow governed execution of in-flight work.",
+        remediation: &["Resolve open unknowns and approve the plan via `decapod govern plan`."],
+        remediation_command: None,
+        auto_remediable: false,
+    },
+];
+
+/// Everything `decapod validate --explain <gate>` reports about a gate.
+#[derive(Debug, Clone, Serialize)]
+pub struct GateExplanation {
+    pub gate: String,
+    pub purpose: String,
+    pub doc_ref: Option<String>,
+    pub fragment: Option<crate::core::docs::DocFragment>,
+    pub remediation: Vec<String>,
+    pub remediation_command: Option<String>,
+    pub auto_remediable: bool,
+}
+
+/// The runnable fix for a gate, if its doc names one.
+pub fn gate_remediation_command(gate: &str) -> Option<&'static str> {
+    GATE_DOCS
+        .iter()
+        .find(|g| g.name == gate)
+        .and_then(|g| g.remediation_command)
+}
+
+/// Names of every gate `--explain` knows about, in run order.
+pub fn gate_names() -> Vec<&'static str> {
+    GATE_DOCS.iter().map(|g| g.name).collect()
+}
+
+/// Describes a gate without running it. Accepts the name with or without
+/// the `validate_` prefix.
+pub fn explain_gate(repo_root: &Path, gate: &str) -> Option<GateExplanation> {
+    let wanted = gate.trim();
+    let doc = GATE_DOCS
+        .iter()
+        .find(|g| g.name == wanted || g.name.strip_prefix("validate_") == Some(wanted))?;
+    let doc_ref = crate::core::docs::get_bindings(repo_root)
+        .gates
+        .get(doc.name)
+        .cloned();
+    let fragment = doc_ref.as_deref().and_then(|r| {
+        let (path, anchor) = crate::core::docs::split_doc_ref(r);
+        crate::core::docs::get_fragment(repo_root, path, anchor)
+    });
+    Some(GateExplanation {
+        gate: doc.name.to_string(),
+        purpose: doc.purpose.to_string(),
+        doc_ref,
+        fragment,
+        remediation: doc.remediation.iter().map(|s| s.to_string()).collect(),
+        remediation_command: doc.remediation_command.map(str::to_string),
+        auto_remediable: doc.auto_remediable,
+    })
+}
+
+pub fn render_gate_explanation(explanation: &GateExplanation) {
+    use crate::core::ansi::AnsiExt;
+
+    println!(
+        "{} {}",
+        "gate".bright_magenta().bold(),
+        explanation.gate.bright_cyan().bold()
+    );
+    println!("  {} {}", "purpose".bright_cyan(), explanation.purpose);
+    match (&explanation.doc_ref, &explanation.fragment) {
+        (Some(doc_ref), Some(fragment)) => {
+            println!(
+                "  {} {} ({})",
+                "binds".bright_cyan(),
+                doc_ref,
+                fragment.title
+            );
+        }
+        (Some(doc_ref), None) => {
+            println!(
+                "  {} {} (fragment unavailable)",
+                "binds".bright_cyan(),
+                doc_ref
+            );
+        }
+        _ => println!("  {} none", "binds".bright_cyan()),
+    }
+    println!("  {}", "remediation".bright_cyan());
+    for step in &explanation.remediation {
+        println!("    - {step}");
+    }
+    if let Some(cmd) = &explanation.remediation_command {
+        println!("  {} {}", "run".bright_cyan(), cmd);
+    }
+    println!(
+        "  {} {}",
+        "auto-remediable".bright_cyan(),
+        if explanation.auto_remediable {
+            "yes"
+        } else {
+            "no"
+        }
+    );
+}
+
+pub fn validate_baseline_path(repo_root: &Path) -> PathBuf {
+    repo_root
+        .join(".decapod")
+        .join("generated")
+        .join("validate_baseline.json")
+}
+
+/// Per-gate outcomes of one validate run, as stored by `validate --baseline`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationBaseline {
+    pub schema_version: String,
+    pub ts: String,
+    /// Gate label (see [`ValidationGateTiming::label`]) to `pass` or `fail`.
+    pub gates: BTreeMap<String, String>,
+}
+
+/// Gate outcome changes relative to the previous baseline.
+#[derive(Debug, Clone, Serialize)]
+pub struct BaselineDelta {
+    /// When the compared baseline was recorded; `None` on the first run.
+    pub previous_ts: Option<String>,
+    /// Gates that passed in the baseline and fail now (regressions).
+    pub newly_failing: Vec<String>,
+    /// Gates that failed in the baseline and pass now.
+    pub newly_passing: Vec<String>,
+}
+
+pub fn gate_outcomes(report: &ValidationReport) -> BTreeMap<String, String> {
+    report
+        .gate_timings
+        .iter()
+        .map(|g| {
+            let outcome = if g.fail_count > 0 { "fail" } else { "pass" };
+            (g.label(), outcome.to_string())
+        })
+        .collect()
+}
+
+/// Compare `report` against the stored baseline, then replace the baseline
+/// with this run. Gates missing from either side are not reported as changes.
+pub fn compare_and_store_baseline(
+    repo_root: &Path,
+    report: &ValidationReport,
+) -> Result<BaselineDelta, error::DecapodError> {
+    let path = validate_baseline_path(repo_root);
+    let previous: Option<ValidationBaseline> = match fs::read_to_string(&path) {
+        Ok(raw) => Some(serde_json::from_str(&raw).map_err(|e| {
+            error::DecapodError::ValidationError(format!(
+                "Malformed validate baseline {} ({e}); delete it to start a new baseline",
+                path.display()
+            ))
+        })?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(error::DecapodError::IoError(e)),
+    };
+
+    let current = gate_outcomes(report);
+    let mut delta = BaselineDelta {
+        previous_ts: previous.as_ref().map(|b| b.ts.clone()),
+        newly_failing: Vec::new(),
+        newly_passing: Vec::new(),
+    };
+    if let Some(previous) = &previous {
+        for (gate, outcome) in &current {
+            match (
+                previous.gates.get(gate).map(String::as_str),
+                outcome.as_str(),
+            ) {
+                (Some("pass"), "fail") => delta.newly_failing.push(gate.clone()),
+                (Some("fail"), "pass") => delta.newly_passing.push(gate.clone()),
+                _ => {}
+            }
+        }
+    }
+
+    let baseline = ValidationBaseline {
+        schema_version: "1.0.0".to_string(),
+        ts: crate::core::time::now_epoch_z(),
+        gates: current,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(error::DecapodError::IoError)?;
+    }
+    let body = serde_json::to_string_pretty(&baseline)
+        .map_err(|e| error::DecapodError::ValidationError(e.to_string()))?;
+    fs::write(&path, body).map_err(error::DecapodError::IoError)?;
+    Ok(delta)
+}
+
+pub fn render_baseline_delta(delta: &BaselineDelta) {
+    use crate::core::ansi::AnsiExt;
+
+    let Some(previous_ts) = &delta.previous_ts else {
+        println!(
+            "  {} recorded (no previous baseline)",
+            "baseline".bright_cyan().bold()
+        );
+        return;
+    };
+    println!(
+        "  {} vs {}: {} regression(s), {} fixed",
+        "baseline".bright_cyan().bold(),
+        previous_ts,
+        delta.newly_failing.len().to_string().bright_red(),
+        delta.newly_passing.len().to_string().bright_green()
+    );
+    for gate in &delta.newly_failing {
+        println!("  {} {}", "✗ newly failing".bright_red(), gate);
+    }
+    for gate in &delta.newly_passing {
+        println!("  {} {}", "✓ newly passing".bright_green(), gate);
+    }
+}
+
+/// Number of validate runs kept in the local run history.
+const VALIDATE_HISTORY_LIMIT: usize = 20;
+
+pub fn validate_history_path(repo_root: &Path) -> PathBuf {
+    repo_root
+        .join(".decapod")
+        .join("generated")
+        .join("validate_runs.jsonl")
+}
+
+/// Append a summary of `report` to the local run history, keeping only the
+/// most recent [`VALIDATE_HISTORY_LIMIT`] runs.
+pub fn record_validation_run(
+    repo_root: &Path,
+    report: &ValidationReport,
+) -> Result<(), error::DecapodError> {
+    let path = validate_history_path(repo_root);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(error::DecapodError::IoError)?;
+    }
+    let mut entry = serde_json::to_value(report)
+        .map_err(|e| error::DecapodError::ValidationError(e.to_string()))?;
+    entry["ts"] = serde_json::json!(crate::core::time::now_epoch_z());
+
+    let mut lines: Vec<String> = fs::read_to_string(&path)
+        .unwrap_or_default()
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(str::to_string)
+        .collect();
+    lines.push(entry.to_string());
+    let keep = lines.len().saturating_sub(VALIDATE_HISTORY_LIMIT);
+    let mut body = lines[keep..].join("\n");
+    body.push('\n');
+    fs::write(&path, body).map_err(error::DecapodError::IoError)
+}
+
+/// The last `n` recorded validate runs, oldest first.
+pub fn recent_validation_runs(repo_root: &Path, n: usize) -> Vec<serde_json::Value> {
+    let runs: Vec<serde_json::Value> = fs::read_to_string(validate_history_path(repo_root))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect();
+    let skip = runs.len().saturating_sub(n);
+    runs.into_iter().skip(skip).collect()
+}
@@ -0,0 +1,267 @@
+//! Poll-based filesystem watcher for long-running `validate --watch` sessions.
+//!
+//! Decapod ships without a native notifier dependency, so the watcher diffs
+//! `(mtime, len)` snapshots of the repo tree on a fixed poll interval and
+//! debounces bursts of writes into a single re-run.
+
+use crate::core::error::DecapodError;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+/// Directories never worth re-running validation for.
+const SKIP_DIRS: &[&str] = &[".git", "target", "node_modules"];
+
+/// SQLite sidecar files churn on every read transaction.
+const SKIP_SUFFIXES: &[&str] = &["-wal", "-shm", "-journal", ".lock"];
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy)]
+pub struct WatchConfig {
+    pub poll_interval: Duration,
+    pub debounce: Duration,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(500),
+            debounce: Duration::from_millis(750),
+        }
+    }
+}
+
+impl WatchConfig {
+    /// Defaults, overridable via `DECAPOD_WATCH_POLL_MS` / `DECAPOD_WATCH_DEBOUNCE_MS`.
+    pub fn from_env() -> Self {
+        let ms = |key: &str| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|v| *v > 0)
+                .map(Duration::from_millis)
+        };
+        let defaults = Self::default();
+        Self {
+            poll_interval: ms("DECAPOD_WATCH_POLL_MS").unwrap_or(defaults.poll_interval),
+            debounce: ms("DECAPOD_WATCH_DEBOUNCE_MS").unwrap_or(defaults.debounce),
+        }
+    }
+}
+
+/// Point-in-time view of every watched file under the root.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeSnapshot {
+    entries: BTreeMap<PathBuf, (Option<SystemTime>, u64)>,
+}
+
+impl TreeSnapshot {
+    pub fn capture(root: &Path) -> Self {
+        let mut entries = BTreeMap::new();
+        collect(root, root, &mut entries);
+        Self { entries }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+fn is_watched(root: &Path, path: &Path, is_dir: bool) -> bool {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    if is_dir {
+        if SKIP_DIRS.contains(&name.as_str()) {
+            return false;
+        }
+        // Inside `.decapod`, only the data store is interesting.
+        if let Ok(rel) = path.strip_prefix(root) {
+            let mut parts = rel.components();
+            if let (Some(first), Some(second)) = (parts.next(), parts.next())
+                && first.as_os_str() == ".decapod"
+                && second.as_os_str() != "data"
+            {
+                return false;
+            }
+        }
+        return true;
+    }
+    !SKIP_SUFFIXES.iter().any(|s| name.ends_with(s))
+}
+
+fn collect(root: &Path, dir: &Path, out: &mut BTreeMap<PathBuf, (Option<SystemTime>, u64)>) {
+    let Ok(read) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read.flatten() {
+        let path = entry.path();
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if !is_watched(root, &path, meta.is_dir()) {
+            continue;
+        }
+        if meta.is_dir() {
+            collect(root, &path, out);
+        } else {
+            out.insert(path, (meta.modified().ok(), meta.len()));
+        }
+    }
+}
+
+/// Set once Ctrl-C has been received; a second Ctrl-C exits immediately.
+pub fn interrupted() -> &'static AtomicBool {
+    &INTERRUPTED
+}
+
+/// Route Ctrl-C into [`interrupted`] so the loop exits between cycles.
+pub fn install_interrupt_handler() -> Result<(), DecapodError> {
+    ctrlc::set_handler(|| {
+        if INTERRUPTED.swap(true, Ordering::SeqCst) {
+            std::process::exit(130);
+        }
+    })
+    .map_err(|e| DecapodError::ValidationError(format!("failed to install Ctrl-C handler: {e}")))
+}
+
+fn sleep_unless_stopped(total: Duration, step: Duration, stop: &AtomicBool) -> bool {
+    let step = step.min(total).max(Duration::from_millis(1));
+    let mut slept = Duration::ZERO;
+    while slept < total {
+        if stop.load(Ordering::SeqCst) {
+            return false;
+        }
+        std::thread::sleep(step);
+        slept += step;
+    }
+    !stop.load(Ordering::SeqCst)
+}
+
+/// Blocks until the tree differs from `baseline` and then stays quiet for
+/// the debounce window. Returns `None` once `stop` is raised.
+pub fn wait_for_change(
+    root: &Path,
+    config: &WatchConfig,
+    baseline: &TreeSnapshot,
+    stop: &AtomicBool,
+) -> Option<TreeSnapshot> {
+    let mut current = loop {
+        if !sleep_unless_stopped(config.poll_interval, config.poll_interval, stop) {
+            return None;
+        }
+        let snap = TreeSnapshot::capture(root);
+        if &snap != baseline {
+            break snap;
+        }
+    };
+    loop {
+        if !sleep_unless_stopped(config.debounce, config.poll_interval, stop) {
+            return None;
+        }
+        let snap = TreeSnapshot::capture(root);
+        if snap == current {
+            return Some(snap);
+        }
+        current = snap;
+    }
+}
+
+/// Runs `cycle` once, then again after every debounced change, until `stop`
+/// is raised. The baseline is re-captured after each cycle so writes made by
+/// the cycle itself never re-trigger it. Returns the number of cycles run.
+pub fn watch_loop<F>(
+    root: &Path,
+    config: &WatchConfig,
+    stop: &AtomicBool,
+    mut cycle: F,
+) -> Result<usize, DecapodError>
+where
+    F: FnMut(usize) -> Result<(), DecapodError>,
+{
+    let mut runs = 0;
+    loop {
+        cycle(runs)?;
+        runs += 1;
+        let baseline = TreeSnapshot::capture(root);
+        if wait_for_change(root, config, &baseline, stop).is_none() {
+            return Ok(runs);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+
+    fn fast_config() -> WatchConfig {
+        WatchConfig {
+            poll_interval: Duration::from_millis(20),
+            debounce: Duration::from_millis(150),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_skips_git_and_non_data_decapod_dirs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::create_dir_all(root.join(".decapod/generated")).unwrap();
+        fs::create_dir_all(root.join(".decapod/data")).unwrap();
+        fs::write(root.join(".git/HEAD"), "ref").unwrap();
+        fs::write(root.join(".decapod/generated/x.json"), "{}").unwrap();
+        fs::write(root.join(".decapod/data/todo.db"), "db").unwrap();
+        fs::write(root.join(".decapod/data/todo.db-wal"), "wal").unwrap();
+        fs::write(root.join("README.md"), "hi").unwrap();
+
+        let snap = TreeSnapshot::capture(root);
+        assert_eq!(snap.len(), 2);
+    }
+
+    #[test]
+    fn test_burst_of_changes_triggers_exactly_one_rerun() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().to_path_buf();
+        fs::write(root.join("a.txt"), "0").unwrap();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let runs = Arc::new(AtomicUsize::new(0));
+
+        let writer_root = root.clone();
+        let writer_stop = Arc::clone(&stop);
+        let writer_runs = Arc::clone(&runs);
+        let writer = std::thread::spawn(move || {
+            while writer_runs.load(Ordering::SeqCst) == 0 {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            std::thread::sleep(Duration::from_millis(60));
+            for i in 0..3 {
+                fs::write(writer_root.join("a.txt"), format!("{i}-changed")).unwrap();
+                std::thread::sleep(Duration::from_millis(20));
+            }
+            // Well past the debounce window: the single re-run has happened.
+            std::thread::sleep(Duration::from_millis(600));
+            writer_stop.store(true, Ordering::SeqCst);
+        });
+
+        let total = watch_loop(&root, &fast_config(), &stop, |_| {
+            runs.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        })
+        .unwrap();
+        writer.join().unwrap();
+
+        assert_eq!(total, 2, "initial run plus exactly one debounced re-run");
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+}
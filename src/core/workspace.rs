@@ -8,8 +8,10 @@
 use crate::core::db;
 use crate::core::error::DecapodError;
 use crate::core::rpc::{AllowedOp, Blocker, BlockerKind};
+use crate::core::settings;
 use crate::core::todo;
 use crate::core::workunit::{self, WorkUnitStatus};
+use crate::plugins::container;
 use crate::plugins::eval;
 use fancy_regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -47,6 +49,12 @@ pub struct GitStatus {
     pub is_main_repo: bool,
     /// Has local modifications
     pub has_local_mods: bool,
+    /// Best-effort on-disk size of the checkout; filled by [`with_worktree_usage`]
+    #[serde(default)]
+    pub worktree_size_bytes: Option<u64>,
+    /// Seconds since the last commit on HEAD; filled by [`with_worktree_usage`]
+    #[serde(default)]
+    pub worktree_age_secs: Option<u64>,
 }
 
 /// Container/Docker status
@@ -60,6 +68,9 @@ pub struct ContainerStatus {
     pub image: Option<String>,
     /// Whether Docker is available on host
     pub docker_available: bool,
+    /// Host env var names `[container] env_allowlist` forwards into runs (never values)
+    #[serde(default)]
+    pub env_passthrough: Vec<String>,
 }
 
 /// Workspace configuration
@@ -192,6 +203,219 @@ pub fn prune_stale_worktree_config(repo_root: &Path) -> Result<usize, DecapodErr
     Ok(removed)
 }
 
+/// One Decapod-managed worktree under `.decapod/workspaces/`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WorktreeEntry {
+    /// Directory name under `.decapod/workspaces/`
+    pub name: String,
+    pub path: PathBuf,
+    /// Checked-out branch; `None` for a detached HEAD
+    pub branch: Option<String>,
+    pub head: String,
+    /// Whether the directory is still on disk
+    pub exists: bool,
+    pub locked: bool,
+    pub has_local_mods: bool,
+    /// HEAD has moved since the branch was created and is already contained
+    /// in the main checkout's HEAD
+    pub merged: bool,
+    /// Seconds since the last commit on HEAD
+    pub age_secs: Option<u64>,
+}
+
+/// A worktree removed (or, on a dry run, selected) by [`prune_workspaces`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PrunedWorktree {
+    pub name: String,
+    pub path: PathBuf,
+    pub branch: Option<String>,
+    /// `missing` (directory gone) or `merged` (clean and already in base)
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PruneReport {
+    pub dry_run: bool,
+    pub pruned: Vec<PrunedWorktree>,
+    /// Managed worktrees left in place
+    pub kept: Vec<WorktreeEntry>,
+    /// Stale `worktree.<name>` sections dropped from `.git/config`
+    pub stale_config_removed: usize,
+}
+
+/// Inventory of worktrees under `.decapod/workspaces/`, from
+/// `git worktree list --porcelain`; other worktrees are not Decapod's to manage.
+pub fn list_workspaces(repo_root: &Path) -> Result<Vec<WorktreeEntry>, DecapodError> {
+    let main_repo = get_main_repo_root(repo_root)?;
+    let workspaces_dir = normalize_path_for_compare(&main_repo.join(".decapod").join("workspaces"));
+    let output = Command::new("git")
+        .args([
+            "-C",
+            main_repo.to_str().unwrap_or("."),
+            "worktree",
+            "list",
+            "--porcelain",
+        ])
+        .output()
+        .map_err(DecapodError::IoError)?;
+    if !output.status.success() {
+        return Err(DecapodError::ValidationError(format!(
+            "Failed to list git worktrees: {}",
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+    let base_head = git_stdout(&main_repo, &["rev-parse", "HEAD"]);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let mut entries = Vec::new();
+    for block in String::from_utf8_lossy(&output.stdout).split("\n\n") {
+        let mut path = None;
+        let mut head = String::new();
+        let mut branch = None;
+        let mut locked = false;
+        for line in block.lines() {
+            if let Some(p) = line.strip_prefix("worktree ") {
+                path = Some(PathBuf::from(p.trim()));
+            } else if let Some(h) = line.strip_prefix("HEAD ") {
+                head = h.trim().to_string();
+            } else if let Some(b) = line.strip_prefix("branch ") {
+                let b = b.trim();
+                branch = Some(b.strip_prefix("refs/heads/").unwrap_or(b).to_string());
+            } else if line == "locked" || line.starts_with("locked ") {
+                locked = true;
+            }
+        }
+        let Some(path) = path else {
+            continue;
+        };
+        let Some(parent) = path.parent() else {
+            continue;
+        };
+        if normalize_path_for_compare(parent) != workspaces_dir {
+            continue;
+        }
+        let exists = path.is_dir();
+        let has_local_mods = exists && has_local_modifications(&path).unwrap_or(false);
+        let merged = match &base_head {
+            Some(base) if !head.is_empty() => {
+                let contained = Command::new("git")
+                    .args(["-C", main_repo.to_str().unwrap_or(".")])
+                    .args(["merge-base", "--is-ancestor", &head, base])
+                    .output()
+                    .map(|o| o.status.success())
+                    .unwrap_or(false);
+                // A branch still sitting where it was created has nothing to
+                // merge; only its reflog tells it apart from a fast-forward.
+                let moved = match &branch {
+                    Some(b) => git_stdout(&main_repo, &["reflog", "show", "--format=%H", b])
+                        .is_some_and(|log| log.lines().count() > 1),
+                    None => head != *base,
+                };
+                contained && moved
+            }
+            _ => false,
+        };
+        let age_secs = if exists {
+            git_stdout(&path, &["log", "-1", "--format=%ct"])
+                .and_then(|ts| ts.parse::<u64>().ok())
+                .map(|committed| now.saturating_sub(committed))
+        } else {
+            None
+        };
+        entries.push(WorktreeEntry {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            path,
+            branch,
+            head,
+            exists,
+            locked,
+            has_local_mods,
+            merged,
+            age_secs,
+        });
+    }
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+/// Remove managed worktrees whose directory is gone, or that are clean and
+/// [`WorktreeEntry::merged`]. Branches are left alone, as are locked worktrees
+/// and the worktree `repo_root` is in.
+pub fn prune_workspaces(repo_root: &Path, dry_run: bool) -> Result<PruneReport, DecapodError> {
+    let main_repo = get_main_repo_root(repo_root)?;
+    let current = get_repo_root(repo_root)
+        .map(|p| normalize_path_for_compare(&p))
+        .ok();
+
+    let mut pruned = Vec::new();
+    let mut kept = Vec::new();
+    for entry in list_workspaces(repo_root)? {
+        let is_current = current.as_deref() == Some(&normalize_path_for_compare(&entry.path));
+        let reason = if !entry.exists {
+            Some("missing")
+        } else if entry.merged && !entry.has_local_mods {
+            Some("merged")
+        } else {
+            None
+        };
+        let Some(reason) = reason.filter(|_| !entry.locked && !is_current) else {
+            kept.push(entry);
+            continue;
+        };
+        if !dry_run && entry.exists {
+            let output = Command::new("git")
+                .args(["-C", main_repo.to_str().unwrap_or(".")])
+                .args(["worktree", "remove"])
+                .arg(&entry.path)
+                .output()
+                .map_err(DecapodError::IoError)?;
+            if !output.status.success() {
+                return Err(DecapodError::ValidationError(format!(
+                    "Failed to remove worktree '{}': {}",
+                    entry.path.display(),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )));
+            }
+        }
+        pruned.push(PrunedWorktree {
+            name: entry.name,
+            path: entry.path,
+            branch: entry.branch,
+            reason: reason.to_string(),
+        });
+    }
+
+    // Missing directories are dropped from git's admin state here.
+    let stale_config_removed = if dry_run {
+        0
+    } else {
+        prune_stale_worktree_config(&main_repo)?
+    };
+    Ok(PruneReport {
+        dry_run,
+        pruned,
+        kept,
+        stale_config_removed,
+    })
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+}
+
 /// Get workspace status
 pub fn get_workspace_status(repo_root: &Path) -> Result<WorkspaceStatus, DecapodError> {
     let git = check_git_status(repo_root)?;
@@ -255,10 +479,105 @@ fn check_git_status(repo_root: &Path) -> Result<GitStatus, DecapodError> {
         },
         is_main_repo,
         has_local_mods,
+        worktree_size_bytes: None,
+        worktree_age_secs: None,
     })
 }
 
-fn check_container_status(_repo_root: &Path) -> Result<ContainerStatus, DecapodError> {
+/// How long a measured worktree size is reused for the same HEAD.
+const WORKTREE_USAGE_TTL_SECS: u64 = 300;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct WorktreeUsageCache {
+    head: String,
+    measured_at: u64,
+    size_bytes: u64,
+}
+
+fn worktree_usage_cache_path(repo_root: &Path) -> PathBuf {
+    repo_root
+        .join(".decapod")
+        .join("generated")
+        .join("worktree_usage.json")
+}
+
+/// Fill in worktree size and age. Size walks the whole checkout, so it is
+/// left out of [`get_workspace_status`] and cached per HEAD under
+/// `.decapod/generated/` for [`WORKTREE_USAGE_TTL_SECS`].
+pub fn with_worktree_usage(mut status: WorkspaceStatus, repo_root: &Path) -> WorkspaceStatus {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let git_out = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(repo_root)
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    status.git.worktree_age_secs = git_out(&["log", "-1", "--format=%ct"])
+        .and_then(|ts| ts.parse::<u64>().ok())
+        .map(|committed| now.saturating_sub(committed));
+
+    let head = git_out(&["rev-parse", "HEAD"]).unwrap_or_default();
+    let cache_path = worktree_usage_cache_path(repo_root);
+    let cached = std::fs::read_to_string(&cache_path)
+        .ok()
+        .and_then(|raw| serde_json::from_str::<WorktreeUsageCache>(&raw).ok())
+        .filter(|c| c.head == head && now.saturating_sub(c.measured_at) < WORKTREE_USAGE_TTL_SECS);
+    status.git.worktree_size_bytes = match cached {
+        Some(c) => Some(c.size_bytes),
+        None => {
+            let size_bytes = dir_size_bytes(repo_root);
+            if repo_root.join(".decapod").is_dir()
+                && let Some(parent) = cache_path.parent()
+                && std::fs::create_dir_all(parent).is_ok()
+            {
+                let cache = WorktreeUsageCache {
+                    head,
+                    measured_at: now,
+                    size_bytes,
+                };
+                let _ = std::fs::write(
+                    &cache_path,
+                    serde_json::to_string_pretty(&cache).unwrap_or_default(),
+                );
+            }
+            Some(size_bytes)
+        }
+    };
+    status
+}
+
+/// Apparent size of every regular file under `root`, like `du -sb`;
+/// symlinks are not followed and unreadable entries are skipped.
+fn dir_size_bytes(root: &Path) -> u64 {
+    let mut total = 0;
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                stack.push(entry.path());
+            } else if file_type.is_file() {
+                total += entry.metadata().map(|m| m.len()).unwrap_or(0);
+            }
+        }
+    }
+    total
+}
+
+fn check_container_status(repo_root: &Path) -> Result<ContainerStatus, DecapodError> {
     let in_container = Path::new("/.dockerenv").exists() || std::env::var("CONTAINER_ID").is_ok();
 
     let container_id = if in_container {
@@ -280,6 +599,13 @@ fn check_container_status(_repo_root: &Path) -> Result<ContainerStatus, DecapodE
         container_id,
         image: std::env::var("DECAPOD_WORKSPACE_IMAGE").ok(),
         docker_available,
+        env_passthrough: container::forwarded_env_vars(
+            &container::ContainerConfig::load(repo_root),
+            false,
+            std::env::vars(),
+        )
+        .into_keys()
+        .collect(),
     })
 }
 
@@ -332,7 +658,9 @@ pub fn ensure_workspace(
     }
 
     let todo_scope = build_todo_scope_component(&assigned_todos);
+    let branch_policy = BranchPolicy::load(&main_repo);
     let config = if let Some(cfg) = config {
+        branch_policy.check(&cfg.branch)?;
         if !branch_contains_any_todo_id_or_hash(&cfg.branch, &assigned_todos) {
             return Err(DecapodError::ValidationError(format!(
                 "Requested branch '{}' must include an assigned todo ID/hash (one of: {}).",
@@ -347,12 +675,7 @@ pub fn ensure_workspace(
             .unwrap_or_default()
             .as_secs();
         WorkspaceConfig {
-            branch: format!(
-                "agent/{}/{}-{}",
-                sanitize_agent_id(agent_id),
-                todo_scope,
-                ts
-            ),
+            branch: branch_policy.render(agent_id, &format!("{}-{}", todo_scope, ts)),
             use_container: false,
             base_image: None,
         }
@@ -678,6 +1001,12 @@ fn is_worktree(repo_root: &Path) -> Result<bool, DecapodError> {
 }
 
 fn has_local_modifications(repo_root: &Path) -> Result<bool, DecapodError> {
+    Ok(!uncommitted_paths(repo_root)?.is_empty())
+}
+
+/// Modified, staged, and untracked paths from `git status --porcelain`, as
+/// `"<XY> <path>"` entries (`??` marks untracked files).
+pub fn uncommitted_paths(repo_root: &Path) -> Result<Vec<String>, DecapodError> {
     let output = Command::new("git")
         .args([
             "-C",
@@ -690,20 +1019,43 @@ fn has_local_modifications(repo_root: &Path) -> Result<bool, DecapodError> {
         .map_err(DecapodError::IoError)?;
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut saw_non_ignorable = false;
-    for entry in stdout.split('\0').filter(|entry| !entry.is_empty()) {
+    let mut paths = Vec::new();
+    let mut entries = stdout.split('\0').filter(|entry| !entry.is_empty());
+    while let Some(entry) = entries.next() {
         if entry.len() < 4 {
             continue;
         }
-        let path = &entry[3..];
+        let (code, path) = (&entry[..2], &entry[3..]);
+        // Renames and copies carry the original path as a separate entry.
+        if code.contains('R') || code.contains('C') {
+            entries.next();
+        }
         if path == ".decapod/OVERRIDE.md" {
             continue;
         }
-        saw_non_ignorable = true;
-        break;
+        paths.push(format!("{} {}", code, path));
     }
+    Ok(paths)
+}
 
-    Ok(saw_non_ignorable)
+/// Refuse to publish a dirty tree unless `include_all` opts into committing everything.
+pub fn check_publish_tree(repo_root: &Path, include_all: bool) -> Result<(), DecapodError> {
+    if include_all {
+        return Ok(());
+    }
+    let paths = uncommitted_paths(repo_root)?;
+    if paths.is_empty() {
+        return Ok(());
+    }
+    Err(DecapodError::ValidationError(format!(
+        "Cannot publish: {} uncommitted path(s) would be ambiguous to publish. Commit them, or pass --all to stage and commit everything (including untracked files):\n{}",
+        paths.len(),
+        paths
+            .iter()
+            .map(|p| format!("  {}", p))
+            .collect::<Vec<_>>()
+            .join("\n")
+    )))
 }
 
 fn sanitize_agent_id(agent_id: &str) -> String {
@@ -715,6 +1067,107 @@ fn sanitize_agent_id(agent_id: &str) -> String {
         .to_string()
 }
 
+/// Default shape for workspace branches; `<id>` is the agent id, `<slug>` the topic.
+pub const DEFAULT_BRANCH_TEMPLATE: &str = "agent/<id>/<slug>";
+
+/// Branch-name policy from `[workspace] branch_template` in `.decapod/config.toml`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BranchPolicy {
+    #[serde(default = "default_branch_template")]
+    pub branch_template: String,
+}
+
+fn default_branch_template() -> String {
+    DEFAULT_BRANCH_TEMPLATE.to_string()
+}
+
+impl Default for BranchPolicy {
+    fn default() -> Self {
+        Self {
+            branch_template: default_branch_template(),
+        }
+    }
+}
+
+impl BranchPolicy {
+    pub fn load(repo_root: &Path) -> Self {
+        settings::load_section(repo_root, "workspace")
+    }
+
+    /// Branch name for `agent_id` working on `topic`.
+    pub fn render(&self, agent_id: &str, topic: &str) -> String {
+        self.branch_template
+            .replace("<id>", &sanitize_agent_id(agent_id))
+            .replace("<slug>", &slugify(topic))
+    }
+
+    fn pattern(&self) -> String {
+        let mut out = String::from("^");
+        let mut rest = self.branch_template.as_str();
+        while !rest.is_empty() {
+            if let Some(tail) = rest.strip_prefix("<id>") {
+                out.push_str("[a-z0-9][a-z0-9_-]*");
+                rest = tail;
+            } else if let Some(tail) = rest.strip_prefix("<slug>") {
+                out.push_str("[a-z0-9][a-z0-9._-]*");
+                rest = tail;
+            } else {
+                let ch = rest.chars().next().unwrap_or_default();
+                if "\\.+*?()|[]{}^$#&-~".contains(ch) {
+                    out.push('\\');
+                }
+                out.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+        out.push('$');
+        out
+    }
+
+    /// Reject protected or non-conforming branch names.
+    pub fn check(&self, branch: &str) -> Result<(), DecapodError> {
+        if is_branch_protected(branch) {
+            return Err(DecapodError::ValidationError(format!(
+                "Branch '{}' is protected; workspace branches must match '{}'.",
+                branch, self.branch_template
+            )));
+        }
+        let re = Regex::new(&self.pattern()).map_err(|e| {
+            DecapodError::ValidationError(format!(
+                "Invalid [workspace] branch_template '{}': {}",
+                self.branch_template, e
+            ))
+        })?;
+        if !re.is_match(branch).unwrap_or(false) {
+            return Err(DecapodError::ValidationError(format!(
+                "Branch '{}' does not match the workspace branch policy '{}' (e.g. '{}').",
+                branch,
+                self.branch_template,
+                self.render("agent-1", "my-topic")
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Lowercase `topic` into a branch-safe slug.
+pub fn slugify(topic: &str) -> String {
+    let mut out = String::with_capacity(topic.len());
+    for ch in topic.to_lowercase().chars() {
+        if ch.is_ascii_alphanumeric() || ch == '.' || ch == '_' {
+            out.push(ch);
+        } else if !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    let slug = out.trim_matches(|c| c == '-' || c == '.').to_string();
+    if slug.is_empty() {
+        "work".to_string()
+    } else {
+        slug
+    }
+}
+
 fn sanitize_todo_component(todo_id: &str) -> String {
     todo_id
         .to_lowercase()
@@ -792,15 +1245,178 @@ pub struct PublishResult {
     pub commit_hash: String,
     /// Remote URL the branch was pushed to
     pub remote_url: String,
-    /// PR URL if one was created
+    /// PR/MR URL if one was created
     pub pr_url: Option<String>,
 }
 
+/// Code-hosting forge that `workspace publish --pr` opens a review request on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Forge {
+    /// GitHub pull request via `gh`
+    #[default]
+    Github,
+    /// GitLab merge request via `glab`
+    Gitlab,
+    /// Push the branch only
+    None,
+}
+
+impl Forge {
+    /// CLI binary: `[publish] bin` when configured, else overridable per
+    /// forge (`DECAPOD_GH_BIN`, `DECAPOD_GLAB_BIN`).
+    fn bin(self, configured: Option<&str>) -> Option<String> {
+        let (env, default) = match self {
+            Forge::Github => ("DECAPOD_GH_BIN", "gh"),
+            Forge::Gitlab => ("DECAPOD_GLAB_BIN", "glab"),
+            Forge::None => return None,
+        };
+        if let Some(bin) = configured {
+            return Some(bin.to_string());
+        }
+        Some(std::env::var(env).unwrap_or_else(|_| default.to_string()))
+    }
+
+    fn request_kind(self) -> &'static str {
+        match self {
+            Forge::Gitlab => "merge request",
+            _ => "pull request",
+        }
+    }
+
+    /// Token variables the forge CLI authenticates with.
+    fn token_envs(self) -> &'static [&'static str] {
+        match self {
+            Forge::Github => &["GH_TOKEN", "GITHUB_TOKEN"],
+            Forge::Gitlab => &["GITLAB_TOKEN", "GLAB_TOKEN"],
+            Forge::None => &[],
+        }
+    }
+
+    /// Arguments that open a review request for `branch` on this forge.
+    pub fn create_args(
+        self,
+        branch: &str,
+        title: &str,
+        description: Option<&str>,
+        config: &PublishConfig,
+    ) -> Vec<String> {
+        let (prefix, source, body, target, extra): (&[&str], _, _, _, &[&str]) = match self {
+            Forge::Github => (&["pr", "create"], "--head", "--body", "--base", &[]),
+            Forge::Gitlab => (
+                &["mr", "create"],
+                "--source-branch",
+                "--description",
+                "--target-branch",
+                &["--yes"],
+            ),
+            Forge::None => return Vec::new(),
+        };
+        let mut args: Vec<String> = prefix.iter().map(|s| s.to_string()).collect();
+        args.extend([
+            source.to_string(),
+            branch.to_string(),
+            "--title".to_string(),
+            title.to_string(),
+            body.to_string(),
+            description.unwrap_or_default().to_string(),
+        ]);
+        if let Some(base) = config.base.as_deref().filter(|b| !b.is_empty()) {
+            args.push(target.to_string());
+            args.push(base.to_string());
+        }
+        for reviewer in &config.reviewers {
+            args.push("--reviewer".to_string());
+            args.push(reviewer.clone());
+        }
+        args.extend(extra.iter().map(|s| s.to_string()));
+        args
+    }
+
+    /// True when the forge CLI can authenticate, via a token variable or a stored login.
+    fn credentials_available(self, bin: &str, repo_root: &Path) -> bool {
+        let token = self
+            .token_envs()
+            .iter()
+            .any(|k| std::env::var(k).is_ok_and(|v| !v.is_empty()));
+        let probe = if token {
+            vec!["--version"]
+        } else {
+            vec!["auth", "status"]
+        };
+        Command::new(bin)
+            .args(probe)
+            .current_dir(repo_root)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+}
+
+/// `[publish]` section of `.decapod/config.toml`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PublishConfig {
+    /// Forge integration used by `--pr` (github|gitlab|none)
+    #[serde(default)]
+    pub forge: Forge,
+    /// Base (target) branch for created PRs/MRs (forge default when unset)
+    #[serde(default)]
+    pub base: Option<String>,
+    /// Reviewers requested on created PRs/MRs
+    #[serde(default)]
+    pub reviewers: Vec<String>,
+    /// Forge CLI binary used instead of `gh`/`glab`
+    #[serde(default)]
+    pub bin: Option<String>,
+}
+
+/// Load `[publish]` from `.decapod/config.toml`.
+pub fn load_publish_config(repo_root: &Path) -> PublishConfig {
+    settings::load_section(repo_root, "publish")
+}
+
+/// Open a PR/MR on the configured forge. Returns `Ok(None)` for `forge = "none"`
+/// or when no credentials are available.
+pub fn create_review_request(
+    repo_root: &Path,
+    branch: &str,
+    title: &str,
+    description: Option<&str>,
+    config: &PublishConfig,
+) -> Result<Option<String>, DecapodError> {
+    let Some(bin) = config.forge.bin(config.bin.as_deref()) else {
+        return Ok(None);
+    };
+    if !config.forge.credentials_available(&bin, repo_root) {
+        return Ok(None);
+    }
+    let output = Command::new(bin)
+        .args(config.forge.create_args(branch, title, description, config))
+        .current_dir(repo_root)
+        .output()
+        .map_err(DecapodError::IoError)?;
+    if !output.status.success() {
+        return Err(DecapodError::ValidationError(format!(
+            "Failed to create {}: {}",
+            config.forge.request_kind(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let url = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .rfind(|l| l.starts_with("http"))
+        .map(str::to_string);
+    Ok(url)
+}
+
 /// Publish workspace changes: commit, push, and optionally create a PR
 pub fn publish_workspace(
     repo_root: &Path,
     title: Option<String>,
     description: Option<String>,
+    create_pr: bool,
+    include_all: bool,
 ) -> Result<PublishResult, DecapodError> {
     let status = get_workspace_status(repo_root)?;
 
@@ -829,10 +1445,11 @@ pub fn publish_workspace(
     }
     verify_workunit_gate_for_publish(repo_root, &status.git.current_branch)?;
     eval::verify_eval_gate_for_publish(&repo_root.join(".decapod").join("data"))?;
+    check_publish_tree(repo_root, include_all)?;
 
     let dir = repo_root.to_str().unwrap_or(".");
 
-    // 2. Stage and commit any uncommitted changes
+    // 2. Stage and commit any uncommitted changes (only reachable with `include_all`)
     if status.git.has_local_mods {
         let add_output = Command::new("git")
             .args(["-C", dir, "add", "-A"])
@@ -901,43 +1518,16 @@ pub fn publish_workspace(
         .trim()
         .to_string();
 
-    // 4. If gh CLI is available, create a PR
-    let pr_url = if Command::new("gh")
-        .arg("--version")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false)
-    {
+    // 4. Optionally open a PR/MR; without credentials the pushed branch is the result.
+    let pr_url = if create_pr {
         let pr_title = title.as_deref().unwrap_or(&status.git.current_branch);
-        let mut pr_args = vec![
-            "-C",
-            dir,
-            "pr",
-            "create",
-            "--title",
-            pr_title,
-            "--head",
+        create_review_request(
+            repo_root,
             &status.git.current_branch,
-        ];
-        let desc;
-        if let Some(ref d) = description {
-            desc = d.clone();
-            pr_args.push("--body");
-            pr_args.push(&desc);
-        }
-        let pr_output = Command::new("gh")
-            .args(&pr_args)
-            .output()
-            .map_err(DecapodError::IoError)?;
-        if pr_output.status.success() {
-            Some(
-                String::from_utf8_lossy(&pr_output.stdout)
-                    .trim()
-                    .to_string(),
-            )
-        } else {
-            None
-        }
+            pr_title,
+            description.as_deref(),
+            &load_publish_config(repo_root),
+        )?
     } else {
         None
     };
@@ -16,7 +16,7 @@ use cli::*;
 
 use core::{
     db, docs, docs_cli, error, flight_recorder, migration, obligation, plan_governance, proof,
-    repomap, scaffold, state_commit,
+    repomap, scaffold, settings, standards, state_commit,
     store::{Store, StoreKind},
     todo, trace, validate, workspace,
 };
@@ -476,6 +476,7 @@ fn init_with_from_config(
         done_criteria: None,
         primary_languages: Vec::new(),
         detected_surfaces: Vec::new(),
+        profile: config.init.profile,
     }
 }
 
@@ -500,8 +501,10 @@ fn config_from_init_with(init: &InitWithCli, repo: RepoContext) -> DecapodProjec
             specs: init.specs,
             diagram_style: init.diagram_style,
             entrypoints,
+            profile: init.profile,
         },
         repo,
+        extra: toml::Table::new(),
     }
 }
 
@@ -728,6 +731,32 @@ fn run_init_apply(
         scaffold_summary.specs_preserved.to_string().bright_white()
     );
     println!("  Backups: {}", backup_count.to_string().bright_magenta());
+    if let Some(name) = init_with.profile {
+        let profile = core::profiles::load(name.as_str())?;
+        if init_with.dry_run {
+            println!(
+                "  Profile: {} (dry run; nothing seeded)",
+                profile.name.bright_white()
+            );
+        } else {
+            let decapod_root = target_dir.join(".decapod");
+            migration::check_and_migrate_with_backup(&decapod_root, |data_root| {
+                subsystems::initialize_all_dbs(data_root)
+            })?;
+            let summary = core::profiles::apply(&target_dir, &profile)?;
+            println!(
+                "  Profile: {} (categories +{}, risk zones +{}, standards {})",
+                profile.name.bright_white(),
+                summary.categories_added,
+                summary.zones_added,
+                if summary.standards_written {
+                    "created"
+                } else {
+                    "preserved"
+                }
+            );
+        }
+    }
     println!(
         "  Diagram Style: {}",
         match init_with.diagram_style {
@@ -749,6 +778,8 @@ pub fn run() -> Result<(), error::DecapodError> {
     let argv: Vec<String> = std::env::args().skip(1).collect();
     let current_dir = std::env::current_dir()?;
     let decapod_root_option = find_decapod_project_root(&current_dir);
+    settings::install(decapod_root_option.as_ref().ok().map(|p| p.as_path()));
+    core::identity::install(decapod_root_option.as_ref().ok().map(|p| p.as_path()));
     let store_root: PathBuf;
 
     match cli.command {
@@ -827,6 +858,9 @@ pub fn run() -> Result<(), error::DecapodError> {
                         if !init_group.detected_surfaces.is_empty() {
                             with.detected_surfaces = init_group.detected_surfaces.clone();
                         }
+                        if init_group.profile.is_some() {
+                            with.profile = init_group.profile;
+                        }
                         with
                     } else {
                         InitWithCli {
@@ -846,6 +880,7 @@ pub fn run() -> Result<(), error::DecapodError> {
                             done_criteria: init_group.done_criteria.clone(),
                             primary_languages: init_group.primary_languages.clone(),
                             detected_surfaces: init_group.detected_surfaces.clone(),
+                            profile: init_group.profile,
                         }
                     }
                 }
@@ -861,7 +896,11 @@ pub fn run() -> Result<(), error::DecapodError> {
                 enrich_repo_context_interactive(&mut repo_ctx)?;
             }
             let target_dir = run_init_apply(&init_with, &current_dir, &repo_ctx)?;
-            let config = config_from_init_with(&init_with, repo_ctx);
+            let mut config = config_from_init_with(&init_with, repo_ctx);
+            // Re-init rewrites `[init]`/`[repo]` but keeps settings sections.
+            if let Ok(Some(existing)) = load_project_config_if_present(&target_dir) {
+                config.extra = existing.extra;
+            }
             write_project_config(&target_dir, &config, init_with.dry_run)?;
         }
         Command::Session(session_cli) => {
@@ -871,18 +910,29 @@ pub fn run() -> Result<(), error::DecapodError> {
             let project_root = decapod_root_option?;
             run_release_command(release_cli, &project_root)?;
         }
+        Command::Config(config_cli) => {
+            let project_root = decapod_root_option?;
+            run_config_command(config_cli, &project_root)?;
+        }
+        Command::Migration(migration_cli) => {
+            let project_root = decapod_root_option?;
+            run_migration_command(migration_cli, &project_root)?;
+        }
         Command::Setup(setup_cli) => match setup_cli.command {
             SetupCommand::Hook {
                 commit_msg,
                 pre_commit,
+                pre_push,
                 uninstall,
+                force,
             } => {
-                run_hook_install(commit_msg, pre_commit, uninstall)?;
+                run_hook_install(commit_msg, pre_commit, pre_push, uninstall, force)?;
             }
         },
         _ => {
             let project_root = decapod_root_option?;
             let is_validate_cmd = matches!(&cli.command, Command::Validate(_));
+            enforce_agent_identity(&cli.command, &argv)?;
             if requires_session_token(&cli.command) {
                 ensure_session_valid()?;
             }
@@ -971,8 +1021,8 @@ pub fn run() -> Result<(), error::DecapodError> {
                 Command::Version => show_version_info()?,
                 Command::Docs(docs_cli) => {
                     let result = docs_cli::run_docs_cli(docs_cli)?;
-                    if result.ingested_core_constitution {
-                        mark_core_constitution_ingested(&project_root)?;
+                    if !result.ingested_docs.is_empty() {
+                        mark_core_constitution_ingested(&project_root, &result)?;
                     }
                 }
                 Command::Todo(todo_cli) => todo::run_todo_cli(&project_store, todo_cli)?,
@@ -1003,6 +1053,9 @@ pub fn run() -> Result<(), error::DecapodError> {
                 Command::Capabilities(cap_cli) => {
                     run_capabilities_command(cap_cli)?;
                 }
+                Command::Bootstrap(bootstrap_cli) => {
+                    run_bootstrap_command(bootstrap_cli, &project_root)?;
+                }
                 Command::Internalize(internalize_cli) => {
                     internalize::run_internalize_cli(&project_store, &store_root, internalize_cli)?;
                 }
@@ -1047,9 +1100,20 @@ fn should_route_via_group_broker(command: &Command, argv: &[String]) -> bool {
     if core::group_broker::is_internal_invocation() {
         return false;
     }
+    command_is_mutating(command, argv)
+}
+
+/// Whether `command` writes to a brokered store (todo, decide, knowledge,
+/// federation, and the aptitude/feedback batch of `feedback propose --apply`).
+fn command_is_mutating(command: &Command, argv: &[String]) -> bool {
     match command {
         Command::Todo(_) => todo_argv_is_mutating(argv),
         Command::Decide(decide_cli) => decide_command_is_mutating(decide_cli),
+        Command::Govern(govern_cli) => matches!(
+            &govern_cli.command,
+            GovernCommand::Feedback(feedback_cli)
+                if matches!(feedback_cli.command, FeedbackCommand::Propose { apply: true })
+        ),
         Command::Data(data_cli) => match &data_cli.command {
             DataCommand::Federation(_) => federation_argv_is_mutating(argv),
             DataCommand::Knowledge(_) => knowledge_argv_is_mutating(argv),
@@ -1059,23 +1123,138 @@ fn should_route_via_group_broker(command: &Command, argv: &[String]) -> bool {
     }
 }
 
+/// Whether strict identity mode gates `command`: every brokered mutation plus
+/// the state-changing commands that run in-process (rpc `--op` writes,
+/// aptitude, archive repair, policy, cron, and reflex writes).
+fn command_requires_agent_id(command: &Command, argv: &[String]) -> bool {
+    if command_is_mutating(command, argv) {
+        return true;
+    }
+    match command {
+        // `--stdin` requests are checked once parsed (`enforce_agent_identity_for_rpc`).
+        Command::Rpc(rpc_cli) => rpc_cli
+            .op
+            .as_deref()
+            .is_some_and(rpc_op_requires_constitutional_awareness),
+        Command::Data(data_cli) => match &data_cli.command {
+            DataCommand::Aptitude(aptitude_cli) => aptitude_command_is_mutating(aptitude_cli),
+            DataCommand::Archive(archive_cli) => {
+                matches!(archive_cli.command, ArchiveCommand::Verify { repair: true })
+            }
+            _ => false,
+        },
+        Command::Govern(govern_cli) => match &govern_cli.command {
+            GovernCommand::Policy(policy_cli) => matches!(
+                policy_cli.command,
+                policy::PolicyCommand::Approve { .. }
+                    | policy::PolicyCommand::Riskmap {
+                        command: policy::RiskmapSubcommand::Init
+                    }
+            ),
+            _ => false,
+        },
+        Command::Auto(auto_cli) => match &auto_cli.command {
+            AutoCommand::Cron(cron_cli) => !matches!(
+                cron_cli.command,
+                cron::CronCommand::Get { .. }
+                    | cron::CronCommand::List { .. }
+                    | cron::CronCommand::History { .. }
+                    | cron::CronCommand::Suggest { .. }
+            ),
+            AutoCommand::Reflex(reflex_cli) => !matches!(
+                reflex_cli.command,
+                reflex::ReflexCommand::Get { .. }
+                    | reflex::ReflexCommand::List { .. }
+                    | reflex::ReflexCommand::History { .. }
+                    | reflex::ReflexCommand::Replay { .. }
+            ),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn aptitude_command_is_mutating(aptitude_cli: &aptitude::AptitudeCli) -> bool {
+    match &aptitude_cli.command {
+        aptitude::AptitudeCommand::Add { .. }
+        | aptitude::AptitudeCommand::Delete { .. }
+        | aptitude::AptitudeCommand::Observe { .. } => true,
+        aptitude::AptitudeCommand::Consolidate { execute, .. } => *execute,
+        aptitude::AptitudeCommand::Skill(skill) => matches!(
+            skill,
+            aptitude::SkillCommand::Add { .. }
+                | aptitude::SkillCommand::Delete { .. }
+                | aptitude::SkillCommand::Import { .. }
+        ),
+        _ => false,
+    }
+}
+
+/// Strict identity mode (`session.require_agent_id`): refuse mutations from an
+/// agent that resolved to `unknown`. Read-only commands are always allowed.
+fn enforce_agent_identity(command: &Command, argv: &[String]) -> Result<(), error::DecapodError> {
+    if !command_requires_agent_id(command, argv) {
+        return Ok(());
+    }
+    require_known_agent(
+        &argv
+            .iter()
+            .take_while(|a| !a.starts_with('-'))
+            .take(3)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Strict identity mode for RPC: mutating ops (the constitutional-awareness
+/// list) need a resolved agent id, whether the op came from `--op` or stdin.
+fn enforce_agent_identity_for_rpc(op: &str) -> Result<(), error::DecapodError> {
+    if !rpc_op_requires_constitutional_awareness(op) {
+        return Ok(());
+    }
+    require_known_agent(&format!("rpc --op {}", op))
+}
+
+fn require_known_agent(action: &str) -> Result<(), error::DecapodError> {
+    if !settings::current().session_require_agent_id || core::group_broker::is_internal_invocation()
+    {
+        return Ok(());
+    }
+    let identity = core::identity::resolve();
+    if identity.id != core::identity::UNKNOWN_AGENT_ID {
+        return Ok(());
+    }
+    Err(error::DecapodError::ValidationError(format!(
+        "AGENT_ID_REQUIRED: '{}' mutates state but the agent id resolved to '{}' (source: {}). \
+         Set DECAPOD_AGENT_ID, write an id to .decapod/{}, or configure git user.email; \
+         check with `decapod session whoami`.",
+        action,
+        identity.id,
+        identity.source.as_str(),
+        core::identity::AGENT_ID_FILE
+    )))
+}
+
 fn enforce_route_strict_mode() -> bool {
-    std::env::var("DECAPOD_GROUP_BROKER_ENFORCE_ROUTE")
-        .ok()
-        .map(|v| v == "1")
-        .unwrap_or(false)
+    settings::current().broker_enforce_route
 }
 
 fn todo_argv_is_mutating(argv: &[String]) -> bool {
     let Some(sub) = argv.get(1).map(|s| s.as_str()) else {
         return false;
     };
+    if sub == "categories" {
+        return matches!(argv.get(2).map(|s| s.as_str()), Some("add" | "remove"));
+    }
+    if sub == "rebuild" && argv.iter().any(|a| a == "--verify") {
+        return false;
+    }
     !matches!(
         sub,
         "list"
             | "get"
             | "show"
-            | "categories"
             | "ownerships"
             | "claim-status"
             | "presence"
@@ -1095,7 +1274,10 @@ fn decide_command_is_mutating(decide_cli: &decide::DecideCli) -> bool {
 }
 
 fn knowledge_argv_is_mutating(argv: &[String]) -> bool {
-    matches!(argv.get(2).map(|s| s.as_str()), Some("add" | "promote"))
+    matches!(
+        argv.get(2).map(|s| s.as_str()),
+        Some("add" | "merge" | "promote")
+    )
 }
 
 fn federation_argv_is_mutating(argv: &[String]) -> bool {
@@ -1104,6 +1286,7 @@ fn federation_argv_is_mutating(argv: &[String]) -> bool {
         Some(
             "add"
                 | "edit"
+                | "update"
                 | "supersede"
                 | "deprecate"
                 | "dispute"
@@ -1126,6 +1309,7 @@ fn should_auto_clock_in(command: &Command) -> bool {
         | Command::Session(_)
         | Command::Release(_)
         | Command::StateCommit(_)
+        | Command::Bootstrap(_)
         | Command::Doctor(_) => false,
         _ => true,
     }
@@ -1140,6 +1324,7 @@ fn command_requires_worktree(command: &Command) -> bool {
         | Command::Version
         | Command::Workspace(_)
         | Command::Capabilities(_)
+        | Command::Bootstrap(_)
         | Command::Trace(_)
         | Command::FlightRecorder(_)
         | Command::Docs(_)
@@ -1179,6 +1364,7 @@ fn command_requires_todo_scoped_worktree(command: &Command) -> bool {
             | Command::Release(_)
             | Command::Trace(_)
             | Command::Capabilities(_)
+            | Command::Bootstrap(_)
             | Command::Doctor(_)
             | Command::StateCommit(_)
             | Command::Qa(_)
@@ -1194,6 +1380,7 @@ fn command_requires_canonical_worktree_path(command: &Command) -> bool {
             | Command::Release(_)
             | Command::Trace(_)
             | Command::Capabilities(_)
+            | Command::Bootstrap(_)
             | Command::Doctor(_)
             | Command::StateCommit(_)
             | Command::Qa(_)
@@ -1277,6 +1464,7 @@ fn rpc_op_requires_worktree(op: &str) -> bool {
         "agent.init"
             | "workspace.status"
             | "workspace.ensure"
+            | "workspace.list"
             | "assurance.evaluate"
             | "mentor.obligations"
             | "context.resolve"
@@ -1286,6 +1474,7 @@ fn rpc_op_requires_worktree(op: &str) -> bool {
             | "schema.get"
             | "store.upsert"
             | "store.query"
+            | "knowledge.search"
             | "validate.run"
             | "standards.resolve"
     )
@@ -1345,9 +1534,11 @@ fn rpc_op_bypasses_session(op: &str) -> bool {
             | "schema.get"
             | "store.upsert"
             | "store.query"
+            | "knowledge.search"
             | "validate.run"
             | "workspace.status"
             | "workspace.ensure"
+            | "workspace.list"
             | "standards.resolve"
     )
 }
@@ -1361,6 +1552,7 @@ fn requires_session_token(command: &Command) -> bool {
         | Command::Activate
         | Command::Docs(_)
         | Command::Capabilities(_)
+        | Command::Bootstrap(_)
         | Command::Release(_)
         | Command::Trace(_)
         | Command::FlightRecorder(_)
@@ -1399,6 +1591,9 @@ struct ConstitutionalAwarenessRecord {
     core_constitution_ingested_at_epoch_secs: Option<u64>,
     context_resolved_at_epoch_secs: Option<u64>,
     source_ops: Vec<String>,
+    /// Ingested doc path -> content hash at ingestion time.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    ingested_sources: BTreeMap<String, String>,
 }
 
 fn now_epoch_secs() -> u64 {
@@ -1409,19 +1604,11 @@ fn now_epoch_secs() -> u64 {
 }
 
 fn session_ttl_secs() -> u64 {
-    std::env::var("DECAPOD_SESSION_TTL_SECS")
-        .ok()
-        .and_then(|v| v.parse::<u64>().ok())
-        .filter(|v| *v > 0)
-        .unwrap_or(3600)
+    settings::current().session_ttl_secs
 }
 
 fn current_agent_id() -> String {
-    std::env::var("DECAPOD_AGENT_ID")
-        .ok()
-        .map(|v| v.trim().to_string())
-        .filter(|v| !v.is_empty())
-        .unwrap_or_else(|| "unknown".to_string())
+    core::identity::current_agent_id()
 }
 
 fn sanitize_agent_component(s: &str) -> String {
@@ -1535,6 +1722,23 @@ fn atomic_write_file(path: &Path, body: &str) -> Result<(), error::DecapodError>
     Ok(())
 }
 
+/// Exclusive advisory lock (flock) on the sessions directory, held until the
+/// returned file is dropped. Serializes session writes across processes so a
+/// concurrent acquire observes the winner's record instead of clobbering it.
+fn lock_sessions_dir(project_root: &Path) -> Result<fs::File, error::DecapodError> {
+    let dir = sessions_dir(project_root);
+    fs::create_dir_all(&dir).map_err(error::DecapodError::IoError)?;
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .read(true)
+        .write(true)
+        .truncate(false)
+        .open(dir.join(".lock"))
+        .map_err(error::DecapodError::IoError)?;
+    file.lock().map_err(error::DecapodError::IoError)?;
+    Ok(file)
+}
+
 fn write_agent_session(
     project_root: &Path,
     rec: &AgentSessionRecord,
@@ -1608,8 +1812,11 @@ fn mark_constitution_initialized(project_root: &Path) -> Result<(), error::Decap
         core_constitution_ingested_at_epoch_secs: existing
             .as_ref()
             .and_then(|r| r.core_constitution_ingested_at_epoch_secs),
-        context_resolved_at_epoch_secs: existing.and_then(|r| r.context_resolved_at_epoch_secs),
+        context_resolved_at_epoch_secs: existing
+            .as_ref()
+            .and_then(|r| r.context_resolved_at_epoch_secs),
         source_ops,
+        ingested_sources: existing.map(|r| r.ingested_sources).unwrap_or_default(),
     };
     write_awareness_record(project_root, &rec)
 }
@@ -1625,6 +1832,7 @@ fn mark_constitution_context_resolved(project_root: &Path) -> Result<(), error::
             core_constitution_ingested_at_epoch_secs: None,
             context_resolved_at_epoch_secs: None,
             source_ops: Vec::new(),
+            ingested_sources: BTreeMap::new(),
         });
     rec.context_resolved_at_epoch_secs = Some(now_epoch_secs());
     if !rec.source_ops.iter().any(|op| op == "context.resolve") {
@@ -1644,6 +1852,7 @@ fn mark_validation_completed(project_root: &Path) -> Result<(), error::DecapodEr
             core_constitution_ingested_at_epoch_secs: None,
             context_resolved_at_epoch_secs: None,
             source_ops: Vec::new(),
+            ingested_sources: BTreeMap::new(),
         });
     rec.validated_at_epoch_secs = Some(now_epoch_secs());
     if !rec.source_ops.iter().any(|op| op == "validate") {
@@ -1652,7 +1861,10 @@ fn mark_validation_completed(project_root: &Path) -> Result<(), error::DecapodEr
     write_awareness_record(project_root, &rec)
 }
 
-fn mark_core_constitution_ingested(project_root: &Path) -> Result<(), error::DecapodError> {
+fn mark_core_constitution_ingested(
+    project_root: &Path,
+    result: &docs_cli::DocsRunResult,
+) -> Result<(), error::DecapodError> {
     let agent_id = current_agent_id();
     let mut rec =
         read_awareness_record(project_root, &agent_id)?.unwrap_or(ConstitutionalAwarenessRecord {
@@ -1663,14 +1875,26 @@ fn mark_core_constitution_ingested(project_root: &Path) -> Result<(), error::Dec
             core_constitution_ingested_at_epoch_secs: None,
             context_resolved_at_epoch_secs: None,
             source_ops: Vec::new(),
+            ingested_sources: BTreeMap::new(),
         });
-    rec.core_constitution_ingested_at_epoch_secs = Some(now_epoch_secs());
+    rec.ingested_sources.extend(result.ingested_docs.clone());
+    // Selective ingestion counts once every required core doc has been ingested.
+    if result.ingested_core_constitution || missing_core_docs(&rec.ingested_sources).is_empty() {
+        rec.core_constitution_ingested_at_epoch_secs = Some(now_epoch_secs());
+    }
     if !rec.source_ops.iter().any(|op| op == "docs.ingest") {
         rec.source_ops.push("docs.ingest".to_string());
     }
     write_awareness_record(project_root, &rec)
 }
 
+fn missing_core_docs(ingested: &BTreeMap<String, String>) -> Vec<String> {
+    docs_cli::required_core_docs()
+        .into_iter()
+        .filter(|doc| !ingested.contains_key(doc))
+        .collect()
+}
+
 fn cleanup_expired_sessions(
     project_root: &Path,
     store_root: &Path,
@@ -1725,6 +1949,9 @@ fn ensure_session_valid() -> Result<(), error::DecapodError> {
     let _ = cleanup_expired_sessions(&project_root, &store_root)?;
 
     let agent_id = current_agent_id();
+    // Read and (re)issue under the sessions lock so concurrent processes for
+    // one agent converge on a single session instead of overwriting tokens.
+    let _lock = lock_sessions_dir(&project_root)?;
     let session = read_agent_session(&project_root, &agent_id)?;
     let Some(session) = session else {
         // Auto-acquire session if none exists (entrypoint funnel behavior)
@@ -1747,21 +1974,24 @@ fn ensure_session_valid() -> Result<(), error::DecapodError> {
         return auto_acquire_session(&project_root, &agent_id);
     }
 
-    let supplied_password = match std::env::var("DECAPOD_SESSION_PASSWORD") {
-        Ok(p) => p,
-        Err(_) => {
-            // No password in env - auto-acquire new session (entrypoint funnel)
-            return auto_acquire_session(&project_root, &agent_id);
-        }
+    let Ok(supplied_password) = std::env::var("DECAPOD_SESSION_PASSWORD") else {
+        // No password in env: reuse the live session rather than replacing a
+        // token another process for this agent may be holding.
+        eprintln!(
+            "session: reusing the active session for agent '{}'.",
+            agent_id
+        );
+        return Ok(());
     };
-    let supplied_hash = hash_password(&supplied_password, &session.token);
-    if supplied_hash != session.password_hash {
+    if hash_password(&supplied_password, &session.token) != session.password_hash {
         // Password invalid - auto-acquire new session (entrypoint funnel)
         return auto_acquire_session(&project_root, &agent_id);
     }
     Ok(())
 }
 
+/// Issue a fresh session for `agent_id`, replacing any existing one. Callers
+/// hold [`lock_sessions_dir`].
 fn auto_acquire_session(project_root: &Path, agent_id: &str) -> Result<(), error::DecapodError> {
     let issued = now_epoch_secs();
     let expires = issued.saturating_add(session_ttl_secs());
@@ -1796,6 +2026,7 @@ fn run_session_command(session_cli: SessionCli) -> Result<(), error::DecapodErro
     match session_cli.command {
         SessionCommand::Acquire => {
             let agent_id = current_agent_id();
+            let _lock = lock_sessions_dir(&project_root)?;
             if let Some(existing) = read_agent_session(&project_root, &agent_id)?
                 && existing.expires_at_epoch_secs > now_epoch_secs()
             {
@@ -1832,12 +2063,30 @@ fn run_session_command(session_cli: SessionCli) -> Result<(), error::DecapodErro
             println!("\nYou may now use other decapod commands.");
             Ok(())
         }
-        SessionCommand::Status => {
+        SessionCommand::Status {
+            format,
+            show_secrets,
+        } => {
             let agent_id = current_agent_id();
-            if let Some(session) = read_agent_session(&project_root, &agent_id)? {
+            let session = read_agent_session(&project_root, &agent_id)?;
+            if format == "json" {
+                let status = serde_json::json!({
+                    "active": session.is_some(),
+                    "agent_id": agent_id,
+                    "record": session,
+                    "awareness": read_awareness_record(&project_root, &agent_id)?,
+                });
+                println!("{}", core::output::render_json(&status, show_secrets)?);
+                return Ok(());
+            }
+            if let Some(session) = session {
                 println!("Session active");
                 println!("Agent: {}", session.agent_id);
-                println!("Token: {}", session.token);
+                if show_secrets {
+                    println!("Token: {}", session.token);
+                } else {
+                    println!("Token: {}", core::output::REDACTED);
+                }
                 println!("IssuedAtEpoch: {}", session.issued_at_epoch_secs);
                 println!("ExpiresAtEpoch: {}", session.expires_at_epoch_secs);
             } else {
@@ -1863,6 +2112,21 @@ fn run_session_command(session_cli: SessionCli) -> Result<(), error::DecapodErro
             }
             Ok(())
         }
+        SessionCommand::Whoami { format } => {
+            let identity = core::identity::resolve();
+            if format == "json" {
+                println!(
+                    "{}",
+                    serde_json::json!({
+                        "agent_id": identity.id,
+                        "source": identity.source.as_str(),
+                    })
+                );
+            } else {
+                println!("{} (source: {})", identity.id, identity.source.as_str());
+            }
+            Ok(())
+        }
         SessionCommand::Init {
             scope,
             mut proofs,
@@ -1886,6 +2150,10 @@ struct HandshakeArtifact {
     proofs: Vec<String>,
     declared_docs: Vec<String>,
     doc_hashes: serde_json::Value,
+    /// [`standards::standards_fingerprint`] at handshake time; absent in
+    /// records written before standards were pinned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    standards_hash: Option<String>,
     artifact_hash: String,
 }
 
@@ -1926,6 +2194,9 @@ fn build_handshake_artifact(
         );
     }
 
+    let standards_hash =
+        standards::standards_fingerprint(&standards::resolve_standards(project_root)?);
+
     let request_id = crate::core::ulid::new_ulid();
     let mut unsigned = serde_json::json!({
         "schema_version": "1.0.0",
@@ -1936,6 +2207,7 @@ fn build_handshake_artifact(
         "proofs": proofs,
         "declared_docs": required_docs,
         "doc_hashes": doc_hashes,
+        "standards_hash": standards_hash,
     });
     let canonical = serde_json::to_vec(&unsigned).map_err(|e| {
         error::DecapodError::ValidationError(format!("Failed to encode handshake artifact: {e}"))
@@ -1970,16 +2242,114 @@ fn write_handshake_artifact(
     Ok(path)
 }
 
+/// Hash of the artifact with `artifact_hash` itself left out, as sealed by
+/// [`build_handshake_artifact`].
+fn handshake_artifact_hash(artifact: &HandshakeArtifact) -> Result<String, error::DecapodError> {
+    let mut unsigned = serde_json::to_value(artifact).map_err(|e| {
+        error::DecapodError::ValidationError(format!("Failed to encode handshake artifact: {e}"))
+    })?;
+    if let Some(obj) = unsigned.as_object_mut() {
+        obj.remove("artifact_hash");
+    }
+    let canonical = serde_json::to_vec(&unsigned).map_err(|e| {
+        error::DecapodError::ValidationError(format!("Failed to encode handshake artifact: {e}"))
+    })?;
+    Ok(hash_bytes_hex(&canonical))
+}
+
+#[derive(Debug, Serialize)]
+struct HandshakeDrift {
+    doc: String,
+    recorded: Option<String>,
+    /// `None` when the doc no longer exists
+    current: Option<String>,
+}
+
+fn verify_handshake_artifact(
+    project_root: &Path,
+    path: &Path,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let raw = fs::read_to_string(path).map_err(|e| {
+        error::DecapodError::NotFound(format!(
+            "Handshake record '{}' not readable: {e}",
+            path.display()
+        ))
+    })?;
+    let artifact: HandshakeArtifact = serde_json::from_str(&raw).map_err(|e| {
+        error::DecapodError::ValidationError(format!(
+            "Handshake record '{}' is malformed: {e}",
+            path.display()
+        ))
+    })?;
+
+    let artifact_hash_valid = handshake_artifact_hash(&artifact)? == artifact.artifact_hash;
+    let mut drift = Vec::new();
+    for rel in &artifact.declared_docs {
+        let recorded = artifact.doc_hashes[rel.as_str()]
+            .as_str()
+            .map(str::to_string);
+        let current = fs::read(project_root.join(rel))
+            .ok()
+            .map(|bytes| hash_bytes_hex(&bytes));
+        if current.is_none() || current != recorded {
+            drift.push(HandshakeDrift {
+                doc: rel.clone(),
+                recorded,
+                current,
+            });
+        }
+    }
+
+    let current_standards =
+        standards::standards_fingerprint(&standards::resolve_standards(project_root)?);
+    let standards_drift = artifact
+        .standards_hash
+        .as_ref()
+        .is_some_and(|recorded| *recorded != current_standards);
+
+    let valid = artifact_hash_valid && drift.is_empty() && !standards_drift;
+    Ok(serde_json::json!({
+        "cmd": "handshake.verify",
+        "status": if valid { "ok" } else { "drift" },
+        "valid": valid,
+        "path": path,
+        "artifact_hash": artifact.artifact_hash,
+        "artifact_hash_valid": artifact_hash_valid,
+        "drift": drift,
+        "standards": {
+            "recorded": artifact.standards_hash,
+            "current": current_standards,
+            "drift": standards_drift,
+        },
+    }))
+}
+
 fn run_handshake_command(
     cli: HandshakeCli,
     project_root: &Path,
 ) -> Result<(), error::DecapodError> {
+    if let Some(HandshakeCommand::Verify { path }) = cli.command {
+        let report = verify_handshake_artifact(project_root, &path)?;
+        println!("{report}");
+        if report["valid"] != true {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Handshake '{}' no longer matches the repo; re-run `decapod handshake`.",
+                path.display()
+            )));
+        }
+        return Ok(());
+    }
+    let Some(scope) = cli.scope else {
+        return Err(error::DecapodError::ValidationError(
+            "Handshake requires `--scope`.".to_string(),
+        ));
+    };
     if cli.proofs.is_empty() {
         return Err(error::DecapodError::ValidationError(
             "Handshake requires at least one `--proof` declaration.".to_string(),
         ));
     }
-    let artifact = build_handshake_artifact(project_root, &cli.scope, &cli.proofs)?;
+    let artifact = build_handshake_artifact(project_root, &scope, &cli.proofs)?;
     let path = write_handshake_artifact(project_root, &artifact)?;
     println!(
         "{}",
@@ -3141,6 +3511,63 @@ fn run_validate_command(
     validate_cli: ValidateCli,
     project_root: &Path,
     project_store: &Store,
+) -> Result<(), error::DecapodError> {
+    if let Some(gate) = validate_cli.explain.as_deref() {
+        let explanation = validate::explain_gate(project_root, gate).ok_or_else(|| {
+            error::DecapodError::ValidationError(format!(
+                "Unknown gate '{}'. Known gates: {}",
+                gate,
+                validate::gate_names().join(", ")
+            ))
+        })?;
+        if validate_cli.format == "json" {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&explanation).map_err(|e| {
+                    error::DecapodError::ValidationError(format!(
+                        "validate JSON encode failed: {e}"
+                    ))
+                })?
+            );
+        } else {
+            validate::render_gate_explanation(&explanation);
+        }
+        return Ok(());
+    }
+
+    if !validate_cli.watch {
+        return run_validate_once(&validate_cli, project_root, project_store);
+    }
+
+    use crate::core::ansi::AnsiExt;
+    use crate::core::watch;
+    watch::install_interrupt_handler()?;
+    let config = watch::WatchConfig::from_env();
+    let cycles = watch::watch_loop(project_root, &config, watch::interrupted(), |cycle| {
+        if validate_cli.format != "json" {
+            println!(
+                "\n{} cycle {} ({})",
+                "validate --watch:".bright_cyan(),
+                cycle + 1,
+                crate::core::time::now_epoch_z()
+            );
+        }
+        // A failing cycle is reported and the watcher keeps going.
+        if let Err(e) = run_validate_once(&validate_cli, project_root, project_store) {
+            eprintln!("validate --watch: {e}");
+        }
+        Ok(())
+    })?;
+    if validate_cli.format != "json" {
+        println!("validate --watch: stopped after {cycles} cycle(s)");
+    }
+    Ok(())
+}
+
+fn run_validate_once(
+    validate_cli: &ValidateCli,
+    project_root: &Path,
+    project_store: &Store,
 ) -> Result<(), error::DecapodError> {
     use crate::core::workspace;
 
@@ -3180,23 +3607,6 @@ fn run_validate_command(
         }
     }
 
-    let decapod_root = project_root.to_path_buf();
-    let store = match validate_cli.store.as_str() {
-        "user" => {
-            // User store uses a temp directory for blank-slate validation
-            let tmp_root = std::env::temp_dir().join(format!(
-                "decapod_validate_user_{}",
-                crate::core::ulid::new_ulid()
-            ));
-            std::fs::create_dir_all(&tmp_root).map_err(error::DecapodError::IoError)?;
-            Store {
-                kind: StoreKind::User,
-                root: tmp_root,
-            }
-        }
-        _ => project_store.clone(),
-    };
-
     let mut heal_actions = Vec::new();
     if let Some(action) = heal_override_checksum(project_root)? {
         heal_actions.push(action);
@@ -3211,33 +3621,62 @@ fn run_validate_command(
         heal_actions.push(action);
     }
 
-    let mut report = run_validation_bounded(&store, &decapod_root, validate_cli.verbose)?;
-    for _ in 0..2 {
-        if report.fail_count == 0 {
-            break;
-        }
-        let mut round_actions = attempt_validation_failure_heal(&report, project_root, &store)?;
-        if round_actions.is_empty() {
-            break;
+    let report = match validate_cli.store.as_str() {
+        "user" => validate_store_with_heal(
+            &blank_slate_user_store()?,
+            project_root,
+            validate_cli.verbose,
+            &mut heal_actions,
+        )?,
+        "both" => {
+            let repo = validate_store_with_heal(
+                project_store,
+                project_root,
+                validate_cli.verbose,
+                &mut heal_actions,
+            )?;
+            let user = validate_store_with_heal(
+                &blank_slate_user_store()?,
+                project_root,
+                validate_cli.verbose,
+                &mut heal_actions,
+            )?;
+            validate::merge_store_reports(vec![("repo", repo), ("user", user)])
         }
-        heal_actions.append(&mut round_actions);
-        report = run_validation_bounded(&store, &decapod_root, validate_cli.verbose)?;
-    }
+        _ => validate_store_with_heal(
+            project_store,
+            project_root,
+            validate_cli.verbose,
+            &mut heal_actions,
+        )?,
+    };
+
+    let baseline = if validate_cli.baseline {
+        Some(validate::compare_and_store_baseline(project_root, &report)?)
+    } else {
+        None
+    };
 
     if validate_cli.format == "json" {
+        let mut payload = serde_json::json!({
+            "status": report.status,
+            "self_heal": heal_actions,
+            "report": report,
+        });
+        if let Some(delta) = &baseline {
+            payload["baseline"] = serde_json::json!(delta);
+        }
         println!(
             "{}",
-            serde_json::to_string_pretty(&serde_json::json!({
-                "status": report.status,
-                "self_heal": heal_actions,
-                "report": report,
-            }))
-            .map_err(|e| error::DecapodError::ValidationError(format!(
-                "validate JSON encode failed: {e}"
-            )))?,
+            serde_json::to_string_pretty(&payload).map_err(|e| {
+                error::DecapodError::ValidationError(format!("validate JSON encode failed: {e}"))
+            })?,
         );
     } else {
         render_validation_text(&report, &heal_actions, validate_cli.verbose);
+        if let Some(delta) = &baseline {
+            validate::render_baseline_delta(delta);
+        }
     }
 
     if report.fail_count > 0 {
@@ -3250,13 +3689,44 @@ fn run_validate_command(
     Ok(())
 }
 
+/// A fresh temp-dir user store for blank-slate validation.
+fn blank_slate_user_store() -> Result<Store, error::DecapodError> {
+    let tmp_root = std::env::temp_dir().join(format!(
+        "decapod_validate_user_{}",
+        crate::core::ulid::new_ulid()
+    ));
+    std::fs::create_dir_all(&tmp_root).map_err(error::DecapodError::IoError)?;
+    Ok(Store {
+        kind: StoreKind::User,
+        root: tmp_root,
+    })
+}
+
+/// Validate `store`, retrying up to twice after self-healing known failures.
+fn validate_store_with_heal(
+    store: &Store,
+    project_root: &Path,
+    verbose: bool,
+    heal_actions: &mut Vec<ValidationHealAction>,
+) -> Result<validate::ValidationReport, error::DecapodError> {
+    let decapod_root = project_root.to_path_buf();
+    let mut report = run_validation_bounded(store, &decapod_root, verbose)?;
+    for _ in 0..2 {
+        if report.fail_count == 0 {
+            break;
+        }
+        let mut round_actions = attempt_validation_failure_heal(&report, project_root, store)?;
+        if round_actions.is_empty() {
+            break;
+        }
+        heal_actions.append(&mut round_actions);
+        report = run_validation_bounded(store, &decapod_root, verbose)?;
+    }
+    Ok(report)
+}
+
 fn validate_timeout_secs() -> u64 {
-    std::env::var("DECAPOD_VALIDATE_TIMEOUT_SECS")
-        .ok()
-        .or_else(|| std::env::var("DECAPOD_VALIDATE_TIMEOUT_SECONDS").ok())
-        .and_then(|v| v.parse::<u64>().ok())
-        .filter(|v| *v > 0)
-        .unwrap_or(120)
+    settings::current().validate_timeout_secs
 }
 
 fn validate_diagnostics_enabled() -> bool {
@@ -3412,6 +3882,16 @@ fn normalize_validate_error(err: error::DecapodError) -> error::DecapodError {
             }
             error::DecapodError::ValidationError(message)
         }
+        // Aggregated subsystem init failures: any locked DB still means contention.
+        error::DecapodError::DatabaseInitializationError(message) => {
+            let lower = message.to_ascii_lowercase();
+            if lower.contains("database is locked") || lower.contains("databasebusy") {
+                return error::DecapodError::ValidationError(
+                    "VALIDATE_TIMEOUT_OR_LOCK: SQLite contention detected. Retry with backoff or inspect concurrent decapod processes.".to_string(),
+                );
+            }
+            error::DecapodError::DatabaseInitializationError(message)
+        }
         other => other,
     }
 }
@@ -3501,16 +3981,32 @@ fn run_validation_bounded(
         let _ = tx.send(result);
     });
 
-    let result = match rx.recv_timeout(std::time::Duration::from_secs(timeout_secs)) {
+    let timeouts = validate::GateTimeouts::load(project_root, timeout_secs);
+    let result = match validate::wait_for_validation(&rx, &timeouts, validate::gate_progress()) {
         Ok(result) => result.map_err(normalize_validate_error),
-        Err(mpsc::RecvTimeoutError::Timeout) => Err(error::DecapodError::ValidationError(format!(
-            "VALIDATE_TIMEOUT_OR_LOCK: validate exceeded timeout ({}s). Terminated to preserve proof-gate liveness.",
-            timeout_secs
-        ))),
-        Err(mpsc::RecvTimeoutError::Disconnected) => Err(error::DecapodError::ValidationError(
-            "VALIDATE_TIMEOUT_OR_LOCK: validate worker disconnected unexpectedly.".to_string(),
-        )),
+        Err(validate::ValidationWaitError::GateTimeout { gate, bound }) => {
+            Err(error::DecapodError::ValidationError(format!(
+                "VALIDATE_TIMEOUT_OR_LOCK: validate gate '{}' exceeded timeout ({}s). Terminated to preserve proof-gate liveness.",
+                gate,
+                bound.as_secs()
+            )))
+        }
+        Err(validate::ValidationWaitError::RunTimeout(overall)) => {
+            Err(error::DecapodError::ValidationError(format!(
+                "VALIDATE_TIMEOUT_OR_LOCK: validate exceeded timeout ({}s). Terminated to preserve proof-gate liveness.",
+                overall.as_secs()
+            )))
+        }
+        Err(validate::ValidationWaitError::Disconnected) => {
+            Err(error::DecapodError::ValidationError(
+                "VALIDATE_TIMEOUT_OR_LOCK: validate worker disconnected unexpectedly.".to_string(),
+            ))
+        }
     };
+    if let Ok(report) = &result {
+        // Best-effort: the history only feeds `doctor snapshot`.
+        let _ = validate::record_validation_run(project_root, report);
+    }
     result.map_err(|err| {
         attach_validate_diagnostic_if_enabled(
             err,
@@ -3526,6 +4022,8 @@ fn rpc_op_requires_constitutional_awareness(op: &str) -> bool {
         op,
         "workspace.publish"
             | "store.upsert"
+            | "federation.add_node"
+            | "federation.add_edge"
             | "scaffold.apply_answer"
             | "scaffold.generate_artifacts"
     )
@@ -3567,12 +4065,43 @@ fn enforce_constitutional_awareness_for_rpc(
     }
 
     if rec.core_constitution_ingested_at_epoch_secs.is_none() {
+        if !rec.ingested_sources.is_empty() {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Constitutional awareness incomplete: core docs not yet ingested: {}. Run `decapod docs ingest --path <doc>` for each, or `decapod docs ingest`.",
+                missing_core_docs(&rec.ingested_sources).join(", ")
+            )));
+        }
         return Err(error::DecapodError::ValidationError(
             "Constitutional awareness incomplete: core constitution ingestion missing. Run `decapod docs ingest` to ingest `constitution/core/*.md` before mutating operations."
                 .to_string(),
         ));
     }
 
+    let ingested_at = rec.core_constitution_ingested_at_epoch_secs.unwrap_or(0);
+    let ttl = settings::current().awareness_ttl_secs;
+    let age = now_epoch_secs().saturating_sub(ingested_at);
+    if age > ttl {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Constitutional awareness expired: core constitution was ingested {}s ago (awareness.ttl_secs = {}). Re-run `decapod docs ingest`.",
+            age, ttl
+        )));
+    }
+
+    let changed: Vec<&str> = rec
+        .ingested_sources
+        .iter()
+        .filter(|(doc, hash)| {
+            docs_cli::merged_doc_hash(project_root, doc).as_deref() != Some(hash.as_str())
+        })
+        .map(|(doc, _)| doc.as_str())
+        .collect();
+    if !changed.is_empty() {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Constitutional awareness is stale: docs changed since ingestion: {}. Re-run `decapod docs ingest` (or `--path` for each).",
+            changed.join(", ")
+        )));
+    }
+
     if rec.context_resolved_at_epoch_secs.is_none() {
         return Err(error::DecapodError::ValidationError(
             "Constitutional awareness incomplete: `context.resolve` has not been executed after initialization. Run `decapod rpc --op context.resolve`."
@@ -3619,9 +4148,36 @@ fn run_govern_command(
                         feedback::add_feedback(project_store, &source, &text, links.as_deref())?;
                     println!("Feedback recorded: {}", id);
                 }
-                FeedbackCommand::Propose => {
-                    let proposal = feedback::propose_prefs(project_store)?;
-                    println!("{}", proposal);
+                FeedbackCommand::List { status } => {
+                    let entries = feedback::list_feedback(project_store, status.as_deref())?;
+                    println!("{}", serde_json::to_string_pretty(&entries).unwrap());
+                }
+                FeedbackCommand::Resolve { id, outcome, note } => {
+                    let entry =
+                        feedback::resolve_feedback(project_store, &id, &outcome, note.as_deref())?;
+                    println!("{}", serde_json::to_string_pretty(&entry).unwrap());
+                }
+                FeedbackCommand::Promote {
+                    id,
+                    priority,
+                    category,
+                } => {
+                    let promoted = feedback::promote_feedback(
+                        project_store,
+                        &id,
+                        &priority,
+                        category.as_deref(),
+                    )?;
+                    println!("{}", serde_json::to_string_pretty(&promoted).unwrap());
+                }
+                FeedbackCommand::Propose { apply } => {
+                    if apply {
+                        let result = feedback::apply_proposed_prefs(project_store)?;
+                        println!("{}", serde_json::to_string_pretty(&result).unwrap());
+                    } else {
+                        let proposal = feedback::propose_prefs(project_store)?;
+                        println!("{}", proposal);
+                    }
                 }
             }
         }
@@ -3645,16 +4201,9 @@ fn run_govern_command(
                     explicit.into_iter().map(std::path::PathBuf::from).collect()
                 } else {
                     // Get staged files from git
-                    let output = std::process::Command::new("git")
-                        .args(["diff", "--cached", "--name-only"])
-                        .current_dir(repo_root)
-                        .output()
-                        .map_err(error::DecapodError::IoError)?;
-                    String::from_utf8_lossy(&output.stdout)
-                        .lines()
-                        .filter(|l| !l.is_empty())
-                        .map(std::path::PathBuf::from)
-                        .collect()
+                    gatekeeper::staged_paths(repo_root)
+                        .map_err(error::DecapodError::IoError)?
+                        .unwrap_or_default()
                 };
 
                 // Get diff size
@@ -4024,9 +4573,27 @@ fn run_data_command(
                     let items = archive::list_archives(project_store)?;
                     println!("{}", serde_json::to_string_pretty(&items).unwrap());
                 }
-                ArchiveCommand::Verify => {
-                    let failures = archive::verify_archives(project_store)?;
-                    if failures.is_empty() {
+                ArchiveCommand::Verify { repair } => {
+                    if repair {
+                        let report = archive::repair_archives(project_store)?;
+                        for fixed in &report.repaired {
+                            println!(
+                                "Repaired archive {} ({}): {} -> {}",
+                                fixed.id,
+                                fixed.drift.as_str(),
+                                fixed.previous_hash,
+                                fixed.content_hash
+                            );
+                        }
+                        for refused in &report.refused {
+                            println!(
+                                "Refused to repair archive {}: {}",
+                                refused.id, refused.reason
+                            );
+                        }
+                    }
+                    let failures = archive::verify_archives(project_store)?;
+                    if failures.is_empty() {
                         println!("All archives verified successfully.");
                     } else {
                         println!("Archive verification failed:");
@@ -4044,9 +4611,35 @@ fn run_data_command(
                     id,
                     title,
                     text,
+                    text_file,
                     provenance,
                     claim_id,
+                    namespace,
                 } => {
+                    let from_file = text_file
+                        .as_deref()
+                        .map(knowledge::read_text_file)
+                        .transpose()?;
+                    let text = match (&from_file, text) {
+                        (Some(source), _) => source.content.clone(),
+                        (None, Some(text)) => text,
+                        (None, None) => unreachable!("clap requires --text or --text-file"),
+                    };
+                    let title = match (title, &from_file) {
+                        (Some(title), _) => title,
+                        (None, Some(source)) => source.heading.clone().ok_or_else(|| {
+                            error::DecapodError::ValidationError(format!(
+                                "No markdown heading in {}; pass --title",
+                                source.path
+                            ))
+                        })?,
+                        (None, None) => unreachable!("clap requires --title or --text-file"),
+                    };
+                    let provenance = match (provenance, &from_file) {
+                        (Some(provenance), _) => provenance,
+                        (None, Some(source)) => source.provenance(),
+                        (None, None) => unreachable!("clap requires --provenance or --text-file"),
+                    };
                     let result = knowledge::add_knowledge(
                         project_store,
                         knowledge::AddKnowledgeParams {
@@ -4060,6 +4653,7 @@ fn run_data_command(
                             status: "active",
                             ttl_policy: "persistent",
                             expires_ts: None,
+                            namespace: Some(&namespace),
                         },
                     )?;
                     println!(
@@ -4067,18 +4661,39 @@ fn run_data_command(
                         result.id, id, result.action
                     );
                 }
-                KnowledgeCommand::Search { query } => {
-                    let results = knowledge::search_knowledge(
+                KnowledgeCommand::Search {
+                    query,
+                    scope,
+                    namespace,
+                } => {
+                    let user_store = Store::user();
+                    let results = knowledge::search_knowledge_scoped(
                         project_store,
+                        user_store.as_ref(),
                         &query,
+                        &scope,
                         knowledge::SearchOptions {
                             as_of: None,
                             window_days: None,
                             rank: "relevance",
+                            namespace: namespace.as_deref(),
                         },
                     )?;
                     println!("{}", serde_json::to_string_pretty(&results).unwrap());
                 }
+                KnowledgeCommand::Stats { as_of } => {
+                    let stats = knowledge::knowledge_stats(project_store, as_of.as_deref())?;
+                    println!("{}", serde_json::to_string_pretty(&stats).unwrap());
+                }
+                KnowledgeCommand::Merge { into, from } => {
+                    let result = knowledge::merge_knowledge(
+                        project_store,
+                        &into,
+                        &from,
+                        &current_agent_id(),
+                    )?;
+                    println!("{}", serde_json::to_string_pretty(&result).unwrap());
+                }
                 KnowledgeCommand::Promote {
                     source_entry_id,
                     evidence_refs,
@@ -4120,9 +4735,15 @@ fn run_data_command(
                         }
                     }
                 }
-                ContextCommand::Pack { path, summary } => {
-                    let archive_path = manager
-                        .pack_and_archive(project_store, &path, &summary)
+                ContextCommand::Pack {
+                    path,
+                    summary,
+                    in_place,
+                    index,
+                } => {
+                    let rewrite = in_place.then(|| index.as_deref().unwrap_or(&path));
+                    let outcome = manager
+                        .pack_and_archive(project_store, &path, &summary, rewrite)
                         .map_err(|err| match err {
                             error::DecapodError::ContextPackError(msg) => {
                                 error::DecapodError::ContextPackError(format!(
@@ -4132,27 +4753,100 @@ fn run_data_command(
                             }
                             other => other,
                         })?;
-                    println!("Session archived to: {}", archive_path.display());
+                    println!("Session archived to: {}", outcome.archive_path.display());
+                    println!("Archive ID: {}", outcome.archive_id);
+                    if let Some(rewritten) = outcome.rewritten {
+                        println!(
+                            "Rewrote {} to summary + restore pointer",
+                            rewritten.display()
+                        );
+                    }
                 }
                 ContextCommand::Restore {
                     id,
                     profile,
                     current_files,
-                } => {
-                    let content = manager.restore_archive(&id, &profile, &current_files)?;
-                    println!(
-                        "--- RESTORED CONTENT (Archive: {}) ---\n{}\n--- END RESTORED ---",
-                        id, content
-                    );
-                }
+                    force,
+                } => match manager.restore_archive(&id, &profile, &current_files, force)? {
+                    context::RestoreDecision::Blocked(overflow) => {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&serde_json::json!({
+                                "status": "blocked",
+                                "archive_id": id,
+                                "overflow": overflow,
+                            }))
+                            .unwrap()
+                        );
+                        let hint = if overflow.eviction_candidates.is_empty() {
+                            "The archive alone exceeds the budget; pass --force.".to_string()
+                        } else {
+                            format!(
+                                "Evict {} or pass --force.",
+                                overflow
+                                    .eviction_candidates
+                                    .iter()
+                                    .map(|c| format!("{} ({} tokens)", c.path, c.tokens))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )
+                        };
+                        return Err(error::DecapodError::ValidationError(format!(
+                            "Restore blocked: budget exceeded by {} tokens ({} + {} > {}). {}",
+                            overflow.overflow_tokens,
+                            overflow.current_tokens,
+                            overflow.restore_tokens,
+                            overflow.budget_tokens,
+                            hint
+                        )));
+                    }
+                    context::RestoreDecision::Restored {
+                        content,
+                        restore_tokens,
+                        overflow,
+                    } => {
+                        match overflow {
+                            Some(overflow) => println!(
+                                "⚠ Restore forced past '{}' budget by {} tokens",
+                                profile, overflow.overflow_tokens
+                            ),
+                            None => println!(
+                                "✓ Restore approved within '{}' budget ({} tokens added)",
+                                profile, restore_tokens
+                            ),
+                        }
+                        println!(
+                            "--- RESTORED CONTENT (Archive: {}) ---\n{}\n--- END RESTORED ---",
+                            id, content
+                        );
+                    }
+                },
             }
         }
         DataCommand::Schema(schema_cli) => {
+            if let Some(old_path) = &schema_cli.diff {
+                let raw = fs::read_to_string(old_path)?;
+                let old: serde_json::Value = serde_json::from_str(&raw).map_err(|e| {
+                    error::DecapodError::ValidationError(format!(
+                        "Invalid schema catalog {}: {}",
+                        old_path.display(),
+                        e
+                    ))
+                })?;
+                let diff = schema_catalog_diff(&old, &deterministic_schema_envelope())?;
+                println!("{}", serde_json::to_string_pretty(&diff).unwrap());
+                return Ok(());
+            }
+
             let schemas = schema_catalog();
 
-            let output = if let Some(sub) = schema_cli.subsystem {
+            let selected = schema_cli
+                .subsystem
+                .as_deref()
+                .and_then(|sub| schemas.get_key_value(sub).map(|(name, _)| *name));
+            let output = if let Some(sub) = schema_cli.subsystem.as_deref() {
                 schemas
-                    .get(sub.as_str())
+                    .get(sub)
                     .cloned()
                     .unwrap_or(serde_json::json!({ "error": "subsystem not found" }))
             } else {
@@ -4168,9 +4862,10 @@ fn run_data_command(
 
             match schema_cli.format.as_str() {
                 "json" => println!("{}", serde_json::to_string_pretty(&output).unwrap()),
-                "md" => {
-                    println!("{}", schema_to_markdown(&output));
-                }
+                "md" => match selected {
+                    Some(name) => println!("{}", subsystem_schema_to_markdown(name, &output)),
+                    None => println!("{}", schema_to_markdown(&output)),
+                },
                 other => {
                     return Err(error::DecapodError::ValidationError(format!(
                         "Unsupported schema format '{}'. Use 'json' or 'md'.",
@@ -4190,13 +4885,39 @@ fn run_data_command(
             }
         },
         DataCommand::Broker(broker_cli) => match broker_cli.command {
-            BrokerCommand::Audit => {
+            BrokerCommand::Audit { format } => {
                 let audit_log = store_root.join("broker.events.jsonl");
-                if audit_log.exists() {
-                    let content = std::fs::read_to_string(audit_log)?;
-                    println!("{}", content);
-                } else {
-                    println!("No audit log found.");
+                match format {
+                    BrokerAuditFormat::Text => {
+                        if audit_log.exists() {
+                            let content = std::fs::read_to_string(audit_log)?;
+                            println!("{}", content);
+                        } else {
+                            println!("No audit log found.");
+                        }
+                    }
+                    BrokerAuditFormat::Jsonl => {
+                        if !audit_log.exists() {
+                            return Ok(());
+                        }
+                        let reader = std::io::BufReader::new(fs::File::open(&audit_log)?);
+                        let mut writer = core::output::JsonlWriter::new(std::io::stdout().lock());
+                        for (idx, line) in std::io::BufRead::lines(reader).enumerate() {
+                            let line = line?;
+                            if line.trim().is_empty() {
+                                continue;
+                            }
+                            let event: serde_json::Value =
+                                serde_json::from_str(&line).map_err(|e| {
+                                    error::DecapodError::ValidationError(format!(
+                                        "Malformed broker audit event at line {}: {}",
+                                        idx + 1,
+                                        e
+                                    ))
+                                })?;
+                            writer.write_record(&event)?;
+                        }
+                    }
                 }
             }
             BrokerCommand::Verify => {
@@ -4210,6 +4931,10 @@ fn run_data_command(
                     )));
                 }
             }
+            BrokerCommand::Replay { to, into } => {
+                let replay = core::broker::replay_to(store_root, &to, &into)?;
+                println!("{}", serde_json::to_string_pretty(&replay).unwrap());
+            }
         },
         DataCommand::Aptitude(aptitude_cli) => {
             aptitude::run_aptitude_cli(project_store, aptitude_cli)?;
@@ -4226,48 +4951,181 @@ fn run_data_command(
 }
 
 fn schema_to_markdown(schema: &serde_json::Value) -> String {
-    fn render_value(v: &serde_json::Value) -> String {
+    let mut out = String::from("# Decapod Schema\n\n");
+    out.push_str(&render_schema_value(schema));
+    out
+}
+
+/// Renders one subsystem's `schema()` as a titled section: an ops table from
+/// `commands`, a fields table for scalar/list entries, and nested objects
+/// through the generic renderer.
+fn subsystem_schema_to_markdown(name: &str, schema: &serde_json::Value) -> String {
+    fn inline(v: &serde_json::Value) -> String {
         match v {
-            serde_json::Value::Object(map) => {
-                let mut keys: Vec<_> = map.keys().cloned().collect();
-                keys.sort();
-                let mut out = String::new();
-                for key in keys {
-                    let value = &map[&key];
-                    match value {
-                        serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
-                            out.push_str(&format!("- **{}**:\n", key));
-                            for line in render_value(value).lines() {
-                                out.push_str(&format!("  {}\n", line));
-                            }
+            serde_json::Value::String(s) => format!("`{}`", s.replace('|', "\\|")),
+            serde_json::Value::Array(items) => {
+                items.iter().map(inline).collect::<Vec<_>>().join(", ")
+            }
+            other => format!("`{}`", other.to_string().replace('|', "\\|")),
+        }
+    }
+    fn is_flat(v: &serde_json::Value) -> bool {
+        match v {
+            serde_json::Value::Object(_) => false,
+            serde_json::Value::Array(items) => items.iter().all(|i| {
+                !matches!(
+                    i,
+                    serde_json::Value::Object(_) | serde_json::Value::Array(_)
+                )
+            }),
+            _ => true,
+        }
+    }
+
+    let Some(map) = schema.as_object() else {
+        return schema_to_markdown(schema);
+    };
+    let version = map.get("version").and_then(|v| v.as_str());
+    let mut out = match version {
+        Some(v) => format!("# `{name}` schema (v{v})\n\n"),
+        None => format!("# `{name}` schema\n\n"),
+    };
+    if let Some(desc) = map.get("description").and_then(|v| v.as_str()) {
+        out.push_str(desc);
+        out.push_str("\n\n");
+    }
+
+    if let Some(commands) = map.get("commands").and_then(|v| v.as_array()) {
+        let rows: Vec<(String, String, String)> = commands
+            .iter()
+            .map(|cmd| {
+                let op = cmd.get("name").and_then(|v| v.as_str()).unwrap_or("?");
+                let params = cmd
+                    .get("parameters")
+                    .map(inline)
+                    .filter(|p| !p.is_empty())
+                    .unwrap_or_else(|| "—".to_string());
+                let notes = cmd
+                    .as_object()
+                    .map(|obj| {
+                        obj.iter()
+                            .filter(|(k, _)| k.as_str() != "name" && k.as_str() != "parameters")
+                            .map(|(k, v)| format!("{k}: {}", inline(v)))
+                            .collect::<Vec<_>>()
+                            .join("; ")
+                    })
+                    .unwrap_or_default();
+                (format!("`{op}`"), params, notes)
+            })
+            .collect();
+        let with_notes = rows.iter().any(|(_, _, notes)| !notes.is_empty());
+        out.push_str("## Operations\n\n");
+        if with_notes {
+            out.push_str("| Op | Parameters | Notes |\n|---|---|---|\n");
+        } else {
+            out.push_str("| Op | Parameters |\n|---|---|\n");
+        }
+        for (op, params, notes) in rows {
+            if with_notes {
+                out.push_str(&format!("| {op} | {params} | {notes} |\n"));
+            } else {
+                out.push_str(&format!("| {op} | {params} |\n"));
+            }
+        }
+        out.push('\n');
+    }
+
+    if let Some(entity_fields) = map.get("fields").and_then(|v| v.as_object()) {
+        out.push_str(
+            "## Fields\n\n| Field | Type | Allowed values | Description |\n|---|---|---|---|\n",
+        );
+        for (field, spec) in entity_fields {
+            let ty = spec
+                .get("type")
+                .and_then(|v| v.as_str())
+                .unwrap_or("string");
+            let required = if spec.get("required").and_then(|v| v.as_bool()) == Some(true) {
+                " (required)"
+            } else {
+                ""
+            };
+            let allowed = spec
+                .get("enum")
+                .map(inline)
+                .unwrap_or_else(|| "—".to_string());
+            let desc = spec
+                .get("description")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .replace('|', "\\|");
+            out.push_str(&format!(
+                "| `{field}` | {ty}{required} | {allowed} | {desc} |\n"
+            ));
+        }
+        out.push('\n');
+    }
+
+    let skip = ["name", "version", "description", "commands", "fields"];
+    let fields: Vec<_> = map
+        .iter()
+        .filter(|(k, v)| !skip.contains(&k.as_str()) && is_flat(v))
+        .collect();
+    if !fields.is_empty() {
+        out.push_str("## Metadata\n\n| Key | Value |\n|---|---|\n");
+        for (key, value) in fields {
+            out.push_str(&format!("| `{key}` | {} |\n", inline(value)));
+        }
+        out.push('\n');
+    }
+
+    for (key, value) in map
+        .iter()
+        .filter(|(k, v)| !skip.contains(&k.as_str()) && !is_flat(v))
+    {
+        out.push_str(&format!("## {key}\n\n"));
+        out.push_str(&render_schema_value(value));
+        out.push('\n');
+    }
+    out
+}
+
+fn render_schema_value(v: &serde_json::Value) -> String {
+    match v {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<_> = map.keys().cloned().collect();
+            keys.sort();
+            let mut out = String::new();
+            for key in keys {
+                let value = &map[&key];
+                match value {
+                    serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                        out.push_str(&format!("- **{}**:\n", key));
+                        for line in render_schema_value(value).lines() {
+                            out.push_str(&format!("  {}\n", line));
                         }
-                        _ => out.push_str(&format!("- **{}**: `{}`\n", key, value)),
                     }
+                    _ => out.push_str(&format!("- **{}**: `{}`\n", key, value)),
                 }
-                out
             }
-            serde_json::Value::Array(items) => {
-                let mut out = String::new();
-                for item in items {
-                    match item {
-                        serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
-                            out.push_str("- item:\n");
-                            for line in render_value(item).lines() {
-                                out.push_str(&format!("  {}\n", line));
-                            }
+            out
+        }
+        serde_json::Value::Array(items) => {
+            let mut out = String::new();
+            for item in items {
+                match item {
+                    serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                        out.push_str("- item:\n");
+                        for line in render_schema_value(item).lines() {
+                            out.push_str(&format!("  {}\n", line));
                         }
-                        _ => out.push_str(&format!("- `{}`\n", item)),
                     }
+                    _ => out.push_str(&format!("- `{}`\n", item)),
                 }
-                out
             }
-            _ => format!("- `{}`\n", v),
+            out
         }
+        _ => format!("- `{}`\n", v),
     }
-
-    let mut out = String::from("# Decapod Schema\n\n");
-    out.push_str(&render_value(schema));
-    out
 }
 
 pub(crate) fn deterministic_schema_envelope() -> serde_json::Value {
@@ -4284,6 +5142,172 @@ pub(crate) fn deterministic_schema_envelope() -> serde_json::Value {
     })
 }
 
+/// Accept either a full `data schema` envelope or a bare subsystem map.
+fn catalog_subsystems(
+    catalog: &serde_json::Value,
+) -> Option<&serde_json::Map<String, serde_json::Value>> {
+    catalog
+        .get("subsystems")
+        .and_then(|v| v.as_object())
+        .or_else(|| catalog.as_object())
+}
+
+/// Ops are a subsystem's `commands` entries, keyed by name.
+fn schema_ops(schema: &serde_json::Value) -> Vec<(String, &serde_json::Value)> {
+    schema
+        .get("commands")
+        .and_then(|v| v.as_array())
+        .map(|cmds| {
+            cmds.iter()
+                .filter_map(|c| {
+                    let name = c.get("name").and_then(|n| n.as_str()).or(c.as_str())?;
+                    Some((name.to_string(), c))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Fields are every other top-level key of a subsystem schema.
+fn schema_fields(schema: &serde_json::Value) -> Vec<(String, &serde_json::Value)> {
+    schema
+        .as_object()
+        .map(|o| {
+            o.iter()
+                .filter(|(k, _)| k.as_str() != "commands")
+                .map(|(k, v)| (k.clone(), v))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Serialize)]
+struct SchemaEntryChange {
+    name: String,
+    old: serde_json::Value,
+    new: serde_json::Value,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct SchemaKeyedDiff {
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: Vec<SchemaEntryChange>,
+}
+
+impl SchemaKeyedDiff {
+    fn between(old: &[(String, &serde_json::Value)], new: &[(String, &serde_json::Value)]) -> Self {
+        let find = |list: &[(String, &serde_json::Value)], name: &str| {
+            list.iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| (*v).clone())
+        };
+        let mut diff = Self::default();
+        for (name, after) in new {
+            match find(old, name) {
+                None => diff.added.push(name.clone()),
+                Some(before) if before != **after => diff.changed.push(SchemaEntryChange {
+                    name: name.clone(),
+                    old: before,
+                    new: (*after).clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        for (name, _) in old {
+            if find(new, name).is_none() {
+                diff.removed.push(name.clone());
+            }
+        }
+        diff
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SubsystemSchemaDiff {
+    ops: SchemaKeyedDiff,
+    fields: SchemaKeyedDiff,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct SubsystemChanges {
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: std::collections::BTreeMap<String, SubsystemSchemaDiff>,
+}
+
+#[derive(Debug, Serialize)]
+struct SchemaCatalogDiff {
+    old_schema_version: Option<serde_json::Value>,
+    new_schema_version: Option<serde_json::Value>,
+    breaking: bool,
+    subsystems: SubsystemChanges,
+    deprecations: serde_json::Value,
+}
+
+/// Structured diff of a prior schema catalog against the current one:
+/// subsystems added/removed, and per changed subsystem its ops and remaining
+/// top-level fields. Any removal marks the diff `breaking`; the current
+/// deprecation entries ride along for the migration picture.
+fn schema_catalog_diff(
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+) -> Result<SchemaCatalogDiff, error::DecapodError> {
+    let not_a_catalog = |which: &str| {
+        error::DecapodError::ValidationError(format!(
+            "{} schema catalog must be a JSON object (a `data schema` envelope or subsystem map)",
+            which
+        ))
+    };
+    let old_subs = catalog_subsystems(old).ok_or_else(|| not_a_catalog("Old"))?;
+    let new_subs = catalog_subsystems(new).ok_or_else(|| not_a_catalog("Current"))?;
+
+    let mut subsystems = SubsystemChanges {
+        added: new_subs
+            .keys()
+            .filter(|k| !old_subs.contains_key(*k))
+            .cloned()
+            .collect(),
+        removed: old_subs
+            .keys()
+            .filter(|k| !new_subs.contains_key(*k))
+            .cloned()
+            .collect(),
+        ..Default::default()
+    };
+    let mut breaking = !subsystems.removed.is_empty();
+    for (name, after) in new_subs {
+        let Some(before) = old_subs.get(name) else {
+            continue;
+        };
+        let diff = SubsystemSchemaDiff {
+            ops: SchemaKeyedDiff::between(&schema_ops(before), &schema_ops(after)),
+            fields: SchemaKeyedDiff::between(&schema_fields(before), &schema_fields(after)),
+        };
+        if diff.ops.is_empty() && diff.fields.is_empty() {
+            continue;
+        }
+        breaking |= !diff.ops.removed.is_empty() || !diff.fields.removed.is_empty();
+        subsystems.changed.insert(name.clone(), diff);
+    }
+
+    Ok(SchemaCatalogDiff {
+        old_schema_version: old.get("schema_version").cloned(),
+        new_schema_version: new.get("schema_version").cloned(),
+        breaking,
+        subsystems,
+        deprecations: new
+            .get("deprecations")
+            .and_then(|d| d.get("entries"))
+            .cloned()
+            .unwrap_or(serde_json::json!([])),
+    })
+}
+
 fn schema_catalog() -> std::collections::BTreeMap<&'static str, serde_json::Value> {
     let mut schemas = std::collections::BTreeMap::new();
     schemas.insert("todo", todo::schema());
@@ -4468,7 +5492,9 @@ fn run_qa_command(
 fn run_hook_install(
     commit_msg: bool,
     pre_commit: bool,
+    pre_push: bool,
     uninstall: bool,
+    force: bool,
 ) -> Result<(), error::DecapodError> {
     let git_dir_output = std::process::Command::new("git")
         .args(["rev-parse", "--git-dir"])
@@ -4488,19 +5514,24 @@ fn run_hook_install(
     fs::create_dir_all(&hooks_dir).map_err(error::DecapodError::IoError)?;
 
     if uninstall {
-        let commit_msg_path = hooks_dir.join("commit-msg");
-        let pre_commit_path = hooks_dir.join("pre-commit");
         let mut removed_any = false;
-
-        if commit_msg_path.exists() {
-            fs::remove_file(&commit_msg_path).map_err(error::DecapodError::IoError)?;
-            println!("✓ Removed commit-msg hook");
-            removed_any = true;
-        }
-        if pre_commit_path.exists() {
-            fs::remove_file(&pre_commit_path).map_err(error::DecapodError::IoError)?;
-            println!("✓ Removed pre-commit hook");
+        for hook_name in ["commit-msg", "pre-commit", "pre-push"] {
+            let hook_path = hooks_dir.join(hook_name);
+            if !hook_path.exists() {
+                continue;
+            }
+            if !is_decapod_hook(&hook_path) {
+                println!("! Skipped {} hook (not installed by Decapod)", hook_name);
+                continue;
+            }
+            fs::remove_file(&hook_path).map_err(error::DecapodError::IoError)?;
+            println!("✓ Removed {} hook", hook_name);
             removed_any = true;
+            let backup_path = hooks_dir.join(format!("{}.pre-decapod", hook_name));
+            if backup_path.exists() {
+                fs::rename(&backup_path, &hook_path).map_err(error::DecapodError::IoError)?;
+                println!("✓ Restored original {} hook from backup", hook_name);
+            }
         }
         if !removed_any {
             println!("No hooks found to remove");
@@ -4508,8 +5539,66 @@ fn run_hook_install(
         return Ok(());
     }
 
+    // Refuse before writing anything so a partial install never clobbers a team hook.
+    let requested = [
+        (commit_msg, "commit-msg"),
+        (pre_commit, "pre-commit"),
+        (pre_push, "pre-push"),
+    ];
+    let mut to_back_up = Vec::new();
+    for (_, hook_name) in requested.iter().filter(|(wanted, _)| *wanted) {
+        let hook_path = hooks_dir.join(hook_name);
+        if !hook_path.exists() || is_decapod_hook(&hook_path) {
+            continue;
+        }
+        if !force {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Existing {} hook was not installed by Decapod; re-run with --force to back it up to {}.pre-decapod",
+                hook_name, hook_name
+            )));
+        }
+        let backup_path = hooks_dir.join(format!("{}.pre-decapod", hook_name));
+        if backup_path.exists() {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Backup {} already exists; move it aside before installing over the current {} hook",
+                backup_path.display(),
+                hook_name
+            )));
+        }
+        to_back_up.push((hook_name, hook_path, backup_path));
+    }
+    for (hook_name, hook_path, backup_path) in to_back_up {
+        fs::rename(&hook_path, &backup_path).map_err(error::DecapodError::IoError)?;
+        println!(
+            "✓ Backed up existing {} hook to {}",
+            hook_name,
+            backup_path.display()
+        );
+    }
+
     if commit_msg {
-        let hook_content = r#"#!/bin/sh
+        write_git_hook(&hooks_dir, "commit-msg", COMMIT_MSG_HOOK)?;
+        println!("✓ Installed commit-msg hook for conventional commits");
+    }
+
+    if pre_commit {
+        write_git_hook(&hooks_dir, "pre-commit", PRE_COMMIT_HOOK)?;
+        println!("✓ Installed pre-commit hook (fmt + clippy)");
+    }
+
+    if pre_push {
+        write_git_hook(&hooks_dir, "pre-push", PRE_PUSH_HOOK)?;
+        println!("✓ Installed pre-push hook (decapod validate)");
+    }
+
+    if !commit_msg && !pre_commit && !pre_push {
+        println!("No hooks specified. Use --commit-msg, --pre-commit and/or --pre-push");
+    }
+
+    Ok(())
+}
+
+const COMMIT_MSG_HOOK: &str = r#"#!/bin/sh
 MSG_FILE="$1"
 SUBJECT="$(head -n1 "$MSG_FILE")"
 if printf '%s' "$SUBJECT" | grep -Eq '^(feat|fix|docs|style|refactor|test|chore|ci|build|perf|revert)(\([^)]+\))?: .+'; then
@@ -4519,48 +5608,58 @@ echo "commit-msg hook: expected conventional commit subject"
 echo "got: $SUBJECT"
 exit 1
 "#;
-        let hook_path = hooks_dir.join("commit-msg");
-        let mut file = fs::File::create(&hook_path).map_err(error::DecapodError::IoError)?;
-        file.write_all(hook_content.as_bytes())
-            .map_err(error::DecapodError::IoError)?;
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&hook_path)
-                .map_err(error::DecapodError::IoError)?
-                .permissions();
-            perms.set_mode(0o755);
-            fs::set_permissions(&hook_path, perms).map_err(error::DecapodError::IoError)?;
-        }
-        println!("✓ Installed commit-msg hook for conventional commits");
-    }
 
-    if pre_commit {
-        let hook_content = r#"#!/bin/sh
+const PRE_COMMIT_HOOK: &str = r#"#!/bin/sh
 set -e
 cargo fmt --check
 cargo clippy --all-targets --all-features -- -D warnings
 "#;
-        let hook_path = hooks_dir.join("pre-commit");
-        let mut file = fs::File::create(&hook_path).map_err(error::DecapodError::IoError)?;
-        file.write_all(hook_content.as_bytes())
-            .map_err(error::DecapodError::IoError)?;
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&hook_path)
-                .map_err(error::DecapodError::IoError)?
-                .permissions();
-            perms.set_mode(0o755);
-            fs::set_permissions(&hook_path, perms).map_err(error::DecapodError::IoError)?;
-        }
-        println!("✓ Installed pre-commit hook (fmt + clippy)");
-    }
 
-    if !commit_msg && !pre_commit {
-        println!("No hooks specified. Use --commit-msg and/or --pre-commit");
-    }
+// DECAPOD_VALIDATE_SKIP_GIT_GATES is inherited from the pushing shell,
+// so operators can still bypass git workspace gates explicitly.
+const PRE_PUSH_HOOK: &str = r#"#!/bin/sh
+if ! decapod validate; then
+  echo "pre-push hook: decapod validate failed; push blocked"
+  exit 1
+fi
+exit 0
+"#;
+
+/// Marker line embedded in every generated hook so later runs recognize their own.
+const DECAPOD_HOOK_MARKER: &str = "# decapod-managed-hook";
+
+/// Hooks written before the marker existed are recognized by their exact content.
+fn is_decapod_hook(hook_path: &Path) -> bool {
+    fs::read_to_string(hook_path)
+        .map(|content| {
+            content
+                .lines()
+                .any(|line| line.trim() == DECAPOD_HOOK_MARKER)
+                || [COMMIT_MSG_HOOK, PRE_COMMIT_HOOK, PRE_PUSH_HOOK].contains(&content.as_str())
+        })
+        .unwrap_or(false)
+}
 
+fn write_git_hook(
+    hooks_dir: &Path,
+    hook_name: &str,
+    hook_content: &str,
+) -> Result<(), error::DecapodError> {
+    let hook_path = hooks_dir.join(hook_name);
+    let (shebang, body) = hook_content.split_once('\n').unwrap_or((hook_content, ""));
+    let content = format!("{}\n{}\n{}", shebang, DECAPOD_HOOK_MARKER, body);
+    let mut file = fs::File::create(&hook_path).map_err(error::DecapodError::IoError)?;
+    file.write_all(content.as_bytes())
+        .map_err(error::DecapodError::IoError)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&hook_path)
+            .map_err(error::DecapodError::IoError)?
+            .permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&hook_path, perms).map_err(error::DecapodError::IoError)?;
+    }
     Ok(())
 }
 
@@ -4676,8 +5775,7 @@ fn run_workspace_command(
 
     match cli.command {
         WorkspaceCommand::Ensure { branch, container } => {
-            let agent_id =
-                std::env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+            let agent_id = current_agent_id();
             let config = branch.map(|b| workspace::WorkspaceConfig {
                 branch: b,
                 use_container: container,
@@ -4704,7 +5802,10 @@ fn run_workspace_command(
             );
         }
         WorkspaceCommand::Status => {
-            let status = workspace::get_workspace_status(project_root)?;
+            let status = workspace::with_worktree_usage(
+                workspace::get_workspace_status(project_root)?,
+                project_root,
+            );
 
             println!(
                 "{}",
@@ -4713,15 +5814,36 @@ fn run_workspace_command(
                     "git_branch": status.git.current_branch,
                     "git_is_protected": status.git.is_protected,
                     "git_has_local_mods": status.git.has_local_mods,
+                    "worktree_size_bytes": status.git.worktree_size_bytes,
+                    "worktree_age_secs": status.git.worktree_age_secs,
                     "in_container": status.container.in_container,
                     "container_image": status.container.image,
                     "docker_available": status.container.docker_available,
+                    "container_env_passthrough": status.container.env_passthrough,
                     "blockers": status.blockers.len(),
                     "required_actions": status.required_actions,
                 })
             );
         }
-        WorkspaceCommand::Publish { title, description } => {
+        WorkspaceCommand::List => {
+            let worktrees = workspace::list_workspaces(project_root)?;
+            println!(
+                "{}",
+                serde_json::json!({ "status": "ok", "worktrees": worktrees })
+            );
+        }
+        WorkspaceCommand::Prune { dry_run } => {
+            let report = workspace::prune_workspaces(project_root, dry_run)?;
+            println!("{}", serde_json::json!({ "status": "ok", "prune": report }));
+        }
+        WorkspaceCommand::Publish {
+            title,
+            description,
+            pr,
+            all,
+        } => {
+            // Fail fast on a dirty tree before spending a full validation run.
+            workspace::check_publish_tree(project_root, all)?;
             let project_store = Store {
                 kind: StoreKind::Repo,
                 root: project_root.join(".decapod").join("data"),
@@ -4738,7 +5860,7 @@ fn run_workspace_command(
                     report.fail_count
                 )));
             }
-            let result = workspace::publish_workspace(project_root, title, description)?;
+            let result = workspace::publish_workspace(project_root, title, description, pr, all)?;
             println!(
                 "{}",
                 serde_json::json!({
@@ -4761,7 +5883,12 @@ fn run_state_commit_command(
     project_root: &Path,
 ) -> Result<(), error::DecapodError> {
     match cli.command {
-        StateCommitCommand::Prove { base, head, output } => {
+        StateCommitCommand::Prove {
+            base,
+            head,
+            output,
+            since,
+        } => {
             let head = head.unwrap_or_else(|| {
                 state_commit::run_git(project_root, &["rev-parse", "HEAD"])
                     .unwrap_or_else(|_| "HEAD".to_string())
@@ -4778,10 +5905,22 @@ fn run_state_commit_command(
                 ignore_policy_hash: "da39a3ee5e6b4b0d3255bfef95601890afd80709".to_string(), // empty
             };
 
-            let result = state_commit::prove(&input, project_root)
-                .map_err(error::DecapodError::ValidationError)?;
+            let result = match since {
+                Some(prior_path) => {
+                    let prior_bytes =
+                        std::fs::read(&prior_path).map_err(error::DecapodError::IoError)?;
+                    let prior = state_commit::decode_scope_record(&prior_bytes)
+                        .map_err(error::DecapodError::ValidationError)?;
+                    state_commit::prove_since(&input, project_root, &prior)
+                }
+                None => state_commit::prove(&input, project_root),
+            }
+            .map_err(error::DecapodError::ValidationError)?;
 
             println!("  files: {}", result.entries.len());
+            if result.reused_entries > 0 {
+                println!("  reused: {}", result.reused_entries);
+            }
 
             // Write output
             std::fs::write(&output, &result.scope_record_bytes)
@@ -4796,6 +5935,7 @@ fn run_state_commit_command(
         StateCommitCommand::Verify {
             scope_record,
             expected_root,
+            against_worktree,
         } => {
             // Read scope record
             let cbor_bytes = std::fs::read(&scope_record).map_err(error::DecapodError::IoError)?;
@@ -4821,6 +5961,34 @@ fn run_state_commit_command(
             println!("STATE_COMMIT verification:");
             println!("  scope_record: {}", scope_record.display());
             println!("  scope_record_hash: {}", record_hash);
+
+            if against_worktree {
+                let record = state_commit::decode_scope_record(&cbor_bytes)
+                    .map_err(error::DecapodError::ValidationError)?;
+                let diverged = state_commit::diff_against_worktree(&record, project_root)
+                    .map_err(error::DecapodError::ValidationError)?;
+                println!(
+                    "  worktree: {} of {} entries diverged",
+                    diverged.len(),
+                    record.entries.len()
+                );
+                for d in &diverged {
+                    println!(
+                        "  ❌ {} ({}): recorded {}, current {}",
+                        String::from_utf8_lossy(&d.path),
+                        d.reason,
+                        d.recorded_hash,
+                        d.current_hash.as_deref().unwrap_or("-")
+                    );
+                }
+                if !diverged.is_empty() {
+                    return Err(error::DecapodError::ValidationError(format!(
+                        "STATE_COMMIT worktree mismatch: {} file(s) diverged",
+                        diverged.len()
+                    )));
+                }
+            }
+
             println!("  ✅ VERIFIED");
 
             Ok(())
@@ -4835,28 +6003,50 @@ fn run_state_commit_command(
             hasher.update(&cbor_bytes);
             let scope_record_hash = format!("{:x}", hasher.finalize());
 
-            // Parse basic structure (simplified - looks for embedded strings)
-            let content = String::from_utf8_lossy(&cbor_bytes);
+            let record = state_commit::decode_scope_record(&cbor_bytes)
+                .map_err(error::DecapodError::ValidationError)?;
 
             println!("STATE_COMMIT Explanation:");
             println!("  File: {}", scope_record.display());
             println!("  Size: {} bytes", cbor_bytes.len());
             println!("  scope_record_hash: {}", scope_record_hash);
+            println!(
+                "  state_commit_root: {}",
+                state_commit::compute_merkle_root(&record.entries)
+            );
+            println!("  algo_version: {}", record.algo_version);
+            println!("  base: {}", record.base_sha);
+            println!("  head: {}", record.head_sha);
+            println!("  record_version: {}", record.record_version);
+            println!("  ignore_policy_hash: {}", record.ignore_policy_hash);
+            println!("  entries: {}", record.entries.len());
             println!();
 
-            // Try to extract version and SHAs from the CBOR structure
-            if let Some(version_pos) = content.find("state_commit.")
-                && let Some(end_pos) = content[version_pos..].find('\0')
-            {
+            let path_width = record
+                .entries
+                .iter()
+                .map(|e| e.display_path().chars().count())
+                .max()
+                .unwrap_or(0)
+                .max("PATH".len());
+            println!(
+                "  {:<path_width$}  {:<7}  {:<4}  {:<64}  SIZE",
+                "PATH", "KIND", "EXEC", "HASH"
+            );
+            for e in &record.entries {
+                let kind = if e.kind == 1 { "symlink" } else { "file" };
+                let path = e.display_path();
+                let pad = path_width - path.chars().count();
                 println!(
-                    "  algo_version: {}",
-                    &content[version_pos..version_pos + end_pos]
+                    "  {}{}  {:<7}  {:<4}  {:<64}  {}",
+                    path,
+                    " ".repeat(pad),
+                    kind,
+                    if e.mode_exec { "yes" } else { "no" },
+                    e.content_hash,
+                    e.size
                 );
             }
-
-            // Count entries (looking for patterns in the binary data)
-            let entry_count = content.matches("kind=").count();
-            println!("  Estimated entries: {}", entry_count);
             println!();
 
             println!("Note: scope_record_hash is sha256(scope_record_bytes)");
@@ -4887,6 +6077,11 @@ mod rpc_handlers {
     use crate::core::standards;
     use crate::core::workspace;
 
+    /// `context.resolve` fragment count when the request gives no `limit`.
+    const CONTEXT_RESOLVE_DEFAULT_LIMIT: usize = 5;
+    /// Upper bound on `limit`, so one request cannot pull the whole constitution.
+    const CONTEXT_RESOLVE_MAX_LIMIT: usize = 20;
+
     pub(crate) fn handle_agent_init(ctx: &RpcCtx) -> Result<RpcResponse, error::DecapodError> {
         let workspace_status = workspace::get_workspace_status(ctx.project_root)?;
         let mut allowed_ops = workspace::get_allowed_ops(&workspace_status);
@@ -4992,20 +6187,68 @@ mod rpc_handlers {
             allowed_ops,
             ctx.mandates.clone(),
         );
-        response.result = Some(serde_json::json!({
-            "git_branch": status.git.current_branch,
-            "git_is_protected": status.git.is_protected,
-            "in_container": status.container.in_container,
-            "can_work": status.can_work,
-        }));
-        response.blocked_by = blocked_by;
+        response.result = Some(serde_json::json!({
+            "git_branch": status.git.current_branch,
+            "git_is_protected": status.git.is_protected,
+            "in_container": status.container.in_container,
+            "can_work": status.can_work,
+        }));
+        response.blocked_by = blocked_by;
+        Ok(response)
+    }
+
+    pub(crate) fn handle_workspace_list(ctx: &RpcCtx) -> Result<RpcResponse, error::DecapodError> {
+        let worktrees = workspace::list_workspaces(ctx.project_root)?;
+        let status = workspace::get_workspace_status(ctx.project_root)?;
+
+        let mut response = success_response(
+            ctx.request.id.clone(),
+            ctx.request.op.clone(),
+            ctx.request.params.clone(),
+            None,
+            vec![],
+            None,
+            workspace::get_allowed_ops(&status),
+            ctx.mandates.clone(),
+        );
+        response.result = Some(serde_json::json!({ "worktrees": worktrees }));
+        Ok(response)
+    }
+
+    pub(crate) fn handle_workspace_prune(ctx: &RpcCtx) -> Result<RpcResponse, error::DecapodError> {
+        let dry_run = ctx
+            .request
+            .params
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let report = workspace::prune_workspaces(ctx.project_root, dry_run)?;
+        let status = workspace::get_workspace_status(ctx.project_root)?;
+        let touched = report
+            .pruned
+            .iter()
+            .filter(|_| !dry_run)
+            .map(|p| p.path.to_string_lossy().to_string())
+            .collect();
+
+        let mut response = success_response(
+            ctx.request.id.clone(),
+            ctx.request.op.clone(),
+            ctx.request.params.clone(),
+            None,
+            touched,
+            None,
+            workspace::get_allowed_ops(&status),
+            ctx.mandates.clone(),
+        );
+        response.result = Some(serde_json::to_value(&report).unwrap_or_default());
         Ok(response)
     }
 
     pub(crate) fn handle_workspace_ensure(
         ctx: &RpcCtx,
     ) -> Result<RpcResponse, error::DecapodError> {
-        let agent_id = std::env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+        let agent_id = current_agent_id();
         let branch = ctx
             .request
             .params
@@ -5056,7 +6299,27 @@ mod rpc_handlers {
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
 
-        let result = workspace::publish_workspace(ctx.project_root, title, description)?;
+        let create_pr = ctx
+            .request
+            .params
+            .get("pr")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let include_all = ctx
+            .request
+            .params
+            .get("all")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let result = workspace::publish_workspace(
+            ctx.project_root,
+            title,
+            description,
+            create_pr,
+            include_all,
+        )?;
 
         Ok(success_response(
             ctx.request.id.clone(),
@@ -5085,7 +6348,11 @@ mod rpc_handlers {
         let touched_paths = params.get("touched_paths").and_then(|v| v.as_array());
         let intent_tags = params.get("intent_tags").and_then(|v| v.as_array());
         let query = params.get("query").and_then(|v| v.as_str());
-        let limit = params.get("limit").and_then(|v| v.as_u64()).unwrap_or(5) as usize;
+        let limit = params
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .map(|l| (l as usize).clamp(1, CONTEXT_RESOLVE_MAX_LIMIT))
+            .unwrap_or(CONTEXT_RESOLVE_DEFAULT_LIMIT);
 
         let mut fragments = Vec::new();
         let bindings = docs::get_bindings(ctx.project_root);
@@ -5103,14 +6370,10 @@ mod rpc_handlers {
 
         if let Some(paths) = touched_paths {
             for p in paths.iter().filter_map(|v| v.as_str()) {
-                for (prefix, doc_ref) in &bindings.paths {
-                    if p.contains(prefix) {
-                        let parts: Vec<&str> = doc_ref.split('#').collect();
-                        let path = parts[0];
-                        let anchor = parts.get(1).copied();
-                        if let Some(f) = docs::get_fragment(ctx.project_root, path, anchor) {
-                            fragments.push(f);
-                        }
+                if let Some(doc_ref) = docs::match_path_binding(&bindings.paths, p) {
+                    let (path, anchor) = docs::split_doc_ref(doc_ref);
+                    if let Some(f) = docs::get_fragment(ctx.project_root, path, anchor) {
+                        fragments.push(f);
                     }
                 }
             }
@@ -5153,10 +6416,12 @@ mod rpc_handlers {
             &tags_vec,
             limit,
         );
+        // Direct bindings first (ref order), then scoped matches in rank order;
+        // dedup keeps the first occurrence so the top-N is stable across calls.
         fragments.extend(scoped_fragments.clone());
-        fragments.sort_by(|a, b| a.r#ref.cmp(&b.r#ref));
-        fragments.dedup_by(|a, b| a.r#ref == b.r#ref);
-        fragments.truncate(limit.max(1));
+        let mut seen = std::collections::HashSet::new();
+        fragments.retain(|f| seen.insert(f.r#ref.clone()));
+        fragments.truncate(limit);
 
         let local_specs = core::project_specs::local_project_specs_context(ctx.project_root);
         let canonical_paths = local_specs.canonical_paths.clone();
@@ -5325,6 +6590,40 @@ mod rpc_handlers {
         ))
     }
 
+    /// Converts a subsystem `schema()["fields"]` map into a JSON Schema object,
+    /// carrying each field's `description` and allowed-values `enum`.
+    fn json_schema_from_fields(fields: &serde_json::Value) -> serde_json::Value {
+        let mut properties = serde_json::Map::new();
+        let mut required = Vec::new();
+        if let Some(map) = fields.as_object() {
+            for (name, spec) in map {
+                let mut prop = serde_json::Map::new();
+                let ty = spec
+                    .get("type")
+                    .cloned()
+                    .unwrap_or(serde_json::json!("string"));
+                if ty == "array" {
+                    prop.insert("items".to_string(), serde_json::json!({ "type": "string" }));
+                }
+                prop.insert("type".to_string(), ty);
+                for key in ["description", "enum"] {
+                    if let Some(v) = spec.get(key) {
+                        prop.insert(key.to_string(), v.clone());
+                    }
+                }
+                properties.insert(name.clone(), serde_json::Value::Object(prop));
+                if spec.get("required").and_then(|v| v.as_bool()) == Some(true) {
+                    required.push(serde_json::Value::String(name.clone()));
+                }
+            }
+        }
+        serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "required": required
+        })
+    }
+
     pub(crate) fn handle_schema_get(ctx: &RpcCtx) -> Result<RpcResponse, error::DecapodError> {
         let entity = ctx.request.params.get("entity").and_then(|v| v.as_str());
         match entity {
@@ -5334,16 +6633,7 @@ mod rpc_handlers {
                 ctx.request.params.clone(),
                 Some(serde_json::json!({
                     "schema_version": "v1",
-                    "json_schema": {
-                        "type": "object",
-                        "properties": {
-                            "title": { "type": "string" },
-                            "description": { "type": "string" },
-                            "priority": { "type": "string", "enum": ["low", "medium", "high", "critical"] },
-                            "tags": { "type": "string" }
-                        },
-                        "required": ["title"]
-                    }
+                    "json_schema": json_schema_from_fields(&todo::schema()["fields"])
                 })),
                 vec![],
                 None,
@@ -5356,16 +6646,7 @@ mod rpc_handlers {
                 ctx.request.params.clone(),
                 Some(serde_json::json!({
                     "schema_version": "v1",
-                    "json_schema": {
-                        "type": "object",
-                        "properties": {
-                            "id": { "type": "string" },
-                            "title": { "type": "string" },
-                            "text": { "type": "string" },
-                            "provenance": { "type": "string" }
-                        },
-                        "required": ["id", "title", "text", "provenance"]
-                    }
+                    "json_schema": json_schema_from_fields(&knowledge::schema()["fields"])
                 })),
                 vec![],
                 None,
@@ -5482,6 +6763,9 @@ mod rpc_handlers {
                     .and_then(|v| v.as_str())
                     .unwrap_or("")
                     .to_string();
+                let namespace = payload
+                    .and_then(|p| p.get("namespace"))
+                    .and_then(|v| v.as_str());
 
                 db::initialize_knowledge_db(&ctx.store.root)?;
                 let result = knowledge::add_knowledge(
@@ -5497,6 +6781,7 @@ mod rpc_handlers {
                         status: "active",
                         ttl_policy: "persistent",
                         expires_ts: None,
+                        namespace,
                     },
                 )?;
                 Ok(success_response(
@@ -5566,6 +6851,143 @@ mod rpc_handlers {
         }
     }
 
+    /// Map a federation validation/lookup failure to an RPC error instead of a process error.
+    fn federation_error(
+        ctx: &RpcCtx,
+        err: error::DecapodError,
+    ) -> Result<RpcResponse, error::DecapodError> {
+        let code = match &err {
+            error::DecapodError::ValidationError(_) => "invalid_params",
+            error::DecapodError::NotFound(_) => "not_found",
+            _ => return Err(err),
+        };
+        Ok(error_response(
+            ctx.request.id.clone(),
+            ctx.request.op.clone(),
+            ctx.request.params.clone(),
+            code.to_string(),
+            err.to_string(),
+            None,
+            ctx.mandates.clone(),
+        ))
+    }
+
+    pub(crate) fn handle_federation_add_node(
+        ctx: &RpcCtx,
+    ) -> Result<RpcResponse, error::DecapodError> {
+        let params = &ctx.request.params;
+        let str_param = |key: &str| params.get(key).and_then(|v| v.as_str());
+        let (Some(title), Some(kind)) = (str_param("title"), str_param("kind")) else {
+            return federation_error(
+                ctx,
+                error::DecapodError::ValidationError(
+                    "federation.add_node requires string params 'title' and 'kind'".to_string(),
+                ),
+            );
+        };
+        // `source` and `tags` accept either a comma-separated string or an array of strings.
+        let list_param = |key: &str| match params.get(key) {
+            Some(serde_json::Value::Array(items)) => items
+                .iter()
+                .filter_map(|v| v.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+            Some(v) => v.as_str().unwrap_or("").to_string(),
+            None => String::new(),
+        };
+
+        federation::initialize_federation_db(&ctx.store.root)?;
+        let node = match federation::add_node(
+            ctx.store,
+            title,
+            kind,
+            str_param("salience").unwrap_or("notable"),
+            str_param("origin").unwrap_or("agent_inferred"),
+            str_param("content").unwrap_or(""),
+            &list_param("source"),
+            &list_param("tags"),
+            str_param("scope").unwrap_or("repo"),
+            str_param("effective_from"),
+            &current_agent_id(),
+        ) {
+            Ok(node) => node,
+            Err(e) => return federation_error(ctx, e),
+        };
+        federation::refresh_derived_files(ctx.store)?;
+
+        Ok(success_response(
+            ctx.request.id.clone(),
+            ctx.request.op.clone(),
+            ctx.request.params.clone(),
+            Some(serde_json::json!({ "node": node })),
+            vec![],
+            None,
+            vec![],
+            ctx.mandates.clone(),
+        ))
+    }
+
+    pub(crate) fn handle_federation_add_edge(
+        ctx: &RpcCtx,
+    ) -> Result<RpcResponse, error::DecapodError> {
+        let params = &ctx.request.params;
+        let str_param = |key: &str| params.get(key).and_then(|v| v.as_str());
+        let (Some(from), Some(to)) = (str_param("from"), str_param("to")) else {
+            return federation_error(
+                ctx,
+                error::DecapodError::ValidationError(
+                    "federation.add_edge requires string params 'from' and 'to'".to_string(),
+                ),
+            );
+        };
+        let edge_type = str_param("type").unwrap_or("relates_to");
+        let confidence = match params.get("confidence") {
+            None => 1.0,
+            Some(v) => match v.as_f64() {
+                Some(c) => c,
+                None => {
+                    return federation_error(
+                        ctx,
+                        error::DecapodError::ValidationError(
+                            "federation.add_edge param 'confidence' must be a number".to_string(),
+                        ),
+                    );
+                }
+            },
+        };
+
+        federation::initialize_federation_db(&ctx.store.root)?;
+        let edge_id = match federation::add_weighted_edge(
+            ctx.store,
+            from,
+            to,
+            edge_type,
+            confidence,
+            str_param("rationale"),
+        ) {
+            Ok(id) => id,
+            Err(e) => return federation_error(ctx, e),
+        };
+        federation::refresh_derived_files(ctx.store)?;
+
+        Ok(success_response(
+            ctx.request.id.clone(),
+            ctx.request.op.clone(),
+            ctx.request.params.clone(),
+            Some(serde_json::json!({
+                "edge_id": edge_id,
+                "from": from,
+                "to": to,
+                "type": edge_type,
+                "confidence": confidence,
+            })),
+            vec![],
+            None,
+            vec![],
+            ctx.mandates.clone(),
+        ))
+    }
+
     pub(crate) fn handle_store_query(ctx: &RpcCtx) -> Result<RpcResponse, error::DecapodError> {
         let params = &ctx.request.params;
         let entity = params.get("entity").and_then(|v| v.as_str());
@@ -5602,6 +7024,7 @@ mod rpc_handlers {
                         as_of: None,
                         window_days: None,
                         rank: "relevance",
+                        namespace: None,
                     },
                 )?;
                 Ok(success_response(
@@ -5646,6 +7069,78 @@ mod rpc_handlers {
         }
     }
 
+    pub(crate) fn handle_knowledge_search(
+        ctx: &RpcCtx,
+    ) -> Result<RpcResponse, error::DecapodError> {
+        let params = &ctx.request.params;
+        let invalid = |message: String| {
+            Ok(error_response(
+                ctx.request.id.clone(),
+                ctx.request.op.clone(),
+                ctx.request.params.clone(),
+                "invalid_params".to_string(),
+                message,
+                None,
+                ctx.mandates.clone(),
+            ))
+        };
+
+        let Some(query) = params.get("query").and_then(|v| v.as_str()) else {
+            return invalid("knowledge.search requires string param 'query'".to_string());
+        };
+        let rank = params
+            .get("rank")
+            .and_then(|v| v.as_str())
+            .unwrap_or("relevance");
+        let rank = match knowledge::parse_rank_mode(rank) {
+            Ok(rank) => rank,
+            Err(e) => return invalid(e.to_string()),
+        };
+        let as_of = params.get("as_of").and_then(|v| v.as_str());
+        let window_days = match params.get("window_days") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(v) => match v.as_u64().and_then(|d| u32::try_from(d).ok()) {
+                Some(days) => Some(days),
+                None => {
+                    return invalid("'window_days' must be a non-negative integer".to_string());
+                }
+            },
+        };
+
+        let namespace = params.get("namespace").and_then(|v| v.as_str());
+        if let Some(namespace) = namespace
+            && let Err(e) = knowledge::normalize_namespace(namespace)
+        {
+            return invalid(e.to_string());
+        }
+
+        db::initialize_knowledge_db(&ctx.store.root)?;
+        let entries = knowledge::search_knowledge(
+            ctx.store,
+            query,
+            knowledge::SearchOptions {
+                as_of,
+                window_days,
+                rank,
+                namespace,
+            },
+        )?;
+        Ok(success_response(
+            ctx.request.id.clone(),
+            ctx.request.op.clone(),
+            ctx.request.params.clone(),
+            Some(serde_json::json!({
+                "rank": rank,
+                "count": entries.len(),
+                "items": entries
+            })),
+            vec![],
+            None,
+            vec![],
+            ctx.mandates.clone(),
+        ))
+    }
+
     pub(crate) fn handle_validate_run(ctx: &RpcCtx) -> Result<RpcResponse, error::DecapodError> {
         let project_store = Store {
             kind: StoreKind::Repo,
@@ -5833,11 +7328,26 @@ mod rpc_handlers {
             standards: Some(standards_map),
         };
 
+        let explain = ctx
+            .request
+            .params
+            .get("explain")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let mut result = serde_json::json!({
+            "precedence": ["embedded", "repo", "branch"],
+            "layers": resolved.layers,
+        });
+        if explain {
+            let sources: std::collections::BTreeMap<_, _> = resolved.sources.iter().collect();
+            result["sources"] = serde_json::json!(sources);
+        }
+
         Ok(success_response(
             ctx.request.id.clone(),
             ctx.request.op.clone(),
             ctx.request.params.clone(),
-            None,
+            Some(result),
             vec![],
             Some(context_capsule),
             vec![],
@@ -6071,6 +7581,7 @@ fn run_rpc_command(cli: RpcCli, project_root: &Path) -> Result<(), error::Decapo
         }
     };
 
+    enforce_agent_identity_for_rpc(&request.op)?;
     enforce_worktree_requirement_for_rpc(&request.op, project_root)?;
 
     if !rpc_op_bypasses_session(&request.op) {
@@ -6084,33 +7595,51 @@ fn run_rpc_command(cli: RpcCli, project_root: &Path) -> Result<(), error::Decapo
     };
 
     let mandates = docs::resolve_mandates(project_root, &request.op);
-    let mandate_blockers = if rpc_op_skips_mandate_enforcement(&request.op) {
+    let violations = if rpc_op_skips_mandate_enforcement(&request.op) {
         Vec::new()
     } else {
         validate::evaluate_mandates(project_root, &project_store, &mandates)
     };
 
-    // If any mandate is blocked, we fail the operation
-    let blocked_mandate = mandates.iter().find(|m| {
-        mandate_blockers
-            .iter()
-            .any(|b| b.message.contains(&m.fragment.title))
-    });
-
-    if let Some(mandate) = blocked_mandate {
-        let blocker = mandate_blockers
-            .iter()
-            .find(|b| b.message.contains(&mandate.fragment.title))
-            .unwrap();
-        let response = error_response(
+    // Any blocked mandate fails the operation. All of them are reported so the
+    // agent can fix everything in one pass; the first is the primary blocker.
+    if let Some(primary) = violations.first() {
+        let message = if violations.len() > 1 {
+            format!(
+                "{} (+{} more blocked mandate(s))",
+                primary.blocker.message,
+                violations.len() - 1
+            )
+        } else {
+            primary.blocker.message.clone()
+        };
+        let mut response = error_response(
             request.id.clone(),
             request.op.clone(),
             request.params.clone(),
             "mandate_violation".to_string(),
-            blocker.message.clone(),
-            Some(blocker.clone()),
+            message,
+            Some(primary.blocker.clone()),
             mandates,
         );
+        response.blocked_by = violations.iter().map(|v| v.blocker.clone()).collect();
+        if let Some(error) = response.error.as_mut() {
+            error.details = Some(serde_json::json!({
+                "primary": primary.mandate_id,
+                "blocked_mandates": violations
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| serde_json::json!({
+                        "id": v.mandate_id,
+                        "title": v.title,
+                        "severity": v.severity,
+                        "message": v.blocker.message,
+                        "remediation": v.blocker.resolve_hint,
+                        "primary": i == 0,
+                    }))
+                    .collect::<Vec<_>>(),
+            }));
+        }
         println!("{}", serde_json::to_string_pretty(&response).unwrap());
         return Ok(());
     }
@@ -6122,10 +7651,13 @@ fn run_rpc_command(cli: RpcCli, project_root: &Path) -> Result<(), error::Decapo
         mandates: mandates.clone(),
     };
 
+    let dispatch_started = std::time::Instant::now();
     let response = match request.op.as_str() {
         "agent.init" => rpc_handlers::handle_agent_init(&rpc_ctx)?,
         "workspace.status" => rpc_handlers::handle_workspace_status(&rpc_ctx)?,
         "workspace.ensure" => rpc_handlers::handle_workspace_ensure(&rpc_ctx)?,
+        "workspace.list" => rpc_handlers::handle_workspace_list(&rpc_ctx)?,
+        "workspace.prune" => rpc_handlers::handle_workspace_prune(&rpc_ctx)?,
         "workspace.publish" => rpc_handlers::handle_workspace_publish(&rpc_ctx)?,
         "context.resolve" | "context.scope" => rpc_handlers::handle_context_resolve(&rpc_ctx)?,
         "context.capsule.query" => rpc_handlers::handle_context_capsule_query(&rpc_ctx)?,
@@ -6133,6 +7665,9 @@ fn run_rpc_command(cli: RpcCli, project_root: &Path) -> Result<(), error::Decapo
         "schema.get" => rpc_handlers::handle_schema_get(&rpc_ctx)?,
         "store.upsert" => rpc_handlers::handle_store_upsert(&rpc_ctx)?,
         "store.query" => rpc_handlers::handle_store_query(&rpc_ctx)?,
+        "federation.add_node" => rpc_handlers::handle_federation_add_node(&rpc_ctx)?,
+        "federation.add_edge" => rpc_handlers::handle_federation_add_edge(&rpc_ctx)?,
+        "knowledge.search" => rpc_handlers::handle_knowledge_search(&rpc_ctx)?,
         "validate.run" => rpc_handlers::handle_validate_run(&rpc_ctx)?,
         "scaffold.next_question" => rpc_handlers::handle_scaffold_next_question(&rpc_ctx)?,
         "scaffold.apply_answer" => rpc_handlers::handle_scaffold_apply_answer(&rpc_ctx)?,
@@ -6154,13 +7689,17 @@ fn run_rpc_command(cli: RpcCli, project_root: &Path) -> Result<(), error::Decapo
     };
 
     // Trace the RPC call
+    let duration_ms = dispatch_started.elapsed().as_millis() as u64;
+    let response_value = serde_json::to_value(&response).unwrap_or(serde_json::Value::Null);
     let trace_event = trace::TraceEvent {
         trace_id: request.id.clone(),
         ts: crate::core::time::now_epoch_z(),
         actor: current_agent_id(),
         op: request.op.clone(),
         request: serde_json::to_value(&request).unwrap_or(serde_json::Value::Null),
-        response: serde_json::to_value(&response).unwrap_or(serde_json::Value::Null),
+        status: Some(trace::response_status(&response_value).to_string()),
+        response: response_value,
+        duration_ms: Some(duration_ms),
     };
     let _ = trace::append_trace(project_root, trace_event);
 
@@ -6198,6 +7737,36 @@ fn run_capabilities_command(cli: CapabilitiesCli) -> Result<(), error::DecapodEr
 
     let report = generate_capabilities();
 
+    if let Some(name) = cli.check.as_deref() {
+        let found = report.probe(name);
+        if cli.format == "json" {
+            let mut out = serde_json::json!({ "check": name, "found": found.is_some() });
+            if let Some(entry) = &found {
+                out["entry"] = entry.clone();
+            }
+            println!("{}", serde_json::to_string_pretty(&out).unwrap());
+        } else if let Some(entry) = &found {
+            let level = entry
+                .get("stability")
+                .or_else(|| entry.get("status"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown");
+            println!(
+                "{} [{}] ({})",
+                name,
+                level,
+                entry["kind"].as_str().unwrap_or("")
+            );
+        }
+        return match found {
+            Some(_) => Ok(()),
+            None => Err(error::DecapodError::NotFound(format!(
+                "capability not available: {}",
+                name
+            ))),
+        };
+    }
+
     match cli.format.as_str() {
         "json" => {
             println!("{}", serde_json::to_string_pretty(&report).unwrap());
@@ -6213,7 +7782,10 @@ fn run_capabilities_command(cli: CapabilitiesCli) -> Result<(), error::DecapodEr
 
             println!("\nSubsystems:");
             for sub in &report.subsystems {
-                println!("  {} [{}]", sub.name, sub.status);
+                match sub.schema_version {
+                    Some(v) => println!("  {} [{}] schema v{}", sub.name, sub.status, v),
+                    None => println!("  {} [{}]", sub.name, sub.status),
+                }
                 println!("    Ops: {}", sub.ops.join(", "));
             }
 
@@ -6260,6 +7832,185 @@ fn run_capabilities_command(cli: CapabilitiesCli) -> Result<(), error::DecapodEr
     Ok(())
 }
 
+/// Assemble the onboarding bundle from the binary, the constitution, and repo
+/// state. It carries no timestamps, so repeated calls on an unchanged repo are
+/// byte-identical; core docs are bounded excerpts with hashes (full text via
+/// `docs show`) and the doc graph is reduced to counts.
+fn bootstrap_bundle(project_root: &Path) -> Result<serde_json::Value, error::DecapodError> {
+    let mut core_docs = docs_cli::required_core_docs();
+    core_docs.sort();
+    let constitution: Vec<docs::DocFragment> = core_docs
+        .iter()
+        .filter_map(|path| docs::get_fragment(project_root, path, None))
+        .collect();
+
+    let map = repomap::generate_map(project_root);
+    let (doc_count, doc_link_count) = map
+        .doc_graph
+        .as_ref()
+        .map_or((0, 0), |g| (g.nodes.len(), g.edges.len()));
+
+    let status = workspace::get_workspace_status(project_root)?;
+
+    Ok(serde_json::json!({
+        "schema_version": "1.0.0",
+        "constitution": constitution,
+        "capabilities": core::rpc::generate_capabilities(),
+        "repo_map": {
+            "manifests": map.manifests,
+            "entry_points": map.entry_points,
+            "build_hints": map.build_hints,
+            "skill_hints": map.skill_hints,
+            "doc_count": doc_count,
+            "doc_link_count": doc_link_count,
+        },
+        "workspace": {
+            "can_work": status.can_work,
+            "git_branch": status.git.current_branch,
+            "git_is_protected": status.git.is_protected,
+            "git_in_worktree": status.git.in_worktree,
+            "in_container": status.container.in_container,
+            "docker_available": status.container.docker_available,
+            "blockers": status.blockers.len(),
+            "required_actions": status.required_actions,
+        },
+    }))
+}
+
+fn run_bootstrap_command(
+    cli: BootstrapCli,
+    project_root: &Path,
+) -> Result<(), error::DecapodError> {
+    if cli.format != "json" {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Unsupported bootstrap format '{}'; expected json",
+            cli.format
+        )));
+    }
+    let bundle = bootstrap_bundle(project_root)?;
+    println!("{}", serde_json::to_string_pretty(&bundle).unwrap());
+    Ok(())
+}
+
+fn setting_json(
+    spec: &settings::SettingSpec,
+    value: &str,
+    source: settings::SettingSource,
+) -> serde_json::Value {
+    serde_json::json!({
+        "key": spec.key,
+        "value": value,
+        "source": source.as_str(),
+        "default": spec.default,
+        "env": spec.env,
+        "description": spec.description,
+    })
+}
+
+fn run_config_command(cli: ConfigCli, project_root: &Path) -> Result<(), error::DecapodError> {
+    match cli.command {
+        ConfigCommand::Get { key, format } => {
+            let spec = settings::find_spec(&key)?;
+            let (value, source) = settings::effective(project_root, &key)?;
+            if format == "json" {
+                println!("{}", setting_json(spec, &value, source));
+            } else {
+                println!("{}", value);
+            }
+        }
+        ConfigCommand::Set { key, value } => {
+            settings::set(project_root, &key, &value)?;
+            let (effective, source) = settings::effective(project_root, &key)?;
+            println!("{} = {} ({})", key, effective, source.as_str());
+            if source == settings::SettingSource::Env {
+                eprintln!(
+                    "note: {} is overridden by the environment for this shell.",
+                    key
+                );
+            }
+        }
+        ConfigCommand::List { format } => {
+            let mut rows = Vec::new();
+            for spec in settings::SETTINGS {
+                let (value, source) = settings::effective(project_root, spec.key)?;
+                rows.push((spec, value, source));
+            }
+            if format == "json" {
+                let items: Vec<_> = rows
+                    .iter()
+                    .map(|(spec, value, source)| setting_json(spec, value, *source))
+                    .collect();
+                println!(
+                    "{}",
+                    serde_json::json!({
+                        "config": settings::config_path(project_root),
+                        "precedence": ["env", "file", "default"],
+                        "settings": items,
+                    })
+                );
+            } else {
+                for (spec, value, source) in rows {
+                    println!(
+                        "{} = {} ({}; env {})",
+                        spec.key,
+                        value,
+                        source.as_str(),
+                        spec.env.join(", ")
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_migration_command(
+    cli: MigrationCli,
+    project_root: &Path,
+) -> Result<(), error::DecapodError> {
+    let data_root = project_root.join(".decapod").join("data");
+    let MigrationCommand::Backup(backup_cli) = cli.command;
+    match backup_cli.command {
+        MigrationBackupCommand::List { format } => {
+            let backups = migration::list_backups(&data_root)?;
+            if format == "json" {
+                println!("{}", serde_json::json!({ "backups": backups }));
+            } else if backups.is_empty() {
+                println!("No migration backups in {}", data_root.display());
+            } else {
+                for b in backups {
+                    println!(
+                        "{}  {}  decapod {}  todo schema {}  {} bytes  {}",
+                        b.id,
+                        b.created_at,
+                        b.decapod_version,
+                        b.todo_schema_version
+                            .map(|v| v.to_string())
+                            .unwrap_or_else(|| "-".to_string()),
+                        b.size_bytes,
+                        b.reason
+                    );
+                }
+            }
+        }
+        MigrationBackupCommand::Restore { id } => {
+            // Dispatched ahead of `enforce_agent_identity`, so gate it here.
+            require_known_agent("migration backup restore")?;
+            let restore = migration::restore_backup(&data_root, &id)?;
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "cmd": "migration.backup.restore",
+                    "status": "ok",
+                    "restore": restore,
+                }))
+                .unwrap()
+            );
+        }
+    }
+    Ok(())
+}
+
 fn run_trace_command(cli: TraceCli, project_root: &Path) -> Result<(), error::DecapodError> {
     match cli.command {
         TraceCommand::Export { last } => {
@@ -6268,6 +8019,21 @@ fn run_trace_command(cli: TraceCli, project_root: &Path) -> Result<(), error::De
                 println!("{}", t);
             }
         }
+        TraceCommand::Stats { since } => {
+            let since = since
+                .as_deref()
+                .map(|raw| {
+                    crate::core::time::parse_rfc3339_epoch(raw).ok_or_else(|| {
+                        error::DecapodError::ValidationError(format!(
+                            "--since expects an RFC3339 timestamp (e.g. 2026-02-19T10:00:00Z), got '{}'",
+                            raw
+                        ))
+                    })
+                })
+                .transpose()?;
+            let stats = trace::trace_stats(project_root, since)?;
+            println!("{}", serde_json::to_string_pretty(&stats).unwrap());
+        }
     }
     Ok(())
 }
@@ -216,6 +216,26 @@ pub struct Observation {
     pub created_at: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BehaviorObservationInput {
+    pub subject: String,
+    pub pattern: String,
+    pub evidence: Option<String>,
+    pub source: String,
+}
+
+/// A recurring behavior of one subject, aggregated over its observations.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BehaviorPattern {
+    pub subject: String,
+    pub pattern: String,
+    pub recurrence: i64,
+    pub first_seen: String,
+    pub last_seen: String,
+    pub sources: Vec<String>,
+    pub evidence: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Consolidation {
     pub id: String,
@@ -274,6 +294,7 @@ pub fn initialize_aptitude_db(root: &Path) -> Result<(), error::DecapodError> {
         conn.execute(schemas::APTITUDE_DB_SCHEMA_SKILLS, [])?;
         conn.execute(schemas::APTITUDE_DB_SCHEMA_PATTERNS, [])?;
         conn.execute(schemas::APTITUDE_DB_SCHEMA_OBSERVATIONS, [])?;
+        conn.execute(schemas::APTITUDE_DB_SCHEMA_BEHAVIOR_OBSERVATIONS, [])?;
         conn.execute(schemas::APTITUDE_DB_SCHEMA_CONSOLIDATIONS, [])?;
         conn.execute(schemas::APTITUDE_DB_SCHEMA_AGENT_PROMPTS, [])?;
 
@@ -290,6 +311,7 @@ pub fn initialize_aptitude_db(root: &Path) -> Result<(), error::DecapodError> {
         conn.execute(schemas::APTITUDE_DB_SCHEMA_INDEX_SKILL_NAME, [])?;
         conn.execute(schemas::APTITUDE_DB_SCHEMA_INDEX_PATTERN_CATEGORY, [])?;
         conn.execute(schemas::APTITUDE_DB_SCHEMA_INDEX_OBS_PROCESSED, [])?;
+        conn.execute(schemas::APTITUDE_DB_SCHEMA_INDEX_BEHAVIOR_SUBJECT, [])?;
         conn.execute(schemas::APTITUDE_DB_SCHEMA_INDEX_PROMPT_CONTEXT, [])?;
 
         // Insert default patterns
@@ -365,6 +387,77 @@ pub fn add_preference(
     Ok(id)
 }
 
+/// A preference to record together with the items it was consolidated from.
+pub struct ConsolidatedPreference {
+    pub input: PreferenceInput,
+    /// `(source_type, source_id)` of each item folded into the preference.
+    pub sources: Vec<(String, String)>,
+}
+
+/// Upsert every preference and record its consolidations in one transaction,
+/// so a failure part-way leaves none of the batch behind. Returns each
+/// preference's id, in input order.
+pub fn add_consolidated_preferences(
+    store: &Store,
+    batch: &[ConsolidatedPreference],
+    reason: Option<&str>,
+) -> Result<Vec<String>, error::DecapodError> {
+    let broker = DbBroker::new(&store.root);
+    let db_path = aptitude_db_path(&store.root);
+    let now = now_iso();
+
+    broker.with_conn(&db_path, "decapod", None, "aptitude.add", |conn| {
+        let tx =
+            rusqlite::Transaction::new_unchecked(conn, rusqlite::TransactionBehavior::Immediate)?;
+        let mut ids = Vec::new();
+        for item in batch {
+            let input = &item.input;
+            tx.execute(
+                "INSERT INTO preferences(id, category, key, value, context, source, confidence, created_at, updated_at, last_accessed_at, access_count)
+                 VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, NULL, NULL, 0)
+                 ON CONFLICT(category, key) DO UPDATE SET
+                    value = excluded.value,
+                    context = excluded.context,
+                    source = excluded.source,
+                    confidence = excluded.confidence,
+                    updated_at = ?8",
+                params![
+                    crate::core::ulid::new_ulid(),
+                    input.category,
+                    input.key,
+                    input.value,
+                    input.context,
+                    input.source,
+                    input.confidence.unwrap_or(100),
+                    now
+                ],
+            )?;
+            let id: String = tx.query_row(
+                "SELECT id FROM preferences WHERE category = ?1 AND key = ?2",
+                params![input.category, input.key],
+                |row| row.get(0),
+            )?;
+            for (source_type, source_id) in &item.sources {
+                tx.execute(
+                    "INSERT INTO consolidations(id, source_type, source_id, target_type, target_id, reason, created_at)
+                     VALUES(?1, ?2, ?3, 'preference', ?4, ?5, ?6)",
+                    params![
+                        crate::core::ulid::new_ulid(),
+                        source_type,
+                        source_id,
+                        id,
+                        reason,
+                        now
+                    ],
+                )?;
+            }
+            ids.push(id);
+        }
+        tx.commit()?;
+        Ok(ids)
+    })
+}
+
 pub fn get_preference(
     store: &Store,
     category: &str,
@@ -1108,6 +1201,118 @@ pub fn mark_observation_processed(store: &Store, id: &str) -> Result<bool, error
     Ok(updated)
 }
 
+// ============================================================================
+// BEHAVIOR OBSERVATIONS
+// ============================================================================
+
+/// Record that `subject` exhibited `pattern`; repeats of the same pattern
+/// (case-insensitive) accumulate recurrence rather than replacing each other.
+pub fn record_behavior_observation(
+    store: &Store,
+    input: BehaviorObservationInput,
+) -> Result<String, error::DecapodError> {
+    let subject = input.subject.trim();
+    let pattern = input.pattern.trim();
+    if subject.is_empty() || pattern.is_empty() {
+        return Err(error::DecapodError::ValidationError(
+            "subject and pattern must be non-empty".into(),
+        ));
+    }
+
+    let broker = DbBroker::new(&store.root);
+    let db_path = aptitude_db_path(&store.root);
+    let id = crate::core::ulid::new_ulid();
+    let now = now_iso();
+
+    broker.with_conn(
+        &db_path,
+        "decapod",
+        None,
+        "aptitude.behavior.observe",
+        |conn| {
+            conn.execute(
+            "INSERT INTO behavior_observations(id, subject, pattern, evidence, source, created_at)
+             VALUES(?1, ?2, ?3, ?4, ?5, ?6)",
+            params![id, subject, pattern, input.evidence, input.source, now],
+        )?;
+            Ok(())
+        },
+    )?;
+
+    Ok(id)
+}
+
+/// Behavior patterns ranked by recurrence, most recently seen first on ties.
+pub fn list_behavior_patterns(
+    store: &Store,
+    subject: Option<&str>,
+) -> Result<Vec<BehaviorPattern>, error::DecapodError> {
+    let broker = DbBroker::new(&store.root);
+    let db_path = aptitude_db_path(&store.root);
+
+    let rows = broker.with_conn(
+        &db_path,
+        "decapod",
+        None,
+        "aptitude.behavior.patterns",
+        |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT subject, pattern, evidence, source, created_at
+             FROM behavior_observations
+             WHERE ?1 IS NULL OR subject = ?1
+             ORDER BY created_at, id",
+            )?;
+            let rows = stmt.query_map(params![subject], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })?;
+            let mut out = Vec::new();
+            for r in rows {
+                out.push(r?);
+            }
+            Ok(out)
+        },
+    )?;
+
+    let mut grouped: HashMap<(String, String), BehaviorPattern> = HashMap::new();
+    for (subject, pattern, evidence, source, created_at) in rows {
+        let entry = grouped
+            .entry((subject.clone(), pattern.to_lowercase()))
+            .or_insert_with(|| BehaviorPattern {
+                subject,
+                pattern,
+                recurrence: 0,
+                first_seen: created_at.clone(),
+                last_seen: created_at.clone(),
+                sources: Vec::new(),
+                evidence: Vec::new(),
+            });
+        entry.recurrence += 1;
+        entry.last_seen = created_at;
+        if !entry.sources.contains(&source) {
+            entry.sources.push(source);
+        }
+        if let Some(e) = evidence {
+            entry.evidence.push(e);
+        }
+    }
+
+    let mut patterns: Vec<BehaviorPattern> = grouped.into_values().collect();
+    patterns.sort_by(|a, b| {
+        b.recurrence
+            .cmp(&a.recurrence)
+            .then_with(|| b.last_seen.cmp(&a.last_seen))
+            .then_with(|| a.subject.cmp(&b.subject))
+            .then_with(|| a.pattern.cmp(&b.pattern))
+    });
+    Ok(patterns)
+}
+
 // ============================================================================
 // CONSOLIDATION MANAGEMENT
 // ============================================================================
@@ -1374,7 +1579,8 @@ pub fn schema() -> serde_json::Value {
             { "name": "skill delete", "description": "Delete a skill", "parameters": ["name"] },
             { "name": "skill import", "description": "Import SKILL.md into aptitude skill memory and optional deterministic skill card", "parameters": ["path", "write-card?"] },
             { "name": "skill resolve", "description": "Resolve best-matching skills for a query with deterministic ranking", "parameters": ["query", "limit?", "write?"] },
-            { "name": "observe", "description": "Record an observation for pattern matching", "parameters": ["content", "category?"] },
+            { "name": "observe", "description": "Record an observation for pattern matching, or a behavioral pattern with provenance", "parameters": ["content?", "category?", "subject?", "pattern?", "evidence?"] },
+            { "name": "patterns", "description": "List behavioral patterns ranked by recurrence", "parameters": ["subject?", "format?"] },
             { "name": "pending", "description": "List pending observations", "parameters": ["limit?"] },
             { "name": "consolidate", "description": "Analyze and consolidate similar entries", "parameters": ["--dry-run", "--execute"] },
             { "name": "prompt", "description": "Get contextual prompts for agents", "parameters": ["--context", "--format"] },
@@ -1389,6 +1595,7 @@ pub fn schema() -> serde_json::Value {
             "confidence_levels",
             "pattern_matching",
             "observations",
+            "behavior_patterns",
             "consolidation",
             "agent_prompts"
         ]
@@ -1458,14 +1665,32 @@ pub enum AptitudeCommand {
     /// Skill management commands
     #[clap(subcommand)]
     Skill(SkillCommand),
-    /// Record an observation
+    /// Record an observation, or a behavioral pattern with --subject/--pattern
     Observe {
         /// Observation content
-        #[clap(long)]
-        content: String,
+        #[clap(long, required_unless_present = "pattern", conflicts_with = "pattern")]
+        content: Option<String>,
         /// Optional category
         #[clap(long)]
         category: Option<String>,
+        /// Who exhibited the behavior (e.g., user, reviewer name)
+        #[clap(long, requires = "pattern")]
+        subject: Option<String>,
+        /// The behavior observed (e.g., "reviews DB migrations personally")
+        #[clap(long, requires = "subject")]
+        pattern: Option<String>,
+        /// What was seen that supports the pattern
+        #[clap(long, requires = "pattern")]
+        evidence: Option<String>,
+    },
+    /// List behavioral patterns ranked by recurrence
+    Patterns {
+        /// Filter by subject
+        #[clap(long)]
+        subject: Option<String>,
+        /// Output format (text, json)
+        #[clap(long, default_value = "text")]
+        format: String,
     },
     /// List pending observations
     Pending {
@@ -1719,7 +1944,39 @@ pub fn run_aptitude_cli(store: &Store, cli: AptitudeCli) -> Result<(), error::De
                 println!("{}", serde_json::to_string_pretty(&out).unwrap());
             }
         },
-        AptitudeCommand::Observe { content, category } => {
+        AptitudeCommand::Observe {
+            subject: Some(subject),
+            pattern: Some(pattern),
+            evidence,
+            ..
+        } => {
+            let source = crate::core::identity::current_agent_id();
+            let id = record_behavior_observation(
+                store,
+                BehaviorObservationInput {
+                    subject: subject.clone(),
+                    pattern: pattern.clone(),
+                    evidence,
+                    source,
+                },
+            )?;
+            let recurrence = list_behavior_patterns(store, Some(subject.trim()))?
+                .into_iter()
+                .find(|p| p.pattern.eq_ignore_ascii_case(pattern.trim()))
+                .map(|p| p.recurrence)
+                .unwrap_or(1);
+            println!(
+                "✓ Behavior observed: {} → {} (seen {}x, id: {})",
+                subject.trim(),
+                pattern.trim(),
+                recurrence,
+                id
+            );
+        }
+        AptitudeCommand::Observe {
+            content, category, ..
+        } => {
+            let content = content.unwrap_or_default();
             let id = record_observation(store, &content, category.as_deref())?;
 
             // Check for pattern matches
@@ -1739,6 +1996,22 @@ pub fn run_aptitude_cli(store: &Store, cli: AptitudeCli) -> Result<(), error::De
                 println!("✓ Observation recorded (id: {})", id);
             }
         }
+        AptitudeCommand::Patterns { subject, format } => {
+            let patterns = list_behavior_patterns(store, subject.as_deref())?;
+            if format == "json" {
+                println!("{}", serde_json::to_string_pretty(&patterns).unwrap());
+            } else if patterns.is_empty() {
+                println!("No behavioral patterns observed yet.");
+            } else {
+                println!("Behavioral patterns:");
+                for p in patterns {
+                    println!(
+                        "  {} → {} (seen {}x, last: {})",
+                        p.subject, p.pattern, p.recurrence, p.last_seen
+                    );
+                }
+            }
+        }
         AptitudeCommand::Pending { limit } => {
             let observations = list_pending_observations(store, limit)?;
             if observations.is_empty() {
@@ -103,9 +103,11 @@ pub fn verify_archives(store: &Store) -> Result<Vec<String>, error::DecapodError
             continue;
         }
 
-        let content = fs::read_to_string(&full_path).map_err(error::DecapodError::IoError)?;
-        if hash_text(&content) != entry.content_hash {
-            failures.push(format!("Archive {}: Content hash mismatch", entry.id));
+        let bytes = fs::read(&full_path).map_err(error::DecapodError::IoError)?;
+        match String::from_utf8(bytes) {
+            Ok(content) if hash_text(&content) == entry.content_hash => {}
+            Ok(_) => failures.push(format!("Archive {}: Content hash mismatch", entry.id)),
+            Err(_) => failures.push(format!("Archive {}: Content is not valid UTF-8", entry.id)),
         }
 
         // In Epoch 5, summary linkage verification: we check if the archive ID is referenced
@@ -122,6 +124,154 @@ pub fn verify_archives(store: &Store) -> Result<Vec<String>, error::DecapodError
     Ok(failures)
 }
 
+/// A recorded hash that went stale for a known, content-preserving reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveDrift {
+    /// Same digest recorded in another encoding (`sha256:` prefix, uppercase hex).
+    HashEncoding,
+    /// Hash was recorded over the same text with CRLF line endings.
+    LineEndings,
+}
+
+impl ArchiveDrift {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ArchiveDrift::HashEncoding => "hash_encoding",
+            ArchiveDrift::LineEndings => "line_endings",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveRepair {
+    pub id: String,
+    pub drift: ArchiveDrift,
+    pub previous_hash: String,
+    pub content_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveRefusal {
+    pub id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ArchiveRepairReport {
+    pub repaired: Vec<ArchiveRepair>,
+    pub refused: Vec<ArchiveRefusal>,
+}
+
+fn normalize_recorded_hash(recorded: &str) -> String {
+    let trimmed = recorded.trim();
+    trimmed
+        .strip_prefix("sha256:")
+        .unwrap_or(trimmed)
+        .to_ascii_lowercase()
+}
+
+/// Classify why `recorded` no longer matches `content`, if the cause is a
+/// known format change rather than different content.
+pub fn classify_drift(recorded: &str, content: &str) -> Option<ArchiveDrift> {
+    let current = hash_text(content);
+    let recorded = normalize_recorded_hash(recorded);
+    if recorded == current {
+        return Some(ArchiveDrift::HashEncoding);
+    }
+    if !content.contains("\r\n") && recorded == hash_text(&content.replace('\n', "\r\n")) {
+        return Some(ArchiveDrift::LineEndings);
+    }
+    None
+}
+
+fn archive_events_path(root: &Path) -> PathBuf {
+    root.join("archive.events.jsonl")
+}
+
+fn append_repair_event(root: &Path, repair: &ArchiveRepair) -> Result<(), error::DecapodError> {
+    use std::io::Write;
+
+    let event = serde_json::json!({
+        "event_id": crate::core::time::new_event_id(),
+        "ts": crate::core::time::now_epoch_z(),
+        "op": "archive.repair",
+        "actor": crate::core::identity::current_agent_id(),
+        "archive_id": repair.id,
+        "drift": repair.drift,
+        "previous_hash": repair.previous_hash,
+        "content_hash": repair.content_hash,
+    });
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(archive_events_path(root))
+        .map_err(error::DecapodError::IoError)?;
+    writeln!(file, "{}", event).map_err(error::DecapodError::IoError)
+}
+
+/// Re-record content hashes that drifted for a known format reason. Missing,
+/// empty, non-UTF-8, or genuinely different content is refused and left failing.
+pub fn repair_archives(store: &Store) -> Result<ArchiveRepairReport, error::DecapodError> {
+    let mut report = ArchiveRepairReport::default();
+    for entry in list_archives(store)? {
+        let full_path = store.root.join(&entry.path);
+        let refuse = |reason: &str| ArchiveRefusal {
+            id: entry.id.clone(),
+            reason: reason.to_string(),
+        };
+        if !full_path.exists() {
+            report.refused.push(refuse("content missing"));
+            continue;
+        }
+        let bytes = fs::read(&full_path).map_err(error::DecapodError::IoError)?;
+        let Ok(content) = String::from_utf8(bytes) else {
+            report.refused.push(refuse("content is not valid UTF-8"));
+            continue;
+        };
+        let current = hash_text(&content);
+        if current == entry.content_hash {
+            continue;
+        }
+        if content.is_empty() {
+            report
+                .refused
+                .push(refuse("content truncated to zero bytes"));
+            continue;
+        }
+        let Some(drift) = classify_drift(&entry.content_hash, &content) else {
+            report.refused.push(refuse(
+                "content differs from the recorded hash under every known format; possible corruption",
+            ));
+            continue;
+        };
+
+        let broker = DbBroker::new(&store.root);
+        broker.with_conn(
+            &archive_db_path(&store.root),
+            "decapod",
+            None,
+            "archive.repair",
+            |conn| {
+                conn.execute(
+                    "UPDATE archives SET content_hash = ?1 WHERE id = ?2",
+                    params![current, entry.id],
+                )?;
+                Ok(())
+            },
+        )?;
+        let repair = ArchiveRepair {
+            id: entry.id.clone(),
+            drift,
+            previous_hash: entry.content_hash.clone(),
+            content_hash: current,
+        };
+        append_repair_event(&store.root, &repair)?;
+        report.repaired.push(repair);
+    }
+    Ok(report)
+}
+
 pub fn schema() -> serde_json::Value {
     serde_json::json!({
         "name": "archive",
@@ -129,8 +279,11 @@ pub fn schema() -> serde_json::Value {
         "description": "Archive indexing and integrity",
         "commands": [
             { "name": "list", "description": "List all registered archives" },
-            { "name": "verify", "description": "Run integrity scan on all archives" }
+            { "name": "verify", "description": "Run integrity scan on all archives",
+              "parameters": [
+                  {"name": "repair", "required": false, "description": "Re-record hashes that drifted for a known format reason (hash encoding, line endings)"}
+              ] }
         ],
-        "storage": ["archive.db"]
+        "storage": ["archive.db", "archive.events.jsonl"]
     })
 }
@@ -1,8 +1,9 @@
 use crate::core::error;
+use crate::core::settings;
 use crate::core::store::Store;
 use crate::core::time;
 use clap::{Parser, Subcommand, ValueEnum};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
@@ -28,6 +29,7 @@ pub struct ContainerCli {
 }
 
 #[derive(Subcommand, Debug)]
+#[allow(clippy::large_enum_variant)]
 pub enum ContainerCommand {
     /// Execute one command in a fresh container against an isolated git worktree.
     Run {
@@ -68,6 +70,17 @@ pub enum ContainerCommand {
         #[clap(long, default_value_t = false)]
         local_only: bool,
     },
+    /// Print the captured stdout/stderr of a past container run.
+    Logs {
+        /// Run ID reported as `run_id` by `container run`
+        #[clap(long, required_unless_present = "last", conflicts_with = "last")]
+        id: Option<String>,
+        /// Show the most recent run
+        #[clap(long, default_value_t = false)]
+        last: bool,
+        #[clap(long)]
+        repo: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -91,6 +104,59 @@ pub struct RunSummary {
 
 pub(crate) const CONTAINER_DISABLE_MARKER: &str = "DECAPOD_CONTAINER_RUNTIME_DISABLED=true";
 
+/// Per-stream cap for captured run output; the tail is kept.
+const CONTAINER_LOG_STREAM_MAX_BYTES: usize = 128 * 1024;
+
+/// Host env vars never forwarded into a container, whatever the allowlist says.
+const NEVER_FORWARDED_ENV: &[&str] = &["DECAPOD_SESSION_PASSWORD"];
+
+/// `[container]` section of `.decapod/config.toml`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ContainerConfig {
+    /// Host env vars forwarded into runs: exact names, or prefixes ending in `*`
+    #[serde(default)]
+    pub env_allowlist: Vec<String>,
+}
+
+impl ContainerConfig {
+    pub fn load(repo_root: &Path) -> Self {
+        settings::load_section(repo_root, "container")
+    }
+
+    fn allows(&self, name: &str) -> bool {
+        self.env_allowlist
+            .iter()
+            .any(|pattern| match pattern.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => name == pattern,
+            })
+    }
+}
+
+/// Host env vars a run passes with `-e`. A non-empty allowlist forwards only
+/// matching vars; otherwise `inherit_env` forwards everything. Decapod
+/// secrets are dropped in every case.
+pub fn forwarded_env_vars<I>(
+    config: &ContainerConfig,
+    inherit_env: bool,
+    host_env: I,
+) -> BTreeMap<String, String>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    host_env
+        .into_iter()
+        .filter(|(k, _)| !k.starts_with("BASH_FUNC_") && !NEVER_FORWARDED_ENV.contains(&k.as_str()))
+        .filter(|(k, _)| {
+            if config.env_allowlist.is_empty() {
+                inherit_env
+            } else {
+                config.allows(k)
+            }
+        })
+        .collect()
+}
+
 pub(crate) enum ContainerRuntimeOverrideHeal {
     Cleared,
     Unchanged,
@@ -138,12 +204,101 @@ pub fn run_container_cli(store: &Store, cli: ContainerCli) -> Result<(), error::
             inherit_env,
             local_only,
         )?,
+        ContainerCommand::Logs { id, last, repo } => {
+            let repo = resolve_repo_path(repo.as_deref())?;
+            let run_id = match id {
+                Some(id) => id,
+                None if last => latest_container_log_id(&repo)?.ok_or_else(|| {
+                    error::DecapodError::NotFound("No container run logs recorded".to_string())
+                })?,
+                None => unreachable!("clap requires --id or --last"),
+            };
+            print!("{}", read_container_log(&repo, &run_id)?);
+            return Ok(());
+        }
     };
 
     println!("{}", serde_json::to_string_pretty(&summary.value).unwrap());
     Ok(())
 }
 
+fn container_logs_dir(repo: &Path) -> PathBuf {
+    repo.join(".decapod")
+        .join("generated")
+        .join("container_logs")
+}
+
+/// Keep at most the last `max` bytes of `text`, marking what was dropped.
+fn bounded_tail(text: &str, max: usize) -> String {
+    if text.len() <= max {
+        return text.to_string();
+    }
+    let mut start = text.len() - max;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    format!("[... {} bytes truncated ...]\n{}", start, &text[start..])
+}
+
+/// Persist one run's output to `.decapod/generated/container_logs/<run_id>.log`.
+fn write_container_log(
+    repo: &Path,
+    run_id: &str,
+    container_name: &str,
+    exit_code: Option<i32>,
+    stdout: &str,
+    stderr: &str,
+) -> Result<PathBuf, error::DecapodError> {
+    let dir = container_logs_dir(repo);
+    fs::create_dir_all(&dir).map_err(error::DecapodError::IoError)?;
+    let path = dir.join(format!("{}.log", run_id));
+    let exit = exit_code.map_or_else(|| "none".to_string(), |c| c.to_string());
+    let content = format!(
+        "run_id: {}\ncontainer: {}\nexit_code: {}\n--- stdout ---\n{}\n--- stderr ---\n{}\n",
+        run_id,
+        container_name,
+        exit,
+        bounded_tail(stdout, CONTAINER_LOG_STREAM_MAX_BYTES),
+        bounded_tail(stderr, CONTAINER_LOG_STREAM_MAX_BYTES),
+    );
+    fs::write(&path, content).map_err(error::DecapodError::IoError)?;
+    Ok(path)
+}
+
+fn read_container_log(repo: &Path, run_id: &str) -> Result<String, error::DecapodError> {
+    if !crate::core::ulid::is_valid(run_id) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Invalid container run id '{}'",
+            run_id
+        )));
+    }
+    let path = container_logs_dir(repo).join(format!("{}.log", run_id));
+    fs::read_to_string(&path).map_err(|_| {
+        error::DecapodError::NotFound(format!("No log recorded for container run '{}'", run_id))
+    })
+}
+
+/// Run IDs are ULIDs, so the lexically greatest log is the newest.
+fn latest_container_log_id(repo: &Path) -> Result<Option<String>, error::DecapodError> {
+    let dir = container_logs_dir(repo);
+    if !dir.exists() {
+        return Ok(None);
+    }
+    let latest = fs::read_dir(&dir)
+        .map_err(error::DecapodError::IoError)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_suffix(".log"))
+                .filter(|id| crate::core::ulid::is_valid(id))
+                .map(str::to_string)
+        })
+        .max();
+    Ok(latest)
+}
+
 pub fn run_container_for_claim(
     store: &Store,
     agent: &str,
@@ -265,9 +420,18 @@ Clear the disable marker or let Decapod self-heal the override file before retry
         local_only,
     )?;
 
+    let run_id = crate::core::ulid::new_ulid();
     let start = Instant::now();
     let output = execute_container_with_timeout(&docker, &spec.args, timeout_seconds).map_err(
         |exec_err| {
+            let _ = write_container_log(
+                &repo,
+                &run_id,
+                &spec.container_name,
+                None,
+                "",
+                &exec_err.to_string(),
+            );
             let sync_msg =
                 match sync_workspace_branch_to_host_repo(&repo, &workspace.path, &workspace.branch)
                 {
@@ -282,12 +446,20 @@ Clear the disable marker or let Decapod self-heal the override file before retry
                 let _ = cleanup_workspace_clone(&workspace.path);
             }
             error::DecapodError::ValidationError(format!(
-                "container runtime terminated before normal completion: {}\n{}",
-                exec_err, sync_msg
+                "container runtime terminated before normal completion: {}\n{}\nlog: decapod auto container logs --id {}",
+                exec_err, sync_msg, run_id
             ))
         },
     )?;
     let elapsed = start.elapsed().as_secs();
+    let log_path = write_container_log(
+        &repo,
+        &run_id,
+        &spec.container_name,
+        output.status.code(),
+        &String::from_utf8_lossy(&output.stdout),
+        &String::from_utf8_lossy(&output.stderr),
+    )?;
 
     let status = if output.status.success() {
         "ok"
@@ -314,6 +486,8 @@ Clear the disable marker or let Decapod self-heal the override file before retry
     let summary = json!({
         "ts": time::now_epoch_z(),
         "cmd": "container.run",
+        "run_id": run_id,
+        "log_path": log_path,
         "status": status,
         "agent": agent,
         "runtime": docker,
@@ -347,10 +521,11 @@ Clear the disable marker or let Decapod self-heal the override file before retry
         let stdout = String::from_utf8_lossy(&output.stdout);
         let stderr = String::from_utf8_lossy(&output.stderr);
         return Err(error::DecapodError::ValidationError(format!(
-            "Container command failed (exit {:?})\nstdout:\n{}\nstderr:\n{}",
+            "Container command failed (exit {:?})\nstdout:\n{}\nstderr:\n{}\nlog: decapod auto container logs --id {}",
             output.status.code(),
             stdout.trim(),
-            stderr.trim()
+            stderr.trim(),
+            run_id
         )));
     }
     if let Some(err) = cleanup_err {
@@ -614,7 +789,7 @@ fn push_branch_to_origin(repo: &Path, branch: &str) -> Result<(), error::Decapod
     )))
 }
 
-fn command_exists(cmd: &str) -> bool {
+pub(crate) fn command_exists(cmd: &str) -> bool {
     Command::new(cmd)
         .arg("--version")
         .stdout(Stdio::null())
@@ -1112,11 +1287,10 @@ fn build_docker_spec(
         workspace_str.to_string(),
     ];
 
-    if inherit_env {
-        for (k, v) in inherited_env_vars() {
-            args.push("-e".to_string());
-            args.push(format!("{}={}", k, v));
-        }
+    let config = ContainerConfig::load(repo_root);
+    for (k, v) in forwarded_env_vars(&config, inherit_env, std::env::vars()) {
+        args.push("-e".to_string());
+        args.push(format!("{}={}", k, v));
     }
     args.push("-e".to_string());
     args.push("HOME=/tmp/decapod-home".to_string());
@@ -1156,17 +1330,6 @@ fn build_docker_spec(
     })
 }
 
-fn inherited_env_vars() -> BTreeMap<String, String> {
-    let mut vars = BTreeMap::new();
-    for (k, v) in std::env::vars() {
-        if k.starts_with("BASH_FUNC_") {
-            continue;
-        }
-        vars.insert(k, v);
-    }
-    vars
-}
-
 #[allow(clippy::too_many_arguments)]
 fn build_container_script(
     user_cmd: &str,
@@ -1286,8 +1449,10 @@ pub fn schema() -> serde_json::Value {
         "version": "0.2.0",
         "description": "Ephemeral containerized agent execution with isolated local clone workspaces and host-branch foldback",
         "commands": [
-            { "name": "run", "parameters": ["agent", "cmd", "branch", "task_id", "push", "pr", "pr_base", "pr_title", "pr_body", "image_profile", "image", "timeout_seconds", "memory", "cpus", "repo", "keep_worktree", "inherit_env", "local_only"] }
+            { "name": "run", "parameters": ["agent", "cmd", "branch", "task_id", "push", "pr", "pr_base", "pr_title", "pr_body", "image_profile", "image", "timeout_seconds", "memory", "cpus", "repo", "keep_worktree", "inherit_env", "local_only"] },
+            { "name": "logs", "parameters": ["id", "last", "repo"] }
         ],
+        "storage": [".decapod/generated/container_logs/<run_id>.log"],
         "profiles": {
             "debian-slim": "rust:1.91.1",
             "alpine": "local build from .decapod/generated/Dockerfile (alpine + detected project dependencies)"
@@ -1379,6 +1544,112 @@ mod tests {
         assert!(joined.contains("git_safe checkout -B 'ahr/branch' 'master'"));
     }
 
+    #[test]
+    fn container_run_log_is_retrievable_by_id_and_last() {
+        let root = std::env::temp_dir().join(format!(
+            "decapod-container-logs-{}",
+            crate::core::ulid::new_ulid().to_lowercase()
+        ));
+        fs::create_dir_all(&root).expect("mkdir");
+        assert_eq!(latest_container_log_id(&root).expect("no logs"), None);
+
+        let first = crate::core::ulid::new_ulid();
+        write_container_log(&root, &first, "c1", Some(0), "built ok", "").expect("log");
+        std::thread::sleep(Duration::from_millis(2));
+        let second = crate::core::ulid::new_ulid();
+        let noisy = "x".repeat(CONTAINER_LOG_STREAM_MAX_BYTES + 10);
+        let path = write_container_log(&root, &second, "c2", Some(101), &noisy, "error[E0425]")
+            .expect("log");
+        assert_eq!(
+            path,
+            root.join(".decapod/generated/container_logs")
+                .join(format!("{}.log", second))
+        );
+
+        let log = read_container_log(&root, &first).expect("read by id");
+        assert!(log.contains("exit_code: 0"));
+        assert!(log.contains("built ok"));
+
+        assert_eq!(
+            latest_container_log_id(&root).expect("latest"),
+            Some(second.clone())
+        );
+        let log = read_container_log(&root, &second).expect("read last");
+        assert!(log.contains("error[E0425]"));
+        assert!(log.contains("[... 10 bytes truncated ...]"));
+        assert!(log.len() < CONTAINER_LOG_STREAM_MAX_BYTES + 512);
+
+        assert!(read_container_log(&root, "../../etc/passwd").is_err());
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn env_allowlist_forwards_matches_and_never_session_secrets() {
+        let root = std::env::temp_dir().join(format!(
+            "decapod-container-env-{}",
+            crate::core::ulid::new_ulid().to_lowercase()
+        ));
+        fs::create_dir_all(root.join(".decapod")).expect("mkdir");
+        fs::write(
+            root.join(".decapod/config.toml"),
+            "[container]\nenv_allowlist = [\"PATH\", \"HTTPS_PROXY\", \"REGISTRY_*\", \"DECAPOD_SESSION_PASSWORD\"]\n",
+        )
+        .expect("config");
+        let config = ContainerConfig::load(&root);
+        let host = || {
+            [
+                ("HTTPS_PROXY", "http://proxy:3128"),
+                ("REGISTRY_TOKEN", "r-tok"),
+                ("HOME", "/home/agent"),
+                ("DECAPOD_SESSION_PASSWORD", "hunter2"),
+            ]
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+        };
+
+        let forwarded = forwarded_env_vars(&config, true, host());
+        assert_eq!(
+            forwarded.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec!["HTTPS_PROXY", "REGISTRY_TOKEN"]
+        );
+        assert_eq!(forwarded["HTTPS_PROXY"], "http://proxy:3128");
+
+        let inherit_all = forwarded_env_vars(&ContainerConfig::default(), true, host());
+        assert!(inherit_all.contains_key("HOME"));
+        assert!(!inherit_all.contains_key("DECAPOD_SESSION_PASSWORD"));
+        assert!(forwarded_env_vars(&ContainerConfig::default(), false, host()).is_empty());
+
+        // The runner passes allowlisted host vars with `-e` even without --inherit-env.
+        let spec = build_docker_spec(
+            "docker",
+            &root,
+            &root.join("ws"),
+            "alpine:3.20",
+            "agent-a",
+            "true",
+            "agent/a",
+            "master",
+            "2g",
+            "2.0",
+            None,
+            false,
+            true,
+        )
+        .expect("spec");
+        let path = std::env::var("PATH").expect("PATH set");
+        assert!(
+            spec.args
+                .windows(2)
+                .any(|w| w[0] == "-e" && w[1] == format!("PATH={path}"))
+        );
+        assert!(
+            !spec
+                .args
+                .iter()
+                .any(|a| a.starts_with("DECAPOD_SESSION_PASSWORD="))
+        );
+        let _ = fs::remove_dir_all(root);
+    }
+
     #[test]
     fn sanitize_name_normalizes_agent_identifiers() {
         assert_eq!(sanitize_name("Agent_One"), "agent-one");
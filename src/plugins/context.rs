@@ -19,6 +19,45 @@ pub struct ContextConfig {
     pub profiles: HashMap<String, ContextProfile>,
 }
 
+/// What [`ContextManager::pack_and_archive`] produced.
+#[derive(Debug, Clone)]
+pub struct PackOutcome {
+    pub archive_id: String,
+    pub archive_path: PathBuf,
+    /// File rewritten to the summary + restore pointer, if any.
+    pub rewritten: Option<PathBuf>,
+}
+
+/// A current file worth evicting to make room for a restore.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct EvictionCandidate {
+    pub path: String,
+    pub tokens: usize,
+}
+
+/// How far a restore would push the profile past its budget.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RestoreOverflow {
+    pub profile: String,
+    pub current_tokens: usize,
+    pub restore_tokens: usize,
+    pub budget_tokens: usize,
+    pub overflow_tokens: usize,
+    /// Largest current files, in eviction order, that together cover the overflow.
+    pub eviction_candidates: Vec<EvictionCandidate>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RestoreDecision {
+    /// `overflow` is set when the budget was exceeded and `force` overrode it.
+    Restored {
+        content: String,
+        restore_tokens: usize,
+        overflow: Option<RestoreOverflow>,
+    },
+    Blocked(RestoreOverflow),
+}
+
 pub struct ContextManager {
     root: PathBuf,
     config: ContextConfig,
@@ -78,12 +117,16 @@ impl ContextManager {
         Ok(total)
     }
 
+    /// Archive `session_path` under `memory/archive/`. The source is left
+    /// untouched unless `rewrite` names a file (the source itself, or an
+    /// index file) to replace with the summary and a restore pointer.
     pub fn pack_and_archive(
         &self,
         store: &Store,
         session_path: &Path,
         summary: &str,
-    ) -> Result<PathBuf, error::DecapodError> {
+        rewrite: Option<&Path>,
+    ) -> Result<PackOutcome, error::DecapodError> {
         use std::time::{SystemTime, UNIX_EPOCH};
 
         // Validate inputs before attempting operations
@@ -162,37 +205,55 @@ impl ContextManager {
         archive::initialize_archive_db(&self.root)?;
         archive::register_archive(store, &archive_id, &archive_path, &content, summary)?;
 
-        // MOVE-not-TRIM: Replace original with summary + pointer
+        let Some(target) = rewrite else {
+            return Ok(PackOutcome {
+                archive_id,
+                archive_path,
+                rewritten: None,
+            });
+        };
+
+        // MOVE-not-TRIM: Replace the referencing file with summary + pointer
         let pointer_content = format!(
             "
 [Archived session: {}]
 Summary: {}
 Archive ID: {}
+Restore: decapod data context restore --id {}
 ",
             archive_path.display(),
             summary,
+            archive_id,
             archive_id
         );
 
-        if let Err(e) = fs::write(session_path, pointer_content) {
-            // Archive was created but original file update failed
+        if let Err(e) = fs::write(target, pointer_content) {
+            // Archive was created but the referencing file update failed
             return Err(error::DecapodError::ContextPackError(format!(
-                "Archive created at '{}' but failed to update original file '{}': {}. Manual cleanup required.",
+                "Archive created at '{}' but failed to update '{}': {}. Manual cleanup required.",
                 archive_path.display(),
-                session_path.display(),
+                target.display(),
                 e
             )));
         }
 
-        Ok(archive_path)
+        Ok(PackOutcome {
+            archive_id,
+            archive_path,
+            rewritten: Some(target.to_path_buf()),
+        })
     }
 
+    /// Restore archived content if it fits the profile budget. Over budget,
+    /// returns [`RestoreDecision::Blocked`] with the overflow detail unless
+    /// `force` is set, in which case the content is restored anyway.
     pub fn restore_archive(
         &self,
         archive_id: &str,
         profile_name: &str,
         current_files: &[PathBuf],
-    ) -> Result<String, error::DecapodError> {
+        force: bool,
+    ) -> Result<RestoreDecision, error::DecapodError> {
         let profile = self.get_profile(profile_name).ok_or_else(|| {
             error::DecapodError::ValidationError(format!("Profile '{}' not found", profile_name))
         })?;
@@ -212,26 +273,48 @@ Archive ID: {}
         let archived_content =
             fs::read_to_string(full_path).map_err(error::DecapodError::IoError)?;
 
-        let current_tokens = self.audit_session(current_files)?;
-        let added_tokens = self.estimate_tokens(&archived_content);
-
-        if current_tokens + added_tokens > profile.budget_tokens {
-            println!(
-                "⚠ RESTORE BLOCKED: budget of {} would be exceeded (total: {})",
-                profile.budget_tokens,
-                current_tokens + added_tokens
-            );
-            return Err(error::DecapodError::ValidationError(format!(
-                "Restore blocked: budget exceeded ({} + {} > {})",
-                current_tokens, added_tokens, profile.budget_tokens
-            )));
+        let mut sizes = Vec::new();
+        for path in current_files {
+            if path.exists() {
+                let content = fs::read_to_string(path).map_err(error::DecapodError::IoError)?;
+                sizes.push((path.display().to_string(), self.estimate_tokens(&content)));
+            }
         }
+        let current_tokens: usize = sizes.iter().map(|(_, tokens)| tokens).sum();
+        let restore_tokens = self.estimate_tokens(&archived_content);
 
-        println!(
-            "✓ Restore approved within '{}' budget ({} tokens added)",
-            profile_name, added_tokens
-        );
-        Ok(archived_content)
+        let overflow = (current_tokens + restore_tokens > profile.budget_tokens).then(|| {
+            let overflow_tokens = current_tokens + restore_tokens - profile.budget_tokens;
+            // Largest files first, just enough of them to make room.
+            sizes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            let mut freed = 0;
+            let eviction_candidates = sizes
+                .into_iter()
+                .take_while(|(_, tokens)| {
+                    let needed = freed < overflow_tokens;
+                    freed += tokens;
+                    needed
+                })
+                .map(|(path, tokens)| EvictionCandidate { path, tokens })
+                .collect();
+            RestoreOverflow {
+                profile: profile_name.to_string(),
+                current_tokens,
+                restore_tokens,
+                budget_tokens: profile.budget_tokens,
+                overflow_tokens,
+                eviction_candidates,
+            }
+        });
+
+        match overflow {
+            Some(overflow) if !force => Ok(RestoreDecision::Blocked(overflow)),
+            overflow => Ok(RestoreDecision::Restored {
+                content: archived_content,
+                restore_tokens,
+                overflow,
+            }),
+        }
     }
 
     pub fn get_profile(&self, name: &str) -> Option<&ContextProfile> {
@@ -246,9 +329,17 @@ pub fn schema() -> serde_json::Value {
         "description": "Agent context and token budget management",
         "commands": [
             { "name": "audit", "parameters": ["profile", "files"] },
-            { "name": "pack", "parameters": ["path", "summary"] },
-            { "name": "restore", "parameters": ["archive_id", "profile"] }
+            { "name": "pack", "parameters": ["path", "summary", "in_place", "index"] },
+            { "name": "restore", "parameters": ["archive_id", "profile", "current_files", "force"] }
         ],
+        "fields": {
+            "profile": { "type": "string", "required": true, "description": "Named budget profile from CONTEXT.json" },
+            "budget_tokens": { "type": "integer", "description": "Token ceiling for the profile; audits above it report OVER BUDGET" },
+            "required_files": { "type": "array", "description": "Files always loaded for the profile" },
+            "optional_files": { "type": "array", "description": "Files loaded only while budget remains" },
+            "summary": { "type": "string", "description": "Summary kept in place of archived content when packing" },
+            "in_place": { "type": "boolean", "description": "Rewrite the packed file (or `index`) to the summary plus a restore pointer; off by default" }
+        },
         "storage": ["CONTEXT.json", "memory/archive/"]
     })
 }
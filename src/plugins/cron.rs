@@ -4,7 +4,7 @@ use crate::core::schemas;
 use crate::core::store::Store;
 use crate::core::todo;
 use clap::{Parser, Subcommand};
-use rusqlite::{Result as SqlResult, types::ToSql};
+use rusqlite::{OptionalExtension, Result as SqlResult, types::ToSql};
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs;
@@ -19,13 +19,50 @@ pub fn initialize_cron_db(root: &Path) -> Result<(), error::DecapodError> {
     let broker = DbBroker::new(root);
     let db_path = cron_db_path(root);
     broker.with_conn(&db_path, "decapod", None, "cron.init", |conn| {
-        conn.execute(schemas::CRON_DB_SCHEMA, [])
-            .map_err(error::DecapodError::RusqliteError)?;
-        Ok(())
+        ensure_cron_tables(conn).map_err(error::DecapodError::RusqliteError)
     })?;
     Ok(())
 }
 
+fn ensure_cron_tables(conn: &rusqlite::Connection) -> SqlResult<()> {
+    conn.execute(schemas::CRON_DB_SCHEMA, [])?;
+    conn.execute(schemas::CRON_DB_SCHEMA_EXECUTIONS, [])?;
+    conn.execute(schemas::CRON_DB_INDEX_EXECUTIONS_JOB, [])?;
+    ensure_cron_job_columns(conn)?;
+    Ok(())
+}
+
+/// Add the pause columns to `cron_jobs` tables created before they existed.
+fn ensure_cron_job_columns(conn: &rusqlite::Connection) -> SqlResult<()> {
+    let columns = |conn: &rusqlite::Connection| -> SqlResult<Vec<String>> {
+        let mut stmt = conn.prepare("PRAGMA table_info(cron_jobs)")?;
+        stmt.query_map([], |row| row.get::<_, String>(1))?
+            .collect::<SqlResult<Vec<_>>>()
+    };
+    let cols = columns(conn)?;
+    for (name, ddl) in [
+        (
+            "enabled",
+            "ALTER TABLE cron_jobs ADD COLUMN enabled INTEGER NOT NULL DEFAULT 1",
+        ),
+        (
+            "paused_at",
+            "ALTER TABLE cron_jobs ADD COLUMN paused_at TEXT",
+        ),
+    ] {
+        if cols.iter().any(|c| c == name) {
+            continue;
+        }
+        // A concurrent process may add the column between the check and the ALTER.
+        if let Err(e) = conn.execute(ddl, [])
+            && !columns(conn)?.iter().any(|c| c == name)
+        {
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
 fn now_iso() -> String {
     crate::core::time::now_epoch_z()
 }
@@ -78,6 +115,29 @@ pub struct CronJob {
     pub scope: String,
     pub last_run: Option<String>,
     pub next_run: Option<String>,
+    /// Cleared by `cron pause`; scheduled runs of a paused job are skipped.
+    pub enabled: bool,
+    pub paused_at: Option<String>,
+}
+
+/// What started an execution.
+const CRON_TRIGGERS: &[&str] = &["manual", "schedule"];
+
+/// Tail of stderr kept as a failed execution's `error`.
+const CRON_ERROR_MAX_BYTES: usize = 4 * 1024;
+
+/// One recorded run of a cron job's command.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CronExecution {
+    pub id: String,
+    pub job_id: String,
+    pub trigger: String,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    /// `succeeded`, `failed`, or `skipped` (scheduled run of a paused job)
+    pub status: String,
+    pub exit_code: Option<i32>,
+    pub error: Option<String>,
 }
 
 #[derive(Parser, Debug)]
@@ -153,6 +213,31 @@ pub enum CronCommand {
         #[clap(long)]
         id: String,
     },
+    /// Run a job's command now and record the execution.
+    Run {
+        #[clap(long)]
+        id: String,
+        /// What started this run: manual or schedule.
+        #[clap(long, default_value = "manual")]
+        trigger: String,
+    },
+    /// Pause a job: scheduled runs are skipped (and recorded) until resumed.
+    Pause {
+        #[clap(long)]
+        id: String,
+    },
+    /// Resume a paused job, reporting the scheduled runs skipped while paused.
+    Resume {
+        #[clap(long)]
+        id: String,
+    },
+    /// Show a job's recent executions, newest first.
+    History {
+        #[clap(long)]
+        id: String,
+        #[clap(long, default_value_t = 10)]
+        last: usize,
+    },
     /// Suggest schedules from open tasks using heuristics.
     Suggest {
         #[clap(long, default_value_t = 8)]
@@ -200,7 +285,7 @@ fn add_cron_job(
     let db_path = cron_db_path(root);
 
     broker.with_conn(&db_path, "decapod", None, "cron.add", |conn| {
-        conn.execute(schemas::CRON_DB_SCHEMA, [])?;
+        ensure_cron_tables(conn)?;
         conn.execute(
             "INSERT INTO cron_jobs(id, name, description, schedule, command, status, tags, created_at, updated_at, dir_path, scope)
              VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
@@ -232,7 +317,7 @@ fn list_cron_jobs(
     let db_path = cron_db_path(root);
 
     broker.with_conn(&db_path, "decapod", None, "cron.list", |conn| {
-        let mut query = "SELECT id, name, description, schedule, command, status, last_run, next_run, tags, created_at, updated_at, dir_path, scope FROM cron_jobs WHERE 1=1".to_string();
+        let mut query = "SELECT id, name, description, schedule, command, status, last_run, next_run, tags, created_at, updated_at, dir_path, scope, enabled, paused_at FROM cron_jobs WHERE 1=1".to_string();
         let mut params: Vec<Box<dyn ToSql>> = Vec::new();
 
         if let Some(s) = status {
@@ -275,6 +360,8 @@ fn list_cron_jobs(
                 updated_at: row.get(10)?,
                 dir_path: row.get(11)?,
                 scope: row.get(12)?,
+                enabled: row.get::<_, i64>(13)? != 0,
+                paused_at: row.get(14)?,
             })
         })?;
 
@@ -292,7 +379,13 @@ fn list_cron_jobs(
                         println!("Name: {}", job.name);
                         println!("Schedule: {}", job.schedule);
                         println!("Command: {}", job.command);
-                        println!("Status: {}", job.status);
+                        match &job.paused_at {
+                            Some(since) if !job.enabled => {
+                                println!("Status: {} (paused since {})", job.status, since)
+                            }
+                            _ if !job.enabled => println!("Status: {} (paused)", job.status),
+                            _ => println!("Status: {}", job.status),
+                        }
                         if let Some(last_run) = job.last_run {
                             println!("Last Run: {}", last_run);
                         }
@@ -319,7 +412,7 @@ fn get_cron_job(root: &Path, id: String) -> Result<(), error::DecapodError> {
     let db_path = cron_db_path(root);
 
     broker.with_conn(&db_path, "decapod", None, "cron.get", |conn| {
-        let mut stmt = conn.prepare("SELECT id, name, description, schedule, command, status, last_run, next_run, tags, created_at, updated_at, dir_path, scope FROM cron_jobs WHERE id = ?1")?;
+        let mut stmt = conn.prepare("SELECT id, name, description, schedule, command, status, last_run, next_run, tags, created_at, updated_at, dir_path, scope, enabled, paused_at FROM cron_jobs WHERE id = ?1")?;
         let mut cron_job_iter = stmt.query_map([&id], |row| {
             Ok(CronJob {
                 id: row.get(0)?,
@@ -335,6 +428,8 @@ fn get_cron_job(root: &Path, id: String) -> Result<(), error::DecapodError> {
                 updated_at: row.get(10)?,
                 dir_path: row.get(11)?,
                 scope: row.get(12)?,
+                enabled: row.get::<_, i64>(13)? != 0,
+                paused_at: row.get(14)?,
             })
         })?;
 
@@ -376,6 +471,278 @@ fn delete_cron_job(root: &Path, id: String) -> Result<(), error::DecapodError> {
     Ok(())
 }
 
+fn bounded_error(raw: &str) -> String {
+    let trimmed = raw.trim();
+    if trimmed.len() <= CRON_ERROR_MAX_BYTES {
+        return trimmed.to_string();
+    }
+    let mut start = trimmed.len() - CRON_ERROR_MAX_BYTES;
+    while !trimmed.is_char_boundary(start) {
+        start += 1;
+    }
+    trimmed[start..].to_string()
+}
+
+/// Run job `id`'s command through `sh -c` in its directory and record the outcome.
+///
+/// A non-zero exit or a spawn failure is recorded as a `failed` execution
+/// rather than returned as an error; only an unknown job or trigger errors.
+pub fn run_cron_job(
+    root: &Path,
+    id: &str,
+    trigger: &str,
+) -> Result<CronExecution, error::DecapodError> {
+    if !CRON_TRIGGERS.contains(&trigger) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Unknown cron trigger '{}'. Expected one of: {}",
+            trigger,
+            CRON_TRIGGERS.join(", ")
+        )));
+    }
+    let broker = DbBroker::new(root);
+    let db_path = cron_db_path(root);
+
+    let job: Option<(String, String, bool)> =
+        broker.with_conn(&db_path, "decapod", None, "cron.get", |conn| {
+            ensure_cron_tables(conn)?;
+            let mut stmt =
+                conn.prepare("SELECT command, dir_path, enabled FROM cron_jobs WHERE id = ?1")?;
+            let mut rows = stmt.query_map([id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get::<_, i64>(2)? != 0))
+            })?;
+            Ok(rows.next().transpose()?)
+        })?;
+    let Some((command, dir_path, enabled)) = job else {
+        return Err(error::DecapodError::NotFound(format!(
+            "Cron job '{}' not found",
+            id
+        )));
+    };
+    if !enabled && trigger == "schedule" {
+        let now = now_iso();
+        let execution = CronExecution {
+            id: ulid_like(),
+            job_id: id.to_string(),
+            trigger: trigger.to_string(),
+            started_at: now.clone(),
+            finished_at: Some(now),
+            status: "skipped".to_string(),
+            exit_code: None,
+            error: Some("job is paused".to_string()),
+        };
+        broker.with_conn(&db_path, "decapod", None, "cron.run.skip", |conn| {
+            insert_execution(conn, &execution)?;
+            Ok(())
+        })?;
+        return Ok(execution);
+    }
+
+    let started_at = now_iso();
+    let output = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .current_dir(&dir_path)
+        .output();
+    let finished_at = now_iso();
+    let (status, exit_code, error) = match output {
+        Ok(out) if out.status.success() => ("succeeded", out.status.code(), None),
+        Ok(out) => {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            let error = if stderr.trim().is_empty() {
+                match out.status.code() {
+                    Some(code) => format!("exited with code {}", code),
+                    None => "terminated by signal".to_string(),
+                }
+            } else {
+                bounded_error(&stderr)
+            };
+            ("failed", out.status.code(), Some(error))
+        }
+        Err(e) => (
+            "failed",
+            None,
+            Some(format!(
+                "failed to start `sh -c {}` in {}: {}",
+                command, dir_path, e
+            )),
+        ),
+    };
+
+    let execution = CronExecution {
+        id: ulid_like(),
+        job_id: id.to_string(),
+        trigger: trigger.to_string(),
+        started_at,
+        finished_at: Some(finished_at),
+        status: status.to_string(),
+        exit_code,
+        error,
+    };
+    broker.with_conn(&db_path, "decapod", None, "cron.run", |conn| {
+        insert_execution(conn, &execution)?;
+        conn.execute(
+            "UPDATE cron_jobs SET last_run = ?1, updated_at = ?1 WHERE id = ?2",
+            rusqlite::params![execution.started_at, id],
+        )?;
+        Ok(())
+    })?;
+    Ok(execution)
+}
+
+fn insert_execution(conn: &rusqlite::Connection, execution: &CronExecution) -> SqlResult<()> {
+    conn.execute(
+        "INSERT INTO cron_executions(id, job_id, trigger, started_at, finished_at, status, exit_code, error)
+         VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        rusqlite::params![
+            execution.id,
+            execution.job_id,
+            execution.trigger,
+            execution.started_at,
+            execution.finished_at,
+            execution.status,
+            execution.exit_code,
+            execution.error
+        ],
+    )?;
+    Ok(())
+}
+
+/// Pause (`enabled = false`) or resume job `id`. Resuming reports how many
+/// scheduled runs were skipped since the pause so callers can catch up.
+pub fn set_cron_job_enabled(
+    root: &Path,
+    id: &str,
+    enabled: bool,
+) -> Result<serde_json::Value, error::DecapodError> {
+    let op = if enabled { "cron.resume" } else { "cron.pause" };
+    let broker = DbBroker::new(root);
+    let db_path = cron_db_path(root);
+    let now = now_iso();
+
+    broker.with_conn(&db_path, "decapod", None, op, |conn| {
+        ensure_cron_tables(conn)?;
+        let paused_at: Option<Option<String>> = conn
+            .query_row(
+                "SELECT paused_at FROM cron_jobs WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let Some(paused_at) = paused_at else {
+            return Err(error::DecapodError::NotFound(format!(
+                "Cron job '{}' not found",
+                id
+            )));
+        };
+        let skipped: i64 = match (&paused_at, enabled) {
+            (Some(since), true) => conn.query_row(
+                "SELECT COUNT(*) FROM cron_executions
+                 WHERE job_id = ?1 AND status = 'skipped' AND started_at >= ?2",
+                rusqlite::params![id, since],
+                |row| row.get(0),
+            )?,
+            _ => 0,
+        };
+        let new_paused_at = if enabled {
+            None
+        } else {
+            paused_at.clone().or_else(|| Some(now.clone()))
+        };
+        conn.execute(
+            "UPDATE cron_jobs SET enabled = ?1, paused_at = ?2, updated_at = ?3 WHERE id = ?4",
+            rusqlite::params![enabled as i64, new_paused_at, now, id],
+        )?;
+        Ok(serde_json::json!({
+            "ts": now,
+            "cmd": if enabled { "resume" } else { "pause" },
+            "id": id,
+            "enabled": enabled,
+            "paused_at": if enabled { paused_at } else { new_paused_at },
+            "skipped_while_paused": skipped,
+            "status": "ok",
+        }))
+    })
+}
+
+/// The `last` most recent executions of job `id`, newest first.
+pub fn cron_history(
+    root: &Path,
+    id: &str,
+    last: usize,
+) -> Result<Vec<CronExecution>, error::DecapodError> {
+    let broker = DbBroker::new(root);
+    let db_path = cron_db_path(root);
+    broker.with_conn(&db_path, "decapod", None, "cron.history", |conn| {
+        ensure_cron_tables(conn)?;
+        let mut stmt = conn.prepare(
+            "SELECT id, job_id, trigger, started_at, finished_at, status, exit_code, error
+             FROM cron_executions WHERE job_id = ?1
+             ORDER BY started_at DESC, id DESC LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![id, last as i64], |row| {
+            Ok(CronExecution {
+                id: row.get(0)?,
+                job_id: row.get(1)?,
+                trigger: row.get(2)?,
+                started_at: row.get(3)?,
+                finished_at: row.get(4)?,
+                status: row.get(5)?,
+                exit_code: row.get(6)?,
+                error: row.get(7)?,
+            })
+        })?;
+        Ok(rows.collect::<SqlResult<Vec<_>>>()?)
+    })
+}
+
+fn print_cron_run(root: &Path, id: String, trigger: String) -> Result<(), error::DecapodError> {
+    let execution = run_cron_job(root, &id, &trigger)?;
+    match (&execution.error, execution.status.as_str()) {
+        (Some(err), "skipped") => eprintln!("cron job {} skipped: {}", id, err),
+        (Some(err), _) => eprintln!("cron job {} FAILED: {}", id, err),
+        (None, _) => {}
+    }
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&serde_json::json!({
+            "ts": now_iso(),
+            "cmd": "run",
+            "execution": execution,
+        }))
+        .unwrap()
+    );
+    Ok(())
+}
+
+fn print_cron_history(root: &Path, id: String, last: usize) -> Result<(), error::DecapodError> {
+    let executions = cron_history(root, &id, last)?;
+    let failures: Vec<&CronExecution> =
+        executions.iter().filter(|e| e.status == "failed").collect();
+    if let Some(latest) = failures.first() {
+        eprintln!(
+            "cron job {}: {} of {} recent runs FAILED; latest failure at {}: {}",
+            id,
+            failures.len(),
+            executions.len(),
+            latest.started_at,
+            latest.error.as_deref().unwrap_or("")
+        );
+    }
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&serde_json::json!({
+            "ts": now_iso(),
+            "cmd": "history",
+            "id": id,
+            "failed": failures.len(),
+            "last_failure": failures.first(),
+            "executions": executions,
+        }))
+        .unwrap()
+    );
+    Ok(())
+}
+
 fn suggest_cron_jobs(root: &Path, limit: usize) -> Result<(), error::DecapodError> {
     let tasks = todo::list_tasks(root, Some("open".to_string()), None, None, None, None)?;
     let suggestions: Vec<serde_json::Value> = tasks
@@ -531,6 +898,12 @@ pub fn run_cron_cli(store: &Store, cli: CronCli) -> Result<(), error::DecapodErr
         } => list_cron_jobs(root, status, scope, tags, name_search, dir),
         CronCommand::Get { id } => get_cron_job(root, id),
         CronCommand::Delete { id } => delete_cron_job(root, id),
+        CronCommand::Run { id, trigger } => print_cron_run(root, id, trigger),
+        CronCommand::Pause { id } => set_cron_job_enabled(root, &id, false)
+            .map(|out| println!("{}", serde_json::to_string_pretty(&out).unwrap())),
+        CronCommand::Resume { id } => set_cron_job_enabled(root, &id, true)
+            .map(|out| println!("{}", serde_json::to_string_pretty(&out).unwrap())),
+        CronCommand::History { id, last } => print_cron_history(root, id, last),
         CronCommand::Suggest { limit } => suggest_cron_jobs(root, limit),
         CronCommand::Update {
             id,
@@ -573,6 +946,10 @@ pub fn schema() -> serde_json::Value {
             { "name": "get", "parameters": ["id"] },
             { "name": "update", "parameters": ["id"] },
             { "name": "delete", "parameters": ["id"] },
+            { "name": "run", "parameters": ["id", "trigger"] },
+            { "name": "pause", "parameters": ["id"] },
+            { "name": "resume", "parameters": ["id"] },
+            { "name": "history", "parameters": ["id", "last"] },
             { "name": "suggest", "parameters": ["limit"] }
         ],
         "storage": ["cron.db"]
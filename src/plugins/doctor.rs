@@ -6,9 +6,15 @@
 //! - Database presence and accessibility
 //! - Configuration validation
 //! - Version and toolchain checks
+//!
+//! `doctor snapshot` bundles the same environment facts (plus workspace,
+//! session, and recent validate results) into one redacted JSON artifact
+//! for attaching to bug reports.
 
 use crate::core::error::DecapodError;
 use crate::core::migration;
+use crate::core::output;
+use crate::core::schemas;
 use crate::core::store::Store;
 use clap::{Parser, Subcommand};
 use serde::Serialize;
@@ -28,6 +34,12 @@ pub enum DoctorCommand {
         #[clap(long, default_value = "text")]
         format: String,
     },
+    /// Emit a redacted JSON environment snapshot for bug reports
+    Snapshot {
+        /// Number of recent validate runs to include
+        #[clap(long, default_value = "5")]
+        last: usize,
+    },
 }
 
 #[derive(Debug, Serialize)]
@@ -91,10 +103,66 @@ pub fn run_doctor_cli(
                 )));
             }
         }
+        DoctorCommand::Snapshot { last } => {
+            let snapshot = build_snapshot(project_root, last);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&snapshot)
+                    .map_err(|e| DecapodError::ValidationError(e.to_string()))?
+            );
+        }
     }
     Ok(())
 }
 
+/// Environment bundle for bug reports. The whole bundle is passed through
+/// [`output::redact_secrets`], so tokens and password hashes never appear in
+/// the output.
+pub fn build_snapshot(project_root: &Path, last_validate_runs: usize) -> serde_json::Value {
+    let workspace = match crate::core::workspace::get_workspace_status(project_root) {
+        Ok(status) => serde_json::to_value(status).unwrap_or(serde_json::Value::Null),
+        Err(e) => serde_json::json!({ "error": e.to_string() }),
+    };
+    let generated = project_root.join(".decapod").join("generated");
+    output::redact_secrets(serde_json::json!({
+        "schema_version": "1.0.0",
+        "kind": "doctor_snapshot",
+        "generated_at": crate::core::time::now_epoch_z(),
+        "version": migration::DECAPOD_VERSION,
+        "workspace": workspace,
+        "sessions": read_records(&generated.join("sessions")),
+        "awareness": read_records(&generated.join("awareness")),
+        "schema_versions": {
+            "todo": schemas::TODO_SCHEMA_VERSION,
+            "memory": schemas::MEMORY_SCHEMA_VERSION,
+            "federation": schemas::FEDERATION_SCHEMA_VERSION,
+            "decide": schemas::DECIDE_SCHEMA_VERSION,
+        },
+        "container_runtimes": {
+            "docker": crate::plugins::container::command_exists("docker"),
+            "podman": crate::plugins::container::command_exists("podman"),
+        },
+        "validate_runs": crate::core::validate::recent_validation_runs(project_root, last_validate_runs),
+    }))
+}
+
+fn read_records(dir: &Path) -> Vec<serde_json::Value> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<_> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|p| std::fs::read_to_string(p).ok())
+        .filter_map(|raw| serde_json::from_str::<serde_json::Value>(&raw).ok())
+        .collect()
+}
+
 fn run_preflight_checks(store: &Store, project_root: &Path) -> Result<DoctorReport, DecapodError> {
     let mut checks = Vec::new();
 
@@ -1,5 +1,6 @@
 use crate::core::broker::DbBroker;
 use crate::core::error;
+use crate::core::output::JsonlWriter;
 use crate::core::schemas;
 use crate::core::store::Store;
 use clap::{Parser, Subcommand, ValueEnum};
@@ -7,6 +8,7 @@ use rusqlite::{Connection, OptionalExtension, params};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
@@ -40,6 +42,8 @@ const VALID_EDGE_TYPES: &[&str] = &["relates_to", "depends_on", "supersedes", "i
 pub enum OutputFormat {
     Text,
     Json,
+    /// One JSON object per line; streamed by `query`.
+    Jsonl,
 }
 
 #[derive(Parser, Debug)]
@@ -105,6 +109,26 @@ pub enum FederationCommand {
         #[clap(long)]
         scope: Option<String>,
     },
+    /// Query nodes with structured filters; newest first, ties by id.
+    Query {
+        /// Node type (decision, lesson, ...)
+        #[clap(long)]
+        kind: Option<String>,
+        /// Priority: critical, notable, background
+        #[clap(long)]
+        salience: Option<String>,
+        /// Confidence: human_confirmed, agent_inferred, imported
+        #[clap(long)]
+        origin: Option<String>,
+        /// Exact tag match
+        #[clap(long)]
+        tag: Option<String>,
+        /// Only nodes created at or after this time (RFC3339 or epoch seconds with Z suffix)
+        #[clap(long)]
+        since: Option<String>,
+        #[clap(long)]
+        limit: Option<usize>,
+    },
     /// Search nodes by title and body text.
     Search {
         #[clap(long)]
@@ -125,6 +149,24 @@ pub enum FederationCommand {
         #[clap(long)]
         priority: Option<String>,
     },
+    /// Revise a non-critical node's content, keeping the prior content in history.
+    Update {
+        #[clap(long)]
+        id: String,
+        /// New body content
+        #[clap(long)]
+        content: String,
+        /// Why the content changed
+        #[clap(long)]
+        reason: String,
+        #[clap(long, default_value = "decapod")]
+        actor: String,
+    },
+    /// List a node's content revisions, oldest first.
+    History {
+        #[clap(long)]
+        id: String,
+    },
     /// Supersede a node: transitions old to 'superseded' and creates a supersedes edge.
     Supersede {
         /// The node being superseded
@@ -162,6 +204,12 @@ pub enum FederationCommand {
         /// Edge type: relates_to, depends_on, supersedes, invalidated_by
         #[clap(long = "type")]
         edge_type: String,
+        /// How strongly the link holds (0.0–1.0)
+        #[clap(long, default_value_t = 1.0)]
+        confidence: f64,
+        /// Why the link exists
+        #[clap(long)]
+        rationale: Option<String>,
     },
     /// Remove an edge by ID.
     Unlink {
@@ -176,6 +224,16 @@ pub enum FederationCommand {
         #[clap(long, default_value = "1")]
         depth: u32,
     },
+    /// Find the most confident route between two nodes.
+    Path {
+        #[clap(long)]
+        from: String,
+        #[clap(long)]
+        to: String,
+        /// Ignore edges below this confidence (0.0–1.0)
+        #[clap(long, default_value_t = 0.0)]
+        min_confidence: f64,
+    },
     /// Add a provenance source to an existing node.
     SourcesAdd {
         /// Node ID to add source to
@@ -231,6 +289,36 @@ pub struct FederationEdge {
     pub edge_type: String,
     pub created_at: String,
     pub actor: String,
+    /// How strongly the link holds, 0.0–1.0; edges recorded without one are 1.0.
+    #[serde(default = "default_edge_confidence")]
+    pub confidence: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<String>,
+}
+
+fn default_edge_confidence() -> f64 {
+    1.0
+}
+
+/// One version of a node's body, from its create, edit, or revise event.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NodeRevision {
+    pub revision: usize,
+    pub ts: String,
+    pub event_type: String,
+    pub actor: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub content: String,
+}
+
+/// A route between two nodes found by [`find_path`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NodePath {
+    pub nodes: Vec<String>,
+    pub edges: Vec<FederationEdge>,
+    /// Product of the edge confidences along the route.
+    pub confidence: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -339,6 +427,16 @@ fn validate_edge_type(t: &str) -> Result<(), error::DecapodError> {
     Ok(())
 }
 
+fn validate_edge_confidence(confidence: f64) -> Result<(), error::DecapodError> {
+    if !(0.0..=1.0).contains(&confidence) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Invalid edge confidence {}. Must be between 0.0 and 1.0",
+            confidence
+        )));
+    }
+    Ok(())
+}
+
 fn validate_provenance(source: &str) -> Result<(), error::DecapodError> {
     let prov_re = fancy_regex::Regex::new(
         r"^(file:[^#]+(#L\d+(-L\d+)?)?|url:[^ ]+|cmd:[^ ]+|commit:[a-f0-9]+|event:.+)$",
@@ -466,20 +564,11 @@ fn read_node_full(conn: &Connection, id: &str) -> Result<FederationNode, error::
 
     // Fetch edges (both directions)
     let mut edge_stmt = conn.prepare(
-        "SELECT id, source_id, target_id, edge_type, created_at, actor
+        "SELECT id, source_id, target_id, edge_type, created_at, actor, confidence, rationale
              FROM edges WHERE source_id = ?1 OR target_id = ?1",
     )?;
     let edges: Vec<FederationEdge> = edge_stmt
-        .query_map(params![id], |row| {
-            Ok(FederationEdge {
-                id: row.get(0)?,
-                source_id: row.get(1)?,
-                target_id: row.get(2)?,
-                edge_type: row.get(3)?,
-                created_at: row.get(4)?,
-                actor: row.get(5)?,
-            })
-        })?
+        .query_map(params![id], edge_from_row)?
         .filter_map(|r| r.ok())
         .collect();
 
@@ -490,8 +579,184 @@ fn read_node_full(conn: &Connection, id: &str) -> Result<FederationNode, error::
     })
 }
 
+fn edge_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<FederationEdge> {
+    Ok(FederationEdge {
+        id: row.get(0)?,
+        source_id: row.get(1)?,
+        target_id: row.get(2)?,
+        edge_type: row.get(3)?,
+        created_at: row.get(4)?,
+        actor: row.get(5)?,
+        confidence: row.get(6)?,
+        rationale: row.get(7)?,
+    })
+}
+
 // --- Initialization ---
 
+/// Filters for [`query_nodes`]; `None` fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct NodeQuery {
+    pub kind: Option<String>,
+    pub salience: Option<String>,
+    pub origin: Option<String>,
+    pub tag: Option<String>,
+    pub since: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/// Nodes matching every filter in `query`, ordered by `created_at` descending
+/// and then by id so equal timestamps still come back in a stable order.
+pub fn query_nodes(
+    store: &Store,
+    query: &NodeQuery,
+) -> Result<Vec<FederationNode>, error::DecapodError> {
+    let mut nodes = Vec::new();
+    for_each_queried_node(store, query, |node| {
+        nodes.push(node);
+        Ok(())
+    })?;
+    Ok(nodes)
+}
+
+/// Rows read per broker connection while streaming nodes; the connection is
+/// released between pages so a slow `visit` never holds the federation database.
+const STREAM_PAGE_SIZE: i64 = 256;
+
+/// Hands each node `query_nodes` would return to `visit` in the same order,
+/// reading [`STREAM_PAGE_SIZE`] rows at a time and stopping once `limit` nodes
+/// have been visited.
+pub fn for_each_queried_node<F>(
+    store: &Store,
+    query: &NodeQuery,
+    mut visit: F,
+) -> Result<(), error::DecapodError>
+where
+    F: FnMut(FederationNode) -> Result<(), error::DecapodError>,
+{
+    if let Some(kind) = &query.kind {
+        validate_node_type(kind)?;
+    }
+    if let Some(salience) = &query.salience {
+        validate_priority(salience)?;
+    }
+    if let Some(origin) = &query.origin {
+        validate_confidence(origin)?;
+    }
+    let since = query
+        .since
+        .as_deref()
+        .map(|since| {
+            crate::core::time::parse_event_ts_epoch(since).ok_or_else(|| {
+                error::DecapodError::ValidationError(format!(
+                    "Invalid --since '{}': expected RFC3339 or epoch seconds with Z suffix",
+                    since
+                ))
+            })
+        })
+        .transpose()?;
+    if query.limit == Some(0) {
+        return Ok(());
+    }
+
+    let broker = DbBroker::new(&store.root);
+    let db_path = federation_db_path(&store.root);
+    // Keyset cursor over (created_at DESC, id ASC); `created_at` never changes,
+    // so pages neither skip nor repeat nodes.
+    let mut cursor: Option<(i64, String)> = None;
+    let mut visited = 0;
+    loop {
+        let page = broker.with_conn(&db_path, "decapod", None, "federation.query", |conn| {
+            let mut conditions = vec!["1=1".to_string()];
+            let mut param_values: Vec<&dyn rusqlite::types::ToSql> = vec![];
+            for (column, value) in [
+                ("node_type", &query.kind),
+                ("priority", &query.salience),
+                ("confidence", &query.origin),
+            ] {
+                if let Some(value) = value {
+                    param_values.push(value);
+                    conditions.push(format!("{} = ?{}", column, param_values.len()));
+                }
+            }
+            if let Some((created_at, id)) = &cursor {
+                param_values.push(created_at);
+                let ts = param_values.len();
+                param_values.push(id);
+                conditions.push(format!(
+                    "(CAST(created_at AS INTEGER) < ?{ts} OR (CAST(created_at AS INTEGER) = ?{ts} AND id > ?{}))",
+                    param_values.len()
+                ));
+            }
+            param_values.push(&STREAM_PAGE_SIZE);
+            // Timestamps are epoch seconds with a `Z` suffix (see `now_ts`); the
+            // integer cast orders them numerically rather than as text.
+            let sql = format!(
+                "SELECT id, node_type, status, priority, confidence, title, body, scope, tags,
+                        created_at, updated_at, effective_from, effective_to, actor,
+                        CAST(created_at AS INTEGER)
+                 FROM nodes WHERE {}
+                 ORDER BY CAST(created_at AS INTEGER) DESC, id ASC
+                 LIMIT ?{}",
+                conditions.join(" AND "),
+                param_values.len()
+            );
+            let mut stmt = conn.prepare(&sql)?;
+            let rows = stmt.query_map(param_values.as_slice(), |row| {
+                Ok((
+                    FederationNode {
+                        id: row.get(0)?,
+                        node_type: row.get(1)?,
+                        status: row.get(2)?,
+                        priority: row.get(3)?,
+                        confidence: row.get(4)?,
+                        title: row.get(5)?,
+                        body: row.get(6)?,
+                        scope: row.get(7)?,
+                        tags: row.get(8)?,
+                        created_at: row.get(9)?,
+                        updated_at: row.get(10)?,
+                        effective_from: row.get(11)?,
+                        effective_to: row.get(12)?,
+                        actor: row.get(13)?,
+                        sources: None,
+                        edges: None,
+                    },
+                    row.get::<_, i64>(14)?,
+                ))
+            })?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(error::DecapodError::RusqliteError)
+        })?;
+
+        let full = page.len() as i64 == STREAM_PAGE_SIZE;
+        if let Some((node, created_at)) = page.last() {
+            cursor = Some((*created_at, node.id.clone()));
+        }
+        // Tags are a comma-separated text column; match on parsed values.
+        for (node, _) in page {
+            if let Some(tag) = &query.tag
+                && !parse_tags(&node.tags).iter().any(|t| t == tag)
+            {
+                continue;
+            }
+            if let Some(since) = since
+                && crate::core::time::parse_event_ts_epoch(&node.created_at).unwrap_or(0) < since
+            {
+                continue;
+            }
+            visit(node)?;
+            visited += 1;
+            if query.limit == Some(visited) {
+                return Ok(());
+            }
+        }
+        if !full {
+            return Ok(());
+        }
+    }
+}
+
 pub fn initialize_federation_db(root: &Path) -> Result<(), error::DecapodError> {
     let db_path = federation_db_path(root);
     let broker = DbBroker::new(root);
@@ -501,6 +766,7 @@ pub fn initialize_federation_db(root: &Path) -> Result<(), error::DecapodError>
         conn.execute_batch(schemas::MEMORY_DB_SCHEMA_SOURCES)?;
         conn.execute_batch(schemas::MEMORY_DB_SCHEMA_EDGES)?;
         conn.execute_batch(schemas::MEMORY_DB_SCHEMA_EVENTS)?;
+        ensure_edge_columns(conn)?;
 
         // Indexes
         conn.execute_batch(schemas::MEMORY_DB_INDEX_NODES_TYPE)?;
@@ -531,6 +797,25 @@ pub fn initialize_federation_db(root: &Path) -> Result<(), error::DecapodError>
     Ok(())
 }
 
+/// Add edge columns introduced after the original schema to existing DBs.
+fn ensure_edge_columns(conn: &Connection) -> Result<(), error::DecapodError> {
+    let mut stmt = conn.prepare("PRAGMA table_info(edges)")?;
+    let cols: std::collections::HashSet<String> = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .filter_map(|r| r.ok())
+        .collect();
+    if !cols.contains("confidence") {
+        conn.execute(
+            "ALTER TABLE edges ADD COLUMN confidence REAL NOT NULL DEFAULT 1.0",
+            [],
+        )?;
+    }
+    if !cols.contains("rationale") {
+        conn.execute("ALTER TABLE edges ADD COLUMN rationale TEXT", [])?;
+    }
+    Ok(())
+}
+
 // --- Core Operations ---
 
 #[allow(clippy::too_many_arguments)]
@@ -779,6 +1064,136 @@ pub fn edit_node(
     Ok(())
 }
 
+/// Replace a node's body with `content`, recording the prior body and
+/// `reason` in a `node.revise` event. Critical nodes must be superseded.
+pub fn update_node(
+    store: &Store,
+    id: &str,
+    content: &str,
+    reason: &str,
+    actor: &str,
+) -> Result<(), error::DecapodError> {
+    if reason.trim().is_empty() {
+        return Err(error::DecapodError::ValidationError(
+            "A revision requires a non-empty --reason".to_string(),
+        ));
+    }
+    let broker = DbBroker::new(&store.root);
+    let db_path = federation_db_path(&store.root);
+    let events_path = federation_events_path(&store.root);
+    let now = now_ts();
+
+    broker.with_conn(&db_path, actor, None, "federation.update", |conn| {
+        let (nt, pri) = get_node_type_and_priority(conn, id)?;
+        if is_critical(&nt, &pri) {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Cannot revise critical node '{}' (type={}, priority={}). Use 'supersede' instead.",
+                id, nt, pri
+            )));
+        }
+        let status = get_node_status(conn, id)?;
+        if status != "active" {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Cannot revise node '{}' with status '{}'. Only active nodes can be revised.",
+                id, status
+            )));
+        }
+
+        let previous: String =
+            conn.query_row("SELECT body FROM nodes WHERE id = ?1", params![id], |row| {
+                row.get(0)
+            })?;
+        conn.execute(
+            "UPDATE nodes SET body = ?1, updated_at = ?2 WHERE id = ?3",
+            params![content, now, id],
+        )?;
+
+        let event_id = crate::core::ulid::new_ulid();
+        let payload_json = serde_json::json!({
+            "previous_body": previous,
+            "body": content,
+            "reason": reason,
+        });
+        conn.execute(
+            "INSERT INTO federation_events(event_id, ts, event_type, node_id, payload, actor)
+             VALUES(?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                event_id,
+                now,
+                "node.revise",
+                id,
+                serde_json::to_string(&payload_json).unwrap(),
+                actor,
+            ],
+        )?;
+
+        append_event(
+            &events_path,
+            &FederationEvent {
+                event_id,
+                ts: now.clone(),
+                event_type: "node.revise".to_string(),
+                status: "success".to_string(),
+                node_id: Some(id.to_string()),
+                payload: payload_json,
+                actor: actor.to_string(),
+            },
+        )?;
+
+        Ok(())
+    })
+}
+
+/// Every version of a node's body in event order: the original, then each
+/// edit that touched the body and each revision.
+pub fn node_history(store: &Store, id: &str) -> Result<Vec<NodeRevision>, error::DecapodError> {
+    let broker = DbBroker::new(&store.root);
+    let db_path = federation_db_path(&store.root);
+
+    broker.with_conn(&db_path, "decapod", None, "federation.history", |conn| {
+        if !node_exists(conn, id)? {
+            return Err(error::DecapodError::NotFound(format!(
+                "Node '{}' not found",
+                id
+            )));
+        }
+        let mut stmt = conn.prepare(
+            "SELECT ts, event_type, payload, actor FROM federation_events
+             WHERE node_id = ?1 AND event_type IN ('node.create', 'node.edit', 'node.revise')
+             ORDER BY rowid",
+        )?;
+        let rows = stmt.query_map(params![id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+
+        let mut history = Vec::new();
+        for row in rows {
+            let (ts, event_type, payload, actor) = row?;
+            let payload: JsonValue = serde_json::from_str(&payload).unwrap_or(JsonValue::Null);
+            let Some(content) = payload.get("body").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            history.push(NodeRevision {
+                revision: history.len(),
+                ts,
+                event_type,
+                actor,
+                reason: payload
+                    .get("reason")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                content: content.to_string(),
+            });
+        }
+        Ok(history)
+    })
+}
+
 pub fn supersede_node(
     store: &Store,
     old_id: &str,
@@ -938,8 +1353,22 @@ pub fn add_edge(
     source_id: &str,
     target_id: &str,
     edge_type: &str,
+) -> Result<String, error::DecapodError> {
+    add_weighted_edge(store, source_id, target_id, edge_type, 1.0, None)
+}
+
+/// Add an edge with an explicit `confidence` (0.0–1.0) and optional `rationale`.
+pub fn add_weighted_edge(
+    store: &Store,
+    source_id: &str,
+    target_id: &str,
+    edge_type: &str,
+    confidence: f64,
+    rationale: Option<&str>,
 ) -> Result<String, error::DecapodError> {
     validate_edge_type(edge_type)?;
+    validate_edge_confidence(confidence)?;
+    let rationale = rationale.map(str::trim).filter(|r| !r.is_empty());
 
     let broker = DbBroker::new(&store.root);
     let db_path = federation_db_path(&store.root);
@@ -962,9 +1391,9 @@ pub fn add_edge(
         }
 
         conn.execute(
-            "INSERT INTO edges(id, source_id, target_id, edge_type, created_at, actor)
-             VALUES(?1, ?2, ?3, ?4, ?5, 'decapod')",
-            params![edge_id, source_id, target_id, edge_type, now],
+            "INSERT INTO edges(id, source_id, target_id, edge_type, created_at, actor, confidence, rationale)
+             VALUES(?1, ?2, ?3, ?4, ?5, 'decapod', ?6, ?7)",
+            params![edge_id, source_id, target_id, edge_type, now, confidence, rationale],
         )?;
 
         let event_id = crate::core::ulid::new_ulid();
@@ -973,6 +1402,8 @@ pub fn add_edge(
             "source_id": source_id,
             "target_id": target_id,
             "edge_type": edge_type,
+            "confidence": confidence,
+            "rationale": rationale,
         });
         conn.execute(
             "INSERT INTO federation_events(event_id, ts, event_type, node_id, payload, actor)
@@ -1163,6 +1594,8 @@ fn graph_neighbors(store: &Store, id: &str, depth: u32) -> Result<JsonValue, err
                             "source_id": edge.source_id,
                             "target_id": edge.target_id,
                             "edge_type": edge.edge_type,
+                            "confidence": edge.confidence,
+                            "rationale": edge.rationale,
                         }));
                         let neighbor = if edge.source_id == *node_id {
                             &edge.target_id
@@ -1210,6 +1643,104 @@ fn graph_neighbors(store: &Store, id: &str, depth: u32) -> Result<JsonValue, err
     })
 }
 
+/// Most confident route from `from` to `to`, following edges in either
+/// direction and ignoring any edge below `min_confidence`.
+///
+/// Routes are ranked by the product of their edge confidences, so one strong
+/// hop beats several weak ones; ties go to fewer hops, then to edge ID order.
+pub fn find_path(
+    store: &Store,
+    from: &str,
+    to: &str,
+    min_confidence: f64,
+) -> Result<Option<NodePath>, error::DecapodError> {
+    validate_edge_confidence(min_confidence)?;
+    let broker = DbBroker::new(&store.root);
+    let db_path = federation_db_path(&store.root);
+
+    let edges = broker.with_conn(&db_path, "decapod", None, "federation.path", |conn| {
+        for id in [from, to] {
+            if !node_exists(conn, id)? {
+                return Err(error::DecapodError::NotFound(format!(
+                    "Node '{}' not found",
+                    id
+                )));
+            }
+        }
+        let mut stmt = conn.prepare(
+            "SELECT id, source_id, target_id, edge_type, created_at, actor, confidence, rationale
+             FROM edges WHERE confidence >= ?1 ORDER BY id",
+        )?;
+        let edges = stmt
+            .query_map(params![min_confidence], edge_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(edges)
+    })?;
+
+    let mut adjacency: HashMap<&str, Vec<(usize, &str)>> = HashMap::new();
+    for (idx, edge) in edges.iter().enumerate() {
+        adjacency
+            .entry(edge.source_id.as_str())
+            .or_default()
+            .push((idx, edge.target_id.as_str()));
+        adjacency
+            .entry(edge.target_id.as_str())
+            .or_default()
+            .push((idx, edge.source_id.as_str()));
+    }
+
+    // Dijkstra over (confidence product, hops); the graph is small enough that
+    // a linear scan for the next node keeps the ordering easy to follow.
+    // node -> (confidence product, hops, (edge index, previous node))
+    type Step<'a> = (f64, usize, Option<(usize, &'a str)>);
+    let mut best: HashMap<&str, Step> = HashMap::new();
+    let mut settled: HashSet<&str> = HashSet::new();
+    best.insert(from, (1.0, 0, None));
+    while let Some((node, (score, hops, _))) = best
+        .iter()
+        .filter(|(id, _)| !settled.contains(*id))
+        .max_by(|(a_id, a), (b_id, b)| a.0.total_cmp(&b.0).then(b.1.cmp(&a.1)).then(b_id.cmp(a_id)))
+        .map(|(id, entry)| (*id, *entry))
+    {
+        if node == to {
+            break;
+        }
+        settled.insert(node);
+        for &(idx, next) in adjacency.get(node).into_iter().flatten() {
+            if settled.contains(next) {
+                continue;
+            }
+            let candidate = (score * edges[idx].confidence, hops + 1);
+            let better = match best.get(next) {
+                None => true,
+                Some(&(s, h, _)) => candidate.0 > s || (candidate.0 == s && candidate.1 < h),
+            };
+            if better {
+                best.insert(next, (candidate.0, candidate.1, Some((idx, node))));
+            }
+        }
+    }
+
+    let Some(&(confidence, _, _)) = best.get(to) else {
+        return Ok(None);
+    };
+    let mut nodes = vec![to.to_string()];
+    let mut path_edges = Vec::new();
+    let mut cursor = to;
+    while let Some(&(_, _, Some((idx, prev)))) = best.get(cursor) {
+        path_edges.push(edges[idx].clone());
+        nodes.push(prev.to_string());
+        cursor = prev;
+    }
+    nodes.reverse();
+    path_edges.reverse();
+    Ok(Some(NodePath {
+        nodes,
+        edges: path_edges,
+        confidence,
+    }))
+}
+
 fn parse_tags(tags: &str) -> Vec<String> {
     tags.split(',')
         .map(|s| s.trim().to_string())
@@ -1270,7 +1801,7 @@ fn build_graph_json(conn: &Connection) -> Result<JsonValue, error::DecapodError>
 
     {
         let mut stmt = conn.prepare(
-            "SELECT id, source_id, target_id, edge_type
+            "SELECT id, source_id, target_id, edge_type, confidence
              FROM edges ORDER BY source_id, edge_type, target_id, id",
         )?;
         let mut rows = stmt.query([])?;
@@ -1280,6 +1811,7 @@ fn build_graph_json(conn: &Connection) -> Result<JsonValue, error::DecapodError>
                 "source_id": row.get::<_, String>(1)?,
                 "target_id": row.get::<_, String>(2)?,
                 "edge_type": row.get::<_, String>(3)?,
+                "confidence": row.get::<_, f64>(4)?,
             }));
         }
     }
@@ -1629,6 +2161,15 @@ fn replay_event(conn: &Connection, event: &FederationEvent) -> Result<(), error:
                 )?;
             }
         }
+        "node.revise" => {
+            let node_id = event.node_id.as_deref().unwrap_or("");
+            if let Some(body) = event.payload.get("body").and_then(|v| v.as_str()) {
+                conn.execute(
+                    "UPDATE nodes SET body = ?1, updated_at = ?2 WHERE id = ?3",
+                    params![body, event.ts, node_id],
+                )?;
+            }
+        }
         "node.supersede" => {
             let p = &event.payload;
             let old_id = p.get("old_id").and_then(|v| v.as_str()).unwrap_or("");
@@ -1672,11 +2213,13 @@ fn replay_event(conn: &Connection, event: &FederationEvent) -> Result<(), error:
             let source_id = p.get("source_id").and_then(|v| v.as_str()).unwrap_or("");
             let target_id = p.get("target_id").and_then(|v| v.as_str()).unwrap_or("");
             let edge_type = p.get("edge_type").and_then(|v| v.as_str()).unwrap_or("");
+            let confidence = p.get("confidence").and_then(|v| v.as_f64()).unwrap_or(1.0);
+            let rationale = p.get("rationale").and_then(|v| v.as_str());
 
             conn.execute(
-                "INSERT OR IGNORE INTO edges(id, source_id, target_id, edge_type, created_at, actor)
-                 VALUES(?1, ?2, ?3, ?4, ?5, ?6)",
-                params![edge_id, source_id, target_id, edge_type, event.ts, event.actor],
+                "INSERT OR IGNORE INTO edges(id, source_id, target_id, edge_type, created_at, actor, confidence, rationale)
+                 VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![edge_id, source_id, target_id, edge_type, event.ts, event.actor, confidence, rationale],
             )
             ?;
         }
@@ -2207,6 +2750,12 @@ fn detect_cycle_in_edges(edges: &[(String, String)]) -> bool {
 
 pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error::DecapodError> {
     initialize_federation_db(&store.root)?;
+    if cli.format == OutputFormat::Jsonl && !matches!(cli.command, FederationCommand::Query { .. })
+    {
+        return Err(error::DecapodError::ValidationError(
+            "--format jsonl is only supported by `federation query`".to_string(),
+        ));
+    }
 
     match cli.command {
         FederationCommand::Add {
@@ -2236,7 +2785,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             )?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!("{}", serde_json::to_string_pretty(&node).unwrap());
                 }
                 OutputFormat::Text => {
@@ -2254,7 +2803,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             })?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!("{}", serde_json::to_string_pretty(&node).unwrap());
                 }
                 OutputFormat::Text => {
@@ -2374,7 +2923,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             })?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!("{}", serde_json::to_string_pretty(&nodes).unwrap());
                 }
                 OutputFormat::Text => {
@@ -2393,6 +2942,43 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             }
         }
 
+        FederationCommand::Query {
+            kind,
+            salience,
+            origin,
+            tag,
+            since,
+            limit,
+        } => {
+            let query = NodeQuery {
+                kind,
+                salience,
+                origin,
+                tag,
+                since,
+                limit,
+            };
+            if cli.format == OutputFormat::Jsonl {
+                let mut writer = JsonlWriter::new(std::io::stdout().lock());
+                return for_each_queried_node(store, &query, |node| writer.write_record(&node));
+            }
+            let nodes = query_nodes(store, &query)?;
+            match cli.format {
+                OutputFormat::Json | OutputFormat::Jsonl => {
+                    println!("{}", serde_json::to_string_pretty(&nodes).unwrap());
+                }
+                OutputFormat::Text => {
+                    for n in &nodes {
+                        println!(
+                            "[{}] {} | {} | {} | {}",
+                            n.status, n.id, n.node_type, n.priority, n.title
+                        );
+                    }
+                    println!("\n{} node(s)", nodes.len());
+                }
+            }
+        }
+
         FederationCommand::Search { query, scope } => {
             let broker = DbBroker::new(&store.root);
             let db_path = federation_db_path(&store.root);
@@ -2454,7 +3040,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
                 })?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!("{}", serde_json::to_string_pretty(&nodes).unwrap());
                 }
                 OutputFormat::Text => {
@@ -2473,6 +3059,46 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             }
         }
 
+        FederationCommand::Update {
+            id,
+            content,
+            reason,
+            actor,
+        } => {
+            update_node(store, &id, &content, &reason, &actor)?;
+            match cli.format {
+                OutputFormat::Json | OutputFormat::Jsonl => {
+                    println!(
+                        "{}",
+                        serde_json::json!({"status": "ok", "id": id, "op": "update"})
+                    );
+                }
+                OutputFormat::Text => {
+                    println!("Node '{}' revised.", id);
+                }
+            }
+        }
+
+        FederationCommand::History { id } => {
+            let history = node_history(store, &id)?;
+            match cli.format {
+                OutputFormat::Json | OutputFormat::Jsonl => {
+                    println!("{}", serde_json::to_string_pretty(&history).unwrap());
+                }
+                OutputFormat::Text => {
+                    for rev in &history {
+                        println!(
+                            "r{} {} {} {}",
+                            rev.revision,
+                            rev.ts,
+                            rev.event_type,
+                            rev.reason.as_deref().unwrap_or("-")
+                        );
+                    }
+                }
+            }
+        }
+
         FederationCommand::Edit {
             id,
             title,
@@ -2490,7 +3116,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             )?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({"status": "ok", "id": id, "op": "edit"})
@@ -2506,7 +3132,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             supersede_node(store, &id, &by, &reason)?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({
@@ -2527,7 +3153,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             transition_node_status(store, &id, "deprecated", "node.deprecate", &reason)?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({"status": "ok", "id": id, "op": "deprecate"})
@@ -2543,7 +3169,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             transition_node_status(store, &id, "disputed", "node.dispute", &reason)?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({"status": "ok", "id": id, "op": "dispute"})
@@ -2559,11 +3185,20 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             source,
             target,
             edge_type,
+            confidence,
+            rationale,
         } => {
-            let edge_id = add_edge(store, &source, &target, &edge_type)?;
+            let edge_id = add_weighted_edge(
+                store,
+                &source,
+                &target,
+                &edge_type,
+                confidence,
+                rationale.as_deref(),
+            )?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({
@@ -2572,6 +3207,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
                             "source": source,
                             "target": target,
                             "edge_type": edge_type,
+                            "confidence": confidence,
                         })
                     );
                 }
@@ -2588,7 +3224,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             remove_edge(store, &id)?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({"status": "ok", "edge_id": id, "op": "unlink"})
@@ -2604,7 +3240,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             let result = graph_neighbors(store, &id, depth)?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!("{}", serde_json::to_string_pretty(&result).unwrap());
                 }
                 OutputFormat::Text => {
@@ -2627,11 +3263,46 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             }
         }
 
+        FederationCommand::Path {
+            from,
+            to,
+            min_confidence,
+        } => {
+            let path = find_path(store, &from, &to, min_confidence)?;
+
+            match cli.format {
+                OutputFormat::Json | OutputFormat::Jsonl => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "from": from,
+                            "to": to,
+                            "min_confidence": min_confidence,
+                            "path": path,
+                        }))
+                        .unwrap()
+                    );
+                }
+                OutputFormat::Text => match path {
+                    Some(path) => println!(
+                        "{} (confidence {:.2}, {} hops)",
+                        path.nodes.join(" -> "),
+                        path.confidence,
+                        path.edges.len()
+                    ),
+                    None => println!(
+                        "No path from '{}' to '{}' with confidence >= {}",
+                        from, to, min_confidence
+                    ),
+                },
+            }
+        }
+
         FederationCommand::Rebuild => {
             let count = rebuild_from_events(&store.root)?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({"status": "ok", "events_replayed": count})
@@ -2650,7 +3321,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
             let src_id = add_source_to_node(store, &id, &source)?;
 
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({
@@ -2671,7 +3342,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
         FederationCommand::Init => {
             // initialize_federation_db is already called at the top of run_federation_cli
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({"status": "ok", "op": "init", "store": store.root.to_string_lossy()})
@@ -2686,7 +3357,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
         FederationCommand::VaultExport => {
             let count = export_vault_notes(store)?;
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({
@@ -2710,7 +3381,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
         FederationCommand::IndexBuild => {
             let lines = build_index_file(store)?;
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({
@@ -2734,7 +3405,7 @@ pub fn run_federation_cli(store: &Store, cli: FederationCli) -> Result<(), error
         FederationCommand::GraphExport => {
             let (nodes, edges) = export_graph_file(store)?;
             match cli.format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Jsonl => {
                     println!(
                         "{}",
                         serde_json::json!({
@@ -2782,14 +3453,18 @@ pub fn schema() -> serde_json::Value {
             {"name": "add", "description": "Create a new memory node"},
             {"name": "get", "description": "Get a node by ID with sources and edges"},
             {"name": "list", "description": "List nodes with filters"},
+            {"name": "query", "description": "Filter nodes by kind, salience (priority), origin (confidence), tag, since, limit"},
             {"name": "search", "description": "Search nodes by title and body"},
             {"name": "edit", "description": "Edit non-critical node fields"},
+            {"name": "update", "description": "Revise a non-critical node's content with a reason; prior content kept in history"},
+            {"name": "history", "description": "List a node's content revisions with timestamps and reasons"},
             {"name": "supersede", "description": "Supersede a node with a replacement"},
             {"name": "deprecate", "description": "Mark a node as deprecated"},
             {"name": "dispute", "description": "Mark a node as disputed"},
-            {"name": "link", "description": "Add a typed edge between nodes"},
+            {"name": "link", "description": "Add a typed edge between nodes with optional confidence and rationale"},
             {"name": "unlink", "description": "Remove an edge"},
             {"name": "graph", "description": "Show node neighborhood"},
+            {"name": "path", "description": "Find the most confident route between nodes, skipping edges below --min-confidence"},
             {"name": "vault-export", "description": "Export vault markdown notes under federation/vault"},
             {"name": "index-build", "description": "Build deterministic federation/_index.md"},
             {"name": "graph-export", "description": "Build deterministic federation/_graph.json"},
@@ -2,10 +2,18 @@ use crate::core::broker::DbBroker;
 use crate::core::error;
 use crate::core::schemas;
 use crate::core::store::Store;
-use rusqlite::params;
+use crate::core::todo;
+use crate::plugins::aptitude;
+use crate::plugins::federation;
+use rusqlite::{OptionalExtension, params};
 use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
+/// Terminal states a feedback item can be resolved into.
+pub const FEEDBACK_OUTCOMES: [&str; 2] = ["addressed", "rejected"];
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FeedbackEntry {
     pub id: String,
@@ -13,6 +21,32 @@ pub struct FeedbackEntry {
     pub text: String,
     pub links: Option<String>,
     pub created_at: String,
+    #[serde(default = "default_feedback_status")]
+    pub status: String,
+    #[serde(default)]
+    pub resolved_at: Option<String>,
+    #[serde(default)]
+    pub resolution_note: Option<String>,
+}
+
+fn default_feedback_status() -> String {
+    "open".to_string()
+}
+
+const FEEDBACK_COLUMNS: &str =
+    "id, source, text, links, created_at, status, resolved_at, resolution_note";
+
+fn feedback_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<FeedbackEntry> {
+    Ok(FeedbackEntry {
+        id: row.get(0)?,
+        source: row.get(1)?,
+        text: row.get(2)?,
+        links: row.get(3)?,
+        created_at: row.get(4)?,
+        status: row.get(5)?,
+        resolved_at: row.get(6)?,
+        resolution_note: row.get(7)?,
+    })
 }
 
 pub fn feedback_db_path(root: &Path) -> PathBuf {
@@ -25,6 +59,13 @@ pub fn initialize_feedback_db(root: &Path) -> Result<(), error::DecapodError> {
 
     broker.with_conn(&db_path, "decapod", None, "feedback.init", |conn| {
         conn.execute(schemas::FEEDBACK_DB_SCHEMA, [])?;
+        // Ledgers created before the status lifecycle lack these columns.
+        let _ = conn.execute(
+            "ALTER TABLE feedback ADD COLUMN status TEXT NOT NULL DEFAULT 'open'",
+            [],
+        );
+        let _ = conn.execute("ALTER TABLE feedback ADD COLUMN resolved_at TEXT", []);
+        let _ = conn.execute("ALTER TABLE feedback ADD COLUMN resolution_note TEXT", []);
         Ok(())
     })
 }
@@ -51,75 +92,534 @@ pub fn add_feedback(
     Ok(id)
 }
 
-pub fn propose_prefs(store: &Store) -> Result<String, error::DecapodError> {
-    // This generates a proposal text based on feedback.
-    // It MUST NOT edit files directly.
+/// List feedback entries, newest first. `status` of `None` means open items
+/// only; pass `"all"` to include resolved ones.
+pub fn list_feedback(
+    store: &Store,
+    status: Option<&str>,
+) -> Result<Vec<FeedbackEntry>, error::DecapodError> {
     let broker = DbBroker::new(&store.root);
     let db_path = feedback_db_path(&store.root);
+    let status = status.unwrap_or("open");
+    if !["all", "open", "promoted", "incorporated"].contains(&status)
+        && !FEEDBACK_OUTCOMES.contains(&status)
+    {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Invalid feedback status '{}': expected open, addressed, rejected, promoted, incorporated, or all",
+            status
+        )));
+    }
 
-    let entries = broker.with_conn(&db_path, "decapod", None, "feedback.list", |conn| {
-        let mut stmt = conn.prepare("SELECT id, source, text, links, created_at FROM feedback ORDER BY created_at DESC LIMIT 10")?;
-        let rows = stmt.query_map([], |row| {
-            Ok(FeedbackEntry {
-                id: row.get(0)?,
-                source: row.get(1)?,
-                text: row.get(2)?,
-                links: row.get(3)?,
-                created_at: row.get(4)?,
-            })
-        })?;
+    broker.with_conn(&db_path, "decapod", None, "feedback.list", |conn| {
+        let sql = if status == "all" {
+            format!(
+                "SELECT {} FROM feedback ORDER BY created_at DESC",
+                FEEDBACK_COLUMNS
+            )
+        } else {
+            format!(
+                "SELECT {} FROM feedback WHERE status = ?1 ORDER BY created_at DESC",
+                FEEDBACK_COLUMNS
+            )
+        };
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = if status == "all" {
+            stmt.query_map([], feedback_from_row)?
+        } else {
+            stmt.query_map(params![status], feedback_from_row)?
+        };
         let mut out = Vec::new();
         for r in rows {
             out.push(r?);
         }
         Ok(out)
+    })
+}
+
+/// Transition an open feedback item to `addressed` or `rejected` and append
+/// the transition to `feedback.events.jsonl`.
+pub fn resolve_feedback(
+    store: &Store,
+    id: &str,
+    outcome: &str,
+    note: Option<&str>,
+) -> Result<FeedbackEntry, error::DecapodError> {
+    if !FEEDBACK_OUTCOMES.contains(&outcome) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Invalid feedback outcome '{}': expected addressed or rejected",
+            outcome
+        )));
+    }
+    let broker = DbBroker::new(&store.root);
+    let db_path = feedback_db_path(&store.root);
+    let now = crate::core::time::now_epoch_z();
+
+    let entry = broker.with_conn(&db_path, "decapod", None, "feedback.resolve", |conn| {
+        let current: Option<String> = conn
+            .query_row(
+                "SELECT status FROM feedback WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match current.as_deref() {
+            None => {
+                return Err(error::DecapodError::ValidationError(format!(
+                    "Feedback {} not found",
+                    id
+                )));
+            }
+            Some("open") => {}
+            Some(other) => {
+                return Err(error::DecapodError::ValidationError(format!(
+                    "Feedback {} is already {}",
+                    id, other
+                )));
+            }
+        }
+        conn.execute(
+            "UPDATE feedback SET status = ?1, resolved_at = ?2, resolution_note = ?3 WHERE id = ?4",
+            params![outcome, now, note, id],
+        )?;
+        let entry = conn.query_row(
+            &format!("SELECT {} FROM feedback WHERE id = ?1", FEEDBACK_COLUMNS),
+            params![id],
+            feedback_from_row,
+        )?;
+        Ok(entry)
     })?;
 
+    append_feedback_event(
+        store,
+        serde_json::json!({
+            "event_id": crate::core::ulid::new_ulid(),
+            "ts": now,
+            "event_type": "feedback.resolve",
+            "feedback_id": id,
+            "outcome": outcome,
+            "note": note,
+        }),
+    )?;
+
+    Ok(entry)
+}
+
+fn append_feedback_event(
+    store: &Store,
+    event: serde_json::Value,
+) -> Result<(), error::DecapodError> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(store.root.join(schemas::FEEDBACK_EVENTS_NAME))
+        .map_err(error::DecapodError::IoError)?;
+    writeln!(file, "{}", event).map_err(error::DecapodError::IoError)?;
+    Ok(())
+}
+
+fn get_feedback(store: &Store, id: &str) -> Result<FeedbackEntry, error::DecapodError> {
+    let broker = DbBroker::new(&store.root);
+    let db_path = feedback_db_path(&store.root);
+    broker
+        .with_conn(&db_path, "decapod", None, "feedback.get", |conn| {
+            Ok(conn
+                .query_row(
+                    &format!("SELECT {} FROM feedback WHERE id = ?1", FEEDBACK_COLUMNS),
+                    params![id],
+                    feedback_from_row,
+                )
+                .optional()?)
+        })?
+        .ok_or_else(|| error::DecapodError::ValidationError(format!("Feedback {} not found", id)))
+}
+
+/// Result of promoting a feedback item into a tracked task.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PromotionResult {
+    pub feedback: FeedbackEntry,
+    pub task_id: String,
+    pub edge_id: Option<String>,
+}
+
+/// Turn an open feedback item into a todo. The task's `ref` is
+/// `feedback:<id>` for provenance, a federation `relates_to` edge links the
+/// feedback node to the task's node, and the feedback becomes `promoted`.
+pub fn promote_feedback(
+    store: &Store,
+    id: &str,
+    priority: &str,
+    category: Option<&str>,
+) -> Result<PromotionResult, error::DecapodError> {
+    let entry = get_feedback(store, id)?;
+    if entry.status != "open" {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Feedback {} is already {}",
+            id, entry.status
+        )));
+    }
+
+    // Claim the feedback before creating the task so a concurrent promote or
+    // resolve cannot also act on it; the claim is released if linking fails.
+    let now = crate::core::time::now_epoch_z();
+    let broker = DbBroker::new(&store.root);
+    let db_path = feedback_db_path(&store.root);
+    let claimed = broker.with_conn(&db_path, "decapod", None, "feedback.promote", |conn| {
+        Ok(conn.execute(
+            "UPDATE feedback SET status = 'promoted', resolved_at = ?1, resolution_note = 'promoting'
+             WHERE id = ?2 AND status = 'open'",
+            params![now, id],
+        )?)
+    })?;
+    if claimed == 0 {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Feedback {} is no longer open",
+            id
+        )));
+    }
+
+    let (task_id, edge_id) = match create_promoted_task(store, id, &entry, priority, category) {
+        Ok(linked) => linked,
+        Err(e) => {
+            broker.with_conn(&db_path, "decapod", None, "feedback.promote", |conn| {
+                Ok(conn.execute(
+                    "UPDATE feedback SET status = 'open', resolved_at = NULL, resolution_note = NULL
+                     WHERE id = ?1 AND status = 'promoted'",
+                    params![id],
+                )?)
+            })?;
+            return Err(e);
+        }
+    };
+
+    let note = format!("promoted to {}", task_id);
+    broker.with_conn(&db_path, "decapod", None, "feedback.promote", |conn| {
+        Ok(conn.execute(
+            "UPDATE feedback SET resolution_note = ?1 WHERE id = ?2",
+            params![note, id],
+        )?)
+    })?;
+    append_feedback_event(
+        store,
+        serde_json::json!({
+            "event_id": crate::core::ulid::new_ulid(),
+            "ts": now,
+            "event_type": "feedback.promote",
+            "feedback_id": id,
+            "task_id": task_id,
+            "edge_id": edge_id,
+        }),
+    )?;
+
+    Ok(PromotionResult {
+        feedback: get_feedback(store, id)?,
+        task_id,
+        edge_id,
+    })
+}
+
+/// Create the todo for a claimed feedback item and link it in the federation
+/// graph. If anything after the task insert fails, the task is archived so no
+/// unlinked todo is left behind.
+fn create_promoted_task(
+    store: &Store,
+    id: &str,
+    entry: &FeedbackEntry,
+    priority: &str,
+    category: Option<&str>,
+) -> Result<(String, Option<String>), error::DecapodError> {
+    let first_line = entry.text.lines().next().unwrap_or("").trim();
+    let mut title: String = first_line.chars().take(80).collect();
+    if title.is_empty() {
+        title = format!("Feedback {}", id);
+    }
+    let add = todo::TodoCommand::Add {
+        title,
+        description: format!(
+            "{}\n\nPromoted from feedback {} ({}).",
+            entry.text, id, entry.source
+        ),
+        priority: priority.to_string(),
+        tags: "feedback".to_string(),
+        owner: String::new(),
+        due: None,
+        r#ref: format!("feedback:{}", id),
+        dir: None,
+        depends_on: String::new(),
+        blocks: String::new(),
+        parent: None,
+        one_shot: 0,
+    };
+    let added = todo::add_task(&store.root, &add)?;
+    let task_id = added
+        .get("id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| error::DecapodError::ValidationError("todo.add returned no id".into()))?
+        .to_string();
+
+    let link = || -> Result<Option<String>, error::DecapodError> {
+        if let Some(cat) = category.filter(|c| !c.is_empty()) {
+            todo::edit_task(&store.root, &task_id, None, None, None, Some(cat))?;
+        }
+        let node = federation::add_node(
+            store,
+            &format!("Feedback: {}", entry.text.lines().next().unwrap_or("")),
+            "observation",
+            "notable",
+            "agent_inferred",
+            &entry.text,
+            &format!("event:feedback:{}", id),
+            "feedback",
+            "repo",
+            None,
+            "decapod",
+        )?;
+        let edge_id = match federation::find_node_by_source(store, &format!("event:{}", task_id))? {
+            Some(task_node) => Some(federation::add_edge(
+                store,
+                &node.id,
+                &task_node,
+                "relates_to",
+            )?),
+            None => None,
+        };
+        let _ = federation::refresh_derived_files(store);
+        Ok(edge_id)
+    };
+    match link() {
+        Ok(edge_id) => Ok((task_id, edge_id)),
+        Err(e) => {
+            let _ = todo::discard_created_task(
+                store,
+                &task_id,
+                serde_json::json!({ "reason": "feedback_promote_rollback", "feedback_id": id }),
+            );
+            Err(e)
+        }
+    }
+}
+
+/// A preference update derived from one or more open feedback items.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProposedPreference {
+    pub category: String,
+    pub key: String,
+    pub value: String,
+    pub feedback_ids: Vec<String>,
+}
+
+/// Result of `feedback propose --apply`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProposalApplyResult {
+    pub applied: Vec<AppliedPreference>,
+    pub incorporated: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppliedPreference {
+    pub preference_id: String,
+    pub category: String,
+    pub key: String,
+    pub value: String,
+    pub feedback_ids: Vec<String>,
+}
+
+fn preference_slug(text: &str) -> String {
+    let mut slug = String::new();
+    for word in text.split(|c: char| !c.is_ascii_alphanumeric()) {
+        if word.is_empty() {
+            continue;
+        }
+        if !slug.is_empty() {
+            slug.push('_');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    slug.chars().take(64).collect()
+}
+
+/// Derive preference updates from up to ten open feedback items by running
+/// their text through the aptitude preference patterns. Patterns that name a
+/// preference slot (e.g. `git.ssh_key`) fill it; free-form always/never/prefer
+/// statements land under the `feedback` category keyed by the statement.
+pub fn derive_preference_updates(
+    store: &Store,
+) -> Result<(Vec<FeedbackEntry>, Vec<ProposedPreference>), error::DecapodError> {
+    let mut entries = list_feedback(store, Some("open"))?;
+    entries.truncate(10);
+    if entries.is_empty() {
+        return Ok((entries, Vec::new()));
+    }
+
+    aptitude::initialize_aptitude_db(&store.root)?;
+    let mut proposals: Vec<ProposedPreference> = Vec::new();
+    for entry in &entries {
+        for (pattern, captures) in aptitude::match_patterns(store, &entry.text)? {
+            for capture in captures {
+                let value = capture.trim().to_string();
+                let (category, key) = match (&pattern.preference_category, &pattern.preference_key)
+                {
+                    (Some(cat), Some(key)) => (cat.clone(), key.clone()),
+                    _ => {
+                        let kind = pattern.name.trim_end_matches("_statement");
+                        (
+                            "feedback".to_string(),
+                            format!("{}_{}", kind, preference_slug(&value)),
+                        )
+                    }
+                };
+                match proposals
+                    .iter_mut()
+                    .find(|p| p.category == category && p.key == key)
+                {
+                    Some(existing) => {
+                        if !existing.feedback_ids.contains(&entry.id) {
+                            existing.feedback_ids.push(entry.id.clone());
+                        }
+                    }
+                    None => proposals.push(ProposedPreference {
+                        category,
+                        key,
+                        value,
+                        feedback_ids: vec![entry.id.clone()],
+                    }),
+                }
+            }
+        }
+    }
+
+    Ok((entries, proposals))
+}
+
+pub fn propose_prefs(store: &Store) -> Result<String, error::DecapodError> {
+    // This generates a proposal text based on feedback.
+    // It MUST NOT edit files directly; `apply_proposed_prefs` is the write path.
+    // Resolved feedback has already been acted on and is not cited again.
+    let (entries, proposals) = derive_preference_updates(store)?;
+
     if entries.is_empty() {
         return Ok("No feedback found to base a proposal on.".to_string());
     }
 
-    let mut proposal = "DECAPOD PREFERENCE PROPOSAL (NON-BINDING)
-"
-    .to_string();
-    proposal.push_str(
-        "============================
-",
-    );
-    proposal.push_str(
-        "Evidence cited:
-",
-    );
+    let mut proposal = "DECAPOD PREFERENCE PROPOSAL (NON-BINDING)\n".to_string();
+    proposal.push_str("============================\n");
+    proposal.push_str("Evidence cited:\n");
     for e in &entries {
+        proposal.push_str(&format!("- [{}] {}: {}\n", e.id, e.source, e.text));
+    }
+    proposal.push_str("\nPROPOSED PREFERENCE UPDATES:\n");
+    if proposals.is_empty() {
+        proposal.push_str("(none: no feedback states an always/never/prefer preference)\n");
+    }
+    for p in &proposals {
         proposal.push_str(&format!(
-            "- [{}] {}: {}
-",
-            e.id, e.source, e.text
+            "+ {}.{} = {} (from {})\n",
+            p.category,
+            p.key,
+            p.value,
+            p.feedback_ids.join(", ")
         ));
     }
-    proposal.push_str(
-        "
-PROPOSED DIFF (against SYSTEM.md):
-",
-    );
-    proposal.push_str(
-        "--- SYSTEM.md
-+++ SYSTEM.md
-",
-    );
-    proposal.push_str(
-        "@@ -10,1 +10,1 @@
-",
-    );
-    proposal.push_str(
-        "- [Placeholder rule]
-+ [New rule derived from feedback]
-",
-    );
+    if !proposals.is_empty() {
+        proposal.push_str("\nRe-run with --apply to record these preferences.\n");
+    }
 
     Ok(proposal)
 }
 
+/// Write the proposed preferences into the aptitude store and mark the
+/// source feedback `incorporated`. Each preference's `source` is
+/// `feedback:<id>[,<id>...]` and a `feedback -> preference` consolidation is
+/// recorded per source item, so the update traces back to its evidence.
+///
+/// The preferences and consolidations commit as one transaction, then the
+/// feedback rows flip in a second one; a failure before that leaves the
+/// feedback open, so re-applying converges on the same preferences.
+pub fn apply_proposed_prefs(store: &Store) -> Result<ProposalApplyResult, error::DecapodError> {
+    let (_, proposals) = derive_preference_updates(store)?;
+
+    let batch: Vec<aptitude::ConsolidatedPreference> = proposals
+        .iter()
+        .map(|p| aptitude::ConsolidatedPreference {
+            input: aptitude::PreferenceInput {
+                category: p.category.clone(),
+                key: p.key.clone(),
+                value: p.value.clone(),
+                context: Some("derived from operator feedback".to_string()),
+                source: format!("feedback:{}", p.feedback_ids.join(",")),
+                confidence: None,
+            },
+            sources: p
+                .feedback_ids
+                .iter()
+                .map(|fid| ("feedback".to_string(), fid.clone()))
+                .collect(),
+        })
+        .collect();
+    let preference_ids =
+        aptitude::add_consolidated_preferences(store, &batch, Some("feedback propose --apply"))?;
+
+    let mut applied = Vec::new();
+    let mut incorporated: Vec<String> = Vec::new();
+    for (p, preference_id) in proposals.into_iter().zip(preference_ids) {
+        for fid in &p.feedback_ids {
+            if !incorporated.contains(fid) {
+                incorporated.push(fid.clone());
+            }
+        }
+        applied.push(AppliedPreference {
+            preference_id,
+            category: p.category,
+            key: p.key,
+            value: p.value,
+            feedback_ids: p.feedback_ids,
+        });
+    }
+
+    let now = crate::core::time::now_epoch_z();
+    let notes: Vec<(String, Vec<String>)> = incorporated
+        .iter()
+        .map(|fid| {
+            let keys = applied
+                .iter()
+                .filter(|a| a.feedback_ids.contains(fid))
+                .map(|a| format!("{}.{}", a.category, a.key))
+                .collect();
+            (fid.clone(), keys)
+        })
+        .collect();
+    let broker = DbBroker::new(&store.root);
+    let db_path = feedback_db_path(&store.root);
+    broker.with_conn(&db_path, "decapod", None, "feedback.incorporate", |conn| {
+        let tx =
+            rusqlite::Transaction::new_unchecked(conn, rusqlite::TransactionBehavior::Immediate)?;
+        for (fid, keys) in &notes {
+            tx.execute(
+                "UPDATE feedback SET status = 'incorporated', resolved_at = ?1, resolution_note = ?2 WHERE id = ?3 AND status = 'open'",
+                params![now, format!("incorporated into {}", keys.join(", ")), fid],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    })?;
+    for (fid, keys) in &notes {
+        append_feedback_event(
+            store,
+            serde_json::json!({
+                "event_id": crate::core::ulid::new_ulid(),
+                "ts": now,
+                "event_type": "feedback.incorporate",
+                "feedback_id": fid,
+                "preferences": keys,
+            }),
+        )?;
+    }
+
+    Ok(ProposalApplyResult {
+        applied,
+        incorporated,
+    })
+}
+
 pub fn schema() -> serde_json::Value {
     serde_json::json!({
         "name": "feedback",
@@ -127,8 +627,11 @@ pub fn schema() -> serde_json::Value {
         "description": "Append-only operator feedback ledger",
         "commands": [
             { "name": "add", "parameters": ["source", "text", "links"] },
-            { "name": "propose", "description": "Generate a preference proposal based on feedback" }
+            { "name": "list", "parameters": ["status"], "description": "List feedback (open by default; status=all for everything)" },
+            { "name": "resolve", "parameters": ["id", "outcome", "note"], "description": "Mark feedback addressed or rejected" },
+            { "name": "promote", "parameters": ["id", "priority", "category"], "description": "Create a linked todo from feedback and mark it promoted" },
+            { "name": "propose", "parameters": ["apply"], "description": "Propose preference updates from open feedback; --apply records them and marks the feedback incorporated" }
         ],
-        "storage": ["feedback.db"]
+        "storage": ["feedback.db", "feedback.events.jsonl"]
     })
 }
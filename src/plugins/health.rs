@@ -3,7 +3,7 @@ use crate::core::error;
 use crate::core::schemas;
 use crate::core::store::Store;
 use crate::plugins::{policy, watcher};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use rusqlite::params;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -62,12 +62,35 @@ pub enum HealthCommand {
         id: String,
     },
     /// Show system health summary (aggregates health, policy, watcher status).
-    Summary,
+    Summary {
+        #[clap(long, value_enum, default_value = "json")]
+        format: SummaryFormat,
+    },
+    /// Show a claim's proof history in order and flag pass->fail regressions.
+    Trend {
+        #[clap(long)]
+        claim: String,
+    },
     /// Show agent autonomy status based on proof history.
     Autonomy {
         #[clap(long, default_value = "decapod")]
         id: String,
     },
+    /// List persisted assurance attestations (newest last).
+    Attestations {
+        /// Only show attestations for this evaluated op
+        #[clap(long)]
+        op: Option<String>,
+        /// Maximum number of most recent attestations to show
+        #[clap(long, default_value = "50")]
+        limit: usize,
+    },
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum SummaryFormat {
+    Text,
+    Json,
 }
 
 pub fn run_health_cli(store: &Store, cli: HealthCli) -> Result<(), error::DecapodError> {
@@ -95,14 +118,41 @@ pub fn run_health_cli(store: &Store, cli: HealthCli) -> Result<(), error::Decapo
             let (state, reason) = get_health(store, &id)?;
             println!("Claim: {}\nHealth: {:?}\nReason: {}", id, state, reason);
         }
-        HealthCommand::Summary => {
-            let summary = get_summary(store)?;
-            println!("{}", serde_json::to_string_pretty(&summary).unwrap());
+        HealthCommand::Summary { format } => {
+            let report = get_summary_report(store)?;
+            match format {
+                SummaryFormat::Json => {
+                    println!("{}", serde_json::to_string_pretty(&report).unwrap())
+                }
+                SummaryFormat::Text => print_summary_text(&report),
+            }
+        }
+        HealthCommand::Trend { claim } => {
+            let trend = get_trend(store, &claim)?;
+            println!("{}", serde_json::to_string_pretty(&trend).unwrap());
         }
         HealthCommand::Autonomy { id } => {
             let status = get_autonomy(store, &id)?;
             println!("{}", serde_json::to_string_pretty(&status).unwrap());
         }
+        HealthCommand::Attestations { op, limit } => {
+            let repo_root = store
+                .root
+                .parent()
+                .and_then(|p| p.parent())
+                .ok_or_else(|| {
+                    error::DecapodError::ValidationError(
+                        "Attestations require a repo-scoped store".to_string(),
+                    )
+                })?;
+            let mut records = crate::core::assurance::list_attestation_records(repo_root)?;
+            if let Some(op) = op {
+                records.retain(|r| r.evaluated_op == op);
+            }
+            let skip = records.len().saturating_sub(limit);
+            let records: Vec<_> = records.into_iter().skip(skip).collect();
+            println!("{}", serde_json::to_string_pretty(&records).unwrap());
+        }
     }
     Ok(())
 }
@@ -127,6 +177,57 @@ pub struct SummaryStatus {
     pub alerts: Vec<String>,
 }
 
+/// Version of the `govern health summary --format json` contract.
+pub const HEALTH_SUMMARY_SCHEMA_VERSION: &str = "1.0.0";
+
+/// Machine-readable health summary. This is the consolidation point for the
+/// retired `heartbeat` (presence) and `trust` (autonomy) commands.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SummaryReport {
+    pub schema_version: String,
+    pub ts: String,
+    /// Claim count per health state, as in the pre-versioned summary.
+    pub health_summary: std::collections::BTreeMap<String, usize>,
+    pub claims: Vec<ClaimSummary>,
+    pub totals: ClaimTotals,
+    pub pending_approvals: usize,
+    pub watcher_last_run: Option<String>,
+    pub watcher_stale: bool,
+    pub alerts: Vec<String>,
+    pub presence: PresenceRollup,
+    pub autonomy: AutonomyStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClaimSummary {
+    pub id: String,
+    /// `pass` (VERIFIED), `fail` (CONTRADICTED), or `unknown` (ASSERTED/STALE).
+    pub status: String,
+    pub state: HealthState,
+    pub last_checked: Option<String>,
+    pub proof: Option<ProofRef>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProofRef {
+    pub surface: String,
+    pub result: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ClaimTotals {
+    pub pass: usize,
+    pub fail: usize,
+    pub unknown: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PresenceRollup {
+    pub total: usize,
+    pub active: usize,
+    pub expired: usize,
+}
+
 // ===== Autonomy (formerly trust) =====
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
@@ -190,8 +291,10 @@ pub fn compute_health(
         );
     }
 
-    // Sort by timestamp descending
+    // Sort by timestamp descending. Callers pass events oldest-first, so
+    // reversing before the stable sort keeps same-second events newest-first.
     let mut sorted_events = events.to_vec();
+    sorted_events.reverse();
     sorted_events.sort_by(|a, b| b.ts.cmp(&a.ts));
 
     let latest = &sorted_events[0];
@@ -291,7 +394,7 @@ pub fn get_health(
             }),
         ).map_err(|_| error::DecapodError::ValidationError(format!("Claim not found: {}", claim_id)))?;
 
-        let mut stmt = conn.prepare("SELECT event_id, claim_id, ts, surface, result, sla_seconds FROM proof_events WHERE claim_id = ?1")?;
+        let mut stmt = conn.prepare("SELECT event_id, claim_id, ts, surface, result, sla_seconds FROM proof_events WHERE claim_id = ?1 ORDER BY ts, rowid")?;
         let event_iter = stmt.query_map(params![claim.id], |row| {
             Ok(ProofEvent {
                 event_id: row.get(0)?,
@@ -316,6 +419,70 @@ pub fn get_health(
     })
 }
 
+/// One proof evaluation in a claim's history.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrendPoint {
+    pub ts: String,
+    pub surface: String,
+    pub result: String,
+    /// True when this evaluation failed and the previous one passed.
+    pub regression: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClaimTrend {
+    pub claim_id: String,
+    pub current_state: HealthState,
+    pub evaluations: Vec<TrendPoint>,
+    pub regressions: usize,
+}
+
+/// Replay a claim's proof events oldest-first. `proof_events` is the
+/// append-only history, so the trend is derived rather than stored.
+pub fn get_trend(store: &Store, claim_id: &str) -> Result<ClaimTrend, error::DecapodError> {
+    let (current_state, _) = get_health(store, claim_id)?;
+    let broker = DbBroker::new(&store.root);
+    let db_path = health_db_path(&store.root);
+
+    broker.with_conn(&db_path, "decapod", None, "health.trend", |conn| {
+        let id: String = conn.query_row(
+            "SELECT id FROM claims WHERE id = ?1 OR subject = ?1",
+            params![claim_id],
+            |row| row.get(0),
+        )?;
+        let mut stmt = conn.prepare(
+            "SELECT ts, surface, result FROM proof_events WHERE claim_id = ?1 ORDER BY ts, rowid",
+        )?;
+        let rows = stmt.query_map(params![id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+
+        let mut evaluations: Vec<TrendPoint> = Vec::new();
+        for row in rows {
+            let (ts, surface, result) = row?;
+            let regression =
+                result == "fail" && evaluations.last().is_some_and(|prev| prev.result == "pass");
+            evaluations.push(TrendPoint {
+                ts,
+                surface,
+                result,
+                regression,
+            });
+        }
+        let regressions = evaluations.iter().filter(|p| p.regression).count();
+        Ok(ClaimTrend {
+            claim_id: id,
+            current_state,
+            evaluations,
+            regressions,
+        })
+    })
+}
+
 pub fn get_all_health(
     store: &Store,
 ) -> Result<Vec<(String, HealthState, String)>, error::DecapodError> {
@@ -343,7 +510,7 @@ pub fn get_all_health(
         let mut results = Vec::new();
         for claim_res in claim_iter {
             let claim = claim_res?;
-            let mut ev_stmt = conn.prepare("SELECT event_id, claim_id, ts, surface, result, sla_seconds FROM proof_events WHERE claim_id = ?1")?;
+            let mut ev_stmt = conn.prepare("SELECT event_id, claim_id, ts, surface, result, sla_seconds FROM proof_events WHERE claim_id = ?1 ORDER BY ts, rowid")?;
             let event_iter = ev_stmt.query_map(params![claim.id], |row| {
                 Ok(ProofEvent {
                     event_id: row.get(0)?,
@@ -439,6 +606,122 @@ pub fn get_summary(store: &Store) -> Result<SummaryStatus, error::DecapodError>
     })
 }
 
+pub fn get_summary_report(store: &Store) -> Result<SummaryReport, error::DecapodError> {
+    let summary = get_summary(store)?;
+
+    let broker = DbBroker::new(&store.root);
+    let db_path = health_db_path(&store.root);
+    let latest = broker.with_conn(&db_path, "decapod", None, "health.summary", |conn| {
+        let mut stmt = conn
+            .prepare("SELECT claim_id, ts, surface, result FROM proof_events ORDER BY ts, rowid")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?;
+        let mut latest = std::collections::HashMap::new();
+        for row in rows {
+            let (claim_id, ts, surface, result) = row?;
+            latest.insert(claim_id, (ts, ProofRef { surface, result }));
+        }
+        Ok(latest)
+    })?;
+
+    let mut totals = ClaimTotals::default();
+    let mut claims: Vec<ClaimSummary> = get_all_health(store)?
+        .into_iter()
+        .map(|(id, state, _)| {
+            let status = match state {
+                HealthState::VERIFIED => {
+                    totals.pass += 1;
+                    "pass"
+                }
+                HealthState::CONTRADICTED => {
+                    totals.fail += 1;
+                    "fail"
+                }
+                HealthState::ASSERTED | HealthState::STALE => {
+                    totals.unknown += 1;
+                    "unknown"
+                }
+            };
+            let (last_checked, proof) = match latest.get(&id) {
+                Some((ts, proof)) => (Some(ts.clone()), Some(proof.clone())),
+                None => (None, None),
+            };
+            ClaimSummary {
+                id,
+                status: status.to_string(),
+                state,
+                last_checked,
+                proof,
+            }
+        })
+        .collect();
+    claims.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut presence = PresenceRollup::default();
+    if crate::core::todo::todo_db_path(&store.root).exists() {
+        for agent in crate::core::todo::list_agent_presence(&store.root, None)? {
+            presence.total += 1;
+            if agent.status == "active" {
+                presence.active += 1;
+            } else {
+                presence.expired += 1;
+            }
+        }
+    }
+
+    Ok(SummaryReport {
+        schema_version: HEALTH_SUMMARY_SCHEMA_VERSION.to_string(),
+        ts: summary.ts,
+        health_summary: summary.health_summary.into_iter().collect(),
+        claims,
+        totals,
+        pending_approvals: summary.pending_approvals,
+        watcher_last_run: summary.watcher_last_run,
+        watcher_stale: summary.watcher_stale,
+        alerts: summary.alerts,
+        presence,
+        autonomy: get_autonomy(store, "decapod")?,
+    })
+}
+
+fn print_summary_text(report: &SummaryReport) {
+    println!(
+        "Health: {} pass, {} fail, {} unknown",
+        report.totals.pass, report.totals.fail, report.totals.unknown
+    );
+    for claim in &report.claims {
+        println!(
+            "  {} [{}] last checked: {}",
+            claim.id,
+            claim.status,
+            claim.last_checked.as_deref().unwrap_or("never")
+        );
+    }
+    println!("Pending approvals: {}", report.pending_approvals);
+    println!(
+        "Watcher: {}{}",
+        report.watcher_last_run.as_deref().unwrap_or("never run"),
+        if report.watcher_stale { " (stale)" } else { "" }
+    );
+    println!(
+        "Presence: {} agent(s), {} active, {} expired",
+        report.presence.total, report.presence.active, report.presence.expired
+    );
+    println!(
+        "Autonomy ({}): {}",
+        report.autonomy.actor_id, report.autonomy.tier
+    );
+    for alert in &report.alerts {
+        println!("! {}", alert);
+    }
+}
+
 pub fn get_autonomy(store: &Store, actor_id: &str) -> Result<AutonomyStatus, error::DecapodError> {
     initialize_health_db(&store.root)?;
 
@@ -538,10 +821,12 @@ pub fn health_schema() -> serde_json::Value {
             { "name": "claim", "parameters": ["id", "subject", "kind", "provenance"] },
             { "name": "proof", "parameters": ["claim_id", "surface", "result", "sla"] },
             { "name": "get", "parameters": ["id"] },
-            { "name": "summary", "description": "System health overview (formerly heartbeat)" },
-            { "name": "autonomy", "parameters": ["id"], "description": "Agent autonomy tier (formerly trust)" }
+            { "name": "summary", "parameters": ["format"], "description": "System health overview (formerly heartbeat); --format json emits the versioned SummaryReport" },
+            { "name": "trend", "parameters": ["claim"], "description": "Proof history for a claim with pass->fail regressions flagged" },
+            { "name": "autonomy", "parameters": ["id"], "description": "Agent autonomy tier (formerly trust)" },
+            { "name": "attestations", "parameters": ["op", "limit"], "description": "Persisted assurance attestations" }
         ],
-        "storage": ["health.db"],
+        "storage": ["health.db", ".decapod/records/attestations/"],
         "notes": "Summary consolidates heartbeat; Autonomy consolidates trust"
     })
 }
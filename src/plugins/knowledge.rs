@@ -3,6 +3,7 @@ use crate::core::error;
 use crate::core::store::Store;
 use rusqlite::params;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -22,6 +23,31 @@ pub struct KnowledgeEntry {
     pub created_at: String,
     pub updated_at: Option<String>,
     pub recency_score: Option<f64>,
+    #[serde(default = "default_namespace")]
+    pub namespace: String,
+}
+
+/// Namespace for entries added without one.
+pub const DEFAULT_NAMESPACE: &str = "general";
+
+fn default_namespace() -> String {
+    DEFAULT_NAMESPACE.to_string()
+}
+
+/// Namespaces are slugs, like todo categories: lowercase letters, digits, `-`, `_`.
+pub fn normalize_namespace(namespace: &str) -> Result<String, error::DecapodError> {
+    let namespace = namespace.trim().to_lowercase();
+    if namespace.is_empty()
+        || !namespace
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Invalid knowledge namespace '{}': use lowercase letters, digits, '-' or '_'",
+            namespace
+        )));
+    }
+    Ok(namespace)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +69,8 @@ pub struct AddKnowledgeParams<'a> {
     pub status: &'a str,
     pub ttl_policy: &'a str,
     pub expires_ts: Option<&'a str>,
+    /// Defaults to [`DEFAULT_NAMESPACE`].
+    pub namespace: Option<&'a str>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -57,6 +85,8 @@ pub struct SearchOptions<'a> {
     pub as_of: Option<&'a str>,
     pub window_days: Option<u32>,
     pub rank: &'a str,
+    /// Restrict to one namespace; `None` searches all of them.
+    pub namespace: Option<&'a str>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -111,6 +141,53 @@ pub fn knowledge_db_path(root: &Path) -> PathBuf {
     root.join("knowledge.db")
 }
 
+/// Markdown read from disk for `knowledge add --text-file`.
+#[derive(Debug, Clone)]
+pub struct KnowledgeSourceFile {
+    pub path: String,
+    pub content: String,
+    pub heading: Option<String>,
+}
+
+impl KnowledgeSourceFile {
+    /// `file:<path>@sha256:<hash>`, pinning the entry to the exact bytes read.
+    pub fn provenance(&self) -> String {
+        format!(
+            "file:{}@sha256:{}",
+            self.path,
+            crate::core::project_specs::hash_text(&self.content)
+        )
+    }
+}
+
+pub fn read_text_file(path: &Path) -> Result<KnowledgeSourceFile, error::DecapodError> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        error::DecapodError::ValidationError(format!(
+            "Cannot read knowledge text file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    Ok(KnowledgeSourceFile {
+        path: path.to_string_lossy().to_string(),
+        heading: first_markdown_heading(&content),
+        content,
+    })
+}
+
+/// Text of the first ATX heading (`# Title`, `## Title`, ...), if any.
+pub fn first_markdown_heading(markdown: &str) -> Option<String> {
+    markdown.lines().find_map(|line| {
+        let rest = line.trim_start().trim_start_matches('#');
+        let level = line.trim_start().len() - rest.len();
+        if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with(' ')) {
+            return None;
+        }
+        let title = rest.trim().trim_end_matches('#').trim();
+        (!title.is_empty()).then(|| title.to_string())
+    })
+}
+
 pub fn add_knowledge(
     store: &Store,
     args: AddKnowledgeParams<'_>,
@@ -149,6 +226,8 @@ pub fn add_knowledge(
         parse_epoch_z(expires_ts)?;
     }
 
+    let namespace = normalize_namespace(args.namespace.unwrap_or(DEFAULT_NAMESPACE))?;
+
     if is_procedural_entry_id(args.id) {
         let event_id = args.provenance.strip_prefix("event:").ok_or_else(|| {
             error::DecapodError::ValidationError(
@@ -227,8 +306,8 @@ pub fn add_knowledge(
                         )?;
                         superseded_ids.push(existing_id.clone());
                         conn.execute(
-                            "INSERT INTO knowledge(id, title, content, provenance, claim_id, tags, created_at, updated_at, dir_path, scope, status, merge_key, supersedes_id, ttl_policy, expires_ts)
-                             VALUES(?1, ?2, ?3, ?4, ?5, '', ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+                            "INSERT INTO knowledge(id, title, content, provenance, claim_id, tags, created_at, updated_at, dir_path, scope, status, merge_key, supersedes_id, ttl_policy, expires_ts, namespace)
+                             VALUES(?1, ?2, ?3, ?4, ?5, '', ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
                             params![
                                 args.id,
                                 args.title,
@@ -243,7 +322,8 @@ pub fn add_knowledge(
                                 args.merge_key,
                                 Some(existing_id),
                                 args.ttl_policy,
-                                args.expires_ts
+                                args.expires_ts,
+                                namespace
                             ],
                         )?;
                         action = "superseded".to_string();
@@ -258,8 +338,8 @@ pub fn add_knowledge(
                 }
             } else {
                 conn.execute(
-                    "INSERT INTO knowledge(id, title, content, provenance, claim_id, tags, created_at, updated_at, dir_path, scope, status, merge_key, supersedes_id, ttl_policy, expires_ts)
-                     VALUES(?1, ?2, ?3, ?4, ?5, '', ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+                    "INSERT INTO knowledge(id, title, content, provenance, claim_id, tags, created_at, updated_at, dir_path, scope, status, merge_key, supersedes_id, ttl_policy, expires_ts, namespace)
+                     VALUES(?1, ?2, ?3, ?4, ?5, '', ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
                     params![
                         args.id,
                         args.title,
@@ -274,14 +354,15 @@ pub fn add_knowledge(
                         args.merge_key,
                         Option::<String>::None,
                         args.ttl_policy,
-                        args.expires_ts
+                        args.expires_ts,
+                        namespace
                     ],
                 )?;
             }
         } else {
             conn.execute(
-                "INSERT INTO knowledge(id, title, content, provenance, claim_id, tags, created_at, updated_at, dir_path, scope, status, merge_key, supersedes_id, ttl_policy, expires_ts)
-                 VALUES(?1, ?2, ?3, ?4, ?5, '', ?6, ?7, ?8, ?9, ?10, '', ?11, ?12, ?13)",
+                "INSERT INTO knowledge(id, title, content, provenance, claim_id, tags, created_at, updated_at, dir_path, scope, status, merge_key, supersedes_id, ttl_policy, expires_ts, namespace)
+                 VALUES(?1, ?2, ?3, ?4, ?5, '', ?6, ?7, ?8, ?9, ?10, '', ?11, ?12, ?13, ?14)",
                 params![
                     args.id,
                     args.title,
@@ -295,7 +376,8 @@ pub fn add_knowledge(
                     args.status,
                     Option::<String>::None,
                     args.ttl_policy,
-                    args.expires_ts
+                    args.expires_ts,
+                    namespace
                 ],
             )?;
         }
@@ -308,24 +390,53 @@ pub fn add_knowledge(
     })
 }
 
+/// Ranking modes accepted by knowledge search; `recency` is shorthand for
+/// `recency_decay`.
+pub const SEARCH_RANK_MODES: &[&str] = &["relevance", "recency", "recency_decay"];
+
+pub fn parse_rank_mode(rank: &str) -> Result<&'static str, error::DecapodError> {
+    match rank {
+        "relevance" => Ok("relevance"),
+        "recency" | "recency_decay" => Ok("recency_decay"),
+        other => Err(error::DecapodError::ValidationError(format!(
+            "Invalid rank '{}'. Expected {}",
+            other,
+            SEARCH_RANK_MODES.join("|")
+        ))),
+    }
+}
+
+fn relevance_score(entry: &KnowledgeEntry, query: &str) -> usize {
+    let needle = query.to_lowercase();
+    if needle.is_empty() {
+        return 0;
+    }
+    entry.title.to_lowercase().matches(&needle).count() * 2
+        + entry.content.to_lowercase().matches(&needle).count()
+        + entry.provenance.to_lowercase().matches(&needle).count()
+}
+
 pub fn search_knowledge(
     store: &Store,
     query: &str,
     options: SearchOptions<'_>,
 ) -> Result<Vec<KnowledgeEntry>, error::DecapodError> {
+    let namespace = options.namespace.map(normalize_namespace).transpose()?;
     let broker = DbBroker::new(&store.root);
     let db_path = knowledge_db_path(&store.root);
 
     let mut rows = broker.with_conn(&db_path, "decapod", None, "knowledge.search", |conn| {
         let mut stmt = conn.prepare(
             "SELECT id, title, content, provenance, claim_id, created_at, updated_at,
-                    status, merge_key, ttl_policy, expires_ts, supersedes_id
+                    status, merge_key, ttl_policy, expires_ts, supersedes_id, namespace
              FROM knowledge
              WHERE (title LIKE ?1 OR content LIKE ?1 OR provenance LIKE ?1)
-               AND status = 'active'",
+               AND status = 'active'
+               AND (?2 IS NULL OR namespace = ?2)
+             ORDER BY rowid",
         )?;
         let q = format!("%{}%", query);
-        let rows = stmt.query_map(params![q], |row| {
+        let rows = stmt.query_map(params![q, namespace], |row| {
             Ok(KnowledgeEntry {
                 id: row.get(0)?,
                 title: row.get(1)?,
@@ -340,6 +451,7 @@ pub fn search_knowledge(
                 expires_ts: row.get(10)?,
                 supersedes_id: row.get(11)?,
                 recency_score: None,
+                namespace: row.get(12)?,
             })
         })?;
 
@@ -385,8 +497,15 @@ pub fn search_knowledge(
         });
     }
 
+    // Title hits outweigh body/provenance hits; ties keep insertion order.
+    if options.rank == "relevance" {
+        rows.sort_by_key(|e| std::cmp::Reverse(relevance_score(e, query)));
+    }
+
     // Apply recency scoring
     if options.rank == "recency_decay" {
+        // Newest-inserted first so same-second entries still order by recency.
+        rows.reverse();
         let now_secs = {
             use std::time::{SystemTime, UNIX_EPOCH};
             SystemTime::now()
@@ -414,6 +533,173 @@ pub fn search_knowledge(
     Ok(rows)
 }
 
+/// Scopes accepted by `knowledge search --scope`.
+pub const SEARCH_SCOPES: &[&str] = &["repo", "user", "all"];
+
+pub fn parse_search_scope(scope: &str) -> Result<&'static str, error::DecapodError> {
+    SEARCH_SCOPES
+        .iter()
+        .copied()
+        .find(|s| *s == scope)
+        .ok_or_else(|| {
+            error::DecapodError::ValidationError(format!(
+                "Invalid scope '{}'. Expected {}",
+                scope,
+                SEARCH_SCOPES.join("|")
+            ))
+        })
+}
+
+/// A search hit tagged with the store it came from (`repo` or `user`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopedKnowledgeEntry {
+    pub origin: String,
+    #[serde(flatten)]
+    pub entry: KnowledgeEntry,
+}
+
+/// Search the repo store, the user store, or both.
+///
+/// A user store without a `knowledge.db` contributes no results and is not
+/// initialized. Merged results keep the per-store ranking; on equal scores
+/// repo entries sort ahead of user entries.
+pub fn search_knowledge_scoped(
+    repo: &Store,
+    user: Option<&Store>,
+    query: &str,
+    scope: &str,
+    options: SearchOptions<'_>,
+) -> Result<Vec<ScopedKnowledgeEntry>, error::DecapodError> {
+    let scope = parse_search_scope(scope)?;
+    let mut merged = Vec::new();
+    if scope != "user" {
+        merged.extend(
+            search_knowledge(repo, query, options.clone())?
+                .into_iter()
+                .map(|entry| ScopedKnowledgeEntry {
+                    origin: "repo".to_string(),
+                    entry,
+                }),
+        );
+    }
+    if scope != "repo"
+        && let Some(user) = user
+        && knowledge_db_path(&user.root).exists()
+    {
+        // Brings an older user store up to the current columns; never creates one.
+        crate::core::db::initialize_knowledge_db(&user.root)?;
+        merged.extend(
+            search_knowledge(user, query, options.clone())?
+                .into_iter()
+                .map(|entry| ScopedKnowledgeEntry {
+                    origin: "user".to_string(),
+                    entry,
+                }),
+        );
+    }
+
+    // Stable sorts over repo-then-user input keep repo first on ties.
+    if options.rank == "relevance" {
+        merged.sort_by_key(|e| std::cmp::Reverse(relevance_score(&e.entry, query)));
+    } else if options.rank == "recency_decay" {
+        merged.sort_by(|a, b| {
+            b.entry
+                .recency_score
+                .unwrap_or(0.0)
+                .partial_cmp(&a.entry.recency_score.unwrap_or(0.0))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+    Ok(merged)
+}
+
+/// Coverage summary of a knowledge store.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KnowledgeStats {
+    pub total: usize,
+    pub by_status: BTreeMap<String, usize>,
+    pub by_namespace: BTreeMap<String, usize>,
+    /// Keyed by provenance scheme (`file`, `url`, `cmd`, `commit`, `event`).
+    pub by_provenance: BTreeMap<String, usize>,
+    pub oldest: Option<String>,
+    pub newest: Option<String>,
+    /// Entries whose `expires_ts` is at or before `as_of`, whatever their status.
+    pub expired: usize,
+    pub expired_fraction: f64,
+    pub as_of: String,
+}
+
+/// Tally entries by status, namespace, and provenance scheme.
+pub fn knowledge_stats(
+    store: &Store,
+    as_of: Option<&str>,
+) -> Result<KnowledgeStats, error::DecapodError> {
+    let as_of = as_of.map(|s| s.to_string()).unwrap_or_else(now_iso);
+    let as_of_secs = parse_epoch_z(&as_of)?;
+    let broker = DbBroker::new(&store.root);
+    let db_path = knowledge_db_path(&store.root);
+
+    let rows = broker.with_conn(&db_path, "decapod", None, "knowledge.stats", |conn| {
+        let mut stmt = conn.prepare(
+            "SELECT status, namespace, provenance, created_at, expires_ts FROM knowledge",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, Option<String>>(4)?,
+            ))
+        })?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    })?;
+
+    let mut stats = KnowledgeStats {
+        total: rows.len(),
+        by_status: BTreeMap::new(),
+        by_namespace: BTreeMap::new(),
+        by_provenance: BTreeMap::new(),
+        oldest: None,
+        newest: None,
+        expired: 0,
+        expired_fraction: 0.0,
+        as_of,
+    };
+    let mut oldest: Option<(u64, String)> = None;
+    let mut newest: Option<(u64, String)> = None;
+    for (status, namespace, provenance, created_at, expires_ts) in rows {
+        *stats.by_status.entry(status).or_default() += 1;
+        *stats.by_namespace.entry(namespace).or_default() += 1;
+        let scheme = provenance
+            .split_once(':')
+            .map(|(scheme, _)| scheme)
+            .unwrap_or("unknown");
+        *stats.by_provenance.entry(scheme.to_string()).or_default() += 1;
+        if let Some(secs) = crate::core::time::parse_event_ts_epoch(&created_at) {
+            if oldest.as_ref().is_none_or(|(o, _)| secs < *o) {
+                oldest = Some((secs, created_at.clone()));
+            }
+            if newest.as_ref().is_none_or(|(n, _)| secs > *n) {
+                newest = Some((secs, created_at));
+            }
+        }
+        if expires_ts
+            .as_deref()
+            .and_then(crate::core::time::parse_event_ts_epoch)
+            .is_some_and(|exp| exp <= as_of_secs)
+        {
+            stats.expired += 1;
+        }
+    }
+    stats.oldest = oldest.map(|(_, ts)| ts);
+    stats.newest = newest.map(|(_, ts)| ts);
+    if stats.total > 0 {
+        stats.expired_fraction = stats.expired as f64 / stats.total as f64;
+    }
+    Ok(stats)
+}
+
 /// Log a retrieval feedback event (append-only).
 pub fn log_retrieval_feedback(
     store: &Store,
@@ -540,6 +826,202 @@ pub fn decay_knowledge(
     })
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct KnowledgeMergeResult {
+    pub into_id: String,
+    pub from_id: String,
+    pub event_id: String,
+    /// Source paragraphs that were not already in the target.
+    pub paragraphs_added: usize,
+    pub provenance: Vec<String>,
+    pub tags: Vec<String>,
+    pub claim_id: Option<String>,
+    /// Entry the target supersedes, adopted from the source when missing.
+    pub supersedes_id: Option<String>,
+    /// Entries that superseded the source and now supersede the target.
+    pub relinked: Vec<String>,
+}
+
+fn load_entry_with_tags(
+    conn: &rusqlite::Connection,
+    id: &str,
+) -> Result<(KnowledgeEntry, String), error::DecapodError> {
+    use rusqlite::OptionalExtension;
+    conn.query_row(
+        "SELECT id, title, content, provenance, claim_id, created_at, updated_at,
+                status, merge_key, ttl_policy, expires_ts, supersedes_id, namespace, tags
+         FROM knowledge WHERE id = ?1",
+        params![id],
+        |row| {
+            Ok((
+                KnowledgeEntry {
+                    id: row.get(0)?,
+                    title: row.get(1)?,
+                    content: row.get(2)?,
+                    provenance: row.get(3)?,
+                    claim_id: row.get(4)?,
+                    created_at: row.get(5)?,
+                    updated_at: row.get(6)?,
+                    status: row.get(7)?,
+                    merge_key: row.get(8)?,
+                    ttl_policy: row.get(9)?,
+                    expires_ts: row.get(10)?,
+                    supersedes_id: row.get(11)?,
+                    recency_score: None,
+                    namespace: row.get(12)?,
+                },
+                row.get::<_, Option<String>>(13)?.unwrap_or_default(),
+            ))
+        },
+    )
+    .optional()?
+    .ok_or_else(|| error::DecapodError::NotFound(format!("Knowledge entry '{}' not found", id)))
+}
+
+/// Items of `base` followed by those of `extra` not already present, compared
+/// after trimming; empty items are dropped.
+fn union_preserving_order<'a>(
+    base: impl Iterator<Item = &'a str>,
+    extra: impl Iterator<Item = &'a str>,
+) -> (Vec<String>, usize) {
+    let mut merged: Vec<String> = Vec::new();
+    for item in base.map(str::trim).filter(|i| !i.is_empty()) {
+        if !merged.iter().any(|m| m == item) {
+            merged.push(item.to_string());
+        }
+    }
+    let before = merged.len();
+    for item in extra.map(str::trim).filter(|i| !i.is_empty()) {
+        if !merged.iter().any(|m| m == item) {
+            merged.push(item.to_string());
+        }
+    }
+    let added = merged.len() - before;
+    (merged, added)
+}
+
+/// Fold `from_id` into `into_id`: paragraphs of the source missing from the
+/// target are appended, provenance (one reference per line) and tags are
+/// unioned, and the target adopts the source's claim and `supersedes_id` when
+/// it has none. Entries that superseded the source are relinked to the target.
+/// The source is marked `deprecated`, all in one transaction; both originals
+/// are kept in the event appended to `knowledge.merge.events.jsonl`.
+pub fn merge_knowledge(
+    store: &Store,
+    into_id: &str,
+    from_id: &str,
+    actor: &str,
+) -> Result<KnowledgeMergeResult, error::DecapodError> {
+    if into_id == from_id {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Cannot merge knowledge entry '{}' into itself",
+            into_id
+        )));
+    }
+    let broker = DbBroker::new(&store.root);
+    let db_path = knowledge_db_path(&store.root);
+    let now = now_iso();
+
+    let (into_before, from_before, result) =
+        broker.with_conn(&db_path, actor, None, "knowledge.merge", |conn| {
+            let tx = rusqlite::Transaction::new_unchecked(
+                conn,
+                rusqlite::TransactionBehavior::Immediate,
+            )?;
+            let (into, into_tags) = load_entry_with_tags(&tx, into_id)?;
+            let (from, from_tags) = load_entry_with_tags(&tx, from_id)?;
+            for entry in [&into, &from] {
+                if entry.status != "active" {
+                    return Err(error::DecapodError::ValidationError(format!(
+                        "Knowledge entry '{}' is {}; only active entries can be merged",
+                        entry.id, entry.status
+                    )));
+                }
+            }
+
+            let (paragraphs, paragraphs_added) =
+                union_preserving_order(into.content.split("\n\n"), from.content.split("\n\n"));
+            let (provenance, _) =
+                union_preserving_order(into.provenance.lines(), from.provenance.lines());
+            let (tags, _) = union_preserving_order(into_tags.split(','), from_tags.split(','));
+            let claim_id = into.claim_id.clone().or_else(|| from.claim_id.clone());
+            let supersedes_id = into
+                .supersedes_id
+                .clone()
+                .or_else(|| from.supersedes_id.clone())
+                .filter(|id| *id != into.id);
+
+            tx.execute(
+                "UPDATE knowledge
+                 SET content = ?2, provenance = ?3, tags = ?4, claim_id = ?5, supersedes_id = ?6,
+                     updated_at = ?7
+                 WHERE id = ?1",
+                params![
+                    into.id,
+                    paragraphs.join("\n\n"),
+                    provenance.join("\n"),
+                    tags.join(","),
+                    claim_id,
+                    supersedes_id,
+                    now
+                ],
+            )?;
+            tx.execute(
+                "UPDATE knowledge SET status = 'deprecated', updated_at = ?2 WHERE id = ?1",
+                params![from.id, now],
+            )?;
+            let relinked = {
+                let mut stmt = tx.prepare(
+                    "SELECT id FROM knowledge WHERE supersedes_id = ?1 AND id != ?2 ORDER BY id",
+                )?;
+                stmt.query_map(params![from.id, into.id], |row| row.get::<_, String>(0))?
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+            tx.execute(
+                "UPDATE knowledge SET supersedes_id = ?2, updated_at = ?3
+                 WHERE supersedes_id = ?1 AND id != ?2",
+                params![from.id, into.id, now],
+            )?;
+            tx.commit()?;
+
+            let result = KnowledgeMergeResult {
+                into_id: into.id.clone(),
+                from_id: from.id.clone(),
+                event_id: crate::core::ulid::new_ulid(),
+                paragraphs_added,
+                provenance,
+                tags,
+                claim_id,
+                supersedes_id,
+                relinked,
+            };
+            Ok((into, from, result))
+        })?;
+
+    let event = serde_json::json!({
+        "event_id": result.event_id,
+        "ts": now,
+        "op": "knowledge.merge",
+        "actor": actor,
+        "into_id": result.into_id,
+        "from_id": result.from_id,
+        "into_before": into_before,
+        "from_before": from_before,
+        "relinked": result.relinked,
+    });
+    let events_path = store.root.join("knowledge.merge.events.jsonl");
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&events_path)
+        .map_err(error::DecapodError::IoError)?;
+    let line = serde_json::to_string(&event)
+        .map_err(|e| error::DecapodError::ValidationError(format!("JSON error: {}", e)))?;
+    writeln!(file, "{}", line).map_err(error::DecapodError::IoError)?;
+
+    Ok(result)
+}
+
 pub fn record_promotion_event(
     store: &Store,
     input: KnowledgePromotionEventInput<'_>,
@@ -663,13 +1145,15 @@ pub fn schema() -> serde_json::Value {
                     {"name": "id", "required": true, "description": "Unique knowledge entry ID (ULID or UUID)"},
                     {"name": "title", "required": true, "description": "Short, specific title for the entry"},
                     {"name": "text", "required": true, "description": "Main content/markdown body of the knowledge entry"},
+                    {"name": "text_file", "required": false, "description": "CLI only: read the body from a markdown file instead of text; title defaults to its first heading and provenance to file:<path>@sha256:<hash>"},
                     {"name": "provenance", "required": true, "description": "Source reference (file:|url:|cmd:|commit:|event: format required)"},
                     {"name": "claim_id", "required": false, "description": "Optional claim ID this knowledge relates to"},
                     {"name": "merge_key", "required": false, "description": "Deduplication key for merge/supersede"},
                     {"name": "on_conflict", "required": false, "description": "Conflict policy: merge|supersede|reject (default: merge)"},
                     {"name": "status", "required": false, "description": "Entry status: active|superseded|deprecated|stale (default: active)"},
                     {"name": "ttl_policy", "required": false, "description": "TTL policy: ephemeral|decay|persistent (default: persistent)"},
-                    {"name": "expires_ts", "required": false, "description": "Expiry timestamp (epoch seconds + Z suffix)"}
+                    {"name": "expires_ts", "required": false, "description": "Expiry timestamp (epoch seconds + Z suffix)"},
+                    {"name": "namespace", "required": false, "description": "Slug grouping related entries (default: general)"}
                 ]
             },
             {
@@ -679,7 +1163,16 @@ pub fn schema() -> serde_json::Value {
                     {"name": "query", "required": true, "description": "Search query for title, content, or provenance"},
                     {"name": "as_of", "required": false, "description": "Temporal cutoff (epoch seconds + Z)"},
                     {"name": "window_days", "required": false, "description": "Recency window in days"},
-                    {"name": "rank", "required": false, "description": "Ranking mode: relevance|recency_decay (default: relevance)"}
+                    {"name": "rank", "required": false, "description": "Ranking mode: relevance|recency|recency_decay (default: relevance)"},
+                    {"name": "scope", "required": false, "description": "Stores to query: repo|user|all (default: repo); results carry an origin tag"},
+                    {"name": "namespace", "required": false, "description": "Only return entries in this namespace (default: all namespaces)"}
+                ]
+            },
+            {
+                "name": "stats",
+                "description": "Count entries by status, namespace, and provenance scheme, with oldest/newest created_at and the expired fraction",
+                "parameters": [
+                    {"name": "as_of", "required": false, "description": "Expiry reference timestamp (epoch seconds + Z; default: now)"}
                 ]
             },
             {
@@ -702,6 +1195,14 @@ pub fn schema() -> serde_json::Value {
                     {"name": "dry_run", "required": false, "description": "Preview without mutating"}
                 ]
             },
+            {
+                "name": "merge",
+                "description": "Fold a duplicate entry into another: de-duplicated paragraphs, unioned provenance and tags; the source is deprecated and both originals are kept in knowledge.merge.events.jsonl",
+                "parameters": [
+                    {"name": "into", "required": true, "description": "Entry that keeps the combined content"},
+                    {"name": "from", "required": true, "description": "Duplicate entry to deprecate; must differ from into"}
+                ]
+            },
             {
                 "name": "promote",
                 "description": "Record a promotion firewall event to procedural knowledge class",
@@ -713,10 +1214,32 @@ pub fn schema() -> serde_json::Value {
                 ]
             }
         ],
+        "fields": {
+            "id": { "type": "string", "required": true, "description": "Unique knowledge entry ID (ULID or UUID)" },
+            "title": { "type": "string", "required": true, "description": "Short, specific title for the entry" },
+            "text": { "type": "string", "required": true, "description": "Markdown body of the entry" },
+            "provenance": { "type": "string", "required": true, "description": "Source reference; must start with file:, url:, cmd:, commit:, or event:" },
+            "claim_id": { "type": "string", "description": "Health claim this entry supports" },
+            "merge_key": { "type": "string", "description": "Deduplication key; entries sharing it are merged or superseded" },
+            "conflict_policy": {
+                "type": "string",
+                "enum": ["merge", "supersede", "reject"],
+                "description": "What happens when an active entry already has the same merge_key: merge updates it in place, supersede replaces it, reject fails (default: merge)"
+            },
+            "status": { "type": "string", "enum": ["active", "superseded", "deprecated", "stale"], "description": "Lifecycle state (default: active)" },
+            "ttl_policy": {
+                "type": "string",
+                "enum": ["ephemeral", "decay", "persistent"],
+                "description": "Retention: ephemeral and decay entries are retired by `decay` once expires_ts passes; persistent entries never expire (default: persistent)"
+            },
+            "expires_ts": { "type": "string", "description": "Expiry timestamp (epoch seconds + Z suffix)" },
+            "rank": { "type": "string", "enum": ["relevance", "recency", "recency_decay"], "description": "Search ranking mode: relevance orders by match strength, recency/recency_decay newest first (default: relevance)" }
+        },
         "storage": [
             "knowledge.db",
             "knowledge.retrieval.events.jsonl",
             "knowledge.decay.events.jsonl",
+            "knowledge.merge.events.jsonl",
             "knowledge.promotions.jsonl"
         ]
     })
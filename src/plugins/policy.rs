@@ -33,6 +33,14 @@ pub enum PolicyCommand {
         #[clap(long, default_value = "global")]
         scope: String,
     },
+    /// List approval grants, consumptions, and denials as JSON.
+    Audit {
+        /// Only entries at or after this time (epoch seconds or RFC3339)
+        #[clap(long)]
+        since: Option<String>,
+    },
+    /// Check RISKMAP.json against its schema and report every issue.
+    ValidateRiskmap,
     /// Manage the risk map (blast-radius zones).
     Riskmap {
         #[clap(subcommand)]
@@ -52,13 +60,7 @@ pub fn run_policy_cli(store: &Store, cli: PolicyCli) -> Result<(), error::Decapo
     initialize_policy_db(&store.root)?;
     match cli.command {
         PolicyCommand::Eval { command, path } => {
-            let risk_map_path = store.root.join("RISKMAP.json");
-            let risk_map = if risk_map_path.exists() {
-                let content = std::fs::read_to_string(risk_map_path)?;
-                serde_json::from_str(&content).unwrap_or(RiskMap { zones: vec![] })
-            } else {
-                RiskMap { zones: vec![] }
-            };
+            let risk_map = load_risk_map(&store.root)?;
             let (level, requirements) = eval_risk(&command, path.as_deref(), &risk_map);
             let fingerprint = derive_fingerprint(&command, path.as_deref(), "global");
             let hitl_required = human_in_loop_required(store, "global", level, is_high_risk(level));
@@ -71,8 +73,54 @@ pub fn run_policy_cli(store: &Store, cli: PolicyCli) -> Result<(), error::Decapo
             let approval_id = approve_action(store, &id, None, &actor, &scope)?;
             println!("Action Approved (ID: {})", approval_id);
         }
+        PolicyCommand::Audit { since } => {
+            let since_epoch = match since.as_deref() {
+                Some(raw) => {
+                    Some(crate::core::time::parse_event_ts_epoch(raw).ok_or_else(|| {
+                        error::DecapodError::ValidationError(format!(
+                            "invalid --since '{}': expected epoch seconds or RFC3339",
+                            raw
+                        ))
+                    })?)
+                }
+                None => None,
+            };
+            let entries = audit_approvals(store, since_epoch)?;
+            let out = crate::core::time::command_envelope(
+                "policy.audit",
+                "ok",
+                serde_json::json!({ "since": since, "entries": entries }),
+            );
+            println!("{}", serde_json::to_string_pretty(&out).unwrap());
+        }
+        PolicyCommand::ValidateRiskmap => {
+            let path = risk_map_path(&store.root);
+            let exists = path.exists();
+            let issues = if exists {
+                riskmap_issues(&std::fs::read_to_string(&path)?)
+            } else {
+                Vec::new()
+            };
+            let status = if issues.is_empty() { "ok" } else { "invalid" };
+            let out = crate::core::time::command_envelope(
+                "policy.validate-riskmap",
+                status,
+                serde_json::json!({
+                    "path": path.display().to_string(),
+                    "exists": exists,
+                    "issues": issues,
+                }),
+            );
+            println!("{}", serde_json::to_string_pretty(&out).unwrap());
+            if !issues.is_empty() {
+                return Err(error::DecapodError::ValidationError(format!(
+                    "RISKMAP.json has {} issue(s)",
+                    issues.len()
+                )));
+            }
+        }
         PolicyCommand::Riskmap { command } => {
-            let risk_map_path = store.root.join("RISKMAP.json");
+            let risk_map_path = risk_map_path(&store.root);
             match command {
                 RiskmapSubcommand::Init => {
                     let default_map = RiskMap {
@@ -97,6 +145,7 @@ pub fn run_policy_cli(store: &Store, cli: PolicyCli) -> Result<(), error::Decapo
                 }
                 RiskmapSubcommand::Verify => {
                     if risk_map_path.exists() {
+                        load_risk_map(&store.root)?;
                         println!("Risk map present and readable.");
                     } else {
                         println!("Risk map missing (run `decapod policy riskmap init`).");
@@ -141,6 +190,21 @@ pub struct Approval {
     pub expires_at: Option<String>,
 }
 
+/// One row of `govern policy audit`: a grant from `approvals`, or a
+/// check/denial recorded when a gate looked for approval.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PolicyAuditEntry {
+    /// `granted`, `checked`, or `denied` (older logs may hold `consumed`).
+    pub kind: String,
+    pub ts: String,
+    /// The gated command; grants only keep the fingerprint.
+    pub action: Option<String>,
+    pub action_fingerprint: String,
+    pub approval_id: Option<String>,
+    pub actor: String,
+    pub scope: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RiskZone {
     pub path: String,
@@ -153,6 +217,98 @@ pub struct RiskMap {
     pub zones: Vec<RiskZone>,
 }
 
+pub fn risk_map_path(root: &Path) -> PathBuf {
+    root.join("RISKMAP.json")
+}
+
+/// Schema problems in a RISKMAP.json body; empty means it is valid.
+pub fn riskmap_issues(content: &str) -> Vec<String> {
+    let value: serde_json::Value = match serde_json::from_str(content) {
+        Ok(v) => v,
+        Err(e) => return vec![format!("not valid JSON: {}", e)],
+    };
+    let Some(obj) = value.as_object() else {
+        return vec!["top level must be an object with `zones`".to_string()];
+    };
+
+    let mut issues = Vec::new();
+    for key in obj.keys().filter(|k| k.as_str() != "zones") {
+        issues.push(format!("unknown top-level field `{}`", key));
+    }
+    let Some(zones) = obj.get("zones") else {
+        issues.push("missing `zones` array".to_string());
+        return issues;
+    };
+    let Some(zones) = zones.as_array() else {
+        issues.push("`zones` must be an array".to_string());
+        return issues;
+    };
+
+    let mut seen = std::collections::HashSet::new();
+    for (i, zone) in zones.iter().enumerate() {
+        if let Some(fields) = zone.as_object() {
+            for key in fields
+                .keys()
+                .filter(|k| !matches!(k.as_str(), "path" | "level" | "rules"))
+            {
+                issues.push(format!("zones[{}]: unknown field `{}`", i, key));
+            }
+        }
+        match serde_json::from_value::<RiskZone>(zone.clone()) {
+            Ok(z) => {
+                if z.path.trim().is_empty() {
+                    issues.push(format!("zones[{}]: `path` is empty", i));
+                } else if !seen.insert(z.path.clone()) {
+                    issues.push(format!("zones[{}]: duplicate path `{}`", i, z.path));
+                }
+            }
+            Err(e) => issues.push(format!("zones[{}]: {}", i, e)),
+        }
+    }
+    issues
+}
+
+/// Loads RISKMAP.json strictly: a missing file means no zones, but a malformed
+/// one is an error so risk gating never silently turns off.
+pub fn load_risk_map(root: &Path) -> Result<RiskMap, error::DecapodError> {
+    let path = risk_map_path(root);
+    if !path.exists() {
+        return Ok(RiskMap { zones: vec![] });
+    }
+    let content = std::fs::read_to_string(&path)?;
+    let issues = riskmap_issues(&content);
+    if !issues.is_empty() {
+        return Err(error::DecapodError::ValidationError(format!(
+            "{} is invalid ({}); run `decapod govern policy validate-riskmap`",
+            path.display(),
+            issues.join("; ")
+        )));
+    }
+    serde_json::from_str(&content).map_err(|e| {
+        error::DecapodError::ValidationError(format!("{} is invalid: {}", path.display(), e))
+    })
+}
+
+/// Add `zones` to RISKMAP.json, keeping any zone whose path is already mapped.
+/// Returns how many zones were added.
+pub fn seed_risk_zones(root: &Path, zones: &[RiskZone]) -> Result<usize, error::DecapodError> {
+    let mut map = load_risk_map(root)?;
+    let mut added = 0;
+    for zone in zones {
+        if map.zones.iter().any(|z| z.path == zone.path) {
+            continue;
+        }
+        map.zones.push(zone.clone());
+        added += 1;
+    }
+    if added > 0 {
+        let body = serde_json::to_string_pretty(&map)
+            .map_err(|e| error::DecapodError::ValidationError(e.to_string()))?;
+        std::fs::write(risk_map_path(root), format!("{}\n", body))?;
+    }
+    Ok(added)
+}
+
 pub fn policy_db_path(root: &Path) -> PathBuf {
     root.join(schemas::GOVERNANCE_DB_NAME)
 }
@@ -164,6 +320,7 @@ pub fn initialize_policy_db(root: &Path) -> Result<(), error::DecapodError> {
     broker.with_conn(&db_path, "decapod", None, "policy.init", |conn| {
         conn.execute(schemas::POLICY_DB_SCHEMA_APPROVALS, [])?;
         conn.execute(schemas::POLICY_DB_SCHEMA_INDEX, [])?;
+        conn.execute(schemas::POLICY_DB_SCHEMA_APPROVAL_EVENTS, [])?;
         Ok(())
     })
 }
@@ -292,10 +449,8 @@ fn parse_hitl_directive(line: &str) -> Option<HitlRule> {
         let key = key.trim().to_lowercase();
         let value = value.trim();
         match key.as_str() {
-            "scope" => {
-                if !value.is_empty() {
-                    scope = Some(value.to_string());
-                }
+            "scope" if !value.is_empty() => {
+                scope = Some(value.to_string());
             }
             "risk" | "risk_level" => risk_exact = parse_risk_level(value),
             "min_risk" => min_risk = parse_risk_level(value),
@@ -650,6 +805,10 @@ pub fn approve_action(
     Ok(approval_id)
 }
 
+/// Gate check for an approved action. Approvals are reusable, so a check never
+/// consumes one; the first check per actor is recorded in `approval_events` as
+/// `checked` (naming the approval relied on) or `denied`, and repeats of the
+/// same outcome are not logged again.
 pub fn check_approval(
     store: &Store,
     command: &str,
@@ -659,14 +818,42 @@ pub fn check_approval(
     let broker = DbBroker::new(&store.root);
     let db_path = policy_db_path(&store.root);
     let fingerprint = derive_fingerprint(command, target_path, scope);
+    let actor = crate::core::identity::current_agent_id();
+    let now = now_iso();
 
     broker.with_conn(&db_path, "decapod", None, "policy.check", |conn| {
-        let count: i64 = conn.query_row(
-            "SELECT COUNT(*) FROM approvals WHERE action_fingerprint = ?1",
-            params![fingerprint],
-            |row| row.get(0),
+        conn.execute(schemas::POLICY_DB_SCHEMA_APPROVAL_EVENTS, [])?;
+        let approval_id: Option<String> = conn
+            .query_row(
+                "SELECT approval_id FROM approvals WHERE action_fingerprint = ?1 ORDER BY ts DESC, approval_id DESC LIMIT 1",
+                params![fingerprint],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let kind = if approval_id.is_some() {
+            "checked"
+        } else {
+            "denied"
+        };
+        conn.execute(
+            "INSERT INTO approval_events(event_id, kind, action, action_fingerprint, approval_id, actor, scope, ts)
+             SELECT ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM approval_events
+                 WHERE kind = ?2 AND action_fingerprint = ?4 AND approval_id IS ?5 AND actor = ?6
+             )",
+            params![
+                crate::core::ulid::new_ulid(),
+                kind,
+                command,
+                fingerprint,
+                approval_id,
+                actor,
+                scope,
+                now
+            ],
         )?;
-        Ok(count > 0)
+        Ok(approval_id.is_some())
     })
 }
 
@@ -676,7 +863,7 @@ pub fn list_approvals(store: &Store) -> Result<Vec<Approval>, error::DecapodErro
 
     broker.with_conn(&db_path, "decapod", None, "policy.list", |conn| {
         let mut stmt = conn.prepare(
-            "SELECT approval_id, action_id, actor, ts, scope, expires_at FROM approvals",
+            "SELECT approval_id, action_fingerprint, actor, ts, scope, expires_at FROM approvals",
         )?;
         let rows = stmt.query_map([], |row| {
             Ok(Approval {
@@ -696,6 +883,61 @@ pub fn list_approvals(store: &Store) -> Result<Vec<Approval>, error::DecapodErro
     })
 }
 
+/// Grants, checks, and denials in time order, optionally from `since` (epoch secs).
+pub fn audit_approvals(
+    store: &Store,
+    since: Option<u64>,
+) -> Result<Vec<PolicyAuditEntry>, error::DecapodError> {
+    let broker = DbBroker::new(&store.root);
+    let db_path = policy_db_path(&store.root);
+
+    let mut entries: Vec<PolicyAuditEntry> = list_approvals(store)?
+        .into_iter()
+        .map(|a| PolicyAuditEntry {
+            kind: "granted".to_string(),
+            ts: a.ts,
+            action: None,
+            action_fingerprint: a.action_id,
+            approval_id: Some(a.approval_id),
+            actor: a.actor,
+            scope: a.scope,
+        })
+        .collect();
+
+    let events = broker.with_conn(&db_path, "decapod", None, "policy.audit", |conn| {
+        let mut stmt = conn.prepare(
+            "SELECT kind, ts, action, action_fingerprint, approval_id, actor, scope
+             FROM approval_events ORDER BY ts, event_id",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(PolicyAuditEntry {
+                kind: row.get(0)?,
+                ts: row.get(1)?,
+                action: row.get(2)?,
+                action_fingerprint: row.get(3)?,
+                approval_id: row.get(4)?,
+                actor: row.get(5)?,
+                scope: row.get(6)?,
+            })
+        })?;
+        let mut out = Vec::new();
+        for r in rows {
+            out.push(r?);
+        }
+        Ok(out)
+    })?;
+    entries.extend(events);
+
+    let epoch = |e: &PolicyAuditEntry| crate::core::time::parse_event_ts_epoch(&e.ts).unwrap_or(0);
+    if let Some(since) = since {
+        entries.retain(|e| epoch(e) >= since);
+    }
+    // Grants sort before the gate checks that relied on them within the same second.
+    let rank = |e: &PolicyAuditEntry| u8::from(e.kind != "granted");
+    entries.sort_by_key(|e| (epoch(e), rank(e)));
+    Ok(entries)
+}
+
 fn now_iso() -> String {
     crate::core::time::now_epoch_z()
 }
@@ -707,7 +949,9 @@ pub fn schema() -> serde_json::Value {
         "description": "Risk classification and approval engine",
         "commands": [
             { "name": "eval", "parameters": ["command", "path"] },
-            { "name": "approve", "parameters": ["action_id", "actor", "scope"] }
+            { "name": "approve", "parameters": ["action_id", "actor", "scope"] },
+            { "name": "audit", "parameters": ["since"] },
+            { "name": "validate-riskmap", "parameters": [] }
         ],
         "storage": ["policy.db", "RISKMAP.json"]
     })
@@ -9,6 +9,8 @@ use rusqlite::{Result, types::ToSql};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 fn reflex_db_path(root: &Path) -> PathBuf {
@@ -22,11 +24,34 @@ pub fn initialize_reflex_db(root: &Path) -> Result<(), error::DecapodError> {
     broker.with_conn(&db_path, "decapod", None, "reflex.init", |conn| {
         conn.execute(schemas::REFLEX_DB_SCHEMA, [])
             .map_err(error::DecapodError::RusqliteError)?;
+        ensure_reflex_columns(conn).map_err(error::DecapodError::RusqliteError)?;
         Ok(())
     })?;
     Ok(())
 }
 
+/// Add columns introduced after the original `reflexes` table.
+fn ensure_reflex_columns(conn: &rusqlite::Connection) -> Result<(), rusqlite::Error> {
+    let has_enabled = |conn: &rusqlite::Connection| -> Result<bool, rusqlite::Error> {
+        let mut stmt = conn.prepare("PRAGMA table_info(reflexes)")?;
+        let cols = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(cols.iter().any(|c| c == "enabled"))
+    };
+    if has_enabled(conn)? {
+        return Ok(());
+    }
+    // A concurrent process may add the column between the check and the ALTER.
+    match conn.execute(
+        "ALTER TABLE reflexes ADD COLUMN enabled INTEGER NOT NULL DEFAULT 1",
+        [],
+    ) {
+        Err(_) if has_enabled(conn)? => Ok(()),
+        other => other.map(|_| ()),
+    }
+}
+
 fn now_iso() -> String {
     crate::core::time::now_epoch_z()
 }
@@ -79,6 +104,85 @@ pub struct Reflex {
     pub updated_at: String,
     pub dir_path: String,
     pub scope: String,
+    /// Cleared by `reflex disarm`; disarmed rules never match.
+    pub enabled: bool,
+}
+
+/// Columns read into a [`Reflex`]; selected by name so added columns never
+/// shift positions.
+const REFLEX_COLUMNS: &str = "id, name, description, trigger_type, trigger_config, action_type, action_config, status, tags, created_at, updated_at, dir_path, scope, enabled";
+
+fn reflex_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Reflex> {
+    Ok(Reflex {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        description: row.get("description")?,
+        trigger_type: row.get("trigger_type")?,
+        trigger_config: row.get("trigger_config")?,
+        action_type: row.get("action_type")?,
+        action_config: row.get("action_config")?,
+        status: row.get("status")?,
+        tags: row.get("tags")?,
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+        dir_path: row.get("dir_path")?,
+        scope: row.get("scope")?,
+        enabled: row.get::<_, i64>("enabled")? != 0,
+    })
+}
+
+/// Append-only record of every reflex action fired, one JSON object per line.
+pub const REFLEX_EXECUTIONS_NAME: &str = "reflex.executions.jsonl";
+
+/// Append-only audit log of rule toggles (`reflex.arm`, `reflex.disarm`).
+pub const REFLEX_EVENTS_NAME: &str = "reflex.events.jsonl";
+
+/// The rule a reflex matched on, as it stood when the reflex fired.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MatchedRule {
+    pub trigger_type: String,
+    pub trigger_config: JsonValue,
+}
+
+/// One fired reflex: the triggering event, the matched rule, the action and its result.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReflexExecution {
+    pub ts: String,
+    pub event_id: String,
+    /// Shared by every reflex fired in the same dispatch.
+    pub firing_id: String,
+    pub trigger_type: String,
+    /// Synthetic event that triggered the dispatch; absent for `reflex run`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<JsonValue>,
+    pub reflex_id: String,
+    pub reflex_name: String,
+    pub matched_rule: MatchedRule,
+    pub action_type: String,
+    /// `ok` or `error`
+    pub status: String,
+    pub result: JsonValue,
+}
+
+/// How one logged dispatch would be handled by the rules active now.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ReplayEntry {
+    pub firing_id: String,
+    pub ts: String,
+    pub trigger_type: String,
+    pub event: Option<JsonValue>,
+    /// Reflex ids that fired at the time.
+    pub fired: Vec<String>,
+    /// Active reflexes whose rule matches the event today; nothing is executed.
+    pub would_fire: Vec<MatchedReflex>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct MatchedReflex {
+    pub reflex_id: String,
+    pub name: String,
+    pub matched_rule: MatchedRule,
+    pub action_type: String,
 }
 
 #[derive(Parser, Debug)]
@@ -158,6 +262,16 @@ pub enum ReflexCommand {
         #[clap(long)]
         id: String,
     },
+    /// Re-enable a disarmed reflex so its rule matches again.
+    Arm {
+        #[clap(long)]
+        id: String,
+    },
+    /// Disable a reflex without deleting it; the matcher skips it until re-armed.
+    Disarm {
+        #[clap(long)]
+        id: String,
+    },
     /// Run active reflex actions by id or trigger type.
     Run {
         #[clap(long)]
@@ -167,6 +281,19 @@ pub enum ReflexCommand {
         #[clap(long, default_value_t = 10)]
         limit: usize,
     },
+    /// Show fired reflexes from the execution log, newest first.
+    History {
+        #[clap(long)]
+        id: Option<String>,
+        #[clap(long, default_value_t = 20)]
+        limit: usize,
+    },
+    /// Re-evaluate active rules against logged events since `--from` (dry run).
+    Replay {
+        /// Epoch seconds, with or without a trailing `Z`.
+        #[clap(long)]
+        from: String,
+    },
     /// Install a canonical human-triggered heartbeat autoclaim reflex.
     AddHeartbeatLoop {
         #[clap(long, default_value = "human-heartbeat-autoclaim")]
@@ -228,9 +355,9 @@ pub fn schema() -> serde_json::Value {
                 "parameters": [
                     {"name": "name", "required": true, "description": "Unique reflex name identifier"},
                     {"name": "description", "required": false, "description": "Human-readable description of the reflex purpose", "default": ""},
-                    {"name": "trigger_type", "required": true, "description": "Type of trigger (e.g., file_change, command_exit, schedule)"},
+                    {"name": "trigger_type", "required": true, "description": "Type of trigger (e.g., human, todo.overdue)"},
                     {"name": "trigger_config", "required": true, "description": "JSON configuration for trigger conditions", "default": "{}"},
-                    {"name": "action_type", "required": true, "description": "Type of action to perform (e.g., notify, exec, webhook)"},
+                    {"name": "action_type", "required": true, "description": "Type of action to perform (todo.comment, todo.heartbeat.autoclaim, todo.human.trigger.loop, todo.health.remediate)"},
                     {"name": "action_config", "required": true, "description": "JSON configuration for the action to execute"},
                     {"name": "status", "required": false, "description": "Initial reflex status", "default": "active"},
                     {"name": "tags", "required": false, "description": "Comma-separated tags for categorization", "default": ""}
@@ -266,6 +393,20 @@ pub fn schema() -> serde_json::Value {
                     {"name": "id", "required": true, "description": "Reflex entry ID to delete"}
                 ]
             },
+            {
+                "name": "arm",
+                "description": "Re-enable a disarmed reflex",
+                "parameters": [
+                    {"name": "id", "required": true, "description": "Reflex entry ID to arm"}
+                ]
+            },
+            {
+                "name": "disarm",
+                "description": "Disable a reflex without deleting it; disarmed reflexes never fire",
+                "parameters": [
+                    {"name": "id", "required": true, "description": "Reflex entry ID to disarm"}
+                ]
+            },
             {
                 "name": "run",
                 "description": "Run active reflex actions by id or trigger type",
@@ -275,6 +416,21 @@ pub fn schema() -> serde_json::Value {
                     {"name": "limit", "required": false, "description": "Maximum reflex actions to run", "default": 10}
                 ]
             },
+            {
+                "name": "history",
+                "description": "Show fired reflexes from reflex.executions.jsonl, newest first",
+                "parameters": [
+                    {"name": "id", "required": false, "description": "Only entries for this reflex ID"},
+                    {"name": "limit", "required": false, "description": "Maximum entries to return", "default": 20}
+                ]
+            },
+            {
+                "name": "replay",
+                "description": "Dry run: re-evaluate active rules against logged triggering events",
+                "parameters": [
+                    {"name": "from", "required": true, "description": "Earliest event timestamp (epoch seconds, optional Z)"}
+                ]
+            },
             {
                 "name": "add-heartbeat-loop",
                 "description": "Install a canonical human-triggered heartbeat autoclaim reflex",
@@ -306,7 +462,7 @@ pub fn schema() -> serde_json::Value {
                 ]
             }
         ],
-        "storage": ["reflex.db"]
+        "storage": ["reflex.db", "reflex.executions.jsonl", "reflex.events.jsonl"]
     })
 }
 
@@ -366,11 +522,15 @@ pub fn run_reflex_cli(store: &Store, cli: ReflexCli) {
             dir,
         } => list_reflexes(root, status, scope, tags, name_search, dir),
         ReflexCommand::Delete { id } => delete_reflex(root, id),
+        ReflexCommand::Arm { id } => set_reflex_enabled(root, &id, true),
+        ReflexCommand::Disarm { id } => set_reflex_enabled(root, &id, false),
         ReflexCommand::Run {
             id,
             trigger_type,
             limit,
         } => run_reflex_actions(root, &id, &trigger_type, &limit),
+        ReflexCommand::History { id, limit } => print_execution_history(root, id.as_deref(), limit),
+        ReflexCommand::Replay { from } => print_replay(root, &from),
         ReflexCommand::AddHeartbeatLoop {
             name,
             agent,
@@ -424,7 +584,9 @@ fn fetch_matching_reflexes(
     let broker = DbBroker::new(root);
     let db_path = reflex_db_path(root);
     broker.with_conn(&db_path, "decapod", None, "reflex.run.scan", |conn| {
-        let mut query = "SELECT id, name, description, trigger_type, trigger_config, action_type, action_config, status, tags, created_at, updated_at, dir_path, scope FROM reflexes WHERE status = 'active'".to_string();
+        let mut query = format!(
+            "SELECT {REFLEX_COLUMNS} FROM reflexes WHERE status = 'active' AND enabled = 1"
+        );
         let mut params: Vec<Box<dyn ToSql>> = Vec::new();
 
         if let Some(i) = id {
@@ -440,23 +602,7 @@ fn fetch_matching_reflexes(
 
         let params_as_dyn: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
         let mut stmt = conn.prepare(&query)?;
-        let rows = stmt.query_map(&params_as_dyn[..], |row| {
-            Ok(Reflex {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                description: row.get(2)?,
-                trigger_type: row.get(3)?,
-                trigger_config: row.get(4)?,
-                action_type: row.get(5)?,
-                action_config: row.get(6)?,
-                status: row.get(7)?,
-                tags: row.get(8)?,
-                created_at: row.get(9)?,
-                updated_at: row.get(10)?,
-                dir_path: row.get(11)?,
-                scope: row.get(12)?,
-            })
-        })?;
+        let rows = stmt.query_map(&params_as_dyn[..], reflex_from_row)?;
         let mut out = Vec::new();
         for row in rows {
             out.push(row?);
@@ -499,15 +645,42 @@ fn run_decapod_command_json(
     }))
 }
 
-fn execute_reflex_action(
+/// Execute a reflex action, optionally in the context of the synthetic event
+/// that triggered it (e.g. `todo.overdue`), so actions can target the task
+/// the event is about.
+fn execute_reflex_action_for_event(
     root: &Path,
     reflex: &Reflex,
+    event: Option<&JsonValue>,
 ) -> Result<serde_json::Value, error::DecapodError> {
     match reflex.action_type.as_str() {
+        "todo.comment" => {
+            let cfg = parse_json_config(&reflex.action_config, "action_config")?;
+            let task_id = cfg
+                .get("id")
+                .and_then(|v| v.as_str())
+                .or_else(|| {
+                    event
+                        .and_then(|e| e.get("task_id"))
+                        .and_then(|v| v.as_str())
+                })
+                .ok_or_else(|| {
+                    error::DecapodError::ValidationError(
+                        "todo.comment requires action_config.id or a triggering task event"
+                            .to_string(),
+                    )
+                })?;
+            let default_comment =
+                format!("Reflex '{}' fired on {}", reflex.name, reflex.trigger_type);
+            let comment = cfg
+                .get("comment")
+                .and_then(|v| v.as_str())
+                .unwrap_or(default_comment.as_str());
+            crate::core::todo::comment_task(root, task_id, comment)
+        }
         "todo.heartbeat.autoclaim" => {
             let cfg = parse_json_config(&reflex.action_config, "action_config")?;
-            let default_agent =
-                env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+            let default_agent = crate::core::identity::current_agent_id();
             let agent = cfg
                 .get("agent")
                 .and_then(|v| v.as_str())
@@ -529,8 +702,7 @@ fn execute_reflex_action(
         }
         "todo.human.trigger.loop" => {
             let cfg = parse_json_config(&reflex.action_config, "action_config")?;
-            let default_agent =
-                env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+            let default_agent = crate::core::identity::current_agent_id();
             let agent = cfg
                 .get("agent")
                 .and_then(|v| v.as_str())
@@ -601,8 +773,7 @@ fn execute_reflex_action(
         }
         "todo.health.remediate" => {
             let cfg = parse_json_config(&reflex.action_config, "action_config")?;
-            let default_agent =
-                env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+            let default_agent = crate::core::identity::current_agent_id();
             let agent = cfg
                 .get("agent")
                 .and_then(|v| v.as_str())
@@ -715,38 +886,212 @@ fn run_reflex_actions(
     limit: &usize,
 ) -> Result<(), error::DecapodError> {
     let reflexes = fetch_matching_reflexes(root, id.clone(), trigger_type.clone(), *limit)?;
+    let results = dispatch_reflexes(root, reflexes, None, None)?;
+
+    println!(
+        "{}",
+        serde_json::json!({
+            "ts": now_iso(),
+            "cmd": "reflex.run",
+            "status": "ok",
+            "count": results.len(),
+            "results": results
+        })
+    );
+    Ok(())
+}
+
+/// Fire every active reflex whose `trigger_type` matches a synthetic event
+/// raised by another subsystem. Returns one result entry per reflex; action
+/// failures are reported in the entry rather than aborting the dispatch.
+pub fn fire_trigger(
+    root: &Path,
+    trigger_type: &str,
+    event: &JsonValue,
+) -> Result<Vec<JsonValue>, error::DecapodError> {
+    if !reflex_db_path(root).exists() {
+        return Ok(Vec::new());
+    }
+    let reflexes = fetch_matching_reflexes(root, None, Some(trigger_type.to_string()), 100)?;
+    dispatch_reflexes(root, reflexes, Some(trigger_type), Some(event))
+}
+
+fn parse_trigger_config(raw: &str) -> JsonValue {
+    serde_json::from_str(raw).unwrap_or_else(|_| JsonValue::String(raw.to_string()))
+}
+
+/// Execute each reflex and append one execution log entry per reflex.
+fn dispatch_reflexes(
+    root: &Path,
+    reflexes: Vec<Reflex>,
+    trigger_type: Option<&str>,
+    event: Option<&JsonValue>,
+) -> Result<Vec<JsonValue>, error::DecapodError> {
+    let firing_id = ulid_like();
     let mut results = Vec::new();
     for reflex in reflexes {
-        let action_result = execute_reflex_action(root, &reflex);
-        match action_result {
-            Ok(payload) => results.push(serde_json::json!({
-                "reflex_id": reflex.id,
-                "name": reflex.name,
-                "trigger_type": reflex.trigger_type,
-                "action_type": reflex.action_type,
-                "result": payload
-            })),
-            Err(e) => results.push(serde_json::json!({
-                "reflex_id": reflex.id,
-                "name": reflex.name,
-                "trigger_type": reflex.trigger_type,
-                "action_type": reflex.action_type,
-                "result": {
-                    "status": "error",
-                    "error": e.to_string()
-                }
-            })),
+        let result = match execute_reflex_action_for_event(root, &reflex, event) {
+            Ok(payload) => payload,
+            Err(e) => serde_json::json!({ "status": "error", "error": e.to_string() }),
+        };
+        let status = match result.get("status").and_then(|v| v.as_str()) {
+            Some("error") => "error",
+            _ => "ok",
+        };
+        append_execution(
+            root,
+            &ReflexExecution {
+                ts: now_iso(),
+                event_id: ulid_like(),
+                firing_id: firing_id.clone(),
+                trigger_type: trigger_type.unwrap_or(&reflex.trigger_type).to_string(),
+                event: event.cloned(),
+                reflex_id: reflex.id.clone(),
+                reflex_name: reflex.name.clone(),
+                matched_rule: MatchedRule {
+                    trigger_type: reflex.trigger_type.clone(),
+                    trigger_config: parse_trigger_config(&reflex.trigger_config),
+                },
+                action_type: reflex.action_type.clone(),
+                status: status.to_string(),
+                result: result.clone(),
+            },
+        )?;
+        results.push(serde_json::json!({
+            "reflex_id": reflex.id,
+            "name": reflex.name,
+            "trigger_type": reflex.trigger_type,
+            "action_type": reflex.action_type,
+            "result": result
+        }));
+    }
+    Ok(results)
+}
+
+fn executions_path(root: &Path) -> PathBuf {
+    root.join(REFLEX_EXECUTIONS_NAME)
+}
+
+fn append_execution(root: &Path, entry: &ReflexExecution) -> Result<(), error::DecapodError> {
+    let mut f = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(executions_path(root))
+        .map_err(error::DecapodError::IoError)?;
+    writeln!(f, "{}", serde_json::to_string(entry).unwrap()).map_err(error::DecapodError::IoError)
+}
+
+fn read_executions(root: &Path) -> Result<Vec<ReflexExecution>, error::DecapodError> {
+    let path = executions_path(root);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let raw = std::fs::read_to_string(&path).map_err(error::DecapodError::IoError)?;
+    Ok(raw
+        .lines()
+        .filter_map(|line| serde_json::from_str::<ReflexExecution>(line).ok())
+        .collect())
+}
+
+/// Logged executions, newest first, optionally only those of reflex `id`.
+pub fn execution_history(
+    root: &Path,
+    id: Option<&str>,
+    limit: usize,
+) -> Result<Vec<ReflexExecution>, error::DecapodError> {
+    Ok(read_executions(root)?
+        .into_iter()
+        .rev()
+        .filter(|e| id.is_none_or(|id| e.reflex_id == id))
+        .take(limit)
+        .collect())
+}
+
+fn parse_epoch(raw: &str) -> Option<u64> {
+    raw.trim().trim_end_matches('Z').parse().ok()
+}
+
+/// Re-evaluate the currently active rules against every dispatch logged at or
+/// after `from` (epoch seconds). Nothing is executed and nothing is logged.
+pub fn replay_executions(root: &Path, from: u64) -> Result<Vec<ReplayEntry>, error::DecapodError> {
+    let mut entries: Vec<ReplayEntry> = Vec::new();
+    for execution in read_executions(root)? {
+        if parse_epoch(&execution.ts).is_none_or(|ts| ts < from) {
+            continue;
+        }
+        match entries
+            .iter_mut()
+            .find(|e| e.firing_id == execution.firing_id)
+        {
+            Some(entry) => entry.fired.push(execution.reflex_id),
+            None => entries.push(ReplayEntry {
+                firing_id: execution.firing_id,
+                ts: execution.ts,
+                trigger_type: execution.trigger_type,
+                event: execution.event,
+                fired: vec![execution.reflex_id],
+                would_fire: Vec::new(),
+            }),
         }
     }
+    if !reflex_db_path(root).exists() {
+        return Ok(entries);
+    }
+    for entry in &mut entries {
+        entry.would_fire =
+            fetch_matching_reflexes(root, None, Some(entry.trigger_type.clone()), 100)?
+                .into_iter()
+                .map(|reflex| MatchedReflex {
+                    matched_rule: MatchedRule {
+                        trigger_type: reflex.trigger_type,
+                        trigger_config: parse_trigger_config(&reflex.trigger_config),
+                    },
+                    reflex_id: reflex.id,
+                    name: reflex.name,
+                    action_type: reflex.action_type,
+                })
+                .collect();
+    }
+    Ok(entries)
+}
 
+fn print_execution_history(
+    root: &Path,
+    id: Option<&str>,
+    limit: usize,
+) -> Result<(), error::DecapodError> {
+    let executions = execution_history(root, id, limit)?;
     println!(
         "{}",
         serde_json::json!({
             "ts": now_iso(),
-            "cmd": "reflex.run",
+            "cmd": "reflex.history",
             "status": "ok",
-            "count": results.len(),
-            "results": results
+            "count": executions.len(),
+            "executions": executions
+        })
+    );
+    Ok(())
+}
+
+fn print_replay(root: &Path, from: &str) -> Result<(), error::DecapodError> {
+    let from_epoch = parse_epoch(from).ok_or_else(|| {
+        error::DecapodError::ValidationError(format!(
+            "invalid --from '{}': expected epoch seconds (e.g. 1700000000Z)",
+            from
+        ))
+    })?;
+    let entries = replay_executions(root, from_epoch)?;
+    println!(
+        "{}",
+        serde_json::json!({
+            "ts": now_iso(),
+            "cmd": "reflex.replay",
+            "status": "ok",
+            "dry_run": true,
+            "from": format!("{}Z", from_epoch),
+            "count": entries.len(),
+            "entries": entries
         })
     );
     Ok(())
@@ -760,7 +1105,7 @@ fn add_heartbeat_loop_reflex(
     tags: &str,
     dir: &Option<String>,
 ) -> Result<(), error::DecapodError> {
-    let default_agent = env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+    let default_agent = crate::core::identity::current_agent_id();
     let agent = agent.clone().unwrap_or(default_agent);
     let trigger_config = serde_json::json!({
         "source": "human",
@@ -797,7 +1142,7 @@ fn add_human_trigger_loop_reflex(
     tags: &str,
     dir: &Option<String>,
 ) -> Result<(), error::DecapodError> {
-    let default_agent = env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+    let default_agent = crate::core::identity::current_agent_id();
     let agent = agent.clone().unwrap_or(default_agent);
     let trigger_config = serde_json::json!({
         "source": "human",
@@ -958,24 +1303,8 @@ fn get_reflex(root: &Path, id: String) -> Result<(), error::DecapodError> {
     let db_path = reflex_db_path(root);
 
     broker.with_conn(&db_path, "decapod", None, "reflex.get", |conn| {
-        let mut stmt = conn.prepare("SELECT * FROM reflexes WHERE id = ?1")?;
-        let mut rows = stmt.query_map([&id], |row| {
-            Ok(Reflex {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                description: row.get(2)?,
-                trigger_type: row.get(3)?,
-                trigger_config: row.get(4)?,
-                action_type: row.get(5)?,
-                action_config: row.get(6)?,
-                status: row.get(7)?,
-                tags: row.get(8)?,
-                created_at: row.get(9)?,
-                updated_at: row.get(10)?,
-                dir_path: row.get(11)?,
-                scope: row.get(12)?,
-            })
-        })?;
+        let mut stmt = conn.prepare(&format!("SELECT {REFLEX_COLUMNS} FROM reflexes WHERE id = ?1"))?;
+        let mut rows = stmt.query_map([&id], reflex_from_row)?;
 
         if let Some(reflex_result) = rows.next() {
             match reflex_result {
@@ -1004,7 +1333,7 @@ fn list_reflexes(
     let db_path = reflex_db_path(root);
 
     broker.with_conn(&db_path, "decapod", None, "reflex.list", |conn| {
-        let mut query = "SELECT * FROM reflexes WHERE 1=1".to_string();
+        let mut query = format!("SELECT {REFLEX_COLUMNS} FROM reflexes WHERE 1=1");
         let mut params: Vec<Box<dyn ToSql>> = Vec::new();
 
         if let Some(s) = status {
@@ -1032,30 +1361,14 @@ fn list_reflexes(
 
         let mut stmt = conn.prepare(&query)?;
         let params_as_dyn: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
-        let rows = stmt.query_map(&params_as_dyn[..], |row| {
-            Ok(Reflex {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                description: row.get(2)?,
-                trigger_type: row.get(3)?,
-                trigger_config: row.get(4)?,
-                action_type: row.get(5)?,
-                action_config: row.get(6)?,
-                status: row.get(7)?,
-                tags: row.get(8)?,
-                created_at: row.get(9)?,
-                updated_at: row.get(10)?,
-                dir_path: row.get(11)?,
-                scope: row.get(12)?,
-            })
-        })?;
+        let rows = stmt.query_map(&params_as_dyn[..], reflex_from_row)?;
 
         println!("Reflexes:");
         for reflex in rows {
             let r = reflex?;
             println!("----------------------------------------------------");
             println!(
-                "ID: {}\nName: {}\nTrigger: {} ({})\nAction: {} ({})\nStatus: {}\nScope: {} (Path: {})\nUpdated: {}",
+                "ID: {}\nName: {}\nTrigger: {} ({})\nAction: {} ({})\nStatus: {}{}\nScope: {} (Path: {})\nUpdated: {}",
                 r.id,
                 r.name,
                 r.trigger_type,
@@ -1063,6 +1376,7 @@ fn list_reflexes(
                 r.action_type,
                 r.action_config,
                 r.status,
+                if r.enabled { "" } else { " (disarmed)" },
                 r.scope,
                 r.dir_path,
                 r.updated_at
@@ -1083,7 +1397,7 @@ fn add_health_trigger_reflex(
     tags: &str,
     dir: &Option<String>,
 ) -> Result<(), error::DecapodError> {
-    let default_agent = env::var("DECAPOD_AGENT_ID").unwrap_or_else(|_| "unknown".to_string());
+    let default_agent = crate::core::identity::current_agent_id();
     let agent = agent.clone().unwrap_or(default_agent);
     let states: Vec<String> = watch_states
         .split(',')
@@ -1147,3 +1461,47 @@ fn delete_reflex(root: &Path, id: String) -> Result<(), error::DecapodError> {
     );
     Ok(())
 }
+
+fn set_reflex_enabled(root: &Path, id: &str, enabled: bool) -> Result<(), error::DecapodError> {
+    let op = if enabled {
+        "reflex.arm"
+    } else {
+        "reflex.disarm"
+    };
+    let broker = DbBroker::new(root);
+    let db_path = reflex_db_path(root);
+    let now = now_iso();
+
+    let changed = broker.with_conn(&db_path, "decapod", None, op, |conn| {
+        Ok(conn.execute(
+            "UPDATE reflexes SET enabled = ?1, updated_at = ?2 WHERE id = ?3",
+            rusqlite::params![enabled as i64, now, id],
+        )?)
+    })?;
+    if changed == 0 {
+        return Err(error::DecapodError::NotFound(format!(
+            "reflex '{}' not found",
+            id
+        )));
+    }
+
+    let event = serde_json::json!({
+        "ts": now,
+        "event_id": ulid_like(),
+        "op": op,
+        "reflex_id": id,
+        "actor": crate::core::identity::current_agent_id(),
+    });
+    let mut f = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(root.join(REFLEX_EVENTS_NAME))
+        .map_err(error::DecapodError::IoError)?;
+    writeln!(f, "{}", event).map_err(error::DecapodError::IoError)?;
+
+    println!(
+        "{}",
+        serde_json::json!({ "ts": now, "cmd": op, "id": id, "enabled": enabled, "status": "ok" })
+    );
+    Ok(())
+}
@@ -23,6 +23,15 @@ pub struct VerifyCli {
     /// List stale items only; do not run verification.
     #[clap(long, global = true)]
     stale: bool,
+    /// Accept the current artifact hashes as the new baseline for `--task`.
+    #[clap(long, requires = "task")]
+    baseline_update: bool,
+    /// TODO whose baseline `--baseline-update` replaces.
+    #[clap(long, requires = "baseline_update")]
+    task: Option<String>,
+    /// Apply the baseline update; without it the proposed changes are only shown.
+    #[clap(long, requires = "baseline_update")]
+    confirm: bool,
     #[clap(subcommand)]
     command: Option<VerifyCommand>,
 }
@@ -112,6 +121,20 @@ struct VerifyReport {
     results: Vec<VerifyTodoResult>,
 }
 
+#[derive(Debug, Serialize)]
+struct BaselineChange {
+    path: String,
+    old_hash: String,
+    new_hash: String,
+}
+
+#[derive(Debug, Serialize)]
+struct BaselineUpdate {
+    todo_id: String,
+    applied: bool,
+    changes: Vec<BaselineChange>,
+}
+
 #[derive(Debug, Serialize)]
 struct StaleItem {
     todo_id: String,
@@ -705,12 +728,76 @@ fn verify_target(
     Ok(result)
 }
 
+/// Directory holding portable `todo done --validated` completion receipts.
+pub fn completion_records_dir(repo_root: &Path) -> PathBuf {
+    repo_root
+        .join(".decapod")
+        .join("records")
+        .join("completions")
+}
+
+/// STATE_COMMIT root for HEAD~1..HEAD, when a `scope_record.cbor` has been
+/// produced for this repo and git can resolve both commits.
+fn current_state_commit_root(repo_root: &Path) -> Option<String> {
+    if !repo_root.join("scope_record.cbor").exists() {
+        return None;
+    }
+    let head_sha = state_commit::run_git(repo_root, &["rev-parse", "HEAD"]).ok()?;
+    let base_sha = state_commit::run_git(repo_root, &["rev-parse", "HEAD~1"]).ok()?;
+    let input = state_commit::StateCommitInput {
+        base_sha,
+        head_sha,
+        ignore_policy_hash: "da39a3ee5e6b4b0d3255bfef95601890afd80709".to_string(),
+    };
+    state_commit::prove(&input, repo_root)
+        .ok()
+        .map(|out| out.state_commit_root)
+}
+
+/// Write a self-hashed completion receipt. `receipt_hash` is the SHA-256 of
+/// the canonical JSON encoding of every other field.
+fn write_completion_receipt(
+    repo_root: &Path,
+    todo_id: &str,
+    artifacts: &VerificationArtifacts,
+) -> Result<PathBuf, error::DecapodError> {
+    let mut receipt = serde_json::json!({
+        "schema_version": "1.0.0",
+        "kind": "todo.completion",
+        "receipt_id": crate::core::ulid::new_ulid(),
+        "task_id": todo_id,
+        "completed_at": artifacts.completed_at,
+        "artifacts": artifacts.file_artifacts,
+        "proof_results": artifacts.proof_plan_results,
+        "state_commit_root": current_state_commit_root(repo_root),
+    });
+    let canonical = serde_json::to_vec(&receipt).map_err(|e| {
+        error::DecapodError::ValidationError(format!("Failed to encode completion receipt: {e}"))
+    })?;
+    receipt["receipt_hash"] = serde_json::json!(sha256_hex(&canonical));
+
+    let dir = completion_records_dir(repo_root);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "{}-{}.json",
+        todo_id.replace('/', "_"),
+        artifacts.completed_at
+    ));
+    let pretty = serde_json::to_vec_pretty(&receipt).map_err(|e| {
+        error::DecapodError::ValidationError(format!("Failed to serialize completion receipt: {e}"))
+    })?;
+    fs::write(&path, pretty)?;
+    Ok(path)
+}
+
+/// Capture the proof baseline for a done task and return the completion
+/// receipt written for it.
 pub fn capture_baseline_for_todo(
     store: &Store,
     repo_root: &Path,
     todo_id: &str,
     artifact_paths: Vec<String>,
-) -> Result<(), error::DecapodError> {
+) -> Result<PathBuf, error::DecapodError> {
     todo::initialize_todo_db(&store.root)?;
 
     let broker = DbBroker::new(&store.root);
@@ -818,7 +905,104 @@ pub fn capture_baseline_for_todo(
             "verification_policy_days": 90
         }),
     )?;
-    Ok(())
+    write_completion_receipt(repo_root, todo_id, &artifacts)
+}
+
+/// Replace drifted file artifact hashes for `todo_id` with their current
+/// values. Nothing is written unless `confirm` is set; an applied update
+/// appends `baseline.updated` with the old and new hashes.
+fn update_baseline_for_todo(
+    store: &Store,
+    repo_root: &Path,
+    todo_id: &str,
+    confirm: bool,
+) -> Result<BaselineUpdate, error::DecapodError> {
+    let Some(target) = load_targets(store, Some(todo_id))?.into_iter().next() else {
+        return Err(error::DecapodError::NotFound(format!(
+            "TODO not found: {}",
+            todo_id
+        )));
+    };
+    if target.status != "done" {
+        return Err(error::DecapodError::ValidationError(format!(
+            "TODO {} is not done; only done tasks have a verification baseline",
+            todo_id
+        )));
+    }
+    let mut artifacts: VerificationArtifacts = target
+        .artifacts
+        .as_deref()
+        .and_then(|raw| serde_json::from_str(raw).ok())
+        .ok_or_else(|| {
+            error::DecapodError::ValidationError(format!(
+                "TODO {} has no verification artifacts to re-baseline. Remediation: capture verification artifacts for this TODO.",
+                todo_id
+            ))
+        })?;
+
+    let mut changes = Vec::new();
+    for artifact in &mut artifacts.file_artifacts {
+        let disk_path = resolve_artifact_path(repo_root, &artifact.path);
+        if !disk_path.exists() {
+            return Err(error::DecapodError::NotFound(format!(
+                "Verification artifact file not found: {}; a missing artifact cannot become the baseline",
+                disk_path.display()
+            )));
+        }
+        let (hash, size, mtime) = hash_file(&disk_path)?;
+        if hash != artifact.hash {
+            changes.push(BaselineChange {
+                path: artifact.path.clone(),
+                old_hash: std::mem::replace(&mut artifact.hash, hash.clone()),
+                new_hash: hash,
+            });
+            artifact.size = size;
+            artifact.mtime = mtime;
+        }
+    }
+
+    let mut update = BaselineUpdate {
+        todo_id: todo_id.to_string(),
+        applied: false,
+        changes,
+    };
+    if !confirm || update.changes.is_empty() {
+        return Ok(update);
+    }
+
+    // The event log is the source of truth: append first so a failed DB write
+    // below is repaired by `todo rebuild` replaying `task.verify.baseline_updated`.
+    let ts = now_iso();
+    todo::record_task_event(
+        &store.root,
+        "task.verify.baseline_updated",
+        Some(todo_id),
+        serde_json::json!({
+            "verification_artifacts": artifacts,
+            "changes": update.changes,
+        }),
+    )?;
+    append_jsonl(
+        &verification_events_path(store),
+        &serde_json::json!({
+            "event_type": "baseline.updated",
+            "ts": ts,
+            "todo_id": todo_id,
+            "changes": update.changes,
+        }),
+    )?;
+    let artifacts_json = serde_json::to_string(&artifacts).unwrap();
+    let broker = DbBroker::new(&store.root);
+    let db_path = todo::todo_db_path(&store.root);
+    broker.with_conn(&db_path, "decapod", None, "verify.baseline.update", |conn| {
+        conn.execute(
+            "UPDATE task_verification SET verification_artifacts = ?2, updated_at = ?3 WHERE todo_id = ?1",
+            rusqlite::params![todo_id, artifacts_json, ts],
+        )?;
+        Ok(())
+    })?;
+    update.applied = true;
+    Ok(update)
 }
 
 pub fn run_verify_cli(
@@ -826,6 +1010,38 @@ pub fn run_verify_cli(
     repo_root: &Path,
     cli: VerifyCli,
 ) -> Result<(), error::DecapodError> {
+    if cli.baseline_update {
+        let todo_id = cli.task.as_deref().unwrap_or_default();
+        let update = update_baseline_for_todo(store, repo_root, todo_id, cli.confirm)?;
+        if cli.json {
+            println!("{}", serde_json::to_string_pretty(&update).unwrap());
+        } else if update.changes.is_empty() {
+            println!(
+                "Baseline for {} already matches; nothing to update.",
+                todo_id
+            );
+        } else {
+            for c in &update.changes {
+                println!("- {}: {} -> {}", c.path, c.old_hash, c.new_hash);
+            }
+            if update.applied {
+                println!(
+                    "Baseline updated for {} ({} artifact(s)).",
+                    todo_id,
+                    update.changes.len()
+                );
+            }
+        }
+        if !update.applied && !update.changes.is_empty() {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Baseline for {} not updated: re-run with --confirm to accept {} drifted artifact(s)",
+                todo_id,
+                update.changes.len()
+            )));
+        }
+        return Ok(());
+    }
+
     let single_id = cli
         .command
         .as_ref()
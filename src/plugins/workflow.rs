@@ -1,10 +1,548 @@
 use crate::core::error;
+use crate::core::external_action::{self, ExternalCapability};
 use crate::core::store::Store;
 use crate::core::todo;
 use clap::{Parser, Subcommand};
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
+/// Workflow definitions from `.decapod/workflows.toml`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct WorkflowConfig {
+    #[serde(default)]
+    pub workflow: Vec<WorkflowDef>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkflowDef {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default, rename = "param")]
+    pub params: Vec<WorkflowParam>,
+    #[serde(default, rename = "step")]
+    pub steps: Vec<WorkflowStep>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamType {
+    #[default]
+    String,
+    Int,
+    Bool,
+}
+
+impl ParamType {
+    fn as_str(self) -> &'static str {
+        match self {
+            ParamType::String => "string",
+            ParamType::Int => "int",
+            ParamType::Bool => "bool",
+        }
+    }
+
+    fn accepts(self, value: &str) -> bool {
+        match self {
+            ParamType::String => true,
+            ParamType::Int => value.parse::<i64>().is_ok(),
+            ParamType::Bool => matches!(value, "true" | "false"),
+        }
+    }
+}
+
+/// A typed input; steps reference it as `{{name}}`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkflowParam {
+    pub name: String,
+    #[serde(default, rename = "type")]
+    pub kind: ParamType,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<toml::Value>,
+}
+
+/// One command of a workflow. `args` may contain `{{param}}`; `command` is
+/// literal so params can never choose the binary.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkflowStep {
+    pub name: String,
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Safe to run again after a partial run; resume refuses to re-enter at a
+    /// non-idempotent step unless told to skip it.
+    #[serde(default)]
+    pub idempotent: bool,
+}
+
+/// A step's checkpoint, after substitution.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StepResult {
+    pub name: String,
+    /// `completed`, or `skipped` when resume passed over a non-idempotent step
+    pub status: String,
+    pub command: String,
+    pub args: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    #[serde(default)]
+    pub output: String,
+    pub finished_at: String,
+}
+
+/// State of one workflow run, stored as `run.json` in its run directory.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkflowRun {
+    pub run_id: String,
+    pub workflow: String,
+    pub params: BTreeMap<String, String>,
+    /// Step names when the run started; resume refuses a changed definition.
+    pub steps: Vec<String>,
+    /// `running`, `failed`, or `completed`
+    pub status: String,
+    pub started_at: String,
+    pub updated_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_step: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowRunReport {
+    pub run_id: String,
+    pub workflow: String,
+    pub params: BTreeMap<String, String>,
+    /// First step this invocation executed when resuming.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resumed_at: Option<String>,
+    /// Steps executed or skipped by this invocation.
+    pub steps: Vec<StepResult>,
+}
+
+pub fn workflows_config_path(project_root: &Path) -> PathBuf {
+    project_root.join(".decapod").join("workflows.toml")
+}
+
+/// Load `.decapod/workflows.toml`; a missing file means no workflows.
+pub fn load_workflow_config(project_root: &Path) -> Result<WorkflowConfig, error::DecapodError> {
+    let path = workflows_config_path(project_root);
+    if !path.exists() {
+        return Ok(WorkflowConfig::default());
+    }
+    let content = fs::read_to_string(&path).map_err(error::DecapodError::IoError)?;
+    toml::from_str(&content).map_err(|e| {
+        error::DecapodError::ValidationError(format!("Invalid .decapod/workflows.toml: {}", e))
+    })
+}
+
+fn find_workflow<'a>(
+    config: &'a WorkflowConfig,
+    name: &str,
+) -> Result<&'a WorkflowDef, error::DecapodError> {
+    config
+        .workflow
+        .iter()
+        .find(|w| w.name == name)
+        .ok_or_else(|| {
+            error::DecapodError::NotFound(format!(
+                "Workflow '{}' is not defined in .decapod/workflows.toml (defined: {})",
+                name,
+                config
+                    .workflow
+                    .iter()
+                    .map(|w| w.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        })
+}
+
+fn toml_scalar(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Integer(i) => Some(i.to_string()),
+        toml::Value::Boolean(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn placeholders(template: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        out.push(rest[start + 2..start + 2 + len].trim());
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out
+}
+
+/// Replace every `{{name}}` in `template` with its resolved value.
+pub fn substitute(template: &str, params: &BTreeMap<String, String>) -> String {
+    let mut out = template.to_string();
+    for (name, value) in params {
+        out = out.replace(&format!("{{{{{}}}}}", name), value);
+    }
+    out
+}
+
+/// Check `provided` against the workflow's declared params before any step
+/// runs: unknown names, missing required params, and type mismatches are
+/// rejected; omitted optional params take their default (or empty).
+pub fn resolve_params(
+    def: &WorkflowDef,
+    provided: &[(String, String)],
+) -> Result<BTreeMap<String, String>, error::DecapodError> {
+    let declared = |name: &str| def.params.iter().any(|p| p.name == name);
+    if let Some((name, _)) = provided.iter().find(|(name, _)| !declared(name)) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Workflow '{}' has no param '{}' (declared: {})",
+            def.name,
+            name,
+            def.params
+                .iter()
+                .map(|p| p.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+    for step in &def.steps {
+        if !placeholders(&step.command).is_empty() {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Workflow '{}' step '{}' command '{}' must be literal; use {{{{param}}}} only in args",
+                def.name, step.name, step.command
+            )));
+        }
+        for ph in step.args.iter().flat_map(|t| placeholders(t)) {
+            if !declared(ph) {
+                return Err(error::DecapodError::ValidationError(format!(
+                    "Workflow '{}' step '{}' references undeclared param '{{{{{}}}}}'",
+                    def.name, step.name, ph
+                )));
+            }
+        }
+    }
+
+    let mut resolved = BTreeMap::new();
+    let mut missing = Vec::new();
+    for param in &def.params {
+        let value = provided
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == param.name)
+            .map(|(_, v)| v.clone())
+            .or_else(|| param.default.as_ref().and_then(toml_scalar));
+        let Some(value) = value else {
+            if param.required {
+                missing.push(format!("{} ({})", param.name, param.kind.as_str()));
+            } else {
+                resolved.insert(param.name.clone(), String::new());
+            }
+            continue;
+        };
+        if !param.kind.accepts(&value) {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Workflow '{}' param '{}' expects {}, got '{}'",
+                def.name,
+                param.name,
+                param.kind.as_str(),
+                value
+            )));
+        }
+        resolved.insert(param.name.clone(), value);
+    }
+    if !missing.is_empty() {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Workflow '{}' requires param(s): {}. Pass --param <name>=<value>",
+            def.name,
+            missing.join(", ")
+        )));
+    }
+    Ok(resolved)
+}
+
+pub fn workflow_runs_dir(project_root: &Path) -> PathBuf {
+    project_root
+        .join(".decapod")
+        .join("generated")
+        .join("workflow_runs")
+}
+
+fn run_dir(project_root: &Path, run_id: &str) -> Result<PathBuf, error::DecapodError> {
+    if !crate::core::ulid::is_valid(run_id) {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Invalid workflow run id '{}'",
+            run_id
+        )));
+    }
+    Ok(workflow_runs_dir(project_root).join(run_id))
+}
+
+/// Checkpoint file for step `index`. The name is reduced to `[A-Za-z0-9_-]` so
+/// a step called `../x` or `a/b` stays inside the run directory; the index
+/// keeps the file unique.
+fn step_checkpoint_path(dir: &Path, index: usize, name: &str) -> PathBuf {
+    let safe: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    dir.join(format!("step-{:03}-{}.json", index + 1, safe))
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), error::DecapodError> {
+    let body = serde_json::to_string_pretty(value).map_err(|e| {
+        error::DecapodError::ValidationError(format!("Failed to serialize workflow state: {}", e))
+    })?;
+    fs::write(path, body).map_err(error::DecapodError::IoError)
+}
+
+/// Load a run's `run.json`.
+pub fn load_workflow_run(
+    project_root: &Path,
+    run_id: &str,
+) -> Result<WorkflowRun, error::DecapodError> {
+    let path = run_dir(project_root, run_id)?.join("run.json");
+    let raw = fs::read_to_string(&path).map_err(|_| {
+        error::DecapodError::NotFound(format!("No workflow run '{}' recorded", run_id))
+    })?;
+    serde_json::from_str(&raw).map_err(|e| {
+        error::DecapodError::ValidationError(format!(
+            "Corrupt workflow run state {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Run workflow `name` from `.decapod/workflows.toml` with `provided` params.
+///
+/// Steps run in order from the project root, each completed step leaving a
+/// checkpoint under `.decapod/generated/workflow_runs/<run-id>/`. The first
+/// non-zero exit stops the run with an error naming the step and the run id
+/// to pass to `workflow resume`.
+pub fn run_defined_workflow(
+    store: &Store,
+    project_root: &Path,
+    name: &str,
+    provided: &[(String, String)],
+) -> Result<WorkflowRunReport, error::DecapodError> {
+    let config = load_workflow_config(project_root)?;
+    let def = find_workflow(&config, name)?;
+    let params = resolve_params(def, provided)?;
+
+    let now = crate::core::time::now_epoch_z();
+    let mut run = WorkflowRun {
+        run_id: crate::core::ulid::new_ulid(),
+        workflow: def.name.clone(),
+        params,
+        steps: def.steps.iter().map(|s| s.name.clone()).collect(),
+        status: "running".to_string(),
+        started_at: now.clone(),
+        updated_at: now,
+        failed_step: None,
+        error: None,
+    };
+    let dir = run_dir(project_root, &run.run_id)?;
+    fs::create_dir_all(&dir).map_err(error::DecapodError::IoError)?;
+    write_json(&dir.join("run.json"), &run)?;
+    execute_steps(store, project_root, def, &mut run, 0, None)
+}
+
+/// Re-enter run `run_id` at its first step without a checkpoint.
+///
+/// That step re-runs if it is `idempotent`. A non-idempotent step may have
+/// partly taken effect, so resume refuses it unless `skip` is set, in which
+/// case it is recorded as `skipped` and the run continues with the next step.
+pub fn resume_workflow(
+    store: &Store,
+    project_root: &Path,
+    run_id: &str,
+    skip: bool,
+) -> Result<WorkflowRunReport, error::DecapodError> {
+    let mut run = load_workflow_run(project_root, run_id)?;
+    if run.status == "completed" {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Workflow run '{}' already completed",
+            run_id
+        )));
+    }
+    let config = load_workflow_config(project_root)?;
+    let def = find_workflow(&config, &run.workflow)?;
+    let names: Vec<String> = def.steps.iter().map(|s| s.name.clone()).collect();
+    if names != run.steps {
+        return Err(error::DecapodError::ValidationError(format!(
+            "Workflow '{}' steps changed since run '{}' started ({} -> {}); start a new run",
+            run.workflow,
+            run_id,
+            run.steps.join(", "),
+            names.join(", ")
+        )));
+    }
+    let dir = run_dir(project_root, run_id)?;
+    let start = def
+        .steps
+        .iter()
+        .enumerate()
+        .position(|(i, step)| !step_checkpoint_path(&dir, i, &step.name).exists())
+        .unwrap_or(def.steps.len());
+
+    let mut skipped = Vec::new();
+    let mut first = start;
+    if let Some(step) = def.steps.get(start)
+        && !step.idempotent
+    {
+        if !skip {
+            return Err(error::DecapodError::ValidationError(format!(
+                "Workflow run '{}' stopped at non-idempotent step '{}', which may have partly run. \
+                 Finish it by hand, then pass --skip to continue after it: \
+                 decapod auto workflow resume --run {} --skip",
+                run_id, step.name, run_id
+            )));
+        }
+        let result = StepResult {
+            name: step.name.clone(),
+            status: "skipped".to_string(),
+            command: step.command.clone(),
+            args: step
+                .args
+                .iter()
+                .map(|a| substitute(a, &run.params))
+                .collect(),
+            exit_code: None,
+            output: String::new(),
+            finished_at: crate::core::time::now_epoch_z(),
+        };
+        write_json(&step_checkpoint_path(&dir, start, &step.name), &result)?;
+        skipped.push(result);
+        first += 1;
+    }
+    run.status = "running".to_string();
+    run.failed_step = None;
+    run.error = None;
+    let resumed_at = def.steps.get(start).map(|s| s.name.clone());
+    let mut report = execute_steps(store, project_root, def, &mut run, first, resumed_at)?;
+    skipped.append(&mut report.steps);
+    report.steps = skipped;
+    Ok(report)
+}
+
+fn execute_steps(
+    store: &Store,
+    project_root: &Path,
+    def: &WorkflowDef,
+    run: &mut WorkflowRun,
+    start: usize,
+    resumed_at: Option<String>,
+) -> Result<WorkflowRunReport, error::DecapodError> {
+    let dir = run_dir(project_root, &run.run_id)?;
+    let mut steps = Vec::new();
+    for (index, step) in def.steps.iter().enumerate().skip(start) {
+        let command = step.command.clone();
+        let args: Vec<String> = step
+            .args
+            .iter()
+            .map(|a| substitute(a, &run.params))
+            .collect();
+        let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+        let outcome = external_action::execute(
+            &store.root,
+            ExternalCapability::WorkflowExec,
+            &format!("workflow.{}.{}", def.name, step.name),
+            &command,
+            &arg_refs,
+            project_root,
+        );
+        let failure = match &outcome {
+            Ok(output) if output.status.success() => None,
+            Ok(output) => Some(format!(
+                "exit {}: {}",
+                output.status.code().unwrap_or(-1),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )),
+            Err(e) => Some(e.to_string()),
+        };
+        run.updated_at = crate::core::time::now_epoch_z();
+        if let Some(failure) = failure {
+            run.status = "failed".to_string();
+            run.failed_step = Some(step.name.clone());
+            run.error = Some(failure.clone());
+            write_json(&dir.join("run.json"), run)?;
+            return Err(error::DecapodError::ValidationError(format!(
+                "Workflow '{}' step '{}' failed ({}). Resume with: decapod auto workflow resume --run {}",
+                def.name, step.name, failure, run.run_id
+            )));
+        }
+        let output = outcome?;
+        let result = StepResult {
+            name: step.name.clone(),
+            status: "completed".to_string(),
+            command,
+            args,
+            exit_code: output.status.code(),
+            output: String::from_utf8_lossy(&output.stdout)
+                .chars()
+                .take(1000)
+                .collect(),
+            finished_at: run.updated_at.clone(),
+        };
+        write_json(&step_checkpoint_path(&dir, index, &step.name), &result)?;
+        steps.push(result);
+    }
+    run.status = "completed".to_string();
+    write_json(&dir.join("run.json"), run)?;
+    Ok(WorkflowRunReport {
+        run_id: run.run_id.clone(),
+        workflow: def.name.clone(),
+        params: run.params.clone(),
+        resumed_at,
+        steps,
+    })
+}
+
+fn print_run_report(report: &WorkflowRunReport) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&serde_json::json!({
+            "ts": crate::core::time::now_epoch_z(),
+            "cmd": "workflow.run",
+            "status": "ok",
+            "run": report
+        }))
+        .unwrap()
+    );
+}
+
+fn project_root_of(store: &Store) -> &Path {
+    store
+        .root
+        .parent()
+        .and_then(|p| p.parent())
+        .unwrap_or_else(|| Path::new("."))
+}
+
+fn parse_param(raw: &str) -> Result<(String, String), String> {
+    match raw.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!("Invalid param '{}': expected key=value", raw)),
+    }
+}
+
 #[derive(Parser, Debug)]
 #[clap(
     name = "workflow",
@@ -17,12 +555,19 @@ pub struct WorkflowCli {
 
 #[derive(Subcommand, Debug)]
 pub enum WorkflowCommand {
-    /// Execute one automation loop (trigger -> task -> context -> execution -> lesson).
+    /// Execute a workflow from `.decapod/workflows.toml` (`--name`), or one
+    /// automation loop (trigger -> task -> context -> execution -> lesson).
     Run {
-        #[clap(long)]
-        agent: String,
-        #[clap(long)]
-        title: String,
+        /// Workflow defined in `.decapod/workflows.toml`.
+        #[clap(long, conflicts_with_all = ["agent", "title"])]
+        name: Option<String>,
+        /// Workflow parameter as `key=value` (repeatable).
+        #[clap(long = "param", value_parser = parse_param, requires = "name")]
+        params: Vec<(String, String)>,
+        #[clap(long, required_unless_present = "name")]
+        agent: Option<String>,
+        #[clap(long, required_unless_present = "name")]
+        title: Option<String>,
         #[clap(long, default_value = "medium")]
         priority: String,
         #[clap(long, default_value = "")]
@@ -34,6 +579,14 @@ pub enum WorkflowCommand {
         #[clap(long, default_value_t = true)]
         autoclose: bool,
     },
+    /// Continue a failed workflow run from its first incomplete step.
+    Resume {
+        #[clap(long)]
+        run: String,
+        /// Skip a non-idempotent step the run stopped at instead of refusing.
+        #[clap(long)]
+        skip: bool,
+    },
     /// Suggest discovery opportunities from open work and stale ownership.
     Discover {
         #[clap(long, default_value_t = 10)]
@@ -43,6 +596,20 @@ pub enum WorkflowCommand {
 
 pub fn run_workflow_cli(store: &Store, cli: WorkflowCli) -> Result<(), error::DecapodError> {
     match cli.command {
+        WorkflowCommand::Run {
+            name: Some(name),
+            params,
+            ..
+        } => {
+            let report = run_defined_workflow(store, project_root_of(store), &name, &params)?;
+            print_run_report(&report);
+            Ok(())
+        }
+        WorkflowCommand::Resume { run, skip } => {
+            let report = resume_workflow(store, project_root_of(store), &run, skip)?;
+            print_run_report(&report);
+            Ok(())
+        }
         WorkflowCommand::Run {
             agent,
             title,
@@ -51,8 +618,16 @@ pub fn run_workflow_cli(store: &Store, cli: WorkflowCli) -> Result<(), error::De
             max_tasks,
             lesson,
             autoclose,
+            ..
         } => run_workflow(
-            store, &agent, &title, &priority, &tags, max_tasks, lesson, autoclose,
+            store,
+            agent.as_deref().unwrap_or_default(),
+            title.as_deref().unwrap_or_default(),
+            &priority,
+            &tags,
+            max_tasks,
+            lesson,
+            autoclose,
         ),
         WorkflowCommand::Discover { limit } => discover(store, limit),
     }
@@ -207,9 +782,12 @@ pub fn schema() -> serde_json::Value {
         "version": "0.1.0",
         "description": "Workflow automation and discovery command group",
         "commands": [
-            { "name": "run", "parameters": ["agent", "title", "priority", "tags", "max_tasks", "lesson", "autoclose"] },
+            { "name": "run", "parameters": ["name", "param", "agent", "title", "priority", "tags", "max_tasks", "lesson", "autoclose"] },
+            { "name": "resume", "parameters": ["run"] },
             { "name": "discover", "parameters": ["limit"] }
         ],
-        "storage": ["todo.db", "todo.events.jsonl", "knowledge.db"]
+        "storage": ["todo.db", "todo.events.jsonl", "knowledge.db"],
+        "definitions": ".decapod/workflows.toml",
+        "runs": ".decapod/generated/workflow_runs/<run-id>/"
     })
 }
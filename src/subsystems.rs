@@ -11,7 +11,6 @@ use std::path::Path;
 
 pub(crate) struct SubsystemInit {
     /// Subsystem identifier (used for diagnostics and future registry queries).
-    #[allow(dead_code)]
     pub name: &'static str,
     pub initialize_db: fn(&Path) -> Result<(), error::DecapodError>,
 }
@@ -72,8 +71,105 @@ pub(crate) const SUBSYSTEMS: &[SubsystemInit] = &[
 
 /// Initialize all subsystem databases sequentially.
 pub(crate) fn initialize_all_dbs(data_root: &Path) -> Result<(), error::DecapodError> {
-    for sub in SUBSYSTEMS {
-        (sub.initialize_db)(data_root)?;
+    initialize_subsystems(data_root, SUBSYSTEMS)
+}
+
+/// Runs every initializer even after a failure, so a botched migration
+/// reports all broken databases at once. A single failure is returned as-is
+/// to keep its original error variant.
+fn initialize_subsystems(
+    data_root: &Path,
+    subsystems: &[SubsystemInit],
+) -> Result<(), error::DecapodError> {
+    let mut failures: Vec<(&'static str, error::DecapodError)> = Vec::new();
+    for sub in subsystems {
+        if let Err(e) = (sub.initialize_db)(data_root) {
+            failures.push((sub.name, e));
+        }
+    }
+    match failures.len() {
+        0 => Ok(()),
+        1 => Err(failures.remove(0).1),
+        n => {
+            let details = failures
+                .iter()
+                .map(|(name, e)| format!("- {name}: {e}"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            Err(error::DecapodError::DatabaseInitializationError(format!(
+                "{n} subsystem(s) failed to initialize:\n{details}"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok_init(_: &Path) -> Result<(), error::DecapodError> {
+        Ok(())
+    }
+
+    fn locked_init(_: &Path) -> Result<(), error::DecapodError> {
+        Err(error::DecapodError::ValidationError(
+            "database is locked".to_string(),
+        ))
+    }
+
+    fn missing_init(_: &Path) -> Result<(), error::DecapodError> {
+        Err(error::DecapodError::NotFound(
+            "schema_meta table".to_string(),
+        ))
+    }
+
+    #[test]
+    fn test_every_failed_subsystem_is_reported() {
+        let subs = [
+            SubsystemInit {
+                name: "todo",
+                initialize_db: locked_init,
+            },
+            SubsystemInit {
+                name: "health",
+                initialize_db: ok_init,
+            },
+            SubsystemInit {
+                name: "policy",
+                initialize_db: missing_init,
+            },
+        ];
+        let err = initialize_subsystems(Path::new("."), &subs).unwrap_err();
+        let msg = err.to_string();
+        assert!(matches!(
+            err,
+            error::DecapodError::DatabaseInitializationError(_)
+        ));
+        assert!(msg.contains("2 subsystem(s) failed"), "{msg}");
+        assert!(
+            msg.contains("- todo: Validation error: database is locked"),
+            "{msg}"
+        );
+        assert!(
+            msg.contains("- policy: Not found: schema_meta table"),
+            "{msg}"
+        );
+        assert!(!msg.contains("health"), "{msg}");
+    }
+
+    #[test]
+    fn test_single_failure_keeps_original_variant() {
+        let subs = [
+            SubsystemInit {
+                name: "todo",
+                initialize_db: ok_init,
+            },
+            SubsystemInit {
+                name: "policy",
+                initialize_db: missing_init,
+            },
+        ];
+        let err = initialize_subsystems(Path::new("."), &subs).unwrap_err();
+        assert!(matches!(err, error::DecapodError::NotFound(_)));
     }
-    Ok(())
 }
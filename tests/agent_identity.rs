@@ -0,0 +1,412 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str], agent: Option<&str>) -> std::process::Output {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_decapod"));
+    cmd.current_dir(dir)
+        .args(args)
+        .env_remove("DECAPOD_AGENT_ID");
+    if let Some(agent) = agent {
+        cmd.env("DECAPOD_AGENT_ID", agent);
+    }
+    cmd.output().expect("run decapod")
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let out = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .expect("git");
+    assert!(out.status.success(), "git {args:?} failed");
+}
+
+fn whoami(dir: &Path, agent: Option<&str>) -> Value {
+    let out = run_decapod(dir, &["session", "whoami", "--format", "json"], agent);
+    assert!(
+        out.status.success(),
+        "whoami failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    serde_json::from_slice(&out.stdout).expect("whoami json")
+}
+
+#[test]
+fn agent_id_falls_back_from_env_to_file_to_git_config() {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    let init = run_decapod(dir, &["init", "--force"], None);
+    assert!(
+        init.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&init.stderr)
+    );
+
+    fs::write(dir.join(".decapod/agent_id"), "file-agent\n").expect("write agent_id");
+    let from_file = whoami(dir, None);
+    assert_eq!(from_file["agent_id"], "file-agent");
+    assert_eq!(from_file["source"], "file");
+
+    let acquire = run_decapod(dir, &["session", "acquire"], None);
+    assert!(acquire.status.success());
+    assert!(String::from_utf8_lossy(&acquire.stdout).contains("Agent: file-agent"));
+    assert!(
+        dir.join(".decapod/generated/sessions/file-agent.json")
+            .exists()
+    );
+
+    let from_env = whoami(dir, Some("env-agent"));
+    assert_eq!(from_env["agent_id"], "env-agent");
+    assert_eq!(from_env["source"], "env");
+
+    fs::remove_file(dir.join(".decapod/agent_id")).expect("remove agent_id");
+    git(dir, &["config", "user.email", "Alice.Smith@example.com"]);
+    let from_git = whoami(dir, None);
+    assert_eq!(from_git["agent_id"], "alice-smith");
+    assert_eq!(from_git["source"], "git");
+}
+
+#[test]
+fn strict_agent_id_mode_blocks_unknown_mutations_but_allows_reads() {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    let init = run_decapod(dir, &["init", "--force"], None);
+    assert!(init.status.success());
+
+    let strict = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_decapod"))
+            .current_dir(dir)
+            .args(args)
+            .env("DECAPOD_AGENT_ID", "unknown")
+            .env("DECAPOD_REQUIRE_AGENT_ID", "1")
+            .output()
+            .expect("run decapod")
+    };
+
+    let add = strict(&["todo", "add", "blocked task"]);
+    assert!(!add.status.success(), "unknown agent must not mutate");
+    let stderr = String::from_utf8_lossy(&add.stderr);
+    assert!(stderr.contains("AGENT_ID_REQUIRED"), "stderr: {stderr}");
+    assert!(stderr.contains("DECAPOD_AGENT_ID"), "stderr: {stderr}");
+
+    let list = strict(&["todo", "list"]);
+    assert!(
+        list.status.success(),
+        "reads stay allowed: {}",
+        String::from_utf8_lossy(&list.stderr)
+    );
+
+    let lenient = run_decapod(dir, &["todo", "add", "lenient task"], Some("unknown"));
+    assert!(
+        !String::from_utf8_lossy(&lenient.stderr).contains("AGENT_ID_REQUIRED"),
+        "default mode stays lenient"
+    );
+}
+
+/// Init a repo, then run `args` as an `unknown` agent in strict mode.
+fn run_strict(args: &[&str]) -> std::process::Output {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    let init = run_decapod(dir, &["init", "--force"], None);
+    assert!(init.status.success());
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_AGENT_ID", "unknown")
+        .env("DECAPOD_REQUIRE_AGENT_ID", "1")
+        .output()
+        .expect("run decapod")
+}
+
+fn assert_strict_blocks(args: &[&str]) {
+    let out = run_strict(args);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(!out.status.success(), "{args:?} must be refused");
+    assert!(
+        stderr.contains("AGENT_ID_REQUIRED"),
+        "{args:?} stderr: {stderr}"
+    );
+}
+
+fn assert_strict_allows(args: &[&str]) {
+    let out = run_strict(args);
+    assert!(
+        !String::from_utf8_lossy(&out.stderr).contains("AGENT_ID_REQUIRED"),
+        "{args:?} is read-only"
+    );
+}
+
+#[test]
+fn strict_agent_id_mode_gates_mutating_rpc_ops() {
+    assert_strict_blocks(&[
+        "rpc",
+        "--op",
+        "store.upsert",
+        "--params",
+        r#"{"id":"n1","content":"x"}"#,
+    ]);
+    assert_strict_blocks(&["rpc", "--op", "federation.add_node", "--params", "{}"]);
+    assert_strict_blocks(&["rpc", "--op", "workspace.publish"]);
+
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    assert!(
+        run_decapod(dir, &["init", "--force"], None)
+            .status
+            .success()
+    );
+    let mut child = Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(["rpc", "--stdin"])
+        .env("DECAPOD_AGENT_ID", "unknown")
+        .env("DECAPOD_REQUIRE_AGENT_ID", "1")
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .expect("spawn rpc");
+    std::io::Write::write_all(
+        child.stdin.as_mut().unwrap(),
+        br#"{"op":"store.upsert","params":{}}"#,
+    )
+    .unwrap();
+    let out = child.wait_with_output().expect("rpc output");
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(
+        combined.contains("AGENT_ID_REQUIRED"),
+        "stdin requests are gated too: {combined}"
+    );
+}
+
+#[test]
+fn strict_agent_id_mode_gates_policy_approve() {
+    assert_strict_blocks(&["govern", "policy", "approve", "--id", "fp-1"]);
+    assert_strict_blocks(&["govern", "policy", "riskmap", "init"]);
+}
+
+#[test]
+fn strict_agent_id_mode_gates_aptitude_writes() {
+    assert_strict_blocks(&[
+        "data",
+        "aptitude",
+        "add",
+        "--category",
+        "git",
+        "--key",
+        "k",
+        "--value",
+        "v",
+    ]);
+    assert_strict_allows(&["data", "aptitude", "list"]);
+}
+
+#[test]
+fn strict_agent_id_mode_gates_reflex_writes() {
+    assert_strict_blocks(&["auto", "reflex", "disarm", "--id", "R_1"]);
+    assert_strict_allows(&["auto", "reflex", "list"]);
+}
+
+#[test]
+fn strict_agent_id_mode_gates_cron_writes() {
+    assert_strict_blocks(&["auto", "cron", "pause", "--id", "C_1"]);
+    assert_strict_allows(&["auto", "cron", "list"]);
+}
+
+#[test]
+fn strict_agent_id_mode_gates_archive_repair() {
+    assert_strict_blocks(&["data", "archive", "verify", "--repair"]);
+    assert_strict_allows(&["data", "archive", "verify"]);
+}
+
+#[test]
+fn strict_agent_id_mode_gates_backup_restore() {
+    assert_strict_blocks(&[
+        "migration",
+        "backup",
+        "restore",
+        "--id",
+        "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+    ]);
+    assert_strict_allows(&["migration", "backup", "list"]);
+}
+
+#[test]
+fn strict_agent_id_mode_gates_federation_update() {
+    assert_strict_blocks(&[
+        "data",
+        "federation",
+        "update",
+        "--id",
+        "F_1",
+        "--content",
+        "revised",
+        "--reason",
+        "typo",
+    ]);
+    assert_strict_allows(&["data", "federation", "history", "--id", "F_1"]);
+}
+
+#[test]
+fn concurrent_session_acquires_for_one_agent_keep_a_single_token() {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    let init = run_decapod(dir, &["init", "--force"], None);
+    assert!(init.status.success());
+
+    let spawn = || {
+        Command::new(env!("CARGO_BIN_EXE_decapod"))
+            .current_dir(dir)
+            .args(["session", "acquire"])
+            .env("DECAPOD_AGENT_ID", "racer")
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .expect("spawn acquire")
+    };
+    let children = [spawn(), spawn()];
+    let outputs: Vec<String> = children
+        .into_iter()
+        .map(|c| {
+            let out = c.wait_with_output().expect("acquire output");
+            assert!(
+                out.status.success(),
+                "acquire failed: {}",
+                String::from_utf8_lossy(&out.stderr)
+            );
+            String::from_utf8_lossy(&out.stdout).to_string()
+        })
+        .collect();
+
+    let winners: Vec<&String> = outputs
+        .iter()
+        .filter(|o| o.contains("Session acquired successfully."))
+        .collect();
+    assert_eq!(winners.len(), 1, "exactly one acquire wins: {outputs:?}");
+    assert!(
+        outputs.iter().any(|o| o.contains("Session already active")),
+        "the loser observes the winner's session: {outputs:?}"
+    );
+    let issued = winners[0]
+        .lines()
+        .find_map(|l| l.strip_prefix("Token: "))
+        .expect("winner prints its token");
+
+    let raw = fs::read_to_string(dir.join(".decapod/generated/sessions/racer.json"))
+        .expect("session file");
+    let record: Value = serde_json::from_str(&raw).expect("session json");
+    assert_eq!(record["token"], issued);
+    assert!(
+        record["expires_at_epoch_secs"].as_u64().unwrap() > now_secs(),
+        "the surviving session is live for both processes"
+    );
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[test]
+fn concurrent_auto_acquires_for_one_agent_share_the_session() {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    let init = run_decapod(dir, &["init", "--force"], None);
+    assert!(init.status.success());
+    let session_file = dir.join(".decapod/generated/sessions/racer.json");
+    let _ = fs::remove_file(&session_file);
+
+    let spawn = || {
+        Command::new(env!("CARGO_BIN_EXE_decapod"))
+            .current_dir(dir)
+            .args(["todo", "list"])
+            .env("DECAPOD_AGENT_ID", "racer")
+            .env_remove("DECAPOD_SESSION_PASSWORD")
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .expect("spawn todo list")
+    };
+    let children: Vec<_> = (0..4).map(|_| spawn()).collect();
+    let stderrs: Vec<String> = children
+        .into_iter()
+        .map(|c| {
+            let out = c.wait_with_output().expect("todo list output");
+            let stderr = String::from_utf8_lossy(&out.stderr).to_string();
+            assert!(out.status.success(), "todo list failed: {stderr}");
+            stderr
+        })
+        .collect();
+    assert_eq!(
+        stderrs
+            .iter()
+            .filter(|e| e.contains("session: auto-acquired"))
+            .count(),
+        1,
+        "one process issues the session, the rest reuse it: {stderrs:?}"
+    );
+
+    let read_token = || {
+        let record: Value =
+            serde_json::from_str(&fs::read_to_string(&session_file).expect("session file"))
+                .expect("session json");
+        assert!(record["expires_at_epoch_secs"].as_u64().unwrap() > now_secs());
+        record["token"].as_str().unwrap().to_string()
+    };
+    let token = read_token();
+    let later = run_decapod(dir, &["todo", "list"], Some("racer"));
+    assert!(later.status.success());
+    assert_eq!(read_token(), token, "a live session is never replaced");
+}
+
+#[test]
+fn mismatched_session_password_reissues_the_session() {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    let init = run_decapod(dir, &["init", "--force"], None);
+    assert!(init.status.success());
+    let session_file = dir.join(".decapod/generated/sessions/holder.json");
+    let token = || {
+        let record: Value =
+            serde_json::from_str(&fs::read_to_string(&session_file).expect("session file"))
+                .expect("session json");
+        record["token"].as_str().unwrap().to_string()
+    };
+
+    assert!(
+        run_decapod(dir, &["todo", "list"], Some("holder"))
+            .status
+            .success()
+    );
+    let issued = token();
+
+    let wrong = Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(["todo", "list"])
+        .env("DECAPOD_AGENT_ID", "holder")
+        .env("DECAPOD_SESSION_PASSWORD", "not-the-password")
+        .output()
+        .expect("todo list");
+    assert!(wrong.status.success());
+    assert!(String::from_utf8_lossy(&wrong.stderr).contains("session: auto-acquired"));
+    assert_ne!(
+        token(),
+        issued,
+        "a wrong password never rides the live session"
+    );
+}
@@ -308,6 +308,94 @@ fn test_rpc_schema_get() {
     assert_eq!(res["result"]["schema_version"], "v1");
 }
 
+#[test]
+fn test_rpc_schema_get_documents_knowledge_conflict_policy() {
+    let request = serde_json::json!({
+        "op": "schema.get",
+        "params": {
+            "entity": "knowledge"
+        }
+    });
+
+    let res = run_rpc(request);
+    assert!(res["success"].as_bool().unwrap());
+    let conflict = &res["result"]["json_schema"]["properties"]["conflict_policy"];
+    assert_eq!(
+        conflict["enum"],
+        serde_json::json!(["merge", "supersede", "reject"])
+    );
+    assert!(
+        conflict["description"]
+            .as_str()
+            .unwrap_or_default()
+            .contains("merge_key")
+    );
+    assert_eq!(
+        res["result"]["json_schema"]["required"],
+        serde_json::json!(["id", "provenance", "text", "title"])
+    );
+
+    // The `data schema` catalog carries the same field documentation.
+    let catalog = decapod::plugins::knowledge::schema();
+    assert_eq!(
+        catalog["fields"]["conflict_policy"]["enum"],
+        conflict["enum"]
+    );
+}
+
+#[test]
+fn test_rpc_knowledge_search_rank_modes() {
+    let token = format!("rankprobe{}", new_ulid().to_lowercase());
+    let older = format!("K_RANK_OLD_{}", new_ulid());
+    let newer = format!("K_RANK_NEW_{}", new_ulid());
+    for (id, title, text) in [
+        (
+            &older,
+            format!("{token} design"),
+            format!("{token} overview"),
+        ),
+        (&newer, "Follow-up".to_string(), format!("mentions {token}")),
+    ] {
+        let res = run_rpc(serde_json::json!({
+            "op": "store.upsert",
+            "params": {
+                "entity": "knowledge",
+                "payload": {
+                    "id": id,
+                    "title": title,
+                    "text": text,
+                    "provenance": "cmd:cargo-test"
+                }
+            }
+        }));
+        assert!(res["success"].as_bool().unwrap(), "upsert failed: {res}");
+    }
+
+    let search = |rank: &str| {
+        let res = run_rpc(serde_json::json!({
+            "op": "knowledge.search",
+            "params": { "query": token, "rank": rank, "window_days": 1 }
+        }));
+        assert!(res["success"].as_bool().unwrap(), "search failed: {res}");
+        res["result"]["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|i| i["id"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(search("relevance"), vec![older.clone(), newer.clone()]);
+    assert_eq!(search("recency"), vec![newer, older]);
+
+    let bad = run_rpc(serde_json::json!({
+        "op": "knowledge.search",
+        "params": { "query": token, "rank": "popularity" }
+    }));
+    assert!(!bad["success"].as_bool().unwrap());
+    assert_eq!(bad["error"]["code"], "invalid_params");
+}
+
 #[test]
 fn test_rpc_store_upsert_knowledge() {
     let id = format!("K_TEST_{}", new_ulid());
@@ -385,3 +473,76 @@ fn test_rpc_trace_and_redaction() {
     assert!(trace_line.contains("[REDACTED]"));
     assert!(!trace_line.contains("supersecretpassword"));
 }
+
+#[test]
+fn test_rpc_trace_records_duration_and_status() {
+    let ok_id = format!("trace_ok_{}", new_ulid());
+    let err_id = format!("trace_err_{}", new_ulid());
+    run_rpc(serde_json::json!({
+        "op": "schema.get",
+        "id": ok_id,
+        "params": {"entity": "todo"}
+    }));
+    run_rpc(serde_json::json!({
+        "op": "knowledge.search",
+        "id": err_id,
+        "params": {}
+    }));
+
+    let traces = std::fs::read_to_string(test_repo_root().join(".decapod/data/traces.jsonl"))
+        .expect("read traces");
+    let find = |id: &str| -> serde_json::Value {
+        traces
+            .lines()
+            .filter_map(|l| serde_json::from_str::<serde_json::Value>(l).ok())
+            .find(|t| t["trace_id"] == id)
+            .unwrap_or_else(|| panic!("trace {id} recorded"))
+    };
+
+    let ok = find(&ok_id);
+    assert!(ok["duration_ms"].is_u64(), "duration_ms missing: {ok}");
+    assert_eq!(ok["status"], "ok");
+    let err = find(&err_id);
+    assert!(err["duration_ms"].is_u64(), "duration_ms missing: {err}");
+    assert_eq!(err["status"], "error");
+}
+
+#[test]
+fn test_rpc_context_resolve_honors_limit() {
+    // "decapod" appears in nearly every constitution doc, so the query alone
+    // matches far more than ten fragments.
+    let request = |limit: Option<u64>| {
+        let mut params = serde_json::json!({ "query": "decapod agent validate" });
+        if let Some(limit) = limit {
+            params["limit"] = limit.into();
+        }
+        serde_json::json!({ "op": "context.resolve", "params": params })
+    };
+    let refs = |res: &serde_json::Value| -> Vec<String> {
+        assert!(res["success"].as_bool().unwrap(), "{res}");
+        res["result"]["fragments"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| f["ref"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let ten = refs(&run_rpc(request(Some(10))));
+    assert_eq!(ten.len(), 10);
+    assert_eq!(
+        ten,
+        refs(&run_rpc(request(Some(10)))),
+        "deterministic order"
+    );
+
+    let five = refs(&run_rpc(request(None)));
+    assert_eq!(five.len(), 5, "default limit");
+    assert_eq!(
+        five[..],
+        ten[..5],
+        "smaller limit is a prefix of the larger"
+    );
+
+    assert_eq!(refs(&run_rpc(request(Some(500)))).len(), 20, "clamped");
+}
@@ -1,4 +1,6 @@
-use decapod::core::assurance::{AssuranceEngine, AssuranceEvaluateInput, AssurancePhase};
+use decapod::core::assurance::{
+    AssuranceEngine, AssuranceEvaluateInput, AssurancePhase, list_attestation_records,
+};
 use std::fs;
 use std::path::Path;
 use std::process::Command;
@@ -208,3 +210,122 @@ fn protected_branch_or_workspace_state_triggers_workspace_interlock() {
         Some("workspace_required")
     );
 }
+
+#[test]
+fn assurance_evaluate_persists_attestation_record() {
+    let tmp = tempdir().expect("temp");
+    init_repo(tmp.path(), "master");
+    seed_docs(tmp.path());
+
+    let engine = AssuranceEngine::new(tmp.path());
+    let input = AssuranceEvaluateInput {
+        op: "todo.done".to_string(),
+        params: serde_json::json!({}),
+        touched_paths: vec!["src/lib.rs".to_string()],
+        diff_summary: None,
+        session_id: None,
+        phase: Some(AssurancePhase::Complete),
+        time_budget_s: None,
+    };
+    let result = engine.evaluate(&input).expect("eval");
+
+    let records = list_attestation_records(tmp.path()).expect("list attestations");
+    assert_eq!(records.len(), 1);
+    let record = &records[0];
+    assert_eq!(record.attestation_id, result.attestation.id);
+    assert_eq!(record.evaluated_op, "todo.done");
+    assert_eq!(
+        record.interlock_code.as_deref(),
+        Some("verification_required")
+    );
+    assert_eq!(record.touched_paths, vec!["src/lib.rs".to_string()]);
+    assert_eq!(record.content_hash.len(), 64);
+    assert!(
+        tmp.path()
+            .join(".decapod/records/attestations")
+            .join(format!("{}.json", record.attestation_id))
+            .exists()
+    );
+}
+
+#[test]
+fn interlock_policy_can_scope_verification_to_high_risk_ops() {
+    let tmp = tempdir().expect("temp");
+    init_repo(tmp.path(), "feature/x");
+    seed_docs(tmp.path());
+    fs::create_dir_all(tmp.path().join(".decapod/policy")).expect("policy dir");
+    fs::write(
+        tmp.path().join(".decapod/policy/interlock_policy.json"),
+        r#"{
+  "schema_version": "1.0.0",
+  "conditions": {
+    "missing_completion_proof": {
+      "code": "verification_required",
+      "enforcement": "high_risk",
+      "unblock_ops": ["validate"]
+    }
+  }
+}"#,
+    )
+    .expect("policy");
+
+    let engine = AssuranceEngine::new(tmp.path());
+    let complete = |touched: &str| AssuranceEvaluateInput {
+        op: "complete".to_string(),
+        params: serde_json::json!({"auth_provider":"oauth"}),
+        touched_paths: vec![touched.to_string()],
+        diff_summary: None,
+        session_id: None,
+        phase: Some(AssurancePhase::Complete),
+        time_budget_s: None,
+    };
+
+    let low_risk = engine.evaluate(&complete("src/lib.rs")).expect("eval");
+    assert_ne!(
+        low_risk.interlock.as_ref().map(|i| i.code.as_str()),
+        Some("verification_required"),
+        "policy should downgrade verification for low-risk ops"
+    );
+    let advisory = low_risk
+        .advisory
+        .reconciliations
+        .recommended
+        .iter()
+        .find(|p| p.kind == "interlock")
+        .expect("downgraded condition surfaces as an advisory");
+    assert_eq!(advisory.r#ref, "missing_completion_proof");
+    assert_eq!(advisory.evidence.id, "verification_required");
+
+    let high_risk = engine.evaluate(&complete("src/auth/mod.rs")).expect("eval");
+    let interlock = high_risk
+        .interlock
+        .expect("high-risk completion interlocks");
+    assert_eq!(interlock.code, "verification_required");
+    assert_eq!(interlock.unblock_ops, vec!["validate".to_string()]);
+}
+
+#[test]
+fn interlock_policy_rejects_unknown_codes() {
+    let tmp = tempdir().expect("temp");
+    init_repo(tmp.path(), "feature/x");
+    seed_docs(tmp.path());
+    fs::create_dir_all(tmp.path().join(".decapod/policy")).expect("policy dir");
+    fs::write(
+        tmp.path().join(".decapod/policy/interlock_policy.json"),
+        r#"{"schema_version":"1.0.0","conditions":{"store_boundary":{"code":"maybe_later","enforcement":"always","unblock_ops":[]}}}"#,
+    )
+    .expect("policy");
+
+    let err = AssuranceEngine::new(tmp.path())
+        .evaluate(&AssuranceEvaluateInput {
+            op: "build".to_string(),
+            params: serde_json::json!({}),
+            touched_paths: vec![],
+            diff_summary: None,
+            session_id: None,
+            phase: None,
+            time_budget_s: None,
+        })
+        .expect_err("unknown code must fail policy load");
+    assert!(err.to_string().contains("INTERLOCK_POLICY_UNKNOWN_CODE"));
+}
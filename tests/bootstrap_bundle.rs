@@ -0,0 +1,75 @@
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_AGENT_ID", "bootstrap-agent")
+        .env_remove("DECAPOD_SESSION_PASSWORD")
+        .env_remove("DECAPOD_VALIDATE_SKIP_GIT_GATES")
+        .output()
+        .expect("run decapod")
+}
+
+fn setup_repo() -> TempDir {
+    let tmp = TempDir::new().expect("tmpdir");
+    let init = Command::new("git")
+        .current_dir(tmp.path())
+        .args(["init", "-b", "master"])
+        .output()
+        .expect("git init");
+    assert!(init.status.success(), "git init failed");
+    let out = run_decapod(tmp.path(), &["init", "--force"]);
+    assert!(
+        out.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    tmp
+}
+
+#[test]
+fn bootstrap_bundle_has_constitution_capabilities_and_workspace() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    // No session and no worktree yet: bootstrap is the first call an agent makes.
+    let out = run_decapod(dir, &["bootstrap", "--format", "json"]);
+    assert!(
+        out.status.success(),
+        "bootstrap failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let bundle: Value = serde_json::from_slice(&out.stdout).expect("bundle json");
+
+    let constitution = bundle["constitution"].as_array().expect("constitution");
+    let decapod = constitution
+        .iter()
+        .find(|f| f["ref"] == "core/DECAPOD.md")
+        .expect("core/DECAPOD.md fragment");
+    assert!(!decapod["excerpt"].as_str().unwrap().is_empty());
+    assert_eq!(decapod["hash"].as_str().unwrap().len(), 64);
+
+    assert!(bundle["capabilities"]["version"].is_string());
+    assert!(
+        bundle["capabilities"]["capabilities"]
+            .as_array()
+            .is_some_and(|caps| !caps.is_empty())
+    );
+
+    assert_eq!(bundle["workspace"]["git_branch"], "master");
+    assert_eq!(bundle["workspace"]["git_is_protected"], true);
+    assert!(bundle["workspace"]["required_actions"].is_array());
+    assert!(bundle["repo_map"]["doc_count"].as_u64().unwrap() > 0);
+    assert!(bundle["repo_map"].get("doc_graph").is_none());
+
+    let again = run_decapod(dir, &["bootstrap", "--format", "json"]);
+    assert_eq!(out.stdout, again.stdout, "bundle must be deterministic");
+    assert!(out.stdout.len() < 64 * 1024, "bundle must stay bounded");
+
+    let text = run_decapod(dir, &["bootstrap", "--format", "text"]);
+    assert!(!text.status.success());
+}
@@ -59,6 +59,35 @@ fn test_capabilities_schema_stability() {
     assert!(!caps.is_empty(), "capabilities must not be empty");
 }
 
+#[test]
+fn test_capabilities_check_probes_single_feature() {
+    let (_tmp, dir) = setup_repo();
+
+    let known = run_decapod(
+        dir,
+        &[
+            "capabilities",
+            "--check",
+            "workspace.list",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(known.status.success(), "known capability must probe ok");
+    let json: serde_json::Value = serde_json::from_slice(&known.stdout).expect("valid JSON");
+    assert_eq!(json["found"], true);
+    assert_eq!(json["entry"]["kind"], "capability");
+    assert_eq!(json["entry"]["stability"], "beta");
+
+    let op = run_decapod(dir, &["capabilities", "--check", "todo.claim"]);
+    assert!(op.status.success(), "subsystem op must probe ok");
+    assert!(String::from_utf8_lossy(&op.stdout).contains("todo.claim [active] (op)"));
+
+    let unknown = run_decapod(dir, &["capabilities", "--check", "teleport.now"]);
+    assert!(!unknown.status.success(), "unknown op must exit non-zero");
+    assert!(String::from_utf8_lossy(&unknown.stderr).contains("teleport.now"));
+}
+
 #[test]
 fn test_schema_determinism_command() {
     let (_tmp, dir) = setup_repo();
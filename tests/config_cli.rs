@@ -0,0 +1,121 @@
+use decapod::core::settings::{SettingSource, Settings};
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str], envs: &[(&str, &str)]) -> std::process::Output {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_decapod"));
+    cmd.current_dir(dir)
+        .args(args)
+        .env_remove("DECAPOD_VALIDATE_TIMEOUT_SECS")
+        .env_remove("DECAPOD_VALIDATE_TIMEOUT_SECONDS");
+    for (k, v) in envs {
+        cmd.env(k, v);
+    }
+    cmd.output().expect("run decapod")
+}
+
+fn setup_repo() -> TempDir {
+    let tmp = TempDir::new().expect("tmpdir");
+    let init = Command::new("git")
+        .current_dir(tmp.path())
+        .args(["init", "-b", "master"])
+        .output()
+        .expect("git init");
+    assert!(init.status.success(), "git init failed");
+    let out = run_decapod(tmp.path(), &["init", "--force"], &[]);
+    assert!(
+        out.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    tmp
+}
+
+fn get_json(dir: &Path, key: &str, envs: &[(&str, &str)]) -> Value {
+    let out = run_decapod(dir, &["config", "get", key, "--format", "json"], envs);
+    assert!(
+        out.status.success(),
+        "config get failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    serde_json::from_slice(&out.stdout).expect("config get JSON")
+}
+
+#[test]
+fn file_value_applies_until_env_var_overrides_it() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    let default = get_json(dir, "validate.timeout_secs", &[]);
+    assert_eq!(default["value"], "120");
+    assert_eq!(default["source"], "default");
+
+    let out = run_decapod(dir, &["config", "set", "validate.timeout_secs", "45"], &[]);
+    assert!(
+        out.status.success(),
+        "config set failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let raw = std::fs::read_to_string(dir.join(".decapod/config.toml")).expect("config.toml");
+    assert!(raw.contains("[validate]"), "{raw}");
+    assert!(raw.contains("[init]"), "existing sections kept: {raw}");
+
+    let from_file = get_json(dir, "validate.timeout_secs", &[]);
+    assert_eq!(from_file["value"], "45");
+    assert_eq!(from_file["source"], "file");
+
+    let from_env = get_json(
+        dir,
+        "validate.timeout_secs",
+        &[("DECAPOD_VALIDATE_TIMEOUT_SECS", "7")],
+    );
+    assert_eq!(from_env["value"], "7");
+    assert_eq!(from_env["source"], "env");
+
+    // The typed struct sees the same precedence.
+    let file_only = Settings::resolve(Some(dir), |_| None);
+    assert_eq!(file_only.validate_timeout_secs, 45);
+    let overridden = Settings::resolve(Some(dir), |k| {
+        (k == "DECAPOD_VALIDATE_TIMEOUT_SECS").then(|| "7".to_string())
+    });
+    assert_eq!(overridden.validate_timeout_secs, 7);
+    assert_eq!(overridden.session_ttl_secs, 3600);
+
+    // Re-running init keeps settings sections.
+    let out = run_decapod(dir, &["init", "--force"], &[]);
+    assert!(out.status.success(), "re-init failed");
+    assert_eq!(get_json(dir, "validate.timeout_secs", &[])["value"], "45");
+}
+
+#[test]
+fn set_rejects_unknown_keys_and_bad_values() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    let out = run_decapod(dir, &["config", "set", "validate.nope", "1"], &[]);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("Unknown config key"), "{stderr}");
+    assert!(stderr.contains("session.ttl_secs"), "{stderr}");
+
+    let out = run_decapod(dir, &["config", "set", "broker.disable", "maybe"], &[]);
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("true or false"));
+
+    let out = run_decapod(dir, &["config", "list", "--format", "json"], &[]);
+    assert!(out.status.success());
+    let listing: Value = serde_json::from_slice(&out.stdout).expect("list JSON");
+    assert_eq!(
+        listing["precedence"],
+        serde_json::json!(["env", "file", "default"])
+    );
+    let broker = listing["settings"]
+        .as_array()
+        .expect("settings")
+        .iter()
+        .find(|s| s["key"] == "broker.disable")
+        .expect("broker.disable listed");
+    assert_eq!(broker["source"], SettingSource::Default.as_str());
+}
@@ -0,0 +1,181 @@
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .env("DECAPOD_CLAIM_AUTORUN", "0")
+        .env_remove("DECAPOD_AGENT_ID")
+        .output()
+        .expect("run decapod")
+}
+
+fn ok(dir: &Path, args: &[&str]) -> std::process::Output {
+    let out = run_decapod(dir, args);
+    assert!(
+        out.status.success(),
+        "decapod {:?} failed:\nstdout: {}\nstderr: {}",
+        args,
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    out
+}
+
+/// Repo with validate, session, agent.init and context.resolve done, but no ingestion.
+fn setup_repo() -> TempDir {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    let init = Command::new("git")
+        .current_dir(dir)
+        .args(["init", "-b", "master"])
+        .output()
+        .expect("git init");
+    assert!(init.status.success(), "git init failed");
+    ok(dir, &["init", "--force"]);
+    ok(dir, &["validate"]);
+    ok(dir, &["session", "acquire"]);
+    ok(dir, &["rpc", "--op", "agent.init"]);
+    ok(dir, &["rpc", "--op", "context.resolve"]);
+    tmp
+}
+
+fn core_docs(dir: &Path) -> Vec<String> {
+    let out = ok(dir, &["docs", "list"]);
+    String::from_utf8_lossy(&out.stdout)
+        .split_whitespace()
+        .filter(|t| t.starts_with("core/") && t.ends_with(".md"))
+        .map(str::to_string)
+        .collect()
+}
+
+fn upsert(dir: &Path) -> std::process::Output {
+    run_decapod(
+        dir,
+        &[
+            "rpc",
+            "--op",
+            "store.upsert",
+            "--params",
+            r#"{"entity":"knowledge","payload":{"id":"K_AWARE","title":"t","text":"x","provenance":"cmd:test"}}"#,
+        ],
+    )
+}
+
+#[test]
+fn selective_ingestion_of_core_docs_satisfies_awareness() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+    let core = core_docs(dir);
+    assert!(core.len() > 1, "expected several core docs, got {:?}", core);
+
+    let out = ok(
+        dir,
+        &[
+            "docs",
+            "ingest",
+            "--path",
+            &format!("constitution/{}", core[0]),
+        ],
+    );
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains(&format!("--- BEGIN {} ---", core[0])));
+    assert_eq!(stdout.matches("--- BEGIN ").count(), 1);
+
+    let partial = upsert(dir);
+    let stderr = String::from_utf8_lossy(&partial.stderr);
+    assert!(!partial.status.success());
+    assert!(stderr.contains("core docs not yet ingested"), "{stderr}");
+    assert!(stderr.contains(&core[1]), "{stderr}");
+    assert!(!stderr.contains(&format!("{},", core[0])), "{stderr}");
+
+    let mut args = vec!["docs", "ingest"];
+    for doc in &core[1..] {
+        args.push("--path");
+        args.push(doc);
+    }
+    ok(dir, &args);
+
+    let full = upsert(dir);
+    let stderr = String::from_utf8_lossy(&full.stderr);
+    assert!(!stderr.contains("Constitutional awareness"), "{stderr}");
+
+    let record = dir.join(".decapod/generated/awareness/unknown.json");
+    let record: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&record).expect("awareness record")).unwrap();
+    let sources = record["ingested_sources"].as_object().expect("sources");
+    assert_eq!(sources.len(), core.len());
+    assert!(sources.values().all(|h| h.as_str().unwrap().len() == 64));
+}
+
+#[test]
+fn changed_core_doc_after_ingestion_demands_reingestion() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+    ok(dir, &["docs", "ingest"]);
+    let fresh = upsert(dir);
+    assert!(
+        !String::from_utf8_lossy(&fresh.stderr).contains("Constitutional awareness"),
+        "{}",
+        String::from_utf8_lossy(&fresh.stderr)
+    );
+
+    let override_path = dir.join(".decapod/OVERRIDE.md");
+    let body = std::fs::read_to_string(&override_path).expect("OVERRIDE.md");
+    assert!(body.contains("### core/DECAPOD.md\n"));
+    std::fs::write(
+        &override_path,
+        body.replacen(
+            "### core/DECAPOD.md\n",
+            "### core/DECAPOD.md\nProject rule: DB migrations need human review.\n",
+            1,
+        ),
+    )
+    .unwrap();
+
+    let stale = upsert(dir);
+    let stderr = String::from_utf8_lossy(&stale.stderr);
+    assert!(!stale.status.success());
+    assert!(stderr.contains("stale"), "{stderr}");
+    assert!(stderr.contains("core/DECAPOD.md"), "{stderr}");
+
+    ok(dir, &["docs", "ingest", "--path", "core/DECAPOD.md"]);
+    let reingested = upsert(dir);
+    assert!(
+        !String::from_utf8_lossy(&reingested.stderr).contains("Constitutional awareness"),
+        "{}",
+        String::from_utf8_lossy(&reingested.stderr)
+    );
+}
+
+#[test]
+fn awareness_expires_after_configured_ttl() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+    ok(dir, &["docs", "ingest"]);
+
+    let record_path = dir.join(".decapod/generated/awareness/unknown.json");
+    let mut record: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&record_path).unwrap()).unwrap();
+    let ingested = record["core_constitution_ingested_at_epoch_secs"]
+        .as_u64()
+        .expect("ingested at");
+    record["core_constitution_ingested_at_epoch_secs"] = (ingested - 120).into();
+    std::fs::write(&record_path, serde_json::to_string_pretty(&record).unwrap()).unwrap();
+
+    let expired = Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(["rpc", "--op", "store.upsert", "--params", "{}"])
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .env("DECAPOD_CLAIM_AUTORUN", "0")
+        .env_remove("DECAPOD_AGENT_ID")
+        .env("DECAPOD_AWARENESS_TTL_SECS", "60")
+        .output()
+        .expect("run decapod");
+    let stderr = String::from_utf8_lossy(&expired.stderr);
+    assert!(!expired.status.success());
+    assert!(stderr.contains("awareness expired"), "{stderr}");
+}
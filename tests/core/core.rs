@@ -1,13 +1,18 @@
 use decapod::core::assets;
 use decapod::core::broker::{self, BrokerEvent, DbBroker};
 use decapod::core::db;
+use decapod::core::docs;
 use decapod::core::docs_cli::{self, DocsCli, DocsCommand};
 use decapod::core::error::DecapodError;
 use decapod::core::external_action::{self, ExternalCapability};
+use decapod::core::mentor::{MentorEngine, ObligationsContext};
 use decapod::core::migration;
+use decapod::core::proof;
 use decapod::core::repomap;
 use decapod::core::scaffold::{ScaffoldOptions, scaffold_project_entrypoints};
 use decapod::core::schemas;
+use decapod::core::standards::{self, StandardsLayer};
+use decapod::core::state_commit;
 use decapod::core::store::{Store, StoreKind};
 use decapod::core::validate;
 use decapod::core::workspace;
@@ -185,6 +190,116 @@ fn external_action_broker_enforces_capability_allowlist() {
     assert!(allowed.is_ok(), "git status should be allowed for vcs_read");
 }
 
+#[test]
+fn external_action_policy_allows_git_read_and_denies_protected_push() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    let store_root = root.join(".decapod").join("data");
+    fs::create_dir_all(&store_root).expect("store root");
+    let init = Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(root)
+        .output()
+        .expect("git init");
+    assert!(init.status.success());
+    fs::write(
+        root.join(".decapod").join("EXTERNAL_ACTIONS.json"),
+        r#"{
+  "policy": [
+    { "id": "protect-origin", "effect": "deny", "capability": "vcs_write", "command": "git", "args_prefix": ["push", "origin"] },
+    { "id": "reads-ok", "effect": "allow", "capability": "vcs_read", "command": "git" }
+  ]
+}"#,
+    )
+    .expect("write policy");
+
+    let allowed = external_action::execute(
+        &store_root,
+        ExternalCapability::VcsRead,
+        "test.scope",
+        "git",
+        &["status", "--porcelain"],
+        root,
+    );
+    assert!(allowed.is_ok(), "git read should be allowed: {allowed:?}");
+
+    let denied = external_action::execute(
+        &store_root,
+        ExternalCapability::VcsWrite,
+        "test.scope",
+        "git",
+        &["push", "origin", "main"],
+        root,
+    );
+    match denied {
+        Err(DecapodError::ValidationError(msg)) => {
+            assert!(msg.contains("policy rule 'protect-origin'"), "{msg}");
+        }
+        other => panic!("expected policy denial, got {other:?}"),
+    }
+
+    let audit =
+        fs::read_to_string(store_root.join("external_actions.events.jsonl")).expect("audit log");
+    let events: Vec<serde_json::Value> = audit
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("audit event"))
+        .collect();
+    assert_eq!(events.len(), 2, "every attempt is audited: {audit}");
+    assert_eq!(events[0]["status"], "success");
+    assert_eq!(events[0]["rule"], "reads-ok");
+    assert_eq!(events[1]["status"], "denied");
+    assert_eq!(events[1]["rule"], "protect-origin");
+    assert_eq!(
+        events[1]["args"],
+        serde_json::json!(["push", "origin", "main"])
+    );
+}
+
+#[test]
+fn external_action_dry_run_logs_git_commit_without_moving_head() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    let store_root = root.join(".decapod").join("data");
+    fs::create_dir_all(&store_root).expect("store root");
+    let git = |args: &[&str]| {
+        let out = Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@example.com"])
+            .args(args)
+            .current_dir(root)
+            .output()
+            .expect("git");
+        assert!(out.status.success(), "git {args:?} failed");
+        String::from_utf8_lossy(&out.stdout).trim().to_string()
+    };
+    git(&["init", "-q"]);
+    git(&["commit", "-q", "--allow-empty", "-m", "initial"]);
+    let head_before = git(&["rev-parse", "HEAD"]);
+
+    let output = external_action::execute_with(
+        &store_root,
+        ExternalCapability::VcsWrite,
+        "todo.handoff.reconcile",
+        "git",
+        &["commit", "--allow-empty", "-m", "dry run"],
+        root,
+        true,
+    )
+    .expect("dry-run commit");
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    assert_eq!(git(&["rev-parse", "HEAD"]), head_before);
+    let audit =
+        fs::read_to_string(store_root.join("external_actions.events.jsonl")).expect("audit log");
+    let event: serde_json::Value =
+        serde_json::from_str(audit.lines().last().expect("event")).expect("audit event");
+    assert_eq!(event["status"], "dry_run");
+    assert_eq!(
+        event["args"],
+        serde_json::json!(["commit", "--allow-empty", "-m", "dry run"])
+    );
+}
+
 #[test]
 fn broker_policy_enforces_trust_tier_on_high_risk_mutator_ops() {
     let tmp = tempdir().expect("tempdir");
@@ -350,6 +465,173 @@ fn worktree_config_prune_preserves_live_section() {
     assert!(get.status.success(), "live section should remain");
 }
 
+#[test]
+fn workspace_publish_refuses_untracked_files_unless_all() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    let git = |args: &[&str]| {
+        let out = Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@example.com"])
+            .args(args)
+            .current_dir(root)
+            .output()
+            .expect("git");
+        assert!(out.status.success(), "git {args:?} failed");
+    };
+    git(&["init", "-q"]);
+    fs::write(root.join("README.md"), "hi\n").expect("write readme");
+    git(&["add", "README.md"]);
+    git(&["commit", "-q", "-m", "initial"]);
+    workspace::check_publish_tree(root, false).expect("clean tree publishes");
+
+    fs::write(root.join("notes.txt"), "new\n").expect("write untracked");
+    fs::write(root.join("README.md"), "changed\n").expect("modify tracked");
+    assert_eq!(
+        workspace::uncommitted_paths(root).expect("status"),
+        vec![" M README.md".to_string(), "?? notes.txt".to_string()]
+    );
+
+    let err = workspace::check_publish_tree(root, false).expect_err("dirty tree refused");
+    let msg = err.to_string();
+    assert!(msg.contains("2 uncommitted path(s)"), "{msg}");
+    assert!(msg.contains("?? notes.txt"), "{msg}");
+    assert!(msg.contains("--all"), "{msg}");
+
+    workspace::check_publish_tree(root, true).expect("--all proceeds");
+}
+
+#[test]
+fn workspace_branch_policy_generates_and_rejects_branch_names() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+
+    let policy = workspace::BranchPolicy::load(root);
+    assert_eq!(policy.branch_template, workspace::DEFAULT_BRANCH_TEMPLATE);
+    let generated = policy.render("Agent One", "todo-AB12cd Fix: login!");
+    assert_eq!(generated, "agent/agent-one/todo-ab12cd-fix-login");
+    policy.check(&generated).expect("generated branch conforms");
+
+    for bad in ["main", "release/v1"] {
+        let err = policy.check(bad).expect_err("protected branch");
+        assert!(err.to_string().contains("protected"), "{err}");
+    }
+    let err = policy.check("feature/login").expect_err("malformed branch");
+    let msg = err.to_string();
+    assert!(msg.contains("agent/<id>/<slug>"), "{msg}");
+    assert!(msg.contains("agent/agent-1/my-topic"), "{msg}");
+
+    fs::create_dir_all(root.join(".decapod")).expect("decapod dir");
+    fs::write(
+        root.join(".decapod").join("config.toml"),
+        "[workspace]\nbranch_template = \"work/<id>.<slug>\"\n",
+    )
+    .expect("write config");
+    let custom = workspace::BranchPolicy::load(root);
+    assert_eq!(custom.render("bot", "Topic"), "work/bot.topic");
+    custom.check("work/bot.topic").expect("custom template");
+    assert!(custom.check("agent/bot/topic").is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn workspace_publish_pr_invokes_gh_with_configured_base_and_title() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    fs::create_dir_all(root.join(".decapod")).expect("decapod dir");
+    fs::write(
+        root.join(".decapod").join("config.toml"),
+        "schema_version = \"1.0.0\"\n\n[publish]\nbase = \"develop\"\nreviewers = [\"octocat\"]\n",
+    )
+    .expect("write config");
+    let mut config = workspace::load_publish_config(root);
+    assert_eq!(config.base.as_deref(), Some("develop"));
+
+    let log = root.join("gh.log");
+    let gh = root.join("fake-gh");
+    fs::write(
+        &gh,
+        format!(
+            "#!/bin/sh\nprintf '%s\\n' \"$@\" >> '{}'\necho https://github.com/o/r/pull/7\n",
+            log.display()
+        ),
+    )
+    .expect("write fake gh");
+    fs::set_permissions(&gh, fs::Permissions::from_mode(0o755)).expect("chmod fake gh");
+
+    config.bin = Some(gh.to_string_lossy().into_owned());
+    let url = workspace::create_review_request(
+        root,
+        "agent/feat-x",
+        "Add feature X",
+        Some("Body text"),
+        &config,
+    );
+
+    assert_eq!(
+        url.expect("create pr").as_deref(),
+        Some("https://github.com/o/r/pull/7")
+    );
+    let invoked = fs::read_to_string(&log).expect("gh log");
+    let create: Vec<&str> = invoked.lines().skip_while(|l| *l != "pr").collect();
+    assert_eq!(
+        create,
+        vec![
+            "pr",
+            "create",
+            "--head",
+            "agent/feat-x",
+            "--title",
+            "Add feature X",
+            "--body",
+            "Body text",
+            "--base",
+            "develop",
+            "--reviewer",
+            "octocat",
+        ]
+    );
+}
+
+#[test]
+fn workspace_publish_gitlab_mr_targets_configured_branches() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    fs::create_dir_all(root.join(".decapod")).expect("decapod dir");
+    fs::write(
+        root.join(".decapod").join("config.toml"),
+        "[publish]\nforge = \"gitlab\"\nbase = \"main\"\n",
+    )
+    .expect("write config");
+    let config = workspace::load_publish_config(root);
+    assert_eq!(config.forge, workspace::Forge::Gitlab);
+
+    let args = config
+        .forge
+        .create_args("agent/feat-y", "Add feature Y", None, &config);
+    let value_of = |flag: &str| {
+        args.iter()
+            .position(|a| a == flag)
+            .map(|i| args[i + 1].as_str())
+    };
+    assert_eq!(&args[..2], ["mr", "create"]);
+    assert_eq!(value_of("--source-branch"), Some("agent/feat-y"));
+    assert_eq!(value_of("--target-branch"), Some("main"));
+    assert_eq!(value_of("--title"), Some("Add feature Y"));
+    assert!(args.iter().any(|a| a == "--yes"), "glab must not prompt");
+
+    let none = workspace::PublishConfig {
+        forge: workspace::Forge::None,
+        ..config
+    };
+    assert_eq!(
+        workspace::create_review_request(root, "agent/feat-y", "t", None, &none)
+            .expect("forge none"),
+        None
+    );
+}
+
 #[test]
 #[ignore = "run in PR migration-script gate when migration scripts change"]
 fn migration_reconstructs_legacy_events_from_fixture() {
@@ -1088,3 +1370,577 @@ This is the ACTUAL override content
     assert!(content.contains("ACTUAL override content"));
     assert!(!content.contains("just an example"));
 }
+
+#[test]
+fn standards_branch_layer_overrides_repo_layer_and_reports_source() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    init_git_repo(root);
+    let checkout = Command::new("git")
+        .current_dir(root)
+        .args(["checkout", "-q", "-b", "feature/strict"])
+        .output()
+        .expect("git checkout");
+    assert!(checkout.status.success());
+
+    fs::create_dir_all(root.join(".decapod/overrides")).expect("mkdir overrides");
+    fs::write(
+        root.join(".decapod/OVERRIDE.md"),
+        "## Testing\ncoverage_target: 90\nframework: nextest\n",
+    )
+    .expect("write OVERRIDE.md");
+    fs::write(
+        root.join(".decapod/overrides/feature%2Fstrict.md"),
+        "## Testing\ncoverage_target: 95\n",
+    )
+    .expect("write branch override");
+    // A sibling branch whose name only differs by `-` must not be picked up.
+    fs::write(
+        root.join(".decapod/overrides/feature-strict.md"),
+        "## Testing\ncoverage_target: 10\n",
+    )
+    .expect("write sibling override");
+    assert_ne!(
+        standards::branch_override_file_name("feature/strict"),
+        standards::branch_override_file_name("feature-strict")
+    );
+
+    let resolved = standards::resolve_standards(root).expect("resolve standards");
+    assert_eq!(
+        standards::get_standard(&resolved, "testing", "coverage_target"),
+        Some(serde_json::json!(95))
+    );
+    assert_eq!(
+        standards::standard_source(&resolved, "testing", "coverage_target"),
+        Some(StandardsLayer::Branch)
+    );
+    assert_eq!(
+        standards::get_standard(&resolved, "testing", "framework"),
+        Some(serde_json::json!("nextest"))
+    );
+    assert_eq!(
+        standards::standard_source(&resolved, "testing", "framework"),
+        Some(StandardsLayer::Repo)
+    );
+    assert_eq!(
+        standards::standard_source(&resolved, "security", "secret_scanning"),
+        Some(StandardsLayer::Embedded)
+    );
+    let applied: Vec<_> = resolved
+        .layers
+        .iter()
+        .filter(|l| l.applied)
+        .map(|l| l.layer)
+        .collect();
+    assert_eq!(
+        applied,
+        vec![
+            StandardsLayer::Embedded,
+            StandardsLayer::Repo,
+            StandardsLayer::Branch
+        ]
+    );
+}
+
+#[test]
+fn mentor_obligations_cache_hits_identical_context_and_keeps_contradictions_live() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    let engine = MentorEngine::new(root).with_cache_ttl(300);
+    let ctx = ObligationsContext {
+        op: "db.change".to_string(),
+        params: serde_json::json!({}),
+        touched_paths: vec!["postgres".to_string()],
+        diff_summary: Some("swap storage engine".to_string()),
+        project_profile_id: None,
+        session_id: None,
+        high_risk: false,
+    };
+
+    let first = engine.compute_obligations(&ctx).expect("first compute");
+    assert!(!first.cache_hit, "cold cache must compute fresh");
+    assert!(first.contradictions.is_empty());
+
+    // A new ADR lands between calls: base obligations may come from cache,
+    // but contradiction detection must see it.
+    fs::create_dir_all(root.join("docs/decisions")).expect("mkdir decisions");
+    fs::write(
+        root.join("docs/decisions/0001-storage.md"),
+        "# Storage\n\nWe must use postgres for all persistence.\n",
+    )
+    .expect("write adr");
+
+    let second = engine.compute_obligations(&ctx).expect("second compute");
+    assert!(second.cache_hit, "identical context should hit the cache");
+    assert!(
+        !second.contradictions.is_empty(),
+        "contradictions must be computed against live state"
+    );
+
+    let mut changed = ctx.clone();
+    changed.touched_paths = vec!["src/lib.rs".to_string()];
+    let third = engine.compute_obligations(&changed).expect("third compute");
+    assert!(!third.cache_hit, "changed touched_paths must recompute");
+    assert_ne!(
+        MentorEngine::context_signature(&ctx),
+        MentorEngine::context_signature(&changed)
+    );
+}
+
+#[test]
+fn mentor_obligations_cache_evicts_oldest_entries_beyond_cap() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    let engine = MentorEngine::new(root)
+        .with_cache_ttl(300)
+        .with_cache_max_entries(2);
+    let cache_dir = root.join(".decapod/data/mentor_cache");
+
+    let mut signatures = Vec::new();
+    for path in ["src/a.rs", "src/b.rs", "src/c.rs"] {
+        let ctx = ObligationsContext {
+            op: "code.change".to_string(),
+            params: serde_json::json!({}),
+            touched_paths: vec![path.to_string()],
+            diff_summary: None,
+            project_profile_id: None,
+            session_id: None,
+            high_risk: false,
+        };
+        engine.compute_obligations(&ctx).expect("compute");
+        signatures.push(MentorEngine::context_signature(&ctx));
+        // Keep write times distinct so eviction order is deterministic.
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+
+    let cached = fs::read_dir(&cache_dir).expect("cache dir").count();
+    assert_eq!(cached, 2, "cache must stay within its entry cap");
+    assert!(!cache_dir.join(format!("{}.json", signatures[0])).exists());
+    assert!(cache_dir.join(format!("{}.json", signatures[2])).exists());
+}
+
+#[test]
+fn proof_expect_exit_passes_expected_failure_and_flags_unexpected_success() {
+    let tmp = tempdir().expect("tmpdir");
+    let root = tmp.path();
+    let store_root = root.join(".decapod").join("data");
+    fs::create_dir_all(&store_root).expect("store root");
+    fs::write(
+        root.join(".decapod").join("proofs.toml"),
+        r#"
+[[proof]]
+name = "rejects_bad_input"
+command = "sh"
+args = ["-c", "echo 'bad input' >&2; exit 1"]
+required = true
+expect_exit = 1
+expect_stderr_contains = "bad input"
+
+[[proof]]
+name = "must_fail_but_succeeds"
+command = "sh"
+args = ["-c", "echo ok"]
+required = true
+expect_exit = 1
+"#,
+    )
+    .expect("write proofs.toml");
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: store_root,
+    };
+
+    let summary = proof::run_proofs(&store, root, "test").expect("run proofs");
+    let by_name = |name: &str| {
+        summary
+            .results
+            .iter()
+            .find(|r| r.name == name)
+            .expect("proof result")
+    };
+
+    let expected_failure = by_name("rejects_bad_input");
+    assert_eq!(expected_failure.exit_code, 1);
+    assert!(expected_failure.passed, "{:?}", expected_failure.mismatches);
+
+    let unexpected_success = by_name("must_fail_but_succeeds");
+    assert_eq!(unexpected_success.exit_code, 0);
+    assert!(!unexpected_success.passed);
+    assert_eq!(
+        unexpected_success.mismatches,
+        vec!["expected exit 1, got 0".to_string()]
+    );
+    assert_eq!((summary.passed, summary.failed), (1, 1));
+}
+
+#[test]
+fn workspace_status_reports_cached_worktree_size_and_age() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path().join("repo");
+    fs::create_dir_all(&root).expect("repo dir");
+    init_git_repo(&root);
+    let wt = tmp.path().join("wt");
+    let add = Command::new("git")
+        .current_dir(&root)
+        .args(["worktree", "add", "-b", "agent/usage"])
+        .arg(&wt)
+        .output()
+        .expect("git worktree add");
+    assert!(add.status.success(), "worktree add failed");
+    fs::create_dir_all(wt.join(".decapod")).expect("decapod dir");
+
+    let base = workspace::get_workspace_status(&wt).expect("status");
+    assert!(base.git.in_worktree);
+    assert_eq!(
+        base.git.worktree_size_bytes, None,
+        "size is only measured on request"
+    );
+
+    let status = workspace::with_worktree_usage(base.clone(), &wt);
+    let size = status.git.worktree_size_bytes.expect("worktree size");
+    assert!(size >= "# test\n".len() as u64);
+    assert!(status.git.worktree_age_secs.is_some());
+    assert!(wt.join(".decapod/generated/worktree_usage.json").exists());
+
+    // Same HEAD within the TTL reuses the cached size instead of re-walking.
+    fs::write(wt.join("big.bin"), vec![0u8; 4096]).expect("grow worktree");
+    let cached = workspace::with_worktree_usage(base, &wt);
+    assert_eq!(cached.git.worktree_size_bytes, Some(size));
+}
+
+#[test]
+fn migration_backup_restore_returns_pre_mutation_state() {
+    let tmp = tempdir().unwrap();
+    let data = tmp.path().join(".decapod").join("data");
+    fs::create_dir_all(&data).unwrap();
+    let db_path = data.join("notes.db");
+    let events = data.join("notes.events.jsonl");
+    let count_rows = || -> i64 {
+        rusqlite::Connection::open(&db_path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM notes", [], |row| row.get(0))
+            .unwrap()
+    };
+    {
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        conn.execute("CREATE TABLE notes (body TEXT)", []).unwrap();
+        conn.execute("INSERT INTO notes VALUES ('before')", [])
+            .unwrap();
+    }
+    fs::write(&events, "{\"op\":\"before\"}\n").unwrap();
+
+    let (_, manifest) = migration::create_data_backup_with_reason(&data, "migration")
+        .unwrap()
+        .expect("data root exists");
+    let listed = migration::list_backups(&data).unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, manifest.id);
+    assert_eq!(listed[0].decapod_version, migration::DECAPOD_VERSION);
+    assert_eq!(
+        listed[0].size_bytes,
+        fs::metadata(&db_path).unwrap().len() + fs::metadata(&events).unwrap().len()
+    );
+
+    rusqlite::Connection::open(&db_path)
+        .unwrap()
+        .execute("INSERT INTO notes VALUES ('after')", [])
+        .unwrap();
+    fs::write(&events, "{\"op\":\"before\"}\n{\"op\":\"after\"}\n").unwrap();
+    fs::write(data.join("extra.jsonl"), "{}\n").unwrap();
+    assert_eq!(count_rows(), 2);
+
+    let restore = migration::restore_backup(&data, &manifest.id).unwrap();
+    assert_eq!(restore.restored, manifest.id);
+    assert_eq!(count_rows(), 1);
+    assert_eq!(
+        fs::read_to_string(&events).unwrap(),
+        "{\"op\":\"before\"}\n"
+    );
+    assert!(
+        !data.join("extra.jsonl").exists(),
+        "files absent from backup removed"
+    );
+    assert!(
+        !data.join(format!(".restore_{}", manifest.id)).exists(),
+        "staging directory cleaned up"
+    );
+
+    let listed = migration::list_backups(&data).unwrap();
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed[0].id, restore.archived);
+    assert_eq!(listed[0].reason, "pre_restore");
+
+    // The archived state can itself be restored.
+    migration::restore_backup(&data, &restore.archived).unwrap();
+    assert_eq!(count_rows(), 2);
+}
+
+#[test]
+fn migration_backup_keeps_writes_still_in_the_wal() {
+    let tmp = tempdir().unwrap();
+    let data = tmp.path().join(".decapod").join("data");
+    fs::create_dir_all(&data).unwrap();
+    let db_path = data.join("notes.db");
+
+    // Held open with auto-checkpointing off, so the rows live only in -wal.
+    let conn = rusqlite::Connection::open(&db_path).unwrap();
+    conn.query_row("PRAGMA journal_mode=WAL", [], |_| Ok(()))
+        .unwrap();
+    conn.pragma_update(None, "wal_autocheckpoint", 0).unwrap();
+    conn.execute("CREATE TABLE notes (body TEXT)", []).unwrap();
+    conn.execute("INSERT INTO notes VALUES ('in-wal')", [])
+        .unwrap();
+    assert!(data.join("notes.db-wal").exists());
+
+    let (_, manifest) = migration::create_data_backup_with_reason(&data, "migration")
+        .unwrap()
+        .unwrap();
+    conn.execute("INSERT INTO notes VALUES ('after')", [])
+        .unwrap();
+    migration::restore_backup(&data, &manifest.id).unwrap();
+
+    let rows: Vec<String> = {
+        let fresh = rusqlite::Connection::open(&db_path).unwrap();
+        let mut stmt = fresh.prepare("SELECT body FROM notes").unwrap();
+        stmt.query_map([], |row| row.get(0))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+    };
+    assert_eq!(rows, vec!["in-wal".to_string()]);
+    drop(conn);
+}
+
+#[test]
+fn migration_backup_restore_refuses_tampered_backup_or_held_broker_lock() {
+    let tmp = tempdir().unwrap();
+    let data = tmp.path().join(".decapod").join("data");
+    fs::create_dir_all(&data).unwrap();
+    fs::write(data.join("notes.events.jsonl"), "{}\n").unwrap();
+    let (dir, manifest) = migration::create_data_backup_with_reason(&data, "migration")
+        .unwrap()
+        .unwrap();
+
+    let mut holder = Command::new("sleep").arg("30").spawn().unwrap();
+    fs::write(data.join("broker.lock"), format!("{}\n", holder.id())).unwrap();
+    let err = migration::restore_backup(&data, &manifest.id)
+        .unwrap_err()
+        .to_string();
+    holder.kill().unwrap();
+    holder.wait().unwrap();
+    assert!(err.contains("holds the broker lock"), "{err}");
+    fs::remove_file(data.join("broker.lock")).unwrap();
+
+    fs::write(dir.join("notes.events.jsonl"), "tampered\n").unwrap();
+    let err = migration::restore_backup(&data, &manifest.id)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("failed integrity check"), "{err}");
+    assert_eq!(
+        migration::list_backups(&data).unwrap().len(),
+        1,
+        "nothing archived when verification fails"
+    );
+}
+
+#[test]
+fn path_binding_matches_components_and_prefers_longest() {
+    let paths: std::collections::HashMap<String, String> = [
+        ("src/plugins", "plugins/README.md"),
+        ("src/plugins/federation", "plugins/FEDERATION.md#Graph"),
+        ("rpc", "interfaces/CONTROL_PLANE.md"),
+        ("tests/*_rpc", "interfaces/TESTING.md"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+
+    // Deep path, Windows separators: the more specific binding wins.
+    assert_eq!(
+        docs::match_path_binding(&paths, r".\src\plugins\federation\store\graph.rs"),
+        Some("plugins/FEDERATION.md#Graph")
+    );
+    assert_eq!(
+        docs::match_path_binding(&paths, "src/plugins/todo.rs"),
+        Some("plugins/README.md")
+    );
+    assert_eq!(
+        docs::match_path_binding(&paths, "src/core/rpc.rs"),
+        Some("interfaces/CONTROL_PLANE.md"),
+        "final component matches on file stem"
+    );
+    assert_eq!(
+        docs::match_path_binding(&paths, "tests/context_rpc/mod.rs"),
+        Some("interfaces/TESTING.md")
+    );
+    // Substring-only hits no longer bind.
+    assert_eq!(
+        docs::match_path_binding(&paths, "src/grpcx/client.rs"),
+        None
+    );
+    assert_eq!(
+        docs::match_path_binding(&paths, "vendor/src/pluginsx/a.rs"),
+        None
+    );
+}
+
+fn commit_all(path: &std::path::Path, message: &str) -> String {
+    for args in [vec!["add", "-A"], vec!["commit", "-m", message]] {
+        let out = Command::new("git")
+            .current_dir(path)
+            .args(&args)
+            .output()
+            .expect("git");
+        assert!(out.status.success(), "git {:?} failed", args);
+    }
+    state_commit::run_git(path, &["rev-parse", "HEAD"]).expect("rev-parse")
+}
+
+#[test]
+fn state_commit_prove_since_matches_full_recompute() {
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    init_git_repo(root);
+    let base = state_commit::run_git(root, &["rev-parse", "HEAD"]).expect("base");
+
+    let fixture =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/state_commit_repo");
+    for name in [
+        "initial.txt",
+        "regular.txt",
+        "target.txt",
+        "日本語ファイル.txt",
+    ] {
+        fs::copy(fixture.join(name), root.join(name)).expect("copy fixture");
+    }
+    let first_head = commit_all(root, "fixture");
+    let input = |head: &str| state_commit::StateCommitInput {
+        base_sha: base.clone(),
+        head_sha: head.to_string(),
+        ignore_policy_hash: "da39a3ee5e6b4b0d3255bfef95601890afd80709".to_string(),
+    };
+    let prior = state_commit::prove(&input(&first_head), root).expect("prior prove");
+
+    fs::write(root.join("regular.txt"), "changed content\n").expect("modify");
+    fs::write(root.join("added.txt"), "new\n").expect("add");
+    let head = commit_all(root, "change");
+
+    let full = state_commit::prove(&input(&head), root).expect("full prove");
+    let record =
+        state_commit::decode_scope_record(&prior.scope_record_bytes).expect("decode prior");
+    assert_eq!(record.head_sha, first_head);
+    assert_eq!(record.entries.len(), 4);
+    assert_eq!(record.algo_version, "state_commit.v1");
+    assert_eq!(record.record_version, 1);
+    let incremental = state_commit::prove_since(&input(&head), root, &record).expect("since");
+
+    assert_eq!(incremental.state_commit_root, full.state_commit_root);
+    assert_eq!(incremental.scope_record_bytes, full.scope_record_bytes);
+    assert_eq!(incremental.reused_entries, 3);
+
+    let mismatched = state_commit::StateCommitInput {
+        base_sha: first_head.clone(),
+        ..input(&head)
+    };
+    let err = state_commit::prove_since(&mismatched, root, &record)
+        .err()
+        .expect("base mismatch must be rejected");
+    assert!(err.contains("does not match base"), "{}", err);
+}
+
+#[cfg(unix)]
+#[test]
+fn state_commit_preserves_non_utf8_paths_and_bytes() {
+    use sha2::{Digest, Sha256};
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    init_git_repo(root);
+    let base = state_commit::run_git(root, &["rev-parse", "HEAD"]).expect("base");
+
+    // Latin-1 "café.bin": not valid UTF-8, so lossy decoding would rewrite it.
+    let raw_name: &[u8] = b"caf\xe9.bin";
+    let blob_a: &[u8] = b"\xff\xfe binary \x80";
+    let blob_b: &[u8] = b"\xfe\xff binary \x81";
+    fs::write(root.join(OsStr::from_bytes(raw_name)), blob_a).expect("write raw name");
+    fs::write(root.join("naïve [1].bin"), blob_b).expect("write unicode name");
+    let head = commit_all(root, "odd names");
+
+    let input = state_commit::StateCommitInput {
+        base_sha: base,
+        head_sha: head,
+        ignore_policy_hash: "da39a3ee5e6b4b0d3255bfef95601890afd80709".to_string(),
+    };
+    let first = state_commit::prove(&input, root).expect("prove");
+    let second = state_commit::prove(&input, root).expect("prove again");
+    assert_eq!(first.scope_record_bytes, second.scope_record_bytes);
+    assert_eq!(first.state_commit_root, second.state_commit_root);
+
+    let record = state_commit::decode_scope_record(&first.scope_record_bytes).expect("decode");
+    assert_eq!(record.algo_version, "state_commit.v2");
+    assert_eq!(record.record_version, state_commit::RECORD_VERSION);
+    let paths: Vec<&[u8]> = record.entries.iter().map(|e| e.path.as_slice()).collect();
+    assert_eq!(paths, vec![raw_name, "naïve [1].bin".as_bytes()]);
+
+    let raw = &record.entries[0];
+    assert_eq!(raw.content_hash, format!("{:x}", Sha256::digest(blob_a)));
+    assert_eq!(raw.size, blob_a.len() as u64);
+    assert_ne!(raw.content_hash, record.entries[1].content_hash);
+
+    assert!(
+        state_commit::diff_against_worktree(&record, root)
+            .expect("diff")
+            .is_empty()
+    );
+}
+
+#[test]
+fn state_commit_v1_records_keep_lossy_content_digests() {
+    use sha2::{Digest, Sha256};
+
+    let tmp = tempdir().expect("tempdir");
+    let root = tmp.path();
+    init_git_repo(root);
+    let base = state_commit::run_git(root, &["rev-parse", "HEAD"]).expect("base");
+    let blob: &[u8] = b"\xff\xfe binary \x80";
+    fs::write(root.join("blob.bin"), blob).expect("write blob");
+    let head = commit_all(root, "binary");
+
+    // What a v1 record holds for that blob: the digest of its lossy decode.
+    let lossy = String::from_utf8_lossy(blob);
+    let v1 = state_commit::ScopeRecord {
+        algo_version: "state_commit.v1".to_string(),
+        base_sha: base.clone(),
+        head_sha: head.clone(),
+        record_version: 1,
+        ignore_policy_hash: "da39a3ee5e6b4b0d3255bfef95601890afd80709".to_string(),
+        entries: vec![state_commit::StateCommitEntry {
+            path: b"blob.bin".to_vec(),
+            kind: 0,
+            mode_exec: false,
+            content_hash: format!("{:x}", Sha256::digest(lossy.trim().as_bytes())),
+            size: lossy.trim().len() as u64,
+        }],
+    };
+    assert!(
+        state_commit::diff_against_worktree(&v1, root)
+            .expect("diff")
+            .is_empty(),
+        "v1 records are checked with v1 digests"
+    );
+
+    let input = state_commit::StateCommitInput {
+        base_sha: base,
+        head_sha: head,
+        ignore_policy_hash: v1.ignore_policy_hash.clone(),
+    };
+    let full = state_commit::prove(&input, root).expect("prove");
+    let since = state_commit::prove_since(&input, root, &v1).expect("prove since v1");
+    assert_eq!(
+        since.reused_entries, 0,
+        "lossy v1 digests are never carried over"
+    );
+    assert_eq!(since.scope_record_bytes, full.scope_record_bytes);
+}
@@ -0,0 +1,144 @@
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("run decapod")
+}
+
+fn setup_repo() -> TempDir {
+    let tmp = TempDir::new().expect("tmpdir");
+    let init = Command::new("git")
+        .current_dir(tmp.path())
+        .args(["init", "-b", "master"])
+        .output()
+        .expect("git init");
+    assert!(init.status.success(), "git init failed");
+    let out = run_decapod(tmp.path(), &["init", "--force"]);
+    assert!(
+        out.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    tmp
+}
+
+fn json_stdout(out: &std::process::Output) -> Value {
+    assert!(
+        out.status.success(),
+        "decapod failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    serde_json::from_slice(&out.stdout).expect("JSON stdout")
+}
+
+#[test]
+fn failing_job_records_failed_history_entry() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    let added = json_stdout(&run_decapod(
+        dir,
+        &[
+            "auto",
+            "cron",
+            "add",
+            "--name",
+            "nightly-sync",
+            "--schedule",
+            "0 2 * * *",
+            "--command",
+            "echo sync broke >&2; exit 3",
+        ],
+    ));
+    let id = added["id"].as_str().expect("job id").to_string();
+
+    let run = run_decapod(
+        dir,
+        &["auto", "cron", "run", "--id", &id, "--trigger", "schedule"],
+    );
+    assert!(String::from_utf8_lossy(&run.stderr).contains("FAILED"));
+    let run = json_stdout(&run);
+    assert_eq!(run["execution"]["status"], "failed");
+    assert_eq!(run["execution"]["exit_code"], 3);
+
+    let history = run_decapod(
+        dir,
+        &["auto", "cron", "history", "--id", &id, "--last", "5"],
+    );
+    let stderr = String::from_utf8_lossy(&history.stderr).to_string();
+    assert!(stderr.contains("1 of 1 recent runs FAILED"), "{stderr}");
+    let history = json_stdout(&history);
+    assert_eq!(history["failed"], 1);
+    let executions = history["executions"].as_array().expect("executions");
+    assert_eq!(executions.len(), 1);
+    let entry = &executions[0];
+    assert_eq!(entry["id"], run["execution"]["id"]);
+    assert_eq!(entry["job_id"], id.as_str());
+    assert_eq!(entry["trigger"], "schedule");
+    assert_eq!(entry["status"], "failed");
+    assert_eq!(entry["error"], "sync broke");
+    assert!(entry["started_at"].as_str().unwrap().ends_with('Z'));
+    assert!(entry["finished_at"].as_str().unwrap().ends_with('Z'));
+    assert_eq!(history["last_failure"], *entry);
+}
+
+#[test]
+fn paused_job_skips_scheduled_runs_and_resumes() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    let added = json_stdout(&run_decapod(
+        dir,
+        &[
+            "auto",
+            "cron",
+            "add",
+            "--name",
+            "hourly-touch",
+            "--schedule",
+            "0 * * * *",
+            "--command",
+            "echo tick >> ticks.log",
+        ],
+    ));
+    let id = added["id"].as_str().expect("job id").to_string();
+
+    let paused = json_stdout(&run_decapod(dir, &["auto", "cron", "pause", "--id", &id]));
+    assert_eq!(paused["enabled"], false);
+    assert!(paused["paused_at"].as_str().unwrap().ends_with('Z'));
+
+    let list = run_decapod(dir, &["auto", "cron", "list"]);
+    assert!(String::from_utf8_lossy(&list.stdout).contains("(paused since"));
+
+    let skipped = json_stdout(&run_decapod(
+        dir,
+        &["auto", "cron", "run", "--id", &id, "--trigger", "schedule"],
+    ));
+    assert_eq!(skipped["execution"]["status"], "skipped");
+    assert!(!dir.join("ticks.log").exists(), "paused job must not run");
+
+    let resumed = json_stdout(&run_decapod(dir, &["auto", "cron", "resume", "--id", &id]));
+    assert_eq!(resumed["enabled"], true);
+    assert_eq!(resumed["skipped_while_paused"], 1);
+
+    let ran = json_stdout(&run_decapod(
+        dir,
+        &["auto", "cron", "run", "--id", &id, "--trigger", "schedule"],
+    ));
+    assert_eq!(ran["execution"]["status"], "succeeded");
+    assert_eq!(
+        std::fs::read_to_string(dir.join("ticks.log")).unwrap(),
+        "tick\n"
+    );
+
+    let history = json_stdout(&run_decapod(dir, &["auto", "cron", "history", "--id", &id]));
+    assert_eq!(history["failed"], 0);
+    assert_eq!(history["executions"].as_array().unwrap().len(), 2);
+}
@@ -0,0 +1,106 @@
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_AGENT_ID", "snapshot-agent")
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("run decapod")
+}
+
+fn setup_repo() -> TempDir {
+    let tmp = TempDir::new().expect("tmpdir");
+    let init = Command::new("git")
+        .current_dir(tmp.path())
+        .args(["init", "-b", "master"])
+        .output()
+        .expect("git init");
+    assert!(init.status.success(), "git init failed");
+    let out = run_decapod(tmp.path(), &["init", "--force"]);
+    assert!(
+        out.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    tmp
+}
+
+#[test]
+fn snapshot_includes_workspace_status_and_redacts_session_token() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    let acquire = run_decapod(dir, &["session", "acquire"]);
+    assert!(
+        acquire.status.success(),
+        "session acquire failed: {}",
+        String::from_utf8_lossy(&acquire.stderr)
+    );
+    let session_path = dir.join(".decapod/generated/sessions/snapshot-agent.json");
+    let session: Value =
+        serde_json::from_str(&std::fs::read_to_string(&session_path).expect("session file"))
+            .expect("session json");
+    let token = session["token"].as_str().expect("token").to_string();
+    let password_hash = session["password_hash"].as_str().expect("hash").to_string();
+
+    let out = run_decapod(dir, &["doctor", "snapshot"]);
+    assert!(
+        out.status.success(),
+        "doctor snapshot failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let raw = String::from_utf8_lossy(&out.stdout);
+    assert!(!raw.contains(&token), "session token leaked: {raw}");
+    assert!(!raw.contains(&password_hash), "password hash leaked");
+
+    let snapshot: Value = serde_json::from_str(&raw).expect("snapshot json");
+    assert_eq!(snapshot["kind"], "doctor_snapshot");
+    assert_eq!(snapshot["workspace"]["git"]["current_branch"], "master");
+    assert!(snapshot["workspace"]["can_work"].is_boolean());
+    let sessions = snapshot["sessions"].as_array().expect("sessions");
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0]["agent_id"], "snapshot-agent");
+    assert_eq!(sessions[0]["token"], "[REDACTED]");
+    assert!(snapshot["schema_versions"]["todo"].as_u64().is_some());
+    assert!(snapshot["container_runtimes"]["docker"].is_boolean());
+    assert!(snapshot["validate_runs"].is_array());
+}
+
+#[test]
+fn session_status_json_masks_token_unless_show_secrets() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    let acquire = run_decapod(dir, &["session", "acquire"]);
+    assert!(acquire.status.success(), "session acquire failed");
+    let token = String::from_utf8_lossy(&acquire.stdout)
+        .lines()
+        .find_map(|l| l.strip_prefix("Token: ").map(|t| t.trim().to_string()))
+        .expect("token in acquire output");
+
+    let out = run_decapod(dir, &["session", "status", "--format", "json"]);
+    assert!(out.status.success(), "session status failed");
+    let raw = String::from_utf8_lossy(&out.stdout);
+    assert!(!raw.contains(&token), "token leaked by default: {raw}");
+    let masked: Value = serde_json::from_str(&raw).expect("status json");
+    assert_eq!(masked["active"], true);
+    assert_eq!(masked["record"]["token"], "[REDACTED]");
+    assert_eq!(masked["record"]["password_hash"], "[REDACTED]");
+    assert_eq!(masked["record"]["agent_id"], "snapshot-agent");
+
+    let text = run_decapod(dir, &["session", "status"]);
+    assert!(!String::from_utf8_lossy(&text.stdout).contains(&token));
+
+    let out = run_decapod(
+        dir,
+        &["session", "status", "--format", "json", "--show-secrets"],
+    );
+    assert!(out.status.success(), "session status --show-secrets failed");
+    let revealed: Value = serde_json::from_slice(&out.stdout).expect("status json");
+    assert_eq!(revealed["record"]["token"], token.as_str());
+}
@@ -548,6 +548,7 @@ fn t080_health() {
     ok(&dir, &["govern", "health", "summary"]);
     // T084
     ok(&dir, &["govern", "health", "autonomy"]);
+    ok(&dir, &["govern", "health", "attestations"]);
     // T085
     ok(&dir, &["govern", "health", "--help"]);
     // T086: claim with provenance
@@ -636,6 +637,8 @@ fn t110_feedback() {
     ok(&dir, &["govern", "feedback", "propose"]);
     // T113
     ok(&dir, &["govern", "feedback", "--help"]);
+    // T114
+    ok(&dir, &["govern", "feedback", "list", "--status", "all"]);
 }
 
 // ---------------------------------------------------------------------------
@@ -73,3 +73,29 @@ fn broker_strict_mode_blocks_mutator_bypass_when_disabled() {
         "expected typed strict-route error, got: {stderr}"
     );
 }
+
+#[test]
+fn broker_strict_mode_routes_feedback_apply_but_not_propose() {
+    let (_tmp, dir, password) = setup_repo();
+    let envs = [
+        ("DECAPOD_AGENT_ID", "unknown"),
+        ("DECAPOD_SESSION_PASSWORD", password.as_str()),
+        ("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1"),
+        ("DECAPOD_GROUP_BROKER_DISABLE", "1"),
+        ("DECAPOD_GROUP_BROKER_ENFORCE_ROUTE", "1"),
+    ];
+
+    let apply = run_decapod(&dir, &["govern", "feedback", "propose", "--apply"], &envs);
+    assert!(!apply.status.success(), "apply writes brokered stores");
+    let stderr = String::from_utf8_lossy(&apply.stderr);
+    assert!(
+        stderr.contains("BROKER_ROUTE_REQUIRED"),
+        "expected typed strict-route error, got: {stderr}"
+    );
+
+    let propose = run_decapod(&dir, &["govern", "feedback", "propose"], &envs);
+    assert!(
+        !String::from_utf8_lossy(&propose.stderr).contains("BROKER_ROUTE_REQUIRED"),
+        "a dry proposal is read-only"
+    );
+}
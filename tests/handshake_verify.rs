@@ -0,0 +1,152 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("run decapod")
+}
+
+fn stdout_json(out: &std::process::Output) -> Value {
+    serde_json::from_slice(&out.stdout).unwrap_or_else(|e| {
+        panic!(
+            "JSON stdout ({e}): {}\nstderr: {}",
+            String::from_utf8_lossy(&out.stdout),
+            String::from_utf8_lossy(&out.stderr)
+        )
+    })
+}
+
+fn setup_repo() -> TempDir {
+    let tmp = TempDir::new().expect("tmpdir");
+    let init = Command::new("git")
+        .current_dir(tmp.path())
+        .args(["init", "-b", "master"])
+        .output()
+        .expect("git init");
+    assert!(init.status.success(), "git init failed");
+    let out = run_decapod(tmp.path(), &["init", "--force"]);
+    assert!(
+        out.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    for rel in [
+        "CLAUDE.md",
+        "AGENTS.md",
+        "constitution/core/DECAPOD.md",
+        "constitution/interfaces/CONTROL_PLANE.md",
+    ] {
+        let path = tmp.path().join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        if !path.exists() {
+            fs::write(&path, format!("# {rel}\n")).unwrap();
+        }
+    }
+    tmp
+}
+
+#[test]
+fn verify_reports_drift_on_modified_declared_doc() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    let out = run_decapod(
+        dir,
+        &[
+            "handshake",
+            "--scope",
+            "docs",
+            "--proof",
+            "decapod validate",
+        ],
+    );
+    assert!(
+        out.status.success(),
+        "handshake failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let record = stdout_json(&out)["path"].as_str().unwrap().to_string();
+
+    let clean = run_decapod(dir, &["handshake", "verify", "--path", &record]);
+    assert!(clean.status.success());
+    let clean = stdout_json(&clean);
+    assert_eq!(clean["valid"], true);
+    assert_eq!(clean["artifact_hash_valid"], true);
+    assert!(clean["drift"].as_array().unwrap().is_empty());
+
+    fs::write(
+        dir.join("constitution/interfaces/CONTROL_PLANE.md"),
+        "# changed after handshake\n",
+    )
+    .unwrap();
+    let drifted = run_decapod(dir, &["handshake", "verify", "--path", &record]);
+    assert!(!drifted.status.success(), "drift fails the command");
+    let drifted = stdout_json(&drifted);
+    assert_eq!(drifted["valid"], false);
+    assert_eq!(drifted["status"], "drift");
+    assert_eq!(drifted["artifact_hash_valid"], true, "record itself intact");
+    let drift = drifted["drift"].as_array().unwrap();
+    assert_eq!(drift.len(), 1);
+    assert_eq!(drift[0]["doc"], "constitution/interfaces/CONTROL_PLANE.md");
+    assert_ne!(drift[0]["recorded"], drift[0]["current"]);
+
+    // Editing the record breaks its own seal.
+    let mut tampered: Value = serde_json::from_str(&fs::read_to_string(&record).unwrap()).unwrap();
+    tampered["scope"] = "everything".into();
+    fs::write(&record, serde_json::to_vec_pretty(&tampered).unwrap()).unwrap();
+    let tampered = stdout_json(&run_decapod(
+        dir,
+        &["handshake", "verify", "--path", &record],
+    ));
+    assert_eq!(tampered["artifact_hash_valid"], false);
+}
+
+#[test]
+fn changed_standards_override_invalidates_prior_handshake() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+    let out = run_decapod(
+        dir,
+        &[
+            "handshake",
+            "--scope",
+            "tests",
+            "--proof",
+            "decapod validate",
+        ],
+    );
+    assert!(out.status.success());
+    let record = stdout_json(&out)["path"].as_str().unwrap().to_string();
+    let pinned: Value = serde_json::from_str(&fs::read_to_string(&record).unwrap()).unwrap();
+    assert!(pinned["standards_hash"].as_str().is_some());
+
+    let before = stdout_json(&run_decapod(
+        dir,
+        &["handshake", "verify", "--path", &record],
+    ));
+    assert_eq!(before["valid"], true);
+    assert_eq!(before["standards"]["drift"], false);
+
+    // Docs untouched; only the layered standards move.
+    let override_path = dir.join(".decapod/OVERRIDE.md");
+    let existing = fs::read_to_string(&override_path).unwrap_or_default();
+    fs::write(
+        &override_path,
+        format!("{existing}\n## Testing\ncoverage_target: 97\n"),
+    )
+    .unwrap();
+    let after = run_decapod(dir, &["handshake", "verify", "--path", &record]);
+    assert!(!after.status.success());
+    let after = stdout_json(&after);
+    assert_eq!(after["valid"], false);
+    assert!(after["drift"].as_array().unwrap().is_empty());
+    assert_eq!(after["standards"]["drift"], true);
+    assert_eq!(after["standards"]["recorded"], pinned["standards_hash"]);
+}
@@ -0,0 +1,105 @@
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .args(args)
+        .current_dir(dir)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("run decapod");
+    assert!(
+        out.status.success(),
+        "decapod {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).to_string()
+}
+
+/// Reduce a JSON value to its type skeleton so the golden file pins field
+/// names and types without pinning timestamps or counts.
+fn shape(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::String("null".into()),
+        Value::Bool(_) => Value::String("bool".into()),
+        Value::Number(_) => Value::String("number".into()),
+        Value::String(_) => Value::String("string".into()),
+        Value::Array(items) => Value::Array(items.first().map(shape).into_iter().collect()),
+        Value::Object(map) => {
+            Value::Object(map.iter().map(|(k, v)| (k.clone(), shape(v))).collect())
+        }
+    }
+}
+
+#[test]
+fn health_summary_json_matches_golden_shape() {
+    let tmp = TempDir::new().expect("tempdir");
+    let dir = tmp.path();
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(dir)
+        .output()
+        .expect("git init");
+    run_decapod(dir, &["init", "--force"]);
+    run_decapod(dir, &["session", "acquire"]);
+    run_decapod(
+        dir,
+        &[
+            "govern",
+            "health",
+            "claim",
+            "--id",
+            "claim.build",
+            "--subject",
+            "build passes",
+            "--kind",
+            "proof",
+        ],
+    );
+    run_decapod(
+        dir,
+        &[
+            "govern",
+            "health",
+            "proof",
+            "--claim-id",
+            "claim.build",
+            "--surface",
+            "cargo build",
+            "--result",
+            "pass",
+        ],
+    );
+    run_decapod(dir, &["todo", "heartbeat"]);
+
+    let stdout = run_decapod(dir, &["govern", "health", "summary", "--format", "json"]);
+    let report: Value = serde_json::from_str(&stdout).expect("summary is JSON");
+    assert_eq!(report["schema_version"], "1.0.0");
+    assert_eq!(report["totals"]["pass"], 1);
+    assert_eq!(report["health_summary"]["VERIFIED"], 1);
+    assert_eq!(report["claims"][0]["id"], "claim.build");
+    assert_eq!(report["claims"][0]["status"], "pass");
+
+    let golden_path =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/health/v1/summary.shape.json");
+    let golden: Value =
+        serde_json::from_str(&fs::read_to_string(&golden_path).expect("read golden"))
+            .expect("parse golden");
+    assert_eq!(
+        shape(&report),
+        golden,
+        "health summary JSON shape drifted; bump HEALTH_SUMMARY_SCHEMA_VERSION and update {}",
+        golden_path.display()
+    );
+
+    let default: Value = serde_json::from_str(&run_decapod(dir, &["govern", "health", "summary"]))
+        .expect("summary defaults to JSON");
+    assert_eq!(default["schema_version"], report["schema_version"]);
+
+    let text = run_decapod(dir, &["govern", "health", "summary", "--format", "text"]);
+    assert!(text.starts_with("Health: 1 pass, 0 fail, 0 unknown"));
+}
@@ -168,3 +168,70 @@ fn init_with_accepts_noninteractive_spec_seed_env() {
         "architecture spec should include env-seeded architecture direction"
     );
 }
+
+fn category_names(project: &std::path::Path) -> Vec<String> {
+    decapod::core::todo::list_categories(&project.join(".decapod/data"))
+        .expect("list categories")
+        .into_iter()
+        .map(|c| c.name)
+        .collect()
+}
+
+#[test]
+fn init_profile_service_seeds_categories_zones_and_standards() {
+    let plain = tempdir().expect("tempdir");
+    let out = run_decapod(plain.path(), &["init", "--force"]);
+    assert!(out.status.success());
+    assert!(!plain.path().join(".decapod/STANDARDS.md").exists());
+    // Categories appear once the store is first opened; `init` alone seeds nothing.
+    let builtin = category_names(plain.path());
+    assert_eq!(builtin.len(), 15, "{builtin:?}");
+    assert!(!builtin.iter().any(|c| c == "observability"));
+
+    let tmp = tempdir().expect("tempdir");
+    let out = run_decapod(tmp.path(), &["init", "--force", "--profile", "service"]);
+    assert!(
+        out.status.success(),
+        "decapod init --profile failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let seeded = category_names(tmp.path());
+    for name in ["observability", "reliability", "config"] {
+        assert!(seeded.iter().any(|c| c == name), "missing {name}");
+    }
+    assert!(builtin.iter().all(|b| seeded.contains(b)));
+    assert_eq!(seeded.len(), builtin.len() + 3);
+
+    let riskmap = decapod::plugins::policy::load_risk_map(&tmp.path().join(".decapod/data"))
+        .expect("riskmap");
+    assert!(riskmap.zones.iter().any(|z| z.path == "migrations/"));
+
+    let resolved = decapod::core::standards::resolve_standards(tmp.path()).expect("standards");
+    assert_eq!(
+        decapod::core::standards::get_standard(&resolved, "cicd", "required_checks"),
+        Some(serde_json::json!(["test", "lint", "build", "integration"]))
+    );
+    let config = fs::read_to_string(tmp.path().join(".decapod/config.toml")).expect("config");
+    assert!(config.contains("profile = \"service\""));
+
+    // Re-running is additive and idempotent.
+    let again = run_decapod(tmp.path(), &["init", "--force"]);
+    assert!(again.status.success());
+    assert_eq!(category_names(tmp.path()).len(), seeded.len());
+    let riskmap = decapod::plugins::policy::load_risk_map(&tmp.path().join(".decapod/data"))
+        .expect("riskmap");
+    assert_eq!(riskmap.zones.len(), 3);
+}
+
+#[test]
+fn every_embedded_init_profile_parses() {
+    for name in decapod::core::profiles::PROFILE_NAMES {
+        let profile = decapod::core::profiles::load(name).expect("profile");
+        assert!(!profile.categories.is_empty(), "{name}");
+        assert!(!profile.risk_zones.is_empty(), "{name}");
+        assert!(profile.standards.contains("## "), "{name}");
+        let zones = serde_json::json!({ "zones": profile.risk_zones }).to_string();
+        assert!(decapod::plugins::policy::riskmap_issues(&zones).is_empty());
+    }
+    assert!(decapod::core::profiles::load("monorepo").is_err());
+}
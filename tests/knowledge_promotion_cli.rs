@@ -1,3 +1,5 @@
+use decapod::core::store::{Store, StoreKind};
+use decapod::plugins::knowledge;
 use serde_json::Value;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -241,3 +243,494 @@ fn procedural_knowledge_add_accepts_valid_promotion_event_provenance() {
         String::from_utf8_lossy(&add.stderr)
     );
 }
+
+#[test]
+fn knowledge_search_scope_all_merges_origin_tagged_results() {
+    let (_tmp, dir, password) = setup_repo();
+    let home = TempDir::new().expect("home tmpdir");
+    let home_str = home.path().to_string_lossy().to_string();
+    let envs = [
+        ("DECAPOD_AGENT_ID", "unknown"),
+        ("DECAPOD_SESSION_PASSWORD", password.as_str()),
+        ("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1"),
+        ("HOME", home_str.as_str()),
+    ];
+
+    let search = |scope: &str| -> Vec<Value> {
+        let out = run_decapod(
+            &dir,
+            &[
+                "data",
+                "knowledge",
+                "search",
+                "--query",
+                "retry",
+                "--scope",
+                scope,
+            ],
+            &envs,
+        );
+        assert!(
+            out.status.success(),
+            "knowledge search --scope {scope} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        serde_json::from_slice::<Value>(&out.stdout)
+            .expect("search json")
+            .as_array()
+            .expect("result array")
+            .clone()
+    };
+
+    let add = run_decapod(
+        &dir,
+        &[
+            "data",
+            "knowledge",
+            "add",
+            "--id",
+            "K_repo",
+            "--title",
+            "retry policy",
+            "--text",
+            "Repo retries use backoff",
+            "--provenance",
+            "commit:abc123",
+        ],
+        &envs,
+    );
+    assert!(
+        add.status.success(),
+        "repo knowledge add failed: {}",
+        String::from_utf8_lossy(&add.stderr)
+    );
+
+    // Blank slate: an untouched user store contributes nothing and stays absent.
+    let repo_only = search("all");
+    assert_eq!(repo_only.len(), 1);
+    assert_eq!(repo_only[0]["origin"], "repo");
+    let user_root = home.path().join(".decapod").join("data");
+    assert!(!user_root.join("knowledge.db").exists());
+
+    let user_store = Store {
+        kind: StoreKind::User,
+        root: user_root.clone(),
+    };
+    decapod::core::db::initialize_knowledge_db(&user_root).expect("init user knowledge db");
+    knowledge::add_knowledge(
+        &user_store,
+        knowledge::AddKnowledgeParams {
+            id: "K_user",
+            title: "retry policy",
+            content: "User retries use backoff",
+            provenance: "commit:def456",
+            claim_id: None,
+            merge_key: None,
+            conflict_policy: knowledge::KnowledgeConflictPolicy::Merge,
+            status: "active",
+            ttl_policy: "persistent",
+            expires_ts: None,
+            namespace: None,
+        },
+    )
+    .expect("seed user knowledge");
+
+    let merged = search("all");
+    let tagged: Vec<(&str, &str)> = merged
+        .iter()
+        .map(|e| (e["origin"].as_str().unwrap(), e["id"].as_str().unwrap()))
+        .collect();
+    assert_eq!(tagged, vec![("repo", "K_repo"), ("user", "K_user")]);
+
+    let user_only = search("user");
+    assert_eq!(user_only.len(), 1);
+    assert_eq!(user_only[0]["id"], "K_user");
+    assert_eq!(search("repo").len(), 1);
+}
+
+#[test]
+fn knowledge_add_text_file_derives_title_and_hashed_provenance() {
+    let (_tmp, dir, password) = setup_repo();
+    let envs = [
+        ("DECAPOD_AGENT_ID", "unknown"),
+        ("DECAPOD_SESSION_PASSWORD", password.as_str()),
+        ("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1"),
+    ];
+    let body = "# Flaky retry lesson\n\nFirst paragraph.\n\nSecond paragraph with `code`.\n";
+    fs::write(dir.join("lesson.md"), body).expect("write lesson");
+
+    let add = run_decapod(
+        &dir,
+        &[
+            "data",
+            "knowledge",
+            "add",
+            "--id",
+            "K_file",
+            "--text-file",
+            "lesson.md",
+        ],
+        &envs,
+    );
+    assert!(
+        add.status.success(),
+        "knowledge add --text-file failed: {}",
+        String::from_utf8_lossy(&add.stderr)
+    );
+
+    let out = run_decapod(
+        &dir,
+        &["data", "knowledge", "search", "--query", "Flaky"],
+        &envs,
+    );
+    assert!(out.status.success());
+    let results: Value = serde_json::from_slice(&out.stdout).expect("search json");
+    let entry = &results[0];
+    assert_eq!(entry["id"], "K_file");
+    assert_eq!(entry["title"], "Flaky retry lesson");
+    assert_eq!(entry["content"], body);
+    let provenance = entry["provenance"].as_str().expect("provenance");
+    assert_eq!(
+        provenance,
+        format!(
+            "file:lesson.md@sha256:{}",
+            decapod::core::project_specs::hash_text(body)
+        )
+    );
+
+    let both = run_decapod(
+        &dir,
+        &[
+            "data",
+            "knowledge",
+            "add",
+            "--id",
+            "K_both",
+            "--text",
+            "inline",
+            "--text-file",
+            "lesson.md",
+        ],
+        &envs,
+    );
+    assert!(!both.status.success(), "--text and --text-file conflict");
+}
+
+#[test]
+fn knowledge_search_namespace_scopes_results() {
+    let (_tmp, dir, password) = setup_repo();
+    let envs = [
+        ("DECAPOD_AGENT_ID", "unknown"),
+        ("DECAPOD_SESSION_PASSWORD", password.as_str()),
+        ("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1"),
+    ];
+    let add = |id: &str, namespace: &str| {
+        run_decapod(
+            &dir,
+            &[
+                "data",
+                "knowledge",
+                "add",
+                "--id",
+                id,
+                "--title",
+                "review lesson",
+                "--text",
+                "Reviewers caught it",
+                "--provenance",
+                "commit:abc123",
+                "--namespace",
+                namespace,
+            ],
+            &envs,
+        )
+    };
+    for (id, namespace) in [("K_sec", "security"), ("K_style", "style")] {
+        let out = add(id, namespace);
+        assert!(
+            out.status.success(),
+            "knowledge add --namespace {namespace} failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+    let bad = add("K_bad", "Not A Slug");
+    assert!(!bad.status.success());
+    assert!(String::from_utf8_lossy(&bad.stderr).contains("Invalid knowledge namespace"));
+
+    let search = |extra: &[&str]| -> Vec<Value> {
+        let mut args = vec!["data", "knowledge", "search", "--query", "review"];
+        args.extend_from_slice(extra);
+        let out = run_decapod(&dir, &args, &envs);
+        assert!(
+            out.status.success(),
+            "knowledge search failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        serde_json::from_slice::<Value>(&out.stdout)
+            .expect("search json")
+            .as_array()
+            .expect("result array")
+            .clone()
+    };
+
+    assert_eq!(search(&[]).len(), 2, "unscoped search spans namespaces");
+    let scoped = search(&["--namespace", "security"]);
+    assert_eq!(scoped.len(), 1);
+    assert_eq!(scoped[0]["id"], "K_sec");
+    assert_eq!(scoped[0]["namespace"], "security");
+}
+
+#[test]
+fn knowledge_stats_tallies_status_namespace_and_expiry() {
+    let (_tmp, dir, password) = setup_repo();
+    let data_root = dir.join(".decapod").join("data");
+    let envs = [
+        ("DECAPOD_AGENT_ID", "unknown"),
+        ("DECAPOD_SESSION_PASSWORD", password.as_str()),
+        ("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1"),
+    ];
+    let stats = |as_of: &str| -> Value {
+        let out = run_decapod(
+            &dir,
+            &["data", "knowledge", "stats", "--as-of", as_of],
+            &envs,
+        );
+        assert!(
+            out.status.success(),
+            "knowledge stats failed: {}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+        serde_json::from_slice(&out.stdout).expect("stats json")
+    };
+
+    // The first command after init runs startup migrations; seed after it.
+    let empty = stats("2000Z");
+    assert_eq!(empty["total"], 0);
+    assert_eq!(empty["oldest"], Value::Null);
+    assert_eq!(empty["expired_fraction"], 0.0);
+
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: data_root.clone(),
+    };
+    let seeds = [
+        ("K_a", "active", "security", "commit:abc", None, "1000Z"),
+        ("K_b", "active", "general", "file:docs/a.md", None, "3000Z"),
+        (
+            "K_c",
+            "deprecated",
+            "security",
+            "url:https://x",
+            None,
+            "2000Z",
+        ),
+        (
+            "K_d",
+            "active",
+            "style",
+            "commit:def",
+            Some("1500Z"),
+            "1200Z",
+        ),
+        (
+            "K_e",
+            "stale",
+            "general",
+            "cmd:cargo-test",
+            Some("1800Z"),
+            "1100Z",
+        ),
+        (
+            "K_f",
+            "active",
+            "general",
+            "event:E1",
+            Some("9000Z"),
+            "2500Z",
+        ),
+    ];
+    for (id, status, namespace, provenance, expires_ts, _) in seeds {
+        knowledge::add_knowledge(
+            &store,
+            knowledge::AddKnowledgeParams {
+                id,
+                title: id,
+                content: "seeded",
+                provenance,
+                claim_id: None,
+                merge_key: None,
+                conflict_policy: knowledge::KnowledgeConflictPolicy::Merge,
+                status,
+                ttl_policy: if expires_ts.is_some() {
+                    "decay"
+                } else {
+                    "persistent"
+                },
+                expires_ts,
+                namespace: Some(namespace),
+            },
+        )
+        .expect("seed knowledge");
+    }
+    let conn = rusqlite::Connection::open(knowledge::knowledge_db_path(&data_root)).unwrap();
+    for (id, .., created_at) in seeds {
+        conn.execute(
+            "UPDATE knowledge SET created_at = ?1 WHERE id = ?2",
+            rusqlite::params![created_at, id],
+        )
+        .unwrap();
+    }
+    drop(conn);
+
+    let stats = stats("2000Z");
+    assert_eq!(stats["total"], 6);
+    assert_eq!(
+        stats["by_status"],
+        serde_json::json!({"active": 4, "deprecated": 1, "stale": 1})
+    );
+    assert_eq!(
+        stats["by_namespace"],
+        serde_json::json!({"general": 3, "security": 2, "style": 1})
+    );
+    assert_eq!(
+        stats["by_provenance"],
+        serde_json::json!({"cmd": 1, "commit": 2, "event": 1, "file": 1, "url": 1})
+    );
+    assert_eq!(stats["oldest"], "1000Z");
+    assert_eq!(stats["newest"], "3000Z");
+    assert_eq!(stats["expired"], 2);
+    assert_eq!(stats["expired_fraction"], 2.0 / 6.0);
+}
+
+#[test]
+fn knowledge_merge_combines_into_target_and_deprecates_source() {
+    let (_tmp, dir, password) = setup_repo();
+    let data_root = dir.join(".decapod").join("data");
+    let envs = [
+        ("DECAPOD_AGENT_ID", "unknown"),
+        ("DECAPOD_SESSION_PASSWORD", password.as_str()),
+        ("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1"),
+    ];
+    // The first command after init runs startup migrations; seed after it.
+    let warm = run_decapod(&dir, &["data", "knowledge", "stats"], &envs);
+    assert!(warm.status.success());
+
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: data_root.clone(),
+    };
+    for (id, content, provenance) in [
+        (
+            "K_keep",
+            "Retry with backoff.\n\nCap at 5 attempts.",
+            "commit:abc",
+        ),
+        (
+            "K_dupe",
+            "Cap at 5 attempts.\n\nLog each retry.",
+            "file:docs/retry.md",
+        ),
+    ] {
+        knowledge::add_knowledge(
+            &store,
+            knowledge::AddKnowledgeParams {
+                id,
+                title: "Retry policy",
+                content,
+                provenance,
+                claim_id: None,
+                merge_key: None,
+                conflict_policy: knowledge::KnowledgeConflictPolicy::Merge,
+                status: "active",
+                ttl_policy: "persistent",
+                expires_ts: None,
+                namespace: None,
+            },
+        )
+        .expect("seed knowledge");
+    }
+
+    // K_dupe superseded K_old and was itself superseded by K_next.
+    let conn = rusqlite::Connection::open(knowledge::knowledge_db_path(&data_root)).unwrap();
+    conn.execute(
+        "UPDATE knowledge SET supersedes_id = 'K_old' WHERE id = 'K_dupe'",
+        [],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO knowledge (id, title, content, provenance, created_at, dir_path, scope, supersedes_id)
+         SELECT 'K_next', title, content, provenance, created_at, dir_path, scope, 'K_dupe'
+         FROM knowledge WHERE id = 'K_dupe'",
+        [],
+    )
+    .unwrap();
+    drop(conn);
+
+    let merge = |into: &str, from: &str| {
+        run_decapod(
+            &dir,
+            &["data", "knowledge", "merge", "--into", into, "--from", from],
+            &envs,
+        )
+    };
+    let self_merge = merge("K_keep", "K_keep");
+    assert!(!self_merge.status.success());
+    assert!(String::from_utf8_lossy(&self_merge.stderr).contains("into itself"));
+
+    let out = merge("K_keep", "K_dupe");
+    assert!(
+        out.status.success(),
+        "knowledge merge failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let result: Value = serde_json::from_slice(&out.stdout).expect("merge json");
+    assert_eq!(result["paragraphs_added"], 1);
+    assert_eq!(
+        result["provenance"],
+        serde_json::json!(["commit:abc", "file:docs/retry.md"])
+    );
+    assert_eq!(result["supersedes_id"], "K_old");
+    assert_eq!(result["relinked"], serde_json::json!(["K_next"]));
+
+    let conn = rusqlite::Connection::open(knowledge::knowledge_db_path(&data_root)).unwrap();
+    let row = |id: &str| -> (String, String) {
+        conn.query_row(
+            "SELECT content, status FROM knowledge WHERE id = ?1",
+            [id],
+            |r| Ok((r.get(0)?, r.get(1)?)),
+        )
+        .unwrap()
+    };
+    assert_eq!(
+        row("K_keep"),
+        (
+            "Retry with backoff.\n\nCap at 5 attempts.\n\nLog each retry.".to_string(),
+            "active".to_string()
+        )
+    );
+    assert_eq!(row("K_dupe").1, "deprecated");
+    let supersedes = |id: &str| -> Option<String> {
+        conn.query_row(
+            "SELECT supersedes_id FROM knowledge WHERE id = ?1",
+            [id],
+            |r| r.get(0),
+        )
+        .unwrap()
+    };
+    assert_eq!(supersedes("K_keep").as_deref(), Some("K_old"));
+    assert_eq!(supersedes("K_next").as_deref(), Some("K_keep"));
+
+    let events = fs::read_to_string(data_root.join("knowledge.merge.events.jsonl")).unwrap();
+    let event: Value = serde_json::from_str(events.lines().next().unwrap()).unwrap();
+    assert_eq!(
+        event["into_before"]["content"],
+        "Retry with backoff.\n\nCap at 5 attempts."
+    );
+    assert_eq!(event["from_before"]["status"], "active");
+
+    let again = merge("K_keep", "K_dupe");
+    assert!(
+        !again.status.success(),
+        "deprecated source cannot merge twice"
+    );
+}
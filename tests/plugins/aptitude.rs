@@ -1,10 +1,11 @@
 use decapod::core::store::Store;
 use decapod::core::store::StoreKind;
 use decapod::plugins::aptitude::{
-    PreferenceInput, SkillInput, add_preference, add_skill, aptitude_db_path, delete_preference,
-    generate_contextual_reminders, get_preference, get_preferences_by_category,
-    get_prompts_for_context, get_skill, initialize_aptitude_db, list_preferences, list_skills,
-    match_patterns, record_observation,
+    BehaviorObservationInput, PreferenceInput, SkillInput, add_preference, add_skill,
+    aptitude_db_path, delete_preference, generate_contextual_reminders, get_preference,
+    get_preferences_by_category, get_prompts_for_context, get_skill, initialize_aptitude_db,
+    list_behavior_patterns, list_preferences, list_skills, match_patterns,
+    record_behavior_observation, record_observation,
 };
 use tempfile::tempdir;
 
@@ -218,3 +219,54 @@ fn test_db_path() {
     let db_path = aptitude_db_path(&root);
     assert!(db_path.to_string_lossy().ends_with("memory.db"));
 }
+
+#[test]
+fn test_behavior_pattern_recurrence_increments() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path().to_path_buf();
+    initialize_aptitude_db(&root).unwrap();
+    let store = Store {
+        kind: StoreKind::Repo,
+        root,
+    };
+
+    let observe = |pattern: &str, evidence: &str| {
+        record_behavior_observation(
+            &store,
+            BehaviorObservationInput {
+                subject: "user".to_string(),
+                pattern: pattern.to_string(),
+                evidence: Some(evidence.to_string()),
+                source: "agent-a".to_string(),
+            },
+        )
+        .unwrap()
+    };
+
+    observe(
+        "reviews DB migrations personally",
+        "asked to see 0042_users.sql",
+    );
+    let first = list_behavior_patterns(&store, Some("user")).unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!(first[0].recurrence, 1);
+
+    observe(
+        "Reviews DB migrations personally",
+        "blocked merge of 0043 until reviewed",
+    );
+    observe("prefers squash merges", "squashed PR #12");
+    let patterns = list_behavior_patterns(&store, Some("user")).unwrap();
+    assert_eq!(patterns.len(), 2);
+    assert_eq!(patterns[0].pattern, "reviews DB migrations personally");
+    assert_eq!(patterns[0].recurrence, 2);
+    assert_eq!(patterns[0].evidence.len(), 2);
+    assert_eq!(patterns[0].sources, vec!["agent-a".to_string()]);
+    assert_eq!(patterns[1].recurrence, 1);
+
+    assert!(
+        list_behavior_patterns(&store, Some("someone-else"))
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -0,0 +1,93 @@
+use decapod::core::store::{Store, StoreKind};
+use decapod::plugins::archive::{self, ArchiveDrift};
+use rusqlite::{Connection, params};
+use std::fs;
+use tempfile::tempdir;
+
+fn store_in(root: &std::path::Path) -> Store {
+    Store {
+        kind: StoreKind::Repo,
+        root: root.to_path_buf(),
+    }
+}
+
+fn set_recorded_hash(root: &std::path::Path, id: &str, hash: &str) {
+    let conn = Connection::open(archive::archive_db_path(root)).unwrap();
+    conn.execute(
+        "UPDATE archives SET content_hash = ?1 WHERE id = ?2",
+        params![hash, id],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_repair_rerecords_format_drift_but_refuses_missing_content() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path();
+    let store = store_in(root);
+    archive::initialize_archive_db(root).unwrap();
+
+    // Recorded over CRLF text; the archive was later migrated to LF.
+    let migrated = root.join("migrated.md");
+    let crlf = "line one\r\nline two\r\n";
+    fs::write(&migrated, crlf).unwrap();
+    archive::register_archive(&store, "A1", &migrated, crlf, "summary").unwrap();
+    fs::write(&migrated, "line one\nline two\n").unwrap();
+
+    // Same digest, recorded with a prefix and uppercase hex.
+    let encoded = root.join("encoded.md");
+    fs::write(&encoded, "stable").unwrap();
+    archive::register_archive(&store, "A2", &encoded, "stable", "summary").unwrap();
+    let prefixed = format!("sha256:{}", archive::hash_text("stable").to_uppercase());
+    set_recorded_hash(root, "A2", &prefixed);
+
+    let truncated = root.join("truncated.md");
+    fs::write(&truncated, "full body of the archive").unwrap();
+    archive::register_archive(&store, "A3", &truncated, "full body of the archive", "s").unwrap();
+    fs::write(&truncated, "full body").unwrap();
+
+    let missing = root.join("missing.md");
+    fs::write(&missing, "gone").unwrap();
+    archive::register_archive(&store, "A4", &missing, "gone", "s").unwrap();
+    fs::remove_file(&missing).unwrap();
+
+    // A non-UTF-8 archive is refused on its own without aborting the repair.
+    let binary = root.join("binary.md");
+    fs::write(&binary, "text").unwrap();
+    archive::register_archive(&store, "A5", &binary, "text", "s").unwrap();
+    fs::write(&binary, [0xff, 0xfe, b't']).unwrap();
+
+    assert_eq!(archive::verify_archives(&store).unwrap().len(), 5);
+
+    let report = archive::repair_archives(&store).unwrap();
+    let mut drifts: Vec<(&str, ArchiveDrift)> = report
+        .repaired
+        .iter()
+        .map(|r| (r.id.as_str(), r.drift))
+        .collect();
+    drifts.sort_by_key(|(id, _)| *id);
+    assert_eq!(
+        drifts,
+        vec![
+            ("A1", ArchiveDrift::LineEndings),
+            ("A2", ArchiveDrift::HashEncoding)
+        ]
+    );
+    let mut refused: Vec<&str> = report.refused.iter().map(|r| r.id.as_str()).collect();
+    refused.sort();
+    assert_eq!(refused, vec!["A3", "A4", "A5"]);
+    let binary_refusal = report.refused.iter().find(|r| r.id == "A5").unwrap();
+    assert_eq!(binary_refusal.reason, "content is not valid UTF-8");
+
+    let remaining = archive::verify_archives(&store).unwrap();
+    assert_eq!(remaining.len(), 3, "{remaining:?}");
+    assert!(remaining.iter().all(|f| {
+        ["Archive A3", "Archive A4", "Archive A5"]
+            .iter()
+            .any(|p| f.starts_with(p))
+    }));
+
+    let events = fs::read_to_string(root.join("archive.events.jsonl")).unwrap();
+    assert_eq!(events.lines().count(), 2);
+    assert!(events.contains("\"op\":\"archive.repair\""));
+}
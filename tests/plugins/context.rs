@@ -0,0 +1,127 @@
+use decapod::core::store::{Store, StoreKind};
+use decapod::plugins::context::{ContextManager, EvictionCandidate, RestoreDecision};
+use std::fs;
+use tempfile::tempdir;
+
+fn store_in(root: &std::path::Path) -> Store {
+    Store {
+        kind: StoreKind::Repo,
+        root: root.to_path_buf(),
+    }
+}
+
+#[test]
+fn test_pack_in_place_leaves_summary_and_restore_pointer() {
+    let tmp = tempdir().unwrap();
+    let store = store_in(tmp.path());
+    let manager = ContextManager::new(tmp.path()).unwrap();
+    let session = tmp.path().join("session.md");
+    let original = "# Session\n\nlong transcript the agent no longer needs inline\n";
+    fs::write(&session, original).unwrap();
+
+    let outcome = manager
+        .pack_and_archive(&store, &session, "transcript digest", Some(&session))
+        .unwrap();
+    assert_eq!(outcome.rewritten.as_deref(), Some(session.as_path()));
+
+    let rewritten = fs::read_to_string(&session).unwrap();
+    assert!(
+        rewritten.contains("Summary: transcript digest"),
+        "{rewritten}"
+    );
+    assert!(
+        rewritten.contains(&format!(
+            "decapod data context restore --id {}",
+            outcome.archive_id
+        )),
+        "{rewritten}"
+    );
+    assert!(!rewritten.contains("long transcript"));
+
+    let RestoreDecision::Restored { content, .. } = manager
+        .restore_archive(&outcome.archive_id, "main", &[], false)
+        .unwrap()
+    else {
+        panic!("restore within budget");
+    };
+    assert_eq!(content, original);
+}
+
+#[test]
+fn test_pack_without_in_place_keeps_source() {
+    let tmp = tempdir().unwrap();
+    let store = store_in(tmp.path());
+    let manager = ContextManager::new(tmp.path()).unwrap();
+    let session = tmp.path().join("session.md");
+    fs::write(&session, "keep me").unwrap();
+
+    let outcome = manager
+        .pack_and_archive(&store, &session, "digest", None)
+        .unwrap();
+    assert!(outcome.rewritten.is_none());
+    assert_eq!(fs::read_to_string(&session).unwrap(), "keep me");
+    assert_eq!(
+        fs::read_to_string(&outcome.archive_path).unwrap(),
+        "keep me"
+    );
+}
+
+#[test]
+fn test_over_budget_restore_reports_overflow_and_force_completes() {
+    let tmp = tempdir().unwrap();
+    let store = store_in(tmp.path());
+    fs::write(
+        tmp.path().join("CONTEXT.json"),
+        r#"{"profiles":{"tight":{"budget_tokens":40,"required_files":[],"optional_files":[]}}}"#,
+    )
+    .unwrap();
+    let manager = ContextManager::new(tmp.path()).unwrap();
+
+    let archived = tmp.path().join("archived.md");
+    fs::write(&archived, "alpha beta gamma delta ".repeat(5)).unwrap();
+    let outcome = manager
+        .pack_and_archive(&store, &archived, "digest", None)
+        .unwrap();
+
+    let big = tmp.path().join("big.md");
+    let small = tmp.path().join("small.md");
+    fs::write(&big, "one two three four five ".repeat(6)).unwrap();
+    fs::write(&small, "tiny").unwrap();
+    let current = vec![small.clone(), big.clone()];
+
+    let big_tokens = manager.estimate_tokens(&fs::read_to_string(&big).unwrap());
+    let small_tokens = manager.estimate_tokens("tiny");
+    let restore_tokens = manager.estimate_tokens(&fs::read_to_string(&archived).unwrap());
+
+    let RestoreDecision::Blocked(overflow) = manager
+        .restore_archive(&outcome.archive_id, "tight", &current, false)
+        .unwrap()
+    else {
+        panic!("restore should be blocked over budget");
+    };
+    assert_eq!(overflow.budget_tokens, 40);
+    assert_eq!(overflow.current_tokens, big_tokens + small_tokens);
+    assert_eq!(overflow.restore_tokens, restore_tokens);
+    assert_eq!(
+        overflow.overflow_tokens,
+        big_tokens + small_tokens + restore_tokens - 40
+    );
+    assert_eq!(
+        overflow.eviction_candidates.first(),
+        Some(&EvictionCandidate {
+            path: big.display().to_string(),
+            tokens: big_tokens,
+        })
+    );
+
+    let RestoreDecision::Restored {
+        content, overflow, ..
+    } = manager
+        .restore_archive(&outcome.archive_id, "tight", &current, true)
+        .unwrap()
+    else {
+        panic!("--force restores anyway");
+    };
+    assert_eq!(content, fs::read_to_string(&outcome.archive_path).unwrap());
+    assert!(overflow.is_some());
+}
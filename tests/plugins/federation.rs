@@ -1,8 +1,10 @@
 use decapod::core::store::{Store, StoreKind};
 use decapod::plugins::federation::{
-    FederationCli, FederationCommand, OutputFormat, add_edge, add_node, add_source_to_node,
-    edit_node, find_node_by_source, initialize_federation_db, rebuild_from_events,
-    run_federation_cli, supersede_node, transition_node_status, validate_federation,
+    FederationCli, FederationCommand, NodeQuery, OutputFormat, add_edge, add_node,
+    add_source_to_node, add_weighted_edge, edit_node, find_node_by_source, find_path,
+    for_each_queried_node, initialize_federation_db, node_history, query_nodes,
+    rebuild_from_events, run_federation_cli, supersede_node, transition_node_status, update_node,
+    validate_federation,
 };
 use std::fs;
 use tempfile::tempdir;
@@ -767,3 +769,222 @@ fn test_intent_proof_chain() {
     let found_intent = find_node_by_source(&store, "event:R01KHG4QFQ6ZQAN2F3SR6XC5NA").unwrap();
     assert!(found_intent.is_some());
 }
+
+#[test]
+fn test_query_filters_by_kind_and_tag_in_stable_order() {
+    let (_tmp, store) = test_store();
+    let seed = |title: &str, kind: &str, priority: &str, tags: &str| {
+        add_node(
+            &store,
+            title,
+            kind,
+            priority,
+            "agent_inferred",
+            "",
+            "commit:abc123",
+            tags,
+            "repo",
+            None,
+            "test",
+        )
+        .unwrap()
+        .id
+    };
+    let lesson_ops = seed("Retry lesson", "lesson", "notable", "ops,retry");
+    let lesson_ops_2 = seed("Backoff lesson", "lesson", "background", "ops");
+    seed("Style lesson", "lesson", "notable", "style");
+    seed("Ops decision", "decision", "notable", "ops");
+    seed("Ops person", "person", "notable", "ops");
+
+    let query = NodeQuery {
+        kind: Some("lesson".to_string()),
+        tag: Some("ops".to_string()),
+        ..NodeQuery::default()
+    };
+    let ids: Vec<String> = query_nodes(&store, &query)
+        .unwrap()
+        .into_iter()
+        .map(|n| n.id)
+        .collect();
+    let mut expected = vec![lesson_ops, lesson_ops_2.clone()];
+    expected.sort();
+    let mut sorted = ids.clone();
+    sorted.sort();
+    assert_eq!(sorted, expected, "only ops-tagged lessons");
+    assert_eq!(
+        ids,
+        query_nodes(&store, &query)
+            .unwrap()
+            .into_iter()
+            .map(|n| n.id)
+            .collect::<Vec<_>>(),
+        "ordering is deterministic"
+    );
+
+    let salient = query_nodes(
+        &store,
+        &NodeQuery {
+            salience: Some("background".to_string()),
+            ..NodeQuery::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(salient.len(), 1);
+    assert_eq!(salient[0].id, lesson_ops_2);
+
+    let limited = query_nodes(
+        &store,
+        &NodeQuery {
+            tag: Some("ops".to_string()),
+            limit: Some(2),
+            ..NodeQuery::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(limited.len(), 2);
+
+    let future = query_nodes(
+        &store,
+        &NodeQuery {
+            since: Some("2999-01-01T00:00:00Z".to_string()),
+            ..NodeQuery::default()
+        },
+    )
+    .unwrap();
+    assert!(future.is_empty());
+
+    assert!(
+        query_nodes(
+            &store,
+            &NodeQuery {
+                kind: Some("nonsense".to_string()),
+                ..NodeQuery::default()
+            },
+        )
+        .is_err()
+    );
+
+    let mut streamed = Vec::new();
+    for_each_queried_node(&store, &query, |n| {
+        streamed.push(n.id);
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(streamed, ids, "streaming visits the same nodes in order");
+}
+
+#[test]
+fn test_update_keeps_prior_versions_in_history_across_rebuild() {
+    let (_tmp, store) = test_store();
+    let node = add_node(
+        &store,
+        "Flaky CI lesson",
+        "lesson",
+        "notable",
+        "agent_inferred",
+        "v1: retry once",
+        "",
+        "ci",
+        "repo",
+        None,
+        "test",
+    )
+    .unwrap();
+
+    update_node(
+        &store,
+        &node.id,
+        "v2: retry twice",
+        "one retry was not enough",
+        "agent-a",
+    )
+    .unwrap();
+    update_node(
+        &store,
+        &node.id,
+        "v3: quarantine the test",
+        "retries hid a real bug",
+        "agent-b",
+    )
+    .unwrap();
+    assert!(update_node(&store, &node.id, "v4", " ", "agent-b").is_err());
+
+    let history = node_history(&store, &node.id).unwrap();
+    let contents: Vec<&str> = history.iter().map(|r| r.content.as_str()).collect();
+    assert_eq!(
+        contents,
+        vec![
+            "v1: retry once",
+            "v2: retry twice",
+            "v3: quarantine the test"
+        ]
+    );
+    assert_eq!(history[0].event_type, "node.create");
+    assert_eq!(history[0].reason, None);
+    assert_eq!(
+        history[1].reason.as_deref(),
+        Some("one retry was not enough")
+    );
+    assert_eq!(history[2].actor, "agent-b");
+    assert_eq!(
+        history.iter().map(|r| r.revision).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+
+    rebuild_from_events(&store.root).unwrap();
+    let replayed = node_history(&store, &node.id).unwrap();
+    assert_eq!(
+        replayed.iter().map(|r| &r.content).collect::<Vec<_>>(),
+        history.iter().map(|r| &r.content).collect::<Vec<_>>()
+    );
+    let current = query_nodes(&store, &NodeQuery::default()).unwrap();
+    assert_eq!(current[0].body, "v3: quarantine the test");
+}
+
+#[test]
+fn test_edges_record_confidence_and_path_threshold_skips_weak_links() {
+    let (_tmp, store) = test_store();
+    let node = |title: &str| {
+        add_node(
+            &store,
+            title,
+            "lesson",
+            "notable",
+            "agent_inferred",
+            "",
+            "",
+            "",
+            "repo",
+            None,
+            "test",
+        )
+        .unwrap()
+        .id
+    };
+    let (a, b, c, d) = (node("A"), node("B"), node("C"), node("D"));
+
+    add_weighted_edge(&store, &a, &b, "depends_on", 0.9, Some("same module")).unwrap();
+    add_weighted_edge(&store, &b, &c, "depends_on", 0.9, None).unwrap();
+    add_weighted_edge(&store, &a, &c, "relates_to", 0.3, Some("hunch")).unwrap();
+    add_weighted_edge(&store, &c, &d, "relates_to", 0.2, None).unwrap();
+    add_edge(&store, &d, &b, "relates_to").unwrap();
+    assert!(add_weighted_edge(&store, &a, &d, "relates_to", 1.5, None).is_err());
+
+    // Two strong hops (0.81) beat one weak link (0.3).
+    let path = find_path(&store, &a, &c, 0.0).unwrap().expect("path");
+    assert_eq!(path.nodes, vec![a.clone(), b.clone(), c.clone()]);
+    assert!((path.confidence - 0.81).abs() < 1e-9);
+    assert_eq!(path.edges[0].confidence, 0.9);
+    assert_eq!(path.edges[0].rationale.as_deref(), Some("same module"));
+
+    // Raising the threshold drops the 0.3 and 0.2 links but keeps default-1.0 edges.
+    let strong = find_path(&store, &c, &d, 0.5).unwrap().expect("path");
+    assert_eq!(strong.nodes, vec![c.clone(), b.clone(), d.clone()]);
+    assert_eq!(strong.edges[1].confidence, 1.0);
+    assert!(find_path(&store, &a, &c, 0.95).unwrap().is_none());
+
+    rebuild_from_events(&store.root).unwrap();
+    let replayed = find_path(&store, &a, &c, 0.0).unwrap().expect("path");
+    assert_eq!(replayed.edges[0].rationale.as_deref(), Some("same module"));
+    assert!((replayed.confidence - 0.81).abs() < 1e-9);
+}
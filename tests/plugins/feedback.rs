@@ -0,0 +1,190 @@
+use decapod::core::store::{Store, StoreKind};
+use decapod::core::todo::{get_task, initialize_todo_db, todo_db_path};
+use decapod::plugins::aptitude::get_preference;
+use decapod::plugins::federation::{federation_db_path, initialize_federation_db};
+use decapod::plugins::feedback::{
+    add_feedback, apply_proposed_prefs, initialize_feedback_db, list_feedback, promote_feedback,
+    propose_prefs, resolve_feedback,
+};
+use tempfile::tempdir;
+
+fn store_in(root: &std::path::Path) -> Store {
+    Store {
+        kind: StoreKind::Repo,
+        root: root.to_path_buf(),
+    }
+}
+
+#[test]
+fn test_resolved_feedback_leaves_open_list_and_proposals() {
+    let tmp = tempdir().unwrap();
+    let store = store_in(tmp.path());
+    initialize_feedback_db(&store.root).unwrap();
+
+    let keep = add_feedback(&store, "operator", "prefer small commits", None).unwrap();
+    let done = add_feedback(&store, "operator", "stop renaming files", None).unwrap();
+
+    let resolved = resolve_feedback(&store, &done, "addressed", Some("added lint")).unwrap();
+    assert_eq!(resolved.status, "addressed");
+    assert_eq!(resolved.resolution_note.as_deref(), Some("added lint"));
+    assert!(resolved.resolved_at.is_some());
+
+    let open: Vec<String> = list_feedback(&store, None)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(open, vec![keep.clone()]);
+    assert_eq!(list_feedback(&store, Some("all")).unwrap().len(), 2);
+    assert_eq!(
+        list_feedback(&store, Some("addressed")).unwrap()[0].id,
+        done
+    );
+
+    let proposal = propose_prefs(&store).unwrap();
+    assert!(proposal.contains(&keep));
+    assert!(!proposal.contains(&done));
+
+    let events = std::fs::read_to_string(tmp.path().join("feedback.events.jsonl")).unwrap();
+    let event: serde_json::Value = serde_json::from_str(events.lines().next().unwrap()).unwrap();
+    assert_eq!(event["feedback_id"], done.as_str());
+    assert_eq!(event["outcome"], "addressed");
+
+    let again = resolve_feedback(&store, &done, "rejected", None).unwrap_err();
+    assert!(again.to_string().contains("already addressed"), "{}", again);
+    let missing = resolve_feedback(&store, "nope", "rejected", None).unwrap_err();
+    assert!(missing.to_string().contains("not found"), "{}", missing);
+}
+
+#[test]
+fn test_promote_feedback_creates_linked_task() {
+    let tmp = tempdir().unwrap();
+    let store = store_in(tmp.path());
+    initialize_feedback_db(&store.root).unwrap();
+    initialize_todo_db(&store.root).unwrap();
+    initialize_federation_db(&store.root).unwrap();
+
+    let id = add_feedback(
+        &store,
+        "operator",
+        "Validate output is too noisy\nCollapse passing gates by default.",
+        None,
+    )
+    .unwrap();
+    let promoted = promote_feedback(&store, &id, "high", None).unwrap();
+    assert_eq!(promoted.feedback.status, "promoted");
+    assert!(
+        promoted
+            .feedback
+            .resolution_note
+            .as_deref()
+            .unwrap()
+            .contains(&promoted.task_id)
+    );
+
+    let task = get_task(&store.root, &promoted.task_id)
+        .unwrap()
+        .expect("promoted task exists");
+    assert_eq!(task.title, "Validate output is too noisy");
+    assert!(
+        task.description
+            .contains("Collapse passing gates by default.")
+    );
+    assert_eq!(task.r#ref, format!("feedback:{}", id));
+    assert_eq!(task.priority, "high");
+
+    let edge_id = promoted.edge_id.expect("federation edge created");
+    let conn = rusqlite::Connection::open(federation_db_path(&store.root)).unwrap();
+    let (edge_type, target_source): (String, String) = conn
+        .query_row(
+            "SELECT e.edge_type, s.source FROM edges e JOIN sources s ON s.node_id = e.target_id WHERE e.id = ?1",
+            [&edge_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(edge_type, "relates_to");
+    assert_eq!(target_source, format!("event:{}", promoted.task_id));
+
+    assert!(list_feedback(&store, None).unwrap().is_empty());
+    let again = promote_feedback(&store, &id, "high", None).unwrap_err();
+    assert!(again.to_string().contains("already promoted"), "{}", again);
+}
+
+#[test]
+fn test_promote_feedback_rolls_back_when_linking_fails() {
+    let tmp = tempdir().unwrap();
+    let store = store_in(tmp.path());
+    initialize_feedback_db(&store.root).unwrap();
+    initialize_todo_db(&store.root).unwrap();
+    // A federation store that cannot be opened makes the link step fail
+    // after the todo has been inserted.
+    std::fs::write(federation_db_path(&store.root), b"not a sqlite database").unwrap();
+
+    let id = add_feedback(&store, "operator", "Flaky gate output", None).unwrap();
+    promote_feedback(&store, &id, "high", None).unwrap_err();
+
+    let open = list_feedback(&store, None).unwrap();
+    assert_eq!(open.len(), 1);
+    assert_eq!(open[0].id, id);
+    assert!(open[0].resolution_note.is_none());
+
+    let conn = rusqlite::Connection::open(todo_db_path(&store.root)).unwrap();
+    let live: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM tasks WHERE ref = ?1 AND status != 'archived'",
+            [format!("feedback:{}", id)],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(live, 0);
+}
+
+#[test]
+fn test_propose_apply_records_preferences_and_incorporates_feedback() {
+    let tmp = tempdir().unwrap();
+    let store = store_in(tmp.path());
+    initialize_feedback_db(&store.root).unwrap();
+
+    let ssh = add_feedback(&store, "operator", "please use ssh key work", None).unwrap();
+    let always = add_feedback(&store, "operator", "always run cargo fmt", None).unwrap();
+    let vague = add_feedback(&store, "operator", "this felt slow", None).unwrap();
+
+    let dry = propose_prefs(&store).unwrap();
+    assert!(dry.contains("git.ssh_key = work"), "{}", dry);
+    assert!(dry.contains("feedback.always_run_cargo_fmt"), "{}", dry);
+    assert_eq!(list_feedback(&store, None).unwrap().len(), 3);
+
+    let result = apply_proposed_prefs(&store).unwrap();
+    assert_eq!(result.applied.len(), 2);
+    let mut incorporated = result.incorporated.clone();
+    incorporated.sort();
+    let mut expected = vec![ssh.clone(), always.clone()];
+    expected.sort();
+    assert_eq!(incorporated, expected);
+
+    let pref = get_preference(&store, "git", "ssh_key").unwrap().unwrap();
+    assert_eq!(pref.value, "work");
+    assert_eq!(pref.source, format!("feedback:{}", ssh));
+    let pref = get_preference(&store, "feedback", "always_run_cargo_fmt")
+        .unwrap()
+        .unwrap();
+    assert_eq!(pref.source, format!("feedback:{}", always));
+
+    let open: Vec<String> = list_feedback(&store, None)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(open, vec![vague]);
+    let done = list_feedback(&store, Some("incorporated")).unwrap();
+    assert_eq!(done.len(), 2);
+    assert!(done.iter().all(|e| {
+        e.resolution_note
+            .as_deref()
+            .unwrap()
+            .starts_with("incorporated into")
+    }));
+
+    let events = std::fs::read_to_string(tmp.path().join("feedback.events.jsonl")).unwrap();
+    assert_eq!(events.matches("feedback.incorporate").count(), 2);
+}
@@ -1,5 +1,8 @@
 use decapod::core::store::{Store, StoreKind};
-use decapod::plugins::health::{AutonomyTier, HealthState, compute_health, initialize_health_db};
+use decapod::plugins::health::{
+    AutonomyTier, HealthState, add_claim, compute_health, get_trend, initialize_health_db,
+    record_proof,
+};
 use tempfile::tempdir;
 
 #[test]
@@ -123,3 +126,29 @@ fn test_health_db_init() {
     let db_path = store.root.join("governance.db");
     assert!(db_path.exists());
 }
+
+#[test]
+fn test_trend_flags_pass_to_fail_regression() {
+    let tmp = tempdir().unwrap();
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: tmp.path().to_path_buf(),
+    };
+    initialize_health_db(&store.root).unwrap();
+    add_claim(&store, "claim.tests", "tests pass", "proof", "").unwrap();
+
+    record_proof(&store, "claim.tests", "cargo test", "pass", 3600).unwrap();
+    record_proof(&store, "claim.tests", "cargo test", "fail", 3600).unwrap();
+
+    let trend = get_trend(&store, "claim.tests").unwrap();
+    let results: Vec<&str> = trend
+        .evaluations
+        .iter()
+        .map(|p| p.result.as_str())
+        .collect();
+    assert_eq!(results, vec!["pass", "fail"]);
+    assert_eq!(trend.regressions, 1);
+    assert!(!trend.evaluations[0].regression);
+    assert!(trend.evaluations[1].regression);
+    assert_eq!(trend.current_state, HealthState::CONTRADICTED);
+}
@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod tests {
     use decapod::core::obligation::{
-        ObligationStatus, add_obligation, derive_obligation_status, get_obligation,
-        initialize_obligation_db, list_obligations, validate_obligation_graph,
+        ObligationStatus, add_obligation, check_obligation_graph, derive_obligation_status,
+        get_obligation, initialize_obligation_db, list_obligations, obligation_db_path,
+        satisfy_obligation, validate_obligation_graph, waive_obligation,
     };
     use decapod::core::store::{Store, StoreKind};
     use std::path::PathBuf;
@@ -110,4 +111,210 @@ mod tests {
         assert_eq!(result.total_nodes, 2);
         assert_eq!(result.total_edges, 1);
     }
+
+    #[test]
+    fn test_satisfy_with_met_prerequisites_unblocks_dependent() {
+        let (store, _temp) = test_store();
+        let id1 = add_obligation(&store, "test-1", "medium", "", "").unwrap();
+        let id2 = add_obligation(&store, "test-2", "medium", &id1, "").unwrap();
+
+        let met = satisfy_obligation(&store, &id1, "commit:abc123", "agent-a").unwrap();
+        assert_eq!(met.status, ObligationStatus::Met);
+        let resolution = &met.metadata.as_ref().unwrap()["resolution"];
+        assert_eq!(resolution["action"], "satisfy");
+        assert_eq!(resolution["evidence"], "commit:abc123");
+        assert_eq!(resolution["by"], "agent-a");
+
+        let dependent = derive_obligation_status(&store, &id2).unwrap();
+        assert!(dependent.dependencies_satisfied);
+        let met2 = satisfy_obligation(&store, &id2, "commit:def456", "agent-a").unwrap();
+        assert_eq!(met2.status, ObligationStatus::Met);
+        assert_eq!(
+            derive_obligation_status(&store, &id2)
+                .unwrap()
+                .derived_status,
+            ObligationStatus::Met
+        );
+    }
+
+    #[test]
+    fn test_satisfy_refused_with_unmet_prerequisites() {
+        let (store, _temp) = test_store();
+        let id1 = add_obligation(&store, "test-1", "medium", "", "").unwrap();
+        let id2 = add_obligation(&store, "test-2", "medium", &id1, "").unwrap();
+
+        let err = satisfy_obligation(&store, &id2, "commit:abc123", "agent-a").unwrap_err();
+        assert!(err.to_string().contains("prerequisites not met"), "{err}");
+        assert!(err.to_string().contains(&id1), "{err}");
+        assert_eq!(
+            get_obligation(&store, &id2).unwrap().status,
+            ObligationStatus::Open
+        );
+    }
+
+    #[test]
+    fn test_waive_requires_verified_trust() {
+        let (store, _temp) = test_store();
+        decapod::core::todo::initialize_todo_db(&store.root).unwrap();
+        let id1 = add_obligation(&store, "test-1", "medium", "", "").unwrap();
+        let id2 = add_obligation(&store, "test-2", "medium", &id1, "").unwrap();
+
+        let err = waive_obligation(&store, &id1, "out of scope", "agent-basic", None).unwrap_err();
+        assert!(
+            err.to_string().contains("requires trust 'verified'"),
+            "{err}"
+        );
+
+        let conn = rusqlite::Connection::open(store.root.join("todo.db")).unwrap();
+        conn.execute(
+            "INSERT INTO agent_trust(agent_id, trust_level, granted_at, updated_at, granted_by)
+             VALUES('agent-lead', 'verified', '1Z', '1Z', 'test')",
+            [],
+        )
+        .unwrap();
+
+        // Naming a trusted agent does not lend its trust to the caller.
+        let err = waive_obligation(
+            &store,
+            &id1,
+            "out of scope",
+            "agent-basic",
+            Some("agent-lead"),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("does not match"), "{err}");
+        assert_eq!(
+            get_obligation(&store, &id1).unwrap().status,
+            ObligationStatus::Open
+        );
+
+        let waived = waive_obligation(
+            &store,
+            &id1,
+            "out of scope",
+            "agent-lead",
+            Some("agent-lead"),
+        )
+        .unwrap();
+        assert_eq!(waived.status, ObligationStatus::Waived);
+        assert_eq!(
+            waived.metadata.as_ref().unwrap()["resolution"]["reason"],
+            "out of scope"
+        );
+        let events = std::fs::read_to_string(store.root.join("obligation.events.jsonl")).unwrap();
+        let event: serde_json::Value =
+            serde_json::from_str(events.lines().next().unwrap()).unwrap();
+        assert_eq!(events.lines().count(), 1);
+        assert_eq!(event["event_type"], "obligation.waive");
+        assert_eq!(event["obligation_id"], id1.as_str());
+        assert_eq!(event["by"], "agent-lead");
+        assert!(
+            satisfy_obligation(&store, &id2, "commit:abc123", "agent-a").is_ok(),
+            "waived prerequisite unblocks dependents"
+        );
+    }
+
+    #[test]
+    fn test_check_reports_two_obligation_cycle() {
+        let (store, _temp) = test_store();
+        let id1 = add_obligation(&store, "test-1", "medium", "", "").unwrap();
+        let id2 = add_obligation(&store, "test-2", "medium", &id1, "").unwrap();
+        assert!(check_obligation_graph(&store).unwrap().ok);
+
+        // `add` refuses cycles, so close the loop directly in the edge table.
+        let conn = rusqlite::Connection::open(obligation_db_path(&store.root)).unwrap();
+        conn.execute(
+            "INSERT INTO obligation_edges (edge_id, from_id, to_id, kind, created_at)
+             VALUES ('E_back', ?1, ?2, 'depends_on', '1Z')",
+            [&id1, &id2],
+        )
+        .unwrap();
+
+        let result = check_obligation_graph(&store).unwrap();
+        assert!(!result.ok);
+        assert_eq!(result.cycles.len(), 1);
+        let mut members = result.cycles[0].clone();
+        members.sort();
+        let mut expected = vec![id1.clone(), id2.clone()];
+        expected.sort();
+        assert_eq!(members, expected);
+        assert!(result.unsatisfiable.is_empty());
+    }
+
+    #[test]
+    fn test_check_reports_dependency_on_removed_obligation() {
+        let (store, _temp) = test_store();
+        let id1 = add_obligation(&store, "test-1", "medium", "", "").unwrap();
+        let id2 = add_obligation(&store, "test-2", "medium", &id1, "").unwrap();
+
+        let conn = rusqlite::Connection::open(obligation_db_path(&store.root)).unwrap();
+        conn.execute("DELETE FROM obligations WHERE id = ?1", [&id1])
+            .unwrap();
+
+        let result = check_obligation_graph(&store).unwrap();
+        assert!(!result.ok);
+        assert_eq!(result.unsatisfiable.len(), 1);
+        assert_eq!(result.unsatisfiable[0].obligation_id, id2);
+        assert_eq!(result.unsatisfiable[0].depends_on, id1);
+    }
+
+    #[test]
+    fn test_check_reports_dependency_on_waived_obligation() {
+        let (store, _temp) = test_store();
+        decapod::core::todo::initialize_todo_db(&store.root).unwrap();
+        let id1 = add_obligation(&store, "test-1", "medium", "", "").unwrap();
+        let id2 = add_obligation(&store, "test-2", "medium", &id1, "").unwrap();
+
+        let conn = rusqlite::Connection::open(store.root.join("todo.db")).unwrap();
+        conn.execute(
+            "INSERT INTO agent_trust(agent_id, trust_level, granted_at, updated_at, granted_by)
+             VALUES('agent-lead', 'verified', '1Z', '1Z', 'test')",
+            [],
+        )
+        .unwrap();
+        waive_obligation(&store, &id1, "out of scope", "agent-lead", None).unwrap();
+
+        let result = check_obligation_graph(&store).unwrap();
+        assert!(!result.ok, "a waived prerequisite is never met");
+        assert_eq!(result.unsatisfiable.len(), 1);
+        assert_eq!(result.unsatisfiable[0].obligation_id, id2);
+        assert_eq!(result.unsatisfiable[0].depends_on, id1);
+        assert!(result.unsatisfiable[0].reason.contains("waived"));
+    }
+
+    #[test]
+    fn test_check_skips_dependents_that_are_no_longer_open() {
+        let (store, _temp) = test_store();
+        let id1 = add_obligation(&store, "test-1", "medium", "", "").unwrap();
+        let id2 = add_obligation(&store, "test-2", "medium", &id1, "").unwrap();
+        let id3 = add_obligation(&store, "test-3", "medium", &id1, "").unwrap();
+
+        let conn = rusqlite::Connection::open(obligation_db_path(&store.root)).unwrap();
+        conn.execute(
+            "UPDATE obligations SET status = 'failed' WHERE id = ?1",
+            [&id1],
+        )
+        .unwrap();
+        conn.execute(
+            "UPDATE obligations SET status = 'met' WHERE id = ?1",
+            [&id2],
+        )
+        .unwrap();
+        conn.execute(
+            "UPDATE obligations SET status = 'waived' WHERE id = ?1",
+            [&id3],
+        )
+        .unwrap();
+
+        let result = check_obligation_graph(&store).unwrap();
+        assert!(
+            result.ok,
+            "met and waived dependents are not blocked: {:?}",
+            result
+                .unsatisfiable
+                .iter()
+                .map(|u| &u.obligation_id)
+                .collect::<Vec<_>>()
+        );
+    }
 }
@@ -1,7 +1,8 @@
 use decapod::core::store::{Store, StoreKind};
 use decapod::plugins::policy::{
-    RiskLevel, RiskMap, RiskZone, approve_action, check_approval, derive_fingerprint, eval_risk,
-    initialize_policy_db, is_high_risk,
+    RiskLevel, RiskMap, RiskZone, approve_action, audit_approvals, check_approval,
+    derive_fingerprint, eval_risk, initialize_policy_db, is_high_risk, list_approvals,
+    load_risk_map, riskmap_issues,
 };
 use tempfile::tempdir;
 
@@ -143,8 +144,44 @@ fn test_list_approvals() {
     approve_action(&store, "cmd1", None, "user1", "global").unwrap();
     approve_action(&store, "cmd2", Some("path/to/file"), "user2", "repo").unwrap();
 
-    // Note: list_approvals has a bug (action_id vs action_fingerprint)
-    // Skip this test for now
+    let approvals = list_approvals(&store).unwrap();
+    assert_eq!(approvals.len(), 2);
+    assert!(
+        approvals
+            .iter()
+            .any(|a| a.action_id == derive_fingerprint("cmd2", Some("path/to/file"), "repo"))
+    );
+}
+
+#[test]
+fn test_audit_lists_grant_check_and_denial() {
+    let tmp = tempdir().unwrap();
+    let store = Store {
+        kind: StoreKind::User,
+        root: tmp.path().to_path_buf(),
+    };
+    initialize_policy_db(&store.root).unwrap();
+
+    let approval_id = approve_action(&store, "todo.delete", None, "operator", "global").unwrap();
+    assert!(check_approval(&store, "todo.delete", None, "global").unwrap());
+    assert!(!check_approval(&store, "todo.archive", None, "global").unwrap());
+    // Repeat gate checks neither use up the approval nor grow the log.
+    assert!(check_approval(&store, "todo.delete", None, "global").unwrap());
+    assert!(!check_approval(&store, "todo.archive", None, "global").unwrap());
+
+    let entries = audit_approvals(&store, None).unwrap();
+    let kinds: Vec<&str> = entries.iter().map(|e| e.kind.as_str()).collect();
+    assert_eq!(kinds, vec!["granted", "checked", "denied"]);
+    assert_eq!(entries[0].actor, "operator");
+    assert_eq!(
+        entries[1].approval_id.as_deref(),
+        Some(approval_id.as_str())
+    );
+    assert_eq!(entries[1].action.as_deref(), Some("todo.delete"));
+    assert_eq!(entries[2].action.as_deref(), Some("todo.archive"));
+    assert!(entries[2].approval_id.is_none());
+
+    assert!(audit_approvals(&store, Some(u64::MAX)).unwrap().is_empty());
 }
 
 #[test]
@@ -168,3 +205,49 @@ fn test_approval_different_scopes() {
     // Different scope should NOT work (exact fingerprint match)
     assert!(!check_approval(&store, cmd, path, "docs").unwrap());
 }
+
+#[test]
+fn test_malformed_riskmap_is_rejected_not_ignored() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path();
+
+    // Missing file: no zones, no error.
+    assert!(load_risk_map(root).unwrap().zones.is_empty());
+
+    std::fs::write(
+        root.join("RISKMAP.json"),
+        r#"{"zones": [{"path": ".decapod/", "level": "CRITICAL", "rules": []},"#,
+    )
+    .unwrap();
+    let err = load_risk_map(root).expect_err("truncated JSON must not load as empty");
+    assert!(err.to_string().contains("validate-riskmap"), "{}", err);
+
+    let issues = riskmap_issues(
+        r#"{"zones": [
+            {"path": ".decapod/", "level": "CRITICAL", "rules": []},
+            {"path": ".decapod/", "level": "HIGH", "rules": []},
+            {"path": "docs/", "level": "SEVERE", "rules": []},
+            {"path": "src/", "level": "LOW", "rules": [], "owner": "x"}
+        ], "version": 2}"#,
+    );
+    assert_eq!(issues.len(), 4, "{:?}", issues);
+    assert!(
+        issues
+            .iter()
+            .any(|i| i.contains("unknown top-level field `version`"))
+    );
+    assert!(
+        issues
+            .iter()
+            .any(|i| i.contains("duplicate path `.decapod/`"))
+    );
+    assert!(issues.iter().any(|i| i.starts_with("zones[2]")));
+    assert!(issues.iter().any(|i| i.contains("unknown field `owner`")));
+
+    std::fs::write(
+        root.join("RISKMAP.json"),
+        r#"{"zones": [{"path": ".decapod/", "level": "CRITICAL", "rules": ["NO_AGENT_WRITE"]}]}"#,
+    )
+    .unwrap();
+    assert_eq!(load_risk_map(root).unwrap().zones.len(), 1);
+}
@@ -0,0 +1,157 @@
+use decapod::core::store::{Store, StoreKind};
+use decapod::core::todo::{TodoCommand, add_task, initialize_todo_db};
+use decapod::plugins::reflex::{
+    REFLEX_EVENTS_NAME, ReflexCli, ReflexCommand, execution_history, fire_trigger,
+    initialize_reflex_db, replay_executions, run_reflex_cli,
+};
+use tempfile::tempdir;
+
+#[test]
+fn test_fired_reflex_records_execution_with_matched_rule() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path().to_path_buf();
+    let dir = tmp.path().to_string_lossy().to_string();
+    initialize_todo_db(&root).unwrap();
+    initialize_reflex_db(&root).unwrap();
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: root.clone(),
+    };
+
+    let task = add_task(
+        &root,
+        &TodoCommand::Add {
+            title: "Ship release notes".to_string(),
+            description: "".to_string(),
+            tags: "".to_string(),
+            owner: "".to_string(),
+            due: None,
+            r#ref: "".to_string(),
+            dir: Some(dir.clone()),
+            priority: "medium".to_string(),
+            depends_on: "".to_string(),
+            blocks: "".to_string(),
+            parent: None,
+            one_shot: 0,
+        },
+    )
+    .unwrap();
+    let task_id = task["id"].as_str().unwrap().to_string();
+
+    run_reflex_cli(
+        &store,
+        ReflexCli {
+            command: ReflexCommand::Add {
+                name: "overdue-note".to_string(),
+                description: "".to_string(),
+                trigger_type: "todo.overdue".to_string(),
+                trigger_config: r#"{"grace_secs":0}"#.to_string(),
+                action_type: "todo.comment".to_string(),
+                action_config: r#"{"comment":"task is overdue"}"#.to_string(),
+                status: "active".to_string(),
+                tags: "".to_string(),
+                dir: Some(dir),
+            },
+        },
+    );
+
+    let event = serde_json::json!({ "task_id": task_id });
+    let fired = fire_trigger(&root, "todo.overdue", &event).unwrap();
+    assert_eq!(fired.len(), 1);
+    let reflex_id = fired[0]["reflex_id"].as_str().unwrap().to_string();
+
+    let history = execution_history(&root, Some(&reflex_id), 10).unwrap();
+    assert_eq!(history.len(), 1);
+    let entry = &history[0];
+    assert_eq!(entry.reflex_name, "overdue-note");
+    assert_eq!(entry.trigger_type, "todo.overdue");
+    assert_eq!(entry.event.as_ref(), Some(&event));
+    assert_eq!(entry.matched_rule.trigger_type, "todo.overdue");
+    assert_eq!(
+        entry.matched_rule.trigger_config,
+        serde_json::json!({ "grace_secs": 0 })
+    );
+    assert_eq!(entry.action_type, "todo.comment");
+    assert_eq!(entry.status, "ok", "{:?}", entry.result);
+    assert!(
+        execution_history(&root, Some("other"), 10)
+            .unwrap()
+            .is_empty()
+    );
+
+    // Replay is a dry run: it reports matches without firing again.
+    let replay = replay_executions(&root, 0).unwrap();
+    assert_eq!(replay.len(), 1);
+    assert_eq!(replay[0].fired, vec![reflex_id.clone()]);
+    assert_eq!(replay[0].would_fire.len(), 1);
+    assert_eq!(replay[0].would_fire[0].reflex_id, reflex_id);
+    assert_eq!(execution_history(&root, None, 10).unwrap().len(), 1);
+    assert!(replay_executions(&root, u64::MAX).unwrap().is_empty());
+}
+
+#[test]
+fn test_disarmed_reflex_does_not_fire_until_rearmed() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path().to_path_buf();
+    let dir = tmp.path().to_string_lossy().to_string();
+    initialize_reflex_db(&root).unwrap();
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: root.clone(),
+    };
+
+    run_reflex_cli(
+        &store,
+        ReflexCli {
+            command: ReflexCommand::Add {
+                name: "noisy".to_string(),
+                description: "".to_string(),
+                trigger_type: "todo.overdue".to_string(),
+                trigger_config: "{}".to_string(),
+                action_type: "todo.comment".to_string(),
+                action_config: r#"{"id":"task_missing"}"#.to_string(),
+                status: "active".to_string(),
+                tags: "".to_string(),
+                dir: Some(dir),
+            },
+        },
+    );
+    let event = serde_json::json!({ "task_id": "task_missing" });
+    let fired = fire_trigger(&root, "todo.overdue", &event).unwrap();
+    assert_eq!(fired.len(), 1);
+    let reflex_id = fired[0]["reflex_id"].as_str().unwrap().to_string();
+
+    run_reflex_cli(
+        &store,
+        ReflexCli {
+            command: ReflexCommand::Disarm {
+                id: reflex_id.clone(),
+            },
+        },
+    );
+    assert!(
+        fire_trigger(&root, "todo.overdue", &event)
+            .unwrap()
+            .is_empty(),
+        "disarmed rule must not fire"
+    );
+
+    run_reflex_cli(
+        &store,
+        ReflexCli {
+            command: ReflexCommand::Arm {
+                id: reflex_id.clone(),
+            },
+        },
+    );
+    let refired = fire_trigger(&root, "todo.overdue", &event).unwrap();
+    assert_eq!(refired.len(), 1);
+    assert_eq!(refired[0]["reflex_id"], reflex_id.as_str());
+
+    let audit = std::fs::read_to_string(root.join(REFLEX_EVENTS_NAME)).unwrap();
+    let ops: Vec<String> = audit
+        .lines()
+        .map(|l| serde_json::from_str::<serde_json::Value>(l).unwrap()["op"].to_string())
+        .collect();
+    assert_eq!(ops, vec!["\"reflex.disarm\"", "\"reflex.arm\""]);
+}
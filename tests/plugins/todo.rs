@@ -1,8 +1,8 @@
 use decapod::core::store::Store;
 use decapod::core::store::StoreKind;
 use decapod::core::todo::{
-    TodoCommand, add_task, check_trust_level, get_task, initialize_todo_db, list_tasks,
-    rebuild_from_events, todo_db_path, update_status,
+    ExportFormat, TodoCommand, add_task, check_trust_level, complete_task, due_tasks, export_tasks,
+    get_task, initialize_todo_db, list_tasks, rebuild_from_events, todo_db_path, update_status,
 };
 use decapod::plugins::policy;
 use rusqlite::Connection;
@@ -116,6 +116,60 @@ fn test_todo_rebuild() {
     assert_eq!(tasks.len(), 3);
 }
 
+#[test]
+fn test_rebuild_verify_flags_out_of_band_rows() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let added = run_cmd(repo, &["todo", "--format", "json", "add", "Verify me"]);
+    let task_id = added["id"].as_str().unwrap().to_string();
+    let _ = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "claim", "--id", &task_id, "--agent", "agent-a",
+        ],
+    );
+
+    let clean = run_cmd(repo, &["todo", "--format", "json", "rebuild", "--verify"]);
+    assert_eq!(clean["divergences"], 0);
+
+    let db = Connection::open(repo.join(".decapod/data/todo.db")).unwrap();
+    db.execute(
+        "INSERT INTO tasks(id, hash, title, status, created_at, updated_at, dir_path, scope)
+         VALUES('task_smuggledrow0001', 'smuggl', 'Smuggled', 'open', '1000Z', '1000Z', '', 'root')",
+        [],
+    )
+    .unwrap();
+    db.execute(
+        "UPDATE tasks SET title = 'Edited out of band' WHERE id = ?1",
+        rusqlite::params![task_id],
+    )
+    .unwrap();
+
+    let out = run_raw(repo, &["todo", "rebuild", "--verify"]);
+    assert!(!out.status.success(), "divergence must fail verification");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("tasks task_smuggledrow0001 missing_in_rebuild"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains(&format!("tasks {} differs (title)", task_id)),
+        "{stderr}"
+    );
+
+    // Verification never replaces the live DB.
+    let kept: i64 = db
+        .query_row(
+            "SELECT COUNT(*) FROM tasks WHERE id = 'task_smuggledrow0001'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(kept, 1);
+}
+
 #[test]
 fn test_trust_level_check() {
     let tmp = tempdir().unwrap();
@@ -425,6 +479,304 @@ fn test_done_accepts_positional_id() {
     assert_eq!(got["item"]["status"], "done");
 }
 
+#[test]
+fn test_get_events_returns_task_timeline_in_order() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let added = run_cmd(repo, &["todo", "--format", "json", "add", "Timeline test"]);
+    let task_id = added["id"].as_str().unwrap().to_string();
+    let _ = run_cmd(repo, &["todo", "--format", "json", "add", "Unrelated task"]);
+    let _ = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "claim", "--id", &task_id, "--agent", "agent-a",
+        ],
+    );
+
+    let plain = run_cmd(repo, &["todo", "--format", "json", "get", "--id", &task_id]);
+    assert!(plain.get("events").is_none());
+
+    let got = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "get", "--id", &task_id, "--events",
+        ],
+    );
+    let events = got["events"].as_array().expect("events timeline");
+    let types: Vec<&str> = events
+        .iter()
+        .map(|e| e["event_type"].as_str().unwrap())
+        .collect();
+    let add_at = types
+        .iter()
+        .position(|t| *t == "task.add")
+        .expect("add event");
+    let claim_at = types
+        .iter()
+        .position(|t| *t == "task.claim")
+        .expect("claim event");
+    assert!(add_at < claim_at, "{types:?}");
+    assert_eq!(events[claim_at]["actor"], "agent-a");
+    assert!(events.iter().all(|e| e["ts"].as_str().is_some()));
+    let adds = types.iter().filter(|t| **t == "task.add").count();
+    assert_eq!(adds, 1, "timeline must only include this task's events");
+}
+
+#[test]
+fn test_expired_claim_lease_allows_another_agent_to_claim() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let added = run_cmd(repo, &["todo", "--format", "json", "add", "Lease test"]);
+    let task_id = added["id"].as_str().unwrap().to_string();
+
+    let leased = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "claim", "--id", &task_id, "--agent", "agent-a", "--lease",
+            "600",
+        ],
+    );
+    assert_eq!(leased["status"], "ok");
+    assert!(leased["result"]["claim_expires_at"].as_str().is_some());
+
+    // A live lease still blocks other agents.
+    let blocked = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "claim", "--id", &task_id, "--agent", "agent-b",
+        ],
+    );
+    assert_ne!(blocked["result"]["status"], "ok");
+
+    // Push the expiry into the past.
+    let db = Connection::open(repo.join(".decapod/data/todo.db")).unwrap();
+    db.execute(
+        "UPDATE tasks SET claim_expires_at = '1000Z' WHERE id = ?1",
+        rusqlite::params![task_id],
+    )
+    .unwrap();
+    let expired = || -> i64 {
+        db.query_row(
+            "SELECT COUNT(*) FROM task_events WHERE event_type = 'task.lease_expired' AND task_id = ?1",
+            rusqlite::params![task_id],
+            |row| row.get(0),
+        )
+        .unwrap()
+    };
+
+    // Reads do not sweep leases.
+    let got = run_cmd(repo, &["todo", "--format", "json", "get", "--id", &task_id]);
+    assert_eq!(got["item"]["assigned_to"], "agent-a");
+    assert_eq!(expired(), 0);
+
+    let reclaimed = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "claim", "--id", &task_id, "--agent", "agent-b",
+        ],
+    );
+    assert_eq!(reclaimed["result"]["status"], "ok", "{reclaimed}");
+
+    let got = run_cmd(repo, &["todo", "--format", "json", "get", "--id", &task_id]);
+    assert_eq!(got["item"]["assigned_to"], "agent-b");
+    assert_eq!(expired(), 1);
+}
+
+#[test]
+fn test_custom_category_add_infer_list_and_remove() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let added = run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "categories",
+            "add",
+            "--name",
+            "ml-ops",
+            "--description",
+            "Model training and serving",
+            "--keywords",
+            "mlflow, Model Registry",
+        ],
+    );
+    assert_eq!(added["status"], "ok");
+    assert_eq!(added["keywords"], "mlflow,model registry");
+
+    let dup = run_raw(repo, &["todo", "categories", "add", "--name", "ml-ops"]);
+    assert!(!dup.status.success(), "duplicate category must be rejected");
+
+    let task = run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "add",
+            "Wire mlflow run metadata",
+        ],
+    );
+    let task_id = task["id"].as_str().unwrap().to_string();
+    let got = run_cmd(repo, &["todo", "--format", "json", "get", "--id", &task_id]);
+    assert_eq!(got["item"]["category"], "ml-ops");
+
+    let listed = run_cmd(repo, &["todo", "--format", "json", "categories"]);
+    assert!(
+        listed["categories"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["name"] == "ml-ops" && c["description"] == "Model training and serving")
+    );
+
+    // Removal refuses to orphan the filed task until a destination is given.
+    let refused = run_raw(repo, &["todo", "categories", "remove", "--name", "ml-ops"]);
+    assert!(!refused.status.success());
+    assert!(String::from_utf8_lossy(&refused.stderr).contains("--reassign-to"));
+
+    let removed = run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "categories",
+            "remove",
+            "--name",
+            "ml-ops",
+            "--reassign-to",
+            "infra",
+        ],
+    );
+    assert_eq!(removed["reassigned_tasks"], 1);
+    let got = run_cmd(repo, &["todo", "--format", "json", "get", "--id", &task_id]);
+    assert_eq!(got["item"]["category"], "infra");
+
+    // Both changes survive an event-log rebuild.
+    let _ = run_cmd(repo, &["todo", "--format", "json", "rebuild"]);
+    let listed = run_cmd(repo, &["todo", "--format", "json", "categories"]);
+    assert!(
+        !listed["categories"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["name"] == "ml-ops")
+    );
+    let got = run_cmd(repo, &["todo", "--format", "json", "get", "--id", &task_id]);
+    assert_eq!(got["item"]["category"], "infra");
+}
+
+#[test]
+fn test_reclassify_fills_empty_categories_and_keeps_manual_ones() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let legacy = run_cmd(repo, &["todo", "--format", "json", "add", "Zorblat sweep"]);
+    let legacy_id = legacy["id"].as_str().unwrap().to_string();
+    let manual = run_cmd(
+        repo,
+        &["todo", "--format", "json", "add", "Zorblat docs pass"],
+    );
+    let manual_id = manual["id"].as_str().unwrap().to_string();
+    let _ = run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "edit",
+            "--id",
+            &manual_id,
+            "--category",
+            "ux",
+        ],
+    );
+    let got = run_cmd(
+        repo,
+        &["todo", "--format", "json", "get", "--id", &legacy_id],
+    );
+    assert_eq!(got["item"]["category"], "");
+
+    // A keyword list change after the fact leaves the legacy task stale.
+    let _ = run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "categories",
+            "add",
+            "--name",
+            "zorblat",
+            "--keywords",
+            "zorblat",
+        ],
+    );
+
+    let preview = run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "reclassify",
+            "--dry-run",
+            "--only-empty",
+        ],
+    );
+    assert_eq!(preview["dry_run"], true);
+    let planned = preview["changes"].as_array().unwrap();
+    assert!(
+        planned.iter().any(|c| c["id"] == legacy_id.as_str()
+            && c["category"] == serde_json::json!(["", "zorblat"]))
+    );
+    assert!(
+        !planned
+            .iter()
+            .any(|c| c["id"] == manual_id.as_str() && !c["category"].is_null())
+    );
+    let got = run_cmd(
+        repo,
+        &["todo", "--format", "json", "get", "--id", &legacy_id],
+    );
+    assert_eq!(got["item"]["category"], "", "dry run must not write");
+
+    let applied = run_cmd(
+        repo,
+        &["todo", "--format", "json", "reclassify", "--only-empty"],
+    );
+    assert_eq!(applied["dry_run"], false);
+    let got = run_cmd(
+        repo,
+        &["todo", "--format", "json", "get", "--id", &legacy_id],
+    );
+    assert_eq!(got["item"]["category"], "zorblat");
+    let got = run_cmd(
+        repo,
+        &["todo", "--format", "json", "get", "--id", &manual_id],
+    );
+    assert_eq!(got["item"]["category"], "ux");
+
+    let db = Connection::open(repo.join(".decapod/data/todo.db")).unwrap();
+    let audited: i64 = db
+        .query_row(
+            "SELECT COUNT(*) FROM task_events WHERE event_type = 'task.reclassify' AND task_id = ?1",
+            rusqlite::params![legacy_id],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(audited, 1);
+}
+
 #[test]
 fn test_claim_includes_container_result_when_autorun_enabled() {
     let tmp = tempdir().unwrap();
@@ -603,3 +955,686 @@ fn test_ownership_rebuild_replay_parity() {
         "ownership claim/release replay should be deterministic"
     );
 }
+
+fn add_args_with_parent(dir: &Path, title: &str, parent: Option<&str>) -> TodoCommand {
+    TodoCommand::Add {
+        title: title.to_string(),
+        description: "".to_string(),
+        tags: "".to_string(),
+        owner: "".to_string(),
+        due: None,
+        r#ref: "".to_string(),
+        dir: Some(dir.to_string_lossy().to_string()),
+        priority: "medium".to_string(),
+        depends_on: "".to_string(),
+        blocks: "".to_string(),
+        parent: parent.map(|p| p.to_string()),
+        one_shot: 0,
+    }
+}
+
+#[test]
+fn test_add_rejects_invalid_parent() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path().to_path_buf();
+    initialize_todo_db(&root).unwrap();
+
+    // Missing parent.
+    let err = add_task(
+        &root,
+        &add_args_with_parent(tmp.path(), "orphan", Some("task_doesnotexist0000")),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("not found"), "{}", err);
+
+    // Valid parent is accepted and recorded.
+    let parent = add_task(&root, &add_args_with_parent(tmp.path(), "parent", None)).unwrap();
+    let parent_id = parent["id"].as_str().unwrap().to_string();
+    let child = add_task(
+        &root,
+        &add_args_with_parent(tmp.path(), "child", Some(&parent_id)),
+    )
+    .unwrap();
+    let child_id = child["id"].as_str().unwrap().to_string();
+    let child_task = get_task(&root, &child_id).unwrap().unwrap();
+    assert_eq!(
+        child_task.parent_task_id.as_deref(),
+        Some(parent_id.as_str())
+    );
+
+    // Done parent.
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: root.clone(),
+    };
+    let done = add_task(&root, &add_args_with_parent(tmp.path(), "finished", None)).unwrap();
+    let done_id = done["id"].as_str().unwrap().to_string();
+    update_status(&store, &done_id, "done", "task.done", serde_json::json!({})).unwrap();
+    let err = add_task(
+        &root,
+        &add_args_with_parent(tmp.path(), "late child", Some(&done_id)),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("already done"), "{}", err);
+
+    // Cyclic ancestry: make the parent point back at its own child.
+    let conn = Connection::open(todo_db_path(&root)).unwrap();
+    conn.execute(
+        "UPDATE tasks SET parent_task_id = ?1 WHERE id = ?2",
+        rusqlite::params![child_id, parent_id],
+    )
+    .unwrap();
+    drop(conn);
+    let err = add_task(
+        &root,
+        &add_args_with_parent(tmp.path(), "grandchild", Some(&child_id)),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("cycle"), "{}", err);
+}
+
+#[test]
+fn test_subtask_rollup_and_parent_done_guard() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let parent = run_cmd(repo, &["todo", "--format", "json", "add", "Parent task"]);
+    let parent_id = parent["id"].as_str().unwrap().to_string();
+    let mut child_ids = Vec::new();
+    for title in ["Child one", "Child two"] {
+        let child = run_cmd(
+            repo,
+            &[
+                "todo", "--format", "json", "add", title, "--parent", &parent_id,
+            ],
+        );
+        child_ids.push(child["id"].as_str().unwrap().to_string());
+    }
+    run_cmd(repo, &["todo", "--format", "json", "done", &child_ids[0]]);
+
+    let got = run_cmd(
+        repo,
+        &["todo", "--format", "json", "get", "--id", &parent_id],
+    );
+    assert_eq!(got["item"]["subtask_total"], 2);
+    assert_eq!(got["item"]["subtask_done"], 1);
+
+    let listed = run_cmd(repo, &["todo", "--format", "json", "list"]);
+    let listed_parent = listed["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["id"] == parent_id.as_str())
+        .expect("parent in list");
+    assert_eq!(listed_parent["subtask_total"], 2);
+    assert_eq!(listed_parent["subtask_done"], 1);
+
+    let blocked = run_raw(repo, &["todo", "done", &parent_id]);
+    assert!(
+        !blocked.status.success(),
+        "closing a parent with open subtasks should fail without --force"
+    );
+    assert!(String::from_utf8_lossy(&blocked.stderr).contains("open subtask"));
+
+    let forced = run_cmd(
+        repo,
+        &["todo", "--format", "json", "done", &parent_id, "--force"],
+    );
+    assert_eq!(forced["status"], "ok");
+}
+
+#[test]
+fn test_complete_task_leaves_parent_open_while_subtasks_remain() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path().to_path_buf();
+    initialize_todo_db(&root).unwrap();
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: root.clone(),
+    };
+
+    let parent = add_task(&root, &add_args_with_parent(tmp.path(), "parent", None)).unwrap();
+    let parent_id = parent["id"].as_str().unwrap().to_string();
+    add_task(
+        &root,
+        &add_args_with_parent(tmp.path(), "child", Some(&parent_id)),
+    )
+    .unwrap();
+
+    let err = complete_task(&store, &parent_id, false).unwrap_err();
+    assert!(err.to_string().contains("1 open subtask"));
+    let still_open = get_task(&root, &parent_id).unwrap().expect("parent");
+    assert_eq!(still_open.status, "open");
+
+    let forced = complete_task(&store, &parent_id, true).unwrap();
+    assert_eq!(forced["status"], "ok");
+    let closed = get_task(&root, &parent_id).unwrap().expect("parent");
+    assert_eq!(closed.status, "done");
+}
+
+#[test]
+fn test_export_markdown_groups_non_archived_tasks() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path().to_path_buf();
+    initialize_todo_db(&root).unwrap();
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: root.clone(),
+    };
+
+    let mut ids = Vec::new();
+    for title in ["Write docs", "Ship release", "Old idea", "Fix bug"] {
+        let res = add_task(&root, &add_args_with_parent(tmp.path(), title, None)).unwrap();
+        ids.push(res["id"].as_str().unwrap().to_string());
+    }
+    update_status(&store, &ids[1], "done", "task.done", serde_json::json!({})).unwrap();
+    let conn = Connection::open(todo_db_path(&root)).unwrap();
+    conn.execute(
+        "UPDATE tasks SET status = 'archived' WHERE id = ?1",
+        rusqlite::params![ids[2]],
+    )
+    .unwrap();
+    conn.execute(
+        "UPDATE tasks SET assigned_to = 'agent-a' WHERE id = ?1",
+        rusqlite::params![ids[3]],
+    )
+    .unwrap();
+    drop(conn);
+
+    let md = export_tasks(&root, ExportFormat::Markdown).unwrap();
+    let open_at = md.find("## Open (1)").expect("open section");
+    let progress_at = md.find("## In Progress (1)").expect("in-progress section");
+    let done_at = md.find("## Done (1)").expect("done section");
+    assert!(open_at < progress_at && progress_at < done_at);
+
+    let section_of = |id: &str| md.find(id).expect("task exported");
+    assert!(section_of(&ids[0]) > open_at && section_of(&ids[0]) < progress_at);
+    assert!(section_of(&ids[3]) > progress_at && section_of(&ids[3]) < done_at);
+    assert!(section_of(&ids[1]) > done_at);
+    assert!(md.contains("assignee: agent-a"));
+    assert!(!md.contains(&ids[2]), "archived tasks are not exported");
+
+    assert_eq!(
+        md,
+        export_tasks(&root, ExportFormat::Markdown).unwrap(),
+        "export must be deterministic"
+    );
+}
+
+#[test]
+fn test_due_reports_overdue_and_due_soon_in_order() {
+    let tmp = tempdir().unwrap();
+    let root = tmp.path().to_path_buf();
+    initialize_todo_db(&root).unwrap();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let seeds = [
+        ("due soon", Some(format!("{}Z", now + 2 * 86_400))),
+        ("far future", Some(format!("{}Z", now + 90 * 86_400))),
+        ("overdue", Some("2020-01-15T09:00:00Z".to_string())),
+        ("no due date", None),
+        ("due tomorrow", Some(format!("{}Z", now + 86_400))),
+    ];
+    let mut ids = std::collections::HashMap::new();
+    for (title, due) in seeds {
+        let mut args = add_args_with_parent(tmp.path(), title, None);
+        if let TodoCommand::Add { due: d, .. } = &mut args {
+            *d = due;
+        }
+        let res = add_task(&root, &args).unwrap();
+        ids.insert(title, res["id"].as_str().unwrap().to_string());
+    }
+
+    let due = due_tasks(&root, 7).unwrap();
+    let titles: Vec<&str> = due.iter().map(|d| d.task.title.as_str()).collect();
+    assert_eq!(titles, vec!["overdue", "due tomorrow", "due soon"]);
+    assert!(due[0].overdue);
+    assert!(!due[1].overdue && !due[2].overdue);
+    assert_eq!(due[0].task.id, ids["overdue"]);
+}
+
+#[test]
+fn test_overdue_event_fires_reflex_once() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let reflex = run_raw(
+        repo,
+        &[
+            "auto",
+            "reflex",
+            "add",
+            "--name",
+            "overdue-note",
+            "--trigger-type",
+            "todo.overdue",
+            "--action-type",
+            "todo.comment",
+            "--action-config",
+            r#"{"comment":"task is overdue"}"#,
+        ],
+    );
+    assert!(
+        reflex.status.success(),
+        "reflex add failed: {}",
+        String::from_utf8_lossy(&reflex.stderr)
+    );
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let due = format!("{}Z", now - 3600);
+    let added = run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "add",
+            "Deadline task",
+            "--due",
+            &due,
+        ],
+    );
+    let task_id = added["id"].as_str().unwrap().to_string();
+
+    let events_path = repo.join(".decapod/data/todo.events.jsonl");
+    let count_events = |event_type: &str| -> usize {
+        fs::read_to_string(&events_path)
+            .unwrap()
+            .lines()
+            .filter_map(|l| serde_json::from_str::<Value>(l).ok())
+            .filter(|ev| ev["event_type"] == event_type && ev["task_id"] == task_id.as_str())
+            .count()
+    };
+
+    run_cmd(repo, &["todo", "--format", "json", "list"]);
+    assert_eq!(count_events("todo.overdue"), 0, "reads never sweep");
+
+    run_cmd(repo, &["todo", "--format", "json", "due"]);
+    run_cmd(repo, &["todo", "--format", "json", "due"]);
+
+    assert_eq!(count_events("todo.overdue"), 1, "overdue emitted once");
+    assert_eq!(count_events("task.comment"), 1, "reflex fired exactly once");
+}
+
+#[test]
+fn test_list_jsonl_streams_one_task_per_line() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+    for title in ["Stream one", "Stream two", "Stream three"] {
+        run_cmd(repo, &["todo", "--format", "json", "add", title]);
+    }
+
+    let listed = run_cmd(repo, &["todo", "--format", "json", "list"]);
+    let items = listed["items"].as_array().expect("items");
+    assert_eq!(items.len(), 3);
+
+    let out = run_raw(repo, &["todo", "--format", "jsonl", "list"]);
+    assert!(
+        out.status.success(),
+        "jsonl list failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let records: Vec<Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line is one JSON object"))
+        .collect();
+    assert_eq!(records.len(), items.len(), "one line per row");
+    assert!(records.iter().all(Value::is_object));
+    assert_eq!(&records, items, "same rows in the same order as json");
+
+    let rejected = run_raw(repo, &["todo", "--format", "jsonl", "add", "nope"]);
+    assert!(!rejected.status.success());
+    let listed = run_cmd(repo, &["todo", "--format", "json", "list"]);
+    assert_eq!(listed["items"].as_array().unwrap().len(), 3);
+
+    let audit = run_raw(repo, &["data", "broker", "audit", "--format", "jsonl"]);
+    assert!(audit.status.success());
+    let logged = fs::read_to_string(repo.join(".decapod/data/broker.events.jsonl")).unwrap();
+    let audit_stdout = String::from_utf8_lossy(&audit.stdout);
+    assert_eq!(
+        audit_stdout.lines().count(),
+        logged.lines().filter(|l| !l.trim().is_empty()).count()
+    );
+    for line in audit_stdout.lines() {
+        assert!(serde_json::from_str::<Value>(line).unwrap().is_object());
+    }
+}
+
+#[test]
+fn test_list_jsonl_pages_past_one_read() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+    let seed = run_cmd(repo, &["todo", "--format", "json", "add", "Page seed"]);
+    let seed_id = seed["id"].as_str().unwrap().to_string();
+
+    // More rows than one streamed page, all sharing one `updated_at` so the
+    // cursor has to fall back to the id tiebreak.
+    let conn = Connection::open(repo.join(".decapod/data/todo.db")).unwrap();
+    for i in 0..300 {
+        conn.execute(
+            "INSERT INTO tasks (id, hash, title, description, tags, owner, due, ref, status, created_at, updated_at, dir_path, scope, priority, depends_on, blocks, category, component)
+             SELECT ?1, hash, title || ' ' || ?1, description, tags, owner, due, ref, status, created_at, updated_at, dir_path, scope, priority, depends_on, blocks, category, component
+             FROM tasks WHERE id = ?2",
+            rusqlite::params![format!("R_PAGE_{:03}", i), seed_id],
+        )
+        .unwrap();
+    }
+    drop(conn);
+
+    let listed = run_cmd(repo, &["todo", "--format", "json", "list"]);
+    let items = listed["items"].as_array().expect("items");
+    assert_eq!(items.len(), 301);
+
+    let out = run_raw(repo, &["todo", "--format", "jsonl", "list"]);
+    assert!(out.status.success());
+    let records: Vec<Value> = String::from_utf8_lossy(&out.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(&records, items, "pages join into the same ordered rows");
+    let ids: std::collections::HashSet<_> = records.iter().map(|r| r["id"].clone()).collect();
+    assert_eq!(ids.len(), 301, "no row is skipped or repeated across pages");
+
+    let bad = run_raw(repo, &["data", "broker", "audit", "--format", "xml"]);
+    assert!(!bad.status.success(), "unknown audit formats are rejected");
+}
+
+#[test]
+fn test_concurrent_claim_next_picks_distinct_ready_tasks() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let add = |title: &str, extra: &[&str]| -> String {
+        let mut args = vec!["todo", "--format", "json", "add", title];
+        args.extend_from_slice(extra);
+        run_cmd(repo, &args)["id"].as_str().unwrap().to_string()
+    };
+    let low = add("Zeta chore", &["--priority", "low"]);
+    let high = add("Omega chore", &["--priority", "high"]);
+    let blocked = add("Sigma chore", &["--priority", "high", "--depends-on", &low]);
+
+    let spawn_claim = |agent: &str| {
+        Command::new(env!("CARGO_BIN_EXE_decapod"))
+            .current_dir(repo)
+            .args([
+                "todo", "--format", "json", "claim", "--next", "--agent", agent,
+            ])
+            .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+            .env("DECAPOD_CLAIM_AUTORUN", "0")
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .expect("spawn claim --next")
+    };
+    let children = [spawn_claim("agent-a"), spawn_claim("agent-b")];
+    let mut claimed: Vec<String> = children
+        .into_iter()
+        .map(|child| {
+            let out = child.wait_with_output().unwrap();
+            assert!(
+                out.status.success(),
+                "claim --next failed: {}",
+                String::from_utf8_lossy(&out.stderr)
+            );
+            let res: Value = serde_json::from_slice(&out.stdout).expect("claim json");
+            assert_eq!(res["status"], "ok", "{res}");
+            assert_eq!(res["task"]["id"], res["id"]);
+            res["id"].as_str().unwrap().to_string()
+        })
+        .collect();
+    claimed.sort();
+    let mut expected = vec![low.clone(), high.clone()];
+    expected.sort();
+    assert_eq!(
+        claimed, expected,
+        "distinct ready tasks; the blocked one is skipped"
+    );
+
+    let none = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "claim", "--next", "--agent", "agent-c",
+        ],
+    );
+    assert_eq!(none["status"], "not_found", "{none}");
+
+    run_cmd(repo, &["todo", "--format", "json", "done", "--id", &low]);
+    let unblocked = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "claim", "--next", "--agent", "agent-c",
+        ],
+    );
+    assert_eq!(unblocked["id"], blocked.as_str(), "{unblocked}");
+}
+
+#[test]
+fn test_security_task_routes_to_security_expert() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    // agent-b sorts first and registers first, so only expertise can favour agent-z.
+    for (agent, level) in [("agent-b", "beginner"), ("agent-z", "expert")] {
+        run_cmd(
+            repo,
+            &["todo", "--format", "json", "heartbeat", "--agent", agent],
+        );
+        run_cmd(
+            repo,
+            &[
+                "todo",
+                "--format",
+                "json",
+                "register-expertise",
+                "--agent",
+                agent,
+                "--category",
+                "security",
+                "--level",
+                level,
+            ],
+        );
+    }
+
+    let added = run_cmd(
+        repo,
+        &["todo", "--format", "json", "add", "Harden security posture"],
+    );
+    let id = added["id"].as_str().unwrap();
+    let got = run_cmd(repo, &["todo", "--format", "json", "get", "--id", id]);
+    assert_eq!(got["item"]["category"], "security");
+    assert_eq!(got["item"]["assigned_to"], "agent-z", "{got}");
+
+    // Without a matching expert, a task stays unassigned.
+    let other = run_cmd(
+        repo,
+        &["todo", "--format", "json", "add", "Extend tests suite"],
+    );
+    let other_id = other["id"].as_str().unwrap();
+    let got = run_cmd(repo, &["todo", "--format", "json", "get", "--id", other_id]);
+    assert_eq!(got["item"]["category"], "tests");
+    assert_eq!(got["item"]["assigned_to"], "");
+
+    // `claim --next` weighs the claimant's expertise against priority.
+    run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "register-expertise",
+            "--agent",
+            "agent-q",
+            "--category",
+            "tests",
+            "--level",
+            "expert",
+        ],
+    );
+    run_cmd(
+        repo,
+        &[
+            "todo",
+            "--format",
+            "json",
+            "add",
+            "Tidy wording",
+            "--priority",
+            "high",
+        ],
+    );
+    let next = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "claim", "--next", "--agent", "agent-q",
+        ],
+    );
+    assert_eq!(next["id"], other_id, "{next}");
+}
+
+#[test]
+fn test_depends_add_rejects_cycles_and_remove_drops_edge() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let add = |title: &str| {
+        let out = run_cmd(repo, &["todo", "--format", "json", "add", title]);
+        out["id"].as_str().unwrap().to_string()
+    };
+    let a = add("Ship release");
+    let b = add("Write changelog");
+    let c = add("Tag version");
+
+    let added = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "depends", "add", "--id", &a, "--on", &b,
+        ],
+    );
+    assert_eq!(added["status"], "ok");
+    assert_eq!(added["depends_on"], b.as_str());
+    run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "depends", "add", "--id", &b, "--on", &c,
+        ],
+    );
+
+    // c -> a would close a -> b -> c -> a.
+    let cycle = run_raw(repo, &["todo", "depends", "add", "--id", &c, "--on", &a]);
+    assert!(
+        !cycle.status.success(),
+        "cycle-forming dependency must fail"
+    );
+    assert!(String::from_utf8_lossy(&cycle.stderr).contains("cycle"));
+
+    let missing = run_raw(
+        repo,
+        &["todo", "depends", "add", "--id", &a, "--on", "task_missing"],
+    );
+    assert!(!missing.status.success());
+
+    let removed = run_cmd(
+        repo,
+        &[
+            "todo", "--format", "json", "depends", "remove", "--id", &a, "--on", &b,
+        ],
+    );
+    assert_eq!(removed["depends_on"], "");
+    let got = run_cmd(repo, &["todo", "--format", "json", "get", "--id", &a]);
+    assert_eq!(got["item"]["depends_on"], "");
+
+    let again = run_raw(repo, &["todo", "depends", "remove", "--id", &a, "--on", &b]);
+    assert!(!again.status.success(), "removing an absent edge must fail");
+
+    let events = fs::read_to_string(repo.join(".decapod/data/todo.events.jsonl")).unwrap();
+    assert_eq!(events.matches("\"task.depends.added\"").count(), 2);
+    assert_eq!(events.matches("\"task.depends.removed\"").count(), 1);
+
+    let conn = Connection::open(todo_db_path(&repo.join(".decapod/data"))).unwrap();
+    let edges: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM task_dependencies WHERE task_id = ?1",
+            [&a],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(edges, 0);
+}
+
+#[test]
+fn test_broker_replay_to_mid_history_event_reconstructs_prior_state() {
+    let tmp = tempdir().unwrap();
+    let repo = tmp.path();
+    bootstrap_repo(repo);
+
+    let added = run_cmd(repo, &["todo", "--format", "json", "add", "Replay me"]);
+    let task_id = added["id"].as_str().unwrap().to_string();
+    let _ = run_cmd(repo, &["todo", "--format", "json", "done", &task_id]);
+
+    let events = fs::read_to_string(repo.join(".decapod/data/todo.events.jsonl")).unwrap();
+    let add_event_id = events
+        .lines()
+        .filter_map(|l| serde_json::from_str::<Value>(l).ok())
+        .find(|ev| ev["event_type"] == "task.add" && ev["task_id"] == task_id.as_str())
+        .and_then(|ev| ev["event_id"].as_str().map(str::to_string))
+        .expect("task.add event");
+
+    let into = tmp.path().join("forensics");
+    let replay = run_cmd(
+        repo,
+        &[
+            "data",
+            "broker",
+            "replay",
+            "--to",
+            &add_event_id,
+            "--into",
+            into.to_str().unwrap(),
+        ],
+    );
+    assert_eq!(replay["subsystem"], "todo");
+
+    let status_in = |db: &Path| -> String {
+        Connection::open(db)
+            .unwrap()
+            .query_row("SELECT status FROM tasks WHERE id = ?1", [&task_id], |r| {
+                r.get(0)
+            })
+            .unwrap()
+    };
+    assert_eq!(status_in(&todo_db_path(&into)), "open");
+    assert_eq!(
+        status_in(&todo_db_path(&repo.join(".decapod/data"))),
+        "done",
+        "live store is untouched"
+    );
+
+    let reused = run_raw(
+        repo,
+        &[
+            "data",
+            "broker",
+            "replay",
+            "--to",
+            &add_event_id,
+            "--into",
+            into.to_str().unwrap(),
+        ],
+    );
+    assert!(!reused.status.success(), "non-empty target is refused");
+}
@@ -0,0 +1,278 @@
+use decapod::core::store::{Store, StoreKind};
+use decapod::plugins::workflow::{
+    load_workflow_config, load_workflow_run, resolve_params, resume_workflow, run_defined_workflow,
+    workflow_runs_dir,
+};
+use std::fs;
+use tempfile::tempdir;
+
+const WORKFLOWS: &str = r#"
+[[workflow]]
+name = "greet"
+
+[[workflow.param]]
+name = "who"
+required = true
+
+[[workflow.param]]
+name = "times"
+type = "int"
+default = 2
+
+[[workflow.step]]
+name = "write"
+command = "sh"
+args = ["-c", "echo hello {{who}} x{{times}} > greeting.txt"]
+
+[[workflow]]
+name = "pipeline"
+
+[[workflow.step]]
+name = "one"
+command = "sh"
+args = ["-c", "echo one >> log.txt"]
+
+[[workflow.step]]
+name = "two"
+command = "sh"
+args = ["-c", "echo two >> log.txt"]
+
+[[workflow.step]]
+name = "three"
+command = "sh"
+args = ["-c", "test -f fixed && echo three >> log.txt"]
+idempotent = true
+
+[[workflow.step]]
+name = "four"
+command = "sh"
+args = ["-c", "echo four >> log.txt"]
+
+[[workflow]]
+name = "deploy"
+
+[[workflow.step]]
+name = "../push/remote"
+command = "sh"
+args = ["-c", "echo push >> log.txt; test -f fixed"]
+
+[[workflow.step]]
+name = "announce"
+command = "sh"
+args = ["-c", "echo announce >> log.txt"]
+"#;
+
+// The default `workflow_exec` allowlist has no shell; these fixtures opt in.
+const ALLOW_SH: &str = r#"{"rules": [{"capability": "workflow_exec", "allowed_bins": ["sh"]}]}"#;
+
+fn setup() -> (tempfile::TempDir, Store) {
+    let tmp = tempdir().unwrap();
+    let data = tmp.path().join(".decapod").join("data");
+    fs::create_dir_all(&data).unwrap();
+    fs::write(tmp.path().join(".decapod/workflows.toml"), WORKFLOWS).unwrap();
+    fs::write(tmp.path().join(".decapod/EXTERNAL_ACTIONS.json"), ALLOW_SH).unwrap();
+    let store = Store {
+        kind: StoreKind::Repo,
+        root: data,
+    };
+    (tmp, store)
+}
+
+#[test]
+fn test_params_are_substituted_into_steps() {
+    let (tmp, store) = setup();
+    let report = run_defined_workflow(
+        &store,
+        tmp.path(),
+        "greet",
+        &[("who".to_string(), "world".to_string())],
+    )
+    .unwrap();
+
+    assert_eq!(report.params["who"], "world");
+    assert_eq!(report.params["times"], "2", "default applied");
+    assert_eq!(
+        report.steps[0].args,
+        vec!["-c", "echo hello world x2 > greeting.txt"]
+    );
+    assert_eq!(
+        fs::read_to_string(tmp.path().join("greeting.txt")).unwrap(),
+        "hello world x2\n"
+    );
+}
+
+#[test]
+fn test_missing_or_mistyped_params_are_rejected_before_any_step() {
+    let (tmp, store) = setup();
+
+    let err = run_defined_workflow(&store, tmp.path(), "greet", &[])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("requires param(s): who (string)"), "{err}");
+    assert!(!tmp.path().join("greeting.txt").exists(), "no step ran");
+
+    let config = load_workflow_config(tmp.path()).unwrap();
+    let def = &config.workflow[0];
+    let err = resolve_params(
+        def,
+        &[
+            ("who".to_string(), "world".to_string()),
+            ("times".to_string(), "many".to_string()),
+        ],
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("param 'times' expects int"), "{err}");
+
+    let err = resolve_params(def, &[("whom".to_string(), "x".to_string())])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("has no param 'whom'"), "{err}");
+}
+
+#[test]
+fn test_failed_run_resumes_at_failed_step() {
+    let (tmp, store) = setup();
+
+    let err = run_defined_workflow(&store, tmp.path(), "pipeline", &[])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("step 'three' failed"), "{err}");
+    let run_id = fs::read_dir(workflow_runs_dir(tmp.path()))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .file_name()
+        .to_string_lossy()
+        .to_string();
+    assert!(err.contains(&format!("resume --run {}", run_id)), "{err}");
+    let run = load_workflow_run(tmp.path(), &run_id).unwrap();
+    assert_eq!(run.status, "failed");
+    assert_eq!(run.failed_step.as_deref(), Some("three"));
+
+    fs::write(tmp.path().join("fixed"), "").unwrap();
+    let report = resume_workflow(&store, tmp.path(), &run_id, false).unwrap();
+    assert_eq!(report.resumed_at.as_deref(), Some("three"));
+    let names: Vec<&str> = report.steps.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["three", "four"]);
+    assert_eq!(
+        fs::read_to_string(tmp.path().join("log.txt")).unwrap(),
+        "one\ntwo\nthree\nfour\n",
+        "steps one and two are not re-run"
+    );
+    assert_eq!(
+        load_workflow_run(tmp.path(), &run_id).unwrap().status,
+        "completed"
+    );
+    assert!(resume_workflow(&store, tmp.path(), &run_id, false).is_err());
+}
+
+#[test]
+fn test_params_cannot_choose_the_step_binary() {
+    let (tmp, store) = setup();
+    fs::write(
+        tmp.path().join(".decapod/workflows.toml"),
+        r#"
+[[workflow]]
+name = "pick"
+
+[[workflow.param]]
+name = "bin"
+default = "sh"
+
+[[workflow.step]]
+name = "run"
+command = "{{bin}}"
+args = ["-c", "touch ran"]
+"#,
+    )
+    .unwrap();
+
+    let err = run_defined_workflow(&store, tmp.path(), "pick", &[])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("must be literal"), "{err}");
+    assert!(!tmp.path().join("ran").exists());
+}
+
+#[test]
+fn test_default_allowlist_refuses_shells() {
+    let (tmp, store) = setup();
+    fs::remove_file(tmp.path().join(".decapod/EXTERNAL_ACTIONS.json")).unwrap();
+
+    let err = run_defined_workflow(
+        &store,
+        tmp.path(),
+        "greet",
+        &[("who".to_string(), "world".to_string())],
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("step 'write' failed"), "{err}");
+    assert!(!tmp.path().join("greeting.txt").exists());
+}
+
+#[test]
+fn test_resume_refuses_non_idempotent_step_without_skip() {
+    let (tmp, store) = setup();
+
+    let err = run_defined_workflow(&store, tmp.path(), "deploy", &[])
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("step '../push/remote' failed"), "{err}");
+    let runs = workflow_runs_dir(tmp.path());
+    let run_id = fs::read_dir(&runs)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .file_name()
+        .to_string_lossy()
+        .to_string();
+
+    let err = resume_workflow(&store, tmp.path(), &run_id, false)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("non-idempotent step '../push/remote'"),
+        "{err}"
+    );
+    assert!(err.contains("--skip"), "{err}");
+    assert_eq!(
+        fs::read_to_string(tmp.path().join("log.txt")).unwrap(),
+        "push\n",
+        "the failed step is not re-run"
+    );
+
+    let report = resume_workflow(&store, tmp.path(), &run_id, true).unwrap();
+    let statuses: Vec<(&str, &str)> = report
+        .steps
+        .iter()
+        .map(|s| (s.name.as_str(), s.status.as_str()))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![("../push/remote", "skipped"), ("announce", "completed")]
+    );
+    assert_eq!(
+        fs::read_to_string(tmp.path().join("log.txt")).unwrap(),
+        "push\nannounce\n"
+    );
+
+    let mut files: Vec<String> = fs::read_dir(runs.join(&run_id))
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    files.sort();
+    assert_eq!(
+        files,
+        vec![
+            "run.json",
+            "step-001-___push_remote.json",
+            "step-002-announce.json"
+        ],
+        "step names cannot escape the run directory"
+    );
+    assert_eq!(fs::read_dir(&runs).unwrap().count(), 1);
+}
@@ -0,0 +1,98 @@
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> String {
+    let out = Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .args(args)
+        .current_dir(dir)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("run decapod");
+    assert!(
+        out.status.success(),
+        "decapod {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).to_string()
+}
+
+/// Reduce a JSON value to its type skeleton so the golden file pins field
+/// names and types without pinning timestamps or paths.
+fn shape(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::String("null".into()),
+        Value::Bool(_) => Value::String("bool".into()),
+        Value::Number(_) => Value::String("number".into()),
+        Value::String(_) => Value::String("string".into()),
+        Value::Array(items) => Value::Array(items.first().map(shape).into_iter().collect()),
+        Value::Object(map) => {
+            Value::Object(map.iter().map(|(k, v)| (k.clone(), shape(v))).collect())
+        }
+    }
+}
+
+const BUILD_PROOF: &str = r#"
+[[proof]]
+name = "build"
+command = "sh"
+args = ["-c", "true"]
+required = true
+"#;
+
+#[test]
+fn proof_list_json_joins_config_with_last_run_and_matches_golden_shape() {
+    let tmp = TempDir::new().expect("tempdir");
+    let dir = tmp.path();
+    Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(dir)
+        .output()
+        .expect("git init");
+    run_decapod(dir, &["init", "--force"]);
+    run_decapod(dir, &["session", "acquire"]);
+
+    let proofs_toml = dir.join(".decapod/proofs.toml");
+    fs::write(&proofs_toml, BUILD_PROOF).expect("write proofs.toml");
+    run_decapod(dir, &["govern", "proof", "run"]);
+    // Configured after the run, so it has no history yet.
+    fs::write(
+        &proofs_toml,
+        format!("{BUILD_PROOF}\n[[proof]]\nname = \"lint\"\ncommand = \"sh\"\n"),
+    )
+    .expect("extend proofs.toml");
+
+    let stdout = run_decapod(dir, &["govern", "proof", "list", "--format", "json"]);
+    let report: Value = serde_json::from_str(&stdout).expect("proof list is JSON");
+    assert_eq!(report["schema_version"], "1.0.0");
+    let build = &report["proofs"][0];
+    assert_eq!(build["name"], "build");
+    assert_eq!(build["command"], "sh -c true");
+    assert_eq!(build["last_status"], "pass");
+    assert!(
+        build["artifact"]
+            .as_str()
+            .expect("artifact")
+            .contains("proof.events.jsonl#")
+    );
+    let lint = &report["proofs"][1];
+    assert_eq!(lint["name"], "lint");
+    assert!(lint["last_status"].is_null());
+    assert!(lint["last_run_ts"].is_null());
+    assert!(lint["artifact"].is_null());
+
+    let golden_path =
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/proof/v1/list.shape.json");
+    let golden: Value =
+        serde_json::from_str(&fs::read_to_string(&golden_path).expect("read golden"))
+            .expect("parse golden");
+    assert_eq!(
+        shape(&report),
+        golden,
+        "proof list JSON shape drifted; bump PROOF_LIST_SCHEMA_VERSION and update {}",
+        golden_path.display()
+    );
+}
@@ -0,0 +1,160 @@
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_decapod"));
+    cmd.current_dir(dir)
+        .args(args)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .env("DECAPOD_CLAIM_AUTORUN", "0")
+        .env("DECAPOD_AGENT_ID", "agent-fed");
+    if let Ok(password) = std::fs::read_to_string(dir.join(".session-password")) {
+        cmd.env("DECAPOD_SESSION_PASSWORD", password.trim());
+    }
+    cmd.output().expect("run decapod")
+}
+
+fn ok_json(dir: &Path, args: &[&str]) -> serde_json::Value {
+    let out = run_decapod(dir, args);
+    assert!(
+        out.status.success(),
+        "decapod {:?} failed:\nstdout: {}\nstderr: {}",
+        args,
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    );
+    serde_json::from_slice(&out.stdout).unwrap_or(serde_json::Value::Null)
+}
+
+fn rpc(dir: &Path, op: &str, params: serde_json::Value) -> serde_json::Value {
+    let res = ok_json(dir, &["rpc", "--op", op, "--params", &params.to_string()]);
+    assert_eq!(res["success"], true, "{op} failed: {res}");
+    res["result"].clone()
+}
+
+/// Repo with an ingested, awareness-complete session for `agent-fed`, which holds
+/// the `verified` trust the `federation.mutate` zone demands.
+fn setup_repo() -> TempDir {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    let init = Command::new("git")
+        .current_dir(dir)
+        .args(["init", "-b", "master"])
+        .output()
+        .expect("git init");
+    assert!(init.status.success(), "git init failed");
+    // Mutating ops refuse to run on master.
+    let branch = Command::new("git")
+        .current_dir(dir)
+        .args(["checkout", "-b", "feat/rpc-federation"])
+        .output()
+        .expect("git checkout");
+    assert!(branch.status.success(), "git checkout failed");
+    ok_json(dir, &["init", "--force"]);
+    let session = run_decapod(dir, &["session", "acquire"]);
+    let password = String::from_utf8_lossy(&session.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("Password: ").map(str::to_string))
+        .expect("session password");
+    std::fs::write(dir.join(".session-password"), password).expect("store password");
+    for args in [
+        &["validate"][..],
+        &["rpc", "--op", "agent.init"],
+        &["rpc", "--op", "context.resolve"],
+        &["docs", "ingest"],
+    ] {
+        ok_json(dir, args);
+    }
+    let db = rusqlite::Connection::open(dir.join(".decapod/data/todo.db")).expect("todo db");
+    db.execute(
+        "INSERT INTO agent_trust(agent_id, trust_level, granted_at, updated_at, granted_by)
+         VALUES('agent-fed', 'verified', '1771202800Z', '1771202800Z', 'test')",
+        [],
+    )
+    .expect("grant trust");
+    tmp
+}
+
+#[test]
+fn rpc_added_nodes_and_edge_form_a_queryable_relationship() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+
+    let a = rpc(
+        dir,
+        "federation.add_node",
+        serde_json::json!({
+            "title": "Broker serializes sqlite writes",
+            "kind": "lesson",
+            "content": "All writes go through the DbBroker.",
+            "source": ["file:src/core/broker.rs"],
+            "tags": ["storage"],
+        }),
+    );
+    let b = rpc(
+        dir,
+        "federation.add_node",
+        serde_json::json!({
+            "title": "Persist todo state in sqlite",
+            "kind": "decision",
+            "salience": "critical",
+            "origin": "human_confirmed",
+            "source": "file:src/core/todo.rs",
+        }),
+    );
+    let a_id = a["node"]["id"].as_str().expect("node a id").to_string();
+    let b_id = b["node"]["id"].as_str().expect("node b id").to_string();
+    assert_eq!(b["node"]["priority"], "critical");
+    assert_eq!(b["node"]["confidence"], "human_confirmed");
+
+    let edge = rpc(
+        dir,
+        "federation.add_edge",
+        serde_json::json!({ "from": b_id, "to": a_id, "type": "depends_on", "confidence": 0.75 }),
+    );
+    let edge_id = edge["edge_id"].as_str().expect("edge id");
+
+    let node = ok_json(
+        dir,
+        &[
+            "data",
+            "federation",
+            "--format",
+            "json",
+            "get",
+            "--id",
+            &b_id,
+        ],
+    );
+    let edges = node["edges"].as_array().expect("edges");
+    assert_eq!(edges.len(), 1, "{node}");
+    assert_eq!(edges[0]["id"], edge_id);
+    assert_eq!(edges[0]["source_id"], b_id);
+    assert_eq!(edges[0]["target_id"], a_id);
+    assert_eq!(edges[0]["edge_type"], "depends_on");
+    assert_eq!(edges[0]["confidence"], 0.75);
+
+    let graph = std::fs::read_to_string(dir.join(".decapod/data/federation/_graph.json"))
+        .expect("derived graph");
+    assert!(graph.contains(edge_id), "derived graph not refreshed");
+}
+
+#[test]
+fn rpc_add_edge_to_missing_node_is_an_rpc_error() {
+    let tmp = setup_repo();
+    let dir = tmp.path();
+    let out = run_decapod(
+        dir,
+        &[
+            "rpc",
+            "--op",
+            "federation.add_edge",
+            "--params",
+            r#"{"from":"F_MISSING","to":"F_ALSO_MISSING"}"#,
+        ],
+    );
+    let res: serde_json::Value = serde_json::from_slice(&out.stdout).expect("rpc json");
+    assert_eq!(res["success"], false);
+    assert_eq!(res["error"]["code"], "not_found", "{res}");
+}
@@ -23,3 +23,98 @@ fn rpc_agent_init_golden_vectors_are_parseable_and_stable() {
             .any(|op| op.op == "context.resolve")
     );
 }
+
+#[test]
+fn rpc_knowledge_search_golden_vectors_are_parseable_and_stable() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let req_raw =
+        fs::read_to_string(root.join("tests/golden/rpc/v1/knowledge_search.request.json"))
+            .expect("read request vector");
+    let res_raw =
+        fs::read_to_string(root.join("tests/golden/rpc/v1/knowledge_search.response.json"))
+            .expect("read response vector");
+
+    let req: RpcRequest = serde_json::from_str(&req_raw).expect("parse request vector");
+    let res: RpcResponse = serde_json::from_str(&res_raw).expect("parse response vector");
+
+    assert_eq!(req.op, "knowledge.search");
+    assert_eq!(res.receipt.op, "knowledge.search");
+    assert_eq!(res.id, req.id);
+    assert!(
+        decapod::plugins::knowledge::SEARCH_RANK_MODES
+            .contains(&req.params["rank"].as_str().expect("rank"))
+    );
+
+    let result = res.result.expect("result payload");
+    assert_eq!(result["rank"], "recency_decay");
+    let items = result["items"].as_array().expect("items");
+    assert_eq!(result["count"], items.len());
+    for item in items {
+        // Items must round-trip through the live entry type with no drift.
+        let entry: decapod::plugins::knowledge::KnowledgeEntry =
+            serde_json::from_value(item.clone()).expect("item matches KnowledgeEntry");
+        assert_eq!(&serde_json::to_value(&entry).unwrap(), item);
+    }
+}
+
+#[test]
+fn rpc_workspace_list_golden_vectors_are_parseable_and_stable() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let req_raw = fs::read_to_string(root.join("tests/golden/rpc/v1/workspace_list.request.json"))
+        .expect("read request vector");
+    let res_raw = fs::read_to_string(root.join("tests/golden/rpc/v1/workspace_list.response.json"))
+        .expect("read response vector");
+
+    let req: RpcRequest = serde_json::from_str(&req_raw).expect("parse request vector");
+    let res: RpcResponse = serde_json::from_str(&res_raw).expect("parse response vector");
+
+    assert_eq!(req.op, "workspace.list");
+    assert_eq!(res.receipt.op, "workspace.list");
+    assert_eq!(res.id, req.id);
+
+    let result = res.result.expect("result payload");
+    let worktrees = result["worktrees"].as_array().expect("worktrees");
+    assert!(!worktrees.is_empty());
+    for item in worktrees {
+        let entry: decapod::core::workspace::WorktreeEntry =
+            serde_json::from_value(item.clone()).expect("item matches WorktreeEntry");
+        assert_eq!(&serde_json::to_value(&entry).unwrap(), item);
+        assert!(entry.path.starts_with("/repo/.decapod/workspaces"));
+    }
+}
+
+#[test]
+fn rpc_federation_add_golden_vectors_are_parseable_and_stable() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let read = |name: &str| {
+        fs::read_to_string(root.join("tests/golden/rpc/v1").join(name)).expect("read vector")
+    };
+
+    let node_req: RpcRequest =
+        serde_json::from_str(&read("federation_add_node.request.json")).expect("parse request");
+    let node_res: RpcResponse =
+        serde_json::from_str(&read("federation_add_node.response.json")).expect("parse response");
+    assert_eq!(node_req.op, "federation.add_node");
+    assert_eq!(node_res.receipt.op, "federation.add_node");
+    assert_eq!(node_res.id, node_req.id);
+    let node = &node_res.result.expect("result payload")["node"];
+    let parsed: decapod::plugins::federation::FederationNode =
+        serde_json::from_value(node.clone()).expect("node matches FederationNode");
+    assert_eq!(&serde_json::to_value(&parsed).unwrap(), node);
+    assert_eq!(parsed.node_type, node_req.params["kind"]);
+    assert_eq!(parsed.priority, node_req.params["salience"]);
+    assert_eq!(parsed.confidence, node_req.params["origin"]);
+
+    let edge_req: RpcRequest =
+        serde_json::from_str(&read("federation_add_edge.request.json")).expect("parse request");
+    let edge_res: RpcResponse =
+        serde_json::from_str(&read("federation_add_edge.response.json")).expect("parse response");
+    assert_eq!(edge_req.op, "federation.add_edge");
+    assert_eq!(edge_res.receipt.op, "federation.add_edge");
+    assert_eq!(edge_res.id, edge_req.id);
+    let edge = edge_res.result.expect("result payload");
+    assert!(edge["edge_id"].as_str().unwrap().starts_with("FE_"));
+    for key in ["from", "to", "type", "confidence"] {
+        assert_eq!(edge[key], edge_req.params[key], "{key}");
+    }
+}
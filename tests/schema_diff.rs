@@ -0,0 +1,85 @@
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+fn decapod(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("failed to execute decapod")
+}
+
+fn ok(dir: &Path, args: &[&str]) -> String {
+    let out = decapod(dir, args);
+    assert!(
+        out.status.success(),
+        "{:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+    String::from_utf8_lossy(&out.stdout).to_string()
+}
+
+#[test]
+fn schema_diff_reports_added_op_and_removed_subsystem() {
+    let tmp = TempDir::new().expect("tempdir");
+    let dir = tmp.path();
+    ok(dir, &["init", "--force"]);
+    ok(dir, &["session", "acquire"]);
+
+    // Synthesize an older catalog: todo had no `claim` op yet, and a
+    // `legacy` subsystem has since been dropped.
+    let current: serde_json::Value =
+        serde_json::from_str(&ok(dir, &["data", "schema", "--deterministic"])).unwrap();
+    let mut old = current.clone();
+    old["schema_version"] = serde_json::json!("0.9.0");
+    old["subsystems"]["todo"]["commands"]
+        .as_array_mut()
+        .unwrap()
+        .retain(|c| c["name"] != "claim");
+    old["subsystems"]["legacy"] = serde_json::json!({ "name": "legacy", "commands": [] });
+    let old_path = dir.join("old_catalog.json");
+    std::fs::write(&old_path, serde_json::to_string(&old).unwrap()).unwrap();
+
+    let diff: serde_json::Value = serde_json::from_str(&ok(
+        dir,
+        &["data", "schema", "--diff", old_path.to_str().unwrap()],
+    ))
+    .unwrap();
+
+    assert_eq!(diff["old_schema_version"], "0.9.0");
+    assert_eq!(diff["new_schema_version"], current["schema_version"]);
+    assert_eq!(diff["subsystems"]["removed"], serde_json::json!(["legacy"]));
+    assert_eq!(diff["subsystems"]["added"], serde_json::json!([]));
+    let todo = &diff["subsystems"]["changed"]["todo"];
+    assert_eq!(todo["ops"]["added"], serde_json::json!(["claim"]));
+    assert_eq!(todo["ops"]["removed"], serde_json::json!([]));
+    assert_eq!(
+        diff["subsystems"]["changed"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["todo"],
+        "unchanged subsystems must not be reported"
+    );
+    assert_eq!(diff["breaking"], true, "a removed subsystem is breaking");
+    assert!(diff["deprecations"].as_array().is_some());
+
+    let unchanged_path = dir.join("current.json");
+    std::fs::write(&unchanged_path, serde_json::to_string(&current).unwrap()).unwrap();
+    let same: serde_json::Value = serde_json::from_str(&ok(
+        dir,
+        &["data", "schema", "--diff", unchanged_path.to_str().unwrap()],
+    ))
+    .unwrap();
+    assert_eq!(same["breaking"], false);
+    assert!(
+        same["subsystems"]["changed"]
+            .as_object()
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -50,3 +50,50 @@ fn schema_markdown_format_is_rendered() {
         "markdown output should not fallback to JSON warning"
     );
 }
+
+#[test]
+fn schema_markdown_subsystem_filter_renders_section() {
+    let tmp = TempDir::new().expect("tempdir");
+    let dir = tmp.path();
+
+    let init = Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(["init", "--force"])
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("failed to initialize decapod workspace");
+    assert!(init.status.success());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(["data", "schema", "--subsystem", "todo", "--format", "md"])
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("failed to execute decapod");
+    assert!(
+        output.status.success(),
+        "schema command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("# `todo` schema"), "{stdout}");
+    assert!(!stdout.contains("# Decapod Schema"));
+    assert!(stdout.contains("## Operations"));
+    for op in ["add", "list", "done", "claim", "rebuild"] {
+        assert!(
+            stdout.contains(&format!("| `{op}` |")),
+            "missing op {op}: {stdout}"
+        );
+    }
+    assert!(stdout.contains("| `storage` | `todo.db`, `todo.events.jsonl` |"));
+
+    let json = Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(["data", "schema", "--subsystem", "todo", "--format", "json"])
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("failed to execute decapod");
+    let value: serde_json::Value = serde_json::from_slice(&json.stdout).expect("schema JSON");
+    assert_eq!(value["name"], "todo");
+}
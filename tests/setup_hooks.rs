@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::tempdir;
+
+fn git_init(dir: &Path) {
+    let out = Command::new("git")
+        .args(["init", "-q"])
+        .current_dir(dir)
+        .output()
+        .expect("git init");
+    assert!(out.status.success(), "git init failed");
+}
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("run decapod")
+}
+
+#[test]
+fn setup_hook_pre_push_installs_and_uninstalls() {
+    let tmp = tempdir().expect("tempdir");
+    git_init(tmp.path());
+    let hook_path = tmp.path().join(".git/hooks/pre-push");
+
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--pre-push"]);
+    assert!(
+        out.status.success(),
+        "setup hook --pre-push failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(hook_path.exists(), "pre-push hook should be installed");
+    let content = fs::read_to_string(&hook_path).expect("read pre-push hook");
+    assert!(content.contains("decapod validate"));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&hook_path)
+            .expect("stat hook")
+            .permissions()
+            .mode();
+        assert!(mode & 0o111 != 0, "pre-push hook should be executable");
+    }
+
+    // Re-running is idempotent.
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--pre-push"]);
+    assert!(out.status.success(), "re-running --pre-push should succeed");
+    assert_eq!(
+        fs::read_to_string(&hook_path).expect("read pre-push hook"),
+        content
+    );
+
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--uninstall"]);
+    assert!(out.status.success(), "setup hook --uninstall failed");
+    assert!(!hook_path.exists(), "pre-push hook should be removed");
+}
+
+#[test]
+fn setup_hook_refuses_to_clobber_foreign_hook() {
+    let tmp = tempdir().expect("tempdir");
+    git_init(tmp.path());
+    let hook_path = tmp.path().join(".git/hooks/pre-commit");
+    fs::write(&hook_path, "#!/bin/sh\necho team hook\n").expect("write foreign hook");
+
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--pre-commit"]);
+    assert!(
+        !out.status.success(),
+        "installing over a foreign hook without --force should fail"
+    );
+    assert!(String::from_utf8_lossy(&out.stderr).contains("--force"));
+    assert_eq!(
+        fs::read_to_string(&hook_path).expect("read hook"),
+        "#!/bin/sh\necho team hook\n",
+        "foreign hook must be left untouched"
+    );
+    assert!(
+        !tmp.path()
+            .join(".git/hooks/pre-commit.pre-decapod")
+            .exists()
+    );
+}
+
+#[test]
+fn setup_hook_force_backs_up_foreign_hook() {
+    let tmp = tempdir().expect("tempdir");
+    git_init(tmp.path());
+    let hook_path = tmp.path().join(".git/hooks/pre-commit");
+    let backup_path = tmp.path().join(".git/hooks/pre-commit.pre-decapod");
+    fs::write(&hook_path, "#!/bin/sh\necho team hook\n").expect("write foreign hook");
+
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--pre-commit", "--force"]);
+    assert!(
+        out.status.success(),
+        "setup hook --force failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert_eq!(
+        fs::read_to_string(&backup_path).expect("read backup"),
+        "#!/bin/sh\necho team hook\n"
+    );
+    let installed = fs::read_to_string(&hook_path).expect("read hook");
+    assert!(installed.contains("# decapod-managed-hook"));
+    assert!(installed.contains("cargo clippy"));
+}
+
+#[test]
+fn setup_hook_reinstall_over_decapod_hook_skips_backup() {
+    let tmp = tempdir().expect("tempdir");
+    git_init(tmp.path());
+    let hook_path = tmp.path().join(".git/hooks/commit-msg");
+
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--commit-msg"]);
+    assert!(out.status.success(), "initial install failed");
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--commit-msg"]);
+    assert!(
+        out.status.success(),
+        "re-install over a Decapod hook should not need --force: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(hook_path.exists());
+    assert!(
+        !tmp.path()
+            .join(".git/hooks/commit-msg.pre-decapod")
+            .exists()
+    );
+}
+
+#[test]
+fn setup_hook_uninstall_removes_legacy_unmarked_hook() {
+    let tmp = tempdir().expect("tempdir");
+    git_init(tmp.path());
+    let hook_path = tmp.path().join(".git/hooks/pre-push");
+    // What `setup hook --pre-push` wrote before hooks carried a marker.
+    let legacy = "#!/bin/sh\nif ! decapod validate; then\n  echo \"pre-push hook: decapod validate failed; push blocked\"\n  exit 1\nfi\nexit 0\n";
+    fs::write(&hook_path, legacy).expect("write legacy hook");
+
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--uninstall"]);
+    assert!(out.status.success(), "setup hook --uninstall failed");
+    assert!(!hook_path.exists(), "legacy Decapod hook should be removed");
+}
+
+#[test]
+fn setup_hook_force_keeps_existing_backup() {
+    let tmp = tempdir().expect("tempdir");
+    git_init(tmp.path());
+    let hook_path = tmp.path().join(".git/hooks/pre-commit");
+    let backup_path = tmp.path().join(".git/hooks/pre-commit.pre-decapod");
+    fs::write(&backup_path, "#!/bin/sh\necho original\n").expect("write backup");
+    fs::write(&hook_path, "#!/bin/sh\necho team hook\n").expect("write foreign hook");
+
+    let out = run_decapod(tmp.path(), &["setup", "hook", "--pre-commit", "--force"]);
+    assert!(
+        !out.status.success(),
+        "--force must not overwrite an existing backup"
+    );
+    assert!(String::from_utf8_lossy(&out.stderr).contains("already exists"));
+    assert_eq!(
+        fs::read_to_string(&backup_path).expect("read backup"),
+        "#!/bin/sh\necho original\n"
+    );
+    assert_eq!(
+        fs::read_to_string(&hook_path).expect("read hook"),
+        "#!/bin/sh\necho team hook\n"
+    );
+}
@@ -251,6 +251,29 @@ fn test_workspace_protected_patterns() {
     );
 }
 
+#[test]
+fn test_capabilities_report_subsystem_schema_versions() {
+    let (_tmp, dir) = setup_repo();
+
+    let caps = run_decapod(dir, &["capabilities", "--format", "json"]);
+    let json: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&caps.stdout)).expect("valid JSON");
+
+    let subsystems = json["subsystems"].as_array().expect("subsystems array");
+    let todo = subsystems
+        .iter()
+        .find(|s| s["name"] == "todo")
+        .expect("todo subsystem");
+    assert_eq!(
+        todo["schema_version"],
+        decapod::core::schemas::TODO_SCHEMA_VERSION
+    );
+    assert!(
+        subsystems.iter().all(|s| s.get("schema_version").is_some()),
+        "every subsystem carries schema_version (null when unversioned)"
+    );
+}
+
 #[test]
 fn test_preflight_schema_stability() {
     let (_tmp, dir) = setup_repo();
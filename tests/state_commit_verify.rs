@@ -0,0 +1,162 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("run decapod")
+}
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let out = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .expect("git");
+    assert!(out.status.success(), "git {:?} failed", args);
+    String::from_utf8_lossy(&out.stdout).trim().to_string()
+}
+
+fn setup_repo() -> (TempDir, String, String) {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    git(dir, &["config", "user.email", "test@example.com"]);
+    git(dir, &["config", "user.name", "Test"]);
+    fs::write(dir.join("README.md"), "# fixture\n").unwrap();
+    git(dir, &["add", "README.md"]);
+    git(dir, &["commit", "-m", "base"]);
+    let base = git(dir, &["rev-parse", "HEAD"]);
+    fs::write(dir.join("scoped.txt"), "attested\n").unwrap();
+    fs::write(dir.join("other.txt"), "untouched\n").unwrap();
+    git(dir, &["add", "scoped.txt", "other.txt"]);
+    git(dir, &["commit", "-m", "scoped change"]);
+    let head = git(dir, &["rev-parse", "HEAD"]);
+    let init = run_decapod(dir, &["init", "--force"]);
+    assert!(
+        init.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&init.stderr)
+    );
+    (tmp, base, head)
+}
+
+fn field<'a>(stdout: &'a str, key: &str) -> &'a str {
+    stdout
+        .lines()
+        .find_map(|l| l.trim().strip_prefix(key))
+        .unwrap_or_else(|| panic!("missing {key} in:\n{stdout}"))
+        .trim()
+}
+
+#[test]
+fn verify_against_worktree_flags_modified_scoped_file() {
+    let (tmp, base, head) = setup_repo();
+    let dir = tmp.path();
+    let record_dir = TempDir::new().expect("record dir");
+    let record = record_dir.path().join("scope_record.cbor");
+    let record = record.to_str().unwrap();
+
+    let out = run_decapod(
+        dir,
+        &[
+            "state-commit",
+            "prove",
+            "--base",
+            &base,
+            "--head",
+            &head,
+            "--output",
+            record,
+        ],
+    );
+    let stdout = String::from_utf8_lossy(&out.stdout).to_string();
+    assert!(
+        out.status.success(),
+        "prove failed: {stdout}\n{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let record_hash = field(&stdout, "scope_record_hash:").to_string();
+
+    let clean = run_decapod(
+        dir,
+        &[
+            "state-commit",
+            "verify",
+            "--scope-record",
+            record,
+            "--against-worktree",
+        ],
+    );
+    assert!(clean.status.success(), "clean checkout must match");
+    assert!(String::from_utf8_lossy(&clean.stdout).contains("0 of 2 entries diverged"));
+
+    fs::write(dir.join("scoped.txt"), "edited after proving\n").unwrap();
+
+    let hash_only = run_decapod(
+        dir,
+        &[
+            "state-commit",
+            "verify",
+            "--scope-record",
+            record,
+            "--expected-root",
+            &record_hash,
+        ],
+    );
+    assert!(hash_only.status.success(), "record hash alone still holds");
+
+    let live = run_decapod(
+        dir,
+        &[
+            "state-commit",
+            "verify",
+            "--scope-record",
+            record,
+            "--expected-root",
+            &record_hash,
+            "--against-worktree",
+        ],
+    );
+    assert!(!live.status.success(), "worktree divergence must fail");
+    let stdout = String::from_utf8_lossy(&live.stdout);
+    assert!(stdout.contains("1 of 2 entries diverged"), "{stdout}");
+    assert!(stdout.contains("scoped.txt (content)"), "{stdout}");
+    assert!(!stdout.contains("other.txt ("), "{stdout}");
+}
+
+#[test]
+fn explain_decodes_golden_record() {
+    let (tmp, _, _) = setup_repo();
+    let golden = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden/state_commit/v1");
+    let record = golden.join("scope_record.cbor");
+    let out = run_decapod(
+        tmp.path(),
+        &[
+            "state-commit",
+            "explain",
+            "--scope-record",
+            record.to_str().unwrap(),
+        ],
+    );
+    let stdout = String::from_utf8_lossy(&out.stdout).to_string();
+    assert!(
+        out.status.success(),
+        "explain failed: {stdout}\n{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert_eq!(field(&stdout, "algo_version:"), "state_commit.v1");
+    assert_eq!(field(&stdout, "base:"), "6eb442a");
+    assert_eq!(field(&stdout, "head:"), "58b7c5f");
+    assert_eq!(field(&stdout, "entries:"), "6");
+    let expected_hash = fs::read_to_string(golden.join("scope_record_hash.txt")).unwrap();
+    assert_eq!(field(&stdout, "scope_record_hash:"), expected_hash.trim());
+    let expected_root = fs::read_to_string(golden.join("state_commit_root.txt")).unwrap();
+    assert_eq!(field(&stdout, "state_commit_root:"), expected_root.trim());
+    assert!(stdout.contains("symlink_test"), "{stdout}");
+}
@@ -344,3 +344,54 @@ fn test_workspace_ensure_requires_claimed_todo_and_scopes_naming() {
         task_id
     );
 }
+
+#[test]
+fn test_all_blocked_mandates_are_reported() {
+    let (_tmp, dir, _password) = setup_workspace();
+    // Back onto the protected default branch with no claimed todo: both the
+    // no-master and the mandatory-todo mandates are violated at once.
+    let out = Command::new("git")
+        .args(["checkout", "-q", "-"])
+        .current_dir(&dir)
+        .output()
+        .expect("git checkout");
+    assert!(out.status.success(), "git checkout failed");
+
+    let res = run_rpc(
+        &dir,
+        serde_json::json!({ "op": "agent.init", "params": {} }),
+        "test-agent-two-blockers",
+    );
+    assert_eq!(res["success"], false);
+    assert_eq!(res["error"]["code"], "mandate_violation");
+
+    let details = &res["error"]["details"];
+    let blocked = details["blocked_mandates"]
+        .as_array()
+        .expect("blocked_mandates");
+    let ids: Vec<&str> = blocked.iter().map(|m| m["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec!["no-master", "mandatory-todo"]);
+    assert_eq!(details["primary"], "no-master");
+    assert_eq!(blocked[0]["primary"], true);
+    assert_eq!(blocked[1]["primary"], false);
+    assert_eq!(blocked[0]["title"], "Workspace Rules (Non-Negotiable)");
+    assert!(
+        blocked[0]["remediation"]
+            .as_str()
+            .unwrap()
+            .contains("decapod workspace ensure")
+    );
+    assert!(
+        blocked[1]["remediation"]
+            .as_str()
+            .unwrap()
+            .contains("create and claim a `todo`")
+    );
+
+    assert_eq!(res["blocked_by"].as_array().unwrap().len(), 2);
+    let message = res["error"]["message"].as_str().unwrap();
+    assert!(
+        message.contains("Workspace Rules") && message.contains("+1 more"),
+        "{message}"
+    );
+}
@@ -0,0 +1,118 @@
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("run decapod")
+}
+
+fn setup_repo() -> TempDir {
+    let tmp = TempDir::new().expect("tmpdir");
+    let init = Command::new("git")
+        .current_dir(tmp.path())
+        .args(["init", "-b", "master"])
+        .output()
+        .expect("git init");
+    assert!(init.status.success(), "git init failed");
+    let out = run_decapod(tmp.path(), &["init", "--force"]);
+    assert!(
+        out.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    tmp
+}
+
+#[test]
+fn explain_known_gate_returns_bound_fragment_and_remediation() {
+    let tmp = setup_repo();
+    let out = run_decapod(
+        tmp.path(),
+        &[
+            "validate",
+            "--explain",
+            "repo_store_dogfood",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(
+        out.status.success(),
+        "validate --explain failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let explanation: Value = serde_json::from_slice(&out.stdout).expect("explain JSON");
+    assert_eq!(explanation["gate"], "validate_repo_store_dogfood");
+    assert_eq!(
+        explanation["doc_ref"],
+        "interfaces/STORE_MODEL.md#4. Guarantees (Contract)"
+    );
+    assert_eq!(
+        explanation["fragment"]["ref"],
+        "interfaces/STORE_MODEL.md#4. Guarantees (Contract)"
+    );
+    let remediation = explanation["remediation"].as_array().expect("remediation");
+    assert!(
+        remediation
+            .iter()
+            .any(|step| step.as_str().unwrap_or("").contains("decapod todo rebuild"))
+    );
+    assert_eq!(explanation["auto_remediable"], true);
+}
+
+#[test]
+fn auto_remediable_gates_carry_runnable_remediation_command() {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dogfood =
+        decapod::core::validate::explain_gate(tmp.path(), "repo_store_dogfood").expect("gate");
+    assert_eq!(
+        dogfood.remediation_command.as_deref(),
+        Some("decapod todo rebuild")
+    );
+    for gate in decapod::core::validate::gate_names() {
+        let explanation =
+            decapod::core::validate::explain_gate(tmp.path(), gate).expect("known gate");
+        if explanation.auto_remediable {
+            let cmd = explanation.remediation_command.unwrap_or_default();
+            assert!(
+                cmd.starts_with("decapod "),
+                "{gate} lacks a remediation command"
+            );
+        }
+    }
+}
+
+#[test]
+fn explain_unknown_gate_lists_known_gates() {
+    let tmp = setup_repo();
+    let out = run_decapod(tmp.path(), &["validate", "--explain", "no_such_gate"]);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("Unknown gate 'no_such_gate'"), "{stderr}");
+    assert!(stderr.contains("validate_tooling_gate"), "{stderr}");
+}
+
+#[test]
+fn every_explained_gate_binds_a_resolvable_fragment() {
+    let tmp = TempDir::new().expect("tmpdir");
+    for gate in decapod::core::validate::gate_names() {
+        let explanation =
+            decapod::core::validate::explain_gate(tmp.path(), gate).expect("known gate");
+        assert!(explanation.doc_ref.is_some(), "{gate} has no doc binding");
+        assert!(
+            explanation.fragment.is_some(),
+            "{gate} binds unresolvable fragment {:?}",
+            explanation.doc_ref
+        );
+        assert!(
+            !explanation.remediation.is_empty(),
+            "{gate} has no remediation"
+        );
+    }
+}
@@ -543,6 +543,74 @@ fn validate_fails_on_internalization_source_hash_drift_if_present() {
     assert!(stderr.contains("Internalization source hash mismatch"));
 }
 
+#[test]
+fn validate_baseline_flags_exactly_the_newly_failing_gate() {
+    let (_tmp, dir, password) = setup_repo();
+    let envs = [
+        ("DECAPOD_AGENT_ID", "unknown"),
+        ("DECAPOD_SESSION_PASSWORD", password.as_str()),
+        ("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1"),
+    ];
+    let doc_path = dir.join("doc.txt");
+    fs::write(&doc_path, "version 1").expect("write source doc");
+    let create = run_decapod(
+        &dir,
+        &[
+            "internalize",
+            "create",
+            "--source",
+            "doc.txt",
+            "--model",
+            "test-model",
+            "--profile",
+            "noop",
+            "--format",
+            "json",
+        ],
+        &[("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")],
+    );
+    assert!(
+        create.status.success(),
+        "create failed: {}",
+        combined_output(&create)
+    );
+
+    let baseline_run = || -> serde_json::Value {
+        let out = run_decapod(&dir, &["validate", "--baseline", "--format", "json"], &envs);
+        serde_json::from_slice(&out.stdout)
+            .unwrap_or_else(|e| panic!("validate json ({e}): {}", combined_output(&out)))
+    };
+
+    let first = baseline_run();
+    assert_eq!(first["status"], "ok", "{first}");
+    assert!(first["baseline"]["previous_ts"].is_null());
+    assert!(
+        dir.join(".decapod/generated/validate_baseline.json")
+            .exists()
+    );
+
+    fs::write(&doc_path, "version 2").expect("mutate source doc");
+    let regressed = baseline_run();
+    assert_eq!(regressed["status"], "fail");
+    assert!(regressed["baseline"]["previous_ts"].is_string());
+    assert_eq!(
+        regressed["baseline"]["newly_failing"],
+        serde_json::json!(["validate_internalization_artifacts_if_present"])
+    );
+    assert_eq!(
+        regressed["baseline"]["newly_passing"],
+        serde_json::json!([])
+    );
+
+    fs::write(&doc_path, "version 1").expect("restore source doc");
+    let fixed = baseline_run();
+    assert_eq!(fixed["baseline"]["newly_failing"], serde_json::json!([]));
+    assert_eq!(
+        fixed["baseline"]["newly_passing"],
+        serde_json::json!(["validate_internalization_artifacts_if_present"])
+    );
+}
+
 #[test]
 fn validate_fails_on_best_effort_internalization_claiming_replayable() {
     let (_tmp, dir, password) = setup_repo();
@@ -246,6 +246,79 @@ fn validate_json_reports_self_heal_and_structured_summary() {
     );
 }
 
+#[test]
+fn validate_store_both_labels_gates_and_fails_if_either_store_fails() {
+    use decapod::core::validate::{ValidationGateTiming, ValidationReport, merge_store_reports};
+
+    let (_tmp, dir, password) = setup_repo();
+    let validate = run_decapod(
+        &dir,
+        &["validate", "--store", "both", "--format", "json"],
+        &[
+            ("DECAPOD_CONTAINER", "1"),
+            ("DECAPOD_AGENT_ID", "unknown"),
+            ("DECAPOD_SESSION_PASSWORD", &password),
+            ("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1"),
+        ],
+    );
+    assert!(
+        validate.status.success(),
+        "validate --store both failed: {}",
+        String::from_utf8_lossy(&validate.stderr)
+    );
+    let payload: Value = serde_json::from_slice(&validate.stdout).expect("validate json");
+    assert_eq!(payload["report"]["status"], "ok");
+    let gates = payload["report"]["gate_timings"]
+        .as_array()
+        .expect("gate_timings");
+    let labeled = |store: &str, gate: &str| {
+        gates
+            .iter()
+            .any(|g| g["store"] == store && g["name"] == gate)
+    };
+    assert!(labeled("repo", "validate_repo_store_dogfood"), "{gates:?}");
+    assert!(
+        labeled("user", "validate_user_store_blank_slate"),
+        "{gates:?}"
+    );
+
+    let report = |fail_count: u32, gate: &str| ValidationReport {
+        status: if fail_count > 0 { "fail" } else { "ok" }.to_string(),
+        elapsed_ms: 10,
+        pass_count: 3,
+        fail_count,
+        warn_count: 0,
+        failures: (0..fail_count).map(|i| format!("failure {i}")).collect(),
+        warnings: Vec::new(),
+        gate_timings: vec![ValidationGateTiming {
+            name: gate.to_string(),
+            elapsed_ms: 10,
+            store: None,
+            fail_count,
+            remediation_command: None,
+        }],
+    };
+    for (repo_fails, user_fails) in [(1, 0), (0, 1)] {
+        let merged = merge_store_reports(vec![
+            ("repo", report(repo_fails, "validate_repo_store_dogfood")),
+            (
+                "user",
+                report(user_fails, "validate_user_store_blank_slate"),
+            ),
+        ]);
+        assert_eq!(merged.status, "fail");
+        assert_eq!(merged.fail_count, 1);
+        assert_eq!(merged.pass_count, 6);
+        let store = if repo_fails > 0 { "repo" } else { "user" };
+        assert_eq!(merged.failures, vec![format!("[{store}] failure 0")]);
+    }
+    let clean = merge_store_reports(vec![
+        ("repo", report(0, "validate_repo_store_dogfood")),
+        ("user", report(0, "validate_user_store_blank_slate")),
+    ]);
+    assert_eq!(clean.status, "ok");
+}
+
 #[test]
 fn validate_clears_stale_container_override_when_runtime_is_available() {
     let (_tmp, dir, password) = setup_repo();
@@ -582,3 +655,56 @@ fn validate_smoke_runtime_is_bounded_without_contention() {
         total
     );
 }
+
+#[test]
+fn per_gate_timeout_override_names_the_slow_gate() {
+    use decapod::core::validate::{
+        GateProgress, GateTimeouts, ValidationWaitError, wait_for_validation,
+    };
+    use std::sync::{Arc, Mutex, mpsc};
+    use std::time::Duration;
+
+    let tmp = TempDir::new().expect("tmpdir");
+    fs::create_dir_all(tmp.path().join(".decapod")).expect("decapod dir");
+    fs::write(
+        tmp.path().join(".decapod/config.toml"),
+        "[validate.gate_timeouts]\nslow_fixture = 1\n",
+    )
+    .expect("config");
+    let timeouts = GateTimeouts::load(tmp.path(), 30);
+    assert_eq!(
+        timeouts.bound_for("validate_slow_fixture"),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        timeouts.bound_for("validate_fast_fixture"),
+        Duration::from_secs(30)
+    );
+    assert_eq!(timeouts.overall(), Duration::from_secs(30));
+
+    let progress: Arc<GateProgress> = Arc::new(Mutex::new(None));
+    let (tx, rx) = mpsc::channel::<()>();
+    let worker_progress = Arc::clone(&progress);
+    std::thread::spawn(move || {
+        *worker_progress.lock().unwrap() = Some(("validate_fast_fixture", Instant::now()));
+        std::thread::sleep(Duration::from_millis(50));
+        *worker_progress.lock().unwrap() = Some(("validate_slow_fixture", Instant::now()));
+        std::thread::sleep(Duration::from_secs(5));
+        let _ = tx.send(());
+    });
+
+    let started = Instant::now();
+    let err = wait_for_validation(&rx, &timeouts, &progress).expect_err("slow gate times out");
+    assert_eq!(
+        err,
+        ValidationWaitError::GateTimeout {
+            gate: "validate_slow_fixture".to_string(),
+            bound: Duration::from_secs(1),
+        }
+    );
+    assert!(
+        started.elapsed() < Duration::from_secs(4),
+        "override bound should trip well before the gate finishes: {:?}",
+        started.elapsed()
+    );
+}
@@ -0,0 +1,183 @@
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_decapod(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_decapod"))
+        .current_dir(dir)
+        .args(args)
+        .env("DECAPOD_VALIDATE_SKIP_GIT_GATES", "1")
+        .output()
+        .expect("run decapod")
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let out = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .expect("run git");
+    assert!(
+        out.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+fn json_stdout(out: &std::process::Output) -> Value {
+    assert!(
+        out.status.success(),
+        "decapod failed: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    serde_json::from_slice(&out.stdout).expect("JSON stdout")
+}
+
+/// Repo with one commit, two managed worktrees, and one outside `.decapod/workspaces`.
+fn setup_repo() -> (TempDir, TempDir) {
+    let tmp = TempDir::new().expect("tmpdir");
+    let dir = tmp.path();
+    git(dir, &["init", "-b", "master"]);
+    git(dir, &["config", "user.email", "test@example.com"]);
+    git(dir, &["config", "user.name", "Test"]);
+    std::fs::write(dir.join("README.md"), "# test\n").expect("write readme");
+    git(dir, &["add", "README.md"]);
+    git(dir, &["commit", "-m", "init"]);
+    let init = run_decapod(dir, &["init", "--force"]);
+    assert!(
+        init.status.success(),
+        "decapod init failed: {}",
+        String::from_utf8_lossy(&init.stderr)
+    );
+
+    git(
+        dir,
+        &[
+            "worktree",
+            "add",
+            "-b",
+            "agent/a/one",
+            ".decapod/workspaces/agent-a",
+        ],
+    );
+    git(
+        dir,
+        &[
+            "worktree",
+            "add",
+            "-b",
+            "agent/b/two",
+            ".decapod/workspaces/agent-b",
+        ],
+    );
+    let outside = TempDir::new().expect("outside tmpdir");
+    let outside_path = outside.path().join("elsewhere");
+    git(
+        dir,
+        &[
+            "worktree",
+            "add",
+            "-b",
+            "scratch",
+            outside_path.to_str().unwrap(),
+        ],
+    );
+    (tmp, outside)
+}
+
+#[test]
+fn rpc_workspace_list_returns_managed_worktrees() {
+    let (tmp, _outside) = setup_repo();
+    // Mandates apply: the protected main checkout is refused like any other op.
+    let blocked: Value =
+        serde_json::from_slice(&run_decapod(tmp.path(), &["rpc", "--op", "workspace.list"]).stdout)
+            .expect("JSON stdout");
+    assert_eq!(blocked["error"]["code"], "mandate_violation", "{blocked}");
+
+    git(tmp.path(), &["checkout", "-b", "agent/c/inventory"]);
+    let out = run_decapod(tmp.path(), &["rpc", "--op", "workspace.list"]);
+    let response = json_stdout(&out);
+    assert_eq!(response["success"], true, "{response}");
+    assert_eq!(response["receipt"]["op"], "workspace.list");
+
+    let worktrees = response["result"]["worktrees"]
+        .as_array()
+        .expect("worktrees");
+    let names: Vec<&str> = worktrees
+        .iter()
+        .map(|w| w["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["agent-a", "agent-b"], "scratch is not managed");
+    assert_eq!(worktrees[0]["branch"], "agent/a/one");
+    assert_eq!(worktrees[0]["exists"], true);
+    assert_eq!(worktrees[0]["has_local_mods"], false);
+    assert!(
+        worktrees[0]["path"]
+            .as_str()
+            .unwrap()
+            .ends_with(".decapod/workspaces/agent-a")
+    );
+}
+
+#[test]
+fn workspace_prune_removes_only_clean_merged_worktrees() {
+    let (tmp, _outside) = setup_repo();
+    let dir = tmp.path();
+    let wt_a = dir.join(".decapod/workspaces/agent-a");
+    std::fs::write(wt_a.join("feature.txt"), "done\n").expect("write feature");
+    git(&wt_a, &["add", "feature.txt"]);
+    git(&wt_a, &["commit", "-m", "feature"]);
+    git(dir, &["merge", "--ff-only", "agent/a/one"]);
+    // agent-b never moved off its starting commit, so it has nothing merged.
+    git(
+        dir,
+        &[
+            "worktree",
+            "add",
+            "-b",
+            "agent/c/three",
+            ".decapod/workspaces/agent-c",
+        ],
+    );
+    std::fs::write(dir.join(".decapod/workspaces/agent-c/wip.txt"), "wip\n").expect("write wip");
+
+    let dry = json_stdout(&run_decapod(dir, &["workspace", "prune", "--dry-run"]));
+    let reasons = |report: &Value| -> Vec<(String, String)> {
+        report["prune"]["pruned"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| {
+                (
+                    p["name"].as_str().unwrap().to_string(),
+                    p["reason"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect()
+    };
+    assert_eq!(
+        reasons(&dry),
+        vec![("agent-a".to_string(), "merged".to_string())]
+    );
+    assert!(wt_a.exists(), "dry run removes nothing");
+
+    let report = json_stdout(&run_decapod(dir, &["workspace", "prune"]));
+    assert_eq!(reasons(&report), reasons(&dry));
+    assert!(!wt_a.exists());
+    let kept: Vec<&str> = report["prune"]["kept"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|w| w["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        kept,
+        vec!["agent-b", "agent-c"],
+        "fresh and dirty worktrees are kept"
+    );
+
+    let listed = json_stdout(&run_decapod(dir, &["workspace", "list"]));
+    assert_eq!(listed["worktrees"].as_array().unwrap().len(), 2);
+}